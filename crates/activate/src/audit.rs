@@ -0,0 +1,218 @@
+use super::change_journal::Window;
+use super::events::{Action, ChangeKind};
+use proto_gazette::broker::LabelSet;
+use std::collections::BTreeMap;
+
+/// AuditRecord attributes a single change applied to a data-plane
+/// to the actor which requested it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecord {
+    /// Actor which requested the change, as supplied by the caller.
+    pub actor: String,
+    /// Kind of the changed data-plane specification.
+    pub change_kind: ChangeKind,
+    /// Journal name or shard ID of the changed specification.
+    pub name: String,
+    /// Action which was applied.
+    pub action: Action,
+    /// Build label of the specification prior to the change,
+    /// or None if it didn't exist or its prior build isn't known.
+    pub old_build: Option<String>,
+    /// Build label of the specification after the change,
+    /// or None if it was deleted.
+    pub new_build: Option<String>,
+    /// Etcd revision at which the change was applied.
+    pub revision: i64,
+    /// Time at which the change was applied.
+    #[serde(serialize_with = "serialize_rfc3339")]
+    pub timestamp: std::time::SystemTime,
+}
+
+/// AuditSink is invoked with an AuditRecord of each applied change.
+pub type AuditSink<'a> = &'a (dyn Fn(AuditRecord) + Send + Sync);
+
+/// Audit attributes the changes of an activation to `actor`,
+/// reporting an AuditRecord of each to `sink`.
+#[derive(Copy, Clone)]
+pub struct Audit<'a> {
+    pub actor: &'a str,
+    pub sink: AuditSink<'a>,
+}
+
+// Build labels of current data-plane specifications, keyed on their journal
+// name or shard ID, which are the old builds of audited changes.
+pub(crate) type CurrentBuilds = BTreeMap<String, String>;
+
+pub(crate) fn current_builds<'a>(
+    specs: impl IntoIterator<Item = (&'a str, Option<&'a LabelSet>)>,
+) -> CurrentBuilds {
+    specs
+        .into_iter()
+        .filter_map(|(name, set)| Some((name.to_string(), build_of(set)?)))
+        .collect()
+}
+
+/// Map a `window` applied at `revision` into the AuditRecords of its changes.
+/// Unassignments of failed shards don't change specifications, and aren't audited.
+pub(crate) fn window_records(
+    actor: &str,
+    window: &Window,
+    revision: i64,
+    builds: &CurrentBuilds,
+    now: std::time::SystemTime,
+) -> Vec<AuditRecord> {
+    let record = |change_kind, name: &str, action, new_build| AuditRecord {
+        actor: actor.to_string(),
+        change_kind,
+        name: name.to_string(),
+        action,
+        old_build: builds.get(name).cloned(),
+        new_build,
+        revision,
+        timestamp: now,
+    };
+
+    match window {
+        Window::JournalUpserts(changes) => changes
+            .iter()
+            .map(|c| {
+                let spec = c.upsert.as_ref().unwrap();
+                let new_build = build_of(spec.labels.as_ref());
+                record(ChangeKind::Journal, &spec.name, Action::Upsert, new_build)
+            })
+            .collect(),
+        Window::JournalDeletes(changes) => changes
+            .iter()
+            .map(|c| record(ChangeKind::Journal, &c.delete, Action::Delete, None))
+            .collect(),
+        Window::ShardUpserts(changes) => changes
+            .iter()
+            .map(|c| {
+                let spec = c.upsert.as_ref().unwrap();
+                let new_build = build_of(spec.labels.as_ref());
+                record(ChangeKind::Shard, &spec.id, Action::Upsert, new_build)
+            })
+            .collect(),
+        Window::ShardDeletes(changes) => changes
+            .iter()
+            .map(|c| record(ChangeKind::Shard, &c.delete, Action::Delete, None))
+            .collect(),
+        Window::Unassign(_) => Vec::new(),
+    }
+}
+
+fn build_of(set: Option<&LabelSet>) -> Option<String> {
+    labels::expect_one(set?, labels::BUILD)
+        .ok()
+        .map(str::to_string)
+}
+
+fn serialize_rfc3339<S: serde::Serializer>(
+    time: &std::time::SystemTime,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    s.collect_str(&humantime::format_rfc3339_millis(*time))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proto_gazette::{broker, consumer};
+
+    #[test]
+    fn test_window_records() {
+        let builds = current_builds([
+            (
+                "capture/acmeCo/task/0011223344556677/00000000-00000000",
+                Some(&labels::build_set([(labels::BUILD, "0000000000000010")])),
+            ),
+            (
+                "recovery/capture/acmeCo/task/old",
+                Some(&labels::build_set([(labels::BUILD, "0000000000000008")])),
+            ),
+            ("acmeCo/no-build", None),
+        ]);
+        let now = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+
+        let mut records = window_records(
+            "user:alice@example.com",
+            &Window::ShardUpserts(vec![
+                consumer::apply_request::Change {
+                    expect_mod_revision: 3,
+                    upsert: Some(consumer::ShardSpec {
+                        id: "capture/acmeCo/task/0011223344556677/00000000-00000000".to_string(),
+                        labels: Some(labels::build_set([(labels::BUILD, "0000000000000020")])),
+                        ..Default::default()
+                    }),
+                    delete: String::new(),
+                },
+                consumer::apply_request::Change {
+                    expect_mod_revision: 0,
+                    upsert: Some(consumer::ShardSpec {
+                        id: "capture/acmeCo/task/0011223344556677/80000000-00000000".to_string(),
+                        labels: Some(labels::build_set([(labels::BUILD, "0000000000000020")])),
+                        ..Default::default()
+                    }),
+                    delete: String::new(),
+                },
+            ]),
+            42,
+            &builds,
+            now,
+        );
+        records.extend(window_records(
+            "user:alice@example.com",
+            &Window::JournalDeletes(vec![broker::apply_request::Change {
+                expect_mod_revision: 7,
+                upsert: None,
+                delete: "recovery/capture/acmeCo/task/old".to_string(),
+            }]),
+            43,
+            &builds,
+            now,
+        ));
+        records.extend(window_records(
+            "user:alice@example.com",
+            &Window::Unassign(vec!["capture/acmeCo/task/failed".to_string()]),
+            44,
+            &builds,
+            now,
+        ));
+
+        insta::assert_json_snapshot!(records, @r###"
+        [
+          {
+            "actor": "user:alice@example.com",
+            "changeKind": "shard",
+            "name": "capture/acmeCo/task/0011223344556677/00000000-00000000",
+            "action": "upsert",
+            "oldBuild": "0000000000000010",
+            "newBuild": "0000000000000020",
+            "revision": 42,
+            "timestamp": "2023-11-14T22:13:20.000Z"
+          },
+          {
+            "actor": "user:alice@example.com",
+            "changeKind": "shard",
+            "name": "capture/acmeCo/task/0011223344556677/80000000-00000000",
+            "action": "upsert",
+            "oldBuild": null,
+            "newBuild": "0000000000000020",
+            "revision": 42,
+            "timestamp": "2023-11-14T22:13:20.000Z"
+          },
+          {
+            "actor": "user:alice@example.com",
+            "changeKind": "journal",
+            "name": "recovery/capture/acmeCo/task/old",
+            "action": "delete",
+            "oldBuild": "0000000000000008",
+            "newBuild": null,
+            "revision": 43,
+            "timestamp": "2023-11-14T22:13:20.000Z"
+          }
+        ]
+        "###);
+    }
+}
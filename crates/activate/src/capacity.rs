@@ -0,0 +1,394 @@
+use anyhow::Context;
+use proto_gazette::{
+    broker::{self, JournalSpec, LabelSelector},
+    consumer::{self, ShardSpec},
+};
+use std::collections::BTreeSet;
+
+/// CapacityIssue is a shortfall of data-plane capacity for an activation.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum CapacityIssue {
+    /// Each shard requires more replicas (a primary and its hot standbys)
+    /// than there are consumer members to assign them to.
+    #[serde(rename_all = "camelCase")]
+    InsufficientConsumers { replicas: usize, consumers: usize },
+    /// Journals require more replicas than there are broker members.
+    #[serde(rename_all = "camelCase")]
+    InsufficientBrokers { replication: usize, brokers: usize },
+    /// More initial splits are requested than there are consumer members,
+    /// so that members will host multiple shards of the task.
+    #[serde(rename_all = "camelCase")]
+    SplitsExceedConsumers { splits: usize, consumers: usize },
+}
+
+impl CapacityIssue {
+    /// Errors are issues which prevent the activated task from running.
+    /// Other issues are warnings.
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Self::SplitsExceedConsumers { .. })
+    }
+}
+
+impl std::fmt::Display for CapacityIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InsufficientConsumers {
+                replicas,
+                consumers,
+            } => write!(
+                f,
+                "shards require {replicas} replicas but the data-plane has only {consumers} consumer members"
+            ),
+            Self::InsufficientBrokers {
+                replication,
+                brokers,
+            } => write!(
+                f,
+                "journals require a replication factor of {replication} but the data-plane has only {brokers} broker members"
+            ),
+            Self::SplitsExceedConsumers { splits, consumers } => write!(
+                f,
+                "{splits} initial splits are requested but the data-plane has only {consumers} consumer members"
+            ),
+        }
+    }
+}
+
+/// CapacityReport is the outcome of a pre-flight check of data-plane capacity.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapacityReport {
+    /// Number of consumer members which were observed.
+    pub consumers: usize,
+    /// Number of broker members which were observed.
+    pub brokers: usize,
+    /// Number of shard replicas which are currently assigned.
+    pub assigned_replicas: usize,
+    /// Number of shard replicas which the activation would add.
+    pub requested_replicas: usize,
+    /// Identified capacity issues.
+    pub issues: Vec<CapacityIssue>,
+}
+
+impl CapacityReport {
+    /// Return an error if any identified issue is an error.
+    /// Warnings are logged, and the report is returned.
+    pub fn check(self) -> anyhow::Result<Self> {
+        let mut errors = self
+            .issues
+            .iter()
+            .filter(|issue| issue.is_error())
+            .peekable();
+
+        if errors.peek().is_some() {
+            let errors: Vec<_> = errors.map(ToString::to_string).collect();
+            anyhow::bail!("insufficient data-plane capacity: {}", errors.join("; "));
+        }
+        for issue in &self.issues {
+            tracing::warn!(%issue, "data-plane capacity warning");
+        }
+        Ok(self)
+    }
+}
+
+/// Pre-flight check the capacity of a data-plane to activate a new task
+/// having `initial_splits` shards of `shard_template`, and journals of
+/// `journal_templates` (such as its recovery logs or collection partitions).
+///
+/// Gazette doesn't list its members directly, so consumer and broker members
+/// are observed from the routes of current shards and journals. Listings are
+/// bounded to the first split of each task's shards, and to the ops logs
+/// partitions of each task (per `ops_logs_template`), so members which host
+/// neither aren't observed and the data-plane may have more capacity than is
+/// reported. If no members of a kind are observed at all, its capacity is
+/// unknown and it isn't checked.
+pub async fn preflight_capacity(
+    journal_client: &gazette::journal::Client,
+    shard_client: &gazette::shard::Client,
+    shard_template: Option<&ShardSpec>,
+    journal_templates: &[&JournalSpec],
+    ops_logs_template: Option<&JournalSpec>,
+    initial_splits: usize,
+) -> anyhow::Result<CapacityReport> {
+    let (list_shards, list_journals) = preflight_requests(ops_logs_template);
+
+    let list_journals = async {
+        match list_journals {
+            Some(request) => journal_client.list(request).await,
+            None => Ok(Default::default()),
+        }
+    };
+    let (shards, journals) = futures::join!(shard_client.list(list_shards), list_journals);
+    let shards = shards.context("listing data-plane shards")?;
+    let journals = journals.context("listing data-plane journals")?;
+
+    Ok(capacity_report(
+        shards,
+        journals,
+        shard_template,
+        journal_templates,
+        initial_splits,
+    ))
+}
+
+// Build bounded ListRequests of the first shard split of each task, and of
+// the partitions of the ops logs collection of `ops_logs_template`.
+fn preflight_requests(
+    ops_logs_template: Option<&JournalSpec>,
+) -> (consumer::ListRequest, Option<broker::ListRequest>) {
+    let list_shards = consumer::ListRequest {
+        selector: Some(LabelSelector {
+            include: Some(labels::build_set([
+                (labels::MANAGED_BY, labels::MANAGED_BY_FLOW),
+                (labels::KEY_BEGIN, labels::KEY_BEGIN_MIN),
+                (labels::RCLOCK_BEGIN, labels::RCLOCK_BEGIN_MIN),
+            ])),
+            exclude: None,
+        }),
+        ..Default::default()
+    };
+
+    let ops_logs_collection = ops_logs_template
+        .and_then(|template| template.labels.as_ref())
+        .and_then(|set| labels::values(set, labels::COLLECTION).first());
+
+    let list_journals = ops_logs_collection.map(|label| broker::ListRequest {
+        selector: Some(LabelSelector {
+            include: Some(labels::build_set([
+                (labels::MANAGED_BY, labels::MANAGED_BY_FLOW),
+                (labels::COLLECTION, label.value.as_str()),
+            ])),
+            exclude: None,
+        }),
+        ..Default::default()
+    });
+
+    (list_shards, list_journals)
+}
+
+pub(crate) fn capacity_report(
+    shards: consumer::ListResponse,
+    journals: broker::ListResponse,
+    shard_template: Option<&ShardSpec>,
+    journal_templates: &[&JournalSpec],
+    initial_splits: usize,
+) -> CapacityReport {
+    let route_members = |route: Option<&broker::Route>| {
+        route
+            .into_iter()
+            .flat_map(|route| route.members.iter())
+            .map(|id| (id.zone.clone(), id.suffix.clone()))
+    };
+
+    let consumers: BTreeSet<_> = shards
+        .shards
+        .iter()
+        .flat_map(|shard| route_members(shard.route.as_ref()))
+        .collect();
+    let brokers: BTreeSet<_> = journals
+        .journals
+        .iter()
+        .flat_map(|journal| route_members(journal.route.as_ref()))
+        .collect();
+
+    let assigned_replicas = shards
+        .shards
+        .iter()
+        .map(|shard| shard.route.as_ref().map_or(0, |route| route.members.len()))
+        .sum();
+
+    let (consumers, brokers) = (consumers.len(), brokers.len());
+    let mut issues = Vec::new();
+
+    let replicas = match shard_template {
+        Some(template) if !template.disable && initial_splits != 0 => {
+            1 + template.hot_standbys as usize
+        }
+        _ => 0,
+    };
+    if consumers != 0 && replicas > consumers {
+        issues.push(CapacityIssue::InsufficientConsumers {
+            replicas,
+            consumers,
+        });
+    }
+    if consumers != 0 && replicas != 0 && initial_splits > consumers {
+        issues.push(CapacityIssue::SplitsExceedConsumers {
+            splits: initial_splits,
+            consumers,
+        });
+    }
+
+    let replication = journal_templates
+        .iter()
+        .map(|template| template.replication.max(0) as usize)
+        .max()
+        .unwrap_or_default();
+
+    if brokers != 0 && replication > brokers {
+        issues.push(CapacityIssue::InsufficientBrokers {
+            replication,
+            brokers,
+        });
+    }
+
+    CapacityReport {
+        consumers,
+        brokers,
+        assigned_replicas,
+        requested_replicas: replicas * initial_splits,
+        issues,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proto_gazette::broker::process_spec;
+
+    fn route(members: &[&str]) -> Option<broker::Route> {
+        Some(broker::Route {
+            members: members
+                .iter()
+                .map(|suffix| process_spec::Id {
+                    zone: "us-central1-a".to_string(),
+                    suffix: suffix.to_string(),
+                })
+                .collect(),
+            primary: 0,
+            endpoints: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn test_preflight_requests() {
+        let ops_logs_template = JournalSpec {
+            name: "ops/tasks/public/gcp-us-central1/logs".to_string(),
+            labels: Some(labels::build_set([(
+                labels::COLLECTION,
+                "ops/tasks/public/gcp-us-central1/logs",
+            )])),
+            ..Default::default()
+        };
+
+        let (list_shards, list_journals) = preflight_requests(Some(&ops_logs_template));
+        insta::assert_json_snapshot!((list_shards.selector, list_journals.unwrap().selector), @r###"
+        [
+          {
+            "include": {
+              "labels": [
+                {
+                  "name": "app.gazette.dev/managed-by",
+                  "value": "estuary.dev/flow"
+                },
+                {
+                  "name": "estuary.dev/key-begin",
+                  "value": "00000000"
+                },
+                {
+                  "name": "estuary.dev/rclock-begin",
+                  "value": "00000000"
+                }
+              ]
+            }
+          },
+          {
+            "include": {
+              "labels": [
+                {
+                  "name": "app.gazette.dev/managed-by",
+                  "value": "estuary.dev/flow"
+                },
+                {
+                  "name": "estuary.dev/collection",
+                  "value": "ops/tasks/public/gcp-us-central1/logs"
+                }
+              ]
+            }
+          }
+        ]
+        "###);
+
+        // Without an ops logs template, journals aren't listed.
+        let (_, list_journals) = preflight_requests(None);
+        assert!(list_journals.is_none());
+    }
+
+    #[test]
+    fn test_capacity_report() {
+        let shards = consumer::ListResponse {
+            shards: vec![
+                consumer::list_response::Shard {
+                    route: route(&["reactor-1", "reactor-2"]),
+                    ..Default::default()
+                },
+                consumer::list_response::Shard {
+                    route: route(&["reactor-2"]),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let journals = broker::ListResponse {
+            journals: vec![broker::list_response::Journal {
+                route: route(&["broker-1", "broker-2"]),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let shard_template = ShardSpec {
+            hot_standbys: 1,
+            ..Default::default()
+        };
+        let recovery_template = JournalSpec {
+            replication: 3,
+            ..Default::default()
+        };
+
+        let report = capacity_report(
+            shards.clone(),
+            journals.clone(),
+            Some(&shard_template),
+            &[&recovery_template],
+            32,
+        );
+        insta::assert_json_snapshot!(report, @r###"
+        {
+          "consumers": 2,
+          "brokers": 2,
+          "assignedReplicas": 3,
+          "requestedReplicas": 64,
+          "issues": [
+            {
+              "kind": "SplitsExceedConsumers",
+              "splits": 32,
+              "consumers": 2
+            },
+            {
+              "kind": "InsufficientBrokers",
+              "replication": 3,
+              "brokers": 2
+            }
+          ]
+        }
+        "###);
+
+        insta::assert_snapshot!(report.check().unwrap_err(), @"insufficient data-plane capacity: journals require a replication factor of 3 but the data-plane has only 2 broker members");
+
+        // Requests within capacity have no issues.
+        let report = capacity_report(shards, journals, Some(&shard_template), &[], 2);
+        assert!(report.issues.is_empty());
+        assert!(report.check().is_ok());
+
+        // Members which aren't observed are not checked.
+        let report = capacity_report(
+            Default::default(),
+            Default::default(),
+            Some(&shard_template),
+            &[&recovery_template],
+            32,
+        );
+        assert!(report.issues.is_empty());
+    }
+}
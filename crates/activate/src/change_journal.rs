@@ -0,0 +1,172 @@
+use super::audit::CurrentBuilds;
+use anyhow::Context;
+use proto_gazette::{broker, consumer};
+use std::path::PathBuf;
+
+// Window is a batch of changes which are applied together, in order.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum Window {
+    JournalUpserts(Vec<broker::apply_request::Change>),
+    ShardUpserts(Vec<consumer::apply_request::Change>),
+    ShardDeletes(Vec<consumer::apply_request::Change>),
+    JournalDeletes(Vec<broker::apply_request::Change>),
+    Unassign(Vec<String>),
+}
+
+// Record is a planned activation, and its progress through its windows.
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Record {
+    // Name of the activated catalog entity.
+    pub name: String,
+    // Content hash of `windows`.
+    pub hash: String,
+    // Planned windows of the activation.
+    pub windows: Vec<Window>,
+    // Etcd revisions at which each window was applied, or None if not yet applied.
+    pub applied: Vec<Option<i64>>,
+    // Builds of specifications prior to the activation, which are the
+    // old builds of audited changes of resumed windows.
+    #[serde(default, skip_serializing_if = "CurrentBuilds::is_empty")]
+    pub builds: CurrentBuilds,
+}
+
+/// ChangeJournal durably records the planned changes of activations, and
+/// their progress, so that an activation which is interrupted part-way
+/// through applying its changes may be resumed. Records are held as files
+/// within a local directory, and are removed once their activation completes.
+#[derive(Debug, Clone)]
+pub struct ChangeJournal {
+    dir: PathBuf,
+}
+
+impl ChangeJournal {
+    /// Build a ChangeJournal which records activations within directory `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub(crate) fn begin(
+        &self,
+        name: &str,
+        windows: Vec<Window>,
+        builds: CurrentBuilds,
+    ) -> anyhow::Result<Record> {
+        let record = Record {
+            name: name.to_string(),
+            hash: hash_windows(&windows),
+            applied: vec![None; windows.len()],
+            windows,
+            builds,
+        };
+        self.store(&record)?;
+        Ok(record)
+    }
+
+    pub(crate) fn load(&self, name: &str) -> anyhow::Result<Option<Record>> {
+        let path = self.path(name);
+
+        let content = match std::fs::read(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err).with_context(|| format!("failed to read {path:?}")),
+        };
+        let record: Record = serde_json::from_slice(&content)
+            .with_context(|| format!("failed to parse change journal {path:?}"))?;
+
+        if record.name != name {
+            anyhow::bail!("change journal {path:?} is for {}, not {name}", record.name);
+        }
+        if record.hash != hash_windows(&record.windows)
+            || record.applied.len() != record.windows.len()
+        {
+            anyhow::bail!("change journal {path:?} is corrupt and doesn't match its hash");
+        }
+        Ok(Some(record))
+    }
+
+    pub(crate) fn store(&self, record: &Record) -> anyhow::Result<()> {
+        let path = self.path(&record.name);
+        let tmp_path = path.with_extension("json.tmp");
+
+        // Write and then rename into place, so that a crash never leaves a partial record.
+        std::fs::write(&tmp_path, serde_json::to_vec(record).unwrap())
+            .with_context(|| format!("failed to write {tmp_path:?}"))?;
+        std::fs::rename(&tmp_path, &path)
+            .with_context(|| format!("failed to rename {tmp_path:?} to {path:?}"))?;
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, name: &str) -> anyhow::Result<()> {
+        let path = self.path(name);
+
+        match std::fs::remove_file(&path) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                Err(err).with_context(|| format!("failed to remove {path:?}"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Records are named by the hash of their catalog name, which may contain '/'.
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!(
+            "{:016x}.json",
+            xxhash_rust::xxh3::xxh3_64(name.as_bytes())
+        ))
+    }
+}
+
+fn hash_windows(windows: &[Window]) -> String {
+    let content = serde_json::to_vec(windows).unwrap();
+    format!("{:032x}", xxhash_rust::xxh3::xxh3_128(&content))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_change_journal_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = ChangeJournal::new(dir.path());
+
+        assert_eq!(journal.load("acmeCo/task").unwrap(), None);
+
+        let windows = vec![
+            Window::JournalUpserts(vec![broker::apply_request::Change {
+                expect_mod_revision: 0,
+                upsert: Some(broker::JournalSpec {
+                    name: "acmeCo/collection/pivot=00".to_string(),
+                    ..Default::default()
+                }),
+                delete: String::new(),
+            }]),
+            Window::Unassign(vec!["acmeCo/task/0000".to_string()]),
+        ];
+        let builds = [(
+            "acmeCo/task/0000".to_string(),
+            "0000000000000010".to_string(),
+        )]
+        .into();
+        let mut record = journal.begin("acmeCo/task", windows, builds).unwrap();
+        record.applied[0] = Some(1234);
+        journal.store(&record).unwrap();
+
+        assert_eq!(journal.load("acmeCo/task").unwrap(), Some(record));
+        assert_eq!(journal.load("acmeCo/other").unwrap(), None);
+
+        // A record which doesn't match its hash is rejected.
+        let path = journal.path("acmeCo/task");
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, content.replace("pivot=00", "pivot=80")).unwrap();
+
+        let err = journal.load("acmeCo/task").unwrap_err();
+        assert!(err.to_string().contains("doesn't match its hash"));
+
+        journal.remove("acmeCo/task").unwrap();
+        journal.remove("acmeCo/task").unwrap(); // Idempotent.
+        assert_eq!(journal.load("acmeCo/task").unwrap(), None);
+    }
+}
@@ -0,0 +1,276 @@
+use super::change_journal::Window;
+use anyhow::Context;
+use futures::StreamExt;
+use proto_gazette::{broker, uuid};
+use std::collections::BTreeMap;
+
+/// ActivationEvent describes a single change applied to a data-plane
+/// by an activation of a catalog task.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivationEvent {
+    /// Name of the activated catalog task.
+    pub task: String,
+    /// Kind of the changed data-plane specification.
+    pub change_kind: ChangeKind,
+    /// Journal name or shard ID of the changed specification.
+    pub name: String,
+    /// Etcd revision at which the change was applied,
+    /// or zero if the change doesn't modify a specification.
+    pub revision: i64,
+    /// Action which was applied.
+    pub action: Action,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    Journal,
+    Shard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Action {
+    /// The specification was created or updated.
+    Upsert,
+    /// The specification was deleted.
+    Delete,
+    /// The failed shard was unassigned, so that it's restarted.
+    Unassign,
+}
+
+/// EventSink is invoked with each ActivationEvent as its change is applied.
+pub type EventSink<'a> = &'a (dyn Fn(ActivationEvent) + Send + Sync);
+
+/// Map a `window` of `task`, applied at `revision`, into its ActivationEvents.
+pub(crate) fn window_events(task: &str, window: &Window, revision: i64) -> Vec<ActivationEvent> {
+    let event = |change_kind, name: &str, revision, action| ActivationEvent {
+        task: task.to_string(),
+        change_kind,
+        name: name.to_string(),
+        revision,
+        action,
+    };
+
+    match window {
+        Window::JournalUpserts(changes) => changes
+            .iter()
+            .map(|c| {
+                let name = &c.upsert.as_ref().unwrap().name;
+                event(ChangeKind::Journal, name, revision, Action::Upsert)
+            })
+            .collect(),
+        Window::JournalDeletes(changes) => changes
+            .iter()
+            .map(|c| event(ChangeKind::Journal, &c.delete, revision, Action::Delete))
+            .collect(),
+        Window::ShardUpserts(changes) => changes
+            .iter()
+            .map(|c| {
+                let id = &c.upsert.as_ref().unwrap().id;
+                event(ChangeKind::Shard, id, revision, Action::Upsert)
+            })
+            .collect(),
+        Window::ShardDeletes(changes) => changes
+            .iter()
+            .map(|c| event(ChangeKind::Shard, &c.delete, revision, Action::Delete))
+            .collect(),
+        Window::Unassign(ids) => ids
+            .iter()
+            .map(|id| event(ChangeKind::Shard, id, 0, Action::Unassign))
+            .collect(),
+    }
+}
+
+/// Map ActivationEvents of a task into ops Log documents, as newline-delimited
+/// JSON. Each document is committed on its own, outside of any transaction.
+pub fn ops_log_documents(
+    task_type: ops::TaskType,
+    events: &[ActivationEvent],
+    now: std::time::SystemTime,
+) -> Vec<u8> {
+    let mut clock = uuid::Clock::from_time(now);
+    let mut out = Vec::new();
+
+    for event in events {
+        // Use a stable producer for each task, so that readers may sequence
+        // its events. The low bit of the first byte marks it as not a real MAC.
+        let producer = xxhash_rust::xxh3::xxh3_64(event.task.as_bytes()).to_be_bytes();
+        let mut producer: [u8; 6] = producer[..6].try_into().unwrap();
+        producer[0] |= 0x01;
+
+        clock.tick();
+        let uuid = uuid::build(
+            uuid::Producer::from_bytes(producer),
+            clock,
+            uuid::Flags(proto_gazette::message_flags::OUTSIDE_TXN as u16),
+        );
+        let (seconds, nanos) = clock.to_unix();
+
+        let fields: BTreeMap<String, String> = [
+            ("changeKind", serde_json::to_string(&event.change_kind)),
+            ("name", serde_json::to_string(&event.name)),
+            ("revision", serde_json::to_string(&event.revision)),
+            ("action", serde_json::to_string(&event.action)),
+        ]
+        .into_iter()
+        .map(|(field, value)| (field.to_string(), value.unwrap()))
+        .collect();
+
+        let log = ops::Log {
+            meta: Some(ops::Meta {
+                uuid: uuid.to_string(),
+            }),
+            shard: Some(ops::ShardRef {
+                kind: task_type as i32,
+                name: event.task.clone(),
+                key_begin: labels::KEY_BEGIN_MIN.to_string(),
+                r_clock_begin: labels::RCLOCK_BEGIN_MIN.to_string(),
+            }),
+            timestamp: Some(pbjson_types::Timestamp {
+                seconds: seconds as i64,
+                nanos: nanos as i32,
+            }),
+            level: ops::LogLevel::Info as i32,
+            message: "applied activation change".to_string(),
+            fields_json_map: fields,
+            spans: Vec::new(),
+        };
+
+        serde_json::to_writer(&mut out, &log).expect("Log always serializes");
+        out.push(b'\n');
+    }
+    out
+}
+
+/// Publish ActivationEvents of a task as ops Log documents
+/// which are appended to its ops logs journal `logs_journal`.
+pub async fn publish_events(
+    journal_client: &gazette::journal::Client,
+    task_type: ops::TaskType,
+    logs_journal: &str,
+    events: &[ActivationEvent],
+) -> anyhow::Result<()> {
+    if events.is_empty() {
+        return Ok(());
+    }
+    let content = bytes::Bytes::from(ops_log_documents(
+        task_type,
+        events,
+        std::time::SystemTime::now(),
+    ));
+
+    // Number of attempts of an append before giving up on a transient error.
+    const MAX_ATTEMPTS: usize = 5;
+
+    let request = broker::AppendRequest {
+        journal: logs_journal.to_string(),
+        ..Default::default()
+    };
+    let stream = journal_client.append(request, move || {
+        futures::stream::once(futures::future::ready(Ok(content.clone())))
+    });
+    tokio::pin!(stream);
+
+    loop {
+        match stream.next().await {
+            Some(Ok(_response)) => return Ok(()),
+            Some(Err(gazette::RetryError { attempt, inner }))
+                if inner.is_transient() && attempt + 1 < MAX_ATTEMPTS =>
+            {
+                tracing::warn!(%logs_journal, attempt, error = ?inner, "retrying append of activation events");
+            }
+            Some(Err(gazette::RetryError { inner, .. })) => {
+                return Err(anyhow::anyhow!(inner))
+                    .with_context(|| format!("appending activation events to {logs_journal}"))
+            }
+            None => unreachable!("append stream yields until a response or error is returned"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proto_gazette::consumer;
+
+    #[test]
+    fn test_window_events_and_ops_logs() {
+        let window = Window::ShardUpserts(vec![consumer::apply_request::Change {
+            expect_mod_revision: 0,
+            upsert: Some(consumer::ShardSpec {
+                id: "capture/acmeCo/task/0011223344556677/00000000-00000000".to_string(),
+                ..Default::default()
+            }),
+            delete: String::new(),
+        }]);
+        let mut events = window_events("acmeCo/task", &window, 42);
+
+        events.extend(window_events(
+            "acmeCo/task",
+            &Window::JournalDeletes(vec![proto_gazette::broker::apply_request::Change {
+                expect_mod_revision: 7,
+                upsert: None,
+                delete: "recovery/capture/acmeCo/task/old".to_string(),
+            }]),
+            43,
+        ));
+        events.extend(window_events(
+            "acmeCo/task",
+            &Window::Unassign(vec!["capture/acmeCo/task/failed".to_string()]),
+            44,
+        ));
+
+        assert_eq!(
+            events
+                .iter()
+                .map(|e| (e.change_kind, e.name.as_str(), e.revision, e.action))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    ChangeKind::Shard,
+                    "capture/acmeCo/task/0011223344556677/00000000-00000000",
+                    42,
+                    Action::Upsert
+                ),
+                (
+                    ChangeKind::Journal,
+                    "recovery/capture/acmeCo/task/old",
+                    43,
+                    Action::Delete
+                ),
+                (
+                    ChangeKind::Shard,
+                    "capture/acmeCo/task/failed",
+                    0,
+                    Action::Unassign
+                ),
+            ]
+        );
+
+        let docs = ops_log_documents(
+            ops::TaskType::Capture,
+            &events,
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000),
+        );
+        let docs: Vec<serde_json::Value> = serde_json::Deserializer::from_slice(&docs)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(docs.len(), 3);
+        assert_eq!(docs[1]["shard"]["name"], "acmeCo/task");
+        assert_eq!(docs[1]["fields"]["action"], "delete");
+        assert_eq!(docs[1]["fields"]["revision"], 43);
+
+        // Documents have distinct, increasing UUIDs of a common producer.
+        let uuids: Vec<_> = docs
+            .iter()
+            .map(|doc| uuid::parse_str(doc["_meta"]["uuid"].as_str().unwrap()).unwrap())
+            .collect();
+        assert!(uuids
+            .windows(2)
+            .all(|w| w[0].0 == w[1].0 && w[0].1 < w[1].1));
+    }
+}
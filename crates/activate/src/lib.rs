@@ -4,10 +4,27 @@ use proto_flow::flow;
 use proto_gazette::{
     broker::{self, JournalSpec, Label, LabelSelector, LabelSet},
     consumer::{self, ShardSpec},
+    recoverylog,
 };
 use serde_json::json;
 use std::collections::BTreeMap;
 
+mod audit;
+use audit::CurrentBuilds;
+pub use audit::{Audit, AuditRecord, AuditSink};
+
+mod capacity;
+pub use capacity::{preflight_capacity, CapacityIssue, CapacityReport};
+
+mod change_journal;
+pub use change_journal::ChangeJournal;
+use change_journal::Window;
+
+mod events;
+pub use events::{
+    ops_log_documents, publish_events, Action, ActivationEvent, ChangeKind, EventSink,
+};
+
 // A Shard or Journal change to be applied.
 #[derive(serde::Serialize)]
 enum Change {
@@ -22,6 +39,9 @@ struct JournalSplit {
     labels: LabelSet,
     mod_revision: i64,
     suspend: Option<journal_spec::Suspend>,
+    // Current JournalSpec, sans labels, if known.
+    #[serde(skip)]
+    current: Option<JournalSpec>,
 }
 
 // ShardSplit describes a task partition.
@@ -38,7 +58,70 @@ struct TaskTemplate<'a> {
     recovery: &'a JournalSpec,
 }
 
+/// NewerBuildPolicy is the handling of a current shard which carries a newer
+/// build than the template being activated. This is an anomaly, as successive
+/// activations of a task are expected to move forward through its builds.
+#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NewerBuildPolicy {
+    /// Fail the activation.
+    Error,
+    /// Leave the shard and its recovery log as they are,
+    /// while converging the remainder of the task.
+    SkipSpec,
+    /// Converge the shard to the template regardless,
+    /// which is the behavior of activations which don't choose a policy.
+    #[default]
+    Force,
+}
+
+/// NewerBuild reports a current shard which carries a newer build than its template.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewerBuild {
+    /// ID of the shard.
+    pub shard: String,
+    /// Build of the current shard.
+    pub current_build: models::Id,
+    /// Build of the activated template.
+    pub template_build: models::Id,
+    /// Policy which was applied to the shard.
+    pub policy: NewerBuildPolicy,
+}
+
+/// NewerBuilds configures the policy applied to current shards which carry a
+/// newer build than their template, and an optional sink which is invoked with
+/// a NewerBuild report of each such shard.
+#[derive(Default, Copy, Clone)]
+pub struct NewerBuilds<'a> {
+    pub policy: NewerBuildPolicy,
+    pub sink: Option<&'a (dyn Fn(NewerBuild) + Send + Sync)>,
+}
+
+/// Activated describes the data-plane state of an activated task.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Activated {
+    /// Earliest expiry of the transaction duration overrides of the task's
+    /// shards. Overrides are removed only as the task is activated, so the
+    /// task must be activated again at this time to remove them.
+    pub txn_overrides_expire: Option<std::time::SystemTime>,
+    /// Fully-suspended partitions of the collection which were left suspended.
+    pub suspended_partitions: SuspendedPartitions,
+}
+
+/// SuspendedPartitions are fully-suspended partitions which an activation
+/// left suspended, rather than resuming them.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SuspendedPartitions {
+    /// Partitions whose upserts were deferred, as they wouldn't change their spec.
+    pub deferred: Vec<String>,
+    /// Partitions which were updated, and remain suspended.
+    pub updated: Vec<String>,
+}
+
 /// Activate a capture into a data-plane.
+/// If `preflight`, the data-plane's capacity for the initial splits of a
+/// new task is checked before its shards are created.
 pub async fn activate_capture(
     journal_client: &gazette::journal::Client,
     shard_client: &gazette::shard::Client,
@@ -47,7 +130,12 @@ pub async fn activate_capture(
     ops_logs_template: Option<&broker::JournalSpec>,
     ops_stats_template: Option<&broker::JournalSpec>,
     initial_splits: usize,
-) -> anyhow::Result<()> {
+    preflight: bool,
+    change_journal: Option<&ChangeJournal>,
+    event_sink: Option<EventSink<'_>>,
+    audit: Option<Audit<'_>>,
+    newer_builds: NewerBuilds<'_>,
+) -> anyhow::Result<Activated> {
     let task_template = if let Some(task_spec) = task_spec {
         let shard_template = task_spec
             .shard_template
@@ -67,7 +155,20 @@ pub async fn activate_capture(
         None
     };
 
-    let changes = converge_task_changes(
+    // Complete any prior, interrupted activation before converging anew.
+    if let Some(change_journal) = change_journal {
+        resume_changes(
+            journal_client,
+            shard_client,
+            change_journal,
+            capture,
+            event_sink,
+            audit,
+        )
+        .await?;
+    }
+
+    let (changes, builds, txn_overrides_expire) = converge_task_changes(
         journal_client,
         shard_client,
         ops::TaskType::Capture,
@@ -76,13 +177,30 @@ pub async fn activate_capture(
         ops_logs_template,
         ops_stats_template,
         initial_splits,
+        preflight,
+        newer_builds,
+    )
+    .await?;
+
+    apply_changes(
+        journal_client,
+        shard_client,
+        changes,
+        change_journal.map(|j| (j, capture.as_str())),
+        event_sink.map(|sink| (sink, capture.as_str())),
+        audit.map(|audit| (audit, &builds)),
     )
     .await?;
 
-    apply_changes(journal_client, shard_client, changes).await
+    Ok(Activated {
+        txn_overrides_expire,
+        ..Default::default()
+    })
 }
 
 /// Activate a collection into a data-plane.
+/// If `preflight`, the data-plane's capacity for the initial splits of a
+/// new task is checked before its shards are created.
 pub async fn activate_collection(
     journal_client: &gazette::journal::Client,
     shard_client: &gazette::shard::Client,
@@ -91,7 +209,12 @@ pub async fn activate_collection(
     ops_logs_template: Option<&broker::JournalSpec>,
     ops_stats_template: Option<&broker::JournalSpec>,
     initial_splits: usize,
-) -> anyhow::Result<()> {
+    preflight: bool,
+    change_journal: Option<&ChangeJournal>,
+    event_sink: Option<EventSink<'_>>,
+    audit: Option<Audit<'_>>,
+    newer_builds: NewerBuilds<'_>,
+) -> anyhow::Result<Activated> {
     let (task_template, partition_template) = if let Some(task_spec) = task_spec {
         let partition_template = task_spec
             .partition_template
@@ -122,7 +245,23 @@ pub async fn activate_collection(
         (None, None)
     };
 
-    let (changes_1, changes_2) = futures::try_join!(
+    // Complete any prior, interrupted activation before converging anew.
+    if let Some(change_journal) = change_journal {
+        resume_changes(
+            journal_client,
+            shard_client,
+            change_journal,
+            collection,
+            event_sink,
+            audit,
+        )
+        .await?;
+    }
+
+    let (
+        (changes_1, mut builds, txn_overrides_expire),
+        (changes_2, builds_2, suspended_partitions),
+    ) = futures::try_join!(
         converge_task_changes(
             journal_client,
             shard_client,
@@ -132,19 +271,32 @@ pub async fn activate_collection(
             ops_logs_template,
             ops_stats_template,
             initial_splits,
+            preflight,
+            newer_builds,
         ),
         converge_partition_changes(journal_client, collection, partition_template),
     )?;
+    builds.extend(builds_2);
 
     apply_changes(
         journal_client,
         shard_client,
         changes_1.into_iter().chain(changes_2.into_iter()),
+        change_journal.map(|j| (j, collection.as_str())),
+        event_sink.map(|sink| (sink, collection.as_str())),
+        audit.map(|audit| (audit, &builds)),
     )
-    .await
+    .await?;
+
+    Ok(Activated {
+        txn_overrides_expire,
+        suspended_partitions,
+    })
 }
 
 /// Activate a materialization into a data-plane.
+/// If `preflight`, the data-plane's capacity for the initial splits of a
+/// new task is checked before its shards are created.
 pub async fn activate_materialization(
     journal_client: &gazette::journal::Client,
     shard_client: &gazette::shard::Client,
@@ -153,7 +305,12 @@ pub async fn activate_materialization(
     ops_logs_template: Option<&broker::JournalSpec>,
     ops_stats_template: Option<&broker::JournalSpec>,
     initial_splits: usize,
-) -> anyhow::Result<()> {
+    preflight: bool,
+    change_journal: Option<&ChangeJournal>,
+    event_sink: Option<EventSink<'_>>,
+    audit: Option<Audit<'_>>,
+    newer_builds: NewerBuilds<'_>,
+) -> anyhow::Result<Activated> {
     let task_template = if let Some(task_spec) = task_spec {
         let shard_template = task_spec
             .shard_template
@@ -173,7 +330,20 @@ pub async fn activate_materialization(
         None
     };
 
-    let changes = converge_task_changes(
+    // Complete any prior, interrupted activation before converging anew.
+    if let Some(change_journal) = change_journal {
+        resume_changes(
+            journal_client,
+            shard_client,
+            change_journal,
+            materialization,
+            event_sink,
+            audit,
+        )
+        .await?;
+    }
+
+    let (changes, builds, txn_overrides_expire) = converge_task_changes(
         journal_client,
         shard_client,
         ops::TaskType::Materialization,
@@ -182,17 +352,1044 @@ pub async fn activate_materialization(
         ops_logs_template,
         ops_stats_template,
         initial_splits,
+        preflight,
+        newer_builds,
+    )
+    .await?;
+
+    apply_changes(
+        journal_client,
+        shard_client,
+        changes,
+        change_journal.map(|j| (j, materialization.as_str())),
+        event_sink.map(|sink| (sink, materialization.as_str())),
+        audit.map(|audit| (audit, &builds)),
+    )
+    .await?;
+
+    Ok(Activated {
+        txn_overrides_expire,
+        ..Default::default()
+    })
+}
+
+/// HintBackup is a backed-up set of recovery log hints of a shard.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HintBackup {
+    /// Index of the backup, where zero is the most recent.
+    pub index: usize,
+    /// Etcd key under which the backup is stored.
+    pub key: String,
+    /// Backed-up hints.
+    pub hints: recoverylog::FsmHints,
+}
+
+/// List the available recovery log hint backups of a shard,
+/// ordered from most to least recent. Missing backups are omitted.
+///
+/// This crate doesn't restore hint backups: the Gazette consumer API
+/// has no RPC for writing hints, and there's no Etcd client here with
+/// which to overwrite a shard's primary hints.
+pub async fn list_hint_backups(
+    shard_client: &gazette::shard::Client,
+    shard_id: &str,
+) -> anyhow::Result<Vec<HintBackup>> {
+    let spec = fetch_shard_spec(shard_client, shard_id).await?;
+    let resp = shard_client
+        .get_hints(consumer::GetHintsRequest {
+            shard: shard_id.to_string(),
+            ..Default::default()
+        })
+        .await
+        .with_context(|| format!("failed to fetch hints of shard {shard_id}"))?;
+
+    Ok(unpack_hint_backups(&spec, resp))
+}
+
+/// Set (if Some) or unset (if None) user-patch labels of a single shard,
+/// where each patch is named by the label it patches (such as
+/// `estuary.dev/log-level`). Patches take effect immediately, and are preserved
+/// and re-applied over the task's templated labels by its future activations.
+/// A label which is unset reverts to its templated value upon the next activation.
+///
+/// The shard is updated at its listed revision, and patching fails if the shard
+/// was concurrently modified.
+pub async fn patch_shard_labels(
+    shard_client: &gazette::shard::Client,
+    shard_id: &str,
+    patches: &[(&str, Option<&str>)],
+) -> anyhow::Result<()> {
+    let (spec, mod_revision) = fetch_shard(shard_client, shard_id).await?;
+    let change = patch_shard_change(spec, mod_revision, patches)?;
+
+    shard_client
+        .apply(consumer::ApplyRequest {
+            changes: vec![change],
+            ..Default::default()
+        })
+        .await
+        .with_context(|| format!("failed to patch labels of shard {shard_id}"))?;
+
+    Ok(())
+}
+
+/// Determine the change which applies user-label `patches` to a shard.
+fn patch_shard_change(
+    mut spec: ShardSpec,
+    mod_revision: i64,
+    patches: &[(&str, Option<&str>)],
+) -> anyhow::Result<consumer::apply_request::Change> {
+    let mut set = spec.labels.take().unwrap_or_default();
+
+    for (target, value) in patches {
+        let name = format!("{}{target}", labels::USER_PATCH_PREFIX);
+
+        if labels::user_patch_target(&name).is_none() {
+            anyhow::bail!("label {target:?} of shard {} may not be patched", spec.id);
+        }
+        set = match value {
+            Some(value) => labels::set_value(set, &name, value),
+            None => labels::remove(set, &name),
+        };
+    }
+    spec.labels = Some(apply_user_patches(set));
+
+    Ok(consumer::apply_request::Change {
+        expect_mod_revision: mod_revision,
+        upsert: Some(spec),
+        delete: String::new(),
+    })
+}
+
+/// Apply the user-patch labels of a shard's LabelSet, replacing each patched
+/// label with the value of its patch. Patches of labels which may not be
+/// patched are retained, but have no effect.
+fn apply_user_patches(mut set: LabelSet) -> LabelSet {
+    let patches: Vec<(String, String)> = set
+        .labels
+        .iter()
+        .filter_map(|label| {
+            labels::user_patch_target(&label.name)
+                .map(|target| (target.to_string(), label.value.clone()))
+        })
+        .collect();
+
+    for (target, value) in patches {
+        set = labels::set_value(set, &target, &value);
+    }
+    set
+}
+
+async fn fetch_shard_spec(
+    shard_client: &gazette::shard::Client,
+    shard_id: &str,
+) -> anyhow::Result<ShardSpec> {
+    Ok(fetch_shard(shard_client, shard_id).await?.0)
+}
+
+/// Fetch the ShardSpec of `shard_id` and its current modification revision.
+async fn fetch_shard(
+    shard_client: &gazette::shard::Client,
+    shard_id: &str,
+) -> anyhow::Result<(ShardSpec, i64)> {
+    let resp = shard_client
+        .list(consumer::ListRequest {
+            selector: Some(LabelSelector {
+                include: Some(labels::build_set([("id", shard_id)])),
+                exclude: None,
+            }),
+            ..Default::default()
+        })
+        .await
+        .with_context(|| format!("failed to list shard {shard_id}"))?;
+
+    resp.shards
+        .into_iter()
+        .filter_map(|shard| shard.spec.map(|spec| (spec, shard.mod_revision)))
+        .next()
+        .with_context(|| format!("shard {shard_id} does not exist"))
+}
+
+/// Unpack a GetHintsResponse into its primary hints and indexed backups.
+fn unpack_hint_backups(spec: &ShardSpec, resp: consumer::GetHintsResponse) -> Vec<HintBackup> {
+    resp.backup_hints
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, h)| {
+            h.hints.map(|hints| HintBackup {
+                index,
+                key: format!("{}/{}.backup.{index}", spec.hint_prefix, spec.id),
+                hints,
+            })
+        })
+        .collect()
+}
+
+/// OrphanedSpec is a journal or shard of a stale generation of its collection
+/// or task, which has been superseded by a more-recent generation.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct OrphanedSpec {
+    /// Journal name or shard ID of the orphan.
+    pub name: String,
+    /// Whether the orphan is a "journal" or "shard".
+    pub kind: &'static str,
+    /// Catalog name of the collection or task which owns the orphan.
+    pub catalog_name: String,
+    /// Generation ID of the orphan.
+    pub generation_id: models::Id,
+    /// Most-recent generation ID of the owning collection or task.
+    pub live_generation_id: models::Id,
+}
+
+/// Garbage-collect journals and shards managed by Flow, which belong to
+/// collections or tasks under `catalog_prefix` and are of a generation that
+/// has been superseded by a more-recent generation of their owner.
+///
+/// Orphans are otherwise removed only when their owner is next activated.
+/// If `dry_run`, orphans are returned but not deleted.
+pub async fn prune_orphaned(
+    journal_client: &gazette::journal::Client,
+    shard_client: &gazette::shard::Client,
+    catalog_prefix: &str,
+    dry_run: bool,
+    audit: Option<Audit<'_>>,
+) -> anyhow::Result<Vec<OrphanedSpec>> {
+    let selector = LabelSelector {
+        include: Some(labels::build_set([(
+            labels::MANAGED_BY,
+            labels::MANAGED_BY_FLOW,
+        )])),
+        exclude: None,
+    };
+    let list_journals = broker::ListRequest {
+        selector: Some(selector.clone()),
+        ..Default::default()
+    };
+    let list_shards = consumer::ListRequest {
+        selector: Some(selector),
+        ..Default::default()
+    };
+
+    let (journals, shards) = futures::join!(
+        journal_client.list(list_journals),
+        shard_client.list(list_shards),
+    );
+    let journals = unpack_journal_listing(journals.context("listing managed journals")?)?;
+    let shards = unpack_shard_listing(shards.context("listing managed shards")?)?;
+    let builds = split_builds(&shards, &journals);
+
+    let (orphans, changes) = orphaned_changes(catalog_prefix, journals, shards)?;
+
+    if !dry_run {
+        apply_changes(
+            journal_client,
+            shard_client,
+            changes,
+            None,
+            None,
+            audit.map(|audit| (audit, &builds)),
+        )
+        .await?;
+    }
+    Ok(orphans)
+}
+
+/// Determine the orphaned journals and shards of stale generations,
+/// and the changes which delete them.
+fn orphaned_changes(
+    catalog_prefix: &str,
+    journals: Vec<JournalSplit>,
+    shards: Vec<ShardSplit>,
+) -> anyhow::Result<(Vec<OrphanedSpec>, Vec<Change>)> {
+    // Candidates are keyed on their owner's (type, catalog name).
+    let mut candidates: Vec<((String, String), models::Id, Change)> = Vec::new();
+
+    for JournalSplit {
+        name,
+        labels: set,
+        mod_revision,
+        ..
+    } in journals
+    {
+        let content_type = labels::maybe_one(&set, labels::CONTENT_TYPE)?;
+        let collection = labels::maybe_one(&set, labels::COLLECTION)?;
+
+        let (owner, generation_id) = if content_type == labels::CONTENT_TYPE_RECOVERY_LOG {
+            // Recovery logs are named as "{recovery_log_prefix}/{shard_id}".
+            let owner = (
+                labels::maybe_one(&set, labels::TASK_TYPE)?.to_string(),
+                labels::maybe_one(&set, labels::TASK_NAME)?.to_string(),
+            );
+            let generation_id = name
+                .rsplit_once('/')
+                .and_then(|(prefix, _suffix)| assemble::extract_generation_id_suffix(prefix));
+
+            (owner, generation_id)
+        } else if !collection.is_empty() {
+            // Partitions are named as "{collection}/{generation_id}/{partition suffix}".
+            let generation_id = name
+                .strip_prefix(collection)
+                .and_then(|rest| rest.strip_prefix('/'))
+                .and_then(|rest| rest.split('/').next())
+                .and_then(|gen| {
+                    assemble::extract_generation_id_suffix(&format!("{collection}/{gen}"))
+                });
+
+            (
+                ("collection".to_string(), collection.to_string()),
+                generation_id,
+            )
+        } else {
+            continue;
+        };
+
+        let Some(generation_id) = generation_id else {
+            continue; // Not a generation-suffixed journal (for example, an ops journal).
+        };
+
+        candidates.push((
+            owner,
+            generation_id,
+            Change::Journal(broker::apply_request::Change {
+                expect_mod_revision: mod_revision,
+                upsert: None,
+                delete: name,
+            }),
+        ));
+    }
+
+    for ShardSplit {
+        id,
+        labels: set,
+        mod_revision,
+    } in shards
+    {
+        let owner = (
+            labels::maybe_one(&set, labels::TASK_TYPE)?.to_string(),
+            labels::maybe_one(&set, labels::TASK_NAME)?.to_string(),
+        );
+        // Shards are identified as "{shard_id_prefix}/{split suffix}".
+        let Some(generation_id) = id
+            .rsplit_once('/')
+            .and_then(|(prefix, _suffix)| assemble::extract_generation_id_suffix(prefix))
+        else {
+            continue;
+        };
+
+        candidates.push((
+            owner,
+            generation_id,
+            Change::Shard(consumer::apply_request::Change {
+                expect_mod_revision: mod_revision,
+                upsert: None,
+                delete: id,
+            }),
+        ));
+    }
+
+    candidates.retain(|((_, catalog_name), _, _)| {
+        !catalog_name.is_empty() && catalog_name.starts_with(catalog_prefix)
+    });
+
+    // The live generation of each owner is its most-recent one.
+    let mut live: BTreeMap<(String, String), models::Id> = BTreeMap::new();
+    for (owner, generation_id, _change) in &candidates {
+        let entry = live.entry(owner.clone()).or_insert(*generation_id);
+        *entry = (*entry).max(*generation_id);
+    }
+
+    let mut orphans = Vec::new();
+    let mut changes = Vec::new();
+
+    for (owner, generation_id, change) in candidates {
+        let live_generation_id = live[&owner];
+        if generation_id == live_generation_id {
+            continue;
+        }
+        let (kind, name) = match &change {
+            Change::Journal(change) => ("journal", change.delete.clone()),
+            Change::Shard(change) => ("shard", change.delete.clone()),
+        };
+        orphans.push(OrphanedSpec {
+            name,
+            kind,
+            catalog_name: owner.1,
+            generation_id,
+            live_generation_id,
+        });
+        changes.push(change);
+    }
+
+    Ok((orphans, changes))
+}
+
+/// SuspendStatus is the outcome of suspending or resuming a journal.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct SuspendStatus {
+    /// Name of the journal.
+    pub journal: String,
+    /// Whether the journal is suspended.
+    pub suspended: bool,
+    /// Error encountered while suspending or resuming the journal, if any.
+    pub error: Option<String>,
+}
+
+/// Suspend all partitions of a collection, so that they release their broker
+/// resources until next written. If `now`, partitions are suspended even if
+/// they have content not yet persisted to their fragment store. Otherwise,
+/// such partitions are left as-is. Suspensions are preserved by subsequent
+/// activations of the collection, and a suspended partition is resumed by
+/// its next append. Returns the status of each partition.
+pub async fn suspend_collection(
+    journal_client: &gazette::journal::Client,
+    collection: &models::Collection,
+    now: bool,
+) -> anyhow::Result<Vec<SuspendStatus>> {
+    let journals = list_partitions(journal_client, collection).await?;
+    Ok(suspend_journals(journal_client, journals, suspend_mode(now)).await)
+}
+
+/// Resume all partitions of a collection. Returns the status of each partition.
+pub async fn resume_collection(
+    journal_client: &gazette::journal::Client,
+    collection: &models::Collection,
+) -> anyhow::Result<Vec<SuspendStatus>> {
+    let journals = list_partitions(journal_client, collection).await?;
+    Ok(suspend_journals(
+        journal_client,
+        journals,
+        broker::append_request::Suspend::Resume,
+    )
+    .await)
+}
+
+/// Suspend all recovery logs of a task, as with `suspend_collection`.
+/// The task's shards should be disabled, or else recovery logs will be
+/// resumed by their next write.
+pub async fn suspend_task(
+    journal_client: &gazette::journal::Client,
+    task_type: ops::TaskType,
+    task_name: &str,
+    now: bool,
+) -> anyhow::Result<Vec<SuspendStatus>> {
+    let journals = list_recovery_logs(journal_client, task_type, task_name).await?;
+    Ok(suspend_journals(journal_client, journals, suspend_mode(now)).await)
+}
+
+/// Resume all recovery logs of a task. Returns the status of each recovery log.
+pub async fn resume_task(
+    journal_client: &gazette::journal::Client,
+    task_type: ops::TaskType,
+    task_name: &str,
+) -> anyhow::Result<Vec<SuspendStatus>> {
+    let journals = list_recovery_logs(journal_client, task_type, task_name).await?;
+    Ok(suspend_journals(
+        journal_client,
+        journals,
+        broker::append_request::Suspend::Resume,
+    )
+    .await)
+}
+
+fn suspend_mode(now: bool) -> broker::append_request::Suspend {
+    if now {
+        broker::append_request::Suspend::Now
+    } else {
+        broker::append_request::Suspend::IfFlushed
+    }
+}
+
+async fn list_partitions(
+    journal_client: &gazette::journal::Client,
+    collection: &models::Collection,
+) -> anyhow::Result<Vec<String>> {
+    let listing = journal_client
+        .list(list_partitions_request(collection))
+        .await
+        .with_context(|| format!("listing partitions of {collection}"))?;
+
+    Ok(unpack_journal_listing(listing)?
+        .into_iter()
+        .map(|split| split.name)
+        .collect())
+}
+
+async fn list_recovery_logs(
+    journal_client: &gazette::journal::Client,
+    task_type: ops::TaskType,
+    task_name: &str,
+) -> anyhow::Result<Vec<String>> {
+    let (_, list_recovery) = list_task_request(task_type, task_name);
+
+    let listing = journal_client
+        .list(list_recovery)
+        .await
+        .with_context(|| format!("listing recovery logs of {task_name}"))?;
+
+    Ok(unpack_journal_listing(listing)?
+        .into_iter()
+        .map(|split| split.name)
+        .collect())
+}
+
+/// Suspend or resume `journals` concurrently, through zero-length appends.
+async fn suspend_journals(
+    journal_client: &gazette::journal::Client,
+    journals: Vec<String>,
+    mode: broker::append_request::Suspend,
+) -> Vec<SuspendStatus> {
+    use futures::StreamExt;
+
+    // Number of attempts of each journal before giving up on a transient error.
+    const MAX_ATTEMPTS: usize = 5;
+
+    let futures = journals.into_iter().map(|journal| async move {
+        let request = broker::AppendRequest {
+            journal: journal.clone(),
+            suspend: mode as i32,
+            ..Default::default()
+        };
+        let stream = journal_client.append(request, || futures::stream::empty());
+        tokio::pin!(stream);
+
+        let result = loop {
+            match stream.next().await {
+                Some(Err(gazette::RetryError { attempt, inner }))
+                    if inner.is_transient() && attempt + 1 < MAX_ATTEMPTS =>
+                {
+                    tracing::warn!(%journal, attempt, error = ?inner, "retrying journal suspension");
+                }
+                Some(Ok(_)) | None => break Ok(()),
+                Some(Err(gazette::RetryError { inner, .. })) => break Err(inner),
+            }
+        };
+        suspend_status(journal, mode, result)
+    });
+
+    futures::future::join_all(futures).await
+}
+
+/// Map the result of a suspension append into its SuspendStatus.
+fn suspend_status(
+    journal: String,
+    mode: broker::append_request::Suspend,
+    result: Result<(), gazette::Error>,
+) -> SuspendStatus {
+    use broker::append_request::Suspend;
+
+    let (suspended, error) = match (mode, result) {
+        // A suspend which had no effect, because the journal has unflushed content.
+        (Suspend::IfFlushed, Ok(())) => (false, None),
+        // Brokers respond SUSPENDED to a suspension which took effect.
+        (
+            Suspend::IfFlushed | Suspend::Now,
+            Err(gazette::Error::BrokerStatus(broker::Status::Suspended)),
+        ) => (true, None),
+        (Suspend::Now, Ok(())) => (true, None),
+        (Suspend::Resume | Suspend::NoResume, Ok(())) => (false, None),
+        (_, Err(err)) => (false, Some(err.to_string())),
+    };
+
+    SuspendStatus {
+        journal,
+        suspended,
+        error,
+    }
+}
+
+/// Readiness of a task's shards following an activation.
+#[derive(Debug, Copy, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Readiness {
+    /// The task has shards, and all enabled shards have a PRIMARY replica.
+    Ready,
+    /// At least one replica of an enabled shard has FAILED.
+    Failed,
+    /// Shards are still being created, assigned, or recovered.
+    Pending,
+}
+
+/// ShardReadiness is the readiness of a single task shard.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ShardReadiness {
+    /// ID of the shard.
+    pub id: String,
+    /// Whether the shard is disabled, and is not expected to become PRIMARY.
+    pub disabled: bool,
+    /// Status code of each assigned replica, in the order of the
+    /// members of the shard's route.
+    pub status: Vec<String>,
+    /// Errors reported by FAILED replicas of the shard.
+    pub errors: Vec<String>,
+}
+
+/// ReadinessReport is the outcome of awaiting the readiness of a task.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ReadinessReport {
+    /// Overall readiness of the task.
+    pub readiness: Readiness,
+    /// Readiness of each task shard.
+    pub shards: Vec<ShardReadiness>,
+}
+
+/// Await the readiness of a task following its activation, by polling
+/// the status of its shards until each enabled shard has a PRIMARY replica,
+/// or any replica has FAILED, or `timeout` elapses. A report with `Pending`
+/// readiness is returned if `timeout` elapses, and an error is returned
+/// only if shards could not be listed.
+pub async fn await_ready(
+    shard_client: &gazette::shard::Client,
+    task_type: ops::TaskType,
+    task_name: &str,
+    timeout: std::time::Duration,
+) -> anyhow::Result<ReadinessReport> {
+    // Interval at which shard status is polled.
+    const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+    let deadline = tokio::time::Instant::now() + timeout;
+    let (list_shards, _) = list_task_request(task_type, task_name);
+
+    loop {
+        let listing = shard_client
+            .list(list_shards.clone())
+            .await
+            .with_context(|| format!("listing shards of {task_name}"))?;
+        let report = readiness_report(listing)?;
+
+        if report.readiness != Readiness::Pending
+            || tokio::time::Instant::now() + POLL_INTERVAL > deadline
+        {
+            return Ok(report);
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Map a shard ListResponse into its ReadinessReport.
+fn readiness_report(resp: consumer::ListResponse) -> anyhow::Result<ReadinessReport> {
+    use consumer::replica_status::Code;
+
+    let mut shards = Vec::with_capacity(resp.shards.len());
+    // A task without any listed shards hasn't yet been created.
+    let (mut ready, mut failed) = (!resp.shards.is_empty(), false);
+
+    for resp in resp.shards {
+        let Some(spec) = resp.spec else {
+            anyhow::bail!("listing response is missing spec");
+        };
+        let codes: Vec<Code> = resp
+            .status
+            .iter()
+            .map(|status| Code::try_from(status.code).unwrap_or_default())
+            .collect();
+
+        // Statuses are ordered by route members, and the primary may be any of them.
+        if !spec.disable {
+            ready &= codes.contains(&Code::Primary);
+            failed |= codes.contains(&Code::Failed);
+        }
+
+        shards.push(ShardReadiness {
+            id: spec.id,
+            disabled: spec.disable,
+            status: codes
+                .iter()
+                .map(|code| code.as_str_name().to_string())
+                .collect(),
+            errors: resp
+                .status
+                .into_iter()
+                .flat_map(|status| status.errors)
+                .collect(),
+        });
+    }
+
+    let readiness = if failed {
+        Readiness::Failed
+    } else if ready {
+        Readiness::Ready
+    } else {
+        Readiness::Pending
+    };
+    Ok(ReadinessReport { readiness, shards })
+}
+
+/// Tombstone a task as the first phase of a two-phase deletion.
+///
+/// The task's shards are disabled, and its shards and recovery logs are
+/// labeled with a tombstone of the time after which they may be purged.
+/// During the grace period the task may be restored with `undelete_task`,
+/// or by re-activating it from its specification.
+/// Returns the time after which the task may be purged.
+pub async fn tombstone_task(
+    journal_client: &gazette::journal::Client,
+    shard_client: &gazette::shard::Client,
+    task_type: ops::TaskType,
+    task_name: &str,
+    grace_period: std::time::Duration,
+    audit: Option<Audit<'_>>,
+) -> anyhow::Result<std::time::SystemTime> {
+    let delete_after = std::time::SystemTime::now() + grace_period;
+    let delete_after_label = humantime::format_rfc3339_seconds(delete_after).to_string();
+
+    let (shards, recovery) =
+        list_task_specs(journal_client, shard_client, task_type, task_name).await?;
+    let builds = spec_builds(&shards, &recovery);
+    let changes = tombstone_changes(shards, recovery, Some(&delete_after_label));
+
+    apply_changes(
+        journal_client,
+        shard_client,
+        changes,
+        None,
+        None,
+        audit.map(|audit| (audit, &builds)),
+    )
+    .await?;
+    Ok(delete_after)
+}
+
+/// Remove the tombstone of a task during its grace period, so that it will
+/// not be purged. Its shards remain disabled until the task is re-activated
+/// from its specification.
+pub async fn undelete_task(
+    journal_client: &gazette::journal::Client,
+    shard_client: &gazette::shard::Client,
+    task_type: ops::TaskType,
+    task_name: &str,
+    audit: Option<Audit<'_>>,
+) -> anyhow::Result<()> {
+    let (shards, recovery) =
+        list_task_specs(journal_client, shard_client, task_type, task_name).await?;
+    let builds = spec_builds(&shards, &recovery);
+    let changes = tombstone_changes(shards, recovery, None);
+
+    apply_changes(
+        journal_client,
+        shard_client,
+        changes,
+        None,
+        None,
+        audit.map(|audit| (audit, &builds)),
+    )
+    .await
+}
+
+/// Complete the two-phase deletion of a tombstoned task, by deleting its shards
+/// and recovery logs. Deletion happens only if all of the task's specs are
+/// tombstoned and their grace period has elapsed as of `now`, or if `force`.
+/// Returns whether the task was deleted.
+pub async fn purge_tombstoned_task(
+    journal_client: &gazette::journal::Client,
+    shard_client: &gazette::shard::Client,
+    task_type: ops::TaskType,
+    task_name: &str,
+    now: std::time::SystemTime,
+    force: bool,
+    audit: Option<Audit<'_>>,
+) -> anyhow::Result<bool> {
+    let (shards, recovery) =
+        list_task_specs(journal_client, shard_client, task_type, task_name).await?;
+    let builds = spec_builds(&shards, &recovery);
+
+    let Some(changes) = purge_changes(shards, recovery, now, force)? else {
+        return Ok(false);
+    };
+    apply_changes(
+        journal_client,
+        shard_client,
+        changes,
+        None,
+        None,
+        audit.map(|audit| (audit, &builds)),
     )
     .await?;
 
-    apply_changes(journal_client, shard_client, changes).await
+    Ok(true)
+}
+
+/// List the ShardSpecs and recovery log JournalSpecs of a task,
+/// with their current modification revisions.
+async fn list_task_specs(
+    journal_client: &gazette::journal::Client,
+    shard_client: &gazette::shard::Client,
+    task_type: ops::TaskType,
+    task_name: &str,
+) -> anyhow::Result<(Vec<(ShardSpec, i64)>, Vec<(JournalSpec, i64)>)> {
+    let (list_shards, list_recovery) = list_task_request(task_type, task_name);
+    let (shards, recovery) = futures::join!(
+        shard_client.list(list_shards),
+        journal_client.list(list_recovery),
+    );
+
+    let shards = shards
+        .with_context(|| format!("listing shards of {task_name}"))?
+        .shards
+        .into_iter()
+        .map(|resp| {
+            resp.spec
+                .map(|spec| (spec, resp.mod_revision))
+                .context("listing response is missing spec")
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    let recovery = recovery
+        .with_context(|| format!("listing recovery logs of {task_name}"))?
+        .journals
+        .into_iter()
+        .map(|resp| {
+            resp.spec
+                .map(|spec| (spec, resp.mod_revision))
+                .context("listing response is missing spec")
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok((shards, recovery))
+}
+
+// Current builds of listed ShardSpecs and JournalSpecs.
+fn spec_builds(shards: &[(ShardSpec, i64)], journals: &[(JournalSpec, i64)]) -> CurrentBuilds {
+    audit::current_builds(
+        shards
+            .iter()
+            .map(|(spec, _)| (spec.id.as_str(), spec.labels.as_ref()))
+            .chain(
+                journals
+                    .iter()
+                    .map(|(spec, _)| (spec.name.as_str(), spec.labels.as_ref())),
+            ),
+    )
+}
+
+// Current builds of listed shard and journal splits.
+fn split_builds(shards: &[ShardSplit], journals: &[JournalSplit]) -> CurrentBuilds {
+    audit::current_builds(
+        shards
+            .iter()
+            .map(|split| (split.id.as_str(), Some(&split.labels)))
+            .chain(
+                journals
+                    .iter()
+                    .map(|split| (split.name.as_str(), Some(&split.labels))),
+            ),
+    )
+}
+
+/// Determine the changes which add (if `delete_after` is Some) or remove
+/// (if None) the tombstone of a task's shards and recovery logs.
+/// Shards are disabled when tombstoned.
+fn tombstone_changes(
+    shards: Vec<(ShardSpec, i64)>,
+    recovery: Vec<(JournalSpec, i64)>,
+    delete_after: Option<&str>,
+) -> Vec<Change> {
+    let update = |set: Option<LabelSet>| {
+        let set = set.unwrap_or_default();
+        Some(match delete_after {
+            Some(delete_after) => labels::set_value(set, labels::DELETE_AFTER, delete_after),
+            None => labels::remove(set, labels::DELETE_AFTER),
+        })
+    };
+    let mut changes = Vec::new();
+
+    for (mut spec, mod_revision) in shards {
+        spec.labels = update(spec.labels.take());
+        if delete_after.is_some() {
+            spec.disable = true;
+        }
+        changes.push(Change::Shard(consumer::apply_request::Change {
+            expect_mod_revision: mod_revision,
+            upsert: Some(spec),
+            delete: String::new(),
+        }));
+    }
+    for (mut spec, mod_revision) in recovery {
+        spec.labels = update(spec.labels.take());
+
+        changes.push(Change::Journal(broker::apply_request::Change {
+            expect_mod_revision: mod_revision,
+            upsert: Some(spec),
+            delete: String::new(),
+        }));
+    }
+
+    changes
+}
+
+/// Determine the deletions which purge a tombstoned task,
+/// or None if the task may not yet be purged.
+fn purge_changes(
+    shards: Vec<(ShardSpec, i64)>,
+    recovery: Vec<(JournalSpec, i64)>,
+    now: std::time::SystemTime,
+    force: bool,
+) -> anyhow::Result<Option<Vec<Change>>> {
+    let expired = |set: &Option<LabelSet>| -> anyhow::Result<bool> {
+        let Some(set) = set else {
+            return Ok(false);
+        };
+        let delete_after = labels::maybe_one(set, labels::DELETE_AFTER)?;
+        if delete_after.is_empty() {
+            return Ok(false); // Not tombstoned.
+        }
+        let delete_after = humantime::parse_rfc3339(delete_after)
+            .with_context(|| format!("invalid {} label {delete_after:?}", labels::DELETE_AFTER))?;
+
+        Ok(now >= delete_after)
+    };
+    let mut changes = Vec::new();
+
+    for (spec, mod_revision) in shards {
+        if !force && !expired(&spec.labels)? {
+            return Ok(None);
+        }
+        changes.push(Change::Shard(consumer::apply_request::Change {
+            expect_mod_revision: mod_revision,
+            upsert: None,
+            delete: spec.id,
+        }));
+    }
+    for (spec, mod_revision) in recovery {
+        if !force && !expired(&spec.labels)? {
+            return Ok(None);
+        }
+        changes.push(Change::Journal(broker::apply_request::Change {
+            expect_mod_revision: mod_revision,
+            upsert: None,
+            delete: spec.name,
+        }));
+    }
+
+    Ok(Some(changes))
 }
 
 async fn apply_changes(
     journal_client: &gazette::journal::Client,
     shard_client: &gazette::shard::Client,
     changes: impl IntoIterator<Item = Change>,
+    change_journal: Option<(&ChangeJournal, &str)>,
+    event_sink: Option<(EventSink<'_>, &str)>,
+    audit: Option<(Audit<'_>, &CurrentBuilds)>,
 ) -> anyhow::Result<()> {
+    let windows = plan_windows(changes);
+
+    let emit = |window: &Window, revision: i64| {
+        if let Some((sink, task)) = event_sink {
+            events::window_events(task, window, revision)
+                .into_iter()
+                .for_each(sink);
+        }
+        if let Some((audit, builds)) = audit {
+            let now = std::time::SystemTime::now();
+            audit::window_records(audit.actor, window, revision, builds, now)
+                .into_iter()
+                .for_each(audit.sink);
+        }
+    };
+
+    let Some((change_journal, name)) = change_journal else {
+        for window in &windows {
+            let revision = apply_window(journal_client, shard_client, window).await?;
+            emit(window, revision);
+        }
+        return Ok(());
+    };
+
+    // Durably record the planned windows, and the builds they replace,
+    // before applying any of them.
+    let builds = audit.map(|(_, builds)| builds.clone()).unwrap_or_default();
+    let mut record = change_journal.begin(name, windows, builds)?;
+
+    for index in 0..record.windows.len() {
+        let revision = apply_window(journal_client, shard_client, &record.windows[index]).await?;
+        record.applied[index] = Some(revision);
+        change_journal.store(&record)?;
+        emit(&record.windows[index], revision);
+    }
+    change_journal.remove(name)
+}
+
+/// Resume a partially-applied activation of `name` which was recorded in
+/// `change_journal`. Windows which were already applied are verified against
+/// the data-plane rather than being re-applied, and remaining windows are
+/// applied in order, and are emitted to `event_sink` and `audit` if present.
+/// Audited changes are attributed the old builds recorded with the activation.
+///
+/// The data-plane may have since diverged from the record, as when its specs
+/// were modified by another activation. If an applied window no longer verifies,
+/// or a remaining window fails due to a stale expected revision, then the record
+/// is discarded rather than being retried: the caller's subsequent convergence
+/// plans fresh changes from the current data-plane state.
+/// Returns whether there was an activation which was resumed to completion.
+pub async fn resume_changes(
+    journal_client: &gazette::journal::Client,
+    shard_client: &gazette::shard::Client,
+    change_journal: &ChangeJournal,
+    name: &str,
+    event_sink: Option<EventSink<'_>>,
+    audit: Option<Audit<'_>>,
+) -> anyhow::Result<bool> {
+    let Some(mut record) = change_journal.load(name)? else {
+        return Ok(false);
+    };
+
+    for index in 0..record.windows.len() {
+        if let Some(revision) = record.applied[index] {
+            let verified = verify_window(
+                journal_client,
+                shard_client,
+                &record.windows[index],
+                revision,
+            )
+            .await
+            .with_context(|| format!("verifying applied window {index} of {name}"))?;
+
+            if let Err(divergence) = verified {
+                tracing::warn!(name, index, %divergence, "discarding diverged activation");
+                change_journal.remove(name)?;
+                return Ok(false);
+            }
+            continue;
+        }
+        let revision =
+            match apply_window(journal_client, shard_client, &record.windows[index]).await {
+                Ok(revision) => revision,
+                Err(err) if is_stale_revision(&err) => {
+                    tracing::warn!(name, index, error = ?err, "discarding stale activation");
+                    change_journal.remove(name)?;
+                    return Ok(false);
+                }
+                Err(err) => return Err(err),
+            };
+        record.applied[index] = Some(revision);
+        change_journal.store(&record)?;
+
+        if let Some(sink) = event_sink {
+            events::window_events(name, &record.windows[index], revision)
+                .into_iter()
+                .for_each(sink);
+        }
+        if let Some(audit) = audit {
+            let now = std::time::SystemTime::now();
+            audit::window_records(
+                audit.actor,
+                &record.windows[index],
+                revision,
+                &record.builds,
+                now,
+            )
+            .into_iter()
+            .for_each(audit.sink);
+        }
+    }
+    tracing::info!(name, windows = record.windows.len(), "resumed activation");
+
+    change_journal.remove(name)?;
+    Ok(true)
+}
+
+/// Plan ordered windows of changes, each of which is applied as a single request.
+fn plan_windows(changes: impl IntoIterator<Item = Change>) -> Vec<Window> {
     let mut journal_deletes = Vec::new();
     let mut journal_upserts = Vec::new();
     let mut shard_deletes = Vec::new();
@@ -219,71 +1416,220 @@ async fn apply_changes(
 
     const WINDOW: usize = 120;
 
+    fn split<T>(v: &mut Vec<T>) -> Vec<T> {
+        let bound = WINDOW.max(v.len()) - WINDOW;
+        v.split_off(bound)
+    }
+    let mut windows = Vec::new();
+
     // We must create journals before we create the shards that use them.
     while !journal_upserts.is_empty() {
-        let bound = WINDOW.max(journal_upserts.len()) - WINDOW;
-
-        journal_client
-            .apply(broker::ApplyRequest {
-                changes: journal_upserts.split_off(bound),
-            })
-            .await
-            .context("activating JournalSpec upserts")?;
+        windows.push(Window::JournalUpserts(split(&mut journal_upserts)));
     }
-    std::mem::drop(journal_upserts);
-
     while !shard_upserts.is_empty() {
-        let bound = WINDOW.max(shard_upserts.len()) - WINDOW;
-
-        shard_client
-            .apply(consumer::ApplyRequest {
-                changes: shard_upserts.split_off(bound),
-                ..Default::default()
-            })
-            .await
-            .context("activating ShardSpec upserts")?;
+        windows.push(Window::ShardUpserts(split(&mut shard_upserts)));
     }
-    std::mem::drop(shard_upserts);
-
     while !shard_deletes.is_empty() {
-        let bound = WINDOW.max(shard_deletes.len()) - WINDOW;
-
-        shard_client
-            .apply(consumer::ApplyRequest {
-                changes: shard_deletes.split_off(bound),
-                ..Default::default()
-            })
-            .await
-            .context("activating ShardSpec deletions")?;
+        windows.push(Window::ShardDeletes(split(&mut shard_deletes)));
     }
-    std::mem::drop(shard_deletes);
-
     while !journal_deletes.is_empty() {
-        let bound = WINDOW.max(journal_deletes.len()) - WINDOW;
-
-        journal_client
-            .apply(broker::ApplyRequest {
-                changes: journal_deletes.split_off(bound),
-            })
-            .await
-            .context("activating JournalSpec deletions")?;
+        windows.push(Window::JournalDeletes(split(&mut journal_deletes)));
     }
-    std::mem::drop(journal_deletes);
-
     while !unassign_ids.is_empty() {
-        let bound = WINDOW.max(unassign_ids.len()) - WINDOW;
-
-        shard_client
-            .unassign(consumer::UnassignRequest {
-                shards: unassign_ids.split_off(bound),
-                only_failed: true,
-                dry_run: false,
-            })
-            .await
-            .context("unassigning activated, previously failed shards")?;
+        windows.push(Window::Unassign(split(&mut unassign_ids)));
     }
-    std::mem::drop(unassign_ids);
 
+    windows
+}
+
+/// Apply a window of changes, returning the Etcd revision at which it applied.
+async fn apply_window(
+    journal_client: &gazette::journal::Client,
+    shard_client: &gazette::shard::Client,
+    window: &Window,
+) -> anyhow::Result<i64> {
+    let header = match window {
+        Window::JournalUpserts(changes) | Window::JournalDeletes(changes) => {
+            journal_client
+                .apply(broker::ApplyRequest {
+                    changes: changes.clone(),
+                })
+                .await
+                .context(if matches!(window, Window::JournalUpserts(_)) {
+                    "activating JournalSpec upserts"
+                } else {
+                    "activating JournalSpec deletions"
+                })?
+                .header
+        }
+        Window::ShardUpserts(changes) | Window::ShardDeletes(changes) => {
+            shard_client
+                .apply(consumer::ApplyRequest {
+                    changes: changes.clone(),
+                    ..Default::default()
+                })
+                .await
+                .context(if matches!(window, Window::ShardUpserts(_)) {
+                    "activating ShardSpec upserts"
+                } else {
+                    "activating ShardSpec deletions"
+                })?
+                .header
+        }
+        Window::Unassign(shards) => {
+            shard_client
+                .unassign(consumer::UnassignRequest {
+                    shards: shards.clone(),
+                    only_failed: true,
+                    dry_run: false,
+                })
+                .await
+                .context("unassigning activated, previously failed shards")?;
+
+            None // Unassignments don't change specs, and have no revision.
+        }
+    };
+
+    Ok(header
+        .and_then(|header| header.etcd)
+        .map(|etcd| etcd.revision)
+        .unwrap_or_default())
+}
+
+/// Returns true if `err` is a failure to apply a window because the
+/// expected revision of one of its specs is no longer current.
+fn is_stale_revision(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<gazette::Error>(),
+        Some(gazette::Error::BrokerStatus(
+            broker::Status::EtcdTransactionFailed
+        )) | Some(gazette::Error::ConsumerStatus(
+            consumer::Status::EtcdTransactionFailed
+        ))
+    )
+}
+
+/// Verify that a window applied at `revision` is reflected in the data-plane:
+/// its upserted specs exist at or after `revision`, and its deleted specs don't exist.
+/// The outer Result is an error in listing the data-plane, and the inner Result
+/// describes a divergence of the data-plane from the window.
+async fn verify_window(
+    journal_client: &gazette::journal::Client,
+    shard_client: &gazette::shard::Client,
+    window: &Window,
+    revision: i64,
+) -> anyhow::Result<Result<(), String>> {
+    let (names, upsert) = match window {
+        Window::JournalUpserts(changes) => (
+            changes
+                .iter()
+                .map(|c| c.upsert.as_ref().unwrap().name.clone())
+                .collect::<Vec<_>>(),
+            true,
+        ),
+        Window::JournalDeletes(changes) => {
+            (changes.iter().map(|c| c.delete.clone()).collect(), false)
+        }
+        Window::ShardUpserts(changes) => (
+            changes
+                .iter()
+                .map(|c| c.upsert.as_ref().unwrap().id.clone())
+                .collect(),
+            true,
+        ),
+        Window::ShardDeletes(changes) => {
+            (changes.iter().map(|c| c.delete.clone()).collect(), false)
+        }
+        Window::Unassign(_) => return Ok(Ok(())),
+    };
+
+    let listed: BTreeMap<String, i64> = match window {
+        Window::JournalUpserts(_) | Window::JournalDeletes(_) => {
+            let selector = LabelSelector {
+                include: Some(LabelSet {
+                    labels: names
+                        .iter()
+                        .map(|name| Label {
+                            name: "name".to_string(),
+                            value: name.clone(),
+                            prefix: false,
+                        })
+                        .collect(),
+                }),
+                exclude: None,
+            };
+            let listing = journal_client
+                .list(broker::ListRequest {
+                    selector: Some(selector),
+                    ..Default::default()
+                })
+                .await?;
+
+            unpack_journal_listing(listing)?
+                .into_iter()
+                .map(|split| (split.name, split.mod_revision))
+                .collect()
+        }
+        _ => {
+            let selector = LabelSelector {
+                include: Some(LabelSet {
+                    labels: names
+                        .iter()
+                        .map(|id| Label {
+                            name: "id".to_string(),
+                            value: id.clone(),
+                            prefix: false,
+                        })
+                        .collect(),
+                }),
+                exclude: None,
+            };
+            let listing = shard_client
+                .list(consumer::ListRequest {
+                    selector: Some(selector),
+                    ..Default::default()
+                })
+                .await?;
+
+            unpack_shard_listing(listing)?
+                .into_iter()
+                .map(|split| (split.id, split.mod_revision))
+                .collect()
+        }
+    };
+
+    Ok(check_window(names, upsert, &listed, revision))
+}
+
+/// Check that `names` of a window applied at `revision` are reflected in the
+/// `listed` mod revisions of their current specs.
+fn check_window(
+    names: Vec<String>,
+    upsert: bool,
+    listed: &BTreeMap<String, i64>,
+    revision: i64,
+) -> Result<(), String> {
+    for name in names {
+        match (upsert, listed.get(&name)) {
+            (true, Some(mod_revision)) if *mod_revision >= revision => (),
+            (true, Some(mod_revision)) => {
+                return Err(format!(
+                    "{name} has revision {mod_revision}, which is before its applied revision {revision}"
+                ))
+            }
+            (true, None) => {
+                return Err(format!(
+                    "{name} was applied at revision {revision}, but doesn't exist"
+                ))
+            }
+            (false, Some(_)) => {
+                return Err(format!(
+                    "{name} was deleted at revision {revision}, but exists"
+                ))
+            }
+            (false, None) => (),
+        }
+    }
     Ok(())
 }
 
@@ -299,7 +1645,9 @@ async fn converge_task_changes<'a>(
     ops_logs_template: Option<&broker::JournalSpec>,
     ops_stats_template: Option<&broker::JournalSpec>,
     initial_splits: usize,
-) -> anyhow::Result<Vec<Change>> {
+    preflight: bool,
+    newer_builds: NewerBuilds<'_>,
+) -> anyhow::Result<(Vec<Change>, CurrentBuilds, Option<std::time::SystemTime>)> {
     let (list_shards, list_recovery) = list_task_request(task_type, task_name);
     let list_logs = list_ops_journal(journal_client, task_type, task_name, ops_logs_template);
     let list_stats = list_ops_journal(journal_client, task_type, task_name, ops_stats_template);
@@ -318,6 +1666,29 @@ async fn converge_task_changes<'a>(
     let (ops_logs_name, ops_logs_spec, ops_logs_splits) = logs?;
     let (ops_stats_name, ops_stats_spec, ops_stats_splits) = stats?;
 
+    let mut builds = split_builds(&shards, &recovery);
+    builds.extend(split_builds(&[], &ops_logs_splits));
+    builds.extend(split_builds(&[], &ops_stats_splits));
+
+    let txn_overrides_expire = match template {
+        Some(_) => txn_overrides_expiry(&shards, std::time::SystemTime::now()),
+        None => None, // Shards are being deleted.
+    };
+
+    // Pre-flight data-plane capacity for the initial splits of a new task.
+    if let (true, Some(template), true) = (preflight, template, shards.is_empty()) {
+        preflight_capacity(
+            journal_client,
+            shard_client,
+            Some(template.shard),
+            &[template.recovery],
+            ops_logs_template,
+            initial_splits,
+        )
+        .await?
+        .check()?;
+    }
+
     let mut changes = task_changes(
         template,
         shards,
@@ -325,6 +1696,7 @@ async fn converge_task_changes<'a>(
         initial_splits,
         &ops_logs_name,
         &ops_stats_name,
+        newer_builds,
     )?;
 
     // Apply ops partitions iff the task is active.
@@ -333,7 +1705,7 @@ async fn converge_task_changes<'a>(
         changes.extend(ops_journal_changes(ops_stats_spec, ops_stats_splits));
     }
 
-    Ok(changes)
+    Ok((changes, builds, txn_overrides_expire))
 }
 
 /// Converge a collection by listing data-plane partition JournalSpecs,
@@ -343,13 +1715,16 @@ async fn converge_partition_changes(
     journal_client: &gazette::journal::Client,
     collection: &models::Collection,
     template: Option<&JournalSpec>,
-) -> anyhow::Result<Vec<Change>> {
+) -> anyhow::Result<(Vec<Change>, CurrentBuilds, SuspendedPartitions)> {
     let list_partitions = list_partitions_request(&collection);
 
     let partitions = journal_client.list(list_partitions).await?;
     let partitions = unpack_journal_listing(partitions)?;
+    let builds = split_builds(&[], &partitions);
 
-    partition_changes(template, partitions)
+    let (changes, suspended) = partition_changes(template, partitions)?;
+
+    Ok((changes, builds, suspended))
 }
 
 /// Build ListRequests of a Task's shard splits and recovery logs.
@@ -427,10 +1802,11 @@ fn unpack_journal_listing(resp: broker::ListResponse) -> anyhow::Result<Vec<Jour
             anyhow::bail!("listing response spec is missing labels");
         };
         v.push(JournalSplit {
-            name: spec.name,
+            name: spec.name.clone(),
             labels: set,
             mod_revision: resp.mod_revision,
             suspend: spec.suspend,
+            current: Some(spec),
         });
     }
     Ok(v)
@@ -445,6 +1821,7 @@ fn task_changes<'a>(
     initial_splits: usize,
     ops_logs_name: &str,
     ops_stats_name: &str,
+    newer_builds: NewerBuilds<'_>,
 ) -> anyhow::Result<Vec<Change>> {
     // If the task is being upsert-ed, no current shards have its template prefix,
     // and it's not disabled, then create `initial_splits` new shards.
@@ -514,6 +1891,25 @@ fn task_changes<'a>(
             anyhow::bail!("shard {id} doesn't match its expected Id, which is {expect_id}");
         }
 
+        if let Some(newer) = newer_build(&id, &split, template.shard, newer_builds.policy) {
+            if let Some(sink) = newer_builds.sink {
+                sink(newer.clone());
+            }
+            match newer.policy {
+                NewerBuildPolicy::Error => anyhow::bail!(
+                    "shard {id} has build {} which is newer than its template build {}",
+                    newer.current_build,
+                    newer.template_build,
+                ),
+                NewerBuildPolicy::SkipSpec => {
+                    // Retain the recovery log, rather than deleting it as unpaired.
+                    recovery.remove(&format!("{}/{id}", template.shard.recovery_log_prefix));
+                    continue;
+                }
+                NewerBuildPolicy::Force => {}
+            }
+        }
+
         let mut shard_spec = ShardSpec {
             id,
             ..template.shard.clone()
@@ -539,18 +1935,34 @@ fn task_changes<'a>(
         }
         shard_labels = labels::set_value(shard_labels, labels::LOGS_JOURNAL, ops_logs_name);
         shard_labels = labels::set_value(shard_labels, labels::STATS_JOURNAL, ops_stats_name);
-        shard_spec.labels = Some(shard_labels);
+        shard_labels = apply_user_patches(shard_labels);
+        shard_labels =
+            txn_duration_overrides(&mut shard_spec, shard_labels, std::time::SystemTime::now());
+
+        let zones =
+            zone_constraints(&shard_labels).with_context(|| format!("shard {}", shard_spec.id))?;
 
         // Next resolve the shard's recovery-log JournalSpec.
         let recovery_name = format!("{}/{}", shard_spec.recovery_log_prefix, shard_spec.id);
         let recovery_split = recovery.remove(&recovery_name).unwrap_or_default();
 
-        let recovery_spec = JournalSpec {
+        let mut recovery_spec = JournalSpec {
             name: recovery_name,
             suspend: recovery_split.suspend, // Must be passed through.
             ..template.recovery.clone()
         };
 
+        // Recovery logs are labeled with the same zones as their shard.
+        if !zones.is_empty() {
+            let mut recovery_labels = recovery_spec.labels.take().unwrap_or_default();
+            recovery_labels = labels::remove(recovery_labels, labels::ZONE);
+            for zone in zones {
+                recovery_labels = labels::add_value(recovery_labels, labels::ZONE, &zone);
+            }
+            recovery_spec.labels = Some(recovery_labels);
+        }
+        shard_spec.labels = Some(shard_labels);
+
         changes.push(Change::Shard(consumer::apply_request::Change {
             expect_mod_revision: shard_revision,
             upsert: Some(shard_spec),
@@ -575,19 +1987,175 @@ fn task_changes<'a>(
     Ok(changes)
 }
 
+/// Determine whether current shard `id` having labels `split` carries
+/// a newer build than its `template`. Shards or templates which lack a
+/// well-formed build label are not compared.
+fn newer_build(
+    id: &str,
+    split: &LabelSet,
+    template: &ShardSpec,
+    policy: NewerBuildPolicy,
+) -> Option<NewerBuild> {
+    let build = |set: &LabelSet| -> Option<models::Id> {
+        labels::expect_one(set, labels::BUILD).ok()?.parse().ok()
+    };
+    let current_build = build(split)?;
+    let template_build = build(template.labels.as_ref()?)?;
+
+    (current_build > template_build).then(|| NewerBuild {
+        shard: id.to_string(),
+        current_build,
+        template_build,
+        policy,
+    })
+}
+
+/// Maximum transaction duration which may be set by an override label.
+const MAX_TXN_DURATION_OVERRIDE: std::time::Duration = std::time::Duration::from_secs(24 * 3600);
+/// Maximum lifetime of transaction duration overrides, relative to now.
+const MAX_TXN_DURATION_OVERRIDE_LIFETIME: std::time::Duration =
+    std::time::Duration::from_secs(7 * 24 * 3600);
+
+/// Apply operator overrides of transaction durations, which are data-plane
+/// labels of the ShardSpec that temporarily adjust its templated durations
+/// without requiring a publication. Overrides must be accompanied by a
+/// TXN_DURATION_EXPIRES label, and are removed from the returned LabelSet
+/// once they've expired or if they're invalid.
+///
+/// Expiry is evaluated only when the task is activated. Activations report
+/// the earliest expiry of current overrides (see `txn_overrides_expiry`),
+/// at which the control-plane activates the task again to remove them.
+fn txn_duration_overrides(
+    shard_spec: &mut ShardSpec,
+    set: LabelSet,
+    now: std::time::SystemTime,
+) -> LabelSet {
+    let remove_all = |set| {
+        let set = labels::remove(set, labels::MAX_TXN_DURATION);
+        let set = labels::remove(set, labels::MIN_TXN_DURATION);
+        labels::remove(set, labels::TXN_DURATION_EXPIRES)
+    };
+
+    let parse = |set: &LabelSet| -> anyhow::Result<Option<_>> {
+        let max = labels::maybe_one(set, labels::MAX_TXN_DURATION)?;
+        let min = labels::maybe_one(set, labels::MIN_TXN_DURATION)?;
+        let expires = labels::maybe_one(set, labels::TXN_DURATION_EXPIRES)?;
+
+        if max.is_empty() && min.is_empty() {
+            return Ok(None);
+        } else if expires.is_empty() {
+            anyhow::bail!("overrides require a {} label", labels::TXN_DURATION_EXPIRES);
+        }
+        let expires = humantime::parse_rfc3339(expires).with_context(|| {
+            format!("invalid {} label {expires:?}", labels::TXN_DURATION_EXPIRES)
+        })?;
+
+        if now >= expires {
+            return Ok(None); // Expired.
+        } else if expires > now + MAX_TXN_DURATION_OVERRIDE_LIFETIME {
+            anyhow::bail!(
+                "{} is more than {:?} in the future",
+                labels::TXN_DURATION_EXPIRES,
+                MAX_TXN_DURATION_OVERRIDE_LIFETIME
+            );
+        }
+
+        let parse_duration = |name: &str, value: &str| -> anyhow::Result<_> {
+            if value.is_empty() {
+                return Ok(None);
+            }
+            let duration = humantime::parse_duration(value)
+                .with_context(|| format!("invalid {name} label {value:?}"))?;
+
+            if duration > MAX_TXN_DURATION_OVERRIDE {
+                anyhow::bail!("{name} {value} exceeds {MAX_TXN_DURATION_OVERRIDE:?}");
+            }
+            Ok(Some(duration))
+        };
+        let max = parse_duration(labels::MAX_TXN_DURATION, max)?;
+        let min = parse_duration(labels::MIN_TXN_DURATION, min)?;
+
+        if max == Some(std::time::Duration::ZERO) {
+            anyhow::bail!("{} must be greater than zero", labels::MAX_TXN_DURATION);
+        }
+        Ok(Some((max, min)))
+    };
+
+    let (max, min) = match parse(&set) {
+        Ok(Some(overrides)) => overrides,
+        Ok(None) => return remove_all(set),
+        Err(err) => {
+            tracing::warn!(
+                shard = %shard_spec.id,
+                ?err,
+                "dropping invalid transaction duration overrides"
+            );
+            return remove_all(set);
+        }
+    };
+
+    let to_std = |d: &Option<pbjson_types::Duration>| {
+        d.as_ref()
+            .map(|d| std::time::Duration::new(d.seconds.max(0) as u64, d.nanos.max(0) as u32))
+            .unwrap_or_default()
+    };
+    let min = min.unwrap_or_else(|| to_std(&shard_spec.min_txn_duration));
+    let mut max = max.unwrap_or_else(|| to_std(&shard_spec.max_txn_duration));
+
+    // A minimum override which exceeds the maximum raises it.
+    if min > max {
+        max = min;
+    }
+    shard_spec.max_txn_duration = Some(max.into());
+    shard_spec.min_txn_duration = Some(min.into());
+
+    set
+}
+
+/// Earliest expiry, after `now`, of the transaction duration overrides of
+/// `shards`. Overrides which have already expired, or which expire too far
+/// into the future to be valid, are removed by the current activation.
+fn txn_overrides_expiry(
+    shards: &[ShardSplit],
+    now: std::time::SystemTime,
+) -> Option<std::time::SystemTime> {
+    shards
+        .iter()
+        .filter(|split| {
+            [labels::MAX_TXN_DURATION, labels::MIN_TXN_DURATION]
+                .iter()
+                .any(|name| !labels::values(&split.labels, name).is_empty())
+        })
+        .filter_map(|split| {
+            let expires = labels::maybe_one(&split.labels, labels::TXN_DURATION_EXPIRES).ok()?;
+            humantime::parse_rfc3339(expires).ok()
+        })
+        .filter(|expires| *expires > now && *expires <= now + MAX_TXN_DURATION_OVERRIDE_LIFETIME)
+        .min()
+}
+
 /// Determine the broker partition changes required to converge
 /// from current `partitions` into the desired state.
+///
+/// The suspension of a partition is always passed through, so that an
+/// upsert never resumes a suspended journal: it's instead resumed by the
+/// broker upon its next append. Upserts of fully-suspended partitions which
+/// wouldn't change their spec are deferred, as they would needlessly race
+/// with broker-side suspension and resumption of the journal.
+/// Fully-suspended partitions are returned as SuspendedPartitions.
 fn partition_changes(
     template: Option<&broker::JournalSpec>,
     partitions: Vec<JournalSplit>,
-) -> anyhow::Result<Vec<Change>> {
+) -> anyhow::Result<(Vec<Change>, SuspendedPartitions)> {
     let mut changes = Vec::new();
+    let mut suspended = SuspendedPartitions::default();
 
     for JournalSplit {
         name,
         labels: split,
         mod_revision,
         suspend,
+        current,
     } in partitions
     {
         let template = match template {
@@ -627,8 +2195,24 @@ fn partition_changes(
             }
             spec_labels = labels::add_value(spec_labels, &label.name, &label.value);
         }
+        zone_constraints(&spec_labels).with_context(|| format!("journal {}", spec.name))?;
         spec.labels = Some(spec_labels);
 
+        if is_fully_suspended(suspend.as_ref()) {
+            let unchanged = current.is_some_and(|current| {
+                JournalSpec {
+                    labels: Some(split),
+                    ..current
+                } == spec
+            });
+
+            if unchanged {
+                suspended.deferred.push(spec.name);
+                continue;
+            }
+            suspended.updated.push(spec.name.clone());
+        }
+
         changes.push(Change::Journal(broker::apply_request::Change {
             expect_mod_revision: mod_revision,
             upsert: Some(spec),
@@ -636,14 +2220,77 @@ fn partition_changes(
         }));
     }
 
-    Ok(changes)
+    Ok((changes, suspended))
 }
 
-fn list_ops_journal_request(
+/// Declared zones of a journal or shard having labels `set`.
+/// Zones are set by the control-plane, and an empty zone is malformed.
+fn zone_constraints(set: &LabelSet) -> anyhow::Result<Vec<String>> {
+    labels::values(set, labels::ZONE)
+        .iter()
+        .map(|label| {
+            if label.value.is_empty() {
+                anyhow::bail!("zone label {} has an empty value", labels::ZONE);
+            }
+            Ok(label.value.clone())
+        })
+        .collect()
+}
+
+/// Is a journal having `suspend` fully suspended?
+fn is_fully_suspended(suspend: Option<&journal_spec::Suspend>) -> bool {
+    suspend.is_some_and(|suspend| suspend.level == journal_spec::suspend::Level::Full as i32)
+}
+
+/// Build the JournalSpec of the ops logs journal of a task,
+/// from the partition template of the data-plane's ops logs collection.
+pub fn ops_logs_journal(
+    task_type: ops::TaskType,
+    task_name: &str,
+    logs_template: &JournalSpec,
+) -> JournalSpec {
+    ops_journal_spec(task_type, task_name, logs_template)
+}
+
+/// Build the JournalSpec of the ops stats journal of a task,
+/// from the partition template of the data-plane's ops stats collection.
+pub fn ops_stats_journal(
+    task_type: ops::TaskType,
+    task_name: &str,
+    stats_template: &JournalSpec,
+) -> JournalSpec {
+    ops_journal_spec(task_type, task_name, stats_template)
+}
+
+/// Fully-qualified name of the ops journal of a task, given the partition
+/// template name of an ops collection, which may have a trailing '/'.
+pub fn ops_journal_name(task_type: ops::TaskType, task_name: &str, template_name: &str) -> String {
+    format!(
+        "{}{}",
+        template_name.trim_end_matches('/'),
+        ops_journal_suffix(task_type, task_name)
+    )
+}
+
+/// Suffix of the names of a task's ops journals,
+/// like `/kind={task_type}/name={task_name}/pivot=00`.
+pub fn ops_journal_suffix(task_type: ops::TaskType, task_name: &str) -> String {
+    ops_journal_spec(task_type, task_name, &JournalSpec::default()).name
+}
+
+/// Map the name of a task's ops journal into the
+/// partition template name of its ops collection.
+pub fn ops_journal_template_name(journal: &str) -> Option<&str> {
+    journal
+        .split_once("/kind=")
+        .map(|(template_name, _)| template_name)
+}
+
+fn ops_journal_spec(
     task_type: ops::TaskType,
     task_name: &str,
     template: &JournalSpec,
-) -> (broker::ListRequest, JournalSpec) {
+) -> JournalSpec {
     let mut spec = template.clone();
     let set = spec.labels.take().unwrap_or_default();
     let set = labels::partition::encode_key_range(set, 0, u32::MAX);
@@ -656,6 +2303,15 @@ fn list_ops_journal_request(
         labels::partition::name_suffix(&set).unwrap()
     );
     spec.labels = Some(set);
+    spec
+}
+
+fn list_ops_journal_request(
+    task_type: ops::TaskType,
+    task_name: &str,
+    template: &JournalSpec,
+) -> (broker::ListRequest, JournalSpec) {
+    let spec = ops_journal_spec(task_type, task_name, template);
 
     let list_req = broker::ListRequest {
         selector: Some(LabelSelector {
@@ -725,12 +2381,14 @@ fn map_partition_to_split(parent: &JournalSplit) -> anyhow::Result<(JournalSplit
             labels: lhs_labels,
             mod_revision: parent.mod_revision,
             suspend: parent.suspend, // LHS continues the parent's physical journal.
+            current: None,
         },
         JournalSplit {
             name: rhs_name,
             labels: rhs_labels,
             mod_revision: 0,
             suspend: None,
+            current: None,
         },
     ))
 }
@@ -824,6 +2482,833 @@ mod test {
         ),)
     }
 
+    #[test]
+    fn test_orphaned_changes() {
+        let journal = |name: &str, set: &[(&str, &str)]| JournalSplit {
+            name: name.to_string(),
+            labels: labels::build_set(set.iter().copied()),
+            mod_revision: 1,
+            suspend: None,
+            current: None,
+        };
+        let shard = |id: &str, set: &[(&str, &str)]| ShardSplit {
+            id: id.to_string(),
+            labels: labels::build_set(set.iter().copied()),
+            mod_revision: 2,
+        };
+        let task = [
+            (labels::TASK_TYPE, labels::TASK_TYPE_CAPTURE),
+            (labels::TASK_NAME, "acme/task"),
+        ];
+        let recovery = [
+            (labels::CONTENT_TYPE, labels::CONTENT_TYPE_RECOVERY_LOG),
+            (labels::TASK_TYPE, labels::TASK_TYPE_CAPTURE),
+            (labels::TASK_NAME, "acme/task"),
+        ];
+
+        let journals = vec![
+            journal(
+                "acme/coll/0000000000000001/pivot=00",
+                &[(labels::COLLECTION, "acme/coll")],
+            ),
+            journal(
+                "acme/coll/0000000000000002/pivot=00",
+                &[(labels::COLLECTION, "acme/coll")],
+            ),
+            journal(
+                "recovery/capture/acme/task/0000000000000001/00000000-00000000",
+                &recovery,
+            ),
+            journal(
+                "recovery/capture/acme/task/0000000000000003/00000000-00000000",
+                &recovery,
+            ),
+            // Not under the catalog prefix.
+            journal(
+                "other/coll/0000000000000001/pivot=00",
+                &[(labels::COLLECTION, "other/coll")],
+            ),
+            // Not generation-suffixed.
+            journal(
+                "ops/acme/logs/pivot=00",
+                &[(labels::COLLECTION, "ops/acme/logs")],
+            ),
+        ];
+        let shards = vec![
+            shard(
+                "capture/acme/task/0000000000000001/00000000-00000000",
+                &task,
+            ),
+            shard(
+                "capture/acme/task/0000000000000003/00000000-00000000",
+                &task,
+            ),
+        ];
+
+        let (orphans, changes) = orphaned_changes("acme/", journals, shards).unwrap();
+
+        assert_eq!(
+            orphans
+                .iter()
+                .map(|o| (o.kind, o.name.as_str(), o.live_generation_id.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "journal",
+                    "acme/coll/0000000000000001/pivot=00",
+                    "0000000000000002".to_string()
+                ),
+                (
+                    "journal",
+                    "recovery/capture/acme/task/0000000000000001/00000000-00000000",
+                    "0000000000000003".to_string()
+                ),
+                (
+                    "shard",
+                    "capture/acme/task/0000000000000001/00000000-00000000",
+                    "0000000000000003".to_string()
+                ),
+            ]
+        );
+        assert_eq!(changes.len(), 3);
+    }
+
+    #[test]
+    fn test_suspend_status() {
+        use broker::append_request::Suspend;
+
+        let status = |mode, result| {
+            let SuspendStatus {
+                suspended, error, ..
+            } = suspend_status("a/journal".to_string(), mode, result);
+            (suspended, error.is_some())
+        };
+        let suspended = || Err(gazette::Error::BrokerStatus(broker::Status::Suspended));
+        let failed = || {
+            Err(gazette::Error::BrokerStatus(
+                broker::Status::JournalNotFound,
+            ))
+        };
+
+        assert_eq!(status(Suspend::IfFlushed, Ok(())), (false, false));
+        assert_eq!(status(Suspend::IfFlushed, suspended()), (true, false));
+        assert_eq!(status(Suspend::Now, Ok(())), (true, false));
+        assert_eq!(status(Suspend::Now, suspended()), (true, false));
+        assert_eq!(status(Suspend::Now, failed()), (false, true));
+        assert_eq!(status(Suspend::Resume, Ok(())), (false, false));
+        assert_eq!(status(Suspend::Resume, suspended()), (false, true));
+    }
+
+    #[test]
+    fn test_suspended_partition_changes() {
+        use journal_spec::suspend::Level;
+
+        let template = JournalSpec {
+            name: "acmeCo/collection/2020202020202020".to_string(),
+            replication: 3,
+            labels: Some(labels::build_set([(
+                labels::COLLECTION,
+                "acmeCo/collection",
+            )])),
+            ..Default::default()
+        };
+        let split =
+            labels::partition::encode_key_range(template.labels.clone().unwrap(), 0, u32::MAX);
+        let name = format!(
+            "{}/{}",
+            template.name,
+            labels::partition::name_suffix(&split).unwrap()
+        );
+
+        let partition = |level: Level, replication: i32| {
+            let suspend = Some(journal_spec::Suspend {
+                level: level as i32,
+                offset: 1234,
+            });
+            JournalSplit {
+                name: name.clone(),
+                labels: split.clone(),
+                mod_revision: 5,
+                suspend,
+                current: Some(JournalSpec {
+                    name: name.clone(),
+                    replication,
+                    suspend,
+                    ..Default::default()
+                }),
+            }
+        };
+        let upserts = |changes: Vec<Change>| -> Vec<JournalSpec> {
+            changes
+                .into_iter()
+                .filter_map(|change| match change {
+                    Change::Journal(change) => change.upsert,
+                    Change::Shard(_) => None,
+                })
+                .collect()
+        };
+
+        // An unchanged, fully-suspended partition is left as-is.
+        let (changes, suspended) =
+            partition_changes(Some(&template), vec![partition(Level::Full, 3)]).unwrap();
+        assert!(changes.is_empty());
+        assert_eq!(
+            suspended,
+            SuspendedPartitions {
+                deferred: vec![name.clone()],
+                updated: Vec::new(),
+            }
+        );
+
+        // An unchanged, partially-suspended partition is still upserted.
+        let (changes, suspended) =
+            partition_changes(Some(&template), vec![partition(Level::Partial, 3)]).unwrap();
+        assert_eq!(upserts(changes).len(), 1);
+        assert_eq!(suspended, SuspendedPartitions::default());
+
+        // A changed, fully-suspended partition is upserted and remains suspended.
+        let (changes, suspended) =
+            partition_changes(Some(&template), vec![partition(Level::Full, 2)]).unwrap();
+        assert_eq!(
+            suspended,
+            SuspendedPartitions {
+                deferred: Vec::new(),
+                updated: vec![name.clone()],
+            }
+        );
+        let upserts = upserts(changes);
+        assert_eq!(upserts.len(), 1);
+        assert_eq!(upserts[0].replication, 3);
+        assert!(is_fully_suspended(upserts[0].suspend.as_ref()));
+    }
+
+    #[test]
+    fn test_readiness_report() {
+        use consumer::replica_status::Code;
+
+        let shard = |id: &str, disable: bool, codes: &[Code]| consumer::list_response::Shard {
+            spec: Some(ShardSpec {
+                id: id.to_string(),
+                disable,
+                ..Default::default()
+            }),
+            status: codes
+                .iter()
+                .map(|code| consumer::ReplicaStatus {
+                    code: *code as i32,
+                    errors: if *code == Code::Failed {
+                        vec!["whoops".to_string()]
+                    } else {
+                        Vec::new()
+                    },
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let readiness = |shards: Vec<consumer::list_response::Shard>| {
+            readiness_report(consumer::ListResponse {
+                shards,
+                ..Default::default()
+            })
+            .unwrap()
+        };
+
+        let report = readiness(vec![
+            shard("a", false, &[Code::Primary, Code::Standby]),
+            shard("b", true, &[]),
+        ]);
+        assert_eq!(report.readiness, Readiness::Ready);
+        assert_eq!(report.shards[0].status, vec!["PRIMARY", "STANDBY"]);
+
+        let report = readiness(vec![
+            shard("a", false, &[Code::Primary]),
+            shard("b", false, &[Code::Backfill]),
+        ]);
+        assert_eq!(report.readiness, Readiness::Pending);
+
+        let report = readiness(vec![
+            shard("a", false, &[Code::Failed]),
+            shard("b", false, &[]),
+        ]);
+        assert_eq!(report.readiness, Readiness::Failed);
+        assert_eq!(report.shards[0].errors, vec!["whoops"]);
+
+        // A failed replica of a disabled shard doesn't fail the task.
+        let report = readiness(vec![shard("a", true, &[Code::Failed])]);
+        assert_eq!(report.readiness, Readiness::Ready);
+
+        // The primary needn't be the first member of the route.
+        let report = readiness(vec![
+            shard("a", false, &[Code::Standby, Code::Primary]),
+            shard("b", false, &[Code::Backfill, Code::Standby, Code::Primary]),
+        ]);
+        assert_eq!(report.readiness, Readiness::Ready);
+        assert_eq!(report.shards[0].status, vec!["STANDBY", "PRIMARY"]);
+
+        // A task without shards is not yet ready.
+        let report = readiness(Vec::new());
+        assert_eq!(report.readiness, Readiness::Pending);
+        assert!(report.shards.is_empty());
+    }
+
+    #[test]
+    fn test_txn_overrides_expiry() {
+        let now = humantime::parse_rfc3339("2024-01-01T00:00:00Z").unwrap();
+        let split = |pairs: &[(&str, &str)]| ShardSplit {
+            id: "materialize/acme/task/0102030405060708/00000000-00000000".to_string(),
+            labels: labels::build_set(pairs.iter().copied()),
+            mod_revision: 1,
+        };
+        let expiry = |shards: &[ShardSplit]| {
+            txn_overrides_expiry(shards, now).map(|t| humantime::format_rfc3339(t).to_string())
+        };
+
+        let active = split(&[
+            (labels::MAX_TXN_DURATION, "30s"),
+            (labels::TXN_DURATION_EXPIRES, "2024-01-01T06:00:00Z"),
+        ]);
+        let sooner = split(&[
+            (labels::MIN_TXN_DURATION, "10s"),
+            (labels::TXN_DURATION_EXPIRES, "2024-01-01T03:00:00Z"),
+        ]);
+        let expired = split(&[
+            (labels::MAX_TXN_DURATION, "30s"),
+            (labels::TXN_DURATION_EXPIRES, "2023-12-31T00:00:00Z"),
+        ]);
+        let too_far = split(&[
+            (labels::MAX_TXN_DURATION, "30s"),
+            (labels::TXN_DURATION_EXPIRES, "2024-02-01T00:00:00Z"),
+        ]);
+        let no_overrides = split(&[(labels::TXN_DURATION_EXPIRES, "2024-01-01T01:00:00Z")]);
+        let invalid = split(&[
+            (labels::MAX_TXN_DURATION, "30s"),
+            (labels::TXN_DURATION_EXPIRES, "tomorrow"),
+        ]);
+
+        assert_eq!(expiry(&[]), None);
+        assert_eq!(
+            expiry(&[active.clone()]),
+            Some("2024-01-01T06:00:00Z".to_string())
+        );
+        // The earliest expiry of active overrides is reported.
+        assert_eq!(
+            expiry(&[active, sooner, no_overrides.clone(), invalid.clone()]),
+            Some("2024-01-01T03:00:00Z".to_string())
+        );
+        // Overrides which this activation removes have no expiry.
+        assert_eq!(expiry(&[expired, too_far, no_overrides, invalid]), None);
+    }
+
+    #[test]
+    fn test_txn_duration_overrides() {
+        let now = humantime::parse_rfc3339("2024-01-01T00:00:00Z").unwrap();
+        let template = || ShardSpec {
+            id: "materialize/acme/task/0102030405060708/00000000-00000000".to_string(),
+            max_txn_duration: Some(std::time::Duration::from_secs(300).into()),
+            min_txn_duration: Some(std::time::Duration::ZERO.into()),
+            ..Default::default()
+        };
+        let apply = |pairs: &[(&str, &str)]| {
+            let mut spec = template();
+            let set =
+                txn_duration_overrides(&mut spec, labels::build_set(pairs.iter().copied()), now);
+            let durations = (
+                spec.max_txn_duration.unwrap().seconds,
+                spec.min_txn_duration.unwrap().seconds,
+            );
+            (durations, set.labels.len())
+        };
+
+        // Active overrides are applied and their labels retained.
+        assert_eq!(
+            apply(&[
+                (labels::MAX_TXN_DURATION, "30s"),
+                (labels::TXN_DURATION_EXPIRES, "2024-01-01T06:00:00Z"),
+            ]),
+            ((30, 0), 2)
+        );
+        // A minimum which exceeds the maximum raises it.
+        assert_eq!(
+            apply(&[
+                (labels::MIN_TXN_DURATION, "10m"),
+                (labels::TXN_DURATION_EXPIRES, "2024-01-01T06:00:00Z"),
+            ]),
+            ((600, 600), 2)
+        );
+        // Expired, unbounded, out-of-range, or malformed overrides are dropped.
+        for pairs in [
+            &[
+                (labels::MAX_TXN_DURATION, "30s"),
+                (labels::TXN_DURATION_EXPIRES, "2023-12-31T00:00:00Z"),
+            ][..],
+            &[(labels::MAX_TXN_DURATION, "30s")],
+            &[
+                (labels::MAX_TXN_DURATION, "30s"),
+                (labels::TXN_DURATION_EXPIRES, "2024-02-01T00:00:00Z"),
+            ],
+            &[
+                (labels::MAX_TXN_DURATION, "48h"),
+                (labels::TXN_DURATION_EXPIRES, "2024-01-01T06:00:00Z"),
+            ],
+            &[
+                (labels::MAX_TXN_DURATION, "0s"),
+                (labels::TXN_DURATION_EXPIRES, "2024-01-01T06:00:00Z"),
+            ],
+            &[
+                (labels::MAX_TXN_DURATION, "soon"),
+                (labels::TXN_DURATION_EXPIRES, "2024-01-01T06:00:00Z"),
+            ],
+        ] {
+            assert_eq!(apply(pairs), ((300, 0), 0), "{pairs:?}");
+        }
+    }
+
+    #[test]
+    fn test_user_patches() {
+        let shard = ShardSpec {
+            id: "capture/acmeCo/task/0011223344556677".to_string(),
+            recovery_log_prefix: "recovery".to_string(),
+            labels: Some(labels::build_set([(labels::LOG_LEVEL, "info")])),
+            ..Default::default()
+        };
+        let recovery = JournalSpec {
+            name: "recovery/capture/acmeCo/task/0011223344556677".to_string(),
+            ..Default::default()
+        };
+        let template = TaskTemplate {
+            shard: &shard,
+            recovery: &recovery,
+        };
+        let log_level = |change: &consumer::apply_request::Change| {
+            let set = change.upsert.as_ref().unwrap().labels.as_ref().unwrap();
+            labels::maybe_one(set, labels::LOG_LEVEL)
+                .unwrap()
+                .to_string()
+        };
+
+        // Patch the log level of the current shard, taking effect immediately.
+        let current = ShardSpec {
+            id: format!("{}/00000000-00000000", shard.id),
+            labels: Some(labels::build_set([
+                (labels::KEY_BEGIN, "00000000"),
+                (labels::KEY_END, "ffffffff"),
+                (labels::LOG_LEVEL, "info"),
+                (labels::RCLOCK_BEGIN, "00000000"),
+                (labels::RCLOCK_END, "ffffffff"),
+            ])),
+            ..Default::default()
+        };
+        let change =
+            patch_shard_change(current, 42, &[(labels::LOG_LEVEL, Some("debug"))]).unwrap();
+        assert_eq!(change.expect_mod_revision, 42);
+        assert_eq!(log_level(&change), "debug");
+
+        // The patch is preserved and re-applied by a subsequent activation.
+        let patched = change.upsert.unwrap();
+        let split = ShardSplit {
+            id: patched.id.clone(),
+            labels: patched.labels.clone().unwrap(),
+            mod_revision: 43,
+        };
+        let changes = task_changes(
+            Some(template),
+            vec![split],
+            Vec::new(),
+            1,
+            "logs",
+            "stats",
+            NewerBuilds::default(),
+        )
+        .unwrap();
+        let Change::Shard(change) = &changes[0] else {
+            panic!("expected a shard change")
+        };
+        assert_eq!(log_level(change), "debug");
+
+        // Unsetting the patch reverts to the templated value upon activation.
+        let change = patch_shard_change(patched, 44, &[(labels::LOG_LEVEL, None)]).unwrap();
+        let unpatched = change.upsert.unwrap();
+        let split = ShardSplit {
+            id: unpatched.id.clone(),
+            labels: unpatched.labels.unwrap(),
+            mod_revision: 45,
+        };
+        let changes = task_changes(
+            Some(template),
+            vec![split],
+            Vec::new(),
+            1,
+            "logs",
+            "stats",
+            NewerBuilds::default(),
+        )
+        .unwrap();
+        let Change::Shard(change) = &changes[0] else {
+            panic!("expected a shard change")
+        };
+        assert_eq!(log_level(change), "info");
+
+        // Labels which identify the task may not be patched.
+        let err = patch_shard_change(shard.clone(), 1, &[(labels::TASK_NAME, Some("other"))])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "label \"estuary.dev/task-name\" of shard capture/acmeCo/task/0011223344556677 may not be patched"
+        );
+    }
+
+    #[test]
+    fn test_newer_build_policies() {
+        let shard = ShardSpec {
+            id: "capture/acmeCo/task/0011223344556677".to_string(),
+            recovery_log_prefix: "recovery".to_string(),
+            labels: Some(labels::build_set([(labels::BUILD, "0000000000000010")])),
+            ..Default::default()
+        };
+        let recovery = JournalSpec {
+            name: "recovery/capture/acmeCo/task/0011223344556677".to_string(),
+            ..Default::default()
+        };
+        let template = TaskTemplate {
+            shard: &shard,
+            recovery: &recovery,
+        };
+
+        // Two current shards, of which the first carries a newer build.
+        let split = |key_begin: &str, key_end: &str, build: &str| ShardSplit {
+            id: format!("{}/{key_begin}-00000000", shard.id),
+            labels: labels::build_set([
+                (labels::BUILD, build),
+                (labels::KEY_BEGIN, key_begin),
+                (labels::KEY_END, key_end),
+                (labels::RCLOCK_BEGIN, "00000000"),
+                (labels::RCLOCK_END, "ffffffff"),
+            ]),
+            mod_revision: 10,
+        };
+        let shards = vec![
+            split("00000000", "7fffffff", "0000000000000020"),
+            split("80000000", "ffffffff", "0000000000000008"),
+        ];
+        let recovery_logs: Vec<_> = shards
+            .iter()
+            .map(|shard| JournalSplit {
+                name: format!("recovery/{}", shard.id),
+                mod_revision: 11,
+                ..Default::default()
+            })
+            .collect();
+
+        let reports = std::sync::Mutex::new(Vec::new());
+        let sink = |newer: NewerBuild| reports.lock().unwrap().push(newer);
+        let run = |policy| {
+            task_changes(
+                Some(template),
+                shards.clone(),
+                recovery_logs.clone(),
+                1,
+                "logs",
+                "stats",
+                NewerBuilds {
+                    policy,
+                    sink: Some(&sink),
+                },
+            )
+        };
+        let upserts = |changes: Vec<Change>| -> Vec<String> {
+            changes
+                .into_iter()
+                .map(|change| match change {
+                    Change::Shard(change) => change.upsert.map(|spec| spec.id),
+                    Change::Journal(change) => change.upsert.map(|spec| spec.name),
+                })
+                .map(Option::unwrap)
+                .collect()
+        };
+
+        let err = run(NewerBuildPolicy::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "shard capture/acmeCo/task/0011223344556677/00000000-00000000 has build 0000000000000020 which is newer than its template build 0000000000000010"
+        );
+
+        // The newer shard and its recovery log are left as they are.
+        assert_eq!(
+            upserts(run(NewerBuildPolicy::SkipSpec).unwrap()),
+            vec![
+                "capture/acmeCo/task/0011223344556677/80000000-00000000",
+                "recovery/capture/acmeCo/task/0011223344556677/80000000-00000000",
+            ]
+        );
+        // All shards are converged.
+        assert_eq!(upserts(run(NewerBuildPolicy::Force).unwrap()).len(), 4);
+        // Activations which don't choose a policy converge the shard.
+        assert_eq!(NewerBuildPolicy::default(), NewerBuildPolicy::Force);
+
+        let reports = reports.into_inner().unwrap();
+        assert_eq!(
+            reports
+                .iter()
+                .map(|r| (r.shard.as_str(), r.policy))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "capture/acmeCo/task/0011223344556677/00000000-00000000",
+                    NewerBuildPolicy::Error
+                ),
+                (
+                    "capture/acmeCo/task/0011223344556677/00000000-00000000",
+                    NewerBuildPolicy::SkipSpec
+                ),
+                (
+                    "capture/acmeCo/task/0011223344556677/00000000-00000000",
+                    NewerBuildPolicy::Force
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_zone_constraints() {
+        let shard = ShardSpec {
+            id: "capture/acmeCo/task/0011223344556677".to_string(),
+            recovery_log_prefix: "recovery".to_string(),
+            labels: Some(labels::build_set([
+                (labels::ZONE, "us-east-1a"),
+                (labels::ZONE, "us-east-1b"),
+            ])),
+            ..Default::default()
+        };
+        let recovery = JournalSpec {
+            name: "recovery/capture/acmeCo/task/0011223344556677".to_string(),
+            labels: Some(labels::build_set([(labels::ZONE, "us-west-2a")])),
+            ..Default::default()
+        };
+        let template = TaskTemplate {
+            shard: &shard,
+            recovery: &recovery,
+        };
+
+        // Recovery logs take on the zones of their shard.
+        let changes = task_changes(
+            Some(template),
+            Vec::new(),
+            Vec::new(),
+            1,
+            "logs",
+            "stats",
+            NewerBuilds::default(),
+        )
+        .unwrap();
+        let zones: Vec<_> = changes
+            .iter()
+            .map(|change| match change {
+                Change::Shard(change) => change.upsert.as_ref().unwrap().labels.as_ref(),
+                Change::Journal(change) => change.upsert.as_ref().unwrap().labels.as_ref(),
+            })
+            .map(|set| {
+                labels::values(set.unwrap(), labels::ZONE)
+                    .iter()
+                    .map(|l| l.value.as_str())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        assert_eq!(
+            zones,
+            vec![
+                vec!["us-east-1a", "us-east-1b"],
+                vec!["us-east-1a", "us-east-1b"]
+            ]
+        );
+
+        // An empty zone cannot be satisfied.
+        let shard = ShardSpec {
+            labels: Some(labels::build_set([(labels::ZONE, "")])),
+            ..shard
+        };
+        let template = TaskTemplate {
+            shard: &shard,
+            recovery: &recovery,
+        };
+        let err = task_changes(
+            Some(template),
+            Vec::new(),
+            Vec::new(),
+            1,
+            "logs",
+            "stats",
+            NewerBuilds::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "shard capture/acmeCo/task/0011223344556677/00000000-00000000: zone label estuary.dev/zone has an empty value"
+        );
+    }
+
+    #[test]
+    fn test_tombstone_and_purge_changes() {
+        let shards = || {
+            vec![(
+                ShardSpec {
+                    id: "capture/acme/task/0102030405060708/00000000-00000000".to_string(),
+                    labels: Some(labels::build_set([(labels::TASK_NAME, "acme/task")])),
+                    ..Default::default()
+                },
+                10,
+            )]
+        };
+        let recovery = || {
+            vec![(
+                JournalSpec {
+                    name: "recovery/capture/acme/task/0102030405060708/00000000-00000000"
+                        .to_string(),
+                    labels: Some(labels::build_set([(labels::TASK_NAME, "acme/task")])),
+                    ..Default::default()
+                },
+                20,
+            )]
+        };
+        let unpack = |changes: Vec<Change>| -> (Vec<ShardSpec>, Vec<JournalSpec>) {
+            let (mut s, mut j) = (Vec::new(), Vec::new());
+            for change in changes {
+                match change {
+                    Change::Shard(c) => s.push(c.upsert.unwrap()),
+                    Change::Journal(c) => j.push(c.upsert.unwrap()),
+                }
+            }
+            (s, j)
+        };
+        let now = humantime::parse_rfc3339("2024-01-01T00:00:00Z").unwrap();
+
+        // Tombstoning disables shards and labels all specs.
+        let (t_shards, t_recovery) = unpack(tombstone_changes(
+            shards(),
+            recovery(),
+            Some("2024-01-02T00:00:00Z"),
+        ));
+        assert!(t_shards[0].disable);
+        for set in [&t_shards[0].labels, &t_recovery[0].labels] {
+            assert_eq!(
+                labels::expect_one(set.as_ref().unwrap(), labels::DELETE_AFTER).unwrap(),
+                "2024-01-02T00:00:00Z"
+            );
+        }
+        let tombstoned = || {
+            (
+                t_shards
+                    .iter()
+                    .cloned()
+                    .map(|s| (s, 11))
+                    .collect::<Vec<_>>(),
+                t_recovery
+                    .iter()
+                    .cloned()
+                    .map(|j| (j, 21))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        // Purges are refused before the grace period elapses, or if not tombstoned.
+        let (s, j) = tombstoned();
+        assert!(purge_changes(s, j, now, false).unwrap().is_none());
+        assert!(purge_changes(shards(), recovery(), now, false)
+            .unwrap()
+            .is_none());
+
+        // Purges proceed after the grace period, or if forced.
+        let (s, j) = tombstoned();
+        let later = now + std::time::Duration::from_secs(86400);
+        assert_eq!(purge_changes(s, j, later, false).unwrap().unwrap().len(), 2);
+        assert_eq!(
+            purge_changes(shards(), recovery(), now, true)
+                .unwrap()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Undeleting removes the tombstone.
+        let (s, j) = tombstoned();
+        let (u_shards, u_recovery) = unpack(tombstone_changes(s, j, None));
+        for set in [&u_shards[0].labels, &u_recovery[0].labels] {
+            assert!(labels::values(set.as_ref().unwrap(), labels::DELETE_AFTER).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_resume_divergence() {
+        let listed: BTreeMap<String, i64> = [("a".to_string(), 10), ("b".to_string(), 20)]
+            .into_iter()
+            .collect();
+        let names = |n: &[&str]| n.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        // Upserts verify if their specs exist at or after the applied revision.
+        assert_eq!(check_window(names(&["a", "b"]), true, &listed, 10), Ok(()));
+        assert_eq!(
+            check_window(names(&["a", "b"]), true, &listed, 15),
+            Err("a has revision 10, which is before its applied revision 15".to_string())
+        );
+        assert_eq!(
+            check_window(names(&["c"]), true, &listed, 10),
+            Err("c was applied at revision 10, but doesn't exist".to_string())
+        );
+        // Deletions verify if their specs don't exist.
+        assert_eq!(check_window(names(&["c"]), false, &listed, 10), Ok(()));
+        assert_eq!(
+            check_window(names(&["b"]), false, &listed, 10),
+            Err("b was deleted at revision 10, but exists".to_string())
+        );
+
+        // Windows which fail due to a stale expected revision are discarded,
+        // while other failures are returned to be retried.
+        let stale = |err: gazette::Error| {
+            is_stale_revision(
+                &Result::<(), _>::Err(err)
+                    .context("activating JournalSpec upserts")
+                    .unwrap_err(),
+            )
+        };
+        assert!(stale(gazette::Error::BrokerStatus(
+            broker::Status::EtcdTransactionFailed
+        )));
+        assert!(stale(gazette::Error::ConsumerStatus(
+            consumer::Status::EtcdTransactionFailed
+        )));
+        assert!(!stale(gazette::Error::BrokerStatus(
+            broker::Status::JournalNotFound
+        )));
+        assert!(!stale(gazette::Error::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_unpack_hint_backups() {
+        let spec = ShardSpec {
+            id: "the/shard".to_string(),
+            hint_prefix: "/estuary/flow/hints".to_string(),
+            hint_backups: 2,
+            ..Default::default()
+        };
+        let hints = |log: &str| consumer::get_hints_response::ResponseHints {
+            hints: Some(recoverylog::FsmHints {
+                log: log.to_string(),
+                ..Default::default()
+            }),
+        };
+        let resp = consumer::GetHintsResponse {
+            primary_hints: Some(hints("primary")),
+            backup_hints: vec![Default::default(), hints("older")],
+            ..Default::default()
+        };
+
+        let backups = unpack_hint_backups(&spec, resp);
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].index, 1);
+        assert_eq!(backups[0].key, "/estuary/flow/hints/the/shard.backup.1");
+        assert_eq!(backups[0].hints.log, "older");
+    }
+
     async fn managed_build(source: url::Url) -> build::Output {
         use tables::CatalogResolver;
         let file_root = std::path::Path::new("/");
@@ -955,6 +3440,7 @@ mod test {
                     level: journal_spec::suspend::Level::Partial as i32,
                     offset: 112233,
                 }),
+                current: None,
             });
         };
 
@@ -979,6 +3465,7 @@ mod test {
                     level: journal_spec::suspend::Level::None as i32,
                     offset: 445566,
                 }),
+                current: None,
             });
             all_recovery_disabled.push(JournalSplit {
                 name: format!(
@@ -991,6 +3478,7 @@ mod test {
                     level: journal_spec::suspend::Level::Full as i32,
                     offset: 778899,
                 }),
+                current: None,
             });
             all_shards.push(ShardSplit {
                 id: shard_id,
@@ -1038,7 +3526,9 @@ mod test {
         // Case: test update of existing specs.
         {
             let partition_changes =
-                partition_changes(Some(&partition_template), all_partitions.clone()).unwrap();
+                partition_changes(Some(&partition_template), all_partitions.clone())
+                    .unwrap()
+                    .0;
             let task_changes = task_changes(
                 Some(TaskTemplate {
                     shard: shard_template,
@@ -1049,6 +3539,7 @@ mod test {
                 4,
                 "ops/logs/name",
                 "ops/stats/name",
+                NewerBuilds::default(),
             )
             .unwrap();
 
@@ -1057,8 +3548,9 @@ mod test {
 
         // Case: test creation of new specs.
         {
-            let partition_changes =
-                partition_changes(Some(&partition_template), Vec::new()).unwrap();
+            let partition_changes = partition_changes(Some(&partition_template), Vec::new())
+                .unwrap()
+                .0;
             let task_changes = task_changes(
                 Some(TaskTemplate {
                     shard: shard_template,
@@ -1069,6 +3561,7 @@ mod test {
                 4,
                 "ops/logs/name",
                 "ops/stats/name",
+                NewerBuilds::default(),
             )
             .unwrap();
 
@@ -1077,8 +3570,9 @@ mod test {
 
         // Case: test creation of new specs with no initial splits.
         {
-            let partition_changes =
-                partition_changes(Some(&partition_template), Vec::new()).unwrap();
+            let partition_changes = partition_changes(Some(&partition_template), Vec::new())
+                .unwrap()
+                .0;
             let task_changes = task_changes(
                 Some(TaskTemplate {
                     shard: shard_template,
@@ -1089,6 +3583,7 @@ mod test {
                 0,
                 "ops/logs/name",
                 "ops/stats/name",
+                NewerBuilds::default(),
             )
             .unwrap();
 
@@ -1098,7 +3593,9 @@ mod test {
         // Case: test update of existing specs when disabled.
         {
             let partition_changes =
-                partition_changes(Some(&partition_template), all_partitions.clone()).unwrap();
+                partition_changes(Some(&partition_template), all_partitions.clone())
+                    .unwrap()
+                    .0;
             let task_changes = task_changes(
                 Some(TaskTemplate {
                     shard: disabled_shard_template,
@@ -1109,6 +3606,7 @@ mod test {
                 4,
                 "ops/logs/name",
                 "ops/stats/name",
+                NewerBuilds::default(),
             )
             .unwrap();
 
@@ -1117,8 +3615,9 @@ mod test {
 
         // Case: test creation of new specs when disabled.
         {
-            let partition_changes =
-                partition_changes(Some(&partition_template), Vec::new()).unwrap();
+            let partition_changes = partition_changes(Some(&partition_template), Vec::new())
+                .unwrap()
+                .0;
             let task_changes = task_changes(
                 Some(TaskTemplate {
                     shard: disabled_shard_template,
@@ -1129,6 +3628,7 @@ mod test {
                 4,
                 "ops/logs/name",
                 "ops/stats/name",
+                NewerBuilds::default(),
             )
             .unwrap();
 
@@ -1137,7 +3637,7 @@ mod test {
 
         // Case: test deletion of existing specs.
         {
-            let partition_changes = partition_changes(None, all_partitions.clone()).unwrap();
+            let partition_changes = partition_changes(None, all_partitions.clone()).unwrap().0;
             let task_changes = task_changes(
                 None,
                 all_shards.clone(),
@@ -1145,6 +3645,7 @@ mod test {
                 4,
                 "ops/logs/name",
                 "ops/stats/name",
+                NewerBuilds::default(),
             )
             .unwrap();
 
@@ -1171,8 +3672,9 @@ mod test {
                 *name = name.replace("2020202020202020", "replaced-pub-id");
             }
 
-            let partition_changes =
-                partition_changes(Some(&partition_template), all_partitions).unwrap();
+            let partition_changes = partition_changes(Some(&partition_template), all_partitions)
+                .unwrap()
+                .0;
             let task_changes = task_changes(
                 Some(TaskTemplate {
                     shard: shard_template,
@@ -1183,6 +3685,7 @@ mod test {
                 4,
                 "ops/logs/name",
                 "ops/stats/name",
+                NewerBuilds::default(),
             )
             .unwrap();
 
@@ -1206,6 +3709,7 @@ mod test {
                 4,
                 "ops/logs/name",
                 "ops/stats/name",
+                NewerBuilds::default(),
             )
             .unwrap();
 
@@ -1219,6 +3723,7 @@ mod test {
                 4,
                 "ops/logs/name",
                 "ops/stats/name",
+                NewerBuilds::default(),
             )
             .unwrap();
 
@@ -1251,7 +3756,8 @@ mod test {
             let (lhs, rhs) = map_partition_to_split(parent).unwrap();
             let partition_changes =
                 partition_changes(Some(partition_template), vec![lhs.clone(), rhs.clone()])
-                    .unwrap();
+                    .unwrap()
+                    .0;
 
             insta::assert_json_snapshot!(
                 "partition_splits",
@@ -1259,6 +3765,23 @@ mod test {
             );
         }
 
+        // Case: ops journal names and specs agree.
+        {
+            let spec = ops_logs_journal(ops::TaskType::Capture, "a/task", ops_logs_template);
+            assert_eq!(
+                spec.name,
+                ops_journal_name(ops::TaskType::Capture, "a/task", &ops_logs_template.name)
+            );
+            assert_eq!(
+                ops_journal_suffix(ops::TaskType::Capture, "a/task"),
+                "/kind=capture/name=a%2Ftask/pivot=00"
+            );
+            assert_eq!(
+                ops_journal_template_name(&spec.name),
+                Some(ops_logs_template.name.as_str())
+            );
+        }
+
         // Case: creation and updates of ops collection partitions.
         {
             let (list_req, spec) = list_ops_journal_request(
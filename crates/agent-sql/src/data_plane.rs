@@ -54,7 +54,13 @@ pub async fn fetch_data_planes<'a, 'b>(
             broker_address,
             reactor_address,
             ops_logs_name as "ops_logs_name: models::Collection",
-            ops_stats_name as "ops_stats_name: models::Collection"
+            ops_stats_name as "ops_stats_name: models::Collection",
+            cross_plane_read_peers,
+            zones,
+            max_task_cpu_millis,
+            max_task_memory_bytes,
+            min_max_txn_duration_millis,
+            max_txn_duration_millis
         from data_planes
         where id in (select id from unnest($1::flowid[]) id)
            or data_plane_name = $2
@@ -68,3 +74,56 @@ pub async fn fetch_data_planes<'a, 'b>(
 
     Ok(r.into_iter().collect())
 }
+
+/// Insert audit records of changes applied to data-plane `data_plane_id`.
+/// Records are serialized as `activate::AuditRecord`.
+pub async fn insert_audit_records<R>(
+    pool: &sqlx::PgPool,
+    data_plane_id: models::Id,
+    records: &[R],
+) -> sqlx::Result<()>
+where
+    R: serde::Serialize + Send + Sync,
+{
+    sqlx::query!(
+        r#"
+        insert into internal.data_plane_audit (
+            data_plane_id,
+            actor,
+            change_kind,
+            name,
+            action,
+            old_build,
+            new_build,
+            revision,
+            applied_at
+        )
+        select
+            $1,
+            r.actor,
+            r."changeKind",
+            r.name,
+            r.action,
+            r."oldBuild",
+            r."newBuild",
+            r.revision,
+            r.timestamp
+        from json_to_recordset($2) as r(
+            actor text,
+            "changeKind" text,
+            name text,
+            action text,
+            "oldBuild" text,
+            "newBuild" text,
+            revision bigint,
+            timestamp timestamptz
+        );
+        "#,
+        data_plane_id as models::Id,
+        TextJson(records) as TextJson<&[R]>,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -114,6 +114,8 @@ pub async fn add_built_spec<S, V>(
     catalog_name: &str,
     built_spec: S,
     validated: Option<V>,
+    documentation_url: Option<&str>,
+    oauth2: Option<&proto_flow::flow::OAuth2>,
     db: &PgPool,
 ) -> sqlx::Result<()>
 where
@@ -122,13 +124,19 @@ where
 {
     sqlx::query!(
         r#"
-        update draft_specs set built_spec = $1, validated = $2
+        update draft_specs set
+            built_spec = $1,
+            validated = $2,
+            documentation_url = $5,
+            oauth2 = $6
         where draft_id = $3 and catalog_name = $4;
         "#,
         TextJson(built_spec) as TextJson<S>,
         validated.map(|v| TextJson(v)) as Option<TextJson<V>>,
         draft_id as Id,
         catalog_name as &str,
+        documentation_url,
+        oauth2.map(|o| TextJson(o)) as Option<TextJson<&proto_flow::flow::OAuth2>>,
     )
     .execute(db)
     .await?;
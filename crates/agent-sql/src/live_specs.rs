@@ -44,6 +44,20 @@ pub async fn fetch_live_specs(
     user_id: Uuid,
     names: &[String],
     db: impl sqlx::Executor<'_, Database = sqlx::Postgres>,
+) -> sqlx::Result<Vec<LiveSpec>> {
+    fetch_live_specs_with_cached(user_id, names, &[], &[], db).await
+}
+
+/// Returns a `LiveSpec` row for each of the given `names`, as does `fetch_live_specs`.
+/// The caller holds cached specifications of `cached_names` at the corresponding
+/// `cached_build_ids`, and rows of these names which remain at their cached
+/// build have null `spec` and `built_spec` columns, which needn't be fetched.
+pub async fn fetch_live_specs_with_cached(
+    user_id: Uuid,
+    names: &[String],
+    cached_names: &[String],
+    cached_build_ids: &[Id],
+    db: impl sqlx::Executor<'_, Database = sqlx::Postgres>,
 ) -> sqlx::Result<Vec<LiveSpec>> {
     sqlx::query_as!(
         LiveSpec,
@@ -55,8 +69,10 @@ pub async fn fetch_live_specs(
             coalesce(ls.data_plane_id, '00:00:00:00:00:00:00:00'::flowid) as "data_plane_id!: Id",
             names as "catalog_name!: String",
             ls.spec_type as "spec_type?: CatalogType",
-            ls.spec as "spec: TextJson<Box<RawValue>>",
-            ls.built_spec as "built_spec: TextJson<Box<RawValue>>",
+            case when ls.last_build_id = cached.build_id then null else ls.spec end
+                as "spec: TextJson<Box<RawValue>>",
+            case when ls.last_build_id = cached.build_id then null else ls.built_spec end
+                as "built_spec: TextJson<Box<RawValue>>",
             ls.inferred_schema_md5,
             (
                 select max(capability) from internal.user_roles($1) r
@@ -71,9 +87,13 @@ pub async fn fetch_live_specs(
             ls.dependency_hash
         from unnest($2::text[]) names
         left outer join live_specs ls on ls.catalog_name = names
+        left outer join unnest($3::text[], $4::flowid[]) as cached(catalog_name, build_id)
+            on cached.catalog_name = names
         "#,
         user_id,
         names,
+        cached_names,
+        cached_build_ids as &[Id],
     )
     .fetch_all(db)
     .await
@@ -104,6 +124,32 @@ pub async fn fetch_inferred_schemas(
     .await
 }
 
+pub struct CollectionThroughputRow {
+    pub collection_name: String,
+    pub bytes_per_hour: i64,
+}
+
+/// Fetch the peak bytes per hour written to each of `collections` over the trailing day.
+pub async fn fetch_collection_throughputs(
+    collections: &[&str],
+    pool: &sqlx::PgPool,
+) -> sqlx::Result<Vec<CollectionThroughputRow>> {
+    sqlx::query_as!(
+        CollectionThroughputRow,
+        r#"select
+            catalog_name::text as "collection_name!: String",
+            max(bytes_written_to_me)::bigint as "bytes_per_hour!: i64"
+            from catalog_stats_hourly
+            where catalog_name = ANY($1::text[])
+              and ts >= date_trunc('hour', now() - '1 day'::interval)
+            group by catalog_name
+            "#,
+        collections as &[&str],
+    )
+    .fetch_all(pool)
+    .await
+}
+
 /// Queries for all non-deleted `live_specs` that are connected to the given `collection_names` via
 /// `live_spec_flows`.
 pub async fn fetch_expanded_live_specs(
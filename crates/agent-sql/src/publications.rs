@@ -615,6 +615,34 @@ pub async fn resolve_storage_mappings(
     .await
 }
 
+#[derive(Debug)]
+pub struct TenantRow {
+    pub id: Id,
+    pub tenant: String,
+    pub strict_schemas: bool,
+}
+
+/// Returns the tenants rows of the given set of tenants.
+pub async fn resolve_tenants(
+    tenant_names: Vec<&str>,
+    db: impl sqlx::Executor<'_, Database = sqlx::Postgres>,
+) -> sqlx::Result<Vec<TenantRow>> {
+    sqlx::query_as!(
+        TenantRow,
+        r#"
+        select
+            t.id as "id: Id",
+            t.tenant as "tenant!: String",
+            t.strict_schemas
+        from tenants t
+        where t.tenant = any($1::text[]);
+        "#,
+        tenant_names as Vec<&str>,
+    )
+    .fetch_all(db)
+    .await
+}
+
 pub struct ResolvedCollectionRow {
     pub built_spec: Option<Json<proto_flow::flow::CollectionSpec>>,
 }
@@ -19,8 +19,11 @@ async fn do_authorize_user_collection(
     Request {
         collection: collection_name,
         started_unix,
+        capability,
     }: Request,
 ) -> anyhow::Result<Response> {
+    let capability = capability.unwrap_or(models::Capability::Read);
+
     let (has_started, started_unix) = if started_unix == 0 {
         (false, jsonwebtoken::get_current_timestamp())
     } else {
@@ -29,7 +32,13 @@ async fn do_authorize_user_collection(
 
     loop {
         match Snapshot::evaluate(snapshot, started_unix, |snapshot: &Snapshot| {
-            evaluate_authorization(snapshot, user_id, email.as_ref(), &collection_name)
+            evaluate_authorization(
+                snapshot,
+                user_id,
+                email.as_ref(),
+                &collection_name,
+                capability,
+            )
         }) {
             Ok(response) => return Ok(response),
             Err(Ok(retry_millis)) if has_started => {
@@ -51,16 +60,17 @@ fn evaluate_authorization(
     user_id: uuid::Uuid,
     user_email: Option<&String>,
     collection_name: &models::Collection,
+    capability: models::Capability,
 ) -> anyhow::Result<Response> {
     if !tables::UserGrant::is_authorized(
         &snapshot.role_grants,
         &snapshot.user_grants,
         user_id,
         collection_name,
-        models::Capability::Read,
+        capability,
     ) {
         anyhow::bail!(
-            "{} is not authorized to {collection_name}",
+            "{} is not authorized to {capability:?} {collection_name}",
             user_email.map(String::as_str).unwrap_or("user")
         );
     }
@@ -83,9 +93,14 @@ fn evaluate_authorization(
     let exp = iat + super::exp_seconds();
     let header = jsonwebtoken::Header::default();
 
+    let mut cap = proto_gazette::capability::LIST | proto_gazette::capability::READ;
+    if capability >= models::Capability::Write {
+        cap |= proto_gazette::capability::APPEND;
+    }
+
     let claims = super::DataClaims {
         inner: proto_gazette::Claims {
-            cap: proto_gazette::capability::LIST | proto_gazette::capability::READ,
+            cap,
             exp,
             iat,
             iss: data_plane.data_plane_fqdn.clone(),
@@ -123,3 +138,146 @@ pub async fn authorize_user_collection(
 ) -> axum::response::Response {
     super::wrap(async move { do_authorize_user_collection(&app, claims, request).await }).await
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fixture() -> Snapshot {
+        let (mut snapshot, _refresh_rx) = super::super::snapshot::seed();
+
+        snapshot
+            .collections
+            .push(super::super::snapshot::SnapshotCollection {
+                journal_template_name: "acmeCo/widgets/1122334455667788/".to_string(),
+                collection_name: models::Collection::new("acmeCo/widgets"),
+                data_plane_id: models::Id::zero(),
+            });
+        snapshot.collections_idx_name = vec![0];
+
+        snapshot.data_planes.insert(tables::DataPlane {
+            control_id: models::Id::zero(),
+            data_plane_name: "ops/dp/public/test".to_string(),
+            data_plane_fqdn: "test.dp.estuary-data.com".to_string(),
+            is_default: false,
+            hmac_keys: vec!["c2VjcmV0".to_string()],
+            broker_address: "broker.test".to_string(),
+            reactor_address: "reactor.test".to_string(),
+            ops_logs_name: models::Collection::new("ops/tasks/test/logs"),
+            ops_stats_name: models::Collection::new("ops/tasks/test/stats"),
+            cross_plane_read_peers: Vec::new(),
+            zones: Vec::new(),
+            max_task_cpu_millis: None,
+            max_task_memory_bytes: None,
+            min_max_txn_duration_millis: None,
+            max_txn_duration_millis: None,
+        });
+
+        snapshot.user_grants.insert_row(
+            uuid::Uuid::from_u128(1),
+            models::Prefix::new("acmeCo/"),
+            models::Capability::Write,
+        );
+        snapshot.user_grants.insert_row(
+            uuid::Uuid::from_u128(2),
+            models::Prefix::new("acmeCo/"),
+            models::Capability::Read,
+        );
+
+        snapshot
+    }
+
+    fn decode_cap(response: &Response) -> u32 {
+        let mut validation = jsonwebtoken::Validation::default();
+        validation.set_required_spec_claims(&["exp"]);
+
+        let claims = jsonwebtoken::decode::<super::super::DataClaims>(
+            &response.broker_token,
+            &jsonwebtoken::DecodingKey::from_base64_secret("c2VjcmV0").unwrap(),
+            &validation,
+        )
+        .unwrap()
+        .claims;
+
+        claims.inner.cap
+    }
+
+    #[test]
+    fn test_write_capability_grants_append() {
+        let snapshot = fixture();
+        let email = "writer@acme.co".to_string();
+        let collection = models::Collection::new("acmeCo/widgets");
+
+        // A user with a Write grant who requests Write is authorized to APPEND.
+        let response = evaluate_authorization(
+            &snapshot,
+            uuid::Uuid::from_u128(1),
+            Some(&email),
+            &collection,
+            models::Capability::Write,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decode_cap(&response),
+            proto_gazette::capability::LIST
+                | proto_gazette::capability::READ
+                | proto_gazette::capability::APPEND
+        );
+        assert_eq!(
+            response.journal_name_prefix,
+            "acmeCo/widgets/1122334455667788/"
+        );
+
+        // Requesting only Read doesn't grant APPEND, even to a writer.
+        let response = evaluate_authorization(
+            &snapshot,
+            uuid::Uuid::from_u128(1),
+            Some(&email),
+            &collection,
+            models::Capability::Read,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decode_cap(&response),
+            proto_gazette::capability::LIST | proto_gazette::capability::READ
+        );
+    }
+
+    #[test]
+    fn test_read_capability_does_not_grant_append() {
+        let snapshot = fixture();
+        let email = "reader@acme.co".to_string();
+        let collection = models::Collection::new("acmeCo/widgets");
+
+        let response = evaluate_authorization(
+            &snapshot,
+            uuid::Uuid::from_u128(2),
+            Some(&email),
+            &collection,
+            models::Capability::Read,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decode_cap(&response),
+            proto_gazette::capability::LIST | proto_gazette::capability::READ
+        );
+
+        // A reader which requests Write is rejected.
+        let err = evaluate_authorization(
+            &snapshot,
+            uuid::Uuid::from_u128(2),
+            Some(&email),
+            &collection,
+            models::Capability::Write,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "reader@acme.co is not authorized to Write acmeCo/widgets"
+        );
+    }
+}
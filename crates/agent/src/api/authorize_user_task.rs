@@ -89,9 +89,17 @@ fn evaluate_authorization(
         )
     };
 
-    let ops_suffix = super::ops_suffix(task);
-    let ops_logs_journal = format!("{}{}", ops_logs.journal_template_name, &ops_suffix[1..]);
-    let ops_stats_journal = format!("{}{}", ops_stats.journal_template_name, &ops_suffix[1..]);
+    let ops_task_type = super::ops_task_type(task);
+    let ops_logs_journal = activate::ops_journal_name(
+        ops_task_type,
+        &task.task_name,
+        &ops_logs.journal_template_name,
+    );
+    let ops_stats_journal = activate::ops_journal_name(
+        ops_task_type,
+        &task.task_name,
+        &ops_stats.journal_template_name,
+    );
 
     let iat = jsonwebtoken::get_current_timestamp();
     let exp = iat + super::exp_seconds();
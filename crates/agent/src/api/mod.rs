@@ -270,17 +270,18 @@ fn exp_seconds() -> u64 {
     rand::thread_rng().gen_range(40 * 60..80 * 60)
 }
 
+fn ops_task_type(task: &snapshot::SnapshotTask) -> ops::TaskType {
+    match task.spec_type {
+        models::CatalogType::Capture => ops::TaskType::Capture,
+        models::CatalogType::Collection => ops::TaskType::Derivation,
+        models::CatalogType::Materialization => ops::TaskType::Materialization,
+        // Tests don't have ops journals.
+        models::CatalogType::Test => ops::TaskType::InvalidType,
+    }
+}
+
 fn ops_suffix(task: &snapshot::SnapshotTask) -> String {
-    let ops_kind = match task.spec_type {
-        models::CatalogType::Capture => "capture",
-        models::CatalogType::Collection => "derivation",
-        models::CatalogType::Materialization => "materialization",
-        models::CatalogType::Test => "test",
-    };
-    format!(
-        "/kind={ops_kind}/name={}/pivot=00",
-        labels::percent_encoding(&task.task_name).to_string(),
-    )
+    activate::ops_journal_suffix(ops_task_type(task), &task.task_name)
 }
 
 // Support the legacy data-plane by re-writing its internal service
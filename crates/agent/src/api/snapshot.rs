@@ -203,7 +203,13 @@ async fn try_fetch(pg_pool: &sqlx::PgPool) -> anyhow::Result<Snapshot> {
                 broker_address,
                 reactor_address,
                 ops_logs_name as "ops_logs_name: models::Collection",
-                ops_stats_name as "ops_stats_name: models::Collection"
+                ops_stats_name as "ops_stats_name: models::Collection",
+                cross_plane_read_peers,
+                zones,
+                max_task_cpu_millis,
+                max_task_memory_bytes,
+                min_max_txn_duration_millis,
+                max_txn_duration_millis
             from data_planes
             "#,
     )
@@ -131,6 +131,7 @@ export class Derivation extends Types.IDerivation {"#
 
             backfill: 0,
             priority: 0,
+            max_read_rate: None,
             read_delay: None,
         });
 
@@ -142,6 +143,7 @@ export class Derivation extends Types.IDerivation {"#
             backfill: 0,
             lambda: models::RawValue::default(),
             priority: 0,
+            max_read_rate: None,
             read_delay: None,
             shuffle: models::Shuffle::Any,
         });
@@ -78,5 +78,6 @@ pub async fn update<C: ControlPlane>(
     };
 
     let periodic_next = periodic::next_periodic_publish(state);
-    Ok(NextRun::earliest([ad_next, periodic_next]))
+    let activation_next = publication_status::next_activation(&status.activation);
+    Ok(NextRun::earliest([ad_next, periodic_next, activation_next]))
 }
@@ -70,7 +70,12 @@ pub async fn update<C: ControlPlane>(
     } else {
         None
     };
-    Ok(NextRun::earliest([inferred_schema_next, periodic_next]))
+    let activation_next = publication_status::next_activation(&status.activation);
+    Ok(NextRun::earliest([
+        inferred_schema_next,
+        periodic_next,
+        activation_next,
+    ]))
 }
 
 /// Disables transforms that source from deleted collections.
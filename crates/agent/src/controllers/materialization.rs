@@ -93,7 +93,11 @@ pub async fn update<C: ControlPlane>(
 
     // There isn't any call to notify dependents because nothing currently can depend on a materialization.
 
-    Ok(periodic::next_periodic_publish(state))
+    let activation_next = publication_status::next_activation(&status.activation);
+    Ok(NextRun::earliest([
+        periodic::next_periodic_publish(state),
+        activation_next,
+    ]))
 }
 
 /// Publishes, and handles any incompatibleCollections by automatically
@@ -394,6 +398,7 @@ fn update_linked_materialization(
             disable: false,
             fields: Default::default(),
             priority: Default::default(),
+            max_read_rate: None,
             backfill: 0,
             on_incompatible_schema_change: None,
         };
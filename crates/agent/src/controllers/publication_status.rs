@@ -8,19 +8,24 @@ use models::{
     Id,
 };
 
-use super::{backoff_data_plane_activate, ControllerState};
+use super::{backoff_data_plane_activate, ControllerState, NextRun};
 
-/// Activates the spec in the data plane if necessary.
+/// Activates the spec in the data plane if necessary, which is when it has
+/// a newer build, or when data-plane overrides of its shards have expired.
 pub async fn update_activation<C: ControlPlane>(
     status: &mut ActivationStatus,
     state: &ControllerState,
     control_plane: &C,
 ) -> anyhow::Result<()> {
-    if state.last_build_id > status.last_activated {
+    let overrides_expired = status
+        .txn_overrides_expire
+        .is_some_and(|expire| expire <= control_plane.current_time());
+
+    if state.last_build_id > status.last_activated || overrides_expired {
         let name = state.catalog_name.clone();
         let built_spec = state.built_spec.as_ref().expect("built_spec must be Some");
 
-        crate::timeout(
+        let activation = crate::timeout(
             std::time::Duration::from_secs(60),
             control_plane.data_plane_activate(name, built_spec, state.data_plane_id),
             || "Timeout while activating into data-plane",
@@ -29,12 +34,25 @@ pub async fn update_activation<C: ControlPlane>(
         .with_retry(backoff_data_plane_activate(state.failures))
         .context("failed to activate into data-plane")?;
 
-        tracing::debug!(last_activated = %state.last_build_id, "activated");
+        tracing::debug!(
+            last_activated = %state.last_build_id,
+            skipped_shards = activation.skipped_shards.len(),
+            txn_overrides_expire = ?activation.txn_overrides_expire,
+            "activated"
+        );
         status.last_activated = state.last_build_id;
+        status.skipped_shards = activation.skipped_shards;
+        status.txn_overrides_expire = activation.txn_overrides_expire;
     }
     Ok(())
 }
 
+/// Returns when the spec must next be activated, to remove expiring
+/// data-plane overrides of its shards.
+pub fn next_activation(status: &ActivationStatus) -> Option<NextRun> {
+    status.txn_overrides_expire.map(NextRun::after)
+}
+
 fn is_touch_pub(draft: &tables::DraftCatalog) -> bool {
     draft.tests.iter().all(|r| r.is_touch)
         && draft.collections.iter().all(|r| r.is_touch)
@@ -61,7 +61,7 @@ pub trait ControlPlane: Send + Sync {
         catalog_name: String,
         spec: &AnyBuiltSpec,
         data_plane_id: models::Id,
-    ) -> anyhow::Result<()>;
+    ) -> anyhow::Result<DataPlaneActivation>;
 
     /// Deletes the given entity from the data plane.
     async fn data_plane_delete(
@@ -159,6 +159,16 @@ fn set_of<T: Into<String>>(s: T) -> BTreeSet<String> {
     set
 }
 
+/// DataPlaneActivation is the outcome of activating a built spec.
+#[derive(Debug, Default)]
+pub struct DataPlaneActivation {
+    /// Shards which were skipped because they carry a newer build than the spec.
+    pub skipped_shards: Vec<models::status::publications::SkippedShard>,
+    /// Time at which data-plane overrides of transaction durations of the
+    /// spec's shards expire, and the spec must be activated again.
+    pub txn_overrides_expire: Option<DateTime<Utc>>,
+}
+
 /// Implementation of `ControlPlane` that connects directly to postgres.
 #[derive(Clone)]
 pub struct PGControlPlane<C: DiscoverConnectors> {
@@ -167,6 +177,9 @@ pub struct PGControlPlane<C: DiscoverConnectors> {
     pub publications_handler: Publisher,
     pub id_generator: models::IdGenerator,
     pub discovers_handler: DiscoverHandler<C>,
+    /// Directory within which activations are journaled, so that an
+    /// activation which is interrupted is resumed by the next one.
+    pub change_journal_dir: std::path::PathBuf,
 }
 
 impl<C: DiscoverConnectors> PGControlPlane<C> {
@@ -176,6 +189,7 @@ impl<C: DiscoverConnectors> PGControlPlane<C> {
         publications_handler: Publisher,
         id_generator: models::IdGenerator,
         discovers_handler: DiscoverHandler<C>,
+        change_journal_dir: std::path::PathBuf,
     ) -> Self {
         Self {
             pool,
@@ -183,6 +197,39 @@ impl<C: DiscoverConnectors> PGControlPlane<C> {
             publications_handler,
             id_generator,
             discovers_handler,
+            change_journal_dir,
+        }
+    }
+
+    /// Build the ChangeJournal of activations into data-plane `data_plane_id`.
+    fn change_journal(&self, data_plane_id: models::Id) -> anyhow::Result<activate::ChangeJournal> {
+        let dir = self.change_journal_dir.join(data_plane_id.to_string());
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create change journal directory {dir:?}"))?;
+        Ok(activate::ChangeJournal::new(dir))
+    }
+
+    // Insert audit records of applied changes of an activation into the
+    // control-plane. Like activation events, this is best-effort.
+    async fn insert_audit_records(
+        &self,
+        data_plane_id: models::Id,
+        catalog_name: &str,
+        records: Vec<activate::AuditRecord>,
+    ) {
+        if records.is_empty() {
+            return;
+        }
+        if let Err(error) =
+            agent_sql::data_plane::insert_audit_records(&self.pool, data_plane_id, &records).await
+        {
+            tracing::warn!(
+                %catalog_name,
+                %data_plane_id,
+                ?error,
+                records = records.len(),
+                "failed to insert activation audit records"
+            );
         }
     }
 
@@ -220,7 +267,9 @@ impl<C: DiscoverConnectors> PGControlPlane<C> {
         let mut metadata = gazette::Metadata::default();
         metadata
             .signed_claims(
-                proto_gazette::capability::LIST | proto_gazette::capability::APPLY,
+                proto_gazette::capability::LIST
+                    | proto_gazette::capability::APPLY
+                    | proto_gazette::capability::APPEND,
                 &data_plane.data_plane_fqdn,
                 std::time::Duration::from_secs(60),
                 &data_plane.hmac_keys,
@@ -419,16 +468,52 @@ impl<C: DiscoverConnectors> ControlPlane for PGControlPlane<C> {
         catalog_name: String,
         spec: &AnyBuiltSpec,
         data_plane_id: models::Id,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<DataPlaneActivation> {
         let (shard_client, journal_client, ops_logs_template, ops_stats_template) = self
             .build_data_plane_context(data_plane_id)
             .await
             .context("failed to create data plane clients")?;
+        let change_journal = self.change_journal(data_plane_id)?;
+
+        let events = std::sync::Mutex::new(Vec::new());
+        let sink = |event: activate::ActivationEvent| events.lock().unwrap().push(event);
 
-        match spec {
+        let records = std::sync::Mutex::new(Vec::new());
+        let audit_sink = |record: activate::AuditRecord| records.lock().unwrap().push(record);
+        let actor = format!("user:{}", self.system_user_id);
+        let audit = activate::Audit {
+            actor: &actor,
+            sink: &audit_sink,
+        };
+
+        // Shards carrying a newer build than `spec` are left as they are, so that
+        // the remainder of the task converges, and are surfaced to the controller.
+        let skipped = std::sync::Mutex::new(Vec::new());
+        let newer_sink = |newer: activate::NewerBuild| {
+            tracing::warn!(
+                %catalog_name,
+                shard = %newer.shard,
+                current_build = %newer.current_build,
+                template_build = %newer.template_build,
+                "data-plane shard has a newer build than its activated spec"
+            );
+            skipped
+                .lock()
+                .unwrap()
+                .push(models::status::publications::SkippedShard {
+                    shard: newer.shard,
+                    current_build: newer.current_build,
+                });
+        };
+        let newer_builds = activate::NewerBuilds {
+            policy: activate::NewerBuildPolicy::SkipSpec,
+            sink: Some(&newer_sink),
+        };
+
+        let (task_type, result) = match spec {
             AnyBuiltSpec::Capture(s) => {
-                let name = models::Capture::new(catalog_name);
-                activate::activate_capture(
+                let name = models::Capture::new(&catalog_name);
+                let result = activate::activate_capture(
                     &journal_client,
                     &shard_client,
                     &name,
@@ -436,34 +521,50 @@ impl<C: DiscoverConnectors> ControlPlane for PGControlPlane<C> {
                     Some(&ops_logs_template),
                     Some(&ops_stats_template),
                     INITIAL_SPLITS,
+                    true, // Pre-flight data-plane capacity for new tasks.
+                    Some(&change_journal),
+                    Some(&sink),
+                    Some(audit),
+                    newer_builds,
                 )
-                .await
+                .await;
+                (ops::TaskType::Capture, result)
             }
             AnyBuiltSpec::Collection(s) => {
-                let name = models::Collection::new(catalog_name);
-                activate::activate_collection(
+                let name = models::Collection::new(&catalog_name);
+                let result = activate::activate_collection(
                     &journal_client,
                     &shard_client,
                     &name,
                     Some(s),
                     Some(&ops_logs_template),
                     Some(&ops_stats_template),
-                    INITIAL_SPLITS,
+                    initial_splits(
+                        s.derivation
+                            .as_ref()
+                            .and_then(|d| d.shard_template.as_ref()),
+                    ),
+                    true, // Pre-flight data-plane capacity for new tasks.
+                    Some(&change_journal),
+                    Some(&sink),
+                    Some(audit),
+                    newer_builds,
                 )
-                .await
+                .await;
+                (ops::TaskType::Derivation, result)
             }
             AnyBuiltSpec::Materialization(s) => {
-                let name = models::Materialization::new(catalog_name);
+                let name = models::Materialization::new(&catalog_name);
 
                 let initial_splits = if s.connector_type
                     == proto_flow::flow::materialization_spec::ConnectorType::Dekaf as i32
                 {
                     0 // Dekaf tasks do not have actual shards, but do have ops journals.
                 } else {
-                    INITIAL_SPLITS
+                    initial_splits(s.shard_template.as_ref())
                 };
 
-                activate::activate_materialization(
+                let result = activate::activate_materialization(
                     &journal_client,
                     &shard_client,
                     &name,
@@ -471,13 +572,36 @@ impl<C: DiscoverConnectors> ControlPlane for PGControlPlane<C> {
                     Some(&ops_logs_template),
                     Some(&ops_stats_template),
                     initial_splits,
+                    true, // Pre-flight data-plane capacity for new tasks.
+                    Some(&change_journal),
+                    Some(&sink),
+                    Some(audit),
+                    newer_builds,
                 )
-                .await
+                .await;
+                (ops::TaskType::Materialization, result)
             }
-            AnyBuiltSpec::Test(_) => Err(anyhow::anyhow!(
-                "attempted to activate a Test, which is not a thing"
-            )),
-        }
+            AnyBuiltSpec::Test(_) => {
+                return Err(anyhow::anyhow!(
+                    "attempted to activate a Test, which is not a thing"
+                ))
+            }
+        };
+
+        publish_activation_events(
+            &journal_client,
+            &ops_logs_template,
+            task_type,
+            &catalog_name,
+            events.into_inner().unwrap(),
+        )
+        .await;
+        self.insert_audit_records(data_plane_id, &catalog_name, records.into_inner().unwrap())
+            .await;
+        result.map(|activated| DataPlaneActivation {
+            skipped_shards: skipped.into_inner().unwrap(),
+            txn_overrides_expire: activated.txn_overrides_expire.map(DateTime::<Utc>::from),
+        })
     }
 
     async fn data_plane_delete(
@@ -490,11 +614,23 @@ impl<C: DiscoverConnectors> ControlPlane for PGControlPlane<C> {
             .build_data_plane_context(data_plane_id)
             .await
             .context("failed to create data-plane clients")?;
+        let change_journal = self.change_journal(data_plane_id)?;
 
-        match spec_type {
+        let events = std::sync::Mutex::new(Vec::new());
+        let sink = |event: activate::ActivationEvent| events.lock().unwrap().push(event);
+
+        let records = std::sync::Mutex::new(Vec::new());
+        let audit_sink = |record: activate::AuditRecord| records.lock().unwrap().push(record);
+        let actor = format!("user:{}", self.system_user_id);
+        let audit = activate::Audit {
+            actor: &actor,
+            sink: &audit_sink,
+        };
+
+        let (task_type, result) = match spec_type {
             CatalogType::Capture => {
-                let name = models::Capture::new(catalog_name);
-                activate::activate_capture(
+                let name = models::Capture::new(&catalog_name);
+                let result = activate::activate_capture(
                     &journal_client,
                     &shard_client,
                     &name,
@@ -502,12 +638,18 @@ impl<C: DiscoverConnectors> ControlPlane for PGControlPlane<C> {
                     Some(&ops_logs_template),
                     Some(&ops_stats_template),
                     INITIAL_SPLITS,
+                    false, // Deletions create no shards.
+                    Some(&change_journal),
+                    Some(&sink),
+                    Some(audit),
+                    activate::NewerBuilds::default(), // Deletions have no template.
                 )
-                .await
+                .await;
+                (ops::TaskType::Capture, result)
             }
             CatalogType::Collection => {
-                let name = models::Collection::new(catalog_name);
-                activate::activate_collection(
+                let name = models::Collection::new(&catalog_name);
+                let result = activate::activate_collection(
                     &journal_client,
                     &shard_client,
                     &name,
@@ -515,12 +657,18 @@ impl<C: DiscoverConnectors> ControlPlane for PGControlPlane<C> {
                     Some(&ops_logs_template),
                     Some(&ops_stats_template),
                     INITIAL_SPLITS,
+                    false, // Deletions create no shards.
+                    Some(&change_journal),
+                    Some(&sink),
+                    Some(audit),
+                    activate::NewerBuilds::default(), // Deletions have no template.
                 )
-                .await
+                .await;
+                (ops::TaskType::Derivation, result)
             }
             CatalogType::Materialization => {
-                let name = models::Materialization::new(catalog_name);
-                activate::activate_materialization(
+                let name = models::Materialization::new(&catalog_name);
+                let result = activate::activate_materialization(
                     &journal_client,
                     &shard_client,
                     &name,
@@ -528,14 +676,94 @@ impl<C: DiscoverConnectors> ControlPlane for PGControlPlane<C> {
                     Some(&ops_logs_template),
                     Some(&ops_stats_template),
                     INITIAL_SPLITS,
+                    false, // Deletions create no shards.
+                    Some(&change_journal),
+                    Some(&sink),
+                    Some(audit),
+                    activate::NewerBuilds::default(), // Deletions have no template.
                 )
-                .await
+                .await;
+                (ops::TaskType::Materialization, result)
             }
-            CatalogType::Test => Err(anyhow::anyhow!(
-                "attempted to delete a Test, which is not a thing"
-            )),
-        }
+            CatalogType::Test => {
+                return Err(anyhow::anyhow!(
+                    "attempted to delete a Test, which is not a thing"
+                ))
+            }
+        };
+
+        publish_activation_events(
+            &journal_client,
+            &ops_logs_template,
+            task_type,
+            &catalog_name,
+            events.into_inner().unwrap(),
+        )
+        .await;
+        self.insert_audit_records(data_plane_id, &catalog_name, records.into_inner().unwrap())
+            .await;
+        result.map(|_| ())
+    }
+}
+
+// Publish applied changes of an activation to the ops logs journal of the task,
+// so that they're visible in its activation history. This is best-effort:
+// activation has already happened, and a failure to publish is only logged.
+async fn publish_activation_events(
+    journal_client: &journal::Client,
+    ops_logs_template: &broker::JournalSpec,
+    task_type: ops::TaskType,
+    task_name: &str,
+    events: Vec<activate::ActivationEvent>,
+) {
+    let logs_journal = activate::ops_journal_name(task_type, task_name, &ops_logs_template.name);
+
+    if let Err(error) =
+        activate::publish_events(journal_client, task_type, &logs_journal, &events).await
+    {
+        tracing::warn!(
+            %task_name,
+            %logs_journal,
+            ?error,
+            events = events.len(),
+            "failed to publish activation events"
+        );
     }
 }
 
 const INITIAL_SPLITS: usize = 1;
+
+/// Initial splits of a new task, as recommended by validation within its
+/// ShardSpec template, or INITIAL_SPLITS if there's no recommendation.
+fn initial_splits(template: Option<&proto_gazette::consumer::ShardSpec>) -> usize {
+    template
+        .and_then(|template| template.labels.as_ref())
+        .and_then(|set| labels::values(set, labels::INITIAL_SPLITS).first())
+        .and_then(|label| label.value.parse().ok())
+        .unwrap_or(INITIAL_SPLITS)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_initial_splits() {
+        let template =
+            |set: Option<proto_gazette::broker::LabelSet>| proto_gazette::consumer::ShardSpec {
+                labels: set,
+                ..Default::default()
+            };
+
+        assert_eq!(initial_splits(None), INITIAL_SPLITS);
+        assert_eq!(initial_splits(Some(&template(None))), INITIAL_SPLITS);
+
+        // A recommendation of validation is honored.
+        let set = labels::set_value(Default::default(), labels::INITIAL_SPLITS, "8");
+        assert_eq!(initial_splits(Some(&template(Some(set)))), 8);
+
+        // A malformed recommendation is ignored.
+        let set = labels::set_value(Default::default(), labels::INITIAL_SPLITS, "eight");
+        assert_eq!(initial_splits(Some(&template(Some(set)))), INITIAL_SPLITS);
+    }
+}
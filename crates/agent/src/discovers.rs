@@ -56,10 +56,10 @@ pub struct DiscoverOutput {
 impl DiscoverOutput {
     fn failed(capture_name: models::Capture, error: anyhow::Error) -> DiscoverOutput {
         let mut draft = tables::DraftCatalog::default();
-        draft.errors.insert(tables::Error {
-            scope: tables::synthetic_scope(models::CatalogType::Capture, &capture_name),
+        draft.errors.insert(tables::Error::new(
+            tables::synthetic_scope(models::CatalogType::Capture, &capture_name),
             error,
-        });
+        ));
         DiscoverOutput {
             capture_name,
             draft,
@@ -253,6 +253,7 @@ async fn prepare_discover(
                 }),
                 interval: models::CaptureDef::default_interval(),
                 shards: models::ShardTemplate::default(),
+                annotations: Default::default(),
                 expect_pub_id: None,
                 bindings: Vec::new(),
                 delete: false,
@@ -349,6 +350,12 @@ mod test {
             ops_stats_name: models::Collection::new("tha/stats"),
             broker_address: "broker.test".to_string(),
             reactor_address: "reactor.test".to_string(),
+            cross_plane_read_peers: Vec::new(),
+            zones: Vec::new(),
+            max_task_cpu_millis: None,
+            max_task_memory_bytes: None,
+            min_max_txn_duration_millis: None,
+            max_txn_duration_millis: None,
         };
 
         let result = super::prepare_discover(
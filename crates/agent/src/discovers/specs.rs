@@ -274,9 +274,12 @@ pub fn merge_collections(
                     write_schema: None,
                     read_schema: None,
                     key: models::CompositeKey::new(Vec::new()),
+                    key_migration: None,
                     projections: Default::default(),
                     journals: Default::default(),
+                    frozen: false,
                     derive: None,
+                    annotations: Default::default(),
                     expect_pub_id: None,
                     delete: false,
                 };
@@ -13,13 +13,13 @@ pub async fn load_draft(
     for row in rows {
         let Some(spec_type) = row.spec_type.map(Into::into) else {
             let scope = tables::synthetic_scope("deletion", &row.catalog_name);
-            draft.errors.push(tables::Error {
+            draft.errors.push(tables::Error::new(
                 scope,
-                error: anyhow::anyhow!(
+                anyhow::anyhow!(
                     "draft contains a deletion of {:?}, but no such live spec exists",
                     row.catalog_name
                 ),
-            });
+            ));
             continue;
         };
         let scope = tables::synthetic_scope(spec_type, &row.catalog_name);
@@ -117,10 +117,10 @@ pub async fn evolve(evolution: Evolution, db: &PgPool) -> anyhow::Result<Evoluti
     for req in requests.iter() {
         if let Err(error) = req.validate() {
             let scope = tables::synthetic_scope(models::CatalogType::Collection, &req.current_name);
-            draft.errors.insert(tables::Error {
+            draft.errors.insert(tables::Error::new(
                 scope,
-                error: error.context("validating evolution request"),
-            });
+                error.context("validating evolution request"),
+            ));
         }
     }
     if !draft.errors.is_empty() {
@@ -176,7 +176,7 @@ pub async fn evolve(evolution: Evolution, db: &PgPool) -> anyhow::Result<Evoluti
             Err(error) => {
                 let scope =
                     tables::synthetic_scope(models::CatalogType::Collection, &req.current_name);
-                draft.errors.insert(tables::Error { scope, error });
+                draft.errors.insert(tables::Error::new(scope, error));
             }
         }
     }
@@ -150,10 +150,14 @@ async fn test_collection_evolution() {
     [
         Error {
             scope: flow://collection/moths/missing-collection-backfill,
+            catalog_type: "collection",
+            catalog_name: moths/missing-collection-backfill,
             error: nothing to update for collection 'moths/missing-collection-backfill',
         },
         Error {
             scope: flow://collection/moths/missing-collection-rename,
+            catalog_type: "collection",
+            catalog_name: moths/missing-collection-rename,
             error: missing spec for collection 'moths/missing-collection-rename',
         },
     ]
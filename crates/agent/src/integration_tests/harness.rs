@@ -164,6 +164,7 @@ impl TestHarness {
             &logs_tx,
             pool.clone(),
             id_gen.clone(),
+            validation::Limits::default(),
         );
 
         let control_plane = TestControlPlane::new(PGControlPlane::new(
@@ -172,6 +173,7 @@ impl TestHarness {
             publisher.clone(),
             id_gen.clone(),
             discover_handler.clone(),
+            builds_root.path().join("activations"),
         ));
 
         let controller_exec =
@@ -1125,10 +1127,7 @@ pub trait FailBuild: std::fmt::Debug + Send + 'static {
 pub struct InjectBuildError(Option<tables::Error>);
 impl InjectBuildError {
     pub fn new(scope: url::Url, err: impl Into<anyhow::Error>) -> InjectBuildError {
-        InjectBuildError(Some(tables::Error {
-            scope,
-            error: err.into(),
-        }))
+        InjectBuildError(Some(tables::Error::new(scope, err.into())))
     }
 }
 impl FailBuild for InjectBuildError {
@@ -1321,7 +1320,7 @@ impl ControlPlane for TestControlPlane {
         catalog_name: String,
         spec: &AnyBuiltSpec,
         _data_plane_id: Id,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<crate::controlplane::DataPlaneActivation> {
         let mut mocks = self.mocks.lock().unwrap();
         if mocks.fail_activations.contains(&catalog_name) {
             anyhow::bail!("data_plane_delete simulated failure");
@@ -1337,7 +1336,7 @@ impl ControlPlane for TestControlPlane {
             catalog_type,
             built_spec: Some(spec.clone()),
         });
-        Ok(())
+        Ok(Default::default())
     }
 
     async fn data_plane_delete(
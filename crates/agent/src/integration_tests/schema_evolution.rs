@@ -426,13 +426,10 @@ impl FailBuild for UnsatisfiableConstraints {
         constraint.r#type = ConstraintType::Unsatisfiable as i32;
         constraint.reason = "mock unsatisfiable field".to_string();
 
-        result.output.built.errors.insert(tables::Error {
-            scope: tables::synthetic_scope(
-                CatalogType::Materialization,
-                mat.materialization.as_str(),
-            ),
-            error: anyhow::anyhow!("omg an unsatisfiable constraint"),
-        });
+        result.output.built.errors.insert(tables::Error::new(
+            tables::synthetic_scope(CatalogType::Materialization, mat.materialization.as_str()),
+            anyhow::anyhow!("omg an unsatisfiable constraint"),
+        ));
     }
 }
 
@@ -357,10 +357,14 @@ async fn test_source_capture_no_annotations() {
     [
         Error {
             scope: flow://materialization/ducks/materializeA,
+            catalog_type: "materialization",
+            catalog_name: ducks/materializeA,
             error: sourceCapture.deltaUpdates set but the connector 'materialize/test' does not support delta updates,
         },
         Error {
             scope: flow://materialization/ducks/materializeA,
+            catalog_type: "materialization",
+            catalog_name: ducks/materializeA,
             error: sourceCapture.targetSchema set but the connector 'materialize/test' does not support resource schemas,
         },
     ]
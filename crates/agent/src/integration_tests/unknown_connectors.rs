@@ -65,6 +65,8 @@ async fn test_forbidden_connector() {
     [
         Error {
             scope: flow://capture/sheep/capture,
+            catalog_type: "capture",
+            catalog_name: sheep/capture,
             error: Forbidden connector image 'forbidden_connector',
         },
     ]
@@ -75,6 +75,23 @@ struct Args {
     )]
     #[arg(value_parser = humantime::parse_duration)]
     heartbeat_timeout: std::time::Duration,
+    /// Directory within which data-plane activations are journaled, so that
+    /// an interrupted activation is completed before its task is activated anew.
+    /// Defaults to a directory within the system temporary directory.
+    #[clap(long = "change-journal-dir", env = "CHANGE_JOURNAL_DIR")]
+    change_journal_dir: Option<std::path::PathBuf>,
+    /// Maximum number of enabled bindings of a published task.
+    /// Defaults to the validation default.
+    #[clap(long = "max-bindings", env = "MAX_BINDINGS")]
+    max_bindings: Option<usize>,
+    /// Maximum number of specifications of a single publication.
+    /// Defaults to the validation default.
+    #[clap(long = "max-specs", env = "MAX_SPECS")]
+    max_specs: Option<usize>,
+    /// Maximum number of projections of a published collection.
+    /// Defaults to the validation default.
+    #[clap(long = "max-projections", env = "MAX_PROJECTIONS")]
+    max_projections: Option<usize>,
 }
 
 fn main() -> Result<(), anyhow::Error> {
@@ -201,6 +218,12 @@ async fn async_main(args: Args) -> Result<(), anyhow::Error> {
     // Range starts at 1 because 0 is always used for ids generated in postgres.
     let id_gen_shard = rand::thread_rng().gen_range(1u16..1024u16);
     let id_gen = models::IdGenerator::new(id_gen_shard);
+    let limits = validation::Limits::default();
+    let limits = validation::Limits {
+        max_bindings: args.max_bindings.unwrap_or(limits.max_bindings),
+        max_specs: args.max_specs.unwrap_or(limits.max_specs),
+        max_projections: args.max_projections.unwrap_or(limits.max_projections),
+    };
     let publisher = Publisher::new(
         &bindir,
         &args.builds_root,
@@ -208,6 +231,7 @@ async fn async_main(args: Args) -> Result<(), anyhow::Error> {
         &logs_tx,
         pg_pool.clone(),
         id_gen.clone(),
+        limits,
     );
     let control_plane = agent::PGControlPlane::new(
         pg_pool.clone(),
@@ -215,6 +239,9 @@ async fn async_main(args: Args) -> Result<(), anyhow::Error> {
         publisher.clone(),
         id_gen.clone(),
         discover_handler.clone(),
+        args.change_journal_dir
+            .clone()
+            .unwrap_or_else(|| std::env::temp_dir().join("agent-activations")),
     );
 
     // Share-able future which completes when the agent should exit.
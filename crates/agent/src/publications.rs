@@ -140,6 +140,8 @@ pub struct Publisher {
     logs_tx: logs::Tx,
     id_gen: std::sync::Arc<std::sync::Mutex<models::IdGenerator>>,
     db: sqlx::PgPool,
+    catalog_cache: std::sync::Arc<tables::CatalogCache>,
+    limits: validation::Limits,
 }
 
 pub struct UncommittedBuild {
@@ -228,6 +230,7 @@ impl Publisher {
         logs_tx: &logs::Tx,
         pool: sqlx::PgPool,
         build_id_gen: models::IdGenerator,
+        limits: validation::Limits,
     ) -> Self {
         Self {
             bindir: bindir.to_string(),
@@ -236,6 +239,8 @@ impl Publisher {
             logs_tx: logs_tx.clone(),
             id_gen: std::sync::Mutex::new(build_id_gen.into()).into(),
             db: pool,
+            catalog_cache: Default::default(),
+            limits,
         }
     }
 
@@ -351,10 +356,19 @@ impl Publisher {
         let forbidden_source_capture = specs::check_source_capture_annotations(&draft, &self.db)
             .await
             .context("checking source capture")?;
-        if !forbidden_images.is_empty() || !forbidden_source_capture.is_empty() {
+        // Endpoint configs are checked against their connector's schema from `connector_tags`,
+        // so that schema violations are precisely reported without a Spec of the connector.
+        let invalid_endpoint_configs = specs::check_endpoint_configs(&draft, &self.db)
+            .await
+            .context("checking endpoint configs")?;
+        if !forbidden_images.is_empty()
+            || !forbidden_source_capture.is_empty()
+            || !invalid_endpoint_configs.is_empty()
+        {
             let mut built = tables::Validations::default();
             built.errors = forbidden_images;
             built.errors.extend(forbidden_source_capture.into_iter());
+            built.errors.extend(invalid_endpoint_configs.into_iter());
             let output = build::Output {
                 draft,
                 built,
@@ -377,6 +391,7 @@ impl Publisher {
             user_id,
             &draft,
             &self.db,
+            &self.catalog_cache,
             default_data_plane_name,
             verify_user_authz,
         )
@@ -401,10 +416,10 @@ impl Publisher {
 
         let incompatible_collections = validate_collection_transitions(&draft, &live_catalog);
         if !incompatible_collections.is_empty() {
-            let errors =  incompatible_collections.iter().map(|ic| tables::Error {
-                scope: tables::synthetic_scope(models::CatalogType::Collection, &ic.collection),
-                error: anyhow::anyhow!("collection key and logical partitioning may not be changed; a new collection must be created"),
-            }).collect::<tables::Errors>();
+            let errors =  incompatible_collections.iter().map(|ic| tables::Error::new(
+                tables::synthetic_scope(models::CatalogType::Collection, &ic.collection),
+                anyhow::anyhow!("collection key and logical partitioning may not be changed; a new collection must be created"),
+            )).collect::<tables::Errors>();
             let output = build::Output {
                 draft,
                 live: live_catalog,
@@ -436,7 +451,7 @@ impl Publisher {
 
         let tmpdir_handle = tempfile::TempDir::new().context("creating tempdir")?;
         let tmpdir = tmpdir_handle.path();
-        let built = builds::build_catalog(
+        let mut built = builds::build_catalog(
             &self.builds_root,
             draft,
             live_catalog,
@@ -445,9 +460,14 @@ impl Publisher {
             tmpdir,
             self.logs_tx.clone(),
             logs_token,
+            &self.limits,
         )
         .await?;
 
+        specs::attach_connector_specs(&mut built.built, &self.db)
+            .await
+            .context("attaching connector specs")?;
+
         // If there are any tests, run them now as long as there's no build errors
         let test_errors = if built.built.built_tests.len() > 0
             && !cfg!(test)
@@ -538,6 +558,34 @@ impl Publisher {
             .await
             .context("committing publication transaction")?;
         tracing::info!("successfully committed publication");
+
+        // Cached revisions of published specs are now stale.
+        // Passthrough specs were not updated, and remain valid.
+        use tables::BuiltRow;
+        let built = &uncommitted.output.built;
+        let published = (built.built_captures.iter())
+            .filter(|r| !r.is_passthrough())
+            .map(|r| r.capture.as_str())
+            .chain(
+                (built.built_collections.iter())
+                    .filter(|r| !r.is_passthrough())
+                    .map(|r| r.collection.as_str()),
+            )
+            .chain(
+                (built.built_materializations.iter())
+                    .filter(|r| !r.is_passthrough())
+                    .map(|r| r.materialization.as_str()),
+            )
+            .chain(
+                (built.built_tests.iter())
+                    .filter(|r| !r.is_passthrough())
+                    .map(|r| r.test.as_str()),
+            );
+
+        for catalog_name in published {
+            self.catalog_cache
+                .invalidate(catalog_name, uncommitted.build_id);
+        }
         Ok(uncommitted.into_result(completed_at, JobStatus::Success))
     }
 }
@@ -578,6 +626,9 @@ pub fn partitions(projections: &BTreeMap<models::Field, models::Projection>) ->
 /// not in `validation` because we're hesitant to commit to it, and may want to
 /// allow collection keys to change in the future. So this is easy and lets us
 /// continue to return the same structured errors as before.
+///
+/// A key may change if the draft declares a `keyMigration` from the live key,
+/// which `validation` verifies and builds into a re-keying plan.
 pub fn validate_collection_transitions(
     draft: &tables::DraftCatalog,
     live: &tables::LiveCatalog,
@@ -593,7 +644,12 @@ pub fn validate_collection_transitions(
                 let live_model = &live_row.model;
 
                 let mut requires_recreation = Vec::new();
-                if draft_model.key != live_model.key {
+                let key_migration = draft_model
+                    .key_migration
+                    .as_ref()
+                    .is_some_and(|migration| migration.from == live_model.key);
+
+                if draft_model.key != live_model.key && !key_migration {
                     requires_recreation.push(ReCreateReason::KeyChange);
                 }
                 if partitions(&draft_model.projections) != partitions(&live_model.projections) {
@@ -629,10 +685,10 @@ mod test {
             detail: None,
             started_at: Utc::now(),
             output: Default::default(),
-            test_errors: std::iter::once(tables::Error {
-                scope: tables::synthetic_scope("test", "test/of/a/test"),
-                error: anyhow::anyhow!("test error"),
-            })
+            test_errors: std::iter::once(tables::Error::new(
+                tables::synthetic_scope("test", "test/of/a/test"),
+                anyhow::anyhow!("test error"),
+            ))
             .collect(),
             incompatible_collections: Vec::new(),
             retry_count: 0,
@@ -14,6 +14,7 @@ pub async fn build_catalog(
     tmpdir: &path::Path,
     logs_tx: logs::Tx,
     logs_token: sqlx::types::Uuid,
+    limits: &validation::Limits,
 ) -> anyhow::Result<build::Output> {
     let log_handler = logs::ops_handler(logs_tx.clone(), "build".to_string(), logs_token);
 
@@ -40,14 +41,14 @@ pub async fn build_catalog(
             noop_captures: true,
             noop_derivations: true,
             noop_materializations: true,
-            inner: crate::ProxyConnectors::new(log_handler),
+            inner: crate::ProxyConnectors::new(log_handler.clone()),
         }
     } else {
         validation::NoOpWrapper {
             noop_captures: false,
             noop_derivations: false,
             noop_materializations: false,
-            inner: crate::ProxyConnectors::new(log_handler),
+            inner: crate::ProxyConnectors::new(log_handler.clone()),
         }
     };
 
@@ -59,8 +60,31 @@ pub async fn build_catalog(
         &draft,
         &live,
         true, // fail_fast
+        limits,
     )
     .await;
+
+    // Warnings don't fail the build, so surface them in its logs.
+    for tables::Error {
+        scope,
+        catalog_name,
+        error,
+        ..
+    } in built.warnings.iter()
+    {
+        log_handler(&proto_flow::ops::Log {
+            level: proto_flow::ops::log::Level::Warn as i32,
+            message: format!("{error:#}"),
+            fields_json_map: [
+                ("catalog_name", serde_json::to_string(catalog_name).unwrap()),
+                ("scope", serde_json::to_string(scope.as_str()).unwrap()),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+            ..Default::default()
+        });
+    }
     let output = build::Output { draft, live, built };
 
     // Persist the build before we do anything else.
@@ -198,17 +222,22 @@ pub async fn test_catalog(
             None, // Use "local" logging.
             None,
             3, // use 3 splits to try to catch shuffle errors
+            false,
+            None,
+            None,
+            None,
+            activate::NewerBuilds::default(),
         )
         .await
         .context("activating derivation for test")
         {
             tracing::error!(error = ?err, derivation = %built.catalog_name(), "failed to activate derivation in temp-data-plane");
-            errors.insert(tables::Error {
-                error: anyhow::anyhow!(
+            errors.insert(tables::Error::new(
+                url::Url::parse("flow://publication/test/activate").unwrap(),
+                anyhow::anyhow!(
                     "Test setup failed. View logs for details and reach out to support@estuary.dev"
                 ),
-                scope: url::Url::parse("flow://publication/test/activate").unwrap(),
-            });
+            ));
             // Fail fast on first activation error
             return Ok(errors);
         };
@@ -235,10 +264,10 @@ pub async fn test_catalog(
     .context("starting test runner")?;
 
     if !job.success() {
-        errors.insert(tables::Error {
-            error: anyhow::anyhow!("One or more test cases failed. View logs for details."),
-            scope: url::Url::parse("flow://publication/test/api/test").unwrap(),
-        });
+        errors.insert(tables::Error::new(
+            url::Url::parse("flow://publication/test/api/test").unwrap(),
+            anyhow::anyhow!("One or more test cases failed. View logs for details."),
+        ));
     }
 
     // Clean up derivations.
@@ -256,17 +285,22 @@ pub async fn test_catalog(
             None,
             None,
             1,
+            false,
+            None,
+            None,
+            None,
+            activate::NewerBuilds::default(),
         )
         .await
         .context("cleaning up derivation after test")
         {
             tracing::error!(?error, derivation = %built.catalog_name(), "failed to delete derivation from temp-data-plane");
-            errors.insert(tables::Error {
-                error: anyhow::anyhow!(
+            errors.insert(tables::Error::new(
+                url::Url::parse("flow://publication/test/api/delete").unwrap(),
+                anyhow::anyhow!(
                     "Test cleanup failed. View logs for details and reach out to support@estuary.dev"
                 ),
-                scope: url::Url::parse("flow://publication/test/api/delete").unwrap(),
-            });
+            ));
         }
     }
 
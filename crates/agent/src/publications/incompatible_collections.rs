@@ -125,6 +125,7 @@ mod test {
                     delta_updates: false,
                 },
             ],
+            requires_bounded_objects: false,
         };
 
         let mut validations = tables::Validations::default();
@@ -141,6 +142,10 @@ mod test {
             None,
             false,
             None,
+            None,
+            None,
+            None,
+            Vec::new(),
         );
 
         let result = get_incompatible_collections(&validations);
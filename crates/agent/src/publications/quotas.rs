@@ -112,10 +112,7 @@ pub async fn check_resource_quotas(
                     tenant.tasks_quota,
                     tenant.tasks_used,
                 );
-                errs.push(tables::Error {
-                    scope: err_scope(&tenant.name, "tasks"),
-                    error: value,
-                });
+                errs.push(tables::Error::new(err_scope(&tenant.name, "tasks"), value));
             }
             if collections_delta >= 0 && new_collections_used > tenant.collections_quota {
                 let value = anyhow::anyhow!(
@@ -125,10 +122,7 @@ pub async fn check_resource_quotas(
                     tenant.collections_quota,
                     tenant.collections_used,
                 );
-                errs.push(tables::Error {
-                    scope: err_scope(&tenant.name, "collections"),
-                    error: value,
-                });
+                errs.push(tables::Error::new(err_scope(&tenant.name, "collections"), value));
             }
             tracing::debug!(tenant = ?tenant, err_count = %errs.len(), "checked tenant quotas");
             errs
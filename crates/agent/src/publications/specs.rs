@@ -5,7 +5,7 @@ use anyhow::Context;
 use models::{split_image_tag, Id, ModelDef, SourceCapture, SourceCaptureSchemaMode};
 use serde_json::value::RawValue;
 use sqlx::types::Uuid;
-use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use tables::{BuiltRow, DraftRow};
 
 pub async fn persist_updates(
@@ -325,10 +325,10 @@ pub async fn check_source_capture_annotations(
         let Some(connector_spec) =
             agent_sql::connector_tags::fetch_connector_spec(&image_name, &image_tag, pool).await?
         else {
-            errors.insert(tables::Error {
-                scope: tables::synthetic_scope(model.catalog_type(), materialization.catalog_name()),
-                error: anyhow::anyhow!("materializations with a sourceCapture only work for known connector tags. {image} is not known to the control plane"),
-            });
+            errors.insert(tables::Error::new(
+                tables::synthetic_scope(model.catalog_type(), materialization.catalog_name()),
+                anyhow::anyhow!("materializations with a sourceCapture only work for known connector tags. {image} is not known to the control plane"),
+            ));
             continue;
         };
         if let SourceCapture::Configured(source_capture_def) = source_capture {
@@ -338,25 +338,216 @@ pub async fn check_source_capture_annotations(
 
             if source_capture_def.delta_updates && resource_spec_pointers.x_delta_updates.is_none()
             {
-                errors.insert(tables::Error {
-                    scope: tables::synthetic_scope(model.catalog_type(), materialization.catalog_name()),
-                    error: anyhow::anyhow!("sourceCapture.deltaUpdates set but the connector '{image_name}' does not support delta updates"),
-                });
+                errors.insert(tables::Error::new(
+                    tables::synthetic_scope(model.catalog_type(), materialization.catalog_name()),
+                    anyhow::anyhow!("sourceCapture.deltaUpdates set but the connector '{image_name}' does not support delta updates"),
+                ));
             }
 
             if source_capture_def.target_schema == SourceCaptureSchemaMode::FromSourceName
                 && resource_spec_pointers.x_schema_name.is_none()
             {
-                errors.insert(tables::Error {
-                    scope: tables::synthetic_scope(model.catalog_type(), materialization.catalog_name()),
-                    error: anyhow::anyhow!("sourceCapture.targetSchema set but the connector '{image_name}' does not support resource schemas"),
-                });
+                errors.insert(tables::Error::new(
+                    tables::synthetic_scope(model.catalog_type(), materialization.catalog_name()),
+                    anyhow::anyhow!("sourceCapture.targetSchema set but the connector '{image_name}' does not support resource schemas"),
+                ));
             }
         }
     }
     Ok(errors)
 }
 
+/// Validate the endpoint configs of drafted, enabled tasks against the endpoint
+/// schemas of their connector tags. Schema violations are reported with JSON
+/// pointers into the user's config, rather than as an opaque connector error.
+///
+/// Fields of a `sops` config which bear its encrypted suffix are not validated,
+/// and configs without an encrypted suffix are left to the connector entirely.
+pub async fn check_endpoint_configs(
+    draft: &tables::DraftCatalog,
+    pool: &sqlx::PgPool,
+) -> anyhow::Result<tables::Errors> {
+    let captures = draft.captures.iter().filter_map(|row| match row.model() {
+        Some(models::CaptureDef {
+            endpoint: models::CaptureEndpoint::Connector(config),
+            shards,
+            ..
+        }) if !shards.disable => Some((
+            models::CatalogType::Capture,
+            row.capture.as_str(),
+            "capture",
+            config,
+        )),
+        _ => None,
+    });
+    let derivations = draft.collections.iter().filter_map(|row| {
+        match row.model().and_then(|model| model.derive.as_ref()) {
+            Some(models::Derivation {
+                using: models::DeriveUsing::Connector(config),
+                shards,
+                ..
+            }) if !shards.disable => Some((
+                models::CatalogType::Collection,
+                row.collection.as_str(),
+                "derivation",
+                config,
+            )),
+            _ => None,
+        }
+    });
+    let materializations = draft
+        .materializations
+        .iter()
+        .filter_map(|row| match row.model() {
+            Some(models::MaterializationDef {
+                endpoint: models::MaterializationEndpoint::Connector(config),
+                shards,
+                ..
+            }) if !shards.disable => Some((
+                models::CatalogType::Materialization,
+                row.materialization.as_str(),
+                "materialization",
+                config,
+            )),
+            _ => None,
+        });
+
+    let mut validators: BTreeMap<String, Option<doc::Validator>> = BTreeMap::new();
+    let mut errors = tables::Errors::default();
+
+    for (catalog_type, catalog_name, entity, config) in
+        captures.chain(derivations).chain(materializations)
+    {
+        if !validators.contains_key(&config.image) {
+            let (image_name, image_tag) = split_image_tag(&config.image);
+            let spec =
+                agent_sql::connector_tags::fetch_connector_spec(&image_name, &image_tag, pool)
+                    .await?;
+
+            let validator = spec.and_then(|spec| {
+                match endpoint_config_validator(spec.endpoint_config_schema.0.get()) {
+                    Ok(validator) => Some(validator),
+                    Err(err) => {
+                        tracing::warn!(image = %config.image, ?err, "failed to build endpoint config schema");
+                        None
+                    }
+                }
+            });
+            validators.insert(config.image.clone(), validator);
+        }
+        let Some(validator) = validators.get_mut(&config.image).unwrap() else {
+            continue;
+        };
+
+        for (ptr, error) in endpoint_config_errors(validator, &config.config)? {
+            errors.insert(tables::Error::new(
+                tables::synthetic_scope(catalog_type, catalog_name),
+                anyhow::anyhow!("{entity} {catalog_name} endpoint config at {ptr:?} is invalid against the connector's configuration schema: {error}"),
+            ));
+        }
+    }
+    Ok(errors)
+}
+
+fn endpoint_config_validator(schema: &str) -> anyhow::Result<doc::Validator> {
+    let schema = doc::validation::build_bundle(schema)?;
+    Ok(doc::Validator::new(schema)?)
+}
+
+// Validate `config` with `validator`, returning the (pointer, error) of the first
+// error at each distinct location of the config. Encrypted fields are skipped.
+fn endpoint_config_errors(
+    validator: &mut doc::Validator,
+    config: &models::RawValue,
+) -> anyhow::Result<Vec<(String, String)>> {
+    let mut config = config.to_value();
+
+    let Some(encrypted) = strip_sops_document(&mut config) else {
+        return Ok(Vec::new());
+    };
+    let Err(failed) = validator.validate(None, &config)?.ok() else {
+        return Ok(Vec::new());
+    };
+
+    let mut reported = BTreeSet::new();
+    let mut out = Vec::new();
+
+    for entry in failed.basic_output["errors"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        let ptr = entry["instanceLocation"].as_str().unwrap_or_default();
+
+        let is_encrypted = encrypted
+            .iter()
+            .any(|enc| ptr == enc || ptr.starts_with(&format!("{enc}/")));
+
+        if is_encrypted || !reported.insert(ptr.to_string()) {
+            continue;
+        }
+        let error = entry["error"].as_str().unwrap_or_default();
+        out.push((ptr.to_string(), error.to_string()));
+    }
+    Ok(out)
+}
+
+// Remove the `sops` stanza of a `sops` document, and trim the encrypted suffix
+// from its encrypted properties so that they satisfy `required` keywords.
+// Returns the pointers of encrypted properties, or None if every value is
+// encrypted because the document has no encrypted suffix.
+fn strip_sops_document(doc: &mut serde_json::Value) -> Option<Vec<String>> {
+    let Some(sops) = doc.as_object_mut().and_then(|m| m.remove("sops")) else {
+        return Some(Vec::new());
+    };
+    let suffix = sops.get("encrypted_suffix")?.as_str()?.to_string();
+
+    fn walk(
+        doc: &mut serde_json::Value,
+        ptr: &mut doc::Pointer,
+        suffix: &str,
+        out: &mut Vec<String>,
+    ) {
+        match doc {
+            serde_json::Value::Object(map) => {
+                let keys: Vec<String> = map.keys().cloned().collect();
+
+                for key in keys {
+                    let mut value = map.remove(&key).unwrap();
+
+                    let prop = match key.strip_suffix(suffix) {
+                        Some(prop) => {
+                            ptr.push(doc::ptr::Token::Property(prop.to_string()));
+                            out.push(ptr.to_string());
+                            ptr.0.pop();
+                            prop.to_string()
+                        }
+                        None => {
+                            ptr.push(doc::ptr::Token::Property(key.clone()));
+                            walk(&mut value, ptr, suffix, out);
+                            ptr.0.pop();
+                            key
+                        }
+                    };
+                    map.insert(prop, value);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for (index, item) in items.iter_mut().enumerate() {
+                    ptr.push(doc::ptr::Token::Index(index));
+                    walk(item, ptr, suffix, out);
+                    ptr.0.pop();
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(doc, &mut doc::Pointer::empty(), &suffix, &mut out);
+    Some(out)
+}
+
 pub async fn check_connector_images(
     draft: &tables::DraftCatalog,
     pool: &sqlx::PgPool,
@@ -424,10 +615,10 @@ async fn check_connector_image(
         cached.insert(image_name.clone(), exists);
     }
     if !cached[&image_name] {
-        Ok(Some(tables::Error {
-            scope: tables::synthetic_scope(model.catalog_type(), catalog_name),
-            error: anyhow::anyhow!("Forbidden connector image '{image_name}'"),
-        }))
+        Ok(Some(tables::Error::new(
+            tables::synthetic_scope(model.catalog_type(), catalog_name),
+            anyhow::anyhow!("Forbidden connector image '{image_name}'"),
+        )))
     } else {
         Ok(None)
     }
@@ -663,6 +854,7 @@ pub async fn resolve_live_specs(
     user_id: Uuid,
     draft: &tables::DraftCatalog,
     db: &sqlx::PgPool,
+    cache: &tables::CatalogCache,
     default_data_plane_name: &str,
     verify_user_authz: bool,
 ) -> anyhow::Result<tables::LiveCatalog> {
@@ -691,9 +883,26 @@ pub async fn resolve_live_specs(
         }
     }
 
-    let rows = agent_sql::live_specs::fetch_live_specs(user_id, &all_spec_names, db)
-        .await
-        .context("fetching live specs")?;
+    // Hold specs which are already cached, so that rows which remain at their
+    // cached builds needn't fetch their `spec` and `built_spec` columns.
+    let mut cached: HashMap<&str, tables::CachedSpec> = all_spec_names
+        .iter()
+        .filter_map(|name| Some((name.as_str(), cache.get_any(name)?)))
+        .collect();
+    let (cached_names, cached_build_ids): (Vec<String>, Vec<Id>) = cached
+        .iter()
+        .map(|(name, spec)| (name.to_string(), spec.revision()))
+        .unzip();
+
+    let rows = agent_sql::live_specs::fetch_live_specs_with_cached(
+        user_id,
+        &all_spec_names,
+        &cached_names,
+        &cached_build_ids,
+        db,
+    )
+    .await
+    .context("fetching live specs")?;
 
     // Check the user and spec authorizations.
     // Start by making an easy way to lookup whether each row was drafted or not.
@@ -716,12 +925,10 @@ pub async fn resolve_live_specs(
 
             // If the spec is included in the draft, then the user must have admin capability to it.
             if verify_user_authz && !matches!(spec_row.user_capability, Some(Capability::Admin)) {
-                live.errors.push(tables::Error {
-                    scope: scope.clone(),
-                    error: anyhow::anyhow!(
-                        "User is not authorized to create or change this catalog name"
-                    ),
-                });
+                live.errors.push(tables::Error::new(
+                    scope.clone(),
+                    anyhow::anyhow!("User is not authorized to create or change this catalog name"),
+                ));
                 // Continue because we'll otherwise produce superfluous auth errors
                 // of referenced collections.
                 continue;
@@ -731,13 +938,13 @@ pub async fn resolve_live_specs(
                 if !spec_row.spec_capabilities.iter().any(|c| {
                     source.starts_with(c.object_role.as_str()) && c.capability >= Capability::Read
                 }) {
-                    live.errors.push(tables::Error {
-                        scope: scope.clone(),
-                        error: anyhow::anyhow!(
+                    live.errors.push(tables::Error::new(
+                        scope.clone(),
+                        anyhow::anyhow!(
                             "Specification '{catalog_name}' is not read-authorized to '{source}'.\nAvailable grants are: {}",
                             serde_json::to_string_pretty(&spec_row.spec_capabilities.0).unwrap(),
                         ),
-                    });
+                    ));
                 }
             }
             for target in writes_to {
@@ -745,13 +952,13 @@ pub async fn resolve_live_specs(
                     target.starts_with(c.object_role.as_str())
                         && matches!(c.capability, Capability::Write | Capability::Admin)
                 }) {
-                    live.errors.push(tables::Error {
-                        scope: scope.clone(),
-                        error: anyhow::anyhow!(
+                    live.errors.push(tables::Error::new(
+                        scope.clone(),
+                        anyhow::anyhow!(
                             "Specification is not write-authorized to '{target}'.\nAvailable grants are: {}",
                             serde_json::to_string_pretty(&spec_row.spec_capabilities.0).unwrap(),
                         ),
-                    });
+                    ));
                 }
             }
         // Ops collections are automatically injected, and the user does not need (or have) any
@@ -768,10 +975,10 @@ pub async fn resolve_live_specs(
                 .unwrap_or(false)
             {
                 let scope = tables::synthetic_scope("unauthorized", &spec_row.catalog_name);
-                live.errors.push(tables::Error {
+                live.errors.push(tables::Error::new(
                     scope,
-                    error: anyhow::anyhow!("User is not authorized to read this catalog name"),
-                });
+                    anyhow::anyhow!("User is not authorized to read this catalog name"),
+                ));
                 continue;
             }
         }
@@ -782,7 +989,14 @@ pub async fn resolve_live_specs(
             continue;
         }
 
-        if let Some(model) = spec_row.spec.as_ref() {
+        // Specs cached at their current build are used as-is,
+        // sparing their fetch and deserialization.
+        if let Some(cached) = cached
+            .remove(catalog_name)
+            .filter(|cached| cached.revision() == spec_row.last_build_id)
+        {
+            live.add_cached(cached);
+        } else if let Some(model) = spec_row.spec.as_ref() {
             let catalog_type: models::CatalogType = spec_row.spec_type.unwrap().into();
             live.add_spec(
                 catalog_type,
@@ -805,6 +1019,7 @@ pub async fn resolve_live_specs(
 
         data_plane_ids.push(spec_row.data_plane_id);
     }
+    cache.insert_live(&live);
 
     // Note that we don't need storage mappings for live specs, only the drafted ones.
     let mut tenant_names = drafted_names
@@ -814,18 +1029,26 @@ pub async fn resolve_live_specs(
     tenant_names.sort();
     tenant_names.dedup();
 
+    for row in agent_sql::publications::resolve_tenants(tenant_names.clone(), db).await? {
+        live.tenants
+            .insert_row(models::Prefix::new(row.tenant), row.id, row.strict_schemas);
+    }
+
     let storage_rows = agent_sql::publications::resolve_storage_mappings(tenant_names, db).await?;
+    let mut admissible_data_planes = BTreeMap::new();
+
     for row in storage_rows {
         let store: models::StorageDef = match serde_json::from_value(row.spec) {
             Ok(s) => s,
             Err(err) => {
-                live.errors.push(tables::Error {
-                    scope: tables::synthetic_scope("storageMapping", &row.catalog_prefix),
-                    error: anyhow::Error::from(err).context("deserializing storage mapping spec"),
-                });
+                live.errors.push(tables::Error::new(
+                    tables::synthetic_scope("storageMapping", &row.catalog_prefix),
+                    anyhow::Error::from(err).context("deserializing storage mapping spec"),
+                ));
                 continue;
             }
         };
+        admissible_data_planes.insert(row.catalog_prefix.clone(), store.data_planes);
         live.storage_mappings.insert(tables::StorageMapping {
             control_id: row.id.into(),
             catalog_prefix: models::Prefix::new(row.catalog_prefix),
@@ -833,6 +1056,33 @@ pub async fn resolve_live_specs(
         });
     }
 
+    // Compose the Prefix of each tenant from its storage mapping
+    // and the mapping of its recovery logs.
+    let prefixes: BTreeSet<&str> = live
+        .storage_mappings
+        .iter()
+        .map(|m| {
+            m.catalog_prefix
+                .strip_prefix("recovery/")
+                .unwrap_or(m.catalog_prefix.as_str())
+        })
+        .collect();
+
+    for prefix in prefixes {
+        let partition = live.storage_mappings.get_key(&models::Prefix::new(prefix));
+        let recovery = live
+            .storage_mappings
+            .get_key(&models::Prefix::new(format!("recovery/{prefix}")));
+
+        live.prefixes.insert_row(
+            models::Prefix::new(prefix),
+            partition.or(recovery).unwrap().control_id,
+            partition.map(|m| m.stores.clone()).unwrap_or_default(),
+            recovery.map(|m| m.stores.clone()).unwrap_or_default(),
+            admissible_data_planes.remove(prefix).unwrap_or_default(),
+        );
+    }
+
     live.data_planes = agent_sql::data_plane::fetch_data_planes(
         db,
         data_plane_ids,
@@ -843,6 +1093,7 @@ pub async fn resolve_live_specs(
 
     // TODO(phil): remove once we no longer need to inline inferred schemas as part of validation
     resolve_inferred_schemas(draft, &mut live, db).await?;
+    resolve_collection_throughputs(draft, &mut live, db).await?;
 
     Ok(live)
 }
@@ -886,6 +1137,48 @@ async fn resolve_inferred_schemas(
     Ok(())
 }
 
+/// Resolves the historical throughput of collections read by drafted
+/// derivations and materializations, from which validation recommends
+/// initial splits of new tasks.
+async fn resolve_collection_throughputs(
+    draft: &tables::DraftCatalog,
+    live: &mut tables::LiveCatalog,
+    db: &sqlx::PgPool,
+) -> anyhow::Result<()> {
+    let derivation_sources = draft
+        .collections
+        .iter()
+        .filter_map(|r| r.model.as_ref()?.derive.as_ref())
+        .flat_map(|derive| derive.transforms.iter().map(|t| t.source.collection()));
+    let materialization_sources = draft
+        .materializations
+        .iter()
+        .filter_map(|r| r.model.as_ref())
+        .flat_map(|model| model.bindings.iter().map(|b| b.source.collection()));
+
+    let collection_names = derivation_sources
+        .chain(materialization_sources)
+        .map(|c| c.as_str())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if collection_names.is_empty() {
+        return Ok(());
+    }
+    let rows = agent_sql::live_specs::fetch_collection_throughputs(&collection_names, db).await?;
+
+    for agent_sql::live_specs::CollectionThroughputRow {
+        collection_name,
+        bytes_per_hour,
+    } in rows
+    {
+        live.collection_throughputs
+            .insert_row(models::Collection::new(collection_name), bytes_per_hour);
+    }
+    Ok(())
+}
+
 fn spec_meta(
     draft: &tables::DraftCatalog,
     catalog_name: &str,
@@ -929,6 +1222,107 @@ fn spec_meta(
     panic!("draft is missing spec for '{catalog_name}'");
 }
 
+/// Attach the documentation URL and OAuth2 specification of each connector image,
+/// as recorded by its `connector_tags` row, to the built tasks which use it.
+/// Each such image is also recorded as a ConnectorSpec of the build, as the
+/// data-plane connector proxy doesn't serve Spec requests during validation.
+pub async fn attach_connector_specs(
+    built: &mut tables::Validations,
+    pool: &sqlx::PgPool,
+) -> anyhow::Result<()> {
+    // Map each distinct image to the type of the first task which uses it.
+    let mut images: BTreeMap<String, models::CatalogType> = BTreeMap::new();
+
+    for image in built
+        .built_captures
+        .iter()
+        .filter_map(|r| r.model().and_then(ModelDef::connector_image))
+    {
+        images.entry(image).or_insert(models::CatalogType::Capture);
+    }
+    for image in built
+        .built_collections
+        .iter()
+        .filter_map(|r| r.model().and_then(ModelDef::connector_image))
+    {
+        images
+            .entry(image)
+            .or_insert(models::CatalogType::Collection);
+    }
+    for image in built
+        .built_materializations
+        .iter()
+        .filter_map(|r| r.model().and_then(ModelDef::connector_image))
+    {
+        images
+            .entry(image)
+            .or_insert(models::CatalogType::Materialization);
+    }
+
+    let mut docs = BTreeMap::new();
+    for (image, catalog_type) in images {
+        let (image_name, image_tag) = split_image_tag(&image);
+        let Some(spec) =
+            agent_sql::connector_tags::fetch_connector_spec(&image_name, &image_tag, pool).await?
+        else {
+            continue;
+        };
+        let parsed = connector_docs(
+            &spec.documentation_url,
+            spec.oauth2.as_ref().map(|oauth2| oauth2.0.get()),
+        )
+        .with_context(|| format!("parsing OAuth2 spec of {image}"))?;
+
+        if built.connector_specs.get_key(&image).is_none() {
+            built.connector_specs.insert_row(
+                &image,
+                catalog_type,
+                0u32, // `connector_tags` doesn't record the protocol version.
+                models::RawValue::from(spec.endpoint_config_schema.0),
+                models::RawValue::from(spec.resource_config_schema.0),
+                spec.documentation_url,
+                parsed.1.clone(),
+            );
+        }
+        docs.insert(image, parsed);
+    }
+
+    for r in built.built_captures.iter_mut() {
+        let image = r.model().and_then(ModelDef::connector_image);
+        if let Some((url, oauth2)) = image.and_then(|image| docs.get(&image)) {
+            (r.documentation_url, r.oauth2) = (url.clone(), oauth2.clone());
+        }
+    }
+    for r in built.built_collections.iter_mut() {
+        let image = r.model().and_then(ModelDef::connector_image);
+        if let Some((url, oauth2)) = image.and_then(|image| docs.get(&image)) {
+            (r.documentation_url, r.oauth2) = (url.clone(), oauth2.clone());
+        }
+    }
+    for r in built.built_materializations.iter_mut() {
+        let image = r.model().and_then(ModelDef::connector_image);
+        if let Some((url, oauth2)) = image.and_then(|image| docs.get(&image)) {
+            (r.documentation_url, r.oauth2) = (url.clone(), oauth2.clone());
+        }
+    }
+    Ok(())
+}
+
+// Map the `documentation_url` and `oauth2_spec` of a connector tag into
+// their built representations. An empty URL is no URL at all.
+fn connector_docs(
+    documentation_url: &str,
+    oauth2: Option<&str>,
+) -> anyhow::Result<(Option<String>, Option<proto_flow::flow::OAuth2>)> {
+    let url = (!documentation_url.is_empty()).then(|| documentation_url.to_string());
+    let oauth2 = oauth2
+        .filter(|oauth2| *oauth2 != "null")
+        .map(serde_json::from_str)
+        .transpose()?;
+
+    Ok((url, oauth2))
+}
+
 // add_built_specs_to_draft_specs adds the built spec and validated response to the draft_specs row
 // for all tasks included in build_output if they are in the list of specifications which are
 // changing in this publication per the list of spec_rows.
@@ -946,6 +1340,8 @@ pub async fn add_built_specs_to_draft_specs(
                 collection.catalog_name().as_str(),
                 &collection.spec,
                 collection.validated(),
+                collection.documentation_url.as_deref(),
+                collection.oauth2.as_ref(),
                 db,
             )
             .await?;
@@ -959,6 +1355,8 @@ pub async fn add_built_specs_to_draft_specs(
                 capture.catalog_name().as_str(),
                 &capture.spec,
                 capture.validated(),
+                capture.documentation_url.as_deref(),
+                capture.oauth2.as_ref(),
                 db,
             )
             .await?;
@@ -972,6 +1370,8 @@ pub async fn add_built_specs_to_draft_specs(
                 materialization.catalog_name().as_str(),
                 &materialization.spec,
                 materialization.validated(),
+                materialization.documentation_url.as_deref(),
+                materialization.oauth2.as_ref(),
                 db,
             )
             .await?;
@@ -985,6 +1385,8 @@ pub async fn add_built_specs_to_draft_specs(
                 test.catalog_name().as_str(),
                 &test.spec,
                 test.validated(),
+                None,
+                None,
                 db,
             )
             .await?;
@@ -998,6 +1400,102 @@ pub async fn add_built_specs_to_draft_specs(
 mod test {
     use super::*;
 
+    #[test]
+    fn test_connector_docs() {
+        let oauth2 = serde_json::json!({
+            "provider": "google",
+            "authUrlTemplate": "https://accounts.google.com/o/oauth2/auth?client_id={{ client_id }}",
+            "accessTokenUrlTemplate": "https://oauth2.googleapis.com/token",
+            "accessTokenResponseMap": {"refresh_token": "/refresh_token"},
+        })
+        .to_string();
+
+        let (url, parsed) =
+            connector_docs("https://go.estuary.dev/source-gcs", Some(&oauth2)).unwrap();
+        assert_eq!(url.as_deref(), Some("https://go.estuary.dev/source-gcs"));
+
+        let parsed = parsed.unwrap();
+        assert_eq!(parsed.provider, "google");
+        assert_eq!(
+            parsed.access_token_url_template,
+            "https://oauth2.googleapis.com/token"
+        );
+        assert_eq!(
+            parsed.access_token_response_json_map.get("refresh_token"),
+            Some(&r#""/refresh_token""#.to_string())
+        );
+
+        // An empty URL and an absent or null OAuth2 spec map to None.
+        assert_eq!(connector_docs("", None).unwrap(), (None, None));
+        assert_eq!(connector_docs("", Some("null")).unwrap(), (None, None));
+        // A malformed OAuth2 spec is an error.
+        assert!(connector_docs("", Some(r#"{"provider": 42}"#)).is_err());
+    }
+
+    #[test]
+    fn test_endpoint_config_errors() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "host": {"type": "string"},
+                "port": {"type": "integer"},
+                "password": {"type": "string", "minLength": 100},
+                "tunnel": {
+                    "type": "object",
+                    "properties": {"key": {"type": "string", "pattern": "^-----"}},
+                    "required": ["key"],
+                },
+            },
+            "required": ["host", "password"],
+        });
+        let mut validator = endpoint_config_validator(&schema.to_string()).unwrap();
+
+        let mut sops = serde_json::json!({
+            "host": 42,
+            "password_sops": "ENC[AES256_GCM,data:abc]",
+            "tunnel": {"key_sops": "ENC[AES256_GCM,data:def]"},
+            "sops": {"encrypted_suffix": "_sops"},
+        });
+        let cases = [
+            // Every violation of a plain config is reported.
+            serde_json::json!({"port": "nope", "password": "short"}),
+            // Encrypted fields satisfy `required`, but aren't otherwise validated.
+            sops.clone(),
+            // Without an encrypted suffix, every value of a `sops` config is encrypted.
+            serde_json::json!({"host": "ENC[AES256_GCM,data:ghi]", "sops": {"kms": []}}),
+        ];
+
+        let outcomes = cases
+            .iter()
+            .map(|config| {
+                let config = models::RawValue::from_value(config);
+                endpoint_config_errors(&mut validator, &config)
+                    .unwrap()
+                    .into_iter()
+                    .map(|(ptr, _error)| ptr)
+                    .collect::<BTreeSet<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                BTreeSet::from(["".to_string(), "/password".to_string(), "/port".to_string()]),
+                BTreeSet::from(["/host".to_string()]),
+                BTreeSet::new(),
+            ]
+        );
+
+        assert_eq!(
+            strip_sops_document(&mut sops),
+            Some(vec!["/password".to_string(), "/tunnel/key".to_string()])
+        );
+        assert_eq!(
+            sops,
+            serde_json::json!({"host": 42, "password": "ENC[AES256_GCM,data:abc]", "tunnel": {"key": "ENC[AES256_GCM,data:def]"}})
+        );
+    }
+
     #[test]
     fn test_null_bytes_in_json() {
         let bad = vec![
@@ -112,12 +112,15 @@ pub fn inference_truncation_indicator() -> flow::Inference {
 
 // partition_template returns a template JournalSpec for creating
 // or updating data partitions of the collection.
+// Partitions of a `frozen` collection are read-only.
 pub fn partition_template(
     build_id: models::Id,
     collection: &models::Collection,
     journal_name_prefix: &str,
     journals: &models::JournalTemplate,
+    frozen: bool,
     stores: &[models::Store],
+    annotations: &models::Annotations,
 ) -> broker::JournalSpec {
     let models::JournalTemplate {
         fragments:
@@ -126,11 +129,15 @@ pub fn partition_template(
                 flush_interval,
                 length,
                 retention,
+                path_postfix_template,
             },
+        replication,
+        max_append_rate,
+        zones,
     } = journals.clone();
 
-    // Until there's a good reason otherwise, we hard-code that partition journals are replicated 3x.
-    let replication = 3;
+    // If an explicit replication factor isn't provided, default to 3x.
+    let replication = replication.unwrap_or(3) as i32;
 
     // Use a supplied compression codec. Or, if none, then default to gzip.
     let compression_codec = compression_codec(codec.unwrap_or(models::CompressionCodec::Gzip));
@@ -143,10 +150,12 @@ pub fn partition_template(
     // If a fragment length isn't set, default and then map MB to bytes.
     let length = (length.unwrap_or(512) as i64) << 20;
 
-    // Until there's a good reason otherwise, we hard-code that fragments include the UTC date
+    // If an explicit path postfix template isn't provided, fragments include the UTC date
     // and hour they were created as components of their path. This makes it easy to filter
     // collections on time when making ad-hoc queries using the Hive partitioning scheme.
-    let path_postfix_template = r#"utc_date={{.Spool.FirstAppendTime.Format "2006-01-02"}}/utc_hour={{.Spool.FirstAppendTime.Format "15"}}"#.to_string();
+    let path_postfix_template = path_postfix_template.unwrap_or_else(|| {
+        r#"utc_date={{.Spool.FirstAppendTime.Format "2006-01-02"}}/utc_hour={{.Spool.FirstAppendTime.Format "15"}}"#.to_string()
+    });
 
     // Until there's a good reason otherwise, we hard-code that fragments are refreshed every five minutes.
     let refresh_interval = Some(Duration::from_secs(5 * 60).into());
@@ -154,13 +163,18 @@ pub fn partition_template(
     // If an explicit retention interval isn't provided, then don't set one.
     let retention = retention.map(Into::into);
 
-    // Partition journals are readable and writable.
-    let flags = broker::journal_spec::Flag::ORdwr as u32;
+    // Partition journals are readable and writable, unless frozen.
+    let flags = if frozen {
+        broker::journal_spec::Flag::ORdonly as u32
+    } else {
+        broker::journal_spec::Flag::ORdwr as u32
+    };
 
-    // We hard-code max_append_rate to 4MB/s, which back-pressures captures
-    // and derivations that produce lots of documents. They'll perform more
-    // aggregation per-transaction, and may stall until there's quota.
-    let max_append_rate = 1 << 22; // 4MB.
+    // If an explicit max_append_rate isn't provided, default to 4MB/s.
+    // This back-pressures captures and derivations that produce lots of
+    // documents. They'll perform more aggregation per-transaction,
+    // and may stall until there's quota.
+    let max_append_rate = max_append_rate.unwrap_or(1 << 22) as i64; // 4MB.
 
     let labels = labels::build_set([
         (labels::BUILD, build_id.to_string().as_str()),
@@ -168,6 +182,9 @@ pub fn partition_template(
         (labels::CONTENT_TYPE, labels::CONTENT_TYPE_JSON_LINES),
         (labels::MANAGED_BY, labels::MANAGED_BY_FLOW),
     ]);
+    let labels = annotation_labels(labels, annotations);
+    let labels = zone_labels(labels, &zones);
+    let labels = encryption_labels(labels, stores);
 
     broker::JournalSpec {
         name: journal_name_prefix.to_string(),
@@ -241,6 +258,7 @@ pub fn recovery_log_template(
         (labels::TASK_NAME, task_name),
         (labels::TASK_TYPE, &task_type.to_string()),
     ]);
+    let labels = encryption_labels(labels, stores);
 
     broker::JournalSpec {
         name: format!("recovery/{shard_id_prefix}"),
@@ -277,6 +295,23 @@ pub fn shard_id_prefix(pub_id: models::Id, task_name: &str, task_type: &str) ->
     format!("{task_type}/{task_name}/{pub_id}")
 }
 
+// extract_generation_id_suffix returns the generation ID which suffixes
+// a partition template name or shard ID prefix. Generation IDs are the
+// publication IDs which first created a collection or task, and a new
+// generation is created when a collection is reset or a task is re-created.
+pub fn extract_generation_id_suffix(prefix: &str) -> Option<models::Id> {
+    let (_, suffix) = prefix.rsplit_once('/')?;
+
+    if suffix.len() != 16 || !suffix.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    models::Id::from_hex(suffix).ok()
+}
+
+/// Etcd prefix under which shard recovery log hints are stored,
+/// and beneath which any task-specific hint prefix must be nested.
+pub const DEFAULT_HINT_PREFIX: &str = "/estuary/flow/hints";
+
 // shard_template returns a template ShardSpec for creating or updating
 // shards of the task.
 pub fn shard_template(
@@ -287,23 +322,30 @@ pub fn shard_template(
     shard_id_prefix: &str,
     disable_wait_for_ack: bool,
     ports: &[flow::NetworkPort],
+    annotations: &models::Annotations,
 ) -> consumer::ShardSpec {
     let models::ShardTemplate {
         disable,
+        hint_backups,
+        hint_prefix,
         hot_standbys,
         max_txn_duration,
         min_txn_duration,
         read_channel_size,
         ring_buffer_size,
         log_level,
+        zones,
+        resources,
     } = shard;
 
     // We hard-code that recovery logs always have prefix "recovery".
     let recovery_log_prefix = "recovery".to_string();
-    // We hard-code that hints are stored under this Etcd prefix.
-    let hint_prefix = "/estuary/flow/hints".to_string();
-    // We hard-code two hint backups per shard.
-    let hint_backups = 2;
+    // By default, hints are stored under this Etcd prefix.
+    let hint_prefix = hint_prefix
+        .clone()
+        .unwrap_or_else(|| DEFAULT_HINT_PREFIX.to_string());
+    // By default, we keep two hint backups per shard.
+    let hint_backups = hint_backups.unwrap_or(2) as i32;
 
     // If not set, the maximum transaction duration is five minutes
     // for materializations and one second for captures and derivations.
@@ -317,6 +359,8 @@ pub fn shard_template(
     // By default, there is no minimum duration.
     let min_txn_duration = min_txn_duration.unwrap_or(Duration::ZERO);
 
+    // Validation rejects an explicit maximum which is less than the minimum,
+    // but a minimum may exceed the default maximum, which is raised to match.
     if min_txn_duration > max_txn_duration {
         max_txn_duration = min_txn_duration;
     }
@@ -370,6 +414,20 @@ pub fn shard_template(
         }
     }
 
+    labels = annotation_labels(labels, annotations);
+    labels = zone_labels(labels, zones);
+
+    // Resource limits were validated, and are normalized into
+    // millicores and bytes which reactors apply as cgroup limits.
+    if let Some(resources) = resources {
+        if let Ok(Some(cpu)) = resources.cpu_millis() {
+            labels = labels::set_value(labels, labels::CPU_LIMIT, &cpu.to_string());
+        }
+        if let Ok(Some(memory)) = resources.memory_bytes() {
+            labels = labels::set_value(labels, labels::MEMORY_LIMIT, &memory.to_string());
+        }
+    }
+
     consumer::ShardSpec {
         id: shard_id_prefix.to_string(),
         disable: *disable,
@@ -387,6 +445,48 @@ pub fn shard_template(
     }
 }
 
+// annotation_labels adds the owner and labels of `annotations` to `set`.
+// Descriptions are free-form and aren't surfaced as labels.
+fn annotation_labels(
+    mut set: broker::LabelSet,
+    annotations: &models::Annotations,
+) -> broker::LabelSet {
+    let models::Annotations {
+        owner,
+        description: _,
+        labels: annotated,
+    } = annotations;
+
+    if let Some(owner) = owner {
+        set = labels::set_value(set, labels::OWNER, owner);
+    }
+    for (name, value) in annotated {
+        set = labels::set_value(set, &format!("{}{name}", labels::ANNOTATION_PREFIX), value);
+    }
+    set
+}
+
+// zone_labels adds the declared `zones` to `set`.
+// Labels are a multiset, so each zone is a value of the same label.
+fn zone_labels(mut set: broker::LabelSet, zones: &[String]) -> broker::LabelSet {
+    for zone in zones {
+        set = labels::add_value(set, labels::ZONE, zone);
+    }
+    set
+}
+
+// encryption_labels adds the encryption provider of the first of `stores`,
+// to which new fragments are written. Stores encode their encryption
+// into fragment store URLs, and the label makes it visible to operators.
+fn encryption_labels(set: broker::LabelSet, stores: &[models::Store]) -> broker::LabelSet {
+    match stores.first().and_then(models::Store::encryption) {
+        Some(encryption) => {
+            labels::set_value(set, labels::ENCRYPTION, encryption.provider.as_str())
+        }
+        None => set,
+    }
+}
+
 /// This function supplies a domain name label that identifies _all_ shards for a given task.
 /// To do this, we just hash the task name and convert it to a hexidecimal string.
 /// It's a bit janky, but the only idea I've liked better is pet-names, which we
@@ -505,6 +605,27 @@ mod test {
     use serde_json::{json, Value};
     use std::collections::BTreeMap;
 
+    #[test]
+    fn test_extract_generation_id_suffix() {
+        let id = models::Id::new([1, 2, 3, 4, 5, 6, 7, 8]);
+
+        assert_eq!(
+            extract_generation_id_suffix(&shard_id_prefix(
+                id,
+                "acme/task",
+                labels::TASK_TYPE_CAPTURE
+            )),
+            Some(id)
+        );
+        assert_eq!(
+            extract_generation_id_suffix("acme/collection/0102030405060708"),
+            Some(id)
+        );
+        assert_eq!(extract_generation_id_suffix("acme/collection"), None);
+        assert_eq!(extract_generation_id_suffix("0102030405060708"), None);
+        assert_eq!(extract_generation_id_suffix("acme/01020304050607zz"), None);
+    }
+
     #[test]
     fn test_inference() {
         let mut shape = Shape {
@@ -560,6 +681,166 @@ mod test {
         insta::assert_debug_snapshot!(&[out1, out2, out3, out4]);
     }
 
+    #[test]
+    fn test_annotation_labels() {
+        let annotations = models::Annotations {
+            owner: Some("data-platform".to_string()),
+            description: Some("Not surfaced as a label".to_string()),
+            labels: [("cost-center".to_string(), "cc-1234".to_string())].into(),
+        };
+        let spec = shard_template(
+            models::Id::zero(),
+            "acmeCo/task",
+            labels::TASK_TYPE_CAPTURE,
+            &models::ShardTemplate::default(),
+            "capture/acmeCo/task/0000000000000000",
+            false,
+            &[],
+            &annotations,
+        );
+        let set = spec.labels.unwrap();
+
+        assert_eq!(
+            labels::expect_one(&set, labels::OWNER).unwrap(),
+            "data-platform"
+        );
+        assert_eq!(
+            labels::expect_one(&set, "estuary.dev/annotation/cost-center").unwrap(),
+            "cc-1234"
+        );
+        assert!(!set.labels.iter().any(|l| l.value.contains("surfaced")));
+    }
+
+    #[test]
+    fn test_resource_limit_labels() {
+        let shard = models::ShardTemplate {
+            resources: Some(models::ShardResources {
+                cpu: Some("1.5".to_string()),
+                memory: Some("512Mi".to_string()),
+            }),
+            ..Default::default()
+        };
+        let spec = shard_template(
+            models::Id::zero(),
+            "acmeCo/task",
+            labels::TASK_TYPE_CAPTURE,
+            &shard,
+            "capture/acmeCo/task/0000000000000000",
+            false,
+            &[],
+            &models::Annotations::default(),
+        );
+        let set = spec.labels.unwrap();
+
+        assert_eq!(labels::values(&set, labels::CPU_LIMIT)[0].value, "1500");
+        assert_eq!(
+            labels::values(&set, labels::MEMORY_LIMIT)[0].value,
+            "536870912"
+        );
+
+        // Limits which aren't set are left to the data-plane's defaults.
+        let shard = models::ShardTemplate {
+            resources: Some(models::ShardResources {
+                cpu: Some("250m".to_string()),
+                memory: None,
+            }),
+            ..Default::default()
+        };
+        let spec = shard_template(
+            models::Id::zero(),
+            "acmeCo/task",
+            labels::TASK_TYPE_CAPTURE,
+            &shard,
+            "capture/acmeCo/task/0000000000000000",
+            false,
+            &[],
+            &models::Annotations::default(),
+        );
+        let set = spec.labels.unwrap();
+
+        assert_eq!(labels::values(&set, labels::CPU_LIMIT)[0].value, "250");
+        assert!(labels::values(&set, labels::MEMORY_LIMIT).is_empty());
+    }
+
+    #[test]
+    fn test_zone_labels() {
+        let spec = partition_template(
+            models::Id::zero(),
+            &models::Collection::new("acmeCo/collection"),
+            "acmeCo/collection/0000000000000000",
+            &models::JournalTemplate {
+                zones: vec!["us-east-1b".to_string(), "us-east-1a".to_string()],
+                ..Default::default()
+            },
+            false,
+            &[],
+            &models::Annotations::default(),
+        );
+        let set = spec.labels.unwrap();
+
+        assert_eq!(
+            labels::values(&set, labels::ZONE)
+                .iter()
+                .map(|l| l.value.as_str())
+                .collect::<Vec<_>>(),
+            vec!["us-east-1a", "us-east-1b"],
+        );
+
+        // Without zones, there's no constraint.
+        let spec = shard_template(
+            models::Id::zero(),
+            "acmeCo/task",
+            labels::TASK_TYPE_CAPTURE,
+            &models::ShardTemplate::default(),
+            "capture/acmeCo/task/0000000000000000",
+            false,
+            &[],
+            &models::Annotations::default(),
+        );
+        assert!(labels::values(spec.labels.as_ref().unwrap(), labels::ZONE).is_empty());
+    }
+
+    #[test]
+    fn test_encryption_labels_and_stores() {
+        let stores = vec![models::Store::S3(models::S3StorageConfig {
+            bucket: "a-bucket".to_string(),
+            prefix: None,
+            region: None,
+            encryption: Some(models::StoreEncryption {
+                provider: models::EncryptionProvider::AwsKms,
+                key: "arn:aws:kms:us-east-1:123456789012:key/abcd".to_string(),
+            }),
+        })];
+        let spec = partition_template(
+            models::Id::zero(),
+            &models::Collection::new("acmeCo/collection"),
+            "acmeCo/collection/0000000000000000",
+            &models::JournalTemplate::default(),
+            false,
+            &stores,
+            &models::Annotations::default(),
+        );
+
+        assert_eq!(
+            labels::expect_one(spec.labels.as_ref().unwrap(), labels::ENCRYPTION).unwrap(),
+            "AWS_KMS"
+        );
+        assert_eq!(
+            spec.fragment.unwrap().stores,
+            vec!["s3://a-bucket/?SSE=aws%3Akms&SSEKMSKeyId=arn%3Aaws%3Akms%3Aus-east-1%3A123456789012%3Akey%2Fabcd".to_string()],
+        );
+
+        // Unencrypted stores have no label.
+        let spec = recovery_log_template(
+            models::Id::zero(),
+            "acmeCo/task",
+            labels::TASK_TYPE_CAPTURE,
+            "capture/acmeCo/task/0000000000000000",
+            &[models::Store::example()],
+        );
+        assert!(labels::values(spec.labels.as_ref().unwrap(), labels::ENCRYPTION).is_empty());
+    }
+
     #[test]
     fn test_state_key_escapes() {
         let out = encode_state_key(&["table"], 0);
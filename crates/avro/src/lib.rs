@@ -4,6 +4,7 @@ mod schema;
 // Re-export fundamental schema types so crates don't have to depend on apache_avro.
 pub use apache_avro::{
     schema::{Name as RecordName, RecordField, RecordSchema, UnionSchema},
+    schema_compatibility::SchemaCompatibility,
     Schema,
 };
 
@@ -1,9 +1,11 @@
 use anyhow::Context;
-use futures::{future::BoxFuture, FutureExt};
+use futures::{future::BoxFuture, FutureExt, StreamExt, TryFutureExt};
 use proto_flow::{capture, derive, flow, materialize};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
 };
 
 /// Map a "--source" argument to a corresponding URL, optionally creating an empty
@@ -74,10 +76,57 @@ pub fn project_root(source: &url::Url) -> url::Url {
     url::Url::from_file_path(dir).expect("cannot map project directory into a URL")
 }
 
+/// BuildObserver is notified of the progress of a build as it loads and
+/// validates a catalog, for rendering progress and identifying slow fetches
+/// or connectors. Callbacks may be invoked concurrently, and default to no-ops.
+pub trait BuildObserver: Send + Sync {
+    /// Fetch of a catalog resource has started.
+    fn fetch_started(&self, _resource: &url::Url) {}
+    /// Fetch of a catalog resource has finished, successfully or not.
+    fn fetch_finished(&self, _resource: &url::Url, _elapsed: Duration, _ok: bool) {}
+    /// Validation has started for a draft having `tasks` captures, derivations,
+    /// and materializations. Tasks with disabled shards aren't validated by
+    /// their connectors, so `tasks` is an upper bound of connector RPCs.
+    fn validation_started(&self, _tasks: usize) {}
+    /// A connector Validate RPC of the named task is in flight.
+    fn connector_started(&self, _task_type: &'static str, _name: &str) {}
+    /// A connector Validate RPC of the named task has finished, successfully or not.
+    /// `completed` is the number of RPCs of this build which have now finished.
+    fn connector_finished(
+        &self,
+        _task_type: &'static str,
+        _name: &str,
+        _elapsed: Duration,
+        _ok: bool,
+        _completed: usize,
+    ) {
+    }
+    /// Validation has finished.
+    fn validation_finished(&self, _elapsed: Duration) {}
+}
+
+/// NoOpObserver is a BuildObserver which ignores all progress.
+pub struct NoOpObserver;
+
+impl BuildObserver for NoOpObserver {}
+
 /// Load a Flow specification `source` into a tables::DraftCatalog.
 /// All file:// resources are rooted ("jailed") to the given `file_root`.
 pub async fn load(source: &url::Url, file_root: &Path) -> tables::DraftCatalog {
-    let loader = sources::Loader::new(tables::DraftCatalog::default(), Fetcher::new(file_root));
+    load_with_observer(source, file_root, &NoOpObserver).await
+}
+
+/// Load a Flow specification `source`, as does `load`,
+/// while notifying `observer` of fetched resources.
+pub async fn load_with_observer(
+    source: &url::Url,
+    file_root: &Path,
+    observer: &dyn BuildObserver,
+) -> tables::DraftCatalog {
+    let loader = sources::Loader::new(
+        tables::DraftCatalog::default(),
+        Fetcher::new(file_root, observer),
+    );
 
     loader
         .load_resource(
@@ -94,6 +143,38 @@ pub async fn load(source: &url::Url, file_root: &Path) -> tables::DraftCatalog {
 /// * If `generate_ops_collections` is set, then ops collections are added into `sources`.
 /// * If any of `noop_*` is true, then validations are skipped for connectors of that type.
 pub async fn validate(
+    pub_id: models::Id,
+    build_id: models::Id,
+    allow_local: bool,
+    connector_network: &str,
+    log_handler: impl runtime::LogHandler,
+    noop_captures: bool,
+    noop_derivations: bool,
+    noop_materializations: bool,
+    project_root: &url::Url,
+    draft: tables::DraftCatalog,
+    live: tables::LiveCatalog,
+) -> Output {
+    validate_with_observer(
+        pub_id,
+        build_id,
+        allow_local,
+        connector_network,
+        log_handler,
+        noop_captures,
+        noop_derivations,
+        noop_materializations,
+        project_root,
+        draft,
+        live,
+        &NoOpObserver,
+    )
+    .await
+}
+
+/// Perform validations and produce built specifications, as does `validate`,
+/// while notifying `observer` of the progress of connector validations.
+pub async fn validate_with_observer(
     pub_id: models::Id,
     build_id: models::Id,
     allow_local: bool,
@@ -105,6 +186,7 @@ pub async fn validate(
     project_root: &url::Url,
     mut draft: tables::DraftCatalog,
     live: tables::LiveCatalog,
+    observer: &dyn BuildObserver,
 ) -> Output {
     ::sources::inline_draft_catalog(&mut draft);
 
@@ -115,13 +197,32 @@ pub async fn validate(
         None,
         format!("build/{build_id:#}"),
     );
-    let connectors = validation::NoOpWrapper {
-        noop_captures,
-        noop_derivations,
-        noop_materializations,
-        inner: RuntimeConnectors { runtime },
+    let connectors = ObservedConnectors {
+        inner: validation::NoOpWrapper {
+            noop_captures,
+            noop_derivations,
+            noop_materializations,
+            inner: RuntimeConnectors { runtime },
+        },
+        observer,
+        completed: AtomicUsize::new(0),
     };
 
+    let tasks = draft.captures.iter().filter(|c| c.model.is_some()).count()
+        + draft
+            .collections
+            .iter()
+            .filter(|c| matches!(&c.model, Some(model) if model.derive.is_some()))
+            .count()
+        + draft
+            .materializations
+            .iter()
+            .filter(|m| m.model.is_some())
+            .count();
+
+    observer.validation_started(tasks);
+    let started = Instant::now();
+
     let built = validation::validate(
         pub_id,
         build_id,
@@ -130,12 +231,152 @@ pub async fn validate(
         &draft,
         &live,
         true, // Fail-fast.
+        &validation::Limits::default(),
     )
     .await;
 
+    observer.validation_finished(started.elapsed());
+
     Output::new(draft, live, built)
 }
 
+/// Reuse reports the specifications of an incremental build which were
+/// reused from their live built specifications, and those which are rebuilt.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Reuse {
+    pub reused: Vec<String>,
+    pub rebuilt: Vec<String>,
+}
+
+/// Prepare `draft` for an incremental build, by removing drafted specifications
+/// which may reuse their live built specifications rather than being rebuilt.
+///
+/// A specification is reused if its drafted model is unchanged from its live
+/// model, and its dependency hash as of this publication is unchanged from the
+/// hash of its live built specification. This is akin to a touch publication
+/// which is inferred automatically, but reused specifications are not validated
+/// and their connectors are not invoked. Instead, validation carries forward
+/// their live built specifications, as it does for other live specifications.
+///
+/// Reusing a specification can allow its dependents to also be reused,
+/// so candidates are narrowed until their dependency hashes are stable.
+pub fn reuse_unchanged(
+    pub_id: models::Id,
+    draft: &mut tables::DraftCatalog,
+    live: &tables::LiveCatalog,
+) -> Reuse {
+    ::sources::inline_draft_catalog(draft);
+
+    // Begin with all non-touch drafts having an unchanged model.
+    let mut reused = BTreeSet::new();
+    unchanged_models(&draft.captures, &live.captures, &mut reused);
+    unchanged_models(&draft.collections, &live.collections, &mut reused);
+    unchanged_models(&draft.materializations, &live.materializations, &mut reused);
+    unchanged_models(&draft.tests, &live.tests, &mut reused);
+
+    loop {
+        let deps = tables::Dependencies::of_incremental_publication(pub_id, draft, live, &reused);
+        let mut changed = Vec::new();
+
+        changed_hashes(&live.captures, &deps, &reused, &mut changed);
+        changed_hashes(&live.collections, &deps, &reused, &mut changed);
+        changed_hashes(&live.materializations, &deps, &reused, &mut changed);
+        changed_hashes(&live.tests, &deps, &reused, &mut changed);
+
+        if changed.is_empty() {
+            break;
+        }
+        for name in changed {
+            reused.remove(&name);
+        }
+    }
+
+    let keep = |name: &str| !reused.contains(name);
+    draft.captures.retain(|r| keep(&r.capture));
+    draft.collections.retain(|r| keep(&r.collection));
+    draft.materializations.retain(|r| keep(&r.materialization));
+    draft.tests.retain(|r| keep(&r.test));
+
+    let rebuilt = draft
+        .captures
+        .iter()
+        .filter(|r| r.model.is_some())
+        .map(|r| r.capture.to_string())
+        .chain(
+            draft
+                .collections
+                .iter()
+                .filter(|r| r.model.is_some())
+                .map(|r| r.collection.to_string()),
+        )
+        .chain(
+            draft
+                .materializations
+                .iter()
+                .filter(|r| r.model.is_some())
+                .map(|r| r.materialization.to_string()),
+        )
+        .chain(
+            draft
+                .tests
+                .iter()
+                .filter(|r| r.model.is_some())
+                .map(|r| r.test.to_string()),
+        )
+        .collect();
+
+    Reuse {
+        reused: reused.into_iter().collect(),
+        rebuilt,
+    }
+}
+
+fn unchanged_models<D, L>(
+    drafts: &tables::Table<D>,
+    lives: &tables::Table<L>,
+    reused: &mut BTreeSet<String>,
+) where
+    D: tables::DraftRow,
+    D::Key: AsRef<str>,
+    L: tables::LiveRow<Key = D::Key, ModelDef = D::ModelDef>,
+{
+    for draft in drafts.iter() {
+        let (Some(model), Some(live)) = (draft.model(), lives.get_by_key(draft.catalog_name()))
+        else {
+            continue; // Not a drafted update of a live specification.
+        };
+        if draft.is_touch()
+            || draft
+                .expect_pub_id()
+                .is_some_and(|expect_id| expect_id != live.last_pub_id())
+            || model != live.model()
+        {
+            continue;
+        }
+        reused.insert(draft.catalog_name().as_ref().to_string());
+    }
+}
+
+fn changed_hashes<L>(
+    lives: &tables::Table<L>,
+    deps: &tables::Dependencies,
+    reused: &BTreeSet<String>,
+    changed: &mut Vec<String>,
+) where
+    L: tables::LiveRow,
+    L::Key: AsRef<str>,
+{
+    for live in lives.iter() {
+        let name = live.catalog_name().as_ref();
+
+        if reused.contains(name)
+            && deps.compute_hash(live.model()).as_deref() != live.dependency_hash()
+        {
+            changed.push(name.to_string());
+        }
+    }
+}
+
 /// The output of a build, which can be either successful, failed, or anything
 /// in between. The "in between" may seem silly, but may be important for
 /// some use cases. For example, you may be executing a build for the purpose
@@ -268,18 +509,20 @@ pub fn write_files(project_root: &url::Url, files: Vec<(url::Url, Vec<u8>)>) ->
 }
 
 /// Fetcher is a general-purpose implementation of sources::Fetcher.
-struct Fetcher {
+struct Fetcher<'o> {
     client: reqwest::Result<reqwest::Client>,
     file_root: PathBuf,
+    observer: &'o dyn BuildObserver,
 }
 
-impl Fetcher {
-    fn new(file_root: impl Into<PathBuf>) -> Self {
+impl<'o> Fetcher<'o> {
+    fn new(file_root: impl Into<PathBuf>, observer: &'o dyn BuildObserver) -> Self {
         let client = reqwest::ClientBuilder::new().timeout(FETCH_TIMEOUT).build();
 
         Self {
             client,
             file_root: file_root.into(),
+            observer,
         }
     }
 
@@ -336,15 +579,27 @@ impl Fetcher {
     }
 }
 
-impl sources::Fetcher for Fetcher {
+impl<'o> sources::Fetcher for Fetcher<'o> {
     fn fetch<'a>(
         &'a self,
         resource: &'a url::Url,
         content_type: flow::ContentType,
     ) -> BoxFuture<'a, anyhow::Result<bytes::Bytes>> {
         tracing::debug!(%resource, ?content_type, file_root=?self.file_root, "fetching resource");
-        self.fetch_inner(resource.clone(), self.file_root.clone())
-            .boxed()
+
+        async move {
+            self.observer.fetch_started(resource);
+            let started = Instant::now();
+
+            let result = self
+                .fetch_inner(resource.clone(), self.file_root.clone())
+                .await;
+
+            self.observer
+                .fetch_finished(resource, started.elapsed(), result.is_ok());
+            result
+        }
+        .boxed()
     }
 }
 
@@ -378,6 +633,150 @@ impl<L: runtime::LogHandler> validation::Connectors for RuntimeConnectors<L> {
     ) -> BoxFuture<'a, anyhow::Result<materialize::Response>> {
         self.runtime.clone().unary_materialize(request).boxed()
     }
+
+    fn spec_capture<'a>(
+        &'a self,
+        request: capture::Request,
+        _data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<capture::Response>>> {
+        self.runtime
+            .clone()
+            .unary_capture(request)
+            .map_ok(Some)
+            .boxed()
+    }
+
+    fn spec_derivation<'a>(
+        &'a self,
+        request: derive::Request,
+        _data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<derive::Response>>> {
+        self.runtime
+            .clone()
+            .unary_derive(request)
+            .map_ok(Some)
+            .boxed()
+    }
+
+    fn spec_materialization<'a>(
+        &'a self,
+        request: materialize::Request,
+        _data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<materialize::Response>>> {
+        self.runtime
+            .clone()
+            .unary_materialize(request)
+            .map_ok(Some)
+            .boxed()
+    }
+}
+
+/// ObservedConnectors wraps another validation::Connectors implementation
+/// to notify a BuildObserver of each connector RPC and its timing.
+struct ObservedConnectors<'o, C> {
+    inner: C,
+    observer: &'o dyn BuildObserver,
+    completed: AtomicUsize,
+}
+
+impl<'o, C: validation::Connectors> ObservedConnectors<'o, C> {
+    async fn observe<R>(
+        &self,
+        task_type: &'static str,
+        name: &str,
+        rpc: BoxFuture<'_, anyhow::Result<R>>,
+    ) -> anyhow::Result<R> {
+        self.observer.connector_started(task_type, name);
+        let started = Instant::now();
+
+        let result = rpc.await;
+
+        let completed = self.completed.fetch_add(1, Ordering::Relaxed) + 1;
+        self.observer.connector_finished(
+            task_type,
+            name,
+            started.elapsed(),
+            result.is_ok(),
+            completed,
+        );
+        result
+    }
+}
+
+impl<'o, C: validation::Connectors> validation::Connectors for ObservedConnectors<'o, C> {
+    fn validate_capture<'a>(
+        &'a self,
+        request: capture::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<capture::Response>> {
+        let name = request
+            .validate
+            .as_ref()
+            .map(|v| v.name.clone())
+            .unwrap_or_default();
+        let rpc = self.inner.validate_capture(request, data_plane);
+
+        async move { self.observe(labels::TASK_TYPE_CAPTURE, &name, rpc).await }.boxed()
+    }
+
+    fn validate_derivation<'a>(
+        &'a self,
+        request: derive::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<derive::Response>> {
+        let name = request
+            .validate
+            .as_ref()
+            .and_then(|v| v.collection.as_ref())
+            .map(|c| c.name.clone())
+            .unwrap_or_default();
+        let rpc = self.inner.validate_derivation(request, data_plane);
+
+        async move { self.observe(labels::TASK_TYPE_DERIVATION, &name, rpc).await }.boxed()
+    }
+
+    fn validate_materialization<'a>(
+        &'a self,
+        request: materialize::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<materialize::Response>> {
+        let name = request
+            .validate
+            .as_ref()
+            .map(|v| v.name.clone())
+            .unwrap_or_default();
+        let rpc = self.inner.validate_materialization(request, data_plane);
+
+        async move {
+            self.observe(labels::TASK_TYPE_MATERIALIZATION, &name, rpc)
+                .await
+        }
+        .boxed()
+    }
+
+    fn spec_capture<'a>(
+        &'a self,
+        request: capture::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<capture::Response>>> {
+        self.inner.spec_capture(request, data_plane)
+    }
+
+    fn spec_derivation<'a>(
+        &'a self,
+        request: derive::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<derive::Response>>> {
+        self.inner.spec_derivation(request, data_plane)
+    }
+
+    fn spec_materialization<'a>(
+        &'a self,
+        request: materialize::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<materialize::Response>>> {
+        self.inner.spec_materialization(request, data_plane)
+    }
 }
 
 /// NoOpCatalogResolver is a CatalogResolver which does nothing, for use by
@@ -398,6 +797,7 @@ impl tables::CatalogResolver for NoOpCatalogResolver {
                 vec![models::Store::Gcs(models::GcsBucketAndPrefix {
                     bucket: "example-bucket".to_string(),
                     prefix: None,
+                    encryption: None,
                 })],
             );
 
@@ -411,6 +811,12 @@ impl tables::CatalogResolver for NoOpCatalogResolver {
                 models::Collection::new("ops/stats"),
                 "broker:address".to_string(),
                 "reactor:address".to_string(),
+                Vec::new(),
+                Vec::new(),
+                None,
+                None,
+                None,
+                None,
             );
 
             live
@@ -419,5 +825,230 @@ impl tables::CatalogResolver for NoOpCatalogResolver {
     }
 }
 
+/// Resolve `catalog_names` through the pages of a PagedCatalogResolver.
+/// Up to `pagination.concurrency` pages are resolved at once, and each page
+/// is merged into the returned LiveCatalog as it arrives.
+pub async fn resolve_paginated<R: tables::PagedCatalogResolver + ?Sized>(
+    resolver: &R,
+    catalog_names: Vec<&str>,
+    pagination: &tables::Pagination,
+) -> tables::LiveCatalog {
+    let pages = tables::paginate(catalog_names, pagination);
+    let n_pages = pages.len();
+
+    let mut pages =
+        futures::stream::iter(pages.into_iter().map(|page| resolver.resolve_page(page)))
+            .buffer_unordered(pagination.concurrency.max(1));
+
+    let mut live = tables::LiveCatalog::default();
+    while let Some(page) = pages.next().await {
+        live.merge(page);
+    }
+    tracing::debug!(
+        n_pages,
+        specs = live.spec_count(),
+        "resolved live catalog pages"
+    );
+
+    live
+}
+
 pub const FETCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
 pub const STDIN_URL: &str = "stdin://root/flow.yaml";
+
+#[cfg(test)]
+mod test {
+    use super::{reuse_unchanged, Reuse};
+    use serde_json::json;
+
+    const COLLECTION: &str = "acmeCo/collection";
+    const CAPTURE: &str = "acmeCo/capture";
+    const MATERIALIZATION: &str = "acmeCo/materialization";
+
+    fn id(b: u8) -> models::Id {
+        models::Id::new([0, 0, 0, 0, 0, 0, 0, b])
+    }
+
+    fn collection_model() -> models::CollectionDef {
+        serde_json::from_value(json!({
+            "schema": {
+                "type": "object",
+                "properties": {"id": {"type": "string"}},
+                "required": ["id"],
+            },
+            "key": ["/id"],
+        }))
+        .unwrap()
+    }
+
+    fn capture_model() -> models::CaptureDef {
+        serde_json::from_value(json!({
+            "endpoint": {"connector": {"image": "source/image", "config": {}}},
+            "bindings": [{"target": COLLECTION, "resource": {"stream": "a-stream"}}],
+        }))
+        .unwrap()
+    }
+
+    fn materialization_model() -> models::MaterializationDef {
+        serde_json::from_value(json!({
+            "endpoint": {"connector": {"image": "sink/image", "config": {}}},
+            "bindings": [{"source": COLLECTION, "resource": {"table": "a_table"}}],
+        }))
+        .unwrap()
+    }
+
+    // Live catalog of a collection, and a capture and materialization
+    // which depend upon it and were built after it was last published.
+    fn live_fixture() -> tables::LiveCatalog {
+        let mut live = tables::LiveCatalog::default();
+
+        live.collections.insert_row(
+            models::Collection::new(COLLECTION),
+            id(1),
+            id(2),
+            id(0x10),
+            id(0x10),
+            collection_model(),
+            Default::default(),
+            None,
+        );
+
+        let (capture_hash, materialization_hash) = {
+            let deps = tables::Dependencies::from_live(&live);
+            (
+                deps.compute_hash(&capture_model()),
+                deps.compute_hash(&materialization_model()),
+            )
+        };
+        assert!(capture_hash.is_some() && materialization_hash.is_some());
+
+        live.captures.insert_row(
+            models::Capture::new(CAPTURE),
+            id(3),
+            id(2),
+            id(0x11),
+            id(0x11),
+            capture_model(),
+            Default::default(),
+            capture_hash,
+        );
+        live.materializations.insert_row(
+            models::Materialization::new(MATERIALIZATION),
+            id(4),
+            id(2),
+            id(0x12),
+            id(0x12),
+            materialization_model(),
+            Default::default(),
+            materialization_hash,
+        );
+
+        live
+    }
+
+    fn draft_fixture(
+        collection: Option<models::CollectionDef>,
+        capture: bool,
+        materialization: Option<(Option<models::Id>, bool)>,
+    ) -> tables::DraftCatalog {
+        let scope = url::Url::parse("test://example/flow.yaml").unwrap();
+        let mut draft = tables::DraftCatalog::default();
+
+        if let Some(model) = collection {
+            draft.collections.insert_row(
+                models::Collection::new(COLLECTION),
+                scope.clone(),
+                None,
+                Some(model),
+                false,
+            );
+        }
+        if capture {
+            draft.captures.insert_row(
+                models::Capture::new(CAPTURE),
+                scope.clone(),
+                None,
+                Some(capture_model()),
+                false,
+            );
+        }
+        if let Some((expect_pub_id, is_touch)) = materialization {
+            draft.materializations.insert_row(
+                models::Materialization::new(MATERIALIZATION),
+                scope.clone(),
+                expect_pub_id,
+                Some(materialization_model()),
+                is_touch,
+            );
+        }
+        draft
+    }
+
+    fn reuse(reused: &[&str], rebuilt: &[&str]) -> Reuse {
+        Reuse {
+            reused: reused.iter().map(|s| s.to_string()).collect(),
+            rebuilt: rebuilt.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_unchanged_specs_and_their_dependents_are_reused() {
+        let live = live_fixture();
+        let mut draft = draft_fixture(Some(collection_model()), true, Some((None, false)));
+
+        // The collection is reused, which keeps the dependency hashes of
+        // the capture and materialization stable so they're reused as well.
+        let outcome = reuse_unchanged(id(0x20), &mut draft, &live);
+        assert_eq!(outcome, reuse(&[CAPTURE, COLLECTION, MATERIALIZATION], &[]));
+        assert_eq!(draft.spec_count(), 0);
+    }
+
+    #[test]
+    fn test_changed_dependency_rebuilds_dependents() {
+        let live = live_fixture();
+
+        let mut changed = collection_model();
+        changed.key = models::CompositeKey::new([models::JsonPointer::new("/other")]);
+        let mut draft = draft_fixture(Some(changed), true, Some((None, false)));
+
+        // The changed collection takes this publication's ID, which changes
+        // the dependency hashes of its otherwise-unchanged dependents.
+        let outcome = reuse_unchanged(id(0x20), &mut draft, &live);
+        assert_eq!(outcome, reuse(&[], &[CAPTURE, COLLECTION, MATERIALIZATION]));
+        assert_eq!(draft.spec_count(), 3);
+    }
+
+    #[test]
+    fn test_expect_pub_id_mismatch_is_rebuilt() {
+        let live = live_fixture();
+        let mut draft = draft_fixture(
+            Some(collection_model()),
+            false,
+            Some((Some(id(0x99)), false)),
+        );
+
+        // The materialization is rebuilt, so that validation surfaces the
+        // mismatch. Its unchanged dependency is still reused.
+        let outcome = reuse_unchanged(id(0x20), &mut draft, &live);
+        assert_eq!(outcome, reuse(&[COLLECTION], &[MATERIALIZATION]));
+        assert_eq!(draft.materializations.len(), 1);
+        assert_eq!(draft.collections.len(), 0);
+
+        // A matched expectation doesn't prevent reuse.
+        let mut draft = draft_fixture(None, false, Some((Some(id(0x12)), false)));
+        let outcome = reuse_unchanged(id(0x20), &mut draft, &live);
+        assert_eq!(outcome, reuse(&[MATERIALIZATION], &[]));
+    }
+
+    #[test]
+    fn test_touch_drafts_are_rebuilt() {
+        let live = live_fixture();
+        let mut draft = draft_fixture(Some(collection_model()), false, Some((None, true)));
+
+        // Touched specs are explicitly requested to be rebuilt, and remain drafted.
+        let outcome = reuse_unchanged(id(0x20), &mut draft, &live);
+        assert_eq!(outcome, reuse(&[COLLECTION], &[MATERIALIZATION]));
+        assert_eq!(draft.materializations.len(), 1);
+        assert!(draft.materializations[0].is_touch);
+    }
+}
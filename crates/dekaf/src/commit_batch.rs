@@ -0,0 +1,378 @@
+use kafka_protocol::messages::{
+    offset_commit_response::{OffsetCommitResponsePartition, OffsetCommitResponseTopic},
+    OffsetCommitRequest, OffsetCommitResponse, RequestHeader, TopicName,
+};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Interval over which OffsetCommit requests of a consumer group are
+/// batched together before being written upstream.
+pub const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// PendingCommit is a merged OffsetCommit request of a consumer group.
+#[derive(Debug)]
+pub struct PendingCommit {
+    pub request: OffsetCommitRequest,
+    pub header: RequestHeader,
+    /// Number of client OffsetCommit requests merged into `request`.
+    pub commits: usize,
+    first_at: Instant,
+}
+
+/// CommitBatcher merges the OffsetCommit requests of each consumer group,
+/// so that consumers which commit after every message don't each produce
+/// an upstream write. Merges are last-write-wins for each topic partition,
+/// which makes a retried or duplicated commit of a client harmless.
+///
+/// Commits are acknowledged before they're flushed, so a flush which fails
+/// is recorded against its group, and its error is delivered by the response
+/// to the next commit of a failed partition, which prompts the client to retry.
+#[derive(Debug, Default)]
+pub struct CommitBatcher {
+    pending: HashMap<String, PendingCommit>,
+    // Error codes of failed partition flushes, by group, topic, and partition.
+    failed: HashMap<String, HashMap<(TopicName, i32), i16>>,
+}
+
+impl CommitBatcher {
+    /// Add an OffsetCommit request, returning the response with which the
+    /// client is acknowledged ahead of the request being flushed.
+    pub fn add(
+        &mut self,
+        request: OffsetCommitRequest,
+        header: RequestHeader,
+        now: Instant,
+    ) -> OffsetCommitResponse {
+        let mut response = acknowledge(&request);
+        self.deliver_failures(request.group_id.as_str(), &mut response);
+
+        let Some(mut prior) = self.pending.remove(request.group_id.as_str()) else {
+            self.pending.insert(
+                request.group_id.to_string(),
+                PendingCommit {
+                    request,
+                    header,
+                    commits: 1,
+                    first_at: now,
+                },
+            );
+            return response;
+        };
+
+        // The most-recent request and header are retained, and partitions
+        // of prior requests are carried forward if not since committed.
+        let mut request = request;
+        for prior_topic in prior.request.topics.drain(..) {
+            let topic = match request
+                .topics
+                .iter_mut()
+                .find(|t| t.name == prior_topic.name)
+            {
+                Some(topic) => topic,
+                None => {
+                    request.topics.push(prior_topic);
+                    continue;
+                }
+            };
+            for prior_partition in prior_topic.partitions {
+                if !topic
+                    .partitions
+                    .iter()
+                    .any(|p| p.partition_index == prior_partition.partition_index)
+                {
+                    topic.partitions.push(prior_partition);
+                }
+            }
+        }
+
+        self.pending.insert(
+            request.group_id.to_string(),
+            PendingCommit {
+                request,
+                header,
+                commits: prior.commits + 1,
+                first_at: prior.first_at,
+            },
+        );
+        response
+    }
+
+    /// Instant at which the next pending commit is due to be flushed, if any.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending
+            .values()
+            .map(|p| p.first_at + FLUSH_INTERVAL)
+            .min()
+    }
+
+    /// Record the upstream `response` to a flushed commit of `group`,
+    /// retaining the errors of its failed partitions.
+    pub fn record_response(&mut self, group: &str, response: &OffsetCommitResponse) {
+        for topic in &response.topics {
+            for partition in &topic.partitions {
+                if partition.error_code != 0 {
+                    self.failed.entry(group.to_string()).or_default().insert(
+                        (topic.name.clone(), partition.partition_index),
+                        partition.error_code,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Record that the flush of all partitions of `request` failed with `error_code`.
+    pub fn record_failure(&mut self, request: &OffsetCommitRequest, error_code: i16) {
+        let failed = self.failed.entry(request.group_id.to_string()).or_default();
+
+        for topic in &request.topics {
+            for partition in &topic.partitions {
+                failed.insert((topic.name.clone(), partition.partition_index), error_code);
+            }
+        }
+    }
+
+    /// Deliver recorded failures of `group` into the partitions of `response`
+    /// which don't already have an error. Delivered failures are cleared.
+    pub fn deliver_failures(&mut self, group: &str, response: &mut OffsetCommitResponse) {
+        let Some(failed) = self.failed.get_mut(group) else {
+            return;
+        };
+        for topic in response.topics.iter_mut() {
+            for partition in topic.partitions.iter_mut() {
+                let key = (topic.name.clone(), partition.partition_index);
+
+                if let Some(error_code) = failed.remove(&key) {
+                    if partition.error_code == 0 {
+                        partition.error_code = error_code;
+                    }
+                }
+            }
+        }
+        if failed.is_empty() {
+            self.failed.remove(group);
+        }
+    }
+
+    /// Take pending commits which have been batched for at least FLUSH_INTERVAL.
+    pub fn take_due(&mut self, now: Instant) -> Vec<PendingCommit> {
+        let due: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, p)| now.saturating_duration_since(p.first_at) >= FLUSH_INTERVAL)
+            .map(|(group, _)| group.clone())
+            .collect();
+
+        due.into_iter()
+            .filter_map(|group| self.pending.remove(&group))
+            .collect()
+    }
+
+    /// Take the pending commit of `group`, if any.
+    pub fn take_group(&mut self, group: &str) -> Option<PendingCommit> {
+        self.pending.remove(group)
+    }
+
+    /// Take all pending commits.
+    pub fn take_all(&mut self) -> Vec<PendingCommit> {
+        self.pending.drain().map(|(_, p)| p).collect()
+    }
+}
+
+// Build a successful response to all partitions of `request`.
+fn acknowledge(request: &OffsetCommitRequest) -> OffsetCommitResponse {
+    OffsetCommitResponse::default().with_topics(
+        request
+            .topics
+            .iter()
+            .map(|topic| {
+                OffsetCommitResponseTopic::default()
+                    .with_name(topic.name.clone())
+                    .with_partitions(
+                        topic
+                            .partitions
+                            .iter()
+                            .map(|p| {
+                                OffsetCommitResponsePartition::default()
+                                    .with_partition_index(p.partition_index)
+                            })
+                            .collect(),
+                    )
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use kafka_protocol::messages::{
+        offset_commit_request::{OffsetCommitRequestPartition, OffsetCommitRequestTopic},
+        GroupId,
+    };
+    use kafka_protocol::protocol::StrBytes;
+
+    fn request(group: &str, commits: &[(&str, i32, i64)]) -> OffsetCommitRequest {
+        let mut request = OffsetCommitRequest::default()
+            .with_group_id(GroupId::from(StrBytes::from_string(group.to_string())));
+
+        for (topic, partition, offset) in commits {
+            let name = TopicName::from(StrBytes::from_string(topic.to_string()));
+            let partition = OffsetCommitRequestPartition::default()
+                .with_partition_index(*partition)
+                .with_committed_offset(*offset);
+
+            match request.topics.iter_mut().find(|t| t.name == name) {
+                Some(t) => t.partitions.push(partition),
+                None => request.topics.push(
+                    OffsetCommitRequestTopic::default()
+                        .with_name(name)
+                        .with_partitions(vec![partition]),
+                ),
+            }
+        }
+        request
+    }
+
+    fn offsets(pending: &PendingCommit) -> Vec<(String, i32, i64)> {
+        let mut out: Vec<_> = pending
+            .request
+            .topics
+            .iter()
+            .flat_map(|t| {
+                t.partitions
+                    .iter()
+                    .map(|p| (t.name.to_string(), p.partition_index, p.committed_offset))
+            })
+            .collect();
+        out.sort();
+        out
+    }
+
+    #[test]
+    fn test_commit_batching() {
+        let mut batcher = CommitBatcher::default();
+        let now = Instant::now();
+
+        let resp = batcher.add(
+            request("group", &[("a/topic", 0, 10), ("a/topic", 1, 20)]),
+            RequestHeader::default(),
+            now,
+        );
+        assert_eq!(resp.topics[0].partitions.len(), 2);
+        assert!(resp.topics[0].partitions.iter().all(|p| p.error_code == 0));
+
+        batcher.add(
+            request("group", &[("a/topic", 0, 15), ("b/topic", 0, 5)]),
+            RequestHeader::default(),
+            now + Duration::from_millis(100),
+        );
+        // A retry of the same commit is idempotent.
+        batcher.add(
+            request("group", &[("a/topic", 0, 15)]),
+            RequestHeader::default(),
+            now + Duration::from_millis(200),
+        );
+        batcher.add(
+            request("other", &[("a/topic", 0, 1)]),
+            RequestHeader::default(),
+            now + Duration::from_millis(900),
+        );
+
+        assert!(batcher
+            .take_due(now + Duration::from_millis(500))
+            .is_empty());
+
+        let due = batcher.take_due(now + FLUSH_INTERVAL);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].commits, 3);
+        assert_eq!(
+            offsets(&due[0]),
+            vec![
+                ("a/topic".to_string(), 0, 15),
+                ("a/topic".to_string(), 1, 20),
+                ("b/topic".to_string(), 0, 5),
+            ]
+        );
+
+        assert!(batcher.take_group("group").is_none());
+        assert_eq!(batcher.take_all().len(), 1);
+    }
+
+    #[test]
+    fn test_commit_deadlines_and_failures() {
+        let mut batcher = CommitBatcher::default();
+        let now = Instant::now();
+        assert_eq!(batcher.next_deadline(), None);
+
+        batcher.add(
+            request("group", &[("a/topic", 0, 10), ("a/topic", 1, 20)]),
+            RequestHeader::default(),
+            now + Duration::from_millis(300),
+        );
+        batcher.add(
+            request("other", &[("a/topic", 0, 1)]),
+            RequestHeader::default(),
+            now,
+        );
+        assert_eq!(batcher.next_deadline(), Some(now + FLUSH_INTERVAL));
+
+        // The flush of "group" is rejected upstream for one partition,
+        // and the flush of "other" fails outright.
+        let flushed = batcher.take_group("group").unwrap();
+        let mut response = acknowledge(&flushed.request);
+        response.topics[0].partitions[1].error_code = 27; // REBALANCE_IN_PROGRESS.
+        batcher.record_response("group", &response);
+
+        let flushed = batcher.take_group("other").unwrap();
+        batcher.record_failure(&flushed.request, 15); // COORDINATOR_NOT_AVAILABLE.
+        assert_eq!(batcher.next_deadline(), None);
+
+        let codes = |response: &OffsetCommitResponse| -> Vec<(String, i32, i16)> {
+            response
+                .topics
+                .iter()
+                .flat_map(|t| {
+                    t.partitions
+                        .iter()
+                        .map(|p| (t.name.to_string(), p.partition_index, p.error_code))
+                })
+                .collect()
+        };
+
+        // Failures are delivered to the next commit of each failed partition, only.
+        let resp = batcher.add(
+            request("group", &[("a/topic", 0, 11), ("a/topic", 1, 21)]),
+            RequestHeader::default(),
+            now + FLUSH_INTERVAL,
+        );
+        assert_eq!(
+            codes(&resp),
+            vec![
+                ("a/topic".to_string(), 0, 0),
+                ("a/topic".to_string(), 1, 27)
+            ]
+        );
+        let resp = batcher.add(
+            request("group", &[("a/topic", 1, 22)]),
+            RequestHeader::default(),
+            now + FLUSH_INTERVAL,
+        );
+        assert_eq!(codes(&resp), vec![("a/topic".to_string(), 1, 0)]);
+
+        // Failures of a group don't affect other groups.
+        let resp = batcher.add(
+            request("third", &[("a/topic", 0, 5)]),
+            RequestHeader::default(),
+            now + FLUSH_INTERVAL,
+        );
+        assert_eq!(codes(&resp), vec![("a/topic".to_string(), 0, 0)]);
+
+        let resp = batcher.add(
+            request("other", &[("a/topic", 0, 2)]),
+            RequestHeader::default(),
+            now + FLUSH_INTERVAL,
+        );
+        assert_eq!(codes(&resp), vec![("a/topic".to_string(), 0, 15)]);
+        assert!(batcher.failed.is_empty());
+    }
+}
@@ -23,6 +23,76 @@ impl Default for DeletionMode {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordCompression {
+    // Record batches are sent uncompressed.
+    None,
+    // Record batches are compressed with LZ4 framing.
+    Lz4,
+    // Record batches are compressed with raw Snappy.
+    Snappy,
+    // Record batches are compressed with Zstandard. Consumers which fetch
+    // using a protocol version that predates Zstandard are sent LZ4 instead.
+    Zstd,
+}
+
+impl Default for RecordCompression {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageFormat {
+    // Encodes record keys and values as Avro, prefixed by the ID of their
+    // schema in the schema registry (the Confluent wire format).
+    Avro,
+    // Encodes record keys and values as plain JSON.
+    Json,
+}
+
+impl Default for MessageFormat {
+    fn default() -> Self {
+        Self::Avro
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum JsonKeyFormat {
+    // Encodes keys as a JSON array of the collection's key components, in order.
+    Array,
+    // Encodes keys as a JSON object of key component pointers and their values.
+    Object,
+}
+
+impl Default for JsonKeyFormat {
+    fn default() -> Self {
+        Self::Array
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CompatibilityProfile {
+    /// Dekaf's default protocol behavior, without adjustments.
+    Default,
+    /// Apache Flink's Kafka connector, subscribed to a topic pattern.
+    Flink,
+    /// Spark Structured Streaming's Kafka source, subscribed to a topic pattern.
+    Spark,
+    /// Kafka Connect sink connectors, subscribed to a topic regex.
+    KafkaConnect,
+}
+
+impl Default for CompatibilityProfile {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
 /// Configures the behavior of a whole dekaf task
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct DekafConfig {
@@ -43,6 +113,125 @@ pub struct DekafConfig {
     #[serde(default)]
     #[schemars(title = "Strict Topic Names")]
     pub strict_topic_names: bool,
+    /// The format of record keys and values. "avro" encodes records using
+    /// schemas served by the schema registry, and "json" encodes them as plain JSON
+    /// for consumers which are unable to use a schema registry.
+    #[serde(default)]
+    #[schemars(title = "Message Format")]
+    pub format: MessageFormat,
+    /// How record keys are serialized when using the "json" message format.
+    /// "array" emits the values of the collection key in order, and "object"
+    /// emits an object keyed by the JSON pointer of each key component.
+    #[serde(default)]
+    #[schemars(title = "JSON Key Format")]
+    pub json_key_format: JsonKeyFormat,
+    /// Whether to expose a `__dekaf_metrics` topic which periodically
+    /// publishes JSON documents of the bytes and documents served, and the
+    /// estimated lag, of each topic partition read by this task's consumers.
+    #[serde(default)]
+    #[schemars(title = "Metrics Topic")]
+    pub metrics_topic: bool,
+    /// The maximum rate, in bytes per second, at which each consumer session
+    /// may fetch data. Consumers which exceed it are throttled. Unlimited if unset.
+    #[serde(default)]
+    #[schemars(title = "Max Fetch Bytes Per Second")]
+    pub max_fetch_bytes_per_second: Option<u64>,
+    /// The maximum rate at which each consumer session may issue fetch
+    /// requests. Consumers which exceed it are throttled. Unlimited if unset.
+    #[serde(default)]
+    #[schemars(title = "Max Fetch Requests Per Second")]
+    pub max_fetch_requests_per_second: Option<u32>,
+    /// Whether to attach Flow reduction metadata to each record as headers:
+    /// `flow-reduce` holds the collection's top-level reduction strategy as
+    /// compact JSON (omitted if multiple strategies may apply), and `flow-uuid` holds the document's UUID, which encodes
+    /// its producer, its commit clock, and its transaction flags.
+    #[serde(default)]
+    #[schemars(title = "Reduction Headers")]
+    pub reduction_headers: bool,
+    /// Compression of record batches sent to consumers. Compression reduces
+    /// egress of high-volume consumers at the expense of CPU.
+    #[serde(default)]
+    #[schemars(title = "Record Compression")]
+    pub compression: RecordCompression,
+    /// Topics to which Kafka producers may write, each of which maps onto
+    /// a collection that this task writes. Produced records must be JSON
+    /// documents which conform to the write schema of their collection.
+    /// Writable topics are separate from, and may not be read as, bindings.
+    #[serde(default)]
+    #[schemars(title = "Writable Topics")]
+    pub writable_topics: Vec<DekafWritableTopic>,
+    /// Virtual topics which each merge several collections into a single topic.
+    /// Each collection of a union topic is assigned a fixed range of its partitions,
+    /// and each record has a `flow-collection` header naming its source collection.
+    #[serde(default)]
+    #[schemars(title = "Union Topics")]
+    pub union_topics: Vec<DekafUnionTopic>,
+    /// Envelope encryption of record values with a customer-managed key.
+    /// Each value is encrypted with a data key, which is itself encrypted
+    /// with the customer-managed key and attached to the record as a header.
+    /// Only holders of decrypt permission on the key can read record values.
+    #[serde(default)]
+    #[schemars(title = "Payload Encryption")]
+    pub encryption: Option<DekafPayloadEncryption>,
+    /// A preset of protocol settings required by the consumers of a
+    /// particular ecosystem, such as Flink. Presets of Java consumers which
+    /// subscribe to topic patterns list the partitions of every topic in
+    /// metadata, at greater expense. "default" makes no adjustments.
+    #[serde(default)]
+    #[schemars(title = "Compatibility Profile")]
+    pub profile: CompatibilityProfile,
+}
+
+/// Maps a topic to which Kafka producers may write onto a collection.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DekafWritableTopic {
+    /// The exposed name of the writable topic.
+    pub topic_name: String,
+    /// The collection into which produced records are written.
+    #[schemars(schema_with = "collection_name")]
+    pub collection: String,
+}
+
+/// Maps a virtual topic onto the collections which it merges.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DekafUnionTopic {
+    /// The exposed name of the union topic.
+    pub topic_name: String,
+    /// The collections merged by the topic. Partitions of the topic are
+    /// assigned by the order of collections, so collections may be appended
+    /// but should not be re-ordered or removed.
+    pub collections: Vec<String>,
+    /// Number of topic partitions reserved for each collection. The i'th
+    /// collection maps onto partitions [i * N, (i+1) * N), and partitions
+    /// beyond those of the collection are empty. Collections may not have
+    /// more partitions than this, and it should not be changed once set.
+    #[serde(default = "default_partitions_per_collection")]
+    pub partitions_per_collection: u32,
+}
+
+fn default_partitions_per_collection() -> u32 {
+    16
+}
+
+/// Configures envelope encryption of the record values of topics.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DekafPayloadEncryption {
+    /// Key management service which holds the encryption key.
+    pub provider: models::EncryptionProvider,
+    /// Encryption key, as an AWS KMS key ARN
+    /// (arn:aws:kms:{region}:{account}:key/{id}) or a Google Cloud KMS key
+    /// resource name (projects/{project}/locations/{location}/keyRings/{ring}/cryptoKeys/{key}).
+    pub key: String,
+    /// Topics whose record values are encrypted. All topics are encrypted if empty.
+    #[serde(default)]
+    pub topics: Vec<String>,
+}
+
+impl DekafPayloadEncryption {
+    /// Returns true if record values of `topic` are encrypted.
+    pub fn applies_to(&self, topic: &str) -> bool {
+        self.topics.is_empty() || self.topics.iter().any(|t| t == topic)
+    }
 }
 
 /// Configures a particular binding in a Dekaf-type materialization
@@ -72,6 +261,27 @@ fn token_secret(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::
     .unwrap()
 }
 
+/// Unseal the `config_json` of a Dekaf materialization, which is a
+/// `models::DekafConfig` wrapping a sops-encrypted DekafConfig.
+pub async fn unseal_config(config_json: &str) -> anyhow::Result<DekafConfig> {
+    let parsed_outer_config = serde_json::from_str::<models::DekafConfig>(config_json)
+        .context("validating dekaf config")?;
+
+    serde_json::from_value::<DekafConfig>(
+        unseal::decrypt_sops(&parsed_outer_config.config)
+            .await
+            .context(format!(
+                "decrypting dekaf endpoint config for variant {}",
+                parsed_outer_config.variant
+            ))?
+            .to_value(),
+    )
+    .context(format!(
+        "validating dekaf endpoint config for variant {}",
+        parsed_outer_config.variant
+    ))
+}
+
 pub async fn unary_materialize(
     request: materialize::Request,
 ) -> anyhow::Result<materialize::Response> {
@@ -98,23 +308,7 @@ pub async fn unary_materialize(
             other => bail!("invalid connector type: {}", other.as_str_name()),
         };
 
-        let parsed_outer_config =
-            serde_json::from_str::<models::DekafConfig>(&validate.config_json)
-                .context("validating dekaf config")?;
-
-        let _parsed_inner_config = serde_json::from_value::<DekafConfig>(
-            unseal::decrypt_sops(&parsed_outer_config.config)
-                .await
-                .context(format!(
-                    "decrypting dekaf endpoint config for variant {}",
-                    parsed_outer_config.variant
-                ))?
-                .to_value(),
-        )
-        .context(format!(
-            "validating dekaf endpoint config for variant {}",
-            parsed_outer_config.variant
-        ))?;
+        let _parsed_inner_config = unseal_config(&validate.config_json).await?;
 
         // Largely copied from crates/validation/src/noop.rs
         let validated_bindings = std::mem::take(&mut validate.bindings)
@@ -148,6 +342,7 @@ pub async fn unary_materialize(
         return Ok(materialize::Response {
             validated: Some(materialize::response::Validated {
                 bindings: validated_bindings,
+                requires_bounded_objects: false,
             }),
             ..Default::default()
         });
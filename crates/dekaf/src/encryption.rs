@@ -0,0 +1,236 @@
+use crate::connector::{DekafConfig, DekafPayloadEncryption};
+use aes_siv::{aead::Aead, Aes256SivAead, KeyInit, KeySizeUser};
+use anyhow::Context;
+use rand::RngCore;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Header naming the algorithm with which a record value is encrypted.
+pub const ALGORITHM_HEADER: &str = "flow-encryption";
+/// Header holding the ID of the data key of a record.
+pub const DATA_KEY_ID_HEADER: &str = "flow-encryption-key-id";
+/// Header holding the `sops` document which wraps the data key of a record.
+/// It's attached only to the first encrypted record of each record batch,
+/// and consumers resolve the data keys of other records by their ID.
+pub const DATA_KEY_HEADER: &str = "flow-encryption-key";
+/// Algorithm of encrypted record values.
+pub const ALGORITHM: &str = "AES-256-SIV";
+/// Length of the random nonce which prefixes each encrypted record value.
+pub const NONCE_LEN: usize = 16;
+/// Length of the random ID of a data key, before its hex encoding.
+const DATA_KEY_ID_LEN: usize = 16;
+
+/// Data keys are rotated after this long, bounding the number of records
+/// encrypted under any one data key.
+const DATA_KEY_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// DataKey is a random key which encrypts record values,
+/// along with its `sops` wrapping by a customer-managed key.
+pub struct DataKey {
+    cipher: Aes256SivAead,
+    id: bytes::Bytes,
+    wrapped: bytes::Bytes,
+    created_at: Instant,
+}
+
+impl DataKey {
+    /// Generate a new DataKey wrapped by the customer-managed key of `encryption`.
+    async fn generate(encryption: &DekafPayloadEncryption) -> anyhow::Result<Self> {
+        let mut key = vec![0; Aes256SivAead::key_size()];
+        rand::rngs::OsRng.fill_bytes(&mut key);
+
+        let doc = models::RawValue::from_value(&serde_json::json!({
+            "dataKey": base64::encode(&key),
+        }));
+        let wrapped = unseal::encrypt_sops(&doc, encryption.provider, &encryption.key)
+            .await
+            .context("wrapping data key with customer-managed key")?;
+
+        let mut id = [0; DATA_KEY_ID_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut id);
+
+        Ok(Self {
+            cipher: Aes256SivAead::new_from_slice(&key).unwrap(),
+            id: hex::encode(id).into(),
+            wrapped: bytes::Bytes::copy_from_slice(wrapped.get().as_bytes()),
+            created_at: Instant::now(),
+        })
+    }
+
+    /// Headers of a record value sealed by this DataKey. The `sops` document
+    /// which wraps the data key is included only if `with_wrapped`, as it's
+    /// much larger than the record values it typically encrypts.
+    pub fn headers(&self, with_wrapped: bool) -> Vec<(&'static str, bytes::Bytes)> {
+        let mut headers = vec![
+            (
+                ALGORITHM_HEADER,
+                bytes::Bytes::from_static(ALGORITHM.as_bytes()),
+            ),
+            (DATA_KEY_ID_HEADER, self.id.clone()),
+        ];
+        if with_wrapped {
+            headers.push((DATA_KEY_HEADER, self.wrapped.clone()));
+        }
+        headers
+    }
+
+    /// Encrypt `plaintext`, returning a random nonce followed by its ciphertext.
+    pub fn seal(&self, plaintext: &[u8]) -> Vec<u8> {
+        let mut nonce = [0; NONCE_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut nonce);
+
+        let ciphertext = self
+            .cipher
+            .encrypt(aes_siv::Nonce::from_slice(&nonce), plaintext)
+            .expect("encryption of in-memory buffers cannot fail");
+
+        let mut out = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&ciphertext);
+        out
+    }
+}
+
+/// Decrypt a record value produced by [`DataKey::seal`], given the unwrapped
+/// `data_key`. This is the decryption which consumers must perform.
+pub fn open(data_key: &[u8], value: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let cipher = Aes256SivAead::new_from_slice(data_key)
+        .map_err(|_| anyhow::anyhow!("data key must be {} bytes", Aes256SivAead::key_size()))?;
+
+    if value.len() < NONCE_LEN {
+        anyhow::bail!("encrypted value is shorter than its nonce");
+    }
+    let (nonce, ciphertext) = value.split_at(NONCE_LEN);
+
+    cipher
+        .decrypt(aes_siv::Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow::anyhow!("failed to decrypt record value"))
+}
+
+/// DataKeys holds the current DataKey of each customer-managed key,
+/// shared across all sessions which encrypt with that key.
+#[derive(Default)]
+pub struct DataKeys {
+    keys: std::sync::Mutex<HashMap<String, Arc<DataKeySlot>>>,
+}
+
+/// DataKeySlot holds the current DataKey of a customer-managed key. Its lock
+/// is held while a DataKey is generated, which calls out to the key's KMS,
+/// so that sessions of other keys aren't blocked.
+type DataKeySlot = tokio::sync::Mutex<Option<Arc<DataKey>>>;
+
+impl DataKeys {
+    /// Build an Encryptor of the record values of `topic`, if `config` encrypts them.
+    pub fn encryptor(self: &Arc<Self>, config: &DekafConfig, topic: &str) -> Option<Encryptor> {
+        let encryption = config.encryption.as_ref()?;
+
+        encryption.applies_to(topic).then(|| Encryptor {
+            keys: self.clone(),
+            encryption: encryption.clone(),
+        })
+    }
+
+    async fn get(&self, encryption: &DekafPayloadEncryption) -> anyhow::Result<Arc<DataKey>> {
+        let slot = self
+            .keys
+            .lock()
+            .unwrap()
+            .entry(encryption.key.clone())
+            .or_default()
+            .clone();
+        let mut slot = slot.lock().await;
+
+        match &*slot {
+            Some(data_key) if data_key.created_at.elapsed() < DATA_KEY_TTL => {
+                return Ok(data_key.clone())
+            }
+            _ => (),
+        }
+        let data_key = Arc::new(DataKey::generate(encryption).await?);
+        *slot = Some(data_key.clone());
+
+        metrics::counter!("dekaf_encryption_data_keys_generated").increment(1);
+        Ok(data_key)
+    }
+}
+
+/// Encryptor resolves the current DataKey of a topic's customer-managed key.
+#[derive(Clone)]
+pub struct Encryptor {
+    keys: Arc<DataKeys>,
+    encryption: DekafPayloadEncryption,
+}
+
+impl Encryptor {
+    pub async fn data_key(&self) -> anyhow::Result<Arc<DataKey>> {
+        self.keys.get(&self.encryption).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_seal_and_open() {
+        let key = [7; 64];
+        let data_key = DataKey {
+            cipher: Aes256SivAead::new_from_slice(&key).unwrap(),
+            id: bytes::Bytes::new(),
+            wrapped: bytes::Bytes::new(),
+            created_at: Instant::now(),
+        };
+
+        let one = data_key.seal(b"hello world");
+        let two = data_key.seal(b"hello world");
+
+        // Random nonces make encryptions of equal values distinct.
+        assert_ne!(one, two);
+        assert_eq!(one.len(), NONCE_LEN + 16 + 11);
+
+        assert_eq!(open(&key, &one).unwrap(), b"hello world");
+        assert_eq!(open(&key, &two).unwrap(), b"hello world");
+
+        // Tampering is detected, as are wrong keys.
+        let mut tampered = one.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(open(&key, &tampered).is_err());
+        assert!(open(&[8; 64], &one).is_err());
+        assert!(open(&key, &one[..4]).is_err());
+    }
+
+    #[test]
+    fn test_headers() {
+        let data_key = DataKey {
+            cipher: Aes256SivAead::new_from_slice(&[7; 64]).unwrap(),
+            id: bytes::Bytes::from_static(b"0123456789abcdef0123456789abcdef"),
+            wrapped: bytes::Bytes::from_static(b"{\"dataKey\":\"ENC[...]\",\"sops\":{}}"),
+            created_at: Instant::now(),
+        };
+        let names = |with_wrapped| {
+            data_key
+                .headers(with_wrapped)
+                .into_iter()
+                .map(|(name, value)| format!("{name}: {}", String::from_utf8_lossy(&value)))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(true),
+            vec![
+                "flow-encryption: AES-256-SIV",
+                "flow-encryption-key-id: 0123456789abcdef0123456789abcdef",
+                r#"flow-encryption-key: {"dataKey":"ENC[...]","sops":{}}"#,
+            ]
+        );
+        // Other records of a batch reference their data key only by ID.
+        assert_eq!(
+            names(false),
+            vec![
+                "flow-encryption: AES-256-SIV",
+                "flow-encryption-key-id: 0123456789abcdef0123456789abcdef",
+            ]
+        );
+    }
+}
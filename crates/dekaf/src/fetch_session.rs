@@ -0,0 +1,387 @@
+use kafka_protocol::{
+    error::ResponseError,
+    messages::{
+        fetch_request::{FetchPartition, FetchTopic, ForgottenTopic},
+        fetch_response::FetchableTopicResponse,
+        TopicName,
+    },
+};
+use std::collections::BTreeMap;
+
+/// Epoch of a Fetch request which is sessionless, or which closes a session.
+const FINAL_EPOCH: i32 = -1;
+/// Epoch of a Fetch request which opens a new session.
+const INITIAL_EPOCH: i32 = 0;
+/// Minimum per-partition read target, so that fetches of very many
+/// partitions continue to read reasonably-sized batches.
+const MIN_PARTITION_BUDGET: usize = 64 * 1024;
+
+/// FetchSession is an incremental fetch session, as described by KIP-227.
+/// A session remembers the partitions of a consumer and their fetch
+/// parameters, so that steady-state Fetch requests need only list partitions
+/// which have changed, and responses need only include partitions having
+/// new records or an updated high watermark.
+///
+/// Sessions are scoped to their connection. A consumer which reconnects
+/// presents an unknown session ID, and falls back to a full Fetch.
+#[derive(Debug, Default)]
+pub struct FetchSession {
+    id: i32,
+    // Epoch expected of the next incremental request of this session.
+    epoch: i32,
+    // Partitions of the session, with their current fetch parameters and
+    // the high watermark most-recently returned to the consumer.
+    partitions: BTreeMap<(String, i32), (TopicName, FetchPartition, Option<i64>)>,
+    // ID of the most-recently opened session of this connection.
+    last_id: i32,
+    // Rotation of the order in which partitions of a Fetch are polled.
+    rotation: usize,
+}
+
+/// Resolved is the effective Fetch request of a (possibly incremental) session.
+pub struct Resolved {
+    /// Session ID to return in the response, or zero if sessionless.
+    pub session_id: i32,
+    /// All topics and partitions to be fetched.
+    pub topics: Vec<FetchTopic>,
+    /// Partitions which the consumer has forgotten.
+    pub forgotten: Vec<(TopicName, i32)>,
+    /// Whether the response must be incremental.
+    pub incremental: bool,
+}
+
+impl FetchSession {
+    /// Resolve a Fetch request into its effective topics and partitions.
+    /// An Err is a session-level error of the response.
+    pub fn resolve(
+        &mut self,
+        session_id: i32,
+        session_epoch: i32,
+        topics: Vec<FetchTopic>,
+        forgotten: Vec<ForgottenTopic>,
+    ) -> Result<Resolved, ResponseError> {
+        match session_epoch {
+            FINAL_EPOCH => {
+                // Close the session (if any) and perform a sessionless full fetch.
+                if session_id != 0 && session_id == self.id {
+                    self.close();
+                }
+                Ok(Resolved {
+                    session_id: 0,
+                    topics,
+                    forgotten: Vec::new(),
+                    incremental: false,
+                })
+            }
+            INITIAL_EPOCH => {
+                // Open a new session, replacing any current one, with a full fetch.
+                self.close();
+                self.last_id = self.last_id.checked_add(1).unwrap_or(1);
+                self.id = self.last_id;
+                self.epoch = 1;
+                self.upsert(&topics);
+
+                Ok(Resolved {
+                    session_id: self.id,
+                    topics,
+                    forgotten: Vec::new(),
+                    incremental: false,
+                })
+            }
+            epoch if session_id == 0 || session_id != self.id => {
+                tracing::debug!(session_id, epoch, "fetch session not found");
+                Err(ResponseError::FetchSessionIdNotFound)
+            }
+            epoch if epoch != self.epoch => {
+                tracing::debug!(
+                    session_id,
+                    epoch,
+                    expect = self.epoch,
+                    "invalid fetch epoch"
+                );
+                Err(ResponseError::InvalidFetchSessionEpoch)
+            }
+            epoch => {
+                self.epoch = epoch.checked_add(1).unwrap_or(1);
+                self.upsert(&topics);
+
+                let mut removed = Vec::new();
+                for ForgottenTopic {
+                    topic, partitions, ..
+                } in forgotten
+                {
+                    for partition in partitions {
+                        if self
+                            .partitions
+                            .remove(&(topic.to_string(), partition))
+                            .is_some()
+                        {
+                            removed.push((topic.clone(), partition));
+                        }
+                    }
+                }
+
+                Ok(Resolved {
+                    session_id: self.id,
+                    topics: self.topics(),
+                    forgotten: removed,
+                    incremental: true,
+                })
+            }
+        }
+    }
+
+    /// Observe the `responses` of a resolved Fetch, recording the high
+    /// watermark of each session partition. If `incremental`, then
+    /// partitions without records, errors, or watermark changes are removed.
+    pub fn observe_responses(
+        &mut self,
+        responses: &mut Vec<FetchableTopicResponse>,
+        incremental: bool,
+    ) {
+        if self.id == 0 {
+            return;
+        }
+        for topic in responses.iter_mut() {
+            topic.partitions.retain(|partition| {
+                let key = (topic.topic.to_string(), partition.partition_index);
+                let Some((_, _, last_high_watermark)) = self.partitions.get_mut(&key) else {
+                    return true;
+                };
+
+                let changed = partition.error_code != 0
+                    || partition.records.as_ref().is_some_and(|r| !r.is_empty())
+                    || *last_high_watermark != Some(partition.high_watermark);
+
+                *last_high_watermark = Some(partition.high_watermark);
+                changed || !incremental
+            });
+        }
+        if incremental {
+            responses.retain(|topic| !topic.partitions.is_empty());
+        }
+    }
+
+    /// Order in which to poll the (topic, partition) indices of `topics`.
+    /// The order rotates with each call so that partitions which were deferred
+    /// due to an exhausted `max_bytes` are polled earlier in the next Fetch.
+    pub fn poll_order(&mut self, topics: &[FetchTopic]) -> Vec<(usize, usize)> {
+        let mut order: Vec<(usize, usize)> = topics
+            .iter()
+            .enumerate()
+            .flat_map(|(t, topic)| (0..topic.partitions.len()).map(move |p| (t, p)))
+            .collect();
+
+        if !order.is_empty() {
+            order.rotate_left(self.rotation % order.len());
+        }
+        self.rotation = self.rotation.wrapping_add(1);
+
+        order
+    }
+
+    fn close(&mut self) {
+        self.id = 0;
+        self.epoch = 0;
+        self.partitions.clear();
+    }
+
+    // Add or update session partitions with those of `topics`.
+    fn upsert(&mut self, topics: &[FetchTopic]) {
+        for topic in topics {
+            for partition in &topic.partitions {
+                let key = (topic.topic.to_string(), partition.partition);
+
+                match self.partitions.get_mut(&key) {
+                    Some(entry) => entry.1 = partition.clone(),
+                    None => {
+                        self.partitions
+                            .insert(key, (topic.topic.clone(), partition.clone(), None));
+                    }
+                }
+            }
+        }
+    }
+
+    // Build the topics and partitions of the session, grouped by topic.
+    fn topics(&self) -> Vec<FetchTopic> {
+        let mut topics: Vec<FetchTopic> = Vec::new();
+
+        for ((name, _), (topic, partition, _)) in &self.partitions {
+            match topics.last_mut() {
+                Some(last) if last.topic.as_str() == name => {
+                    last.partitions.push(partition.clone())
+                }
+                _ => topics.push(
+                    FetchTopic::default()
+                        .with_topic(topic.clone())
+                        .with_partitions(vec![partition.clone()]),
+                ),
+            }
+        }
+        topics
+    }
+}
+
+/// Byte target of each partition read, which is a fair share of the Fetch's
+/// `max_bytes` across all of its `partitions`, bounded by `partition_max_bytes`.
+/// A non-positive `max_bytes` is unlimited.
+pub fn partition_budget(max_bytes: i32, partition_max_bytes: i32, partitions: usize) -> usize {
+    let partition_max_bytes = partition_max_bytes.max(0) as usize;
+
+    if max_bytes <= 0 || partitions == 0 {
+        return partition_max_bytes;
+    }
+    let share = (max_bytes as usize / partitions).max(MIN_PARTITION_BUDGET);
+    share.min(partition_max_bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bytes::Bytes;
+    use kafka_protocol::{messages::fetch_response::PartitionData, protocol::StrBytes};
+
+    fn topic(name: &'static str, partitions: &[(i32, i64)]) -> FetchTopic {
+        FetchTopic::default()
+            .with_topic(TopicName::from(StrBytes::from_static_str(name)))
+            .with_partitions(
+                partitions
+                    .iter()
+                    .map(|(index, offset)| {
+                        FetchPartition::default()
+                            .with_partition(*index)
+                            .with_fetch_offset(*offset)
+                    })
+                    .collect(),
+            )
+    }
+
+    fn offsets(topics: &[FetchTopic]) -> Vec<(String, i32, i64)> {
+        topics
+            .iter()
+            .flat_map(|t| {
+                t.partitions
+                    .iter()
+                    .map(|p| (t.topic.to_string(), p.partition, p.fetch_offset))
+            })
+            .collect()
+    }
+
+    fn response(name: &'static str, partitions: &[(i32, i64, usize)]) -> FetchableTopicResponse {
+        FetchableTopicResponse::default()
+            .with_topic(TopicName::from(StrBytes::from_static_str(name)))
+            .with_partitions(
+                partitions
+                    .iter()
+                    .map(|(index, high_watermark, len)| {
+                        PartitionData::default()
+                            .with_partition_index(*index)
+                            .with_high_watermark(*high_watermark)
+                            .with_records(Some(Bytes::from(vec![0; *len])))
+                    })
+                    .collect(),
+            )
+    }
+
+    #[test]
+    fn test_incremental_fetch_session() {
+        let mut session = FetchSession::default();
+
+        // A sessionless fetch passes through, and doesn't open a session.
+        let resolved = session
+            .resolve(0, FINAL_EPOCH, vec![topic("a", &[(0, 10)])], Vec::new())
+            .unwrap();
+        assert_eq!((resolved.session_id, resolved.incremental), (0, false));
+
+        // Incremental fetches of unknown sessions are rejected.
+        assert!(matches!(
+            session.resolve(1, 1, Vec::new(), Vec::new()),
+            Err(ResponseError::FetchSessionIdNotFound)
+        ));
+
+        // Open a session with a full fetch.
+        let resolved = session
+            .resolve(
+                0,
+                INITIAL_EPOCH,
+                vec![topic("a", &[(0, 10), (1, 20)]), topic("b", &[(0, 30)])],
+                Vec::new(),
+            )
+            .unwrap();
+        assert_eq!((resolved.session_id, resolved.incremental), (1, false));
+
+        let mut responses = vec![
+            response("a", &[(0, 10, 0), (1, 25, 5)]),
+            response("b", &[(0, 30, 0)]),
+        ];
+        session.observe_responses(&mut responses, false);
+        assert_eq!(
+            responses.iter().map(|t| t.partitions.len()).sum::<usize>(),
+            3
+        );
+
+        // An incremental fetch updates one partition and forgets another.
+        let resolved = session
+            .resolve(
+                1,
+                1,
+                vec![topic("a", &[(1, 25)])],
+                vec![ForgottenTopic::default()
+                    .with_topic(TopicName::from(StrBytes::from_static_str("b")))
+                    .with_partitions(vec![0])],
+            )
+            .unwrap();
+        assert!(resolved.incremental);
+        assert_eq!(
+            offsets(&resolved.topics),
+            vec![("a".to_string(), 0, 10), ("a".to_string(), 1, 25)]
+        );
+        assert_eq!(resolved.forgotten.len(), 1);
+
+        // Only partitions with records or watermark changes are returned.
+        let mut responses = vec![response("a", &[(0, 10, 0), (1, 25, 0)])];
+        session.observe_responses(&mut responses, true);
+        assert!(responses.is_empty());
+
+        let mut responses = vec![response("a", &[(0, 12, 0), (1, 25, 0)])];
+        session.observe_responses(&mut responses, true);
+        assert_eq!(responses[0].partitions.len(), 1);
+        assert_eq!(responses[0].partitions[0].partition_index, 0);
+
+        // A stale epoch is rejected.
+        assert!(matches!(
+            session.resolve(1, 1, Vec::new(), Vec::new()),
+            Err(ResponseError::InvalidFetchSessionEpoch)
+        ));
+        // The final epoch closes the session.
+        session
+            .resolve(1, FINAL_EPOCH, Vec::new(), Vec::new())
+            .unwrap();
+        assert!(matches!(
+            session.resolve(1, 2, Vec::new(), Vec::new()),
+            Err(ResponseError::FetchSessionIdNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_fair_partition_polling() {
+        let mut session = FetchSession::default();
+        let topics = vec![topic("a", &[(0, 0), (1, 0)]), topic("b", &[(0, 0)])];
+
+        assert_eq!(session.poll_order(&topics), vec![(0, 0), (0, 1), (1, 0)]);
+        assert_eq!(session.poll_order(&topics), vec![(0, 1), (1, 0), (0, 0)]);
+        assert_eq!(session.poll_order(&topics), vec![(1, 0), (0, 0), (0, 1)]);
+        assert_eq!(session.poll_order(&topics), vec![(0, 0), (0, 1), (1, 0)]);
+        assert!(session.poll_order(&[]).is_empty());
+
+        const MB: i32 = 1 << 20;
+        // Budgets are a fair share, bounded by the partition maximum.
+        assert_eq!(partition_budget(50 * MB, MB, 10), MB as usize);
+        assert_eq!(partition_budget(50 * MB, MB, 100), (50 * MB / 100) as usize);
+        // Shares don't fall below a minimum.
+        assert_eq!(partition_budget(MB, MB, 1000), MIN_PARTITION_BUDGET);
+        // Non-positive maximums are unlimited.
+        assert_eq!(partition_budget(0, MB, 1000), MB as usize);
+    }
+}
@@ -12,18 +12,38 @@ use topology::{Collection, Partition};
 mod read;
 use read::Read;
 
+mod rate_limit;
+
+mod fetch_session;
+
+mod commit_batch;
+
+mod metrics_topic;
+
+mod produce;
+
+mod profile;
+
 mod session;
 pub use session::Session;
 
+pub mod warm_start;
+pub use warm_start::WarmStart;
+
+mod schema_ids;
+pub use schema_ids::SchemaIds;
+
 pub mod connector;
+pub mod encryption;
 pub mod metrics_server;
 pub mod registry;
+pub mod self_test;
 
 mod api_client;
 pub use api_client::KafkaApiClient;
 
 use aes_siv::{aead::Aead, Aes256SivAead, KeyInit, KeySizeUser};
-use connector::{DekafConfig, DeletionMode};
+use connector::{DekafConfig, DeletionMode, JsonKeyFormat, MessageFormat};
 use flow_client::client::{refresh_authorizations, RefreshToken};
 use percent_encoding::{percent_decode_str, utf8_percent_encode};
 use serde::{Deserialize, Serialize};
@@ -38,6 +58,22 @@ pub struct App {
     pub secret: String,
     /// Share a single base client in order to re-use connection pools
     pub client_base: flow_client::Client,
+    /// Throughput of served topics, which is exposed through `__dekaf_metrics` topics.
+    pub throughput_stats: metrics_topic::ThroughputStats,
+    /// Committed offsets and write heads, from which consumer lag metrics are exported.
+    pub consumer_offsets: metrics_server::ConsumerOffsets,
+    /// Read historical journal fragments directly from their fragment stores.
+    pub read_fragments_directly: bool,
+    /// Alternate hostname which is advertised for Kafka access while draining.
+    pub drain_advertise_host: Option<String>,
+    /// Set once Dekaf begins draining its sessions ahead of shutdown.
+    pub draining: std::sync::atomic::AtomicBool,
+    /// Recently active tasks, whose topology is prefetched after a restart.
+    pub warm_start: WarmStart,
+    /// Data keys with which record values are encrypted, by customer-managed key.
+    pub data_keys: std::sync::Arc<encryption::DataKeys>,
+    /// Registry IDs of Avro schemas, which are stable across restarts.
+    pub schema_ids: SchemaIds,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Copy)]
@@ -47,28 +83,49 @@ pub struct DeprecatedConfigOptions {
     pub strict_topic_names: bool,
     #[serde(default)]
     pub deletions: DeletionMode,
+    #[serde(default)]
+    pub format: MessageFormat,
+    #[serde(default)]
+    pub json_key_format: JsonKeyFormat,
 }
 
 pub struct Authenticated {
     client: flow_client::Client,
-    refresh_token: RefreshToken,
+    /// Refresh token of the session, or None if the session was authenticated
+    /// with a bearer access token which must be re-authenticated before it expires.
+    refresh_token: Option<RefreshToken>,
     access_token: String,
     task_config: DekafConfig,
+    /// Name of the authenticated materialization, if this is a task session.
+    task_name: Option<String>,
+    /// Topic names of the bindings of the authenticated materialization.
+    topic_names: topology::TopicNames,
     claims: models::authorizations::ControlClaims,
 }
 
 impl Authenticated {
     pub async fn authenticated_client(&mut self) -> anyhow::Result<&flow_client::Client> {
+        let Some(refresh_token) = &self.refresh_token else {
+            // Bearer sessions cannot be refreshed, and clients are expected
+            // to re-authenticate with a fresh token prior to its expiry.
+            if self.claims.time_remaining().is_zero() {
+                anyhow::bail!(
+                    "bearer access token has expired and the session must re-authenticate"
+                );
+            }
+            return Ok(&self.client);
+        };
+
         let (access, refresh) = refresh_authorizations(
             &self.client,
             Some(self.access_token.to_owned()),
-            Some(self.refresh_token.to_owned()),
+            Some(refresh_token.to_owned()),
         )
         .await?;
 
         if access != self.access_token {
             self.access_token = access.clone();
-            self.refresh_token = refresh;
+            self.refresh_token = Some(refresh);
 
             self.client = self
                 .client
@@ -82,20 +139,92 @@ impl Authenticated {
 }
 
 impl App {
+    /// Begin draining sessions ahead of shutdown. While draining, clients are
+    /// advertised the alternate drain host, and their consumer groups are
+    /// asked to rebalance so that group members re-join elsewhere.
+    pub fn start_draining(&self) {
+        self.draining
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Hostname to advertise to clients for Kafka access.
+    pub fn advertised_host(&self) -> &str {
+        match &self.drain_advertise_host {
+            Some(host) if self.is_draining() => host,
+            _ => &self.advertise_host,
+        }
+    }
+
     #[tracing::instrument(level = "info", err(Debug, level = "warn"), skip(self, password))]
     async fn authenticate(&self, username: &str, password: &str) -> anyhow::Result<Authenticated> {
-        let username = if let Ok(decoded) = decode_safe_name(username.to_string()) {
-            decoded
-        } else {
-            username.to_string()
-        };
-
         let raw_token = String::from_utf8(base64::decode(password)?.to_vec())?;
         let refresh: RefreshToken = serde_json::from_str(raw_token.as_str())?;
 
         let (access, refresh) =
             refresh_authorizations(&self.client_base, None, Some(refresh)).await?;
 
+        self.authorize(username, access, Some(refresh)).await
+    }
+
+    /// Authenticate a session using a short-lived control-plane access token,
+    /// as presented through SASL OAUTHBEARER. `authzid` plays the role of a
+    /// PLAIN username, and is either a task name or a JSON object of options.
+    /// The token is verified by the control-plane, and its user must be
+    /// authorized to the tenant of a task (see `check_bearer_authzid`).
+    #[tracing::instrument(level = "info", err(Debug, level = "warn"), skip(self, token))]
+    async fn authenticate_bearer(
+        &self,
+        authzid: &str,
+        token: &str,
+    ) -> anyhow::Result<Authenticated> {
+        let claims: models::authorizations::ControlClaims =
+            flow_client::parse_jwt_claims(token).context("parsing bearer token claims")?;
+
+        let now = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_secs();
+
+        if claims.exp <= now {
+            anyhow::bail!("bearer token has expired");
+        } else if claims.iat > now + BEARER_CLOCK_SKEW_SECS {
+            anyhow::bail!("bearer token was issued in the future");
+        }
+
+        // The control-plane verifies the token as part of listing its roles.
+        let roles: Vec<Role> = flow_client::api_exec(
+            self.client_base
+                .clone()
+                .with_user_access_token(Some(token.to_string()))
+                .rpc(
+                    "auth_roles",
+                    serde_json::json!({"min_capability": "read"}).to_string(),
+                ),
+        )
+        .await
+        .context("verifying bearer token with the control-plane")?;
+
+        let authzid = check_bearer_authzid(authzid, &roles)?;
+
+        self.authorize(authzid, token.to_string(), None).await
+    }
+
+    /// Build an Authenticated session of `username` and its access token.
+    async fn authorize(
+        &self,
+        username: &str,
+        access: String,
+        refresh: Option<RefreshToken>,
+    ) -> anyhow::Result<Authenticated> {
+        let username = if let Ok(decoded) = decode_safe_name(username.to_string()) {
+            decoded
+        } else {
+            username.to_string()
+        };
+
         let client = self
             .client_base
             .clone()
@@ -104,14 +233,22 @@ impl App {
 
         let claims = flow_client::client::client_claims(&client)?;
 
-        if models::Materialization::regex().is_match(username.as_ref())
-            && !username.starts_with("{")
-        {
+        if is_task_name(&username) {
+            // The user must be able to read the task's specification,
+            // from which its topic names and sealed configuration are taken.
+            let spec = topology::fetch_task_spec(&client, &username).await?;
+            let topic_names = topology::TopicNames::from_spec(&spec)?;
+            let task_config = connector::unseal_config(&spec.config_json)
+                .await
+                .with_context(|| format!("unsealing configuration of task {username}"))?;
+
             Ok(Authenticated {
                 client,
                 access_token: access,
                 refresh_token: refresh,
-                task_config: todo!("Fetch and unseal task config"),
+                task_config,
+                task_name: Some(username),
+                topic_names,
                 claims,
             })
         } else if username.contains("{") {
@@ -123,8 +260,21 @@ impl App {
                 task_config: DekafConfig {
                     strict_topic_names: config.strict_topic_names,
                     deletions: config.deletions,
+                    format: config.format,
+                    json_key_format: config.json_key_format,
+                    metrics_topic: false,
                     token: "".to_string(),
+                    max_fetch_bytes_per_second: None,
+                    max_fetch_requests_per_second: None,
+                    reduction_headers: false,
+                    compression: Default::default(),
+                    writable_topics: Vec::new(),
+                    union_topics: Vec::new(),
+                    encryption: None,
+                    profile: Default::default(),
                 },
+                task_name: None,
+                topic_names: topology::TopicNames::default(),
                 access_token: access,
                 refresh_token: refresh,
                 claims,
@@ -135,6 +285,67 @@ impl App {
     }
 }
 
+/// Tolerated skew, in seconds, between our clock and that of the issuer of a bearer token.
+const BEARER_CLOCK_SKEW_SECS: u64 = 60;
+
+/// A role granted to the user of a bearer token.
+#[derive(serde::Deserialize)]
+struct Role {
+    role_prefix: String,
+}
+
+/// Check the authorization identity of a bearer session against the `roles`
+/// of its token, returning the username with which the session is authorized.
+/// An empty `authzid` is a session having default options, and a task name
+/// must fall within a role of the token.
+fn check_bearer_authzid<'a>(authzid: &'a str, roles: &[Role]) -> anyhow::Result<&'a str> {
+    let authzid = if authzid.is_empty() { "{}" } else { authzid };
+    let username = decode_safe_name(authzid.to_string()).unwrap_or(authzid.to_string());
+
+    if is_task_name(&username)
+        && !roles
+            .iter()
+            .any(|role| username.starts_with(&role.role_prefix))
+    {
+        anyhow::bail!("bearer token is not authorized to the tenant of task {username}");
+    }
+    Ok(authzid)
+}
+
+/// Is `username` the name of a task, rather than a JSON object of options?
+fn is_task_name(username: &str) -> bool {
+    models::Materialization::regex().is_match(username) && !username.starts_with("{")
+}
+
+/// Parse the initial client response of a SASL OAUTHBEARER exchange (RFC 7628),
+/// returning its authorization identity (which may be empty) and bearer token.
+pub fn parse_oauthbearer(auth_bytes: &[u8]) -> anyhow::Result<(&str, &str)> {
+    let message = std::str::from_utf8(auth_bytes).context("OAUTHBEARER message is not UTF-8")?;
+
+    // The GS2 header is followed by key/value pairs separated by \x01.
+    let mut it = message.split('\x01');
+    let gs2_header = it.next().context("expected OAUTHBEARER GS2 header")?;
+
+    let authzid = match gs2_header.split(',').collect::<Vec<_>>()[..] {
+        ["n" | "y", "", ""] => "",
+        ["n" | "y", authzid, ""] if authzid.starts_with("a=") => &authzid[2..],
+        _ => anyhow::bail!("invalid OAUTHBEARER GS2 header {gs2_header:?}"),
+    };
+
+    for kv in it {
+        if let Some(value) = kv.strip_prefix("auth=") {
+            let Some(token) = value
+                .strip_prefix("Bearer ")
+                .or_else(|| value.strip_prefix("bearer "))
+            else {
+                anyhow::bail!("OAUTHBEARER auth value is not a bearer token");
+            };
+            return Ok((authzid, token.trim()));
+        }
+    }
+    anyhow::bail!("OAUTHBEARER message has no auth value")
+}
+
 /// Dispatch a read request `frame` of the current session, writing its response into `out`.
 /// `raw_sasl_auth` is the state of SASL "raw" mode authentication,
 /// and conditions the interpretation of request frames.
@@ -249,7 +460,11 @@ async fn handle_api(
 
         ApiKey::FetchKey => {
             let (header, request) = dec_request(frame, version)?;
-            Ok(enc_resp(out, &header, session.fetch(request).await?))
+            Ok(enc_resp(
+                out,
+                &header,
+                session.fetch(request, version).await?,
+            ))
         }
 
         ApiKey::DescribeConfigsKey => {
@@ -260,9 +475,32 @@ async fn handle_api(
                 session.describe_configs(request).await?,
             ))
         }
+        ApiKey::DescribeClusterKey => {
+            let (header, request) = dec_request(frame, version)?;
+            Ok(enc_resp(
+                out,
+                &header,
+                session.describe_cluster(request).await?,
+            ))
+        }
+        ApiKey::DescribeLogDirsKey => {
+            let (header, request) = dec_request(frame, version)?;
+            Ok(enc_resp(
+                out,
+                &header,
+                session.describe_log_dirs(request).await?,
+            ))
+        }
         ApiKey::ProduceKey => {
             let (header, request) = dec_request(frame, version)?;
-            Ok(enc_resp(out, &header, session.produce(request).await?))
+            // Producers which request no acknowledgement (acks=0) expect no response.
+            let acks = request.acks;
+            let response = session.produce(request).await?;
+
+            if acks != 0 {
+                enc_resp(out, &header, response);
+            }
+            Ok(())
         }
 
         ApiKey::JoinGroupKey => {
@@ -329,6 +567,22 @@ async fn handle_api(
                 session.offset_commit(request, header).await?,
             ))
         }
+        ApiKey::DescribeGroupsKey => {
+            let (header, request) = dec_request(frame, version)?;
+            Ok(enc_resp(
+                out,
+                &header.clone(),
+                session.describe_groups(request, header).await?,
+            ))
+        }
+        ApiKey::OffsetDeleteKey => {
+            let (header, request) = dec_request(frame, version)?;
+            Ok(enc_resp(
+                out,
+                &header.clone(),
+                session.offset_delete(request, header).await?,
+            ))
+        }
         /*
         ApiKey::CreateTopicsKey => Ok(K::CreateTopicsRequest(CreateTopicsRequest::decode(b, v)?)),
         ApiKey::ListGroupsKey => Ok(K::ListGroupsRequest(ListGroupsRequest::decode(b, v)?)),
@@ -514,4 +768,48 @@ mod test {
 
         assert_eq!(decrypted.as_str(), "Test Topic");
     }
+
+    #[test]
+    fn test_parse_oauthbearer() {
+        assert_eq!(
+            crate::parse_oauthbearer(b"n,,\x01auth=Bearer the.jwt.token\x01\x01").unwrap(),
+            ("", "the.jwt.token")
+        );
+        assert_eq!(
+            crate::parse_oauthbearer(b"n,a=acmeCo/dekaf,\x01host=dekaf\x01auth=Bearer tok\x01\x01")
+                .unwrap(),
+            ("acmeCo/dekaf", "tok")
+        );
+
+        for invalid in [
+            &b"n,acmeCo,\x01auth=Bearer tok\x01\x01"[..],
+            b"p=tls-unique,,\x01auth=Bearer tok\x01\x01",
+            b"n,,\x01auth=Basic dXNlcjpwYXNz\x01\x01",
+            b"n,,\x01\x01",
+        ] {
+            assert!(crate::parse_oauthbearer(invalid).is_err(), "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn test_check_bearer_authzid() {
+        let roles = [crate::Role {
+            role_prefix: "acmeCo/".to_string(),
+        }];
+
+        // Sessions without an authzid have default options.
+        assert_eq!(crate::check_bearer_authzid("", &roles).unwrap(), "{}");
+        assert_eq!(
+            crate::check_bearer_authzid(r#"{"deletions":"cdc"}"#, &[]).unwrap(),
+            r#"{"deletions":"cdc"}"#
+        );
+        // Tasks must fall within a role of the token.
+        assert_eq!(
+            crate::check_bearer_authzid("acmeCo/dekaf", &roles).unwrap(),
+            "acmeCo/dekaf"
+        );
+        let err = crate::check_bearer_authzid("otherCo/dekaf", &roles).unwrap_err();
+        assert!(err.to_string().contains("not authorized"), "{err}");
+        assert!(crate::check_bearer_authzid("acmeCo/dekaf", &[]).is_err());
+    }
 }
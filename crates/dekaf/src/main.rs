@@ -77,10 +77,63 @@ pub struct Cli {
     #[arg(long, env = "ENCRYPTION_SECRET")]
     encryption_secret: String,
 
+    /// Read persisted journal fragments directly from cloud storage using
+    /// signed URLs, rather than proxying their content through brokers.
+    #[arg(long, env = "READ_FRAGMENTS_DIRECTLY")]
+    read_fragments_directly: bool,
+
     /// How long to wait for a message before closing an idle connection
     #[arg(long, env = "IDLE_SESSION_TIMEOUT", value_parser = humantime::parse_duration, default_value = "30s")]
     idle_session_timeout: std::time::Duration,
 
+    /// Alternate hostname to advertise to clients while draining sessions
+    /// ahead of shutdown, such as that of another Dekaf deployment.
+    #[arg(long, env = "DRAIN_ADVERTISE_HOST")]
+    drain_advertise_host: Option<String>,
+    /// How long to wait for connected sessions to move elsewhere
+    /// or complete their in-flight requests, before exiting.
+    #[arg(long, env = "DRAIN_TIMEOUT", value_parser = humantime::parse_duration, default_value = "30s")]
+    drain_timeout: std::time::Duration,
+
+    /// Endpoint, such as that of the control-plane, to which periodic
+    /// reports of the consumer lag of each task are POSTed as JSON,
+    /// authenticated by an access token of the task.
+    #[arg(long, env = "LAG_REPORT_ENDPOINT")]
+    lag_report_endpoint: Option<Url>,
+    /// Interval at which consumer lag reports are sent.
+    #[arg(long, env = "LAG_REPORT_INTERVAL", value_parser = humantime::parse_duration, default_value = "1m")]
+    lag_report_interval: std::time::Duration,
+
+    /// File into which recently active tasks are persisted, so that their
+    /// topology may be prefetched after a restart.
+    #[arg(long, env = "WARM_START_FILE")]
+    warm_start_file: Option<std::path::PathBuf>,
+    /// Maximum number of concurrent topology prefetches after a restart.
+    #[arg(long, env = "WARM_START_CONCURRENCY", default_value = "8")]
+    warm_start_concurrency: usize,
+    /// Interval at which recently active tasks are persisted.
+    #[arg(long, env = "WARM_START_PERSIST_INTERVAL", value_parser = humantime::parse_duration, default_value = "1m")]
+    warm_start_persist_interval: std::time::Duration,
+    /// File into which registry IDs of Avro schemas are persisted, so that
+    /// they're served without re-resolving them after a restart.
+    #[arg(long, env = "SCHEMA_IDS_FILE")]
+    schema_ids_file: Option<std::path::PathBuf>,
+    /// Interval at which registry IDs of Avro schemas are persisted.
+    #[arg(long, env = "SCHEMA_IDS_PERSIST_INTERVAL", value_parser = humantime::parse_duration, default_value = "1m")]
+    schema_ids_persist_interval: std::time::Duration,
+
+    /// Run a self-test of the configuration and its dependencies, print a
+    /// JSON report to stdout, and exit non-zero if any check failed.
+    #[arg(long)]
+    self_test: bool,
+    /// Credential, in the form of a SASL PLAIN password, used by the self-test
+    /// to resolve the data-plane route of `--self-test-collection`.
+    #[arg(long, env = "SELF_TEST_TOKEN", requires = "self_test_collection")]
+    self_test_token: Option<String>,
+    /// Collection whose data-plane route is resolved by the self-test.
+    #[arg(long, env = "SELF_TEST_COLLECTION", requires = "self_test_token")]
+    self_test_collection: Option<String>,
+
     #[command(flatten)]
     tls: Option<TlsArgs>,
 }
@@ -137,8 +190,78 @@ async fn main() -> anyhow::Result<()> {
             api_endpoint,
             None,
         ),
+        throughput_stats: Default::default(),
+        consumer_offsets: Default::default(),
+        read_fragments_directly: cli.read_fragments_directly,
+        drain_advertise_host: cli.drain_advertise_host.clone(),
+        draining: Default::default(),
+        warm_start: dekaf::WarmStart::load(cli.warm_start_file.clone(), cli.warm_start_concurrency),
+        data_keys: Default::default(),
+        schema_ids: dekaf::SchemaIds::load(cli.schema_ids_file.clone()),
     });
 
+    let broker_username = cli.default_broker_username.as_str();
+    let broker_password = cli.default_broker_password.as_str();
+
+    if cli.self_test {
+        let mut report = dekaf::self_test::Report::default();
+
+        if let Some(tls_cfg) = &cli.tls {
+            report
+                .run("tls", async {
+                    let certs = load_certs(tls_cfg.certificate_file.as_ref().unwrap())?;
+                    load_key(tls_cfg.certificate_key_file.as_ref().unwrap())?;
+
+                    if !validate_certificate_name(&certs, &cli.advertise_host)? {
+                        bail!(
+                            "Provided certificate does not include '{}' as a common or alternative name",
+                            cli.advertise_host
+                        )
+                    }
+                    Ok(format!("certificate is valid for {}", cli.advertise_host))
+                })
+                .await;
+        } else {
+            report.skip("tls", "no TLS certificate was provided");
+        }
+
+        let self_test = dekaf::self_test::SelfTest {
+            session: Session::new(
+                app.clone(),
+                cli.encryption_secret.to_owned(),
+                upstream_kafka_host.to_string(),
+                broker_username.to_string(),
+                broker_password.to_string(),
+            ),
+            listeners: vec![
+                (
+                    "kafka-listener",
+                    format!("[::]:{}", cli.kafka_port).parse()?,
+                ),
+                (
+                    "schema-registry-listener",
+                    format!("[::]:{}", cli.schema_registry_port).parse()?,
+                ),
+                (
+                    "metrics-listener",
+                    format!("[::]:{}", cli.metrics_port).parse()?,
+                ),
+            ],
+            route_probe: cli
+                .self_test_token
+                .clone()
+                .zip(cli.self_test_collection.clone()),
+        };
+        let report = dekaf::self_test::run(&app, self_test, report).await;
+
+        serde_json::to_writer_pretty(std::io::stdout(), &report)?;
+        println!();
+        std::process::exit(if report.passed { 0 } else { 1 });
+    }
+
+    // Tracks served Kafka sessions, which are drained before exiting.
+    let sessions = tokio_util::task::TaskTracker::new();
+
     let mut stop = async {
         tokio::signal::ctrl_c()
             .await
@@ -158,10 +281,36 @@ async fn main() -> anyhow::Result<()> {
         axum_server::bind(metrics_addr).serve(metrics_router.into_make_service());
     tokio::spawn(async move { metrics_server_task.await.unwrap() });
 
+    if let Some(endpoint) = cli.lag_report_endpoint.clone() {
+        let (app, interval) = (app.clone(), cli.lag_report_interval);
+        tokio::spawn(async move {
+            dekaf::metrics_server::serve_lag_reports(
+                &app.consumer_offsets,
+                &app.advertise_host,
+                endpoint,
+                interval,
+            )
+            .await
+        });
+    }
+
+    if cli.warm_start_file.is_some() {
+        let (app, interval) = (app.clone(), cli.warm_start_persist_interval);
+        tokio::spawn(async move {
+            // Prefetch recent tasks loaded from the file, while periodically persisting.
+            tokio::join!(
+                dekaf::warm_start::prefetch_recent(&app),
+                app.warm_start.serve_persistence(interval),
+            )
+        });
+    }
+    if cli.schema_ids_file.is_some() {
+        let (app, interval) = (app.clone(), cli.schema_ids_persist_interval);
+        tokio::spawn(async move { app.schema_ids.serve_persistence(interval).await });
+    }
+
     let schema_router = dekaf::registry::build_router(app.clone());
 
-    let broker_username = cli.default_broker_username.as_str();
-    let broker_password = cli.default_broker_password.as_str();
     if let Some(tls_cfg) = cli.tls {
         let axum_rustls_config = RustlsConfig::from_pem_file(
             tls_cfg.certificate_file.clone().unwrap(),
@@ -209,7 +358,7 @@ async fn main() -> anyhow::Result<()> {
                         continue
                     };
 
-                    tokio::spawn(
+                    sessions.spawn(
                         serve(
                             Session::new(
                                 app.clone(),
@@ -244,7 +393,7 @@ async fn main() -> anyhow::Result<()> {
                     };
                     socket.set_nodelay(true)?;
 
-                    tokio::spawn(
+                    sessions.spawn(
                         serve(
                             Session::new(
                                 app.clone(),
@@ -265,6 +414,33 @@ async fn main() -> anyhow::Result<()> {
         }
     };
 
+    // Stop accepting new sessions, and drain those which remain.
+    std::mem::drop(kafka_listener);
+    app.start_draining();
+    sessions.close();
+
+    tracing::info!(
+        sessions = sessions.len(),
+        drain_timeout = ?cli.drain_timeout,
+        "draining Kafka sessions"
+    );
+    if tokio::time::timeout(cli.drain_timeout, sessions.wait())
+        .await
+        .is_err()
+    {
+        tracing::warn!(
+            sessions = sessions.len(),
+            "drain timeout elapsed with sessions remaining"
+        );
+    }
+
+    if let Err(err) = app.warm_start.persist() {
+        tracing::warn!(?err, "failed to persist recent tasks for warm start");
+    }
+    if let Err(err) = app.schema_ids.persist() {
+        tracing::warn!(?err, "failed to persist cached schema IDs");
+    }
+
     Ok(())
 }
 
@@ -297,8 +473,22 @@ where
 
     let result = async {
         loop {
-            let Some(frame) = tokio::time::timeout(idle_timeout, r.try_next())
-                .await
+            let read = tokio::time::timeout(idle_timeout, r.try_next());
+            let flush_at = session
+                .offset_commit_deadline()
+                .map(tokio::time::Instant::from_std);
+
+            // Flush batched offset commits as they come due, even if the client is idle.
+            // Reads of `r` are cancellation-safe, and resume on the next iteration.
+            let frame = tokio::select! {
+                frame = read => frame,
+                () = tokio::time::sleep_until(flush_at.unwrap_or_else(tokio::time::Instant::now)),
+                    if flush_at.is_some() => {
+                    session.flush_due_offset_commits().await;
+                    continue;
+                }
+            };
+            let Some(frame) = frame
                 .context("timeout waiting for next session request")?
                 .context("failed to read next session request")?
             else {
@@ -314,6 +504,8 @@ where
     }
     .await;
 
+    // Flush commits which were acknowledged but not yet written upstream.
+    session.flush_offset_commits().await;
     metrics::gauge!("dekaf_total_connections").decrement(1);
 
     w.shutdown().await?;
@@ -1,4 +1,6 @@
 use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use std::collections::{BTreeMap, HashMap};
+use std::time::Instant;
 
 pub fn build_router() -> axum::Router<()> {
     use axum::routing::get;
@@ -38,3 +40,452 @@ async fn prometheus_metrics(
 
     (axum::http::StatusCode::OK, prom_handle.render())
 }
+
+/// Duration after which the offsets of a partition which haven't been
+/// committed or read are evicted, along with tasks having no remaining partitions.
+const OFFSETS_TTL: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+/// Maximum number of partitions whose offsets are tracked, across all tasks.
+/// Beyond it, the least-recently updated partitions are evicted
+/// until a tenth of the maximum is free.
+const MAX_OFFSETS_PARTITIONS: usize = 100_000;
+
+#[derive(Default)]
+struct PartitionOffsets {
+    write_head: Option<i64>,          // Most-recent observed journal write head.
+    committed: BTreeMap<String, i64>, // Committed offsets, by consumer group.
+    updated_at: Option<Instant>,      // Last commit or observed write head.
+}
+
+#[derive(Default)]
+struct TaskOffsets {
+    // Most-recent access token of a session of the task, which authenticates
+    // lag reports of the task.
+    access_token: Option<String>,
+    partitions: HashMap<(String, i32), PartitionOffsets>,
+}
+
+/// ConsumerOffsets tracks the committed offsets of consumer groups and the
+/// observed write heads of journals, by task, topic, and partition, and
+/// exports the `dekaf_consumer_lag` of each group as their difference.
+/// Topic names are scoped to a task, as tasks may name their topics alike.
+/// Sessions which aren't of a task are tracked under an empty task name.
+#[derive(Default)]
+pub struct ConsumerOffsets {
+    inner: std::sync::Mutex<(HashMap<String, TaskOffsets>, Option<Instant>)>,
+}
+
+/// LagSummary is the consumer lag of a group of a Dekaf task, over the
+/// partitions of a topic for which both a commit and write head are known.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LagSummary {
+    /// Consumer group of the task.
+    pub group: String,
+    /// Topic (collection) which is consumed.
+    pub topic: String,
+    /// Number of partitions having a known lag.
+    pub partitions: usize,
+    /// Sum of the lag of each partition, in bytes.
+    pub total_lag: i64,
+    /// Largest lag of any single partition, in bytes.
+    pub max_lag: i64,
+}
+
+/// TaskLag is the LagSummaries of a Dekaf task, with the access token
+/// of the task which authenticates their report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskLag {
+    pub task: String,
+    pub access_token: String,
+    pub summaries: Vec<LagSummary>,
+}
+
+impl ConsumerOffsets {
+    pub fn record_commit(
+        &self,
+        task: Option<&str>,
+        access_token: &str,
+        group: &str,
+        topic: &str,
+        partition: i32,
+        offset: i64,
+    ) {
+        self.update(task, |task_offsets, now| {
+            if task.is_some() && task_offsets.access_token.as_deref() != Some(access_token) {
+                task_offsets.access_token = Some(access_token.to_string());
+            }
+            let entry = task_offsets
+                .partitions
+                .entry((topic.to_string(), partition))
+                .or_default();
+
+            entry.committed.insert(group.to_string(), offset);
+            entry.updated_at = Some(now);
+
+            if let Some(write_head) = entry.write_head {
+                set_consumer_lag(group, topic, partition, write_head - offset);
+            }
+        })
+    }
+
+    pub fn record_write_head(
+        &self,
+        task: Option<&str>,
+        topic: &str,
+        partition: i32,
+        write_head: i64,
+    ) {
+        self.update(task, |task_offsets, now| {
+            let entry = task_offsets
+                .partitions
+                .entry((topic.to_string(), partition))
+                .or_default();
+
+            entry.updated_at = Some(now);
+
+            if entry.write_head == Some(write_head) {
+                return;
+            }
+            entry.write_head = Some(write_head);
+
+            for (group, offset) in entry.committed.iter() {
+                set_consumer_lag(group, topic, partition, write_head - offset);
+            }
+        })
+    }
+
+    fn update(&self, task: Option<&str>, f: impl FnOnce(&mut TaskOffsets, Instant)) {
+        let now = Instant::now();
+        let mut guard = self.inner.lock().unwrap();
+        let (tasks, swept_at) = &mut *guard;
+
+        // Sweep for idle offsets at most once per TTL.
+        if swept_at.map_or(true, |at| now.duration_since(at) >= OFFSETS_TTL) {
+            evict_idle(tasks, now);
+            *swept_at = Some(now);
+        }
+
+        let task_offsets = match tasks.get_mut(task.unwrap_or_default()) {
+            Some(task_offsets) => task_offsets,
+            None => tasks
+                .entry(task.unwrap_or_default().to_string())
+                .or_default(),
+        };
+        f(task_offsets, now);
+
+        let total: usize = tasks.values().map(|t| t.partitions.len()).sum();
+        if total > MAX_OFFSETS_PARTITIONS {
+            evict_least_recent(tasks, MAX_OFFSETS_PARTITIONS - MAX_OFFSETS_PARTITIONS / 10);
+        }
+    }
+
+    /// Consumer lag of `group` of `task` on the topic partition, if known.
+    pub fn lag(&self, task: Option<&str>, group: &str, topic: &str, partition: i32) -> Option<i64> {
+        let guard = self.inner.lock().unwrap();
+        let entry = guard
+            .0
+            .get(task.unwrap_or_default())?
+            .partitions
+            .get(&(topic.to_string(), partition))?;
+
+        Some(std::cmp::max(
+            entry.write_head? - entry.committed.get(group)?,
+            0,
+        ))
+    }
+
+    /// Summarize the current consumer lag of each group of each task having
+    /// an access token, by topic. Tasks are ordered on their name, and their
+    /// summaries on (group, topic).
+    pub fn lag_summaries(&self) -> Vec<TaskLag> {
+        let guard = self.inner.lock().unwrap();
+        let mut out = Vec::new();
+
+        for (task, task_offsets) in guard.0.iter() {
+            let Some(access_token) = &task_offsets.access_token else {
+                continue; // Not a task, or no commits of a session of the task.
+            };
+            let mut summaries: BTreeMap<(&str, &str), LagSummary> = BTreeMap::new();
+
+            for ((topic, _partition), entry) in task_offsets.partitions.iter() {
+                let Some(write_head) = entry.write_head else {
+                    continue;
+                };
+                for (group, offset) in entry.committed.iter() {
+                    let lag = std::cmp::max(write_head - offset, 0);
+
+                    let summary = summaries
+                        .entry((group.as_str(), topic.as_str()))
+                        .or_insert_with(|| LagSummary {
+                            group: group.clone(),
+                            topic: topic.clone(),
+                            partitions: 0,
+                            total_lag: 0,
+                            max_lag: 0,
+                        });
+                    summary.partitions += 1;
+                    summary.total_lag += lag;
+                    summary.max_lag = std::cmp::max(summary.max_lag, lag);
+                }
+            }
+            if !summaries.is_empty() {
+                out.push(TaskLag {
+                    task: task.clone(),
+                    access_token: access_token.clone(),
+                    summaries: summaries.into_values().collect(),
+                });
+            }
+        }
+        out.sort_by(|l, r| l.task.cmp(&r.task));
+        out
+    }
+}
+
+/// Evict the offsets of partitions which haven't been updated within
+/// OFFSETS_TTL of `now`, and then tasks which have no remaining partitions.
+fn evict_idle(tasks: &mut HashMap<String, TaskOffsets>, now: Instant) {
+    tasks.retain(|_, task_offsets| {
+        task_offsets.partitions.retain(|_, entry| {
+            entry
+                .updated_at
+                .is_some_and(|at| now.saturating_duration_since(at) < OFFSETS_TTL)
+        });
+        !task_offsets.partitions.is_empty()
+    });
+}
+
+/// Evict the offsets of the least-recently updated partitions until at most
+/// `retain` partitions remain, and then tasks which have no remaining partitions.
+fn evict_least_recent(tasks: &mut HashMap<String, TaskOffsets>, retain: usize) {
+    let mut updated: Vec<(Option<Instant>, String, (String, i32))> = tasks
+        .iter()
+        .flat_map(|(task, task_offsets)| {
+            task_offsets
+                .partitions
+                .iter()
+                .map(move |(key, entry)| (entry.updated_at, task.clone(), key.clone()))
+        })
+        .collect();
+
+    if updated.len() <= retain {
+        return;
+    }
+    updated.sort_unstable_by_key(|(updated_at, _, _)| *updated_at);
+
+    let evict = updated.len() - retain;
+    for (_, task, key) in updated.into_iter().take(evict) {
+        if let Some(task_offsets) = tasks.get_mut(&task) {
+            task_offsets.partitions.remove(&key);
+        }
+    }
+    tasks.retain(|_, task_offsets| !task_offsets.partitions.is_empty());
+}
+
+/// LagReport is a periodic report of the LagSummaries of a task,
+/// from a Dekaf instance.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LagReport<'a> {
+    /// Hostname of the reporting Dekaf instance.
+    pub advertise_host: &'a str,
+    /// Name of the Dekaf materialization task.
+    pub task: &'a str,
+    /// Wall-clock time of the report, as RFC 3339.
+    pub ts: String,
+    pub summaries: &'a [LagSummary],
+}
+
+/// Periodically POST a LagReport of each task of `offsets` to `endpoint`
+/// as JSON, so that the control-plane may use consumer lag as an input for
+/// shard splitting or alerting on under-provisioned collections.
+/// Each report is authenticated by an access token of its task, with which
+/// the endpoint verifies that the report is authorized to the task.
+/// Reports are best-effort: failures are logged and otherwise ignored.
+pub async fn serve_lag_reports(
+    offsets: &ConsumerOffsets,
+    advertise_host: &str,
+    endpoint: url::Url,
+    interval: std::time::Duration,
+) {
+    let client = reqwest::Client::new();
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        let ts = humantime::format_rfc3339(std::time::SystemTime::now()).to_string();
+
+        for TaskLag {
+            task,
+            access_token,
+            summaries,
+        } in offsets.lag_summaries()
+        {
+            let report = LagReport {
+                advertise_host,
+                task: &task,
+                ts: ts.clone(),
+                summaries: &summaries,
+            };
+
+            let result = match client
+                .post(endpoint.clone())
+                .bearer_auth(&access_token)
+                .json(&report)
+                .send()
+                .await
+            {
+                Ok(response) => response.error_for_status().map(|_| ()),
+                Err(err) => Err(err),
+            };
+            if let Err(error) = result {
+                tracing::warn!(%endpoint, %task, ?error, "failed to send consumer lag report");
+            }
+        }
+    }
+}
+
+fn set_consumer_lag(group: &str, topic: &str, partition: i32, lag: i64) {
+    metrics::gauge!(
+        "dekaf_consumer_lag",
+        "group" => group.to_string(),
+        "topic" => topic.to_string(),
+        "partition" => partition.to_string(),
+    )
+    .set(std::cmp::max(lag, 0) as f64);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_consumer_offsets_lag() {
+        let offsets = ConsumerOffsets::default();
+        let task = Some("acmeCo/task");
+
+        offsets.record_commit(task, "token-1", "group", "some/topic", 0, 1000);
+        assert_eq!(offsets.lag(task, "group", "some/topic", 0), None); // No write head yet.
+
+        offsets.record_write_head(task, "some/topic", 0, 1500);
+        assert_eq!(offsets.lag(task, "group", "some/topic", 0), Some(500));
+
+        offsets.record_commit(task, "token-2", "group", "some/topic", 0, 1200);
+        offsets.record_commit(task, "token-2", "other", "some/topic", 0, 1600);
+        assert_eq!(offsets.lag(task, "group", "some/topic", 0), Some(300));
+        assert_eq!(offsets.lag(task, "other", "some/topic", 0), Some(0));
+        assert_eq!(offsets.lag(task, "group", "some/topic", 1), None);
+
+        offsets.record_commit(task, "token-2", "group", "some/topic", 1, 100);
+        offsets.record_write_head(task, "some/topic", 1, 900);
+        offsets.record_write_head(task, "other/topic", 0, 10);
+
+        // Topics are scoped to their task, and sessions which aren't
+        // of a task are tracked but not summarized.
+        offsets.record_commit(
+            Some("acmeCo/another"),
+            "token-3",
+            "group",
+            "some/topic",
+            0,
+            5,
+        );
+        offsets.record_commit(None, "user-token", "group", "some/topic", 0, 1);
+        offsets.record_write_head(None, "some/topic", 0, 11);
+        assert_eq!(
+            offsets.lag(Some("acmeCo/another"), "group", "some/topic", 0),
+            None
+        );
+        assert_eq!(offsets.lag(None, "group", "some/topic", 0), Some(10));
+
+        assert_eq!(
+            offsets.lag_summaries(),
+            vec![TaskLag {
+                task: "acmeCo/task".to_string(),
+                access_token: "token-2".to_string(),
+                summaries: vec![
+                    LagSummary {
+                        group: "group".to_string(),
+                        topic: "some/topic".to_string(),
+                        partitions: 2,
+                        total_lag: 1100,
+                        max_lag: 800,
+                    },
+                    LagSummary {
+                        group: "other".to_string(),
+                        topic: "some/topic".to_string(),
+                        partitions: 1,
+                        total_lag: 0,
+                        max_lag: 0,
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_consumer_offsets_eviction() {
+        let offsets = ConsumerOffsets::default();
+        offsets.record_commit(Some("acmeCo/task"), "token", "group", "some/topic", 0, 10);
+        offsets.record_write_head(Some("acmeCo/task"), "some/topic", 0, 20);
+
+        let now = Instant::now();
+        let mut guard = offsets.inner.lock().unwrap();
+
+        // Partitions updated within the TTL are retained.
+        evict_idle(&mut guard.0, now);
+        assert_eq!(guard.0["acmeCo/task"].partitions.len(), 1);
+
+        // Idle partitions are evicted, and then their task.
+        evict_idle(&mut guard.0, now + OFFSETS_TTL);
+        assert!(guard.0.is_empty());
+    }
+
+    #[test]
+    fn test_consumer_offsets_max_partitions() {
+        let offsets = ConsumerOffsets::default();
+        offsets.record_write_head(Some("acmeCo/one"), "some/topic", 0, 10);
+        offsets.record_write_head(Some("acmeCo/one"), "some/topic", 1, 10);
+        offsets.record_write_head(Some("acmeCo/two"), "some/topic", 0, 10);
+        offsets.record_write_head(Some("acmeCo/two"), "other/topic", 0, 10);
+
+        let now = Instant::now();
+        let mut guard = offsets.inner.lock().unwrap();
+
+        // Order partitions from least to most recently updated.
+        for (task, topic, partition, age) in [
+            ("acmeCo/two", "some/topic", 0, 4),
+            ("acmeCo/one", "some/topic", 1, 3),
+            ("acmeCo/two", "other/topic", 0, 2),
+            ("acmeCo/one", "some/topic", 0, 1),
+        ] {
+            let entry = guard
+                .0
+                .get_mut(task)
+                .unwrap()
+                .partitions
+                .get_mut(&(topic.to_string(), partition))
+                .unwrap();
+            entry.updated_at = Some(now - std::time::Duration::from_secs(age));
+        }
+
+        // At the bound, nothing is evicted.
+        evict_least_recent(&mut guard.0, 4);
+        assert_eq!(guard.0.len(), 2);
+
+        // The least-recently updated partitions are evicted.
+        evict_least_recent(&mut guard.0, 2);
+        assert_eq!(guard.0["acmeCo/one"].partitions.len(), 1);
+        assert_eq!(guard.0["acmeCo/two"].partitions.len(), 1);
+        assert!(guard.0["acmeCo/two"]
+            .partitions
+            .contains_key(&("other/topic".to_string(), 0)));
+
+        // Tasks having no remaining partitions are evicted.
+        evict_least_recent(&mut guard.0, 1);
+        assert!(!guard.0.contains_key("acmeCo/two"));
+        assert!(guard.0["acmeCo/one"]
+            .partitions
+            .contains_key(&("some/topic".to_string(), 0)));
+    }
+}
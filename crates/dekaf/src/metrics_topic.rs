@@ -0,0 +1,270 @@
+use crate::topology::PartitionOffset;
+use bytes::{Bytes, BytesMut};
+use kafka_protocol::protocol::StrBytes;
+use kafka_protocol::records::{
+    Compression, Record, RecordBatchEncoder, RecordEncodeOptions, TimestampType,
+};
+use std::cmp::max;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+/// Name of the synthesized topic through which a task's throughput
+/// metrics are served, when enabled by its configuration.
+pub const TOPIC_NAME: &str = "__dekaf_metrics";
+
+/// Interval at which metric documents are produced into the topic.
+/// Documents are keyed on their production time, in milliseconds,
+/// so that offsets are stable across sessions and Dekaf instances.
+const INTERVAL_MILLIS: i64 = 10_000;
+
+/// Duration after which the stats of a partition which hasn't been served
+/// are evicted, along with principals having no remaining partitions.
+const STATS_TTL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PartitionStats {
+    topic: String,
+    partition: i32,
+    bytes_served: u64,
+    docs_served: u64,
+    offset: i64,
+    write_head: i64,
+    lag_bytes: i64,
+    #[serde(skip)]
+    served_at: Option<Instant>,
+}
+
+type Principals = HashMap<String, BTreeMap<(String, i32), PartitionStats>>;
+
+/// ThroughputStats tracks the data served to each authenticated principal,
+/// by topic and partition. Stats which haven't been served within STATS_TTL
+/// are evicted, so that departed principals and partitions don't accumulate.
+#[derive(Default)]
+pub struct ThroughputStats {
+    inner: std::sync::Mutex<(Principals, Option<Instant>)>,
+}
+
+impl ThroughputStats {
+    pub fn record(
+        &self,
+        principal: &str,
+        topic: &str,
+        partition: i32,
+        bytes: usize,
+        docs: usize,
+        offset: i64,
+        write_head: i64,
+    ) {
+        let now = Instant::now();
+        let mut guard = self.inner.lock().unwrap();
+        let (principals, swept_at) = &mut *guard;
+
+        // Sweep for idle stats at most once per TTL.
+        if swept_at.map_or(true, |at| now.duration_since(at) >= STATS_TTL) {
+            evict_idle(principals, now);
+            *swept_at = Some(now);
+        }
+
+        let stats = principals
+            .entry(principal.to_string())
+            .or_default()
+            .entry((topic.to_string(), partition))
+            .or_insert_with(|| PartitionStats {
+                topic: topic.to_string(),
+                partition,
+                ..Default::default()
+            });
+
+        stats.bytes_served += bytes as u64;
+        stats.docs_served += docs as u64;
+        stats.offset = offset;
+        stats.write_head = write_head;
+        stats.lag_bytes = max(write_head - offset, 0);
+        stats.served_at = Some(now);
+    }
+
+    /// Encode a RecordBatch holding a single metrics document of `principal`
+    /// at the given `offset`, as returned by `latest_offset`.
+    /// If `data_key` is present, the document is encrypted with it.
+    pub fn encode_batch(
+        &self,
+        principal: &str,
+        offset: i64,
+        data_key: Option<&crate::encryption::DataKey>,
+    ) -> anyhow::Result<Bytes> {
+        let partitions: Vec<PartitionStats> = self
+            .inner
+            .lock()
+            .unwrap()
+            .0
+            .get(principal)
+            .map(|stats| stats.values().cloned().collect())
+            .unwrap_or_default();
+
+        let doc = serde_json::json!({
+            "ts": time::OffsetDateTime::from_unix_timestamp_nanos(offset as i128 * 1_000_000)?
+                .format(&time::format_description::well_known::Rfc3339)?,
+            "partitions": partitions,
+        });
+
+        let value = serde_json::to_vec(&doc)?;
+        let value = match data_key {
+            Some(data_key) => data_key.seal(&value),
+            None => value,
+        };
+
+        let mut record = Record {
+            control: false,
+            headers: Default::default(),
+            key: None,
+            offset,
+            partition_leader_epoch: 1,
+            // Metrics documents have no producer. Their offsets are
+            // millisecond timestamps, which don't fit a producer sequence.
+            producer_epoch: -1,
+            producer_id: -1,
+            sequence: -1,
+            timestamp: offset,
+            timestamp_type: TimestampType::LogAppend,
+            transactional: false,
+            value: Some(value.into()),
+        };
+        // As the batch's only record, it carries its wrapped data key.
+        if let Some(data_key) = data_key {
+            for (name, header) in data_key.headers(true) {
+                record
+                    .headers
+                    .insert(StrBytes::from_static_str(name), Some(header));
+            }
+        }
+
+        let mut buf = BytesMut::new();
+        let opts = RecordEncodeOptions {
+            compression: Compression::None,
+            version: 2,
+        };
+        RecordBatchEncoder::encode(
+            &mut buf,
+            [record].iter(),
+            &opts,
+            Some(crate::read::compressor),
+        )
+        .expect("record encoding cannot fail");
+
+        Ok(buf.freeze())
+    }
+}
+
+/// Evict the stats of partitions which haven't been served within STATS_TTL
+/// of `now`, and then principals which have no remaining partitions.
+fn evict_idle(principals: &mut Principals, now: Instant) {
+    principals.retain(|_, partitions| {
+        partitions.retain(|_, stats| {
+            stats
+                .served_at
+                .is_some_and(|at| now.saturating_duration_since(at) < STATS_TTL)
+        });
+        !partitions.is_empty()
+    });
+}
+
+/// Offset of the most-recent metrics document as-of `now_millis`.
+pub fn latest_offset(now_millis: i64) -> i64 {
+    now_millis - now_millis % INTERVAL_MILLIS
+}
+
+/// Offset of the first metrics document at or after `offset`.
+pub fn next_offset(offset: i64) -> i64 {
+    latest_offset(offset + INTERVAL_MILLIS - 1)
+}
+
+/// Map a ListOffsets request of the metrics topic into its PartitionOffset.
+/// The topic has a single partition, and the earliest available document
+/// is always the latest one.
+pub fn partition_offset(
+    partition_index: i32,
+    timestamp_millis: i64,
+    now_millis: i64,
+) -> Option<PartitionOffset> {
+    if partition_index != 0 {
+        return None;
+    }
+    let latest = latest_offset(now_millis);
+
+    let offset = if timestamp_millis < 0 {
+        latest // Both "earliest" and "latest" sentinels.
+    } else {
+        // Round up to the next document at or after the timestamp.
+        next_offset(timestamp_millis).min(latest)
+    };
+
+    Some(PartitionOffset {
+        fragment_start: offset,
+        offset,
+        mod_time: offset / 1_000,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_metrics_topic_offsets() {
+        assert_eq!(latest_offset(1_234_567), 1_230_000);
+        assert_eq!(next_offset(1_230_000), 1_230_000);
+        assert_eq!(next_offset(1_230_001), 1_240_000);
+
+        let now = 1_234_567;
+        let offset = |ts| partition_offset(0, ts, now).map(|o| o.offset);
+
+        assert_eq!(offset(-1), Some(1_230_000));
+        assert_eq!(offset(-2), Some(1_230_000));
+        assert_eq!(offset(1_200_000), Some(1_200_000));
+        assert_eq!(offset(1_200_001), Some(1_210_000));
+        assert_eq!(offset(9_999_999), Some(1_230_000));
+        assert_eq!(partition_offset(1, -1, now), None);
+    }
+
+    #[test]
+    fn test_throughput_stats() {
+        let stats = ThroughputStats::default();
+        stats.record("principal", "some/topic", 0, 100, 2, 1000, 1500);
+        stats.record("principal", "some/topic", 0, 50, 1, 1100, 1500);
+        stats.record("other", "some/topic", 0, 1, 1, 1, 1);
+
+        stats.record("principal", "other/topic", 1, 10, 1, 10, 10);
+
+        let mut inner = stats.inner.lock().unwrap();
+        let principals = &mut inner.0;
+        let partition = &principals["principal"][&("some/topic".to_string(), 0)];
+        assert_eq!(partition.bytes_served, 150);
+        assert_eq!(partition.docs_served, 3);
+        assert_eq!(partition.lag_bytes, 400);
+
+        // Stats served within the TTL are retained.
+        let served_at = principals["other"][&("some/topic".to_string(), 0)]
+            .served_at
+            .unwrap();
+        evict_idle(principals, served_at + STATS_TTL / 2);
+        assert_eq!(principals.len(), 2);
+        assert_eq!(principals["principal"].len(), 2);
+
+        // Partitions idle beyond the TTL are evicted, as are principals
+        // left without partitions.
+        principals
+            .get_mut("principal")
+            .unwrap()
+            .get_mut(&("other/topic".to_string(), 1))
+            .unwrap()
+            .served_at = Some(served_at + STATS_TTL);
+        evict_idle(principals, served_at + STATS_TTL);
+
+        assert!(!principals.contains_key("other"));
+        assert_eq!(
+            principals["principal"].keys().collect::<Vec<_>>(),
+            vec![&("other/topic".to_string(), 1)]
+        );
+    }
+}
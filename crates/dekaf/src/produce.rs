@@ -0,0 +1,529 @@
+use crate::connector::DekafConfig;
+use anyhow::{bail, Context};
+use bytes::{Buf, BufMut, Bytes};
+use gazette::{broker, uuid};
+use kafka_protocol::{
+    error::ResponseError,
+    messages::produce_response::PartitionProduceResponse,
+    protocol::StrBytes,
+    records::{Compression, Record, RecordBatchDecoder},
+};
+use proto_flow::flow;
+use std::io::Write;
+
+/// Placeholder of document UUIDs within collection ack templates.
+const UUID_PLACEHOLDER: &str = "DocUUIDPlaceholder-329Bb50aa48EAa9ef";
+
+/// Resolve the collection which is written by `topic`,
+/// if it's a writable topic of the task.
+pub fn writable_collection<'c>(config: &'c DekafConfig, topic: &str) -> Option<&'c str> {
+    config
+        .writable_topics
+        .iter()
+        .find(|writable| writable.topic_name == topic)
+        .map(|writable| writable.collection.as_str())
+}
+
+/// Duration for which a session re-uses a resolved WriteTarget of a topic
+/// before resolving its collection and write authorization anew.
+pub const WRITE_TARGET_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// WriteTarget is the resolved collection of a writable topic,
+/// with its write schema validator and a journal client authorized to append.
+pub struct WriteTarget {
+    pub collection: crate::Collection,
+    pub validator: doc::Validator,
+    pub journal_client: gazette::journal::Client,
+    pub resolved_at: std::time::Instant,
+}
+
+impl WriteTarget {
+    /// Is this WriteTarget older than WRITE_TARGET_TTL as of `now`?
+    pub fn is_expired(&self, now: std::time::Instant) -> bool {
+        now.saturating_duration_since(self.resolved_at) >= WRITE_TARGET_TTL
+    }
+}
+
+/// Is `response` a failure which may be due to a stale WriteTarget,
+/// such as a changed partitioning or an expired authorization?
+/// Records which fail validation are the producer's error, not the target's.
+pub fn is_stale_target(response: &PartitionProduceResponse) -> bool {
+    response.error_code != 0 && response.error_code != ResponseError::InvalidRecord.code()
+}
+
+/// Build a random Producer for the writes of a session.
+pub fn new_producer() -> uuid::Producer {
+    let mut producer: [u8; 6] = rand::random();
+    producer[0] |= 0x01; // Mark that this is not a real MAC address.
+    uuid::Producer::from_bytes(producer)
+}
+
+/// Build a Validator of the write schema of a collection.
+pub fn write_validator(spec: &flow::CollectionSpec) -> anyhow::Result<doc::Validator> {
+    let schema = doc::validation::build_bundle(&spec.write_schema_json)
+        .context("building collection write schema")?;
+    Ok(doc::Validator::new(schema)?)
+}
+
+/// Frame the values of produced `records` as newline-delimited documents
+/// of the collection `spec`, which are appended to `journal`.
+///
+/// Each record value must be a JSON document which conforms to the write schema
+/// of the collection, and has partitioned fields matching those of `journal`.
+/// Documents are assigned UUIDs of `producer` which continue a transaction,
+/// and are followed by an acknowledgement which commits them.
+pub fn frame_records(
+    spec: &flow::CollectionSpec,
+    validator: &mut doc::Validator,
+    journal: &broker::JournalSpec,
+    producer: uuid::Producer,
+    clock: &mut uuid::Clock,
+    records: &[Record],
+) -> anyhow::Result<Bytes> {
+    let uuid_ptr = doc::Pointer::from_str(&spec.uuid_ptr);
+    let labels = journal.labels.clone().unwrap_or_default();
+
+    // Map partitioned fields into their document locations and journal label values.
+    let mut partitions = Vec::with_capacity(spec.partition_fields.len());
+    for field in &spec.partition_fields {
+        let Some(projection) = spec.projections.iter().find(|p| &p.field == field) else {
+            bail!("partitioned field {field} is not a projection of the collection");
+        };
+        let label = format!("{}{field}", labels::FIELD_PREFIX);
+        let value = labels::expect_one(&labels, &label)
+            .with_context(|| format!("journal {} has no partition {label}", journal.name))?;
+
+        partitions.push((field, doc::Pointer::from_str(&projection.ptr), value));
+    }
+
+    clock.update(uuid::Clock::from_time(std::time::SystemTime::now()));
+    let mut w = bytes::BytesMut::new().writer();
+
+    for (index, record) in records.iter().enumerate() {
+        let Some(value) = &record.value else {
+            bail!("record {index} has no value, but collection documents cannot be deleted by producers");
+        };
+        let mut doc: serde_json::Value = serde_json::from_slice(value)
+            .with_context(|| format!("record {index} is not a JSON document"))?;
+
+        clock.tick();
+        let uuid = uuid::build(
+            producer,
+            *clock,
+            uuid::Flags(proto_gazette::message_flags::CONTINUE_TXN as u16),
+        );
+        let Some(slot) = uuid_ptr.create_value(&mut doc) else {
+            bail!(
+                "record {index} cannot hold a document UUID at {}",
+                spec.uuid_ptr
+            );
+        };
+        *slot = serde_json::Value::String(uuid.to_string());
+
+        validator
+            .validate(None, &doc)?
+            .ok()
+            .with_context(|| format!("record {index} failed validation of {}", spec.name))?;
+
+        for (field, ptr, expect) in &partitions {
+            let actual = match ptr.query(&doc) {
+                Some(value) => labels::partition::encode_field_value(String::new(), value),
+                None => {
+                    labels::partition::encode_field_value(String::new(), &serde_json::Value::Null)
+                }
+            }
+            .with_context(|| format!("record {index} has an invalid partitioned field {field}"))?;
+
+            if actual != *expect {
+                bail!(
+                    "record {index} partitioned field {field} value {actual:?} does not match {expect:?} of journal {}",
+                    journal.name
+                );
+            }
+        }
+
+        serde_json::to_writer(&mut w, &doc)?;
+        w.write_all(b"\n")?;
+    }
+
+    // Acknowledge the transaction, committing its documents to readers.
+    let ack = uuid::build(
+        producer,
+        *clock,
+        uuid::Flags(proto_gazette::message_flags::ACK_TXN as u16),
+    );
+    let ack = spec
+        .ack_template_json
+        .replace(UUID_PLACEHOLDER, &ack.to_string());
+
+    w.write_all(ack.as_bytes())?;
+    w.write_all(b"\n")?;
+
+    Ok(w.into_inner().freeze())
+}
+
+/// Decode, frame, and append the produced `records` of partition `index`,
+/// which is `journal` of the collection `spec`, using `append` to append
+/// framed documents and return the journal offset at which they begin.
+/// The outcome is mapped into the partition's produce response.
+pub async fn produce_partition<A, F>(
+    spec: &flow::CollectionSpec,
+    validator: &mut doc::Validator,
+    journal: &broker::JournalSpec,
+    producer: uuid::Producer,
+    clock: &mut uuid::Clock,
+    index: i32,
+    records: Option<Bytes>,
+    append: A,
+) -> PartitionProduceResponse
+where
+    A: FnOnce(Bytes) -> F,
+    F: std::future::Future<Output = Result<i64, gazette::Error>>,
+{
+    let response = PartitionProduceResponse::default().with_index(index);
+
+    let mut records = records.unwrap_or_default();
+    let framed = RecordBatchDecoder::decode(&mut records, Some(decompressor))
+        .context("decoding produced record batch")
+        .and_then(|records| frame_records(spec, validator, journal, producer, clock, &records));
+
+    let framed = match framed {
+        Ok(framed) => framed,
+        Err(err) => {
+            tracing::warn!(journal = %journal.name, ?err, "rejecting produced records");
+            return response
+                .with_error_code(ResponseError::InvalidRecord.code())
+                .with_error_message(Some(StrBytes::from_string(format!("{err:#}"))));
+        }
+    };
+
+    match append(framed).await {
+        Ok(begin) => response.with_base_offset(begin).with_log_append_time_ms(-1),
+        Err(err) => {
+            tracing::warn!(journal = %journal.name, ?err, "failed to append produced records");
+            response
+                .with_error_code(ResponseError::KafkaStorageError.code())
+                .with_error_message(Some(StrBytes::from_string(err.to_string())))
+        }
+    }
+}
+
+/// Magic header of snappy-java's "xerial" framing, which Java producers
+/// use for Snappy-compressed record batches.
+const XERIAL_SNAPPY_MAGIC: &[u8] = b"\x82SNAPPY\x00";
+
+/// Decompress a produced record batch.
+pub(crate) fn decompressor(input: &mut Bytes, c: Compression) -> anyhow::Result<Bytes> {
+    match c {
+        Compression::None => Ok(input.split_to(input.len())),
+        Compression::Lz4 => {
+            let mut decoder = lz4_flex::frame::FrameDecoder::new(input.reader());
+            let mut output = Vec::new();
+            std::io::copy(&mut decoder, &mut output)?;
+            Ok(output.into())
+        }
+        Compression::Snappy if input.starts_with(XERIAL_SNAPPY_MAGIC) => {
+            decompress_xerial_snappy(input)
+        }
+        Compression::Snappy => Ok(snap::raw::Decoder::new().decompress_vec(input)?.into()),
+        Compression::Zstd => Ok(zstd::stream::decode_all(input.reader())?.into()),
+        unsupported @ _ => bail!("Unsupported compression type {unsupported:?}"),
+    }
+}
+
+/// Decompress xerial-framed Snappy, which is the magic header followed by
+/// big-endian version and compatible-version words, and then a sequence of
+/// raw Snappy blocks which are each prefixed by their big-endian length.
+fn decompress_xerial_snappy(input: &mut Bytes) -> anyhow::Result<Bytes> {
+    const HEADER_LEN: usize = XERIAL_SNAPPY_MAGIC.len() + 8;
+
+    if input.len() < HEADER_LEN {
+        bail!("xerial snappy header is truncated");
+    }
+    input.advance(HEADER_LEN);
+
+    let mut decoder = snap::raw::Decoder::new();
+    let mut output = Vec::new();
+
+    while input.has_remaining() {
+        if input.remaining() < 4 {
+            bail!("xerial snappy block length is truncated");
+        }
+        let len = input.get_u32() as usize;
+        if input.remaining() < len {
+            bail!("xerial snappy block of {len} bytes is truncated");
+        }
+        let block = input.split_to(len);
+        output.extend(decoder.decompress_vec(&block)?);
+    }
+    Ok(output.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fixture() -> (flow::CollectionSpec, broker::JournalSpec) {
+        let spec = flow::CollectionSpec {
+            name: "acmeCo/events".to_string(),
+            write_schema_json: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "id": {"type": "integer"},
+                    "region": {"type": "string"},
+                },
+                "required": ["id", "region"],
+            })
+            .to_string(),
+            key: vec!["/id".to_string()],
+            uuid_ptr: "/_meta/uuid".to_string(),
+            partition_fields: vec!["region".to_string()],
+            projections: vec![flow::Projection {
+                field: "region".to_string(),
+                ptr: "/region".to_string(),
+                ..Default::default()
+            }],
+            ack_template_json: serde_json::json!({
+                "_meta": {"uuid": UUID_PLACEHOLDER, "ack": true},
+            })
+            .to_string(),
+            ..Default::default()
+        };
+        let journal = broker::JournalSpec {
+            name: "acmeCo/events/region=west/pivot=00".to_string(),
+            labels: Some(labels::build_set([("estuary.dev/field/region", "west")])),
+            ..Default::default()
+        };
+        (spec, journal)
+    }
+
+    fn record(value: serde_json::Value) -> Record {
+        Record {
+            transactional: false,
+            control: false,
+            partition_leader_epoch: 0,
+            producer_id: 0,
+            producer_epoch: 0,
+            timestamp_type: kafka_protocol::records::TimestampType::Creation,
+            offset: 0,
+            sequence: 0,
+            timestamp: 0,
+            key: None,
+            value: Some(Bytes::from(value.to_string())),
+            headers: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_framing_of_produced_records() {
+        let (spec, journal) = fixture();
+        let mut validator = write_validator(&spec).unwrap();
+        let producer = uuid::Producer::from_bytes([8 | 1, 6, 7, 5, 3, 9]);
+        let mut clock = uuid::Clock::default();
+
+        let framed = frame_records(
+            &spec,
+            &mut validator,
+            &journal,
+            producer,
+            &mut clock,
+            &[
+                record(serde_json::json!({"id": 1, "region": "west"})),
+                record(serde_json::json!({"id": 2, "region": "west"})),
+            ],
+        )
+        .unwrap();
+
+        let docs: Vec<serde_json::Value> = framed
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+        assert_eq!(docs.len(), 3);
+
+        let flags: Vec<_> = docs
+            .iter()
+            .map(|doc| {
+                let (p, _, flags) =
+                    uuid::parse_str(doc["_meta"]["uuid"].as_str().unwrap()).unwrap();
+                assert_eq!(p, producer);
+                flags.0 as u64
+            })
+            .collect();
+
+        use proto_gazette::message_flags::{ACK_TXN, CONTINUE_TXN};
+        assert_eq!(flags, vec![CONTINUE_TXN, CONTINUE_TXN, ACK_TXN]);
+        assert_eq!(docs[2]["_meta"]["ack"], serde_json::json!(true));
+
+        // Documents which fail validation are rejected.
+        let err = frame_records(
+            &spec,
+            &mut validator,
+            &journal,
+            producer,
+            &mut clock,
+            &[record(serde_json::json!({"id": "one", "region": "west"}))],
+        )
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("record 0 failed validation of acmeCo/events"));
+
+        // As are documents of another partition.
+        let err = frame_records(
+            &spec,
+            &mut validator,
+            &journal,
+            producer,
+            &mut clock,
+            &[record(serde_json::json!({"id": 3, "region": "east"}))],
+        )
+        .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("partitioned field region value \"east\" does not match")
+        );
+    }
+
+    #[test]
+    fn test_xerial_snappy_decompression() {
+        let raw = b"produced record batch contents, produced record batch contents".repeat(4);
+        let block = snap::raw::Encoder::new().compress_vec(&raw).unwrap();
+
+        // Frame `raw` as two xerial blocks, split at an arbitrary boundary.
+        let (first, second) = raw.split_at(37);
+        let mut framed = bytes::BytesMut::new();
+        framed.put_slice(XERIAL_SNAPPY_MAGIC);
+        framed.put_u32(1); // Version.
+        framed.put_u32(1); // Compatible version.
+        for part in [first, second] {
+            let block = snap::raw::Encoder::new().compress_vec(part).unwrap();
+            framed.put_u32(block.len() as u32);
+            framed.put_slice(&block);
+        }
+
+        let mut input = framed.freeze();
+        let output = decompressor(&mut input, Compression::Snappy).unwrap();
+        assert_eq!(output.as_ref(), raw.as_slice());
+
+        // Raw, un-framed Snappy continues to decode.
+        let mut input = Bytes::from(block);
+        let output = decompressor(&mut input, Compression::Snappy).unwrap();
+        assert_eq!(output.as_ref(), raw.as_slice());
+
+        // A truncated block is an error.
+        let mut input = Bytes::from(
+            [
+                XERIAL_SNAPPY_MAGIC,
+                &[0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 9, 1, 2],
+            ]
+            .concat(),
+        );
+        let err = decompressor(&mut input, Compression::Snappy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "xerial snappy block of 9 bytes is truncated"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_produce_partition() {
+        use kafka_protocol::records::{RecordBatchEncoder, RecordEncodeOptions};
+
+        let (spec, journal) = fixture();
+        let mut validator = write_validator(&spec).unwrap();
+        let producer = uuid::Producer::from_bytes([8 | 1, 6, 7, 5, 3, 9]);
+        let mut clock = uuid::Clock::default();
+
+        let encode = |records: Vec<Record>| {
+            let mut buf = bytes::BytesMut::new();
+            let opts = RecordEncodeOptions {
+                compression: Compression::Snappy,
+                version: 2,
+            };
+            RecordBatchEncoder::encode(
+                &mut buf,
+                records.iter(),
+                &opts,
+                Some(crate::read::compressor),
+            )
+            .unwrap();
+            Some(buf.freeze())
+        };
+
+        // Valid records are framed and appended, and the response carries
+        // the journal offset at which they begin.
+        let appended = std::sync::Mutex::new(None);
+        let response = produce_partition(
+            &spec,
+            &mut validator,
+            &journal,
+            producer,
+            &mut clock,
+            3,
+            encode(vec![
+                record(serde_json::json!({"id": 1, "region": "west"})),
+                record(serde_json::json!({"id": 2, "region": "west"})),
+            ]),
+            |framed| {
+                *appended.lock().unwrap() = Some(framed);
+                async { Ok(42) }
+            },
+        )
+        .await;
+
+        assert_eq!(response.index, 3);
+        assert_eq!(response.error_code, 0);
+        assert_eq!(response.base_offset, 42);
+        assert_eq!(response.log_append_time_ms, -1);
+
+        let appended = appended.into_inner().unwrap().unwrap();
+        assert_eq!(
+            appended
+                .split(|b| *b == b'\n')
+                .filter(|l| !l.is_empty())
+                .count(),
+            3
+        );
+
+        // Append failures are storage errors.
+        let response = produce_partition(
+            &spec,
+            &mut validator,
+            &journal,
+            producer,
+            &mut clock,
+            3,
+            encode(vec![record(serde_json::json!({"id": 3, "region": "west"}))]),
+            |_framed| async { Err(gazette::Error::UnexpectedEof) },
+        )
+        .await;
+
+        assert_eq!(response.error_code, ResponseError::KafkaStorageError.code());
+        assert_eq!(
+            response.error_message.as_deref(),
+            Some("unexpected server EOF")
+        );
+
+        // Invalid records are rejected without being appended.
+        let response = produce_partition(
+            &spec,
+            &mut validator,
+            &journal,
+            producer,
+            &mut clock,
+            3,
+            encode(vec![record(
+                serde_json::json!({"id": "four", "region": "west"}),
+            )]),
+            |_framed| -> std::future::Ready<Result<i64, gazette::Error>> {
+                panic!("invalid records must not be appended")
+            },
+        )
+        .await;
+
+        assert_eq!(response.error_code, ResponseError::InvalidRecord.code());
+        assert!(response
+            .error_message
+            .as_deref()
+            .unwrap()
+            .contains("record 0 failed validation of acmeCo/events"));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::connector::CompatibilityProfile;
+
+/// ProfileSettings are the protocol adjustments bundled by a CompatibilityProfile,
+/// which are applied by sessions of a task having the profile.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ProfileSettings {
+    /// Whether unqualified Metadata requests enumerate the partitions of
+    /// every topic, rather than listing topic names only.
+    pub enumerate_all_partitions: bool,
+}
+
+impl CompatibilityProfile {
+    pub fn settings(self) -> ProfileSettings {
+        match self {
+            Self::Default => ProfileSettings::default(),
+            // Java consumers which subscribe to a topic pattern learn topics
+            // and their partitions from unqualified Metadata requests, and
+            // assign only the partitions listed there. Dekaf otherwise lists
+            // a single placeholder partition of each topic, so that all other
+            // partitions of a matched topic would never be read.
+            Self::Flink | Self::Spark | Self::KafkaConnect => ProfileSettings {
+                enumerate_all_partitions: true,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_profile_settings() {
+        // The default profile makes no adjustments.
+        assert_eq!(
+            CompatibilityProfile::Default.settings(),
+            ProfileSettings::default()
+        );
+
+        for profile in [
+            CompatibilityProfile::Flink,
+            CompatibilityProfile::Spark,
+            CompatibilityProfile::KafkaConnect,
+        ] {
+            assert!(profile.settings().enumerate_all_partitions, "{profile:?}");
+        }
+    }
+}
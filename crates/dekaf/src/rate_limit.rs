@@ -0,0 +1,169 @@
+use crate::connector::DekafConfig;
+use std::time::{Duration, Instant};
+
+/// TokenBucket refills at a fixed `rate` of tokens per second, up to `capacity`.
+/// Tokens may be taken in excess of those available, in which case the bucket
+/// goes into debt which must be repaid by refills before it's usable again.
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    rate: f64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: f64, now: Instant) -> Self {
+        // Allow bursts of up to one second's worth of tokens.
+        Self {
+            capacity: rate,
+            rate,
+            tokens: rate,
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.rate).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    fn take(&mut self, n: f64, now: Instant) {
+        self.refill(now);
+        self.tokens -= n;
+    }
+
+    /// Duration until the bucket is out of debt.
+    fn delay(&mut self, now: Instant) -> Duration {
+        self.refill(now);
+
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+/// FetchRateLimiter enforces the per-session fetch limits of a task.
+#[derive(Debug, Default)]
+pub struct FetchRateLimiter {
+    bytes: Option<TokenBucket>,
+    requests: Option<TokenBucket>,
+}
+
+/// Limit which caused a Fetch to be throttled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Throttled {
+    Bytes,
+    Requests,
+}
+
+impl Throttled {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bytes => "bytes",
+            Self::Requests => "requests",
+        }
+    }
+}
+
+impl FetchRateLimiter {
+    pub fn new(config: &DekafConfig) -> Self {
+        let now = Instant::now();
+
+        Self {
+            bytes: config
+                .max_fetch_bytes_per_second
+                .filter(|rate| *rate != 0)
+                .map(|rate| TokenBucket::new(rate as f64, now)),
+            requests: config
+                .max_fetch_requests_per_second
+                .filter(|rate| *rate != 0)
+                .map(|rate| TokenBucket::new(rate as f64, now)),
+        }
+    }
+
+    /// Account for a new Fetch request, returning the limit and duration for
+    /// which it must be throttled, or None if it may proceed.
+    pub fn begin_request(&mut self, now: Instant) -> Option<(Throttled, Duration)> {
+        if let Some(requests) = &mut self.requests {
+            requests.take(1.0, now);
+
+            let delay = requests.delay(now);
+            if !delay.is_zero() {
+                return Some((Throttled::Requests, delay));
+            }
+        }
+        if let Some(bytes) = &mut self.bytes {
+            let delay = bytes.delay(now);
+            if !delay.is_zero() {
+                return Some((Throttled::Bytes, delay));
+            }
+        }
+        None
+    }
+
+    /// Account for `n` bytes which were served by a Fetch response, returning
+    /// the duration for which the client must now be throttled.
+    pub fn complete_request(&mut self, n: usize, now: Instant) -> Duration {
+        let Some(bytes) = &mut self.bytes else {
+            return Duration::ZERO;
+        };
+        bytes.take(n as f64, now);
+        bytes.delay(now)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_debt_and_refill() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(100.0, now);
+
+        bucket.take(50.0, now);
+        assert_eq!(bucket.delay(now), Duration::ZERO);
+
+        // Overdraw by 100 tokens, which takes one second to repay.
+        bucket.take(150.0, now);
+        assert_eq!(bucket.delay(now), Duration::from_secs(1));
+        assert_eq!(
+            bucket.delay(now + Duration::from_millis(500)),
+            Duration::from_millis(500)
+        );
+        assert_eq!(bucket.delay(now + Duration::from_secs(1)), Duration::ZERO);
+
+        // Refills are capped at the bucket capacity.
+        bucket.refill(now + Duration::from_secs(60));
+        assert_eq!(bucket.tokens, 100.0);
+    }
+
+    #[test]
+    fn test_fetch_rate_limiter() {
+        let now = Instant::now();
+        let mut limiter = FetchRateLimiter {
+            bytes: Some(TokenBucket::new(1000.0, now)),
+            requests: Some(TokenBucket::new(2.0, now)),
+        };
+
+        assert_eq!(limiter.begin_request(now), None);
+        assert_eq!(limiter.complete_request(3000, now), Duration::from_secs(2));
+        assert_eq!(
+            limiter.begin_request(now),
+            Some((Throttled::Bytes, Duration::from_secs(2)))
+        );
+        assert_eq!(
+            limiter.begin_request(now),
+            Some((Throttled::Requests, Duration::from_millis(500)))
+        );
+
+        // An unlimited limiter never throttles.
+        let mut limiter = FetchRateLimiter::default();
+        assert_eq!(limiter.begin_request(now), None);
+        assert_eq!(limiter.complete_request(1 << 30, now), Duration::ZERO);
+    }
+}
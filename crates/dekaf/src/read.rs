@@ -1,5 +1,6 @@
 use super::{Collection, Partition};
-use crate::connector::DeletionMode;
+use crate::connector::{DeletionMode, JsonKeyFormat, MessageFormat, RecordCompression};
+use crate::encryption::Encryptor;
 use anyhow::{bail, Context};
 use bytes::{Buf, BufMut, BytesMut};
 use doc::{heap::ArchivedNode, AsNode, HeapNode, OwnedArchivedNode};
@@ -8,7 +9,7 @@ use gazette::journal::{ReadJsonLine, ReadJsonLines};
 use gazette::{broker, journal, uuid};
 use kafka_protocol::{
     protocol::StrBytes,
-    records::{Compression, TimestampType},
+    records::{Compression, Record, TimestampType},
 };
 use lazy_static::lazy_static;
 use lz4_flex::frame::BlockMode;
@@ -19,6 +20,8 @@ pub struct Read {
     pub(crate) offset: i64,
     /// Most-recent journal write head observed by this Read.
     pub(crate) last_write_head: i64,
+    /// Number of documents in the most-recent batch of this Read.
+    pub(crate) batch_docs: usize,
 
     key_ptr: Vec<doc::Pointer>, // Pointers to the document key.
     key_schema: avro::Schema,   // Avro schema when encoding keys.
@@ -37,6 +40,18 @@ pub struct Read {
     offset_start: i64,
 
     deletes: DeletionMode,
+    format: MessageFormat,
+    json_key_format: JsonKeyFormat,
+    // Whether reduction headers of records are enabled.
+    reduction_headers: bool,
+    // Reduction strategy header of records, if the collection has one.
+    reduce_header: Option<bytes::Bytes>,
+    // Source collection header of records, if read through a union topic.
+    collection_header: Option<bytes::Bytes>,
+    // Compression of encoded record batches.
+    compression: Compression,
+    // Encryptor of record values, if enabled.
+    encryptor: Option<Encryptor>,
 
     pub(crate) rewrite_offsets_from: Option<i64>,
 }
@@ -70,6 +85,13 @@ impl Read {
         value_schema_id: u32,
         rewrite_offsets_from: Option<i64>,
         deletes: DeletionMode,
+        format: MessageFormat,
+        json_key_format: JsonKeyFormat,
+        reduction_headers: bool,
+        read_fragments_directly: bool,
+        collection_header: bool,
+        compression: Compression,
+        encryptor: Option<Encryptor>,
     ) -> Self {
         let (not_before_sec, _) = collection.not_before.to_unix();
 
@@ -79,6 +101,11 @@ impl Read {
                 block: true,
                 journal: partition.spec.name.clone(),
                 begin_mod_time: not_before_sec as i64,
+                // When set, brokers respond to reads of persisted fragments with
+                // a signed URL which is read and decompressed directly from the
+                // fragment store, rather than proxying its content. Reads near the
+                // journal head are still served by a broker from its spool.
+                do_not_proxy: read_fragments_directly,
                 ..Default::default()
             },
             // Each ReadResponse can be up to 130K. Buffer up to ~4MB so that
@@ -90,6 +117,7 @@ impl Read {
         Self {
             offset,
             last_write_head: offset,
+            batch_docs: 0,
 
             key_ptr: collection.key_ptr.clone(),
             key_schema: collection.key_schema.clone(),
@@ -104,6 +132,14 @@ impl Read {
             journal_name: partition.spec.name.clone(),
             rewrite_offsets_from,
             deletes,
+            format,
+            json_key_format,
+            reduction_headers,
+            reduce_header: collection.reduce_strategy.clone().map(bytes::Bytes::from),
+            collection_header: collection_header
+                .then(|| bytes::Bytes::from(collection.spec.name.clone())),
+            compression,
+            encryptor,
             offset_start: offset,
         }
     }
@@ -114,10 +150,65 @@ impl Read {
         target: ReadTarget,
         timeout: std::time::Instant,
     ) -> anyhow::Result<(Self, BatchResult)> {
-        use kafka_protocol::records::{
-            Compression, Record, RecordBatchEncoder, RecordEncodeOptions,
+        use kafka_protocol::records::{RecordBatchEncoder, RecordEncodeOptions};
+
+        let mut buf = bytes::BytesMut::new();
+        let (records, records_bytes, did_timeout) =
+            self.next_records(target, timeout, &mut buf).await?;
+
+        let opts = RecordEncodeOptions {
+            compression: self.compression,
+            version: 2,
         };
+        RecordBatchEncoder::encode(&mut buf, records.iter(), &opts, Some(compressor))
+            .expect("record encoding cannot fail");
+
+        if !matches!(self.compression, Compression::None) && records_bytes != 0 {
+            metrics::histogram!("dekaf_fetch_compression_ratio", "codec" => format!("{:?}", self.compression))
+                .record(buf.len() as f64 / records_bytes as f64);
+        }
+
+        tracing::debug!(
+            count = records.len(),
+            first_offset = records.first().map(|r| r.offset).unwrap_or_default(),
+            last_offset = records.last().map(|r| r.offset).unwrap_or_default(),
+            last_write_head = self.last_write_head,
+            ratio = buf.len() as f64 / (records_bytes + 1) as f64,
+            records_bytes,
+            did_timeout,
+            "batch complete"
+        );
+
+        metrics::counter!("dekaf_documents_read", "journal_name" => self.journal_name.to_owned())
+            .increment(records.len() as u64);
+        metrics::counter!("dekaf_bytes_read", "journal_name" => self.journal_name.to_owned())
+            .increment(records_bytes as u64);
+
+        self.batch_docs = records.len();
+        let frozen = buf.freeze();
+
+        Ok((
+            self,
+            match (records.len() > 0, did_timeout) {
+                (false, true) => BatchResult::TimeoutNoData,
+                (true, true) => BatchResult::TimeoutExceededBeforeTarget(frozen),
+                (true, false) => BatchResult::TargetExceededBeforeTimeout(frozen),
+                (false, false) => {
+                    unreachable!("shouldn't be able see no documents, and also not timeout")
+                }
+            },
+        ))
+    }
 
+    /// Read and encode Kafka records of documents until `target` is reached
+    /// or `timeout` elapses, returning the records, their encoded bytes, and
+    /// whether the read timed out. Record keys and values are split from `buf`.
+    pub async fn next_records(
+        &mut self,
+        target: ReadTarget,
+        timeout: std::time::Instant,
+        buf: &mut BytesMut,
+    ) -> anyhow::Result<(Vec<Record>, usize, bool)> {
         let mut alloc = bumpalo::Bump::new();
 
         let mut records: Vec<Record> = Vec::new();
@@ -126,7 +217,6 @@ impl Read {
         // We Avro encode into Vec instead of BytesMut because Vec is
         // better optimized for pushing a single byte at a time.
         let mut tmp = Vec::new();
-        let mut buf = bytes::BytesMut::new();
 
         let timeout = tokio::time::sleep_until(timeout.into());
         let timeout = futures::future::maybe_done(timeout);
@@ -134,6 +224,14 @@ impl Read {
 
         let mut did_timeout = false;
 
+        // Resolve the current data key once per batch, as it may rotate.
+        let data_key = match &self.encryptor {
+            Some(encryptor) => Some(encryptor.data_key().await?),
+            None => None,
+        };
+        // The first encrypted record of the batch carries its wrapped data key.
+        let mut wrapped_key_sent = false;
+
         while match target {
             ReadTarget::Bytes(target_bytes) => records_bytes < target_bytes,
             ReadTarget::Docs(target_docs) => records.len() < target_docs,
@@ -251,9 +349,26 @@ impl Read {
                 record_bytes += buf.len();
                 Some(buf.split().freeze())
             } else {
-                tmp.push(0);
-                tmp.extend(self.key_schema_id.to_be_bytes());
-                () = avro::encode_key(&mut tmp, &self.key_schema, root.get(), &self.key_ptr)?;
+                match self.format {
+                    MessageFormat::Avro => {
+                        tmp.push(0);
+                        tmp.extend(self.key_schema_id.to_be_bytes());
+                        () = avro::encode_key(
+                            &mut tmp,
+                            &self.key_schema,
+                            root.get(),
+                            &self.key_ptr,
+                        )?;
+                    }
+                    MessageFormat::Json => {
+                        () = encode_json_key(
+                            &mut tmp,
+                            self.json_key_format,
+                            root.get(),
+                            &self.key_ptr,
+                        )?;
+                    }
+                }
 
                 record_bytes += tmp.len();
                 buf.extend_from_slice(&tmp);
@@ -262,33 +377,56 @@ impl Read {
             };
 
             // Encode the value.
-            let value =
-                if is_control || (is_deletion && matches!(self.deletes, DeletionMode::Kafka)) {
-                    None
-                } else {
+            let value = if is_control
+                || (is_deletion && matches!(self.deletes, DeletionMode::Kafka))
+            {
+                None
+            } else {
+                if matches!(self.format, MessageFormat::Avro) {
                     tmp.push(0);
                     tmp.extend(self.value_schema_id.to_be_bytes());
+                }
 
-                    if matches!(self.deletes, DeletionMode::CDC) {
-                        let mut heap_node = HeapNode::from_node(root.get(), &alloc);
-                        let foo = DELETION_INDICATOR_PTR
-                            .create_heap_node(&mut heap_node, &alloc)
-                            .context("Unable to add deletion meta indicator")?;
+                if matches!(self.deletes, DeletionMode::CDC) {
+                    let mut heap_node = HeapNode::from_node(root.get(), &alloc);
+                    let foo = DELETION_INDICATOR_PTR
+                        .create_heap_node(&mut heap_node, &alloc)
+                        .context("Unable to add deletion meta indicator")?;
 
-                        *foo = HeapNode::PosInt(if is_deletion { 1 } else { 0 });
+                    *foo = HeapNode::PosInt(if is_deletion { 1 } else { 0 });
 
-                        () = avro::encode(&mut tmp, &self.value_schema, &heap_node)?;
+                    match self.format {
+                        MessageFormat::Avro => {
+                            () = avro::encode(&mut tmp, &self.value_schema, &heap_node)?
+                        }
+                        MessageFormat::Json => {
+                            serde_json::to_writer(&mut tmp, &doc::SerPolicy::noop().on(&heap_node))?
+                        }
+                    }
 
-                        alloc.reset();
-                    } else {
-                        () = avro::encode(&mut tmp, &self.value_schema, root.get())?;
+                    alloc.reset();
+                } else {
+                    match self.format {
+                        MessageFormat::Avro => {
+                            () = avro::encode(&mut tmp, &self.value_schema, root.get())?
+                        }
+                        MessageFormat::Json => {
+                            serde_json::to_writer(&mut tmp, &doc::SerPolicy::noop().on(root.get()))?
+                        }
                     }
+                }
 
-                    record_bytes += tmp.len();
-                    buf.extend_from_slice(&tmp);
+                if let Some(data_key) = &data_key {
+                    let sealed = data_key.seal(&tmp);
                     tmp.clear();
-                    Some(buf.split().freeze())
-                };
+                    tmp.extend_from_slice(&sealed);
+                }
+
+                record_bytes += tmp.len();
+                buf.extend_from_slice(&tmp);
+                tmp.clear();
+                Some(buf.split().freeze())
+            };
 
             self.offset = next_offset;
 
@@ -311,7 +449,7 @@ impl Read {
                 next_offset - 1
             };
 
-            records.push(Record {
+            let mut record = Record {
                 control: is_control,
                 headers: Default::default(),
                 key,
@@ -324,50 +462,75 @@ impl Read {
                 timestamp_type: TimestampType::LogAppend,
                 transactional: false,
                 value,
-            });
+            };
+            if self.reduction_headers && !is_control {
+                if let Some(reduce_header) = &self.reduce_header {
+                    record.headers.insert(
+                        StrBytes::from_static_str("flow-reduce"),
+                        Some(reduce_header.clone()),
+                    );
+                    record_bytes += reduce_header.len();
+                }
+                record.headers.insert(
+                    StrBytes::from_static_str("flow-uuid"),
+                    Some(bytes::Bytes::copy_from_slice(uuid.as_str().as_bytes())),
+                );
+                record_bytes += uuid.as_str().len();
+            }
+            if let (Some(data_key), Some(_)) = (&data_key, &record.value) {
+                for (name, value) in data_key.headers(!wrapped_key_sent) {
+                    record_bytes += value.len();
+                    record
+                        .headers
+                        .insert(StrBytes::from_static_str(name), Some(value));
+                }
+                wrapped_key_sent = true;
+            }
+            if let (Some(collection_header), false) = (&self.collection_header, is_control) {
+                record.headers.insert(
+                    StrBytes::from_static_str("flow-collection"),
+                    Some(collection_header.clone()),
+                );
+                record_bytes += collection_header.len();
+            }
+            records.push(record);
             records_bytes += record_bytes;
         }
 
-        let opts = RecordEncodeOptions {
-            compression: Compression::None,
-            version: 2,
-        };
-        RecordBatchEncoder::encode(&mut buf, records.iter(), &opts, Some(compressor))
-            .expect("record encoding cannot fail");
-
-        tracing::debug!(
-            count = records.len(),
-            first_offset = records.first().map(|r| r.offset).unwrap_or_default(),
-            last_offset = records.last().map(|r| r.offset).unwrap_or_default(),
-            last_write_head = self.last_write_head,
-            ratio = buf.len() as f64 / (records_bytes + 1) as f64,
-            records_bytes,
-            did_timeout,
-            "batch complete"
-        );
+        Ok((records, records_bytes, did_timeout))
+    }
+}
 
-        metrics::counter!("dekaf_documents_read", "journal_name" => self.journal_name.to_owned())
-            .increment(records.len() as u64);
-        metrics::counter!("dekaf_bytes_read", "journal_name" => self.journal_name.to_owned())
-            .increment(records_bytes as u64);
+/// Encode the key of `doc` as JSON, using the given `key_format`.
+fn encode_json_key<N: AsNode>(
+    buf: &mut Vec<u8>,
+    key_format: JsonKeyFormat,
+    doc: &N,
+    key_ptr: &[doc::Pointer],
+) -> anyhow::Result<()> {
+    let policy = doc::SerPolicy::noop();
+    let component = |ptr: &doc::Pointer| match ptr.query(doc) {
+        Some(node) => serde_json::to_value(policy.on(node)),
+        None => Ok(serde_json::Value::Null),
+    };
 
-        let frozen = buf.freeze();
+    let key = match key_format {
+        JsonKeyFormat::Array => {
+            serde_json::Value::Array(key_ptr.iter().map(component).collect::<Result<_, _>>()?)
+        }
+        JsonKeyFormat::Object => serde_json::Value::Object(
+            key_ptr
+                .iter()
+                .map(|ptr| Ok((ptr.to_string(), component(ptr)?)))
+                .collect::<Result<_, serde_json::Error>>()?,
+        ),
+    };
+    serde_json::to_writer(buf, &key)?;
 
-        Ok((
-            self,
-            match (records.len() > 0, did_timeout) {
-                (false, true) => BatchResult::TimeoutNoData,
-                (true, true) => BatchResult::TimeoutExceededBeforeTarget(frozen),
-                (true, false) => BatchResult::TargetExceededBeforeTimeout(frozen),
-                (false, false) => {
-                    unreachable!("shouldn't be able see no documents, and also not timeout")
-                }
-            },
-        ))
-    }
+    Ok(())
 }
 
-fn compressor<Output: BufMut>(
+pub(crate) fn compressor<Output: BufMut>(
     input: &mut BytesMut,
     output: &mut Output,
     c: Compression,
@@ -387,7 +550,64 @@ fn compressor<Output: BufMut>(
 
             encoder.finish()?;
         }
+        Compression::Snappy => {
+            let compressed = snap::raw::Encoder::new().compress_vec(input)?;
+            output.put_slice(&compressed);
+        }
+        Compression::Zstd => {
+            let compressed = zstd::stream::encode_all(input.reader(), 0)?;
+            output.put_slice(&compressed);
+        }
         unsupported @ _ => bail!("Unsupported compression type {unsupported:?}"),
     };
     Ok(())
 }
+
+/// Map the configured compression of a task into the Compression of record
+/// batches returned to a Fetch of `fetch_version`. Kafka requires Fetch v10
+/// or later for Zstandard, and older consumers are sent LZ4 instead.
+pub fn negotiate_compression(config: RecordCompression, fetch_version: i16) -> Compression {
+    match config {
+        RecordCompression::None => Compression::None,
+        RecordCompression::Lz4 => Compression::Lz4,
+        RecordCompression::Snappy => Compression::Snappy,
+        RecordCompression::Zstd if fetch_version >= 10 => Compression::Zstd,
+        RecordCompression::Zstd => Compression::Lz4,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compression_round_trip() {
+        let content = "hello world! ".repeat(100);
+
+        for (config, fetch_version, expect) in [
+            (RecordCompression::None, 12, Compression::None),
+            (RecordCompression::Lz4, 12, Compression::Lz4),
+            (RecordCompression::Snappy, 4, Compression::Snappy),
+            (RecordCompression::Zstd, 12, Compression::Zstd),
+            (RecordCompression::Zstd, 9, Compression::Lz4),
+        ] {
+            let compression = negotiate_compression(config, fetch_version);
+            assert_eq!(compression, expect);
+
+            let mut output = BytesMut::new();
+            compressor(
+                &mut BytesMut::from(content.as_str()),
+                &mut output,
+                compression,
+            )
+            .unwrap();
+
+            if !matches!(compression, Compression::None) {
+                assert!(output.len() < content.len(), "{compression:?}");
+            }
+            let decompressed =
+                crate::produce::decompressor(&mut output.freeze(), compression).unwrap();
+            assert_eq!(&decompressed[..], content.as_bytes(), "{compression:?}");
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use super::App;
-use crate::{from_downstream_topic_name, to_downstream_topic_name, topology, Authenticated};
+use crate::{
+    connector::DekafConfig, from_downstream_topic_name, to_downstream_topic_name, topology,
+    Authenticated,
+};
 use anyhow::Context;
 use axum::response::{IntoResponse, Response};
 use axum_extra::headers;
@@ -7,18 +10,41 @@ use itertools::Itertools;
 use kafka_protocol::{messages::TopicName, protocol::StrBytes};
 use std::sync::Arc;
 
+// Compatibility level reported for all subjects. Dekaf serves the current
+// schema of each collection, and a proposed schema is compatible if it's
+// able to read documents written with that schema.
+const COMPATIBILITY_LEVEL: &str = "BACKWARD";
+
+// Version of every subject. Dekaf doesn't retain prior schemas of a collection.
+const SUBJECT_VERSION: u32 = 1;
+
+// Number of records returned by a topic preview, if not otherwise requested.
+const DEFAULT_PREVIEW_LIMIT: usize = 10;
+// Maximum number of records which may be requested of a topic preview.
+const MAX_PREVIEW_LIMIT: usize = 100;
+// Maximum duration of a topic preview, after which available records are returned.
+const PREVIEW_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
 // Build an axum::Router which implements a subset of the Confluent Schema Registry API,
 // sufficient for decoding Avro-encoded topic data.
 pub fn build_router(app: Arc<App>) -> axum::Router<()> {
-    use axum::routing::get;
+    use axum::routing::{get, post};
 
     let schema_router = axum::Router::new()
         .route("/subjects", get(all_subjects))
+        .route("/subjects/:subject/versions", get(get_subject_versions))
         .route(
-            "/subjects/:subject/versions/latest",
-            get(get_subject_latest),
+            "/subjects/:subject/versions/:version",
+            get(get_subject_version),
         )
         .route("/schemas/ids/:id", get(get_schema_by_id))
+        .route(
+            "/compatibility/subjects/:subject/versions/:version",
+            post(check_compatibility),
+        )
+        .route("/config", get(get_config))
+        .route("/config/:subject", get(get_subject_config))
+        .route("/topics/:topic/preview", get(preview_topic))
         .layer(tower_http::trace::TraceLayer::new_for_http())
         .with_state(app);
 
@@ -37,15 +63,25 @@ async fn all_subjects(
         let Authenticated {
             client,
             task_config,
+            task_name,
+            topic_names,
             ..
         } = app.authenticate(auth.username(), auth.password()).await?;
 
+        // Tasks may also read the ops logs and stats of their tenant.
+        let ops_topics = task_name
+            .as_deref()
+            .map(topology::ops_topic_names)
+            .unwrap_or_default();
+
         topology::fetch_all_collection_names(&client.pg_client())
             .await
             .context("failed to list collections from the control plane")
             .map(|collections| {
-                collections
+                topic_names
+                    .topics(collections)
                     .into_iter()
+                    .chain(ops_topics)
                     .map(|name| {
                         if task_config.strict_topic_names {
                             to_downstream_topic_name(TopicName::from(StrBytes::from_string(name)))
@@ -63,9 +99,10 @@ async fn all_subjects(
     .await
 }
 
-// Fetch the "latest" schema for a subject (collection).
+// List the versions of a subject (collection).
+// Dekaf serves only the current schema of a collection, which is always version 1.
 #[tracing::instrument(skip(app, auth))]
-async fn get_subject_latest(
+async fn get_subject_versions(
     axum::extract::State(app): axum::extract::State<Arc<App>>,
     axum_extra::TypedHeader(auth): axum_extra::TypedHeader<
         headers::Authorization<headers::authorization::Basic>,
@@ -76,30 +113,57 @@ async fn get_subject_latest(
         let Authenticated {
             client,
             task_config,
+            task_name,
+            topic_names,
             ..
         } = app.authenticate(auth.username(), auth.password()).await?;
 
-        let (is_key, collection) = if subject.ends_with("-value") {
-            (false, &subject[..subject.len() - 6])
-        } else if subject.ends_with("-key") {
-            (true, &subject[..subject.len() - 4])
-        } else {
-            anyhow::bail!("expected subject to end with -key or -value")
-        };
+        _ = resolve_subject(
+            &client,
+            task_name.as_deref(),
+            &topic_names,
+            &task_config,
+            &subject,
+        )
+        .await?;
 
-        let collection = super::Collection::new(
+        Ok(vec![SUBJECT_VERSION])
+    })
+    .await
+}
+
+// Fetch a version of the schema for a subject (collection),
+// which must be either "latest" or the current version.
+#[tracing::instrument(skip(app, auth))]
+async fn get_subject_version(
+    axum::extract::State(app): axum::extract::State<Arc<App>>,
+    axum_extra::TypedHeader(auth): axum_extra::TypedHeader<
+        headers::Authorization<headers::authorization::Basic>,
+    >,
+    axum::extract::Path((subject, version)): axum::extract::Path<(String, String)>,
+) -> Response {
+    wrap(async move {
+        let Authenticated {
+            client,
+            task_config,
+            task_name,
+            topic_names,
+            ..
+        } = app.authenticate(auth.username(), auth.password()).await?;
+
+        check_version(&subject, &version)?;
+
+        let (is_key, collection) = resolve_subject(
             &client,
-            &from_downstream_topic_name(TopicName::from(StrBytes::from_string(
-                collection.to_string(),
-            ))),
-            task_config.deletions,
+            task_name.as_deref(),
+            &topic_names,
+            &task_config,
+            &subject,
         )
-        .await
-        .context("failed to fetch collection metadata")?
-        .with_context(|| format!("collection {collection} does not exist"))?;
+        .await?;
 
         let (key_id, value_id) = collection
-            .registered_schema_ids(&client.pg_client())
+            .registered_schema_ids(&client.pg_client(), &app.schema_ids)
             .await
             .context("failed to resolve registered Avro schemas")?;
 
@@ -114,7 +178,7 @@ async fn get_subject_latest(
             "schema": schema.canonical_form(),
             "schemaType": "AVRO",
             "subject": subject,
-            "version": 1,
+            "version": SUBJECT_VERSION,
         }))
     })
     .await
@@ -167,6 +231,305 @@ async fn get_schema_by_id(
     .await
 }
 
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompatibilityRequest {
+    schema: String,
+    #[serde(default)]
+    schema_type: Option<String>,
+}
+
+// Check whether a proposed schema is compatible with the current schema of a subject.
+// Dekaf only ever serves a single version of a subject, which is always its latest,
+// so any requested version is checked against the collection's current read schema.
+#[tracing::instrument(skip(app, auth, request))]
+async fn check_compatibility(
+    axum::extract::State(app): axum::extract::State<Arc<App>>,
+    axum_extra::TypedHeader(auth): axum_extra::TypedHeader<
+        headers::Authorization<headers::authorization::Basic>,
+    >,
+    axum::extract::Path((subject, version)): axum::extract::Path<(String, String)>,
+    axum::Json(request): axum::Json<CompatibilityRequest>,
+) -> Response {
+    wrap(async move {
+        let Authenticated {
+            client,
+            task_config,
+            task_name,
+            topic_names,
+            ..
+        } = app.authenticate(auth.username(), auth.password()).await?;
+
+        check_version(&subject, &version)?;
+        match request.schema_type.as_deref() {
+            None | Some("AVRO") => (),
+            Some(other) => anyhow::bail!("schema type {other} is not supported"),
+        }
+
+        let (is_key, collection) = resolve_subject(
+            &client,
+            task_name.as_deref(),
+            &topic_names,
+            &task_config,
+            &subject,
+        )
+        .await?;
+
+        let proposed = avro::Schema::parse_str(&request.schema)
+            .context("failed to parse proposed Avro schema")?;
+        let current = if is_key {
+            &collection.key_schema
+        } else {
+            &collection.value_schema
+        };
+
+        Ok(serde_json::json!({
+            "is_compatible": avro::SchemaCompatibility::can_read(current, &proposed),
+        }))
+    })
+    .await
+}
+
+// Fetch the global compatibility configuration.
+#[tracing::instrument(skip_all)]
+async fn get_config(
+    axum::extract::State(app): axum::extract::State<Arc<App>>,
+    axum_extra::TypedHeader(auth): axum_extra::TypedHeader<
+        headers::Authorization<headers::authorization::Basic>,
+    >,
+) -> Response {
+    wrap(async move {
+        app.authenticate(auth.username(), auth.password()).await?;
+
+        Ok(serde_json::json!({
+            "compatibilityLevel": COMPATIBILITY_LEVEL,
+        }))
+    })
+    .await
+}
+
+// Fetch the compatibility configuration of a subject (collection).
+#[tracing::instrument(skip(app, auth))]
+async fn get_subject_config(
+    axum::extract::State(app): axum::extract::State<Arc<App>>,
+    axum_extra::TypedHeader(auth): axum_extra::TypedHeader<
+        headers::Authorization<headers::authorization::Basic>,
+    >,
+    axum::extract::Path(subject): axum::extract::Path<String>,
+) -> Response {
+    wrap(async move {
+        let Authenticated {
+            client,
+            task_config,
+            task_name,
+            topic_names,
+            ..
+        } = app.authenticate(auth.username(), auth.password()).await?;
+
+        // Resolve the subject to ensure its collection exists.
+        _ = resolve_subject(
+            &client,
+            task_name.as_deref(),
+            &topic_names,
+            &task_config,
+            &subject,
+        )
+        .await?;
+
+        Ok(serde_json::json!({
+            "compatibilityLevel": COMPATIBILITY_LEVEL,
+        }))
+    })
+    .await
+}
+
+#[derive(serde::Deserialize)]
+struct PreviewQuery {
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(serde::Serialize)]
+struct PreviewRecord {
+    partition: usize,
+    offset: i64,
+    timestamp: i64,
+    key: Option<String>,
+    value: Option<String>,
+    headers: Vec<PreviewHeader>,
+}
+
+#[derive(serde::Serialize)]
+struct PreviewHeader {
+    key: String,
+    value: Option<String>,
+}
+
+// Sample records of a topic, rendered exactly as they would be served to a
+// Kafka consumer of the authenticated task. Record keys and values are encoded
+// per the task's configured message format, and returned as base64.
+// Records are sampled from the most-recent fragment of each partition, in order,
+// until the requested limit is reached or the preview times out.
+#[tracing::instrument(skip(app, auth))]
+async fn preview_topic(
+    axum::extract::State(app): axum::extract::State<Arc<App>>,
+    axum_extra::TypedHeader(auth): axum_extra::TypedHeader<
+        headers::Authorization<headers::authorization::Basic>,
+    >,
+    axum::extract::Path(topic): axum::extract::Path<String>,
+    axum::extract::Query(query): axum::extract::Query<PreviewQuery>,
+) -> Response {
+    wrap(async move {
+        let Authenticated {
+            client,
+            task_config,
+            task_name,
+            topic_names,
+            ..
+        } = app.authenticate(auth.username(), auth.password()).await?;
+
+        let limit = query.limit.unwrap_or(DEFAULT_PREVIEW_LIMIT);
+        if limit > MAX_PREVIEW_LIMIT {
+            anyhow::bail!("preview limit {limit} exceeds the maximum of {MAX_PREVIEW_LIMIT}");
+        }
+
+        let topic_name =
+            from_downstream_topic_name(TopicName::from(StrBytes::from_string(topic.clone())));
+
+        let collection = super::Collection::new(
+            &client,
+            task_name.as_deref(),
+            &topic_names,
+            &topic_name,
+            task_config.deletions,
+        )
+        .await
+        .context("failed to fetch collection metadata")?
+        .with_context(|| format!("collection {topic} does not exist"))?;
+
+        let (key_schema_id, value_schema_id) = collection
+            .registered_schema_ids(&client.pg_client(), &app.schema_ids)
+            .await
+            .context("failed to resolve registered Avro schemas")?;
+
+        let deadline = std::time::Instant::now() + PREVIEW_TIMEOUT;
+        let mut out = Vec::new();
+
+        for (index, partition) in collection.partitions.iter().enumerate() {
+            if out.len() == limit || std::time::Instant::now() >= deadline {
+                break;
+            }
+            let Some(topology::PartitionOffset {
+                fragment_start,
+                offset,
+                ..
+            }) = collection.fetch_partition_offset(index, -1).await?
+            else {
+                continue;
+            };
+            if offset <= fragment_start {
+                continue; // Partition has no readable content.
+            }
+
+            let mut read = super::read::Read::new(
+                collection.journal_client.clone(),
+                &collection,
+                partition,
+                fragment_start,
+                key_schema_id,
+                value_schema_id,
+                None,
+                task_config.deletions,
+                task_config.format,
+                task_config.json_key_format,
+                task_config.reduction_headers,
+                app.read_fragments_directly,
+                false,
+                kafka_protocol::records::Compression::None,
+                app.data_keys.encryptor(&task_config, &topic_name),
+            );
+            let mut buf = bytes::BytesMut::new();
+
+            // Control documents are read but not shown to consumers,
+            // so they don't count towards the preview limit.
+            while out.len() < limit {
+                let (records, _, did_timeout) = read
+                    .next_records(
+                        super::read::ReadTarget::Docs(limit - out.len()),
+                        deadline,
+                        &mut buf,
+                    )
+                    .await?;
+
+                out.extend(records.into_iter().filter(|r| !r.control).map(|r| {
+                    PreviewRecord {
+                        partition: index,
+                        offset: r.offset,
+                        timestamp: r.timestamp,
+                        key: r.key.map(base64::encode),
+                        value: r.value.map(base64::encode),
+                        headers: r
+                            .headers
+                            .into_iter()
+                            .map(|(key, value)| PreviewHeader {
+                                key: key.to_string(),
+                                value: value.map(base64::encode),
+                            })
+                            .collect(),
+                    }
+                }));
+                if did_timeout {
+                    break;
+                }
+            }
+        }
+        out.truncate(limit);
+
+        Ok(out)
+    })
+    .await
+}
+
+// Map a subject into whether it names a key schema, and its resolved Collection.
+async fn resolve_subject(
+    client: &flow_client::Client,
+    task_name: Option<&str>,
+    topic_names: &topology::TopicNames,
+    task_config: &DekafConfig,
+    subject: &str,
+) -> anyhow::Result<(bool, super::Collection)> {
+    let (is_key, collection) = if let Some(collection) = subject.strip_suffix("-value") {
+        (false, collection)
+    } else if let Some(collection) = subject.strip_suffix("-key") {
+        (true, collection)
+    } else {
+        anyhow::bail!("expected subject to end with -key or -value")
+    };
+
+    let collection = super::Collection::new(
+        client,
+        task_name,
+        topic_names,
+        &from_downstream_topic_name(TopicName::from(StrBytes::from_string(
+            collection.to_string(),
+        ))),
+        task_config.deletions,
+    )
+    .await
+    .context("failed to fetch collection metadata")?
+    .with_context(|| format!("collection {collection} does not exist"))?;
+
+    Ok((is_key, collection))
+}
+
+fn check_version(subject: &str, version: &str) -> anyhow::Result<()> {
+    if version == "latest" || version == SUBJECT_VERSION.to_string() {
+        Ok(())
+    } else {
+        anyhow::bail!("subject {subject} does not have version {version}")
+    }
+}
+
 async fn wrap<F, T>(fut: F) -> Response
 where
     T: serde::Serialize,
@@ -0,0 +1,147 @@
+use anyhow::Context;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// SchemaIds caches the registry IDs of Avro schemas, keyed on their
+/// collection and the MD5 of the schema's canonical form.
+///
+/// Registry IDs are assigned by the control-plane's `registered_avro_schemas`
+/// table, which is the source of truth. Cached IDs persist across restarts,
+/// so that a restarted Dekaf hands out the same IDs that it did before,
+/// without first re-resolving them through the control-plane.
+pub struct SchemaIds {
+    /// File into which cached IDs are persisted, if any.
+    path: Option<PathBuf>,
+    /// Registry IDs, keyed on collection and then schema MD5.
+    ids: Mutex<BTreeMap<String, BTreeMap<String, u32>>>,
+    /// Set when IDs have been cached since they were last persisted.
+    dirty: AtomicBool,
+}
+
+impl SchemaIds {
+    /// Build a SchemaIds which persists into `path`, loading the IDs it holds.
+    pub fn load(path: Option<PathBuf>) -> Self {
+        let ids = match &path {
+            Some(path) => match Self::read(path) {
+                Ok(ids) => ids,
+                Err(err) => {
+                    tracing::warn!(?err, ?path, "failed to load cached schema IDs");
+                    BTreeMap::new()
+                }
+            },
+            None => BTreeMap::new(),
+        };
+
+        Self {
+            path,
+            ids: Mutex::new(ids),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    fn read(path: &std::path::Path) -> anyhow::Result<BTreeMap<String, BTreeMap<String, u32>>> {
+        match std::fs::read(path) {
+            Ok(content) => serde_json::from_slice(&content).context("parsing schema IDs"),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(err) => Err(err).context("reading schema IDs"),
+        }
+    }
+
+    /// Get the cached registry ID of the schema of `collection` having `schema_md5`.
+    pub fn get(&self, collection: &str, schema_md5: &str) -> Option<u32> {
+        self.ids
+            .lock()
+            .unwrap()
+            .get(collection)
+            .and_then(|schemas| schemas.get(schema_md5))
+            .copied()
+    }
+
+    /// Cache the resolved `registry_id` of the schema of `collection` having `schema_md5`.
+    pub fn insert(&self, collection: &str, schema_md5: &str, registry_id: u32) {
+        let prior = self
+            .ids
+            .lock()
+            .unwrap()
+            .entry(collection.to_string())
+            .or_default()
+            .insert(schema_md5.to_string(), registry_id);
+
+        if prior != Some(registry_id) {
+            self.dirty.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Persist cached IDs, if there's a path and they've changed.
+    pub fn persist(&self) -> anyhow::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if !self.dirty.swap(false, Ordering::Relaxed) {
+            return Ok(());
+        }
+        let content = serde_json::to_vec(&*self.ids.lock().unwrap())?;
+
+        // Write and then rename, so that a crash never leaves a partial file.
+        let tmp = path.with_extension("tmp");
+        let result = std::fs::write(&tmp, content)
+            .context("writing schema IDs")
+            .and_then(|()| std::fs::rename(&tmp, path).context("renaming schema IDs"));
+
+        if result.is_err() {
+            self.dirty.store(true, Ordering::Relaxed); // Retry on the next attempt.
+        }
+        result
+    }
+
+    /// Periodically persist cached IDs.
+    pub async fn serve_persistence(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            if let Err(err) = self.persist() {
+                tracing::warn!(?err, "failed to persist cached schema IDs");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_schema_ids_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "dekaf-schema-ids-{}-{}.json",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos(),
+        ));
+
+        let ids = SchemaIds::load(Some(path.clone()));
+        assert_eq!(ids.get("acmeCo/one", "aabb"), None);
+
+        ids.insert("acmeCo/one", "aabb", 12);
+        ids.insert("acmeCo/one", "ccdd", 34);
+        ids.insert("acmeCo/two", "aabb", 12);
+        ids.persist().unwrap();
+
+        // IDs are stable across a restart.
+        let ids = SchemaIds::load(Some(path.clone()));
+        assert_eq!(ids.get("acmeCo/one", "aabb"), Some(12));
+        assert_eq!(ids.get("acmeCo/one", "ccdd"), Some(34));
+        assert_eq!(ids.get("acmeCo/two", "aabb"), Some(12));
+        assert_eq!(ids.get("acmeCo/two", "ccdd"), None);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
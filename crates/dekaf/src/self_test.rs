@@ -0,0 +1,235 @@
+use crate::{App, Session};
+use anyhow::Context;
+use kafka_protocol::{error::ResponseError, messages, protocol::StrBytes};
+use std::future::Future;
+use std::net::SocketAddr;
+
+/// Outcome of a self-test check.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Outcome {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// Check is the result of a single self-test check.
+#[derive(Debug, serde::Serialize)]
+pub struct Check {
+    /// Name of the check.
+    pub name: &'static str,
+    /// Outcome of the check.
+    pub outcome: Outcome,
+    /// Detail of a passed check, or the error or reason of a failed or skipped check.
+    pub detail: String,
+    /// Duration of the check, in milliseconds.
+    pub elapsed_ms: u64,
+}
+
+/// Report is a machine-readable report of a Dekaf self-test.
+#[derive(Debug, Default, serde::Serialize)]
+pub struct Report {
+    /// Whether no check failed.
+    pub passed: bool,
+    /// Checks of the self-test, in the order they were run.
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    /// Run a check which resolves to its detail, recording its outcome.
+    /// Returns true if the check passed.
+    pub async fn run<F>(&mut self, name: &'static str, check: F) -> bool
+    where
+        F: Future<Output = anyhow::Result<String>>,
+    {
+        let started = std::time::Instant::now();
+        let result = check.await;
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+
+        let (outcome, detail) = match result {
+            Ok(detail) => (Outcome::Passed, detail),
+            Err(err) => (Outcome::Failed, format!("{err:#}")),
+        };
+        tracing::info!(name, ?outcome, %detail, "self-test check");
+
+        self.checks.push(Check {
+            name,
+            outcome,
+            detail,
+            elapsed_ms,
+        });
+        outcome == Outcome::Passed
+    }
+
+    /// Record a check which was skipped for `reason`.
+    pub fn skip(&mut self, name: &'static str, reason: impl Into<String>) {
+        self.checks.push(Check {
+            name,
+            outcome: Outcome::Skipped,
+            detail: reason.into(),
+            elapsed_ms: 0,
+        });
+    }
+}
+
+/// SelfTest parameterizes a self-test of a configured Dekaf.
+pub struct SelfTest {
+    /// Session through which SASL authentication is exercised.
+    pub session: Session,
+    /// Named addresses on which Dekaf listens.
+    pub listeners: Vec<(&'static str, SocketAddr)>,
+    /// Credential, in the form of a SASL PLAIN password, and a collection
+    /// whose data-plane route is resolved using the credential.
+    /// If not provided, data-plane route resolution is skipped.
+    pub route_probe: Option<(String, String)>,
+}
+
+/// Run a self-test of Dekaf's configuration and dependencies, which validates
+/// its configuration, connects to the control-plane, resolves a data-plane
+/// route, binds its listeners, and exercises SASL authentication.
+/// Checks are appended to `report`, which may include checks already run by
+/// the caller, and the completed Report is returned.
+pub async fn run(app: &App, self_test: SelfTest, mut report: Report) -> Report {
+    let SelfTest {
+        mut session,
+        listeners,
+        route_probe,
+    } = self_test;
+
+    report.run("config", async { check_config(app) }).await;
+
+    let control_plane = report.run("control-plane", check_control_plane(app)).await;
+
+    match (&route_probe, control_plane) {
+        (Some((password, collection)), true) => {
+            report
+                .run("data-plane-route", check_route(app, password, collection))
+                .await;
+        }
+        (Some(_), false) => report.skip("data-plane-route", "control-plane is unavailable"),
+        (None, _) => report.skip("data-plane-route", "no route probe credential was provided"),
+    }
+
+    for (name, addr) in listeners {
+        report
+            .run(name, async move {
+                let listener = tokio::net::TcpListener::bind(addr)
+                    .await
+                    .with_context(|| format!("failed to bind {addr}"))?;
+                Ok(format!("bound {}", listener.local_addr()?))
+            })
+            .await;
+    }
+
+    report.run("sasl", check_sasl(&mut session)).await;
+
+    report.passed = report
+        .checks
+        .iter()
+        .all(|check| check.outcome != Outcome::Failed);
+    report
+}
+
+fn check_config(app: &App) -> anyhow::Result<String> {
+    if app.secret.is_empty() {
+        anyhow::bail!("encryption secret is empty");
+    }
+    for host in std::iter::once(&app.advertise_host).chain(app.drain_advertise_host.iter()) {
+        if host.parse::<std::net::IpAddr>().is_err()
+            && webpki::DnsNameRef::try_from_ascii_str(host).is_err()
+        {
+            anyhow::bail!("advertised host {host:?} is not an IP address or DNS name");
+        }
+    }
+    if app.drain_advertise_host.as_ref() == Some(&app.advertise_host) {
+        anyhow::bail!("drain advertised host must differ from the advertised host");
+    }
+    Ok(format!(
+        "advertising {}:{}",
+        app.advertise_host, app.advertise_kafka_port
+    ))
+}
+
+async fn check_control_plane(app: &App) -> anyhow::Result<String> {
+    #[derive(serde::Deserialize)]
+    struct Row {}
+
+    let rows: Vec<Row> = flow_client::api_exec(
+        app.client_base
+            .from("live_specs_ext")
+            .select("catalog_name")
+            .limit(1),
+    )
+    .await
+    .context("querying the control-plane API")?;
+
+    Ok(format!("queried control-plane ({} rows)", rows.len()))
+}
+
+async fn check_route(app: &App, password: &str, collection: &str) -> anyhow::Result<String> {
+    let auth = app
+        .authenticate("{}", password)
+        .await
+        .context("authenticating route probe credential")?;
+
+    let (journal_prefix, journal_client) =
+        flow_client::fetch_collection_authorization(&auth.client, collection)
+            .await
+            .with_context(|| format!("authorizing collection {collection}"))?;
+
+    let listing = journal_client
+        .list(proto_gazette::broker::ListRequest {
+            selector: Some(proto_gazette::broker::LabelSelector {
+                include: Some(labels::build_set([(
+                    "name:prefix",
+                    journal_prefix.as_str(),
+                )])),
+                exclude: None,
+            }),
+            ..Default::default()
+        })
+        .await
+        .with_context(|| format!("listing partitions of {collection}"))?;
+
+    Ok(format!(
+        "resolved {} partitions of {collection}",
+        listing.journals.len()
+    ))
+}
+
+// Exercise SASL authentication with a synthetic credential, which must be
+// cleanly rejected.
+async fn check_sasl(session: &mut Session) -> anyhow::Result<String> {
+    let handshake = session
+        .sasl_handshake(
+            messages::SaslHandshakeRequest::default()
+                .with_mechanism(StrBytes::from_static_str("PLAIN")),
+        )
+        .await?;
+
+    if handshake.error_code != 0 {
+        anyhow::bail!("SASL handshake failed with code {}", handshake.error_code);
+    }
+
+    let refresh = serde_json::json!({
+        "id": format!("{:016x}", rand::random::<u64>()),
+        "secret": "dekaf-self-test",
+    });
+    let password = base64::encode(refresh.to_string());
+    let auth_bytes = format!("\0{{}}\0{password}");
+
+    let response = session
+        .sasl_authenticate(
+            messages::SaslAuthenticateRequest::default()
+                .with_auth_bytes(bytes::Bytes::from(auth_bytes)),
+        )
+        .await?;
+
+    if response.error_code != ResponseError::SaslAuthenticationFailed.code() {
+        anyhow::bail!(
+            "expected synthetic credential to be rejected, but got code {}",
+            response.error_code
+        );
+    }
+    Ok("synthetic credential was rejected".to_string())
+}
@@ -1,9 +1,13 @@
 use super::{App, Collection, Read};
 use crate::{
-    from_downstream_topic_name, from_upstream_topic_name,
+    commit_batch::{CommitBatcher, PendingCommit},
+    fetch_session::{self, FetchSession, Resolved},
+    from_downstream_topic_name, from_upstream_topic_name, metrics_topic, produce,
+    profile::ProfileSettings,
+    rate_limit::FetchRateLimiter,
     read::BatchResult,
     to_downstream_topic_name, to_upstream_topic_name,
-    topology::{fetch_all_collection_names, PartitionOffset},
+    topology::{self, fetch_all_collection_names, PartitionOffset, UnionPartition, UnionTopic},
     Authenticated, KafkaApiClient,
 };
 use anyhow::{bail, Context};
@@ -12,6 +16,7 @@ use kafka_protocol::{
     error::{ParseResponseErrorCode, ResponseError},
     messages::{
         self,
+        metadata_request::MetadataRequestTopic,
         metadata_response::{
             MetadataResponseBroker, MetadataResponsePartition, MetadataResponseTopic,
         },
@@ -20,19 +25,33 @@ use kafka_protocol::{
     },
     protocol::{buf::ByteBuf, Decodable, Encodable, Message, StrBytes},
 };
-use std::{cmp::max, sync::Arc, time::Duration};
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    cmp::max,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
     time::{SystemTime, UNIX_EPOCH},
 };
 use tracing::instrument;
 
+/// Duration for which a session re-uses its enumeration of all partitions.
+const ALL_PARTITIONS_TTL: Duration = Duration::from_secs(30);
+
 struct PendingRead {
     offset: i64,          // Journal offset to be completed by this PendingRead.
     last_write_head: i64, // Most-recent observed journal write head.
     handle: tokio_util::task::AbortOnDropHandle<anyhow::Result<(Read, BatchResult)>>,
 }
 
+/// SASL mechanism negotiated by a session's handshake.
+#[derive(Clone, Copy, Debug)]
+enum SaslMechanism {
+    Plain,
+    OAuthBearer,
+}
+
 #[derive(Clone, Debug)]
 enum SessionDataPreviewState {
     Unknown,
@@ -40,6 +59,46 @@ enum SessionDataPreviewState {
     DataPreview(HashMap<(TopicName, i32), PartitionOffset>),
 }
 
+/// Append `framed` documents to `journal`, returning the journal offset
+/// at which they begin. Transient errors are retried.
+async fn append_framed(
+    journal_client: &gazette::journal::Client,
+    journal: &str,
+    framed: Bytes,
+) -> Result<i64, gazette::Error> {
+    use futures::StreamExt;
+
+    // Number of attempts of an append before giving up on a transient error.
+    const MAX_ATTEMPTS: usize = 5;
+
+    let request = gazette::broker::AppendRequest {
+        journal: journal.to_string(),
+        ..Default::default()
+    };
+    let stream = journal_client.append(request, move || {
+        futures::stream::once(futures::future::ready(Ok(framed.clone())))
+    });
+    tokio::pin!(stream);
+
+    loop {
+        match stream.next().await {
+            Some(Ok(response)) => {
+                return Ok(response
+                    .commit
+                    .map(|commit| commit.begin)
+                    .unwrap_or_default())
+            }
+            Some(Err(gazette::RetryError { attempt, inner }))
+                if inner.is_transient() && attempt + 1 < MAX_ATTEMPTS =>
+            {
+                tracing::warn!(%journal, attempt, error = ?inner, "retrying append of produced records");
+            }
+            Some(Err(gazette::RetryError { inner, .. })) => return Err(inner),
+            None => unreachable!("append stream yields until a response or error is returned"),
+        }
+    }
+}
+
 pub struct Session {
     app: Arc<App>,
     client: Option<KafkaApiClient>,
@@ -47,6 +106,17 @@ pub struct Session {
     secret: String,
     auth: Option<Authenticated>,
     data_preview_state: SessionDataPreviewState,
+    rate_limiter: FetchRateLimiter,
+    fetch_session: FetchSession,
+    commit_batcher: CommitBatcher,
+    sasl_mechanism: SaslMechanism,
+    producer: gazette::uuid::Producer,
+    produce_clock: gazette::uuid::Clock,
+    write_targets: HashMap<TopicName, produce::WriteTarget>,
+    /// Partitions of all topics, as last enumerated for the task's profile.
+    all_partitions: Option<(Instant, Vec<MetadataResponseTopic>)>,
+    /// Topics which this session has recorded into the warm start of its task.
+    warm_topics: HashSet<String>,
     broker_url: String,
     broker_username: String,
     broker_password: String,
@@ -72,6 +142,15 @@ impl Session {
             secret,
             client_id: None,
             data_preview_state: SessionDataPreviewState::Unknown,
+            rate_limiter: FetchRateLimiter::default(),
+            fetch_session: FetchSession::default(),
+            commit_batcher: CommitBatcher::default(),
+            sasl_mechanism: SaslMechanism::Plain,
+            producer: produce::new_producer(),
+            produce_clock: gazette::uuid::Clock::default(),
+            write_targets: HashMap::new(),
+            all_partitions: None,
+            warm_topics: HashSet::new(),
         }
     }
 
@@ -96,47 +175,80 @@ impl Session {
     }
 
     /// SASL handshake responds with supported SASL mechanisms.
-    /// We support PLAIN user/password, because we expect the password to be a control-plane token.
+    /// We support PLAIN user/password, because we expect the password to be a control-plane token,
+    /// as well as OAUTHBEARER with a short-lived control-plane access token.
     pub async fn sasl_handshake(
         &mut self,
         request: messages::SaslHandshakeRequest,
     ) -> anyhow::Result<messages::SaslHandshakeResponse> {
         let mut response = messages::SaslHandshakeResponse::default();
         response.mechanisms.push(StrBytes::from_static_str("PLAIN"));
-
-        if request.mechanism.ne("PLAIN") {
-            response.error_code = ResponseError::UnsupportedSaslMechanism.code();
+        response
+            .mechanisms
+            .push(StrBytes::from_static_str("OAUTHBEARER"));
+
+        match request.mechanism.as_str() {
+            "PLAIN" => self.sasl_mechanism = SaslMechanism::Plain,
+            "OAUTHBEARER" => self.sasl_mechanism = SaslMechanism::OAuthBearer,
+            _ => response.error_code = ResponseError::UnsupportedSaslMechanism.code(),
         }
         Ok(response)
     }
 
-    /// Parse a PLAIN user/password to extract a control-plane access token.
+    /// Parse a PLAIN user/password to extract a control-plane refresh token,
+    /// or an OAUTHBEARER message to extract a control-plane access token.
     pub async fn sasl_authenticate(
         &mut self,
         request: messages::SaslAuthenticateRequest,
     ) -> anyhow::Result<messages::SaslAuthenticateResponse> {
-        let mut it = request
-            .auth_bytes
-            .split(|b| *b == 0) // SASL uses NULL to separate components.
-            .map(std::str::from_utf8);
-
-        let _authzid = it.next().context("expected SASL authzid")??;
-        let authcid = it.next().context("expected SASL authcid")??;
-        let password = it.next().context("expected SASL passwd")??;
-
-        let response = match self.app.authenticate(authcid, password).await {
-            Ok(auth) => {
-                let claims = auth.claims.clone();
+        let authenticated = match self.sasl_mechanism {
+            SaslMechanism::Plain => {
+                let mut it = request
+                    .auth_bytes
+                    .split(|b| *b == 0) // SASL uses NULL to separate components.
+                    .map(std::str::from_utf8);
+
+                let _authzid = it.next().context("expected SASL authzid")??;
+                let authcid = it.next().context("expected SASL authcid")??;
+                let password = it.next().context("expected SASL passwd")??;
+
+                self.app.authenticate(authcid, password).await
+            }
+            SaslMechanism::OAuthBearer => match crate::parse_oauthbearer(&request.auth_bytes) {
+                Ok((authzid, token)) => self.app.authenticate_bearer(authzid, token).await,
+                Err(err) => Err(err),
+            },
+        };
+
+        // A token may have expired since it was verified.
+        // Treat a session with no remaining lifetime as unauthenticated.
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("")?
+            .as_secs();
+
+        let authenticated =
+            authenticated.and_then(|auth| match auth.claims.exp.saturating_sub(now) {
+                0 => Err(anyhow::anyhow!("access token has expired")),
+                lifetime => Ok((auth, lifetime)),
+            });
+
+        let response = match authenticated {
+            Ok((auth, lifetime)) => {
+                self.rate_limiter = FetchRateLimiter::new(&auth.task_config);
+
+                // Remember the task, and its credential, for warm starts.
+                if let Some(task_name) = &auth.task_name {
+                    self.app.warm_start.touch(
+                        task_name,
+                        auth.refresh_token.as_ref(),
+                        &self.app.secret,
+                    );
+                }
                 self.auth.replace(auth);
 
                 let mut response = messages::SaslAuthenticateResponse::default();
-                response.session_lifetime_ms = (1000
-                    * (claims.exp
-                        - SystemTime::now()
-                            .duration_since(UNIX_EPOCH)
-                            .context("")?
-                            .as_secs()))
-                .try_into()?;
+                response.session_lifetime_ms = lifetime.saturating_mul(1000).try_into()?;
                 response
             }
             Err(err) => messages::SaslAuthenticateResponse::default()
@@ -159,13 +271,17 @@ impl Session {
     ) -> anyhow::Result<messages::MetadataResponse> {
         let topics = match request.topics.take() {
             Some(topics) if topics.len() > 0 => self.metadata_select_topics(topics).await,
+            // Some profiles require partitions of all topics, at greater expense.
+            _ if self.profile().enumerate_all_partitions => self.metadata_all_partitions().await,
             _ => self.metadata_all_topics().await,
         }?;
 
         // We only ever advertise a single logical broker.
         let brokers = vec![MetadataResponseBroker::default()
             .with_node_id(messages::BrokerId(1))
-            .with_host(StrBytes::from_string(self.app.advertise_host.clone()))
+            .with_host(StrBytes::from_string(
+                self.app.advertised_host().to_string(),
+            ))
             .with_port(self.app.advertise_kafka_port as i32)];
 
         Ok(messages::MetadataResponse::default()
@@ -177,21 +293,40 @@ impl Session {
 
     // Lists all read-able collections as Kafka topics. Omits partition metadata.
     async fn metadata_all_topics(&mut self) -> anyhow::Result<Vec<MetadataResponseTopic>> {
-        let collections = fetch_all_collection_names(
-            &self
-                .auth
-                .as_mut()
-                .ok_or(anyhow::anyhow!("Session not authenticated"))?
-                .authenticated_client()
-                .await?
-                .pg_client(),
-        )
-        .await?;
+        let auth = self
+            .auth
+            .as_mut()
+            .ok_or(anyhow::anyhow!("Session not authenticated"))?;
+
+        // Tasks may also read the ops logs and stats of their tenant.
+        let ops_topics = auth
+            .task_name
+            .as_deref()
+            .map(topology::ops_topic_names)
+            .unwrap_or_default();
+
+        let collections =
+            fetch_all_collection_names(&auth.authenticated_client().await?.pg_client()).await?;
+        let collections = auth.topic_names.topics(collections);
 
         tracing::debug!(collections=?ops::DebugJson(&collections), "fetched all collections");
 
+        let metrics_topic = self
+            .metrics_topic_enabled()
+            .then(|| metrics_topic::TOPIC_NAME.to_string());
+
+        let union_topics: Vec<String> = auth
+            .task_config
+            .union_topics
+            .iter()
+            .map(|union| union.topic_name.clone())
+            .collect();
+
         let topics = collections
             .into_iter()
+            .chain(ops_topics)
+            .chain(union_topics)
+            .chain(metrics_topic)
             .map(|name| {
                 MetadataResponseTopic::default()
                     .with_name(Some(self.encode_topic_name(name)))
@@ -205,6 +340,27 @@ impl Session {
         Ok(topics)
     }
 
+    // Lists partitions of all read-able topics. Enumeration fetches the
+    // journals of every topic, so its result is re-used for ALL_PARTITIONS_TTL.
+    async fn metadata_all_partitions(&mut self) -> anyhow::Result<Vec<MetadataResponseTopic>> {
+        if let Some((listed_at, topics)) = &self.all_partitions {
+            if listed_at.elapsed() < ALL_PARTITIONS_TTL {
+                return Ok(topics.clone());
+            }
+        }
+
+        let topics = self
+            .metadata_all_topics()
+            .await?
+            .into_iter()
+            .map(|topic| MetadataRequestTopic::default().with_name(topic.name))
+            .collect();
+        let topics = self.metadata_select_topics(topics).await?;
+
+        self.all_partitions = Some((Instant::now(), topics.clone()));
+        Ok(topics)
+    }
+
     // Lists partitions of specific, requested collections.
     async fn metadata_select_topics(
         &mut self,
@@ -216,25 +372,60 @@ impl Session {
             .ok_or(anyhow::anyhow!("Session not authenticated"))?;
 
         let deletions = auth.task_config.deletions.to_owned();
+        let config = auth.task_config.clone();
+        let config = &config;
+        let topic_names = auth.topic_names.clone();
+        let topic_names = &topic_names;
+        let task_name = auth.task_name.clone();
+        let task_name = task_name.as_deref();
         let client = auth.authenticated_client().await?;
 
-        // Concurrently fetch Collection instances for all requested topics.
-        let collections: anyhow::Result<Vec<(TopicName, Option<Collection>)>> =
+        // Concurrently fetch the partition counts of all requested topics.
+        // Writable topics are resolved to the collections they write,
+        // and union topics to the collections they merge.
+        let collections: anyhow::Result<Vec<(TopicName, Option<usize>)>> =
             futures::future::try_join_all(requests.into_iter().map(|topic| async move {
+                let name = from_downstream_topic_name(topic.name.to_owned().unwrap_or_default());
+
+                if let Some(union) = topology::union_topic(config, name.as_str()) {
+                    let maybe_union = UnionTopic::new(client, task_name, union, deletions).await?;
+                    return Ok((
+                        topic.name.unwrap_or_default(),
+                        maybe_union.map(|union| union.partition_count()),
+                    ));
+                }
                 let maybe_collection = Collection::new(
                     client,
-                    from_downstream_topic_name(topic.name.to_owned().unwrap_or_default()).as_str(),
+                    task_name,
+                    topic_names,
+                    produce::writable_collection(config, name.as_str()).unwrap_or(name.as_str()),
                     deletions,
                 )
                 .await?;
-                Ok((topic.name.unwrap_or_default(), maybe_collection))
+                Ok((
+                    topic.name.unwrap_or_default(),
+                    maybe_collection.map(|collection| collection.partitions.len()),
+                ))
             }))
             .await;
 
         let mut topics = vec![];
 
-        for (name, maybe_collection) in collections? {
-            let Some(collection) = maybe_collection else {
+        for (name, maybe_partition_count) in collections? {
+            if self.is_metrics_topic(&name) {
+                topics.push(
+                    MetadataResponseTopic::default()
+                        .with_name(Some(name))
+                        .with_is_internal(false)
+                        .with_partitions(vec![MetadataResponsePartition::default()
+                            .with_partition_index(0)
+                            .with_leader_id(messages::BrokerId(1))
+                            .with_replica_nodes(vec![messages::BrokerId(1)])
+                            .with_isr_nodes(vec![messages::BrokerId(1)])]),
+                );
+                continue;
+            }
+            let Some(partition_count) = maybe_partition_count else {
                 topics.push(
                     MetadataResponseTopic::default()
                         .with_name(Some(self.encode_topic_name(name.to_string())))
@@ -243,11 +434,8 @@ impl Session {
                 continue;
             };
 
-            let partitions = collection
-                .partitions
-                .iter()
-                .enumerate()
-                .map(|(index, _)| {
+            let partitions = (0..partition_count)
+                .map(|index| {
                     messages::metadata_response::MetadataResponsePartition::default()
                         .with_partition_index(index as i32)
                         .with_leader_id(messages::BrokerId(1))
@@ -272,20 +460,28 @@ impl Session {
         &mut self,
         request: messages::FindCoordinatorRequest,
     ) -> anyhow::Result<messages::FindCoordinatorResponse> {
+        // Consumer group state isn't held by Dekaf: group APIs are proxied to
+        // the group's coordinator within the upstream Kafka cluster. Any Dekaf
+        // replica is therefore able to serve any group, and we advertise our
+        // (load-balanced) address as the coordinator of every key.
         let coordinators = request
             .coordinator_keys
             .iter()
             .map(|_key| {
                 messages::find_coordinator_response::Coordinator::default()
                     .with_node_id(messages::BrokerId(1))
-                    .with_host(StrBytes::from_string(self.app.advertise_host.clone()))
+                    .with_host(StrBytes::from_string(
+                        self.app.advertised_host().to_string(),
+                    ))
                     .with_port(self.app.advertise_kafka_port as i32)
             })
             .collect();
 
         Ok(messages::FindCoordinatorResponse::default()
             .with_node_id(messages::BrokerId(1))
-            .with_host(StrBytes::from_string(self.app.advertise_host.clone()))
+            .with_host(StrBytes::from_string(
+                self.app.advertised_host().to_string(),
+            ))
             .with_port(self.app.advertise_kafka_port as i32)
             .with_coordinators(coordinators))
     }
@@ -299,21 +495,52 @@ impl Session {
             .as_mut()
             .ok_or(anyhow::anyhow!("Session not authenticated"))?;
 
-        let deletions = auth.task_config.deletions.to_owned();
+        let metrics_topic = auth.task_config.metrics_topic;
+        let config = auth.task_config.clone();
+        let config = &config;
+        let topic_names = auth.topic_names.clone();
+        let topic_names = &topic_names;
+        let task_name = auth.task_name.clone();
+        let task_name = task_name.as_deref();
         let client = auth.authenticated_client().await?;
+        let now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as i64;
 
         // Concurrently fetch Collection instances and offsets for all requested topics and partitions.
         // Map each "topic" into Vec<(Partition Index, Option<PartitionOffset>.
         let collections: anyhow::Result<Vec<(TopicName, Vec<(i32, Option<PartitionOffset>)>)>> =
             futures::future::try_join_all(request.topics.into_iter().map(|topic| async move {
-                let maybe_collection = Collection::new(
+                let collection_name = from_downstream_topic_name(topic.name.clone());
+
+                if metrics_topic && collection_name.as_str() == metrics_topic::TOPIC_NAME {
+                    return Ok((
+                        topic.name,
+                        topic
+                            .partitions
+                            .iter()
+                            .map(|p| {
+                                (
+                                    p.partition_index,
+                                    metrics_topic::partition_offset(
+                                        p.partition_index,
+                                        p.timestamp,
+                                        now_millis,
+                                    ),
+                                )
+                            })
+                            .collect(),
+                    ));
+                }
+
+                let maybe_union = UnionTopic::resolve(
                     client,
-                    from_downstream_topic_name(topic.name.clone()).as_str(),
-                    deletions,
+                    task_name,
+                    topic_names,
+                    config,
+                    collection_name.as_str(),
                 )
                 .await?;
 
-                let Some(collection) = maybe_collection else {
+                let Some(union) = maybe_union else {
                     return Ok((
                         topic.name,
                         topic
@@ -323,14 +550,14 @@ impl Session {
                             .collect(),
                     ));
                 };
-                let collection = &collection;
+                let union = &union;
 
                 // Concurrently fetch requested offset for each named partition.
                 let offsets: anyhow::Result<_> = futures::future::try_join_all(
                     topic.partitions.into_iter().map(|partition| async move {
                         Ok((
                             partition.partition_index,
-                            collection
+                            union
                                 .fetch_partition_offset(
                                     partition.partition_index as usize,
                                     partition.timestamp, // In millis.
@@ -395,19 +622,47 @@ impl Session {
     pub async fn fetch(
         &mut self,
         request: messages::FetchRequest,
+        version: i16,
     ) -> anyhow::Result<messages::FetchResponse> {
         use messages::fetch_response::{FetchableTopicResponse, PartitionData};
 
         let messages::FetchRequest {
-            topics: topic_requests,
-            max_bytes: _, // Ignored.
+            topics,
+            max_bytes,
             max_wait_ms,
             min_bytes: _, // Ignored.
             session_id,
+            session_epoch,
+            forgotten_topics_data,
             ..
         } = request;
 
-        let (mut client, config) = {
+        // Consumers fetch continuously, so flush batched commits which are due.
+        self.flush_due_offset_commits().await;
+
+        // Resolve the full set of partitions of an incremental fetch session.
+        let Resolved {
+            session_id,
+            topics: topic_requests,
+            forgotten,
+            incremental,
+        } = match self.fetch_session.resolve(
+            session_id,
+            session_epoch,
+            topics,
+            forgotten_topics_data,
+        ) {
+            Ok(resolved) => resolved,
+            Err(err) => {
+                return Ok(messages::FetchResponse::default().with_error_code(err.code()));
+            }
+        };
+        for (topic, partition) in forgotten {
+            self.reads
+                .remove(&(from_downstream_topic_name(topic), partition));
+        }
+
+        let (mut client, config, task_name, topic_names) = {
             let auth = self
                 .auth
                 .as_mut()
@@ -416,15 +671,78 @@ impl Session {
             (
                 auth.authenticated_client().await?.clone(),
                 auth.task_config.to_owned(),
+                auth.task_name.clone(),
+                auth.topic_names.clone(),
             )
         };
 
+        if let Some((throttled, delay)) = self.rate_limiter.begin_request(Instant::now()) {
+            metrics::counter!("dekaf_fetch_throttled", "limit" => throttled.as_str()).increment(1);
+            tracing::debug!(limit = throttled.as_str(), ?delay, "throttling fetch");
+
+            // Respond immediately without data, and leave it to the client to
+            // back off for `throttle_time_ms` as per KIP-219. Pending reads are
+            // retained and will be served once the client returns.
+            let mut topic_responses = topic_requests
+                .iter()
+                .map(|topic_request| {
+                    let key = from_downstream_topic_name(topic_request.topic.clone());
+
+                    let partition_responses = topic_request
+                        .partitions
+                        .iter()
+                        .map(|partition_request| {
+                            let high_watermark = self
+                                .reads
+                                .get(&(key.clone(), partition_request.partition))
+                                .map(|(pending, _)| pending.last_write_head)
+                                .unwrap_or(-1);
+
+                            PartitionData::default()
+                                .with_partition_index(partition_request.partition)
+                                .with_high_watermark(high_watermark)
+                                .with_last_stable_offset(high_watermark)
+                                .with_records(Some(Bytes::new()))
+                        })
+                        .collect();
+
+                    FetchableTopicResponse::default()
+                        .with_topic(topic_request.topic.clone())
+                        .with_partitions(partition_responses)
+                })
+                .collect();
+
+            self.fetch_session
+                .observe_responses(&mut topic_responses, incremental);
+
+            return Ok(messages::FetchResponse::default()
+                .with_session_id(session_id)
+                .with_throttle_time_ms(delay.as_millis() as i32)
+                .with_responses(topic_responses));
+        }
+
         let timeout = std::time::Duration::from_millis(max_wait_ms as u64);
 
+        // Each partition reads a fair share of `max_bytes`, so that a partition
+        // having a large backlog cannot fill a fetch at the expense of others.
+        let partition_count: usize = topic_requests.iter().map(|t| t.partitions.len()).sum();
+        let budget = |partition_max_bytes| {
+            fetch_session::partition_budget(max_bytes, partition_max_bytes, partition_count)
+        };
+
+        let compression = crate::read::negotiate_compression(config.compression, version);
+
+        // Placeholder partitions of union topics, which are served as empty.
+        let mut placeholders = HashSet::new();
+
         // Start reads for all partitions which aren't already pending.
         for topic_request in &topic_requests {
             let mut key = (from_downstream_topic_name(topic_request.topic.clone()), 0);
 
+            if self.is_metrics_topic(&topic_request.topic) {
+                continue; // Served synthetically, without a Read.
+            }
+
             for partition_request in &topic_request.partitions {
                 key.1 = partition_request.partition;
                 let fetch_offset = partition_request.fetch_offset;
@@ -513,8 +831,59 @@ impl Session {
                     _ => {}
                 }
 
-                let Some(collection) = Collection::new(&client, &key.0, config.deletions).await?
-                else {
+                // Union topics resolve to the member collection of the partition.
+                let (maybe_collection, partition_index, is_union) =
+                    match topology::union_topic(&config, &key.0) {
+                        Some(union) => match UnionTopic::fetch_partition(
+                            &client,
+                            task_name.as_deref(),
+                            union,
+                            key.1 as usize,
+                            config.deletions,
+                        )
+                        .await?
+                        {
+                            UnionPartition::Placeholder => {
+                                placeholders.insert(key.clone());
+                                continue;
+                            }
+                            UnionPartition::Member(collection, index) => {
+                                (Some(collection), index, true)
+                            }
+                            UnionPartition::Missing => (None, 0, true),
+                        },
+                        None => {
+                            let prefetched = task_name
+                                .as_deref()
+                                .and_then(|task_name| self.app.warm_start.take(task_name, &key.0));
+
+                            let maybe_collection = match prefetched {
+                                Some(collection) => Some(collection),
+                                None => {
+                                    Collection::new(
+                                        &client,
+                                        task_name.as_deref(),
+                                        &topic_names,
+                                        &key.0,
+                                        config.deletions,
+                                    )
+                                    .await?
+                                }
+                            };
+                            if let (Some(task_name), Some(_)) = (&task_name, &maybe_collection) {
+                                if !self.warm_topics.contains(key.0.as_str()) {
+                                    self.app.warm_start.record(task_name, &key.0);
+                                    self.warm_topics.insert(key.0.to_string());
+                                }
+                            }
+                            (
+                                maybe_collection,
+                                partition_request.partition as usize,
+                                false,
+                            )
+                        }
+                    };
+                let Some(collection) = maybe_collection else {
                     metrics::counter!(
                         "dekaf_fetch_requests",
                         "topic_name" => key.0.to_string(),
@@ -525,10 +894,7 @@ impl Session {
                     tracing::debug!(collection = ?&key.0, "Collection doesn't exist!");
                     continue; // Collection doesn't exist.
                 };
-                let Some(partition) = collection
-                    .partitions
-                    .get(partition_request.partition as usize)
-                else {
+                let Some(partition) = collection.partitions.get(partition_index) else {
                     metrics::counter!(
                         "dekaf_fetch_requests",
                         "topic_name" => key.0.to_string(),
@@ -540,7 +906,7 @@ impl Session {
                     continue; // Partition doesn't exist.
                 };
                 let (key_schema_id, value_schema_id) = collection
-                    .registered_schema_ids(&client.pg_client())
+                    .registered_schema_ids(&client.pg_client(), &self.app.schema_ids)
                     .await?;
                 let pending = PendingRead {
                     offset: fetch_offset,
@@ -570,6 +936,13 @@ impl Session {
                                     value_schema_id,
                                     Some(partition_request.fetch_offset - 1),
                                     config.deletions,
+                                    config.format,
+                                    config.json_key_format,
+                                    config.reduction_headers,
+                                    self.app.read_fragments_directly,
+                                    is_union,
+                                    compression,
+                                    self.app.data_keys.encryptor(&config, &key.0),
                                 )
                                 .next_batch(
                                     // Have to read at least 2 docs, as the very last doc
@@ -598,11 +971,18 @@ impl Session {
                                     value_schema_id,
                                     None,
                                     config.deletions,
+                                    config.format,
+                                    config.json_key_format,
+                                    config.reduction_headers,
+                                    self.app.read_fragments_directly,
+                                    is_union,
+                                    compression,
+                                    self.app.data_keys.encryptor(&config, &key.0),
                                 )
                                 .next_batch(
-                                    crate::read::ReadTarget::Bytes(
-                                        partition_request.partition_max_bytes as usize,
-                                    ),
+                                    crate::read::ReadTarget::Bytes(budget(
+                                        partition_request.partition_max_bytes,
+                                    )),
                                     std::time::Instant::now() + timeout,
                                 ),
                             )
@@ -635,82 +1015,183 @@ impl Session {
         }
 
         // Poll pending reads across all requested topics.
-        let mut topic_responses = Vec::with_capacity(topic_requests.len());
+        let mut partition_responses: Vec<Vec<Option<PartitionData>>> = topic_requests
+            .iter()
+            .map(|topic_request| vec![None; topic_request.partitions.len()])
+            .collect();
+        let mut served_bytes = 0;
+        let stats_principal = config.metrics_topic.then(|| self.principal());
+        // Waits for new metrics documents share a single deadline of this Fetch.
+        let metrics_deadline = std::time::Instant::now() + timeout;
+
+        for (t, p) in self.fetch_session.poll_order(&topic_requests) {
+            let topic_request = &topic_requests[t];
+            let partition_request = &topic_request.partitions[p];
+
+            if self.is_metrics_topic(&topic_request.topic) {
+                let partition_data = self
+                    .fetch_metrics_topic(
+                        partition_request.partition,
+                        partition_request.fetch_offset,
+                        metrics_deadline,
+                        self.app
+                            .data_keys
+                            .encryptor(&config, metrics_topic::TOPIC_NAME),
+                    )
+                    .await?;
+                served_bytes += partition_data
+                    .records
+                    .as_ref()
+                    .map(Bytes::len)
+                    .unwrap_or_default();
+                partition_responses[t][p] = Some(partition_data);
+                continue;
+            }
+            let key = (
+                from_downstream_topic_name(topic_request.topic.clone()),
+                partition_request.partition,
+            );
 
-        for topic_request in &topic_requests {
-            let mut key = (from_downstream_topic_name(topic_request.topic.clone()), 0);
-            let mut partition_responses = Vec::with_capacity(topic_request.partitions.len());
+            if placeholders.contains(&key) {
+                partition_responses[t][p] = Some(
+                    PartitionData::default()
+                        .with_partition_index(partition_request.partition)
+                        .with_records(Some(Bytes::new()))
+                        .with_high_watermark(0)
+                        .with_last_stable_offset(0),
+                );
+                continue;
+            }
 
-            for partition_request in &topic_request.partitions {
-                key.1 = partition_request.partition;
+            let Some((pending, _)) = self.reads.get_mut(&key) else {
+                partition_responses[t][p] = Some(
+                    PartitionData::default()
+                        .with_partition_index(partition_request.partition)
+                        .with_error_code(ResponseError::UnknownTopicOrPartition.code()),
+                );
+                continue;
+            };
 
-                let Some((pending, _)) = self.reads.get_mut(&key) else {
-                    partition_responses.push(
-                        PartitionData::default()
-                            .with_partition_index(partition_request.partition)
-                            .with_error_code(ResponseError::UnknownTopicOrPartition.code()),
-                    );
-                    continue;
-                };
+            // If `max_bytes` is exhausted, defer remaining partitions without
+            // polling their reads, which are retained for the next Fetch.
+            // As in Kafka, the first non-empty partition is always served in
+            // full so that consumers can make progress.
+            if max_bytes > 0
+                && served_bytes >= max_bytes as usize
+                && matches!(
+                    self.data_preview_state,
+                    SessionDataPreviewState::NotDataPreview
+                )
+            {
+                partition_responses[t][p] = Some(
+                    PartitionData::default()
+                        .with_partition_index(partition_request.partition)
+                        .with_records(Some(Bytes::new()))
+                        .with_high_watermark(pending.last_write_head)
+                        .with_last_stable_offset(pending.last_write_head),
+                );
+                continue;
+            }
 
-                let (read, batch) = (&mut pending.handle).await??;
+            let (read, batch) = (&mut pending.handle).await??;
 
-                let batch = match batch {
-                    BatchResult::TargetExceededBeforeTimeout(b) => Some(b),
-                    BatchResult::TimeoutExceededBeforeTarget(b) => Some(b),
-                    BatchResult::TimeoutNoData => None,
-                };
+            let batch = match batch {
+                BatchResult::TargetExceededBeforeTimeout(b) => Some(b),
+                BatchResult::TimeoutExceededBeforeTarget(b) => Some(b),
+                BatchResult::TimeoutNoData => None,
+            };
+            served_bytes += batch.as_ref().map(Bytes::len).unwrap_or_default();
 
-                let mut partition_data = PartitionData::default()
-                    .with_partition_index(partition_request.partition)
-                    // `kafka-protocol` encodes None here using a length of -1, but librdkafka client library
-                    // complains with: `Protocol parse failure for Fetch v11 ... invalid MessageSetSize -1`
-                    // An empty Bytes will get encoded with a length of 0, which works fine.
-                    .with_records(batch.or(Some(Bytes::new())).to_owned());
+            metrics::counter!(
+                "dekaf_fetch_bytes",
+                "topic" => key.0.to_string(),
+                "partition" => key.1.to_string(),
+            )
+            .increment(batch.as_ref().map(Bytes::len).unwrap_or_default() as u64);
+            self.app.consumer_offsets.record_write_head(
+                task_name.as_deref(),
+                key.0.as_str(),
+                key.1,
+                read.last_write_head,
+            );
 
-                match &self.data_preview_state {
-                    SessionDataPreviewState::Unknown => {
-                        unreachable!("Must have already determined data-preview status of session")
-                    }
-                    SessionDataPreviewState::NotDataPreview => {
-                        pending.offset = read.offset;
-                        pending.last_write_head = read.last_write_head;
-                        pending.handle = tokio_util::task::AbortOnDropHandle::new(tokio::spawn(
-                            read.next_batch(
-                                crate::read::ReadTarget::Bytes(
-                                    partition_request.partition_max_bytes as usize,
-                                ),
-                                std::time::Instant::now() + timeout,
-                            ),
-                        ));
+            if let Some(principal) = &stats_principal {
+                self.app.throughput_stats.record(
+                    principal,
+                    key.0.as_str(),
+                    key.1,
+                    batch.as_ref().map(Bytes::len).unwrap_or_default(),
+                    read.batch_docs,
+                    read.offset,
+                    read.last_write_head,
+                );
+            }
 
-                        partition_data = partition_data
-                            .with_high_watermark(pending.last_write_head) // Map to kafka cursor.
-                            .with_last_stable_offset(pending.last_write_head);
-                    }
-                    SessionDataPreviewState::DataPreview(data_preview_states) => {
-                        let data_preview_state = data_preview_states
-                            .get(&key)
-                            .expect("should be able to find data preview state by this point");
-                        partition_data = partition_data
-                            .with_high_watermark(data_preview_state.offset) // Map to kafka cursor.
-                            .with_last_stable_offset(data_preview_state.offset);
-                        self.reads.remove(&key);
-                    }
-                }
+            let mut partition_data = PartitionData::default()
+                .with_partition_index(partition_request.partition)
+                // `kafka-protocol` encodes None here using a length of -1, but librdkafka client library
+                // complains with: `Protocol parse failure for Fetch v11 ... invalid MessageSetSize -1`
+                // An empty Bytes will get encoded with a length of 0, which works fine.
+                .with_records(batch.or(Some(Bytes::new())).to_owned());
 
-                partition_responses.push(partition_data);
+            match &self.data_preview_state {
+                SessionDataPreviewState::Unknown => {
+                    unreachable!("Must have already determined data-preview status of session")
+                }
+                SessionDataPreviewState::NotDataPreview => {
+                    pending.offset = read.offset;
+                    pending.last_write_head = read.last_write_head;
+                    pending.handle =
+                        tokio_util::task::AbortOnDropHandle::new(tokio::spawn(read.next_batch(
+                            crate::read::ReadTarget::Bytes(budget(
+                                partition_request.partition_max_bytes,
+                            )),
+                            std::time::Instant::now() + timeout,
+                        )));
+
+                    partition_data = partition_data
+                        .with_high_watermark(pending.last_write_head) // Map to kafka cursor.
+                        .with_last_stable_offset(pending.last_write_head);
+                }
+                SessionDataPreviewState::DataPreview(data_preview_states) => {
+                    let data_preview_state = data_preview_states
+                        .get(&key)
+                        .expect("should be able to find data preview state by this point");
+                    partition_data = partition_data
+                        .with_high_watermark(data_preview_state.offset) // Map to kafka cursor.
+                        .with_last_stable_offset(data_preview_state.offset);
+                    self.reads.remove(&key);
+                }
             }
 
-            topic_responses.push(
+            partition_responses[t][p] = Some(partition_data);
+        }
+
+        // Responses are returned in the order of their requests.
+        let mut topic_responses: Vec<FetchableTopicResponse> = topic_requests
+            .iter()
+            .zip(partition_responses)
+            .map(|(topic_request, partitions)| {
                 FetchableTopicResponse::default()
                     .with_topic(topic_request.topic.clone())
-                    .with_partitions(partition_responses),
-            );
+                    .with_partitions(partitions.into_iter().flatten().collect())
+            })
+            .collect();
+
+        let delay = self
+            .rate_limiter
+            .complete_request(served_bytes, Instant::now());
+
+        if !delay.is_zero() {
+            metrics::counter!("dekaf_fetch_throttled", "limit" => "bytes").increment(1);
         }
 
+        self.fetch_session
+            .observe_responses(&mut topic_responses, incremental);
+
         Ok(messages::FetchResponse::default()
             .with_session_id(session_id)
+            .with_throttle_time_ms(delay.as_millis() as i32)
             .with_responses(topic_responses))
     }
 
@@ -750,48 +1231,327 @@ impl Session {
         Ok(DescribeConfigsResponse::default().with_results(results))
     }
 
-    /// Produce is assumed to be supported in various places, and clients using librdkafka
+    /// DescribeCluster describes our single logical broker.
+    /// It's informational, and is used by observability and admin tooling.
+    pub async fn describe_cluster(
+        &mut self,
+        _req: messages::DescribeClusterRequest,
+    ) -> anyhow::Result<messages::DescribeClusterResponse> {
+        use kafka_protocol::messages::describe_cluster_response::*;
+
+        let brokers = vec![DescribeClusterBroker::default()
+            .with_broker_id(messages::BrokerId(1))
+            .with_host(StrBytes::from_string(
+                self.app.advertised_host().to_string(),
+            ))
+            .with_port(self.app.advertise_kafka_port as i32)];
+
+        Ok(DescribeClusterResponse::default()
+            .with_brokers(brokers)
+            .with_cluster_id(StrBytes::from_static_str("estuary-dekaf"))
+            .with_controller_id(messages::BrokerId(1)))
+    }
+
+    /// DescribeLogDirs describes a single synthesized log directory of our
+    /// logical broker, having the requested topics and partitions.
+    /// Partition sizes are estimated from listings of their journal fragments.
+    /// As with Metadata, an unqualified request does not enumerate topics.
+    #[instrument(skip_all)]
+    pub async fn describe_log_dirs(
+        &mut self,
+        req: messages::DescribeLogDirsRequest,
+    ) -> anyhow::Result<messages::DescribeLogDirsResponse> {
+        use kafka_protocol::messages::describe_log_dirs_response::*;
+
+        let auth = self
+            .auth
+            .as_mut()
+            .ok_or(anyhow::anyhow!("Session not authenticated"))?;
+
+        let metrics_topic = auth.task_config.metrics_topic;
+        let config = auth.task_config.clone();
+        let config = &config;
+        let topic_names = auth.topic_names.clone();
+        let topic_names = &topic_names;
+        let task_name = auth.task_name.clone();
+        let task_name = task_name.as_deref();
+        let client = auth.authenticated_client().await?;
+
+        // Concurrently estimate the sizes of all requested partitions.
+        // Partitions which don't exist are omitted.
+        let topics: anyhow::Result<Vec<DescribeLogDirsTopic>> =
+            futures::future::try_join_all(req.topics.unwrap_or_default().into_iter().map(
+                |topic| async move {
+                    let collection_name = from_downstream_topic_name(topic.topic.clone());
+
+                    // The metrics topic is synthesized, and isn't persisted.
+                    if metrics_topic && collection_name.as_str() == metrics_topic::TOPIC_NAME {
+                        return Ok(DescribeLogDirsTopic::default().with_name(topic.topic));
+                    }
+                    let maybe_union = UnionTopic::resolve(
+                        client,
+                        task_name,
+                        topic_names,
+                        config,
+                        collection_name.as_str(),
+                    )
+                    .await?;
+
+                    let Some(union) = maybe_union else {
+                        return Ok(DescribeLogDirsTopic::default().with_name(topic.topic));
+                    };
+                    let union = &union;
+
+                    let sizes = futures::future::try_join_all(topic.partitions.iter().map(
+                        |&partition_index| async move {
+                            anyhow::Ok((
+                                partition_index,
+                                union.fetch_partition_size(partition_index as usize).await?,
+                            ))
+                        },
+                    ))
+                    .await?;
+
+                    let partitions = sizes
+                        .into_iter()
+                        .filter_map(|(partition_index, maybe_size)| {
+                            maybe_size.map(|size| {
+                                DescribeLogDirsPartition::default()
+                                    .with_partition_index(partition_index)
+                                    .with_partition_size(size)
+                            })
+                        })
+                        .collect();
+
+                    Ok(DescribeLogDirsTopic::default()
+                        .with_name(topic.topic)
+                        .with_partitions(partitions))
+                },
+            ))
+            .await;
+
+        let result = DescribeLogDirsResult::default()
+            .with_log_dir(StrBytes::from_static_str("/estuary-dekaf"))
+            .with_topics(topics?)
+            // Journal fragments are held in unbounded cloud storage.
+            .with_total_bytes(-1)
+            .with_usable_bytes(-1);
+
+        Ok(DescribeLogDirsResponse::default().with_results(vec![result]))
+    }
+
+    /// Produce writes records of the task's writable topics into their mapped collections.
+    ///
+    /// Produce is also assumed to be supported in various places, and clients using librdkafka
     /// break when that assumption isn't satisfied. For example, the `Fetch` API > version 0
     /// appears to (indirectly) assume that the broker supports `Produce`.
     /// For example: Each of these 3 conditions (`MSGVER1`, `MSGVER2`, `THROTTLE_TIME`) require `Produce`,
     /// and when it's not present the consumer will sit in a tight loop endlessly failing to
     /// send a fetch request because it's missing an API version flag:
     /// https://github.com/confluentinc/librdkafka/blob/master/src/rdkafka_fetcher.c#L997-L1005
+    ///
+    /// Topics which are not writable topics of the task are rejected.
     pub async fn produce(
         &mut self,
         req: messages::ProduceRequest,
     ) -> anyhow::Result<messages::ProduceResponse> {
         use kafka_protocol::messages::produce_response::*;
 
-        let responses = req
-            .topic_data
-            .into_iter()
-            .map(|v| {
-                TopicProduceResponse::default().with_partition_responses(
-                    v.partition_data
-                        .into_iter()
-                        .map(|part| {
-                            PartitionProduceResponse::default()
-                                .with_index(part.index)
-                                .with_error_code(
-                                    kafka_protocol::error::ResponseError::InvalidRequest.code(),
-                                )
-                        })
-                        .collect(),
+        let auth = self
+            .auth
+            .as_mut()
+            .ok_or(anyhow::anyhow!("Session not authenticated"))?;
+
+        let config = auth.task_config.clone();
+        let task_name = auth.task_name.clone();
+        let client = auth.authenticated_client().await?.clone();
+
+        let mut responses = Vec::with_capacity(req.topic_data.len());
+
+        for topic in req.topic_data {
+            let topic_name = from_downstream_topic_name(topic.name.clone());
+            let error_all = |code: ResponseError| {
+                TopicProduceResponse::default()
+                    .with_name(topic.name.clone())
+                    .with_partition_responses(
+                        topic
+                            .partition_data
+                            .iter()
+                            .map(|part| {
+                                PartitionProduceResponse::default()
+                                    .with_index(part.index)
+                                    .with_error_code(code.code())
+                            })
+                            .collect(),
+                    )
+            };
+
+            let Some(collection_name) = produce::writable_collection(&config, topic_name.as_str())
+            else {
+                tracing::warn!(topic = %topic_name, "rejecting produce to a topic which isn't writable");
+                responses.push(error_all(ResponseError::TopicAuthorizationFailed));
+                continue;
+            };
+
+            // Re-use a resolved WriteTarget of the topic, if it's not expired.
+            let target = match self.write_targets.remove(&topic_name) {
+                Some(target) if !target.is_expired(Instant::now()) => target,
+                _ => match Self::resolve_write_target(
+                    &client,
+                    task_name.as_deref(),
+                    &config,
+                    collection_name,
                 )
-            })
-            .collect();
+                .await
+                {
+                    Ok(target) => target,
+                    Err(code) => {
+                        tracing::warn!(topic = %topic_name, ?code, "rejecting produce to a topic which couldn't be resolved");
+                        responses.push(error_all(code));
+                        continue;
+                    }
+                },
+            };
+            let produce::WriteTarget {
+                collection,
+                mut validator,
+                journal_client,
+                resolved_at,
+            } = target;
+
+            let mut partition_responses = Vec::with_capacity(topic.partition_data.len());
+
+            for part in &topic.partition_data {
+                let Some(partition) = collection.partitions.get(part.index as usize) else {
+                    partition_responses.push(
+                        PartitionProduceResponse::default()
+                            .with_index(part.index)
+                            .with_error_code(ResponseError::UnknownTopicOrPartition.code()),
+                    );
+                    continue;
+                };
+
+                let response = produce::produce_partition(
+                    &collection.spec,
+                    &mut validator,
+                    &partition.spec,
+                    self.producer,
+                    &mut self.produce_clock,
+                    part.index,
+                    part.records.clone(),
+                    |framed| append_framed(&journal_client, &partition.spec.name, framed),
+                )
+                .await;
+
+                partition_responses.push(response);
+            }
+
+            // Retain the WriteTarget for future requests only if it served them all.
+            // Otherwise it's discarded and resolved again by the next request.
+            if !partition_responses.iter().any(produce::is_stale_target) {
+                self.write_targets.insert(
+                    topic_name,
+                    produce::WriteTarget {
+                        collection,
+                        validator,
+                        journal_client,
+                        resolved_at,
+                    },
+                );
+            }
+
+            responses.push(
+                TopicProduceResponse::default()
+                    .with_name(topic.name)
+                    .with_partition_responses(partition_responses),
+            );
+        }
 
         Ok(ProduceResponse::default().with_responses(responses))
     }
 
+    /// Resolve the WriteTarget of `collection_name`, mapping a failure
+    /// into the error code of the produced topic's partitions.
+    async fn resolve_write_target(
+        client: &flow_client::Client,
+        task_name: Option<&str>,
+        config: &crate::connector::DekafConfig,
+        collection_name: &str,
+    ) -> Result<produce::WriteTarget, ResponseError> {
+        let collection = match Collection::new(
+            client,
+            task_name,
+            &topology::TopicNames::default(),
+            collection_name,
+            config.deletions,
+        )
+        .await
+        {
+            Ok(Some(collection)) => collection,
+            Ok(None) => return Err(ResponseError::UnknownTopicOrPartition),
+            Err(err) => {
+                tracing::warn!(
+                    collection = collection_name,
+                    ?err,
+                    "failed to resolve produced collection"
+                );
+                return Err(ResponseError::UnknownServerError);
+            }
+        };
+        let validator = match produce::write_validator(&collection.spec) {
+            Ok(validator) => validator,
+            Err(err) => {
+                tracing::warn!(
+                    collection = collection_name,
+                    ?err,
+                    "failed to build write schema validator"
+                );
+                return Err(ResponseError::InvalidRecord);
+            }
+        };
+
+        // The journal client of `collection` is authorized only to read.
+        // Appends require an authorization of the user's Write capability.
+        let journal_client = match flow_client::fetch_collection_authorization_with_capability(
+            client,
+            collection_name,
+            Some(models::Capability::Write),
+        )
+        .await
+        {
+            Ok((_, journal_client)) => journal_client,
+            Err(err) => {
+                tracing::warn!(
+                    collection = collection_name,
+                    ?err,
+                    "collection isn't writable"
+                );
+                return Err(ResponseError::TopicAuthorizationFailed);
+            }
+        };
+
+        Ok(produce::WriteTarget {
+            collection,
+            validator,
+            journal_client,
+            resolved_at: Instant::now(),
+        })
+    }
+
     #[instrument(skip_all, fields(group=?req.group_id))]
     pub async fn join_group(
         &mut self,
         req: messages::JoinGroupRequest,
         header: RequestHeader,
     ) -> anyhow::Result<messages::JoinGroupResponse> {
+        // While draining, direct members to find a new coordinator.
+        if self.app.is_draining() {
+            return Ok(messages::JoinGroupResponse::default()
+                .with_error_code(ResponseError::NotCoordinator.code()));
+        }
+
         let mut mutable_req = req.clone();
+
         for protocol in mutable_req.protocols.iter_mut() {
             let mut consumer_protocol_subscription_raw = protocol.metadata.clone();
 
@@ -901,6 +1661,8 @@ impl Session {
         req: messages::LeaveGroupRequest,
         header: RequestHeader,
     ) -> anyhow::Result<messages::LeaveGroupResponse> {
+        self.flush_group_offset_commits(req.group_id.as_str()).await;
+
         let client = self
             .get_kafka_client()
             .await?
@@ -950,6 +1712,11 @@ impl Session {
         req: messages::SyncGroupRequest,
         header: RequestHeader,
     ) -> anyhow::Result<messages::SyncGroupResponse> {
+        if self.app.is_draining() {
+            return Ok(messages::SyncGroupResponse::default()
+                .with_error_code(ResponseError::NotCoordinator.code()));
+        }
+
         let mut mutable_req = req.clone();
         for assignment in mutable_req.assignments.iter_mut() {
             let mut consumer_protocol_assignment_raw = assignment.assignment.clone();
@@ -1056,6 +1823,12 @@ impl Session {
         req: messages::HeartbeatRequest,
         header: RequestHeader,
     ) -> anyhow::Result<messages::HeartbeatResponse> {
+        // While draining, ask members to re-join so that they're moved elsewhere.
+        if self.app.is_draining() {
+            return Ok(messages::HeartbeatResponse::default()
+                .with_error_code(ResponseError::RebalanceInProgress.code()));
+        }
+
         let client = self
             .get_kafka_client()
             .await?
@@ -1064,11 +1837,102 @@ impl Session {
         return client.send_request(req, Some(header)).await;
     }
 
+    /// OffsetCommit requests are acknowledged immediately, and are batched
+    /// with other commits of their group which are flushed upstream
+    /// after `commit_batch::FLUSH_INTERVAL`, unless Dekaf is draining.
     #[instrument(skip_all, fields(group=?req.group_id))]
     pub async fn offset_commit(
         &mut self,
         req: messages::OffsetCommitRequest,
         header: RequestHeader,
+    ) -> anyhow::Result<messages::OffsetCommitResponse> {
+        // Members commit as their partitions are revoked by a rebalance,
+        // and while draining these commits are written through immediately.
+        if self.app.is_draining() {
+            let group = req.group_id.to_string();
+            self.flush_group_offset_commits(&group).await;
+
+            let mut response = self.send_offset_commit(req, header).await?;
+            self.commit_batcher.deliver_failures(&group, &mut response);
+            return Ok(response);
+        }
+
+        let now = std::time::Instant::now();
+        let response = self.commit_batcher.add(req, header, now);
+
+        self.flush_due_offset_commits().await;
+        Ok(response)
+    }
+
+    /// Instant at which batched OffsetCommit requests are next due to be flushed, if any.
+    pub fn offset_commit_deadline(&self) -> Option<std::time::Instant> {
+        self.commit_batcher.next_deadline()
+    }
+
+    /// Flush batched OffsetCommit requests which are due.
+    pub async fn flush_due_offset_commits(&mut self) {
+        for pending in self.commit_batcher.take_due(std::time::Instant::now()) {
+            self.flush_offset_commit(pending).await;
+        }
+    }
+
+    /// Flush all batched OffsetCommit requests of the session.
+    pub async fn flush_offset_commits(&mut self) {
+        for pending in self.commit_batcher.take_all() {
+            self.flush_offset_commit(pending).await;
+        }
+    }
+
+    async fn flush_group_offset_commits(&mut self, group: &str) {
+        if let Some(pending) = self.commit_batcher.take_group(group) {
+            self.flush_offset_commit(pending).await;
+        }
+    }
+
+    /// Flush a batched OffsetCommit upstream. Errors are scoped to the group
+    /// of the commit, and are delivered to its next commit rather than failing
+    /// the session, as the client was already acknowledged.
+    async fn flush_offset_commit(&mut self, pending: PendingCommit) {
+        let PendingCommit {
+            request,
+            header,
+            commits,
+            ..
+        } = pending;
+
+        metrics::histogram!("dekaf_offset_commit_batch_size").record(commits as f64);
+        let start = std::time::Instant::now();
+
+        let group = request.group_id.to_string();
+        let resp = match self.send_offset_commit(request.clone(), header).await {
+            Ok(resp) => resp,
+            Err(err) => {
+                tracing::warn!(?err, group, "failed to flush batched offset commits");
+                metrics::counter!("dekaf_offset_commit_errors", "error" => "FlushFailed")
+                    .increment(1);
+                self.commit_batcher
+                    .record_failure(&request, ResponseError::CoordinatorNotAvailable.code());
+                return;
+            }
+        };
+
+        metrics::histogram!("dekaf_offset_commit_flush_time").record(start.elapsed().as_secs_f64());
+
+        for topic in resp.topics.iter() {
+            for partition in topic.partitions.iter() {
+                if let Some(error) = partition.error_code.err() {
+                    metrics::counter!("dekaf_offset_commit_errors", "error" => format!("{error:?}"))
+                        .increment(1);
+                }
+            }
+        }
+        self.commit_batcher.record_response(&group, &resp);
+    }
+
+    async fn send_offset_commit(
+        &mut self,
+        req: messages::OffsetCommitRequest,
+        header: RequestHeader,
     ) -> anyhow::Result<messages::OffsetCommitResponse> {
         let mut mutated_req = req.clone();
         for topic in &mut mutated_req.topics {
@@ -1082,8 +1946,11 @@ impl Session {
             .as_mut()
             .ok_or(anyhow::anyhow!("Session not authenticated"))?;
 
-        let deletions = auth.task_config.deletions.to_owned();
+        let config = auth.task_config.clone();
+        let task_name = auth.task_name.clone();
+        let topic_names = auth.topic_names.clone();
         let flow_client = auth.authenticated_client().await?.clone();
+        let access_token = auth.access_token.clone();
 
         let client = self
             .get_kafka_client()
@@ -1106,25 +1973,34 @@ impl Session {
         for topic in resp.topics.iter_mut() {
             topic.name = self.decrypt_topic_name(topic.name.to_owned());
 
-            let collection_partitions =
-                Collection::new(&flow_client, topic.name.as_str(), deletions)
-                    .await?
-                    .context(format!("unable to look up partitions for {:?}", topic.name))?
-                    .partitions;
+            let union = UnionTopic::resolve(
+                &flow_client,
+                task_name.as_deref(),
+                &topic_names,
+                &config,
+                topic.name.as_str(),
+            )
+            .await?
+            .context(format!("unable to look up partitions for {:?}", topic.name))?;
 
             for partition in &topic.partitions {
                 if let Some(error) = partition.error_code.err() {
                     tracing::warn!(topic=?topic.name,partition=partition.partition_index,?error,"Got error from upstream Kafka when trying to commit offsets");
                 } else {
-                    let journal_name = collection_partitions
-                        .get(partition.partition_index as usize)
-                        .context(format!(
-                            "unable to find partition {} in collection {:?}",
-                            partition.partition_index, topic.name
-                        ))?
-                        .spec
-                        .name
-                        .to_owned();
+                    let partition_index = partition.partition_index as usize;
+                    let journal_name = if union.is_placeholder(partition_index) {
+                        None // Placeholder partitions of union topics have no journal.
+                    } else {
+                        Some(
+                            union
+                                .journal_name(partition_index)
+                                .context(format!(
+                                    "unable to find partition {} in collection {:?}",
+                                    partition.partition_index, topic.name
+                                ))?
+                                .to_owned(),
+                        )
+                    };
 
                     let committed_offset = req
                         .topics
@@ -1132,14 +2008,25 @@ impl Session {
                         .find(|req_topic| req_topic.name == topic.name)
                         .context(format!("unable to find topic in request {:?}", topic.name))?
                         .partitions
-                        .get(partition.partition_index as usize)
+                        .iter()
+                        .find(|p| p.partition_index == partition.partition_index)
                         .context(format!(
                             "unable to find partition {}",
                             partition.partition_index
                         ))?
                         .committed_offset;
 
-                    metrics::gauge!("dekaf_committed_offset", "group_id"=>req.group_id.to_string(),"journal_name"=>journal_name).set(committed_offset as f64);
+                    if let Some(journal_name) = journal_name {
+                        metrics::gauge!("dekaf_committed_offset", "group_id"=>req.group_id.to_string(),"journal_name"=>journal_name).set(committed_offset as f64);
+                    }
+                    self.app.consumer_offsets.record_commit(
+                        task_name.as_deref(),
+                        &access_token,
+                        req.group_id.as_str(),
+                        from_downstream_topic_name(topic.name.to_owned()).as_str(),
+                        partition.partition_index,
+                        committed_offset,
+                    );
                 }
             }
         }
@@ -1153,6 +2040,9 @@ impl Session {
         req: messages::OffsetFetchRequest,
         header: RequestHeader,
     ) -> anyhow::Result<messages::OffsetFetchResponse> {
+        // Flush batched commits so that they're reflected in fetched offsets.
+        self.flush_group_offset_commits(req.group_id.as_str()).await;
+
         let mut mutated_req = req.clone();
         if let Some(ref mut topics) = mutated_req.topics {
             for topic in topics {
@@ -1182,6 +2072,81 @@ impl Session {
         Ok(resp)
     }
 
+    #[instrument(skip_all, fields(groups=?req.groups))]
+    pub async fn describe_groups(
+        &mut self,
+        req: messages::DescribeGroupsRequest,
+        header: RequestHeader,
+    ) -> anyhow::Result<messages::DescribeGroupsResponse> {
+        let mut resp = messages::DescribeGroupsResponse::default();
+
+        // Each group may be managed by a different coordinator,
+        // so describe them one at a time and stitch the results together.
+        for group_id in req.groups.iter() {
+            let group_req = req.clone().with_groups(vec![group_id.clone()]);
+
+            let group_resp = self
+                .get_kafka_client()
+                .await?
+                .connect_to_group_coordinator(group_id.as_str())
+                .await?
+                .send_request(group_req, Some(header.clone()))
+                .await?;
+
+            resp.throttle_time_ms = max(resp.throttle_time_ms, group_resp.throttle_time_ms);
+
+            for mut group in group_resp.groups {
+                if let Some(err) = group.error_code.err() {
+                    tracing::debug!(?err, group=?group.group_id, "DescribeGroups errored");
+                } else if group.protocol_type.as_str() == "consumer" {
+                    for member in group.members.iter_mut() {
+                        member.member_metadata =
+                            self.decrypt_subscription(member.member_metadata.clone())?;
+                        member.member_assignment =
+                            self.decrypt_assignment(member.member_assignment.clone())?;
+                    }
+                }
+                resp.groups.push(group);
+            }
+        }
+
+        Ok(resp)
+    }
+
+    #[instrument(skip_all, fields(group=?req.group_id))]
+    pub async fn offset_delete(
+        &mut self,
+        req: messages::OffsetDeleteRequest,
+        header: RequestHeader,
+    ) -> anyhow::Result<messages::OffsetDeleteResponse> {
+        let mut mutated_req = req.clone();
+        for topic in &mut mutated_req.topics {
+            let encrypted = self.encrypt_topic_name(topic.name.clone());
+            tracing::info!(topic_name = ?topic.name, encrypted_name = ?encrypted, "Deleting offsets");
+            topic.name = encrypted;
+        }
+
+        let client = self
+            .get_kafka_client()
+            .await?
+            .connect_to_group_coordinator(req.group_id.as_str())
+            .await?;
+
+        let mut resp = client.send_request(mutated_req, Some(header)).await?;
+
+        for topic in resp.topics.iter_mut() {
+            topic.name = self.decrypt_topic_name(topic.name.to_owned());
+
+            for partition in &topic.partitions {
+                if let Some(error) = partition.error_code.err() {
+                    tracing::warn!(topic=?topic.name,partition=partition.partition_index,?error,"Got error from upstream Kafka when trying to delete offsets");
+                }
+            }
+        }
+
+        Ok(resp)
+    }
+
     /// ApiVersions lists the APIs which are supported by this "broker".
     pub async fn api_versions(
         &mut self,
@@ -1218,6 +2183,9 @@ impl Session {
                 .with_max_version(12),
             // Needed by `kaf`.
             version::<DescribeConfigsRequest>(ApiKey::DescribeConfigsKey),
+            // Used by observability and admin tooling.
+            version::<DescribeClusterRequest>(ApiKey::DescribeClusterKey),
+            version::<DescribeLogDirsRequest>(ApiKey::DescribeLogDirsKey),
             ApiVersion::default()
                 .with_api_key(ApiKey::ProduceKey as i16)
                 .with_min_version(3)
@@ -1229,6 +2197,8 @@ impl Session {
             client.supported_versions::<DeleteGroupsRequest>()?,
             client.supported_versions::<HeartbeatRequest>()?,
             client.supported_versions::<OffsetCommitRequest>()?,
+            client.supported_versions::<DescribeGroupsRequest>()?,
+            client.supported_versions::<OffsetDeleteRequest>()?,
             ApiVersion::default()
                 .with_api_key(ApiKey::OffsetFetchKey as i16)
                 .with_min_version(0)
@@ -1271,6 +2241,137 @@ impl Session {
         )
     }
 
+    /// Protocol settings of the session's compatibility profile,
+    /// which are the defaults if the session isn't authenticated.
+    fn profile(&self) -> ProfileSettings {
+        self.auth
+            .as_ref()
+            .map(|auth| auth.task_config.profile.settings())
+            .unwrap_or_default()
+    }
+
+    fn metrics_topic_enabled(&self) -> bool {
+        self.auth
+            .as_ref()
+            .map(|auth| auth.task_config.metrics_topic)
+            .unwrap_or_default()
+    }
+
+    fn is_metrics_topic(&self, name: &TopicName) -> bool {
+        self.metrics_topic_enabled()
+            && from_downstream_topic_name(name.clone()).as_str() == metrics_topic::TOPIC_NAME
+    }
+
+    /// Principal of the session, under which throughput stats are tracked.
+    fn principal(&self) -> String {
+        self.auth
+            .as_ref()
+            .expect("Must be authenticated")
+            .claims
+            .sub
+            .to_string()
+    }
+
+    /// Serve a Fetch of the synthesized metrics topic, which holds a single
+    /// partition of periodic metrics documents keyed on their production time.
+    /// A caught-up client waits for the next document until `deadline`.
+    async fn fetch_metrics_topic(
+        &self,
+        partition: i32,
+        fetch_offset: i64,
+        deadline: std::time::Instant,
+        encryptor: Option<crate::encryption::Encryptor>,
+    ) -> anyhow::Result<messages::fetch_response::PartitionData> {
+        use messages::fetch_response::PartitionData;
+
+        if partition != 0 {
+            return Ok(PartitionData::default()
+                .with_partition_index(partition)
+                .with_error_code(ResponseError::UnknownTopicOrPartition.code()));
+        }
+        let now_millis = || -> anyhow::Result<i64> {
+            Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as i64)
+        };
+
+        // If the client is caught up, having read the latest document,
+        // wait until the next document is emitted or the deadline.
+        let mut latest = metrics_topic::latest_offset(now_millis()?);
+        if fetch_offset > latest {
+            let next = metrics_topic::next_offset(fetch_offset);
+            let wait = Duration::from_millis((next - now_millis()?).max(0) as u64);
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            tokio::time::sleep(wait.min(remaining)).await;
+            latest = metrics_topic::latest_offset(now_millis()?);
+        }
+
+        let records = if fetch_offset <= latest {
+            let data_key = match &encryptor {
+                Some(encryptor) => Some(encryptor.data_key().await?),
+                None => None,
+            };
+            self.app.throughput_stats.encode_batch(
+                &self.principal(),
+                latest,
+                data_key.as_deref(),
+            )?
+        } else {
+            Bytes::new()
+        };
+
+        Ok(PartitionData::default()
+            .with_partition_index(partition)
+            .with_high_watermark(latest + 1)
+            .with_last_stable_offset(latest + 1)
+            .with_records(Some(records)))
+    }
+
+    /// Re-write an upstream ConsumerProtocolSubscription so that its topics are decrypted.
+    fn decrypt_subscription(&self, mut raw: Bytes) -> anyhow::Result<Bytes> {
+        if raw.is_empty() {
+            return Ok(raw);
+        }
+        let version = raw
+            .try_get_i16()
+            .context("failed to parse consumer protocol message: subscription version")?;
+
+        let mut msg = ConsumerProtocolSubscription::decode(&mut raw, version)
+            .context("failed to parse consumer protocol message: subscription body")?;
+        msg.topics
+            .iter_mut()
+            .for_each(|topic| *topic = self.decrypt_topic_name(topic.to_owned().into()).into());
+
+        let mut out = BytesMut::new();
+        out.put_i16(version);
+        msg.encode(&mut out, version)?;
+        Ok(out.into())
+    }
+
+    /// Re-write an upstream ConsumerProtocolAssignment so that its topics are decrypted.
+    fn decrypt_assignment(&self, mut raw: Bytes) -> anyhow::Result<Bytes> {
+        if raw.is_empty() {
+            return Ok(raw);
+        }
+        let version = raw
+            .try_get_i16()
+            .context("failed to parse consumer protocol message: assignment version")?;
+
+        let mut msg = ConsumerProtocolAssignment::decode(&mut raw, version)
+            .context("failed to parse consumer protocol message: assignment body")?;
+        msg.assigned_partitions = msg
+            .assigned_partitions
+            .into_iter()
+            .map(|part| {
+                let topic = self.decrypt_topic_name(part.topic.to_owned());
+                part.with_topic(topic)
+            })
+            .collect();
+
+        let mut out = BytesMut::new();
+        out.put_i16(version);
+        msg.encode(&mut out, version)?;
+        Ok(out.into())
+    }
+
     fn encode_topic_name(&self, name: String) -> TopicName {
         if self
             .auth
@@ -1299,24 +2400,30 @@ impl Session {
             .as_mut()
             .ok_or(anyhow::anyhow!("Session not authenticated"))?;
 
-        let deletions = auth.task_config.deletions.to_owned();
+        let config = auth.task_config.clone();
+        let task_name = auth.task_name.clone();
+        let topic_names = auth.topic_names.clone();
         let client = auth.authenticated_client().await?;
 
         tracing::debug!(
             "Loading latest offset for this partition to check if session is data-preview"
         );
-        let collection = Collection::new(&client, collection_name.as_str(), deletions)
-            .await?
-            .ok_or(anyhow::anyhow!("Collection {} not found", collection_name))?;
+        let topic = UnionTopic::resolve(
+            &client,
+            task_name.as_deref(),
+            &topic_names,
+            &config,
+            collection_name.as_str(),
+        )
+        .await?
+        .ok_or(anyhow::anyhow!("Collection {} not found", collection_name))?;
 
         if let Some(
             partition_offset @ PartitionOffset {
                 offset: latest_offset,
                 ..
             },
-        ) = collection
-            .fetch_partition_offset(partition as usize, -1)
-            .await?
+        ) = topic.fetch_partition_offset(partition as usize, -1).await?
         {
             // If fetch_offset is >= latest_offset, this is a caught-up consumer
             // polling for new documents, not a data preview request.
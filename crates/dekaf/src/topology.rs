@@ -1,8 +1,10 @@
-use crate::connector::DeletionMode;
+use crate::connector::{DekafConfig, DekafResourceConfig, DekafUnionTopic, DeletionMode};
+use crate::SchemaIds;
 use anyhow::Context;
 use futures::{StreamExt, TryStreamExt};
 use gazette::{broker, journal, uuid};
 use proto_flow::flow;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Fetch the names of all collections which the current user may read.
 /// Each is mapped into a kafka topic.
@@ -27,6 +29,349 @@ pub async fn fetch_all_collection_names(
     Ok(items)
 }
 
+/// Kind of ops collection which is served through a synthesized ops topic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpsKind {
+    Logs,
+    Stats,
+}
+
+/// Parse a topic of the form `ops/<tenant>/logs` or `ops/<tenant>/stats`,
+/// which serves the ops logs or stats of all tasks under the tenant.
+pub fn parse_ops_topic(topic: &str) -> Option<(&str, OpsKind)> {
+    let (tenant, kind) = topic.strip_prefix("ops/")?.rsplit_once('/')?;
+
+    if tenant.is_empty() || tenant.contains('/') {
+        return None;
+    }
+    match kind {
+        "logs" => Some((tenant, OpsKind::Logs)),
+        "stats" => Some((tenant, OpsKind::Stats)),
+        _ => None,
+    }
+}
+
+/// Names of the ops topics which are available to the authenticated task.
+pub fn ops_topic_names(task_name: &str) -> Vec<String> {
+    let Some((tenant, _)) = task_name.split_once('/') else {
+        return Vec::new();
+    };
+    vec![format!("ops/{tenant}/logs"), format!("ops/{tenant}/stats")]
+}
+
+/// Build a selector of the journals of `collection`, which is restricted to
+/// partitions of tasks under `ops_tenant`, if set.
+/// Ops collections are partitioned on the `kind` and `name` of tasks,
+/// so a prefix match of `name` isolates the ops data of a single tenant.
+fn partition_selector(collection: &str, ops_tenant: Option<&str>) -> broker::LabelSelector {
+    let mut include = labels::build_set([(labels::COLLECTION, collection)]);
+
+    if let Some(tenant) = ops_tenant {
+        include.labels.push(broker::Label {
+            name: format!("{}name", labels::FIELD_PREFIX),
+            value: labels::percent_encoding(&format!("{tenant}/")).to_string(),
+            prefix: true,
+        });
+        include.labels.sort_by(|l, r| l.name.cmp(&r.name));
+    }
+
+    broker::LabelSelector {
+        include: Some(include),
+        exclude: None,
+    }
+}
+
+/// TopicNames maps the topic names of a task's bindings onto the collections
+/// they read, so that consumers see stable topic names even as the collections
+/// of bindings are changed. Topics which aren't mapped name their collection.
+#[derive(Debug, Default, Clone)]
+pub struct TopicNames(BTreeMap<String, String>);
+
+/// Fetch the built specification of Dekaf materialization `task_name`,
+/// which must be visible to the user of `client`.
+pub async fn fetch_task_spec(
+    client: &flow_client::Client,
+    task_name: &str,
+) -> anyhow::Result<flow::MaterializationSpec> {
+    #[derive(serde::Deserialize)]
+    struct Row {
+        built_spec: Option<flow::MaterializationSpec>,
+    }
+
+    let rows: Vec<Row> = flow_client::api_exec(
+        client
+            .from("live_specs_ext")
+            .eq("spec_type", "materialization")
+            .eq("catalog_name", task_name)
+            .select("built_spec"),
+    )
+    .await
+    .context(format!("fetching built specification of {task_name}"))?;
+
+    let Some(spec) = rows.into_iter().next().and_then(|row| row.built_spec) else {
+        anyhow::bail!("task {task_name} does not exist");
+    };
+    if spec.connector_type != flow::materialization_spec::ConnectorType::Dekaf as i32 {
+        anyhow::bail!("task {task_name} is not a Dekaf materialization");
+    }
+    Ok(spec)
+}
+
+impl TopicNames {
+    /// Map the bindings of a built Dekaf materialization into their topic names.
+    pub fn from_spec(spec: &flow::MaterializationSpec) -> anyhow::Result<Self> {
+        let mut names = BTreeMap::new();
+
+        for binding in &spec.bindings {
+            let DekafResourceConfig { topic_name } =
+                serde_json::from_str(&binding.resource_config_json)
+                    .context("parsing binding resource config")?;
+            let collection = binding
+                .collection
+                .as_ref()
+                .context("binding is missing its collection")?;
+
+            if topic_name == collection.name {
+                continue; // Not renamed.
+            }
+            if let Some(prior) = names.insert(topic_name.clone(), collection.name.clone()) {
+                anyhow::bail!(
+                    "topic {topic_name} names both collections {prior} and {}",
+                    collection.name
+                );
+            }
+        }
+        Ok(Self(names))
+    }
+
+    /// Map `topic` into the collection which it names.
+    pub fn collection<'s>(&'s self, topic: &'s str) -> &'s str {
+        self.0.get(topic).map(String::as_str).unwrap_or(topic)
+    }
+
+    /// Map collection names into the topic names under which they're exposed.
+    pub fn topics(&self, collections: Vec<String>) -> Vec<String> {
+        let renamed: BTreeSet<&str> = self.0.values().map(String::as_str).collect();
+
+        collections
+            .into_iter()
+            .filter(|collection| !renamed.contains(collection.as_str()))
+            .chain(self.0.keys().cloned())
+            .collect()
+    }
+}
+
+/// Resolve the union topic of `config` named `topic`, if there is one.
+pub fn union_topic<'c>(config: &'c DekafConfig, topic: &str) -> Option<&'c DekafUnionTopic> {
+    config
+        .union_topics
+        .iter()
+        .find(|union| union.topic_name == topic)
+}
+
+/// Map partition `index` of a union topic which reserves `stride` partitions
+/// for each member collection into the index of its member collection and that
+/// member's partition index. Each member occupies a fixed range of partitions,
+/// so that partition assignments remain stable as members gain partitions
+/// (up to `stride`) or as further members are appended.
+pub fn union_partition(stride: usize, index: usize) -> (usize, usize) {
+    (index / stride, index % stride)
+}
+
+/// A partition of a union topic, resolved to its member collection.
+pub enum UnionPartition {
+    /// Partition `.1` of member collection `.0`.
+    Member(Collection, usize),
+    /// An empty placeholder partition beyond the partitions of its member.
+    Placeholder,
+    /// The partition is out of range, or its member collection doesn't exist.
+    Missing,
+}
+
+/// UnionTopic is a virtual topic which merges the partitions of several collections.
+/// Each member is assigned a fixed range of partitions, and is padded with empty
+/// placeholder partitions to the size of that range.
+pub struct UnionTopic {
+    pub members: Vec<Collection>,
+    /// Partitions reserved for each member, or None if this UnionTopic
+    /// wraps a single plain collection and maps its partitions directly.
+    stride: Option<usize>,
+}
+
+impl UnionTopic {
+    /// Build a UnionTopic of the collections of `union`, or None if any of them do not exist.
+    pub async fn new(
+        client: &flow_client::Client,
+        task_name: Option<&str>,
+        union: &DekafUnionTopic,
+        deletion_mode: DeletionMode,
+    ) -> anyhow::Result<Option<Self>> {
+        if union.collections.is_empty() {
+            return Ok(None);
+        }
+        // Members are named by collection, and not by topic.
+        let topic_names = TopicNames::default();
+        let stride = union.partitions_per_collection.max(1) as usize;
+
+        let members = futures::future::try_join_all(union.collections.iter().map(|collection| {
+            Collection::new(client, task_name, &topic_names, collection, deletion_mode)
+        }))
+        .await?;
+
+        let Some(members) = members.into_iter().collect::<Option<Vec<_>>>() else {
+            return Ok(None);
+        };
+        for member in &members {
+            check_union_stride(union, member, stride)?;
+        }
+
+        Ok(Some(Self {
+            members,
+            stride: Some(stride),
+        }))
+    }
+
+    /// Resolve partition `index` of `union`, fetching only its member collection.
+    pub async fn fetch_partition(
+        client: &flow_client::Client,
+        task_name: Option<&str>,
+        union: &DekafUnionTopic,
+        index: usize,
+        deletion_mode: DeletionMode,
+    ) -> anyhow::Result<UnionPartition> {
+        let stride = union.partitions_per_collection.max(1) as usize;
+        let (member, member_index) = union_partition(stride, index);
+
+        let Some(collection) = union.collections.get(member) else {
+            return Ok(UnionPartition::Missing);
+        };
+        let topic_names = TopicNames::default();
+
+        let Some(collection) =
+            Collection::new(client, task_name, &topic_names, collection, deletion_mode).await?
+        else {
+            return Ok(UnionPartition::Missing);
+        };
+        check_union_stride(union, &collection, stride)?;
+
+        Ok(if member_index < collection.partitions.len() {
+            UnionPartition::Member(collection, member_index)
+        } else {
+            UnionPartition::Placeholder
+        })
+    }
+
+    /// Resolve `topic` into a union topic of `config` or, if it's not one,
+    /// into its plain collection as a UnionTopic having a single member.
+    pub async fn resolve(
+        client: &flow_client::Client,
+        task_name: Option<&str>,
+        topic_names: &TopicNames,
+        config: &DekafConfig,
+        topic: &str,
+    ) -> anyhow::Result<Option<Self>> {
+        if let Some(union) = union_topic(config, topic) {
+            return Self::new(client, task_name, union, config.deletions).await;
+        }
+        let maybe_collection =
+            Collection::new(client, task_name, topic_names, topic, config.deletions).await?;
+
+        Ok(maybe_collection.map(|collection| Self {
+            members: vec![collection],
+            stride: None,
+        }))
+    }
+
+    pub fn partition_count(&self) -> usize {
+        match self.stride {
+            Some(stride) => self.members.len() * stride,
+            None => self.members[0].partitions.len(),
+        }
+    }
+
+    /// Returns true if partition `index` is an empty placeholder of a member
+    /// having fewer partitions than are reserved for it.
+    pub fn is_placeholder(&self, index: usize) -> bool {
+        let (member, member_index) = self.locate(index);
+        index < self.partition_count() && member_index >= self.members[member].partitions.len()
+    }
+
+    /// Map partition `index` into its member Collection and partition index.
+    pub fn member(&self, index: usize) -> (&Collection, usize) {
+        let (member, member_index) = self.locate(index);
+        (&self.members[member], member_index)
+    }
+
+    fn locate(&self, index: usize) -> (usize, usize) {
+        match self.stride {
+            Some(stride) if index < self.partition_count() => union_partition(stride, index),
+            // Out-of-range partitions map past the end of the last member.
+            Some(stride) => (
+                self.members.len() - 1,
+                index - (self.members.len() - 1) * stride,
+            ),
+            None => (0, index),
+        }
+    }
+
+    /// Name of the journal of partition `index`, or None if it's a
+    /// placeholder or doesn't exist.
+    pub fn journal_name(&self, index: usize) -> Option<&str> {
+        if self.is_placeholder(index) {
+            return None;
+        }
+        let (collection, member_index) = self.member(index);
+        collection
+            .partitions
+            .get(member_index)
+            .map(|partition| partition.spec.name.as_str())
+    }
+
+    /// Map a partition and timestamp into the newest covering fragment offset.
+    /// Placeholder partitions are always empty.
+    pub async fn fetch_partition_offset(
+        &self,
+        index: usize,
+        timestamp_millis: i64,
+    ) -> anyhow::Result<Option<PartitionOffset>> {
+        if self.is_placeholder(index) {
+            return Ok(Some(PartitionOffset::default()));
+        }
+        let (collection, member_index) = self.member(index);
+        collection
+            .fetch_partition_offset(member_index, timestamp_millis)
+            .await
+    }
+
+    /// Estimate the size of partition `index`, in bytes.
+    /// Placeholder partitions are always empty.
+    pub async fn fetch_partition_size(&self, index: usize) -> anyhow::Result<Option<i64>> {
+        if self.is_placeholder(index) {
+            return Ok(Some(0));
+        }
+        let (collection, member_index) = self.member(index);
+        collection.fetch_partition_size(member_index).await
+    }
+}
+
+/// Error if `member` of `union` has more partitions than are reserved for it,
+/// as its partitions beyond the reservation would be unreachable.
+fn check_union_stride(
+    union: &DekafUnionTopic,
+    member: &Collection,
+    stride: usize,
+) -> anyhow::Result<()> {
+    if member.partitions.len() > stride {
+        anyhow::bail!(
+            "collection {} of union topic {} has {} partitions, but only {stride} are reserved for it (partitions_per_collection)",
+            member.spec.name,
+            union.topic_name,
+            member.partitions.len(),
+        );
+    }
+    Ok(())
+}
+
 /// Collection is the assembled metadata of a collection being accessed as a Kafka topic.
 pub struct Collection {
     pub journal_client: journal::Client,
@@ -34,6 +379,9 @@ pub struct Collection {
     pub key_schema: avro::Schema,
     pub not_before: uuid::Clock,
     pub partitions: Vec<Partition>,
+    /// Top-level reduction strategy of the collection, as compact JSON,
+    /// or None if multiple strategies may apply.
+    pub reduce_strategy: Option<String>,
     pub spec: flow::CollectionSpec,
     pub uuid_ptr: doc::Pointer,
     pub value_schema: avro::Schema,
@@ -49,6 +397,19 @@ pub struct Partition {
     pub route: broker::Route,
 }
 
+/// Maximum number of bytes which are scanned to map a timestamp into the
+/// offset of its first document, bounding the cost of a ListOffsets request.
+const MAX_OFFSET_SCAN_BYTES: i64 = 1 << 26; // 64MB.
+
+/// Number of fragments which are listed per request when estimating partition sizes.
+const FRAGMENTS_PAGE_LIMIT: i32 = 1_000;
+
+/// Map a document UUID Clock into its Kafka record timestamp, in millis.
+fn clock_millis(clock: uuid::Clock) -> i64 {
+    let (seconds, nanos) = clock.to_unix();
+    seconds as i64 * 1_000 + nanos as i64 / 1_000_000
+}
+
 #[derive(Clone, Copy, Default, Debug)]
 pub struct PartitionOffset {
     pub fragment_start: i64,
@@ -58,19 +419,37 @@ pub struct PartitionOffset {
 
 impl Collection {
     /// Build a Collection by fetching its spec, a authenticated data-plane access token, and its partitions.
+    /// `task_name` is the authenticated task, if any, which scopes access to ops topics,
+    /// and `topic_names` maps the topic names of its bindings into collections.
     pub async fn new(
         client: &flow_client::Client,
-        collection: &str,
+        task_name: Option<&str>,
+        topic_names: &TopicNames,
+        topic: &str,
         deletion_mode: DeletionMode,
     ) -> anyhow::Result<Option<Self>> {
         let not_before = uuid::Clock::default();
         let pg_client = client.pg_client();
+        let topic = topic_names.collection(topic);
+
+        // Ops topics are served from the ops collection of the task's data-plane.
+        let (collection, ops_tenant) = match parse_ops_topic(topic) {
+            Some((tenant, kind)) => {
+                let collection = Self::resolve_ops_collection(client, task_name, tenant, kind)
+                    .await
+                    .context(format!("resolving ops topic {topic}"))?;
+                (collection, Some(tenant))
+            }
+            None => (topic.to_string(), None),
+        };
+        let collection = collection.as_str();
 
         // Build a journal client and use it to fetch partitions while concurrently
         // fetching the collection's metadata from the control plane.
         let client_partitions = async {
             let journal_client = Self::build_journal_client(&client, collection).await?;
-            let partitions = Self::fetch_partitions(&journal_client, collection).await?;
+            let partitions =
+                Self::fetch_partitions(&journal_client, collection, ops_tenant).await?;
             Ok((journal_client, partitions))
         };
         let (spec, client_partitions): (anyhow::Result<_>, anyhow::Result<_>) =
@@ -129,6 +508,14 @@ impl Collection {
             }
         }
 
+        let reduce_strategy = match &shape.reduction {
+            doc::shape::Reduction::Strategy(strategy) => {
+                Some(serde_json::to_string(strategy).unwrap())
+            }
+            // Documents without an annotated strategy are reduced with lastWriteWins.
+            doc::shape::Reduction::Unset => Some(r#"{"strategy":"lastWriteWins"}"#.to_string()),
+            doc::shape::Reduction::Multiple => None,
+        };
         let (key_schema, value_schema) = avro::shape_to_avro(shape, &key_ptr);
 
         tracing::debug!(
@@ -143,6 +530,7 @@ impl Collection {
             key_schema,
             not_before,
             partitions,
+            reduce_strategy,
             spec,
             uuid_ptr,
             value_schema,
@@ -151,14 +539,15 @@ impl Collection {
 
     /// Map the collection's key and value Avro schema into globally unique registry IDs.
     /// This will content-address each schema to fetch a current registry ID if one is available,
-    /// or will register a new schema if not.
+    /// or will register a new schema if not. Resolved IDs are cached in `schema_ids`.
     pub async fn registered_schema_ids(
         &self,
         client: &postgrest::Postgrest,
+        schema_ids: &SchemaIds,
     ) -> anyhow::Result<(u32, u32)> {
         let (key_id, value_id) = futures::try_join!(
-            Self::registered_schema_id(client, &self.spec.name, &self.key_schema),
-            Self::registered_schema_id(client, &self.spec.name, &self.value_schema),
+            Self::registered_schema_id(client, schema_ids, &self.spec.name, &self.key_schema),
+            Self::registered_schema_id(client, schema_ids, &self.spec.name, &self.value_schema),
         )?;
         Ok((key_id, value_id))
     }
@@ -192,16 +581,43 @@ impl Collection {
         }
     }
 
+    /// Map an ops topic of `tenant` into the ops collection of the authenticated task's data-plane.
+    /// Only tasks of the tenant may read its ops topics.
+    async fn resolve_ops_collection(
+        client: &flow_client::Client,
+        task_name: Option<&str>,
+        tenant: &str,
+        kind: OpsKind,
+    ) -> anyhow::Result<String> {
+        let Some(task_name) = task_name else {
+            anyhow::bail!("ops topics may only be read by an authenticated task");
+        };
+        if task_name.split_once('/').map(|(t, _)| t) != Some(tenant) {
+            anyhow::bail!("task {task_name} is not authorized to read ops of tenant {tenant}");
+        }
+
+        let (_, ops_logs_journal, ops_stats_journal, _, _) =
+            flow_client::fetch_user_task_authorization(client, task_name).await?;
+
+        let journal = match kind {
+            OpsKind::Logs => ops_logs_journal,
+            OpsKind::Stats => ops_stats_journal,
+        };
+        // Ops journals are named like `{collection}/kind={kind}/name={name}/pivot=00`.
+        let Some(collection) = activate::ops_journal_template_name(&journal) else {
+            anyhow::bail!("unexpected ops journal name {journal}");
+        };
+        Ok(collection.to_string())
+    }
+
     /// Fetch the journals of a collection and map into stable-order partitions.
     async fn fetch_partitions(
         journal_client: &journal::Client,
         collection: &str,
+        ops_tenant: Option<&str>,
     ) -> anyhow::Result<Vec<Partition>> {
         let request = broker::ListRequest {
-            selector: Some(broker::LabelSelector {
-                include: Some(labels::build_set([(labels::COLLECTION, collection)])),
-                exclude: None,
-            }),
+            selector: Some(partition_selector(collection, ops_tenant)),
             ..Default::default()
         };
         let response = journal_client
@@ -229,7 +645,10 @@ impl Collection {
         Ok(partitions)
     }
 
-    /// Map a partition and timestamp into the newest covering fragment offset.
+    /// Map a partition and timestamp into an offset. The "latest" and "earliest"
+    /// sentinels map to the newest and oldest covering fragment offsets, and
+    /// other timestamps map to the offset of the first document published at
+    /// or after the timestamp.
     pub async fn fetch_partition_offset(
         &self,
         partition_index: usize,
@@ -272,12 +691,23 @@ impl Collection {
                         offset: spec.end - 1,
                         mod_time: spec.mod_time,
                     }
-                } else {
+                } else if timestamp_millis == -2 {
                     PartitionOffset {
                         fragment_start: spec.begin,
                         offset: spec.begin,
                         mod_time: spec.mod_time,
                     }
+                } else {
+                    // Fragments which precede the covering fragment were persisted
+                    // before `timestamp_millis`, and hold only earlier documents.
+                    // The covering fragment may hold earlier documents as well,
+                    // so scan forward from its beginning.
+                    self.scan_partition_offset(
+                        partition,
+                        spec.begin,
+                        timestamp_millis.max(not_before_sec as i64 * 1_000),
+                    )
+                    .await?
                 }
             }
             _ => PartitionOffset::default(),
@@ -294,6 +724,97 @@ impl Collection {
         Ok(Some(offset_data))
     }
 
+    /// Estimate the size of a partition, in bytes, from a listing of its
+    /// persisted and open fragments. Overlapping fragments are counted once.
+    pub async fn fetch_partition_size(
+        &self,
+        partition_index: usize,
+    ) -> anyhow::Result<Option<i64>> {
+        let Some(partition) = self.partitions.get(partition_index) else {
+            return Ok(None);
+        };
+        let mut request = broker::FragmentsRequest {
+            journal: partition.spec.name.clone(),
+            page_limit: FRAGMENTS_PAGE_LIMIT,
+            ..Default::default()
+        };
+        let (mut size, mut covered) = (0, 0);
+
+        loop {
+            let response = self.journal_client.list_fragments(request.clone()).await?;
+
+            // Fragments are listed in order of their begin offset.
+            for spec in response.fragments.iter().filter_map(|f| f.spec.as_ref()) {
+                size += (spec.end - spec.begin.max(covered)).max(0);
+                covered = covered.max(spec.end);
+            }
+            if response.next_page_token == 0 {
+                break;
+            }
+            request.next_page_token = response.next_page_token;
+        }
+
+        Ok(Some(size))
+    }
+
+    /// Scan `partition` from the document boundary `fragment_start` for the
+    /// first document published at or after `timestamp_millis`, and map it
+    /// into a PartitionOffset. If no such document is found before the write
+    /// head or MAX_OFFSET_SCAN_BYTES, then the offset reached is returned.
+    async fn scan_partition_offset(
+        &self,
+        partition: &Partition,
+        fragment_start: i64,
+        timestamp_millis: i64,
+    ) -> anyhow::Result<PartitionOffset> {
+        let mut lines = self.journal_client.clone().read_json_lines(
+            broker::ReadRequest {
+                offset: fragment_start,
+                block: false,
+                journal: partition.spec.name.clone(),
+                ..Default::default()
+            },
+            0,
+        );
+        // Begin offset of the next document.
+        let mut offset = fragment_start;
+
+        while offset - fragment_start < MAX_OFFSET_SCAN_BYTES {
+            let (root, next_offset) = match lines.next().await {
+                None => break, // Read through the write head.
+                Some(Ok(journal::ReadJsonLine::Meta(_))) => continue,
+                Some(Ok(journal::ReadJsonLine::Doc { root, next_offset })) => (root, next_offset),
+                Some(Err(gazette::RetryError { attempt, inner }))
+                    if inner.is_transient() && attempt < 5 =>
+                {
+                    continue;
+                }
+                Some(Err(gazette::RetryError { inner, .. })) => {
+                    return Err(inner).context("scanning for timestamp offset");
+                }
+            };
+
+            if let Some(doc::ArchivedNode::String(uuid)) = self.uuid_ptr.query(root.get()) {
+                let (_, clock, _) = uuid::parse_str(uuid.as_str())?;
+
+                if clock_millis(clock) >= timestamp_millis {
+                    return Ok(PartitionOffset {
+                        fragment_start,
+                        offset,
+                        mod_time: clock.to_unix().0 as i64,
+                    });
+                }
+            }
+            offset = next_offset;
+        }
+
+        Ok(PartitionOffset {
+            fragment_start,
+            offset,
+            mod_time: timestamp_millis / 1_000,
+        })
+    }
+
     /// Build a journal client by resolving the collections data-plane gateway and an access token.
     async fn build_journal_client(
         client: &flow_client::Client,
@@ -308,6 +829,7 @@ impl Collection {
 
     async fn registered_schema_id(
         client: &postgrest::Postgrest,
+        schema_ids: &SchemaIds,
         catalog_name: &str,
         schema: &avro::Schema,
     ) -> anyhow::Result<u32> {
@@ -323,10 +845,22 @@ impl Collection {
         let schema: serde_json::Value = serde_json::from_str(&schema.canonical_form()).unwrap();
         let schema_md5 = format!("{:x}", md5::compute(&schema.to_string()));
 
-        let mut rows: Vec<Row> = client
-            .from("registered_avro_schemas")
-            .eq("avro_schema_md5", &schema_md5)
-            .select("registry_id")
+        if let Some(registry_id) = schema_ids.get(catalog_name, &schema_md5) {
+            return Ok(registry_id);
+        }
+
+        // Concurrent registrations of a schema may have assigned it multiple IDs.
+        // The least ID is always used, so that all Dekaf replicas agree.
+        let query = || {
+            client
+                .from("registered_avro_schemas")
+                .eq("avro_schema_md5", &schema_md5)
+                .select("registry_id")
+                .order("registry_id.asc")
+                .limit(1)
+        };
+
+        let mut rows: Vec<Row> = query()
             .execute()
             .await
             .and_then(|r| r.error_for_status())
@@ -335,10 +869,11 @@ impl Collection {
             .await?;
 
         if let Some(Row { registry_id }) = rows.pop() {
+            schema_ids.insert(catalog_name, &schema_md5, registry_id);
             return Ok(registry_id);
         }
 
-        let mut rows: Vec<Row> = client
+        client
             .from("registered_avro_schemas")
             .insert(
                 serde_json::json!([{
@@ -350,13 +885,235 @@ impl Collection {
             .execute()
             .await
             .and_then(|r| r.error_for_status())
-            .context("inserting new registered schema")?
+            .context("inserting new registered schema")?;
+
+        // Re-query, as a concurrent registration may have assigned a lesser ID.
+        let mut rows: Vec<Row> = query()
+            .execute()
+            .await
+            .and_then(|r| r.error_for_status())
+            .context("querying for the newly-registered schema")?
             .json()
             .await?;
 
-        let registry_id = rows.pop().unwrap().registry_id;
+        let registry_id = rows
+            .pop()
+            .context("newly-registered schema was not found")?
+            .registry_id;
         tracing::info!(schema_md5, registry_id, "registered new Avro schema");
 
+        schema_ids.insert(catalog_name, &schema_md5, registry_id);
         Ok(registry_id)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ops_topics() {
+        assert_eq!(
+            parse_ops_topic("ops/acmeCo/logs"),
+            Some(("acmeCo", OpsKind::Logs))
+        );
+        assert_eq!(
+            parse_ops_topic("ops/acmeCo/stats"),
+            Some(("acmeCo", OpsKind::Stats))
+        );
+        for topic in [
+            "ops/acmeCo/other",
+            "ops//logs",
+            "ops/dp/public/logs",
+            "acmeCo/logs",
+        ] {
+            assert_eq!(parse_ops_topic(topic), None, "{topic}");
+        }
+
+        assert_eq!(
+            ops_topic_names("acmeCo/nested/materialize"),
+            vec!["ops/acmeCo/logs", "ops/acmeCo/stats"]
+        );
+
+        let selector = partition_selector("ops.us-central1.v1/logs", Some("acmeCo"));
+        let include = selector.include.unwrap();
+        assert_eq!(
+            include
+                .labels
+                .iter()
+                .map(|l| (l.name.as_str(), l.value.as_str(), l.prefix))
+                .collect::<Vec<_>>(),
+            vec![
+                (labels::COLLECTION, "ops.us-central1.v1/logs", false),
+                ("estuary.dev/field/name", "acmeCo%2F", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clock_millis() {
+        let clock = uuid::Clock::from_unix(1_700_000_123, 456_789_000);
+        assert_eq!(clock_millis(clock), 1_700_000_123_456);
+        assert_eq!(clock_millis(uuid::Clock::from_unix(0, 999_999)), 0);
+    }
+
+    #[test]
+    fn test_union_partitions() {
+        // Members occupy fixed ranges of `stride` partitions.
+        let mapped: Vec<_> = (0..7).map(|index| union_partition(3, index)).collect();
+        assert_eq!(
+            mapped,
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2), (2, 0)]
+        );
+        assert_eq!(union_partition(4, 9), (2, 1));
+    }
+
+    fn union_member(name: &str, partitions: usize) -> Collection {
+        let journal_client = journal::Client::new(
+            "http://localhost".to_string(),
+            gazette::Metadata::default(),
+            gazette::Router::new("local"),
+        );
+        Collection {
+            journal_client,
+            key_ptr: Vec::new(),
+            key_schema: avro::Schema::Null,
+            not_before: uuid::Clock::default(),
+            partitions: (0..partitions)
+                .map(|index| Partition {
+                    create_revision: 1,
+                    spec: broker::JournalSpec {
+                        name: format!("{name}/pivot={index:02}"),
+                        ..Default::default()
+                    },
+                    mod_revision: 1,
+                    route: Default::default(),
+                })
+                .collect(),
+            reduce_strategy: String::new(),
+            spec: flow::CollectionSpec {
+                name: name.to_string(),
+                ..Default::default()
+            },
+            uuid_ptr: doc::Pointer::from_str("/_meta/uuid"),
+            value_schema: avro::Schema::Null,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_union_partitions_across_append() {
+        let layout = |union: &UnionTopic| -> Vec<Option<String>> {
+            (0..union.partition_count())
+                .map(|index| union.journal_name(index).map(str::to_string))
+                .collect()
+        };
+        let before = UnionTopic {
+            members: vec![union_member("acmeCo/a", 2), union_member("acmeCo/b", 1)],
+            stride: Some(3),
+        };
+        let after = UnionTopic {
+            members: vec![
+                union_member("acmeCo/a", 3),
+                union_member("acmeCo/b", 1),
+                union_member("acmeCo/c", 2),
+            ],
+            stride: Some(3),
+        };
+        let (before, after) = (layout(&before), layout(&after));
+
+        let journal = |name: &str| Some(name.to_string());
+        assert_eq!(
+            before,
+            vec![
+                journal("acmeCo/a/pivot=00"),
+                journal("acmeCo/a/pivot=01"),
+                None,
+                journal("acmeCo/b/pivot=00"),
+                None,
+                None,
+            ]
+        );
+        // Appending a member, and growing the partitions of another, doesn't
+        // re-map any partition which was already assigned a journal, so their
+        // committed offsets continue to refer to the same journals.
+        for (index, name) in before.iter().enumerate() {
+            if name.is_some() {
+                assert_eq!(&after[index], name, "partition {index}");
+            }
+        }
+        assert_eq!(after[2], journal("acmeCo/a/pivot=02"));
+        assert_eq!(
+            &after[6..],
+            &[
+                journal("acmeCo/c/pivot=00"),
+                journal("acmeCo/c/pivot=01"),
+                None
+            ]
+        );
+
+        // Placeholder partitions are empty, and are resolved without a fetch.
+        let union = UnionTopic {
+            members: vec![union_member("acmeCo/a", 1), union_member("acmeCo/b", 2)],
+            stride: Some(3),
+        };
+        assert!(union.is_placeholder(2));
+        assert!(!union.is_placeholder(4));
+        assert!(!union.is_placeholder(6)); // Out of range.
+        assert!(matches!(
+            union.fetch_partition_offset(5, -1).await.unwrap(),
+            Some(PartitionOffset { offset: 0, .. })
+        ));
+        assert_eq!(union.fetch_partition_size(1).await.unwrap(), Some(0));
+
+        // A member may not have more partitions than are reserved for it.
+        let config = DekafUnionTopic {
+            topic_name: "merged".to_string(),
+            collections: vec!["acmeCo/a".to_string()],
+            partitions_per_collection: 3,
+        };
+        assert_eq!(
+            check_union_stride(&config, &union_member("acmeCo/a", 4), 3)
+                .unwrap_err()
+                .to_string(),
+            "collection acmeCo/a of union topic merged has 4 partitions, but only 3 are reserved for it (partitions_per_collection)"
+        );
+    }
+
+    #[test]
+    fn test_topic_names() {
+        let binding = |topic: &str, collection: &str| flow::materialization_spec::Binding {
+            resource_config_json: serde_json::json!({ "topic_name": topic }).to_string(),
+            collection: Some(flow::CollectionSpec {
+                name: collection.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut spec = flow::MaterializationSpec {
+            bindings: vec![
+                binding("events", "acmeCo/events/v2"),
+                binding("acmeCo/orders", "acmeCo/orders"),
+            ],
+            ..Default::default()
+        };
+        let names = TopicNames::from_spec(&spec).unwrap();
+
+        assert_eq!(names.collection("events"), "acmeCo/events/v2");
+        assert_eq!(names.collection("acmeCo/orders"), "acmeCo/orders");
+        assert_eq!(names.collection("acmeCo/other"), "acmeCo/other");
+        assert_eq!(
+            names.topics(vec![
+                "acmeCo/events/v2".to_string(),
+                "acmeCo/orders".to_string(),
+                "acmeCo/other".to_string(),
+            ]),
+            vec!["acmeCo/orders", "acmeCo/other", "events"],
+        );
+
+        spec.bindings.push(binding("events", "acmeCo/events/v1"));
+        assert_eq!(
+            TopicNames::from_spec(&spec).unwrap_err().to_string(),
+            "topic events names both collections acmeCo/events/v2 and acmeCo/events/v1"
+        );
+    }
+}
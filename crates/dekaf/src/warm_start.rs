@@ -0,0 +1,350 @@
+use crate::{create_crypto, topology::Collection, App};
+use aes_siv::aead::Aead;
+use anyhow::Context;
+use flow_client::client::RefreshToken;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Tasks which haven't been active for this long are forgotten.
+const RECENT_TASK_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+/// Prefetched collections which aren't taken within this long are discarded,
+/// as their data-plane authorizations and partition listings become stale.
+const PREFETCH_TTL: Duration = Duration::from_secs(2 * 60);
+
+/// WarmStart smooths fetch latency after a restart. It remembers the topics
+/// read by recently active tasks, persisting them across restarts, and
+/// prefetches their collection specs and partition listings at startup.
+/// As Dekaf holds no credentials of its own, each task is remembered with
+/// the refresh token of its latest PLAIN session, encrypted with the Dekaf
+/// encryption secret, and is prefetched under that token. Tasks which have
+/// only authenticated with bearer tokens are remembered but not prefetched.
+pub struct WarmStart {
+    /// File into which recent tasks are persisted, if any.
+    path: Option<PathBuf>,
+    /// Recently active tasks, and the topics they read.
+    recent: Mutex<BTreeMap<String, RecentTask>>,
+    /// Prefetched collections, keyed on task and topic, awaiting their first fetch.
+    prefetched: Mutex<HashMap<(String, String), (Instant, Collection)>>,
+    /// Bounds the concurrency of prefetches across all tasks.
+    permits: tokio::sync::Semaphore,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecentTask {
+    /// Unix timestamp, in seconds, at which the task last authenticated.
+    last_active: u64,
+    /// Topics which were read by the task.
+    topics: BTreeSet<String>,
+    /// Sealed refresh token of the task's latest PLAIN session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    credential: Option<String>,
+}
+
+impl WarmStart {
+    /// Build a WarmStart which persists into `path`, loading the recent tasks
+    /// it holds, and which runs at most `concurrency` prefetches at once.
+    pub fn load(path: Option<PathBuf>, concurrency: usize) -> Self {
+        let mut recent = match &path {
+            Some(path) => match Self::read(path) {
+                Ok(recent) => recent,
+                Err(err) => {
+                    tracing::warn!(?err, ?path, "failed to load recent tasks for warm start");
+                    BTreeMap::new()
+                }
+            },
+            None => BTreeMap::new(),
+        };
+        expire_idle(&mut recent);
+
+        Self {
+            path,
+            recent: Mutex::new(recent),
+            prefetched: Default::default(),
+            permits: tokio::sync::Semaphore::new(concurrency.max(1)),
+        }
+    }
+
+    fn read(path: &std::path::Path) -> anyhow::Result<BTreeMap<String, RecentTask>> {
+        match std::fs::read(path) {
+            Ok(content) => serde_json::from_slice(&content).context("parsing recent tasks"),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(err) => Err(err).context("reading recent tasks"),
+        }
+    }
+
+    /// Note that `task_name` has authenticated, with a `refresh` token if the
+    /// session is PLAIN. The token is sealed with `secret` for persistence.
+    /// Clients re-authenticate as their sessions expire, which keeps
+    /// long-lived tasks active.
+    pub fn touch(&self, task_name: &str, refresh: Option<&RefreshToken>, secret: &str) {
+        let credential = refresh.map(|refresh| seal_credential(secret, task_name, refresh));
+
+        let mut recent = self.recent.lock().unwrap();
+        let task = recent.entry(task_name.to_string()).or_default();
+
+        task.last_active = unix_now();
+        if credential.is_some() {
+            task.credential = credential;
+        }
+    }
+
+    /// Note that `task_name` has begun to read `topic`. Sessions call this
+    /// only for topics they haven't already recorded.
+    pub fn record(&self, task_name: &str, topic: &str) {
+        let mut recent = self.recent.lock().unwrap();
+
+        if let Some(task) = recent.get_mut(task_name) {
+            if !task.topics.contains(topic) {
+                task.topics.insert(topic.to_string());
+            }
+        }
+    }
+
+    /// Persist recently active tasks, if a file was configured.
+    /// Tasks which have been idle for too long are expired.
+    pub fn persist(&self) -> anyhow::Result<()> {
+        self.prefetched
+            .lock()
+            .unwrap()
+            .retain(|_, (fetched_at, _)| fetched_at.elapsed() < PREFETCH_TTL);
+
+        let content = {
+            let mut recent = self.recent.lock().unwrap();
+            expire_idle(&mut recent);
+            serde_json::to_vec(&*recent)?
+        };
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        // Write and then rename, so that a crash never leaves a partial file.
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, content).context("writing recent tasks")?;
+        std::fs::rename(&tmp, path).context("renaming recent tasks")?;
+
+        Ok(())
+    }
+
+    /// Periodically persist recently active tasks.
+    pub async fn serve_persistence(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            if let Err(err) = self.persist() {
+                tracing::warn!(?err, "failed to persist recent tasks for warm start");
+            }
+        }
+    }
+
+    /// Take the prefetched Collection of `topic` of `task_name`, if there is
+    /// one and it's not stale. Each prefetched Collection is taken only once,
+    /// after which topics are resolved as usual.
+    pub fn take(&self, task_name: &str, topic: &str) -> Option<Collection> {
+        let mut prefetched = self.prefetched.lock().unwrap();
+
+        if prefetched.is_empty() {
+            return None; // Fast path.
+        }
+        prefetched.retain(|_, (fetched_at, _)| fetched_at.elapsed() < PREFETCH_TTL);
+
+        let (_, collection) = prefetched.remove(&(task_name.to_string(), topic.to_string()))?;
+        metrics::counter!("dekaf_warm_start_prefetch_hits").increment(1);
+        Some(collection)
+    }
+}
+
+/// Prefetch the remembered topics of each recent task of `app` which has a
+/// credential, as loaded at startup. Each topic is resolved into a Collection
+/// which is held for the task's first fetch of the topic.
+pub async fn prefetch_recent(app: &App) {
+    let tasks: Vec<(String, String, BTreeSet<String>)> = app
+        .warm_start
+        .recent
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|(name, task)| {
+            let credential = task.credential.clone()?;
+            Some((name.clone(), credential, task.topics.clone()))
+        })
+        .collect();
+
+    let started_at = Instant::now();
+
+    futures::future::join_all(
+        tasks
+            .iter()
+            .map(|(task_name, credential, topics)| async move {
+                if let Err(err) = prefetch_task(app, task_name, credential, topics).await {
+                    tracing::debug!(?err, task_name, "failed to prefetch recent task");
+                }
+            }),
+    )
+    .await;
+
+    metrics::histogram!("dekaf_warm_start_prefetch_seconds")
+        .record(started_at.elapsed().as_secs_f64());
+    tracing::info!(
+        tasks = tasks.len(),
+        elapsed = ?started_at.elapsed(),
+        "prefetched topology of recently active tasks"
+    );
+}
+
+async fn prefetch_task(
+    app: &App,
+    task_name: &str,
+    credential: &str,
+    topics: &BTreeSet<String>,
+) -> anyhow::Result<()> {
+    let warm = &app.warm_start;
+    let auth = {
+        let _permit = warm.permits.acquire().await?;
+        let password = open_credential(&app.secret, task_name, credential)?;
+        app.authenticate(task_name, &password).await?
+    };
+
+    futures::future::join_all(topics.iter().map(|topic| async {
+        let Ok(_permit) = warm.permits.acquire().await else {
+            return;
+        };
+        match Collection::new(
+            &auth.client,
+            Some(task_name),
+            &auth.topic_names,
+            topic,
+            auth.task_config.deletions,
+        )
+        .await
+        {
+            Ok(Some(collection)) => {
+                warm.prefetched.lock().unwrap().insert(
+                    (task_name.to_string(), topic.clone()),
+                    (Instant::now(), collection),
+                );
+            }
+            Ok(None) => {}
+            Err(err) => tracing::debug!(?err, task_name, topic, "failed to prefetch topic"),
+        }
+    }))
+    .await;
+
+    Ok(())
+}
+
+fn expire_idle(recent: &mut BTreeMap<String, RecentTask>) {
+    let horizon = unix_now().saturating_sub(RECENT_TASK_WINDOW.as_secs());
+    recent.retain(|_, task| task.last_active >= horizon);
+}
+
+// Seal a refresh token into the hex-encoded PLAIN password which it's
+// presented as, encrypted with `secret` and bound to `task_name`.
+fn seal_credential(secret: &str, task_name: &str, refresh: &RefreshToken) -> String {
+    let password = base64::encode(serde_json::to_vec(refresh).unwrap());
+    let (cipher, nonce) = create_crypto(secret.to_string(), task_name.to_string());
+
+    hex::encode(cipher.encrypt(&nonce, password.as_bytes()).unwrap())
+}
+
+// Open a credential sealed by `seal_credential`.
+fn open_credential(secret: &str, task_name: &str, sealed: &str) -> anyhow::Result<String> {
+    let (cipher, nonce) = create_crypto(secret.to_string(), task_name.to_string());
+    let sealed = hex::decode(sealed).context("decoding sealed credential")?;
+    let password = cipher
+        .decrypt(&nonce, sealed.as_slice())
+        .map_err(|_| anyhow::anyhow!("failed to open sealed credential"))?;
+
+    Ok(String::from_utf8(password)?)
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_recent_tasks_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "dekaf-warm-start-{}-{}.json",
+            std::process::id(),
+            unix_now()
+        ));
+
+        // Seed a stale task, which is dropped upon loading.
+        let stale = RecentTask {
+            last_active: unix_now() - RECENT_TASK_WINDOW.as_secs() - 60,
+            topics: ["old/topic".to_string()].into(),
+            credential: None,
+        };
+        std::fs::write(
+            &path,
+            serde_json::to_vec(&BTreeMap::from([("acmeCo/stale".to_string(), stale)])).unwrap(),
+        )
+        .unwrap();
+
+        let warm = WarmStart::load(Some(path.clone()), 4);
+        assert!(warm.recent.lock().unwrap().is_empty());
+
+        let refresh = RefreshToken {
+            id: models::Id::new([1, 2, 3, 4, 5, 6, 7, 8]),
+            secret: "the-secret".to_string(),
+        };
+        warm.touch("acmeCo/task", Some(&refresh), "dekaf-secret");
+        warm.record("acmeCo/task", "acmeCo/one");
+        warm.record("acmeCo/task", "acmeCo/two");
+        warm.record("acmeCo/task", "acmeCo/one");
+        warm.touch("acmeCo/other", None, "dekaf-secret");
+        warm.record("acmeCo/other", "acmeCo/three");
+        // Topics of tasks which haven't authenticated aren't recorded.
+        warm.record("acmeCo/unknown", "acmeCo/four");
+
+        // A task which has gone idle is expired as recent tasks are persisted.
+        warm.touch("acmeCo/idle", None, "dekaf-secret");
+        warm.recent
+            .lock()
+            .unwrap()
+            .get_mut("acmeCo/idle")
+            .unwrap()
+            .last_active = 0;
+        warm.persist().unwrap();
+
+        let loaded = WarmStart::load(Some(path.clone()), 4);
+        let recent = loaded.recent.lock().unwrap();
+
+        assert_eq!(
+            recent.keys().collect::<Vec<_>>(),
+            vec!["acmeCo/other", "acmeCo/task"]
+        );
+        assert_eq!(
+            recent["acmeCo/task"].topics.iter().collect::<Vec<_>>(),
+            vec!["acmeCo/one", "acmeCo/two"]
+        );
+        assert_eq!(recent["acmeCo/other"].credential, None);
+        assert!(loaded.take("acmeCo/task", "acmeCo/one").is_none());
+
+        // The persisted credential opens into the PLAIN password of its refresh token,
+        // but only with the same secret and task.
+        let sealed = recent["acmeCo/task"].credential.as_deref().unwrap();
+        let password = open_credential("dekaf-secret", "acmeCo/task", sealed).unwrap();
+        let opened: RefreshToken =
+            serde_json::from_slice(&base64::decode(&password).unwrap()).unwrap();
+        assert_eq!((opened.id, opened.secret), (refresh.id, refresh.secret));
+
+        assert!(open_credential("other-secret", "acmeCo/task", sealed).is_err());
+        assert!(open_credential("dekaf-secret", "acmeCo/other", sealed).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
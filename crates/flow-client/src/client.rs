@@ -339,6 +339,17 @@ pub async fn fetch_user_task_authorization(
 pub async fn fetch_collection_authorization(
     client: &Client,
     collection: &str,
+) -> anyhow::Result<(String, gazette::journal::Client)> {
+    fetch_collection_authorization_with_capability(client, collection, None).await
+}
+
+/// Fetch an authorization to `collection` of the requested `capability`,
+/// where the Write capability authorizes appends to collection journals.
+#[tracing::instrument(skip(client), err)]
+pub async fn fetch_collection_authorization_with_capability(
+    client: &Client,
+    collection: &str,
+    capability: Option<models::Capability>,
 ) -> anyhow::Result<(String, gazette::journal::Client)> {
     let started_unix = std::time::SystemTime::now()
         .duration_since(std::time::SystemTime::UNIX_EPOCH)
@@ -357,6 +368,7 @@ pub async fn fetch_collection_authorization(
                 &models::authorizations::UserCollectionAuthorizationRequest {
                     started_unix,
                     collection: models::Collection::new(collection),
+                    capability,
                 },
             )
             .await?;
@@ -2,7 +2,8 @@ use anyhow::Context;
 
 pub mod client;
 pub use client::{
-    fetch_collection_authorization, fetch_task_authorization, fetch_user_task_authorization, Client,
+    fetch_collection_authorization, fetch_collection_authorization_with_capability,
+    fetch_task_authorization, fetch_user_task_authorization, Client,
 };
 
 pub mod pagination;
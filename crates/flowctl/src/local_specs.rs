@@ -1,4 +1,4 @@
-use futures::{FutureExt, TryStreamExt};
+use futures::FutureExt;
 use itertools::Itertools;
 use proto_flow::flow;
 use tables::CatalogResolver;
@@ -40,18 +40,21 @@ pub(crate) async fn generate_files(
     draft.errors = draft
         .errors
         .into_iter()
-        .filter_map(|tables::Error { scope, error }| {
-            match error.downcast_ref() {
-                // Skip load errors about missing resources. That's the point!
-                Some(sources::LoadError::Fetch { .. }) => None,
-                _ => Some(tables::Error { scope, error }),
-            }
+        .filter(|tables::Error { error, .. }| {
+            // Skip load errors about missing resources. That's the point!
+            !matches!(error.downcast_ref(), Some(sources::LoadError::Fetch { .. }))
         })
         .collect();
 
     if let Err(errors) = draft.into_result().and_then(|_| built.into_result()) {
-        for tables::Error { scope, error } in errors.iter() {
-            tracing::error!(%scope, ?error);
+        for tables::Error {
+            scope,
+            catalog_name,
+            error,
+            ..
+        } in errors.iter()
+        {
+            tracing::error!(%scope, %catalog_name, ?error);
         }
         tracing::error!(
             "I may not have generated all files because the Flow specifications have errors.",
@@ -119,6 +122,16 @@ async fn validate(
         tracing::debug!(db_path=%db_path.to_string_lossy(), "wrote debugging database");
     }
 
+    for tables::Error {
+        scope,
+        catalog_name,
+        error,
+        ..
+    } in output.built.warnings.iter()
+    {
+        tracing::warn!(%scope, %catalog_name, ?error);
+    }
+
     let (draft, _live, built) = output.into_parts();
     (draft, built)
 }
@@ -126,8 +139,14 @@ async fn validate(
 pub(crate) fn surface_errors<T>(result: Result<T, tables::Errors>) -> anyhow::Result<T> {
     match result {
         Err(errors) => {
-            for tables::Error { scope, error } in errors.iter() {
-                tracing::error!(%scope, ?error);
+            for tables::Error {
+                scope,
+                catalog_name,
+                error,
+                ..
+            } in errors.iter()
+            {
+                tracing::error!(%scope, %catalog_name, ?error);
             }
             Err(anyhow::anyhow!("failed due to encountered errors"))
         }
@@ -202,24 +221,40 @@ impl tables::CatalogResolver for Resolver {
     fn resolve<'a>(
         &'a self,
         catalog_names: Vec<&'a str>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = tables::LiveCatalog> + Send + 'a>> {
+        // Pages are bounded so that their filters don't overflow URL length limits.
+        let pagination = tables::Pagination {
+            page_size: API_FETCH_CHUNK_SIZE,
+            ..Default::default()
+        };
+        async move { build::resolve_paginated(self, catalog_names, &pagination).await }.boxed()
+    }
+}
+
+impl tables::PagedCatalogResolver for Resolver {
+    fn resolve_page<'a>(
+        &'a self,
+        page: tables::CatalogPage<'a>,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = tables::LiveCatalog> + Send + 'a>> {
         async move {
             let result = futures::try_join!(
-                self.resolve_specs(&catalog_names),
-                self.resolve_inferred_schemas(&catalog_names),
+                self.resolve_specs(&page),
+                self.resolve_inferred_schemas(&page),
+                self.resolve_tenants(&page),
             );
 
             match result {
-                Ok((mut live, inferred_schemas)) => {
+                Ok((mut live, inferred_schemas, tenants)) => {
                     live.inferred_schemas = inferred_schemas;
+                    live.tenants = tenants;
                     live
                 }
                 Err(err) => {
                     let mut live = tables::LiveCatalog::default();
-                    live.errors.push(tables::Error {
-                        scope: url::Url::parse("flow://control").unwrap(),
-                        error: err,
-                    });
+                    live.errors.push(tables::Error::new(
+                        url::Url::parse("flow://control").unwrap(),
+                        err,
+                    ));
                     live
                 }
             }
@@ -228,8 +263,30 @@ impl tables::CatalogResolver for Resolver {
     }
 }
 
+// Build a PostgREST `or` filter of `column` which matches the names and prefixes of `page`.
+fn page_filter(column: &str, page: &tables::CatalogPage<'_>) -> String {
+    let names = (!page.names.is_empty()).then(|| {
+        format!(
+            "{column}.in.({})",
+            page.names
+                .iter()
+                .map(|name| format!("\"{name}\""))
+                .join(",")
+        )
+    });
+    let prefixes = page
+        .prefixes
+        .iter()
+        .map(|prefix| format!("{column}.like.\"{prefix}%\""));
+
+    names.into_iter().chain(prefixes).join(",")
+}
+
 impl Resolver {
-    async fn resolve_specs(&self, catalog_names: &[&str]) -> anyhow::Result<tables::LiveCatalog> {
+    async fn resolve_specs(
+        &self,
+        page: &tables::CatalogPage<'_>,
+    ) -> anyhow::Result<tables::LiveCatalog> {
         use models::CatalogType;
 
         // NoOpCatalogResolver provides a storage mapping and data-plane fixture.
@@ -253,23 +310,18 @@ impl Resolver {
             dependency_hash: Option<String>,
         }
 
-        let rows = catalog_names
-            .into_iter()
-            .chunks(API_FETCH_CHUNK_SIZE)
-            .into_iter()
-            .map(|names| {
-                let builder = self
-                    .client
-                    .from("live_specs_ext")
-                    .select("id,catalog_name,spec_type,spec,built_spec,last_pub_id,last_build_id")
-                    .not("is", "spec_type", "null")
-                    .in_("catalog_name", names);
-
-                async move { crate::api_exec::<Vec<LiveSpec>>(builder).await }
-            })
-            .collect::<futures::stream::FuturesUnordered<_>>()
-            .try_collect::<Vec<Vec<LiveSpec>>>()
-            .await?;
+        if page.names.is_empty() && page.prefixes.is_empty() {
+            return Ok(live);
+        }
+        let builder = self
+            .client
+            .from("live_specs_ext")
+            .select("id,catalog_name,spec_type,spec,built_spec,last_pub_id,last_build_id")
+            .not("is", "spec_type", "null")
+            .or(page_filter("catalog_name", page));
+
+        // Prefixes may match many rows, so results are paginated.
+        let rows = crate::api_exec_paginated::<LiveSpec>(builder).await?;
 
         for LiveSpec {
             id,
@@ -280,7 +332,7 @@ impl Resolver {
             last_pub_id,
             last_build_id,
             dependency_hash,
-        } in rows.into_iter().flat_map(|i| i.into_iter())
+        } in rows
         {
             match spec_type {
                 CatalogType::Capture => live.captures.insert_row(
@@ -330,7 +382,7 @@ impl Resolver {
 
     async fn resolve_inferred_schemas(
         &self,
-        catalog_names: &[&str],
+        page: &tables::CatalogPage<'_>,
     ) -> anyhow::Result<tables::InferredSchemas> {
         // If we're unauthenticated then return empty InferredSchemas rather than an error.
         if !self.client.is_authenticated() {
@@ -344,22 +396,16 @@ impl Resolver {
             pub md5: String,
         }
 
-        let rows = catalog_names
-            .into_iter()
-            .chunks(API_FETCH_CHUNK_SIZE)
-            .into_iter()
-            .map(|names| {
-                let builder = self
-                    .client
-                    .from("inferred_schemas")
-                    .select("collection_name,schema,md5")
-                    .in_("collection_name", names);
-
-                async move { crate::api_exec::<Vec<Row>>(builder).await }
-            })
-            .collect::<futures::stream::FuturesUnordered<_>>()
-            .try_collect::<Vec<Vec<Row>>>()
-            .await?;
+        if page.names.is_empty() && page.prefixes.is_empty() {
+            return Ok(Default::default());
+        }
+        let builder = self
+            .client
+            .from("inferred_schemas")
+            .select("collection_name,schema,md5")
+            .or(page_filter("collection_name", page));
+
+        let rows = crate::api_exec_paginated::<Row>(builder).await?;
 
         let mut inferred = tables::InferredSchemas::default();
 
@@ -367,13 +413,65 @@ impl Resolver {
             collection_name,
             schema,
             md5,
-        } in rows.into_iter().flat_map(|i| i.into_iter())
+        } in rows
         {
             inferred.insert_row(collection_name, schema, md5);
         }
 
         Ok(inferred)
     }
+
+    async fn resolve_tenants(
+        &self,
+        page: &tables::CatalogPage<'_>,
+    ) -> anyhow::Result<tables::Tenants> {
+        // If we're unauthenticated then return empty Tenants rather than an error.
+        if !self.client.is_authenticated() {
+            return Ok(Default::default());
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Row {
+            id: models::Id,
+            tenant: models::Prefix,
+            strict_schemas: bool,
+        }
+
+        // Map names and prefixes of the page into their tenants.
+        let tenants: Vec<&str> = page
+            .names
+            .iter()
+            .chain(page.prefixes.iter())
+            .copied()
+            .filter_map(|name| name.find('/').map(|ind| &name[..ind + 1]))
+            .sorted()
+            .dedup()
+            .collect();
+
+        if tenants.is_empty() {
+            return Ok(Default::default());
+        }
+        let builder = self
+            .client
+            .from("tenants")
+            .select("id,tenant,strict_schemas")
+            .in_("tenant", tenants);
+
+        let rows = crate::api_exec_paginated::<Row>(builder).await?;
+
+        let mut out = tables::Tenants::default();
+
+        for Row {
+            id,
+            tenant,
+            strict_schemas,
+        } in rows
+        {
+            out.insert_row(tenant, id, strict_schemas);
+        }
+
+        Ok(out)
+    }
 }
 
 // API_BATCH_SIZE is used to chunk a set of API entities fetched in a single request.
@@ -147,9 +147,12 @@ pub async fn do_discover(
                         .map(models::JsonPointer::new)
                         .collect::<Vec<_>>(),
                 ),
+                key_migration: None,
                 derive: None,
                 projections: Default::default(),
                 journals: Default::default(),
+                frozen: false,
+                annotations: Default::default(),
                 expect_pub_id: None,
                 delete: false,
             }),
@@ -130,6 +130,10 @@ pub struct Build {
     /// Source file or URL from which to load the draft catalog.
     #[clap(long)]
     source: String,
+    /// Reuse the live built specifications of drafted specifications whose
+    /// models and dependencies are unchanged, rather than rebuilding them.
+    #[clap(long)]
+    incremental: bool,
 }
 
 #[derive(Debug, clap::Args)]
@@ -279,17 +283,23 @@ async fn do_build(ctx: &mut crate::CliContext, build: &Build) -> anyhow::Result<
         connector_network,
         file_root,
         source,
+        incremental,
     } = build.clone();
 
     let source_url = build::arg_source_to_url(&source, false)?;
     let project_root = build::project_root(&source_url);
 
     let draft = build::load(&source_url, std::path::Path::new(&file_root)).await;
-    let draft = local_specs::surface_errors(draft.into_result())?;
+    let mut draft = local_specs::surface_errors(draft.into_result())?;
 
     let live = resolver.resolve(draft.all_catalog_names()).await;
     let live = local_specs::surface_errors(live.into_result())?;
 
+    if incremental {
+        let build::Reuse { reused, rebuilt } = build::reuse_unchanged(pub_id, &mut draft, &live);
+        tracing::info!(?reused, ?rebuilt, "incremental build");
+    }
+
     let output = build::validate(
         pub_id,
         build_id,
@@ -12,7 +12,13 @@ pub const KEY_BEGIN: &str = "estuary.dev/key-begin";
 pub const KEY_BEGIN_MIN: &str = "00000000";
 pub const KEY_END: &str = "estuary.dev/key-end";
 pub const KEY_END_MAX: &str = "ffffffff";
+pub const KEY_GENERATION: &str = "estuary.dev/key-generation";
 pub const MANAGED_BY_FLOW: &str = "estuary.dev/flow";
+pub const DELETE_AFTER: &str = "estuary.dev/delete-after";
+pub const OWNER: &str = "estuary.dev/owner";
+pub const ANNOTATION_PREFIX: &str = "estuary.dev/annotation/";
+pub const ZONE: &str = "estuary.dev/zone";
+pub const ENCRYPTION: &str = "estuary.dev/encryption";
 
 // ShardSpec labels.
 pub const TASK_NAME: &str = "estuary.dev/task-name";
@@ -29,6 +35,13 @@ pub const SPLIT_SOURCE: &str = "estuary.dev/split-source";
 pub const LOG_LEVEL: &str = "estuary.dev/log-level";
 pub const LOGS_JOURNAL: &str = "estuary.dev/logs-journal";
 pub const STATS_JOURNAL: &str = "estuary.dev/stats-journal";
+pub const MAX_TXN_DURATION: &str = "estuary.dev/max-txn-duration";
+pub const MIN_TXN_DURATION: &str = "estuary.dev/min-txn-duration";
+pub const TXN_DURATION_EXPIRES: &str = "estuary.dev/txn-duration-expires";
+pub const CPU_LIMIT: &str = "estuary.dev/cpu-limit";
+pub const MEMORY_LIMIT: &str = "estuary.dev/memory-limit";
+pub const INITIAL_SPLITS: &str = "estuary.dev/initial-splits";
+pub const USER_PATCH_PREFIX: &str = "estuary.dev/user-patch/";
 // Shard labels related to network connectivity to shards.
 pub const HOSTNAME: &str = "estuary.dev/hostname";
 pub const EXPOSE_PORT: &str = "estuary.dev/expose-port";
@@ -178,13 +191,36 @@ pub fn is_data_plane_label(label: &str) -> bool {
     if label.starts_with(FIELD_PREFIX) {
         return true;
     }
+    // User patches of individual shards are set by operators within the data-plane.
+    if label.starts_with(USER_PATCH_PREFIX) {
+        return true;
+    }
     match label {
         // Key and R-Clock splits are performed within the data-plane.
         KEY_BEGIN | KEY_END | RCLOCK_BEGIN | RCLOCK_END | SPLIT_SOURCE | SPLIT_TARGET => true,
+        // Transaction duration overrides are set by operators within the data-plane.
+        MAX_TXN_DURATION | MIN_TXN_DURATION | TXN_DURATION_EXPIRES => true,
         _ => false,
     }
 }
 
+/// Map a user-patch `label` into the name of the label which it patches,
+/// or None if `label` isn't a user-patch or its target may not be patched.
+/// Data-plane labels, and labels which identify a task or its ops journals,
+/// may not be patched.
+pub fn user_patch_target(label: &str) -> Option<&str> {
+    let target = label.strip_prefix(USER_PATCH_PREFIX)?;
+
+    if target.is_empty() || is_data_plane_label(target) {
+        return None;
+    }
+    match target {
+        BUILD | DELETE_AFTER | LOGS_JOURNAL | MANAGED_BY | STATS_JOURNAL | TASK_NAME
+        | TASK_TYPE => None,
+        _ => Some(target),
+    }
+}
+
 /// Percent-encoding of string values so that they can be used in label values.
 pub fn percent_encoding<'s>(s: &'s str) -> percent_encoding::PercentEncode<'s> {
     // The set of characters that must be percent-encoded when used in partition
@@ -363,4 +399,20 @@ mod test {
             assert_eq!(percent_encoding(fixture).to_string(), expect);
         }
     }
+
+    #[test]
+    fn user_patch_cases() {
+        let patch = |target: &str| format!("{USER_PATCH_PREFIX}{target}");
+
+        assert!(is_data_plane_label(&patch(LOG_LEVEL)));
+        assert_eq!(user_patch_target(&patch(LOG_LEVEL)), Some(LOG_LEVEL));
+        assert_eq!(user_patch_target(&patch(ZONE)), Some(ZONE));
+
+        for target in ["", KEY_BEGIN, SPLIT_SOURCE, TASK_NAME, LOGS_JOURNAL] {
+            assert_eq!(user_patch_target(&patch(target)), None, "{target}");
+        }
+        assert_eq!(user_patch_target(LOG_LEVEL), None);
+        // Patches of patches are not permitted.
+        assert_eq!(user_patch_target(&patch(&patch(LOG_LEVEL))), None);
+    }
 }
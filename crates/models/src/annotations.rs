@@ -0,0 +1,39 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+/// Annotations are descriptive metadata of a catalog specification.
+/// The owner and labels of a task are surfaced as labels of its
+/// data-plane shards and journals, which attribute them to their team.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[schemars(example = "Annotations::example")]
+pub struct Annotations {
+    /// # Team or individual which owns this specification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// # Description of this specification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// # Labels of this specification.
+    /// Label names may contain letters, digits, and the symbols `-_./`,
+    /// and label values may additionally contain the symbols `+=%@:`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+impl Annotations {
+    pub fn example() -> Self {
+        serde_json::from_value(json!({
+            "owner": "data-platform",
+            "description": "Orders placed through the storefront",
+            "labels": {"cost-center": "cc-1234"},
+        }))
+        .unwrap()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.owner.is_none() && self.description.is_none() && self.labels.is_empty()
+    }
+}
@@ -73,6 +73,11 @@ pub struct UserCollectionAuthorizationRequest {
     /// definitively rejected.
     #[serde(default)]
     pub started_unix: u64,
+    /// # Capability to the collection which is requested.
+    /// If unset, Read is requested. The Write capability additionally
+    /// authorizes appends to the journals of the collection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capability: Option<crate::Capability>,
 }
 
 /// UserCollectionAuthorization is an authorization granted to a user for the
@@ -1,4 +1,4 @@
-use super::{Collection, ConnectorConfig, Id, LocalConfig, RawValue, ShardTemplate};
+use super::{Annotations, Collection, ConnectorConfig, Id, LocalConfig, RawValue, ShardTemplate};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -38,6 +38,10 @@ pub struct CaptureDef {
     /// # Template for shards of this capture task.
     #[serde(default, skip_serializing_if = "ShardTemplate::is_empty")]
     pub shards: ShardTemplate,
+    /// # Annotations of this capture.
+    /// Its owner and labels are surfaced as labels of its built specification.
+    #[serde(default, skip_serializing_if = "Annotations::is_empty")]
+    pub annotations: Annotations,
     /// # Expected publication ID of this capture within the control plane.
     /// When present, a publication of the capture will fail if the
     /// last publication ID in the control plane doesn't match this value.
@@ -122,6 +126,7 @@ impl CaptureDef {
             bindings: vec![CaptureBinding::example()],
             interval: Self::default_interval(),
             shards: ShardTemplate::default(),
+            annotations: Annotations::default(),
             expect_pub_id: None,
             delete: false,
         }
@@ -42,7 +42,9 @@ pub struct Catalog {
     pub tests: BTreeMap<Test, TestDef>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, Debug, JsonSchema, PartialEq, Eq)]
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, JsonSchema, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
 #[serde(rename_all = "lowercase")]
 #[cfg_attr(
     feature = "sqlx-support",
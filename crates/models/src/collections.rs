@@ -1,10 +1,13 @@
 use crate::DeriveUsing;
 
-use super::{CompositeKey, Derivation, Field, Id, JournalTemplate, JsonPointer, Schema};
+use super::{
+    Annotations, Collection, CompositeKey, Derivation, Field, Id, JournalTemplate, JsonPointer,
+    Materialization, Schema,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, json};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Collection describes a set of related documents, where each adheres to a
 /// common schema and grouping key. Collections are append-only: once a document
@@ -30,6 +33,13 @@ pub struct CollectionDef {
     pub read_schema: Option<Schema>,
     /// # Composite key of this collection.
     pub key: CompositeKey,
+    /// # Migration of this collection from a prior key.
+    /// When present and `from` is the collection's current key, the key may
+    /// be changed. Existing journals of the collection are retained, and their
+    /// documents are keyed on the new key as they're read. Materializations and
+    /// derivations of the collection must backfill it in the same publication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_migration: Option<KeyMigration>,
     /// # Projections and logical partitions of this collection.
     #[schemars(schema_with = "projections_schema")]
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
@@ -37,9 +47,19 @@ pub struct CollectionDef {
     /// # Template for journals of this collection.
     #[serde(default, skip_serializing_if = "JournalTemplate::is_empty")]
     pub journals: JournalTemplate,
+    /// # Freeze this collection, disallowing new documents.
+    /// When true, journals of the collection are read-only. Its existing
+    /// documents may still be read, but captures and derivations may not
+    /// write to it.
+    #[serde(default, skip_serializing_if = "super::is_false")]
+    pub frozen: bool,
     // # Derivation which builds this collection as transformations of other collections.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub derive: Option<Derivation>,
+    /// # Annotations of this collection.
+    /// Its owner and labels are surfaced as labels of its built specification.
+    #[serde(default, skip_serializing_if = "Annotations::is_empty")]
+    pub annotations: Annotations,
     /// # Expected publication ID of this collection within the control plane.
     /// When present, a publication of the collection will fail if the
     /// last publication ID in the control plane doesn't match this value.
@@ -58,15 +78,59 @@ impl CollectionDef {
             write_schema: None,
             read_schema: None,
             key: CompositeKey::example(),
+            key_migration: None,
             projections: BTreeMap::new(),
             journals: JournalTemplate::default(),
+            frozen: false,
             derive: None,
+            annotations: Annotations::default(),
             expect_pub_id: None,
             delete: false,
         }
     }
 }
 
+/// KeyMigration declares an intended change of a collection's key.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[schemars(example = "KeyMigration::example")]
+pub struct KeyMigration {
+    /// # Current key of the collection, from which it's being migrated.
+    /// The migration applies only while this matches the collection's
+    /// current key, and is otherwise ignored.
+    pub from: CompositeKey,
+}
+
+impl KeyMigration {
+    pub fn example() -> Self {
+        Self {
+            from: CompositeKey::example(),
+        }
+    }
+}
+
+/// KeyMigrationPlan is the built plan of a collection key migration.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyMigrationPlan {
+    /// Current key of the collection.
+    pub from: CompositeKey,
+    /// New key of the collection.
+    pub to: CompositeKey,
+    /// Generation of the new key, which is the publication that applies it.
+    /// Documents written before this generation are keyed on the prior key,
+    /// and are re-keyed on the new key as they're read.
+    pub generation_id: Id,
+    /// Materializations of the collection which backfill its bindings,
+    /// as their existing documents are keyed on the prior key.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub backfill_materializations: BTreeSet<Materialization>,
+    /// Derivations of the collection which backfill its transforms,
+    /// as their existing state and documents reflect the prior key.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub backfill_derivations: BTreeSet<Collection>,
+}
+
 /// Projections are named locations within a collection document which
 /// may be used for logical partitioning or directly exposed to databases
 /// into which collections are materialized.
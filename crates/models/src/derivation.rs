@@ -24,6 +24,11 @@ pub struct Derivation {
     /// # Template for shards of this derivation task.
     #[serde(default, skip_serializing_if = "ShardTemplate::is_empty")]
     pub shards: ShardTemplate,
+    /// # Allow transforms to read collections of other data-planes.
+    /// Cross-plane reads must also be permitted by the data-plane of this
+    /// derivation, which names the peer data-planes it may read from.
+    #[serde(default, skip_serializing_if = "super::is_false")]
+    pub allow_cross_plane_read: bool,
 }
 
 /// A derivation runtime implementation.
@@ -65,6 +70,17 @@ pub struct TransformDef {
     /// before *any* documents of other transforms are processed.
     #[serde(default, skip_serializing_if = "TransformDef::priority_is_zero")]
     pub priority: u32,
+    /// # Maximum rate at which this transform reads its source collection.
+    /// Expressed in bytes per second, and must be at least 1024 (1KiB/s).
+    /// When unset, reads of the source collection are not throttled.
+    ///
+    /// Note that maxReadRate is validated and built into the task,
+    /// but is not yet enforced when reading the source collection.
+    ///
+    /// A throttled transform may not have a higher priority than an
+    /// unthrottled transform of the same derivation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_read_rate: Option<u32>,
     /// # Delay applied to documents processed by this transform.
     /// Delays are applied as an adjustment to the UUID clock encoded within each
     /// document, which is then used to impose a relative ordering of all documents
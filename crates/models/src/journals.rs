@@ -18,6 +18,10 @@ pub struct GcsBucketAndPrefix {
     #[validate]
     #[serde(default)]
     pub prefix: Option<Prefix>,
+
+    /// Optional customer-managed encryption of fragments written to the bucket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<StoreEncryption>,
 }
 
 impl GcsBucketAndPrefix {
@@ -36,6 +40,7 @@ impl GcsBucketAndPrefix {
         Self {
             bucket: "my-bucket".to_string(),
             prefix: None,
+            encryption: None,
         }
     }
 }
@@ -55,6 +60,10 @@ pub struct S3StorageConfig {
     /// AWS region of the S3 bucket. Uses the default value from the AWS credentials of the Gazette
     /// broker if unset.
     pub region: Option<String>,
+
+    /// Optional customer-managed encryption of fragments written to the bucket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<StoreEncryption>,
 }
 
 impl S3StorageConfig {
@@ -79,6 +88,7 @@ impl S3StorageConfig {
             bucket: "my-bucket".to_string(),
             prefix: None,
             region: None,
+            encryption: None,
         }
     }
 }
@@ -100,6 +110,10 @@ pub struct AzureStorageConfig {
     #[validate]
     #[serde(default)]
     pub prefix: Option<Prefix>,
+
+    /// Optional customer-managed encryption of fragments written to the bucket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<StoreEncryption>,
 }
 
 impl AzureStorageConfig {
@@ -122,6 +136,7 @@ impl AzureStorageConfig {
             storage_account_name: "storageaccount".to_string(),
             container_name: "containername".to_string(),
             prefix: None,
+            encryption: None,
         }
     }
 }
@@ -140,6 +155,10 @@ pub struct CustomStore {
     #[validate]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub prefix: Option<Prefix>,
+
+    /// Optional customer-managed encryption of fragments written to the bucket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<StoreEncryption>,
 }
 
 impl CustomStore {
@@ -148,6 +167,7 @@ impl CustomStore {
             bucket: "my-bucket".to_string(),
             endpoint: StorageEndpoint::example(),
             prefix: None,
+            encryption: None,
         }
     }
 
@@ -167,6 +187,49 @@ impl CustomStore {
     }
 }
 
+/// StoreEncryption is a customer-managed key with which brokers encrypt
+/// journal fragments as they're persisted to a Store.
+/// Only S3 stores support customer-managed keys, which must be AWS KMS keys.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+#[schemars(example = "StoreEncryption::example")]
+pub struct StoreEncryption {
+    /// Key management service which holds the encryption key.
+    pub provider: EncryptionProvider,
+    /// Encryption key, as an AWS KMS key ARN
+    /// (arn:aws:kms:{region}:{account}:key/{id}).
+    pub key: String,
+}
+
+impl StoreEncryption {
+    pub fn example() -> Self {
+        Self {
+            provider: EncryptionProvider::AwsKms,
+            key: "arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab"
+                .to_string(),
+        }
+    }
+}
+
+/// Key management service of a StoreEncryption.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, JsonSchema, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EncryptionProvider {
+    /// AWS Key Management Service.
+    AwsKms,
+    /// Google Cloud Key Management Service.
+    GcpKms,
+}
+
+impl EncryptionProvider {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AwsKms => "AWS_KMS",
+            Self::GcpKms => "GCP_KMS",
+        }
+    }
+}
+
 /// A Store into which Flow journal fragments may be written.
 ///
 /// The persisted path of a journal fragment is determined by composing the
@@ -208,7 +271,38 @@ impl Store {
     pub fn example() -> Self {
         Self::S3(S3StorageConfig::example())
     }
+    pub fn encryption(&self) -> Option<&StoreEncryption> {
+        match self {
+            Store::S3(c) => c.encryption.as_ref(),
+            Store::Gcs(c) => c.encryption.as_ref(),
+            Store::Azure(c) => c.encryption.as_ref(),
+            Store::Custom(c) => c.encryption.as_ref(),
+        }
+    }
+
+    /// Map this Store into a fragment store URL of a journal or recovery log.
+    /// Encryption, if set, is passed to brokers as query parameters which
+    /// become server-side encryption headers of fragment writes.
     pub fn to_url(&self, catalog_name: &str) -> url::Url {
+        let mut url = self.to_base_url(catalog_name);
+
+        match self.encryption() {
+            Some(StoreEncryption {
+                provider: EncryptionProvider::AwsKms,
+                key,
+            }) => {
+                url.query_pairs_mut()
+                    .append_pair("SSE", "aws:kms")
+                    .append_pair("SSEKMSKeyId", key);
+            }
+            // Validation rejects other encryption of stores,
+            // which Gazette's store query arguments cannot express.
+            Some(_) | None => {}
+        }
+        url
+    }
+
+    fn to_base_url(&self, catalog_name: &str) -> url::Url {
         match self {
             Self::S3(cfg) => cfg.as_url(),
             Self::Gcs(cfg) => cfg.as_url(),
@@ -270,12 +364,17 @@ pub struct StorageDef {
     /// directory is used instead.
     #[validate]
     pub stores: Vec<Store>,
+    /// # Data-planes to which specifications under this prefix may be assigned.
+    /// If empty, specifications may be assigned to any data-plane.
+    #[serde(default, rename = "dataPlanes", skip_serializing_if = "Vec::is_empty")]
+    pub data_planes: Vec<String>,
 }
 
 impl StorageDef {
     pub fn example() -> Self {
         Self {
             stores: vec![Store::example()],
+            data_planes: Vec::new(),
         }
     }
 }
@@ -304,8 +403,8 @@ impl CompressionCodec {
 
 /// A FragmentTemplate configures how journal fragment files are
 /// produced as part of a collection.
-// path_postfix_template and refresh_interval are deliberately not
-// exposed here. We're fixing these values in place for now.
+// refresh_interval is deliberately not exposed here.
+// We're fixing this value in place for now.
 #[derive(Serialize, Deserialize, Debug, Default, JsonSchema, Validate, Clone, PartialEq)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 #[schemars(example = "FragmentTemplate::example")]
@@ -341,6 +440,14 @@ pub struct FragmentTemplate {
     )]
     #[schemars(schema_with = "super::duration_schema")]
     pub flush_interval: Option<std::time::Duration>,
+    /// # Template of the path postfix of persisted fragment files.
+    /// Templates are Go text/template strings evaluated with the fragment's
+    /// spool, and may reference `.Spool.FirstAppendTime` to partition fragments
+    /// by their creation time. For example, `dt={{.Spool.FirstAppendTime.Format "20060102"}}`.
+    /// If not set, fragments are partitioned by their UTC date and hour using
+    /// the Hive partitioning scheme (`utc_date=2006-01-02/utc_hour=15`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_postfix_template: Option<String>,
 }
 
 impl FragmentTemplate {
@@ -357,34 +464,73 @@ impl FragmentTemplate {
             compression_codec: o2,
             retention: o3,
             flush_interval: o4,
+            path_postfix_template: o5,
         } = self;
 
-        o1.is_none() && o2.is_none() && o3.is_none() && o4.is_none()
+        o1.is_none() && o2.is_none() && o3.is_none() && o4.is_none() && o5.is_none()
     }
 }
 
 /// A JournalTemplate configures the journals which make up the
 /// physical partitions of a collection.
-#[derive(Serialize, Deserialize, Debug, Default, JsonSchema, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Default, JsonSchema, Validate, Clone, PartialEq)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 #[schemars(example = "JournalTemplate::example")]
 pub struct JournalTemplate {
     /// # Fragment configuration of collection journals.
+    #[validate]
     pub fragments: FragmentTemplate,
+    /// # Number of brokers to which each collection journal is replicated.
+    /// If not set, a default of 3 is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = "MIN_REPLICATION", max = "MAX_REPLICATION"))]
+    pub replication: Option<u32>,
+    /// # Maximum rate, in bytes per second, at which each collection journal may be appended.
+    /// Writers are back-pressured when appending at greater rates.
+    /// If not set, a default of 4MB/s is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = "MIN_MAX_APPEND_RATE", max = "MAX_MAX_APPEND_RATE"))]
+    pub max_append_rate: Option<u64>,
+    /// # Zones of collection journals.
+    /// Zones are informational: they must be zones of the collection's
+    /// data-plane and are attached to each journal as labels, but the
+    /// placement of journal replicas is not constrained to them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub zones: Vec<String>,
 }
 
 impl JournalTemplate {
     pub fn example() -> Self {
         Self {
             fragments: FragmentTemplate::example(),
+            replication: None,
+            max_append_rate: None,
+            zones: Vec::new(),
         }
     }
     pub fn is_empty(&self) -> bool {
-        let JournalTemplate { fragments } = self;
+        let JournalTemplate {
+            fragments,
+            replication,
+            max_append_rate,
+            zones,
+        } = self;
         fragments.is_empty()
+            && replication.is_none()
+            && max_append_rate.is_none()
+            && zones.is_empty()
     }
 }
 
+/// Bounds of the number of replicas of each collection journal.
+pub const MIN_REPLICATION: u32 = 1;
+pub const MAX_REPLICATION: u32 = 5;
+/// Bounds of the maximum append rate of each collection journal, in bytes per second.
+/// A zero rate would disable rate limiting altogether, and very large rates
+/// allow a single journal to saturate broker networking.
+pub const MIN_MAX_APPEND_RATE: u64 = 1 << 20;
+pub const MAX_MAX_APPEND_RATE: u64 = 1 << 27;
+
 lazy_static! {
     /// S3_BUCKET_RE matches an S3 bucket name. Simplified from (look-around removed):
     /// https://stackoverflow.com/questions/50480924/regex-for-s3-bucket-name
@@ -404,6 +550,12 @@ lazy_static! {
     /// Matches Azure storage account names.
     /// https://learn.microsoft.com/en-us/azure/storage/common/storage-account-overview#storage-account-name
     pub static ref AZURE_STORAGE_ACCOUNT_RE: Regex = Regex::new(r#"(^[a-z0-9]{3,24}$)"#).unwrap();
+
+    /// Matches AWS KMS key and alias ARNs, capturing the key's region.
+    /// https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#key-id-key-ARN
+    pub static ref AWS_KMS_KEY_RE: Regex =
+            Regex::new(r#"^arn:aws(-[a-z]+)*:kms:([a-z0-9-]+):[0-9]{12}:(key|alias)/[a-zA-Z0-9/_-]+$"#).unwrap();
+
 }
 
 #[cfg(test)]
@@ -503,6 +655,71 @@ mod test {
         );
     }
 
+    #[test]
+    fn encrypted_store_urls() {
+        let s3: Store = serde_json::from_str(
+            r#"{"provider":"S3","bucket":"a-bucket","prefix":"flow/","region":"us-east-1","encryption":{"provider":"AWS_KMS","key":"arn:aws:kms:us-east-1:123456789012:key/abcd-1234"}}"#,
+        ).expect("failed to deserialize");
+        assert_eq!(
+            "s3://a-bucket/flow/?region=us-east-1&SSE=aws%3Akms&SSEKMSKeyId=arn%3Aaws%3Akms%3Aus-east-1%3A123456789012%3Akey%2Fabcd-1234",
+            s3.to_url("acmeCo/foo").as_str()
+        );
+
+        // GCS stores have no encryption arguments (and validation rejects them).
+        let gcs: Store = serde_json::from_str(
+            r#"{"provider":"GCS","bucket":"a-bucket","encryption":{"provider":"GCP_KMS","key":"projects/a-project/locations/us/keyRings/ring/cryptoKeys/key"}}"#,
+        ).expect("failed to deserialize");
+        assert_eq!("gs://a-bucket/", gcs.to_url("acmeCo/foo").as_str());
+
+        assert!(
+            AWS_KMS_KEY_RE.is_match("arn:aws-us-gov:kms:us-gov-west-1:123456789012:alias/my-key")
+        );
+        assert!(!AWS_KMS_KEY_RE.is_match("arn:aws:s3:::a-bucket"));
+    }
+
+    #[test]
+    fn store_url_args_are_parsed_by_gazette() {
+        // Query arguments of Gazette's S3 and GCS fragment stores, which are
+        // decoded into the store's StoreQueryArgs and matched case-insensitively
+        // to its fields. Stores fail to open if given any other argument.
+        // See go.gazette.dev/core/broker/stores/{s3,gcs}.
+        let s3_args = [
+            "find",
+            "replace",
+            "profile",
+            "endpoint",
+            "acl",
+            "storageclass",
+            "sse",
+            "ssekmskeyid",
+            "region",
+        ];
+        let gcs_args = ["find", "replace"];
+
+        let stores = [
+            r#"{"provider":"S3","bucket":"a-bucket","prefix":"flow/","region":"us-east-1","encryption":{"provider":"AWS_KMS","key":"arn:aws:kms:us-east-1:123456789012:key/abcd-1234"}}"#,
+            r#"{"provider":"CUSTOM","bucket":"a-bucket","endpoint":"http://canary.test:1234"}"#,
+            r#"{"provider":"GCS","bucket":"a-bucket","prefix":"flow/","encryption":{"provider":"GCP_KMS","key":"projects/a-project/locations/us/keyRings/ring/cryptoKeys/key"}}"#,
+        ];
+
+        for store in stores {
+            let store: Store = serde_json::from_str(store).expect("failed to deserialize");
+            let url = store.to_url("acmeCo/foo");
+
+            let args: &[&str] = match url.scheme() {
+                "s3" => &s3_args,
+                "gs" => &gcs_args,
+                scheme => panic!("unexpected scheme {scheme}"),
+            };
+            for (key, _value) in url.query_pairs() {
+                assert!(
+                    args.contains(&key.to_lowercase().as_str()),
+                    "{url} has argument {key} which the store doesn't parse"
+                );
+            }
+        }
+    }
+
     // The main catalog schema does not include storage definitions. This test ensures that the
     // storage schemas are available in the snapshot and up-to-date, since we need them for the UI.
     #[test]
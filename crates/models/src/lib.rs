@@ -1,5 +1,6 @@
 use std::collections::BTreeSet;
 
+mod annotations;
 pub mod authorizations;
 mod captures;
 mod catalogs;
@@ -15,6 +16,7 @@ pub mod evolutions;
 mod id;
 mod journals;
 mod labels;
+mod lineage;
 mod materializations;
 pub mod publications;
 mod raw_value;
@@ -28,9 +30,10 @@ pub mod status;
 mod tests;
 
 pub use crate::labels::{Label, LabelSelector, LabelSet};
+pub use annotations::Annotations;
 pub use captures::{AutoDiscover, CaptureBinding, CaptureDef, CaptureEndpoint};
 pub use catalogs::{Capability, Catalog, CatalogType};
-pub use collections::{CollectionDef, Projection};
+pub use collections::{CollectionDef, KeyMigration, KeyMigrationPlan, Projection};
 pub use connector::{
     split_image_tag, ConnectorConfig, DekafConfig, LocalConfig, DEKAF_IMAGE_NAME_PREFIX,
     DEKAF_IMAGE_TAG,
@@ -40,10 +43,12 @@ pub use derive_sqlite::DeriveUsingSqlite;
 pub use derive_typescript::DeriveUsingTypescript;
 pub use id::{Id, IdGenerator};
 pub use journals::{
-    AzureStorageConfig, CompressionCodec, CustomStore, FragmentTemplate, GcsBucketAndPrefix,
-    JournalTemplate, S3StorageConfig, StorageDef, Store, AZURE_CONTAINER_RE,
-    AZURE_STORAGE_ACCOUNT_RE, GCS_BUCKET_RE, S3_BUCKET_RE,
+    AzureStorageConfig, CompressionCodec, CustomStore, EncryptionProvider, FragmentTemplate,
+    GcsBucketAndPrefix, JournalTemplate, S3StorageConfig, StorageDef, Store, StoreEncryption,
+    AWS_KMS_KEY_RE, AZURE_CONTAINER_RE, AZURE_STORAGE_ACCOUNT_RE, GCS_BUCKET_RE,
+    MAX_MAX_APPEND_RATE, MAX_REPLICATION, MIN_MAX_APPEND_RATE, MIN_REPLICATION, S3_BUCKET_RE,
 };
+pub use lineage::{FieldLineage, LineageVia};
 pub use materializations::{
     MaterializationBinding, MaterializationDef, MaterializationEndpoint, MaterializationFields,
     SqliteConfig,
@@ -54,10 +59,12 @@ pub use references::{
     Prefix, RelativeUrl, StorageEndpoint, Test, Token, Transform, CATALOG_PREFIX_RE, TOKEN_RE,
 };
 pub use schemas::Schema;
-pub use shards::ShardTemplate;
+pub use shards::{ShardResources, ShardTemplate};
 pub use source::{FullSource, OnIncompatibleSchemaChange, PartitionSelector, Source};
-pub use source_capture::{SourceCapture, SourceCaptureDef, SourceCaptureSchemaMode};
-pub use tests::{TestDef, TestDocuments, TestStep, TestStepIngest, TestStepVerify};
+pub use source_capture::{
+    SourceCapture, SourceCaptureDef, SourceCapturePreview, SourceCaptureSchemaMode,
+};
+pub use tests::{TestDef, TestDocuments, TestStep, TestStepExpect, TestStepIngest, TestStepVerify};
 
 /// Uniquely identifies a resource in an external system that can be either
 /// captured from or materialized into. For example, a `[schema, table]` in
@@ -0,0 +1,34 @@
+use super::{Collection, Field, JsonPointer};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// FieldLineage relates a location of a source collection
+/// to a field of a materialization binding or derivation.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldLineage {
+    /// Index of the materialization binding or derivation transform.
+    pub index: u32,
+    /// Field which is fed by the source location, or None if the location
+    /// feeds the transform as a whole (for example, a shuffle key read by
+    /// a lambda whose field-level reads are unknown).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<Field>,
+    /// Source collection of the location.
+    pub source: Collection,
+    /// JSON pointer of the location within source documents.
+    pub location: JsonPointer,
+    /// Means by which the location feeds the field.
+    pub via: LineageVia,
+}
+
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, JsonSchema, PartialEq, Eq, PartialOrd, Ord,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum LineageVia {
+    /// The field is a selected projection of the location.
+    Projection,
+    /// The location is a component of the transform's shuffle key.
+    ShuffleKey,
+}
@@ -3,7 +3,9 @@ use crate::{connector::DekafConfig, source::OnIncompatibleSchemaChange, Collecti
 
 use crate::source_capture::SourceCapture;
 
-use super::{ConnectorConfig, Field, LocalConfig, RawValue, RelativeUrl, ShardTemplate, Source};
+use super::{
+    Annotations, ConnectorConfig, Field, LocalConfig, RawValue, RelativeUrl, ShardTemplate, Source,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -31,6 +33,15 @@ pub struct MaterializationDef {
     /// # Template for shards of this materialization task.
     #[serde(default, skip_serializing_if = "ShardTemplate::is_empty")]
     pub shards: ShardTemplate,
+    /// # Allow bindings to read collections of other data-planes.
+    /// Cross-plane reads must also be permitted by the data-plane of this
+    /// materialization, which names the peer data-planes it may read from.
+    #[serde(default, skip_serializing_if = "super::is_false")]
+    pub allow_cross_plane_read: bool,
+    /// # Annotations of this materialization.
+    /// Its owner and labels are surfaced as labels of its built specification.
+    #[serde(default, skip_serializing_if = "Annotations::is_empty")]
+    pub annotations: Annotations,
     /// # Expected publication ID of this materialization within the control plane.
     /// When present, a publication of the materialization will fail if the
     /// last publication ID in the control plane doesn't match this value.
@@ -80,6 +91,17 @@ pub struct MaterializationBinding {
         skip_serializing_if = "MaterializationBinding::priority_is_zero"
     )]
     pub priority: u32,
+    /// # Maximum rate at which this binding reads its source collection.
+    /// Expressed in bytes per second, and must be at least 1024 (1KiB/s).
+    /// When unset, reads of the source collection are not throttled.
+    ///
+    /// Note that maxReadRate is validated and built into the task,
+    /// but is not yet enforced when reading the source collection.
+    ///
+    /// A throttled binding may not have a higher priority than an
+    /// unthrottled binding of the same materialization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_read_rate: Option<u32>,
     /// # Selected projections for this materialization.
     #[serde(default)]
     pub fields: MaterializationFields,
@@ -129,6 +151,7 @@ impl MaterializationDef {
             endpoint: MaterializationEndpoint::Connector(ConnectorConfig::example()),
             bindings: vec![MaterializationBinding::example()],
             shards: ShardTemplate::default(),
+            annotations: Annotations::default(),
             expect_pub_id: None,
             delete: false,
             on_incompatible_schema_change: OnIncompatibleSchemaChange::default(),
@@ -143,6 +166,7 @@ impl MaterializationBinding {
             source: Source::example(),
             disable: false,
             priority: 0,
+            max_read_rate: None,
             fields: MaterializationFields::default(),
             backfill: 0,
             on_incompatible_schema_change: None,
@@ -48,6 +48,22 @@ pub struct ShardTemplate {
     /// EXPERIMENTAL: this field MAY be removed.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hot_standbys: Option<u32>,
+    /// # Number of recovery log hint backups to keep for each task shard.
+    /// Hint backups are older checkpoints of a shard's recovery log hints,
+    /// which are used if the most recent hints are unusable.
+    /// Tasks with large states may benefit from additional backups.
+    /// If not set, two backups are kept.
+    /// EXPERIMENTAL: this field MAY be removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint_backups: Option<u32>,
+    /// # Etcd prefix under which shard recovery log hints are stored.
+    /// The prefix must be "/estuary/flow/hints" or nested beneath it.
+    /// It cannot be changed once a task has been published, as the task's
+    /// shards would no longer find their hints.
+    /// If not set, hints are stored directly under "/estuary/flow/hints".
+    /// EXPERIMENTAL: this field MAY be removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint_prefix: Option<String>,
     /// # Size of the ring buffer used to sequence documents for exactly-once semantics.
     /// The ring buffer is a performance optimization only:
     /// catalog tasks will replay portions of journals as
@@ -73,6 +89,89 @@ pub struct ShardTemplate {
     // we'll introduce a modular logging capability.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub log_level: Option<String>,
+    /// # Zones of task shards and their recovery logs.
+    /// Zones are informational: they must be zones of the task's data-plane
+    /// and are attached to its shards and recovery logs as labels, but the
+    /// assignment of shards and placement of log replicas is not constrained to them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub zones: Vec<String>,
+    /// # Resource limits of the task's connector containers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ShardResources>,
+}
+
+/// ShardResources are limits of the CPU and memory available to the
+/// connector container of each task shard. Limits must fall within the
+/// range permitted by the task's data-plane.
+/// If not set, the data-plane's default limits apply.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+#[schemars(example = "ShardResources::example")]
+pub struct ShardResources {
+    /// # CPU limit, as fractional cores.
+    /// For example, "2", "0.5", or "500m" (500 millicores).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<String>,
+    /// # Memory limit, in bytes.
+    /// For example, "536870912", "512Mi", or "2Gi".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+}
+
+impl ShardResources {
+    pub fn example() -> Self {
+        Self {
+            cpu: Some("500m".to_string()),
+            memory: Some("1Gi".to_string()),
+        }
+    }
+
+    /// Parse the CPU limit into millicores.
+    pub fn cpu_millis(&self) -> Result<Option<u64>, String> {
+        let Some(cpu) = &self.cpu else {
+            return Ok(None);
+        };
+        let millis = if let Some(millis) = cpu.strip_suffix('m') {
+            millis.parse::<u64>().ok()
+        } else {
+            cpu.parse::<f64>()
+                .ok()
+                .filter(|cores| cores.is_finite() && *cores >= 0.0)
+                .map(|cores| (cores * 1000.0).round() as u64)
+        };
+        millis.map(Some).ok_or_else(|| {
+            format!("{cpu:?} is not a valid CPU quantity (expected cores like \"0.5\", or millicores like \"500m\")")
+        })
+    }
+
+    /// Parse the memory limit into bytes.
+    pub fn memory_bytes(&self) -> Result<Option<u64>, String> {
+        let Some(memory) = &self.memory else {
+            return Ok(None);
+        };
+        let split = memory
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(memory.len());
+        let (value, suffix) = memory.split_at(split);
+
+        let scale: Option<u64> = match suffix {
+            "" => Some(1),
+            "K" => Some(1_000),
+            "M" => Some(1_000_000),
+            "G" => Some(1_000_000_000),
+            "Ki" => Some(1 << 10),
+            "Mi" => Some(1 << 20),
+            "Gi" => Some(1 << 30),
+            _ => None,
+        };
+        match (value.parse::<u64>(), scale) {
+            (Ok(value), Some(scale)) => value.checked_mul(scale).map(Some),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            format!("{memory:?} is not a valid memory quantity (expected bytes with an optional suffix of K, M, G, Ki, Mi, or Gi)")
+        })
+    }
 }
 
 impl ShardTemplate {
@@ -89,9 +188,13 @@ impl ShardTemplate {
             min_txn_duration: o1,
             max_txn_duration: o2,
             hot_standbys: o3,
-            ring_buffer_size: o4,
-            read_channel_size: o5,
-            log_level: o6,
+            hint_backups: o4,
+            hint_prefix: o5,
+            ring_buffer_size: o6,
+            read_channel_size: o7,
+            log_level: o8,
+            zones,
+            resources: o9,
         } = self;
 
         !disable
@@ -101,5 +204,9 @@ impl ShardTemplate {
             && o4.is_none()
             && o5.is_none()
             && o6.is_none()
+            && o7.is_none()
+            && o8.is_none()
+            && zones.is_empty()
+            && o9.is_none()
     }
 }
@@ -1,8 +1,9 @@
-use super::Capture;
+use super::{Capture, Collection};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
-#[derive(Debug, Serialize, Deserialize,  Clone, PartialEq, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub enum SourceCaptureSchemaMode {
     /// Leave the materialization binding's schema field empty, therefore falling back to the
@@ -63,3 +64,23 @@ impl SourceCapture {
         }
     }
 }
+
+/// SourceCapturePreview describes the changes to a materialization's bindings
+/// which will be made when it's next reconciled with its `sourceCapture`.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceCapturePreview {
+    /// Collections of the source capture which will be added as new bindings.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub add_bindings: BTreeSet<Collection>,
+    /// Source collections of enabled bindings which will be disabled,
+    /// because the collection is being deleted.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub disable_bindings: BTreeSet<Collection>,
+}
+
+impl SourceCapturePreview {
+    pub fn is_empty(&self) -> bool {
+        self.add_bindings.is_empty() && self.disable_bindings.is_empty()
+    }
+}
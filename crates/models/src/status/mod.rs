@@ -214,6 +214,8 @@ mod test {
         let status = ControllerStatus::Materialization(MaterializationStatus {
             activation: ActivationStatus {
                 last_activated: Id::new([1, 2, 3, 4, 4, 3, 2, 1]),
+                skipped_shards: Vec::new(),
+                txn_overrides_expire: None,
             },
             source_capture: Some(SourceCaptureStatus {
                 up_to_date: false,
@@ -14,16 +14,36 @@ pub struct ActivationStatus {
     /// then an activation is still pending.
     #[serde(default = "Id::zero", skip_serializing_if = "Id::is_zero")]
     pub last_activated: Id,
+    /// Shards which the last activation left as they were, rather than
+    /// converging them, because they carry a newer build than `last_activated`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_shards: Vec<SkippedShard>,
+    /// Time at which data-plane overrides of the transaction durations of
+    /// the task's shards expire, and the task is activated again to remove them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "crate::option_datetime_schema")]
+    pub txn_overrides_expire: Option<DateTime<Utc>>,
 }
 
 impl Default for ActivationStatus {
     fn default() -> Self {
         Self {
             last_activated: Id::zero(),
+            skipped_shards: Vec::new(),
+            txn_overrides_expire: None,
         }
     }
 }
 
+/// A data-plane shard which carries a newer build than its activated task.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct SkippedShard {
+    /// ID of the shard.
+    pub shard: String,
+    /// Build of the shard in the data-plane.
+    pub current_build: Id,
+}
+
 /// Summary of a publication that was attempted by a controller.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
 pub struct PublicationInfo {
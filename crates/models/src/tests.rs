@@ -1,4 +1,4 @@
-use super::{Collection, Id, RawValue, Source};
+use super::{Annotations, Collection, Id, RawValue, Source};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, json};
@@ -13,6 +13,9 @@ pub struct TestDef {
     pub description: String,
     /// # Sequential steps of this test.
     pub steps: Vec<TestStep>,
+    /// # Annotations of this test.
+    #[serde(default, skip_serializing_if = "Annotations::is_empty")]
+    pub annotations: Annotations,
     /// # Expected publication ID of this test within the control plane.
     /// When present, a publication of the test will fail if the
     /// last publication ID in the control plane doesn't match this value.
@@ -29,6 +32,7 @@ impl TestDef {
         Self {
             description: "An example test".to_string(),
             steps: vec![TestStep::example_ingest(), TestStep::example_verify()],
+            annotations: Annotations::default(),
             expect_pub_id: None,
             delete: false,
         }
@@ -79,6 +83,8 @@ pub enum TestStep {
     Ingest(TestStepIngest),
     /// Verify the contents of a collection match a set of document fixtures.
     Verify(TestStepVerify),
+    /// Evaluate document fixtures against the schemas of a collection at build time.
+    Expect(TestStepExpect),
 }
 
 impl TestStep {
@@ -146,6 +152,47 @@ impl TestStepVerify {
     }
 }
 
+/// An expectation test step is evaluated entirely at build time, without
+/// a data-plane. It validates document fixtures against the schemas of the
+/// named collection and, optionally, verifies their reduction by its key.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[schemars(example = "TestStepExpect::example")]
+pub struct TestStepExpect {
+    /// # Description of this test expectation.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// # Name of the collection whose schemas are evaluated.
+    pub collection: Collection,
+    /// # Documents to evaluate.
+    /// Documents are validated against the collection's write and read schemas.
+    pub documents: TestDocuments,
+    /// # Are documents expected to be invalid?
+    /// When true, each document is expected to fail validation
+    /// against the collection's write or read schema.
+    #[serde(default, skip_serializing_if = "super::is_false")]
+    pub invalid: bool,
+    /// # Expected documents after reduction.
+    /// When present, documents are reduced by the collection key using its
+    /// write schema, and reduced documents must match these fixtures in key
+    /// order. As with verify steps, properties present in a reduced document
+    /// but not in its fixture are ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reduced: Option<TestDocuments>,
+}
+
+impl TestStepExpect {
+    pub fn example() -> Self {
+        Self {
+            description: "Description of the expectation.".to_string(),
+            collection: Collection::example(),
+            documents: TestDocuments::example_inline(),
+            invalid: false,
+            reduced: Some(TestDocuments::example_inline()),
+        }
+    }
+}
+
 impl super::ModelDef for TestDef {
     fn sources(&self) -> impl Iterator<Item = &crate::Source> {
         self.steps.iter().filter_map(|step| {
@@ -212,6 +259,7 @@ impl<'de> serde::de::Visitor<'de> for TestDefVisitor {
         Ok(TestDef {
             description: String::new(),
             steps,
+            annotations: Annotations::default(),
             expect_pub_id: None,
             delete: false,
         })
@@ -223,11 +271,13 @@ impl<'de> serde::de::Visitor<'de> for TestDefVisitor {
     {
         let mut description = None;
         let mut steps = None;
+        let mut annotations = None;
         let mut expect_pub_id = None;
         let mut delete = None;
 
         const DESCRIPTION: &str = "description";
         const STEPS: &str = "steps";
+        const ANNOTATIONS: &str = "annotations";
         const EXPECT_PUB_ID: &str = "expectPubId";
         const DELETE: &str = "delete";
 
@@ -247,6 +297,12 @@ impl<'de> serde::de::Visitor<'de> for TestDefVisitor {
                     }
                     steps = Some(map.next_value()?);
                 }
+                ANNOTATIONS => {
+                    if annotations.is_some() {
+                        return Err(serde::de::Error::duplicate_field(ANNOTATIONS));
+                    }
+                    annotations = Some(map.next_value()?);
+                }
                 EXPECT_PUB_ID => {
                     if expect_pub_id.is_some() {
                         return Err(serde::de::Error::duplicate_field(EXPECT_PUB_ID));
@@ -262,7 +318,7 @@ impl<'de> serde::de::Visitor<'de> for TestDefVisitor {
                 _ => {
                     return Err(serde::de::Error::unknown_field(
                         key.as_str(),
-                        &[DESCRIPTION, STEPS, EXPECT_PUB_ID, DELETE],
+                        &[DESCRIPTION, STEPS, ANNOTATIONS, EXPECT_PUB_ID, DELETE],
                     ))
                 }
             }
@@ -271,6 +327,7 @@ impl<'de> serde::de::Visitor<'de> for TestDefVisitor {
         Ok(TestDef {
             description: description.unwrap_or_default(),
             steps: steps.ok_or_else(|| serde::de::Error::missing_field(STEPS))?,
+            annotations: annotations.unwrap_or_default(),
             expect_pub_id,
             delete: delete.unwrap_or_default(),
         })
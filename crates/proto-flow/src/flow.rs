@@ -318,6 +318,11 @@ pub mod collection_spec {
             /// Higher values imply higher priority.
             #[prost(uint32, tag = "4")]
             pub priority: u32,
+            /// Maximum rate, in bytes per second, at which this transform reads
+            /// its source collection. Zero means the read is not throttled.
+            /// Not yet enforced by the runtime.
+            #[prost(uint32, tag = "14")]
+            pub max_read_rate: u32,
             /// Number of seconds for which documents of this transformed are delayed
             /// while reading, relative to other documents (when back-filling) and the
             /// present wall-clock time (when tailing).
@@ -618,6 +623,11 @@ pub mod materialization_spec {
         /// Higher values imply higher priority.
         #[prost(uint32, tag = "9")]
         pub priority: u32,
+        /// Maximum rate, in bytes per second, at which this binding reads
+        /// its source collection. Zero means the read is not throttled.
+        /// Not yet enforced by the runtime.
+        #[prost(uint32, tag = "14")]
+        pub max_read_rate: u32,
         /// Resolved fields selected for materialization.
         #[prost(message, optional, tag = "4")]
         pub field_selection: ::core::option::Option<super::FieldSelection>,
@@ -1509,6 +1509,9 @@ impl serde::Serialize for collection_spec::derivation::Transform {
         if self.priority != 0 {
             len += 1;
         }
+        if self.max_read_rate != 0 {
+            len += 1;
+        }
         if self.read_delay_seconds != 0 {
             len += 1;
         }
@@ -1549,6 +1552,9 @@ impl serde::Serialize for collection_spec::derivation::Transform {
         if self.priority != 0 {
             struct_ser.serialize_field("priority", &self.priority)?;
         }
+        if self.max_read_rate != 0 {
+            struct_ser.serialize_field("maxReadRate", &self.max_read_rate)?;
+        }
         if self.read_delay_seconds != 0 {
             struct_ser.serialize_field("readDelaySeconds", &self.read_delay_seconds)?;
         }
@@ -1591,6 +1597,8 @@ impl<'de> serde::Deserialize<'de> for collection_spec::derivation::Transform {
             "partition_selector",
             "partitionSelector",
             "priority",
+            "max_read_rate",
+            "maxReadRate",
             "read_delay_seconds",
             "readDelaySeconds",
             "shuffle_key",
@@ -1616,6 +1624,7 @@ impl<'de> serde::Deserialize<'de> for collection_spec::derivation::Transform {
             Collection,
             PartitionSelector,
             Priority,
+            MaxReadRate,
             ReadDelaySeconds,
             ShuffleKey,
             ShuffleLambdaConfigJson,
@@ -1650,6 +1659,7 @@ impl<'de> serde::Deserialize<'de> for collection_spec::derivation::Transform {
                             "collection" => Ok(GeneratedField::Collection),
                             "partitionSelector" | "partition_selector" => Ok(GeneratedField::PartitionSelector),
                             "priority" => Ok(GeneratedField::Priority),
+                            "maxReadRate" | "max_read_rate" => Ok(GeneratedField::MaxReadRate),
                             "readDelaySeconds" | "read_delay_seconds" => Ok(GeneratedField::ReadDelaySeconds),
                             "shuffleKey" | "shuffle_key" => Ok(GeneratedField::ShuffleKey),
                             "shuffleLambdaConfig" | "shuffle_lambda_config_json" => Ok(GeneratedField::ShuffleLambdaConfigJson),
@@ -1682,6 +1692,7 @@ impl<'de> serde::Deserialize<'de> for collection_spec::derivation::Transform {
                 let mut collection__ = None;
                 let mut partition_selector__ = None;
                 let mut priority__ = None;
+                let mut max_read_rate__ = None;
                 let mut read_delay_seconds__ = None;
                 let mut shuffle_key__ = None;
                 let mut shuffle_lambda_config_json__ : Option<Box<serde_json::value::RawValue>> = None;
@@ -1719,6 +1730,14 @@ impl<'de> serde::Deserialize<'de> for collection_spec::derivation::Transform {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::MaxReadRate => {
+                            if max_read_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxReadRate"));
+                            }
+                            max_read_rate__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::ReadDelaySeconds => {
                             if read_delay_seconds__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("readDelaySeconds"));
@@ -1784,6 +1803,7 @@ impl<'de> serde::Deserialize<'de> for collection_spec::derivation::Transform {
                     collection: collection__,
                     partition_selector: partition_selector__,
                     priority: priority__.unwrap_or_default(),
+                    max_read_rate: max_read_rate__.unwrap_or_default(),
                     read_delay_seconds: read_delay_seconds__.unwrap_or_default(),
                     shuffle_key: shuffle_key__.unwrap_or_default(),
                     shuffle_lambda_config_json: shuffle_lambda_config_json__.map(|r| Box::<str>::from(r).into()).unwrap_or_default(),
@@ -3761,6 +3781,9 @@ impl serde::Serialize for materialization_spec::Binding {
         if self.priority != 0 {
             len += 1;
         }
+        if self.max_read_rate != 0 {
+            len += 1;
+        }
         if self.field_selection.is_some() {
             len += 1;
         }
@@ -3801,6 +3824,9 @@ impl serde::Serialize for materialization_spec::Binding {
         if self.priority != 0 {
             struct_ser.serialize_field("priority", &self.priority)?;
         }
+        if self.max_read_rate != 0 {
+            struct_ser.serialize_field("maxReadRate", &self.max_read_rate)?;
+        }
         if let Some(v) = self.field_selection.as_ref() {
             struct_ser.serialize_field("fieldSelection", v)?;
         }
@@ -3843,6 +3869,8 @@ impl<'de> serde::Deserialize<'de> for materialization_spec::Binding {
             "partition_selector",
             "partitionSelector",
             "priority",
+            "max_read_rate",
+            "maxReadRate",
             "field_selection",
             "fieldSelection",
             "delta_updates",
@@ -3867,6 +3895,7 @@ impl<'de> serde::Deserialize<'de> for materialization_spec::Binding {
             Collection,
             PartitionSelector,
             Priority,
+            MaxReadRate,
             FieldSelection,
             DeltaUpdates,
             DeprecatedShuffle,
@@ -3901,6 +3930,7 @@ impl<'de> serde::Deserialize<'de> for materialization_spec::Binding {
                             "collection" => Ok(GeneratedField::Collection),
                             "partitionSelector" | "partition_selector" => Ok(GeneratedField::PartitionSelector),
                             "priority" => Ok(GeneratedField::Priority),
+                            "maxReadRate" | "max_read_rate" => Ok(GeneratedField::MaxReadRate),
                             "fieldSelection" | "field_selection" => Ok(GeneratedField::FieldSelection),
                             "deltaUpdates" | "delta_updates" => Ok(GeneratedField::DeltaUpdates),
                             "deprecatedShuffle" | "deprecated_shuffle" => Ok(GeneratedField::DeprecatedShuffle),
@@ -3933,6 +3963,7 @@ impl<'de> serde::Deserialize<'de> for materialization_spec::Binding {
                 let mut collection__ = None;
                 let mut partition_selector__ = None;
                 let mut priority__ = None;
+                let mut max_read_rate__ = None;
                 let mut field_selection__ = None;
                 let mut delta_updates__ = None;
                 let mut deprecated_shuffle__ = None;
@@ -3975,6 +4006,14 @@ impl<'de> serde::Deserialize<'de> for materialization_spec::Binding {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::MaxReadRate => {
+                            if max_read_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxReadRate"));
+                            }
+                            max_read_rate__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::FieldSelection => {
                             if field_selection__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("fieldSelection"));
@@ -4033,6 +4072,7 @@ impl<'de> serde::Deserialize<'de> for materialization_spec::Binding {
                     collection: collection__,
                     partition_selector: partition_selector__,
                     priority: priority__.unwrap_or_default(),
+                    max_read_rate: max_read_rate__.unwrap_or_default(),
                     field_selection: field_selection__,
                     delta_updates: delta_updates__.unwrap_or_default(),
                     deprecated_shuffle: deprecated_shuffle__,
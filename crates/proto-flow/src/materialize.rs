@@ -286,6 +286,11 @@ pub mod response {
     pub struct Validated {
         #[prost(message, repeated, tag = "1")]
         pub bindings: ::prost::alloc::vec::Vec<validated::Binding>,
+        /// When set, the connector materializes into a strongly-typed destination
+        /// which degrades when a selected field is an object having unbounded
+        /// additional properties. Flow validation will then flag such fields.
+        #[prost(bool, tag = "2")]
+        pub requires_bounded_objects: bool,
     }
     /// Nested message and enum types in `Validated`.
     pub mod validated {
@@ -2836,10 +2836,16 @@ impl serde::Serialize for response::Validated {
         if !self.bindings.is_empty() {
             len += 1;
         }
+        if self.requires_bounded_objects {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("materialize.Response.Validated", len)?;
         if !self.bindings.is_empty() {
             struct_ser.serialize_field("bindings", &self.bindings)?;
         }
+        if self.requires_bounded_objects {
+            struct_ser.serialize_field("requiresBoundedObjects", &self.requires_bounded_objects)?;
+        }
         struct_ser.end()
     }
 }
@@ -2851,11 +2857,14 @@ impl<'de> serde::Deserialize<'de> for response::Validated {
     {
         const FIELDS: &[&str] = &[
             "bindings",
+            "requires_bounded_objects",
+            "requiresBoundedObjects",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Bindings,
+            RequiresBoundedObjects,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2878,6 +2887,7 @@ impl<'de> serde::Deserialize<'de> for response::Validated {
                     {
                         match value {
                             "bindings" => Ok(GeneratedField::Bindings),
+                            "requiresBoundedObjects" | "requires_bounded_objects" => Ok(GeneratedField::RequiresBoundedObjects),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2898,6 +2908,7 @@ impl<'de> serde::Deserialize<'de> for response::Validated {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut bindings__ = None;
+                let mut requires_bounded_objects__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Bindings => {
@@ -2906,10 +2917,17 @@ impl<'de> serde::Deserialize<'de> for response::Validated {
                             }
                             bindings__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::RequiresBoundedObjects => {
+                            if requires_bounded_objects__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requiresBoundedObjects"));
+                            }
+                            requires_bounded_objects__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(response::Validated {
                     bindings: bindings__.unwrap_or_default(),
+                    requires_bounded_objects: requires_bounded_objects__.unwrap_or_default(),
                 })
             }
         }
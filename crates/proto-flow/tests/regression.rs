@@ -219,6 +219,7 @@ fn ex_derivation_spec() -> flow::CollectionSpec {
             lambda_config_json: json!({"lambda": "config"}).to_string(),
             partition_selector: Some(ex_label_selector()),
             priority: 1,
+            max_read_rate: 0,
             read_delay_seconds: 14,
             read_only: true,
             shuffle_key: vec!["/shuffle".to_string(), "/key".to_string()],
@@ -268,6 +269,7 @@ fn ex_materialization_spec() -> flow::MaterializationSpec {
             collection: Some(ex_collection_spec()),
             partition_selector: Some(ex_label_selector()),
             priority: 3,
+            max_read_rate: 0,
             field_selection: Some(flow::FieldSelection {
                 document: "flow_document".to_string(),
                 field_config_json_map: ex_field_config(),
@@ -635,6 +637,7 @@ fn ex_materialize_response() -> materialize::Response {
                 .into(),
                 delta_updates: true,
             }],
+            requires_bounded_objects: false,
         }),
         applied: Some(materialize::response::Applied {
             action_description: "I did some stuff".to_string(),
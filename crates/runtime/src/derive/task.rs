@@ -108,6 +108,7 @@ impl Transform {
             collection,
             journal_read_suffix: _,
             lambda_config_json: _,
+            max_read_rate: _,
             name,
             not_after: _,
             not_before: _,
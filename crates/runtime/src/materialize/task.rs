@@ -100,6 +100,7 @@ impl Binding {
             deprecated_shuffle: _,
             field_selection,
             journal_read_suffix,
+            max_read_rate: _,
             not_after: _,
             not_before: _,
             partition_selector: _,
@@ -0,0 +1,336 @@
+use crate::markdown::{md_escape, Code};
+use anyhow::Context;
+use doc::{
+    shape::{location::Exists, Shape},
+    Schema, SchemaIndexBuilder,
+};
+use json::schema::{build::build_schema, types};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use url::Url;
+
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// Path to the previous JSON schema.
+    pub previous: PathBuf,
+    /// Path to the next JSON schema.
+    pub next: PathBuf,
+    /// JSON pointer of a key field of documents of the schema.
+    ///
+    /// Changes to key locations are held to a stricter standard, as any
+    /// change of their types or existence alters the identity of documents.
+    #[clap(short = 'k', long)]
+    pub key: Vec<String>,
+    /// Format of the generated report.
+    #[clap(long, value_enum, default_value_t = Format::Markdown)]
+    pub format: Format,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum Format {
+    Json,
+    Markdown,
+}
+
+pub fn run(args: Args) -> anyhow::Result<()> {
+    let Args {
+        previous,
+        next,
+        key,
+        format,
+    } = args;
+
+    let previous = load(&previous)?;
+    let next = load(&next)?;
+    let report = diff(&previous, &next, &key)?;
+
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        Format::Markdown => print!("{}", report.to_markdown()),
+    }
+    Ok(())
+}
+
+/// Compatibility classifies a change of a schema location, from the point of
+/// view of a reader (such as a materialization) of documents of the schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Compatibility {
+    /// The change introduces a new location.
+    Additive,
+    /// The location changed in a way that readers of the previous schema tolerate,
+    /// such as becoming nullable, optional, or narrower in its types.
+    Compatible,
+    /// The location changed in a way that readers of the previous schema
+    /// cannot tolerate, such as being removed, or taking on new types.
+    Breaking,
+}
+
+/// Change is a difference of a single location between two schemas.
+#[derive(Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    /// JSON pointer of the changed location.
+    pub ptr: String,
+    /// Whether the location is a pattern (additionalProperties or patternProperties) location.
+    pub pattern: bool,
+    /// Whether the location is a key field.
+    pub key: bool,
+    /// Compatibility of the change.
+    pub compatibility: Compatibility,
+    /// Human-readable description of the change.
+    pub detail: String,
+}
+
+/// Report of the differences between two schemas.
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    /// Whether no change is Breaking.
+    pub compatible: bool,
+    /// Changes ordered on their JSON pointer.
+    pub changes: Vec<Change>,
+}
+
+impl Report {
+    /// Render the Report as a Markdown table.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+
+        if self.changes.is_empty() {
+            out.push_str("Schemas have no differences.\n");
+            return out;
+        } else if self.compatible {
+            out.push_str("Schemas are compatible.\n\n");
+        } else {
+            out.push_str("Schemas have **breaking** changes.\n\n");
+        }
+
+        out.push_str("| Location | Key | Compatibility | Detail |\n");
+        out.push_str("|---|---|---|---|\n");
+
+        for change in &self.changes {
+            let ptr = Code(&change.ptr).to_string();
+            let ptr = if change.pattern {
+                format!("_{ptr}_")
+            } else {
+                ptr
+            };
+            let compatibility = match change.compatibility {
+                Compatibility::Additive => "Additive",
+                Compatibility::Compatible => "Compatible",
+                Compatibility::Breaking => "**Breaking**",
+            };
+            out.push_str(&format!(
+                "| {ptr} | {} | {compatibility} | {} |\n",
+                if change.key { "Yes" } else { "" },
+                md_escape(&change.detail),
+            ));
+        }
+        out
+    }
+}
+
+/// Diff the locations of `previous` and `next` schemas, having `key` pointers.
+pub fn diff(
+    previous: &serde_json::Value,
+    next: &serde_json::Value,
+    key: &[String],
+) -> anyhow::Result<Report> {
+    let previous = infer(previous).context("failed to build previous JSON schema")?;
+    let next = infer(next).context("failed to build next JSON schema")?;
+
+    let mut locations: BTreeMap<String, (Option<Location>, Option<Location>)> = BTreeMap::new();
+
+    for (ptr, pattern, shape, exists) in previous.locations() {
+        if exists.cannot() {
+            continue;
+        }
+        locations.entry(ptr.to_string()).or_default().0 = Some(Location {
+            pattern,
+            types: shape.type_,
+            exists,
+        });
+    }
+    for (ptr, pattern, shape, exists) in next.locations() {
+        if exists.cannot() {
+            continue;
+        }
+        locations.entry(ptr.to_string()).or_default().1 = Some(Location {
+            pattern,
+            types: shape.type_,
+            exists,
+        });
+    }
+
+    let mut changes = Vec::new();
+    for (ptr, (previous, next)) in locations {
+        let is_key = key.contains(&ptr);
+
+        changes.extend(
+            classify(previous, next, is_key).map(|(compatibility, detail)| Change {
+                pattern: previous.or(next).map(|l| l.pattern).unwrap_or_default(),
+                ptr,
+                key: is_key,
+                compatibility,
+                detail,
+            }),
+        );
+    }
+
+    Ok(Report {
+        compatible: changes
+            .iter()
+            .all(|c| c.compatibility != Compatibility::Breaking),
+        changes,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Location {
+    pattern: bool,
+    types: types::Set,
+    exists: Exists,
+}
+
+fn classify(
+    previous: Option<Location>,
+    next: Option<Location>,
+    is_key: bool,
+) -> Option<(Compatibility, String)> {
+    let (previous, next) = match (previous, next) {
+        (None, None) => return None,
+        (None, Some(next)) => {
+            let detail = format!("added with types [{}]", next.types.to_vec().join(", "));
+            // A new key location changes the identity of documents.
+            let compatibility = if is_key {
+                Compatibility::Breaking
+            } else {
+                Compatibility::Additive
+            };
+            return Some((compatibility, detail));
+        }
+        (Some(_), None) => return Some((Compatibility::Breaking, "removed".to_string())),
+        (Some(previous), Some(next)) => (previous, next),
+    };
+
+    let mut details = Vec::new();
+    let mut breaking = false;
+
+    if previous.types != next.types {
+        let added = next.types - previous.types;
+        let why = format!(
+            "types changed from [{}] to [{}]",
+            previous.types.to_vec().join(", "),
+            next.types.to_vec().join(", ")
+        );
+
+        // Readers tolerate narrowed types, or a location becoming nullable.
+        breaking |= is_key || (added != types::INVALID && added != types::NULL);
+        details.push(why);
+    }
+
+    if previous.exists.must() != next.exists.must() {
+        let why = if next.exists.must() {
+            "became required".to_string()
+        } else {
+            "is no longer required".to_string()
+        };
+        // Key locations must always exist.
+        breaking |= is_key && !next.exists.must();
+        details.push(why);
+    }
+
+    if details.is_empty() {
+        None
+    } else if breaking {
+        Some((Compatibility::Breaking, details.join("; ")))
+    } else {
+        Some((Compatibility::Compatible, details.join("; ")))
+    }
+}
+
+fn load(path: &PathBuf) -> anyhow::Result<serde_json::Value> {
+    let file =
+        std::fs::File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    serde_json::from_reader(std::io::BufReader::new(file))
+        .with_context(|| format!("failed to parse {}", path.display()))
+}
+
+pub(crate) fn infer(dom: &serde_json::Value) -> anyhow::Result<Shape> {
+    let curi = Url::parse("https://example/schema").unwrap();
+    let root: Schema = build_schema(curi, dom)?;
+
+    let mut index = SchemaIndexBuilder::new();
+    index.add(&root)?;
+    index.verify_references()?;
+    let index = index.into_index();
+
+    Ok(Shape::infer(&root, &index))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_diff_classification() {
+        let previous = json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer"},
+                "name": {"type": "string"},
+                "count": {"type": "integer"},
+                "gone": {"type": "boolean"},
+                "narrowed": {"type": ["string", "integer"]},
+            },
+            "required": ["id", "name"],
+        });
+        let next = json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "string"},
+                "name": {"type": ["string", "null"]},
+                "count": {"type": ["integer", "string"]},
+                "narrowed": {"type": "string"},
+                "added": {"type": "number"},
+            },
+            "required": ["id"],
+        });
+
+        let report = diff(&previous, &next, &["/id".to_string()]).unwrap();
+        assert!(!report.compatible);
+
+        let summary: Vec<_> = report
+            .changes
+            .iter()
+            .map(|c| (c.ptr.as_str(), c.key, c.compatibility))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("/added", false, Compatibility::Additive),
+                ("/count", false, Compatibility::Breaking),
+                ("/gone", false, Compatibility::Breaking),
+                ("/id", true, Compatibility::Breaking),
+                ("/name", false, Compatibility::Compatible),
+                ("/narrowed", false, Compatibility::Compatible),
+            ]
+        );
+        assert_eq!(
+            report.changes[4].detail,
+            "types changed from [string] to [null, string]; is no longer required"
+        );
+
+        let md = report.to_markdown();
+        assert!(md.starts_with("Schemas have **breaking** changes."));
+        assert!(md.contains("| `/id` | Yes | **Breaking** |"));
+
+        // Identical schemas have no differences.
+        let report = diff(&previous, &previous, &[]).unwrap();
+        assert!(report.compatible);
+        assert!(report.changes.is_empty());
+    }
+}
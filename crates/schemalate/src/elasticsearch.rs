@@ -0,0 +1,243 @@
+use anyhow::Context;
+use doc::shape::Shape;
+use json::schema::{formats::Format, types};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// Path to a JSON file of mapping overrides.
+    #[clap(short = 'o', long)]
+    pub overrides: Option<PathBuf>,
+}
+
+/// Overrides tailor the generated mappings of a schema.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Overrides {
+    /// Strings having a `maxLength` at or below this length are mapped as
+    /// `keyword`. Longer or unbounded strings are mapped as `text`.
+    #[serde(default = "default_keyword_max_length")]
+    pub keyword_max_length: u32,
+    /// Mappings of specific locations, keyed on their JSON pointer, which
+    /// replace the generated mapping. A `null` mapping omits the location.
+    /// Locations within arrays use the pointer of the array itself.
+    #[serde(default)]
+    pub fields: BTreeMap<String, Value>,
+}
+
+fn default_keyword_max_length() -> u32 {
+    256
+}
+
+impl Default for Overrides {
+    fn default() -> Self {
+        Self {
+            keyword_max_length: default_keyword_max_length(),
+            fields: BTreeMap::new(),
+        }
+    }
+}
+
+pub fn run(args: Args) -> anyhow::Result<()> {
+    let overrides = match &args.overrides {
+        Some(path) => {
+            let content = std::fs::read(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            serde_json::from_slice(&content)
+                .with_context(|| format!("failed to parse {}", path.display()))?
+        }
+        None => Overrides::default(),
+    };
+    let dom: Value = serde_json::from_reader(std::io::stdin())?;
+    let shape = crate::diff::infer(&dom).context("failed to build JSON schema")?;
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&to_mappings(&shape, &overrides))?
+    );
+    Ok(())
+}
+
+/// Map the document `shape` of a collection into Elasticsearch index mappings.
+pub fn to_mappings(shape: &Shape, overrides: &Overrides) -> Value {
+    json!({
+        "mappings": {
+            "properties": properties(shape, "", overrides),
+        }
+    })
+}
+
+fn properties(shape: &Shape, ptr: &str, overrides: &Overrides) -> Map<String, Value> {
+    let mut out = Map::new();
+
+    for prop in &shape.object.properties {
+        let ptr = format!("{ptr}/{}", prop.name.replace('~', "~0").replace('/', "~1"));
+
+        if let Some(mapping) = field(&prop.shape, &ptr, overrides) {
+            out.insert(prop.name.to_string(), mapping);
+        }
+    }
+    out
+}
+
+fn field(shape: &Shape, ptr: &str, overrides: &Overrides) -> Option<Value> {
+    if let Some(mapping) = overrides.fields.get(ptr) {
+        return (!mapping.is_null()).then(|| mapping.clone());
+    }
+    let type_ = shape.type_ - types::NULL;
+
+    let mapping = if type_ == types::INVALID {
+        return None; // Location is always null, or cannot exist.
+    } else if type_ == types::OBJECT {
+        json!({"properties": properties(shape, ptr, overrides)})
+    } else if type_ == types::ARRAY {
+        // Elasticsearch has no array type. Any field may hold multiple values,
+        // and arrays of objects are indexed as `nested` documents.
+        let items = shape
+            .array
+            .tuple
+            .iter()
+            .cloned()
+            .chain(shape.array.additional_items.as_deref().cloned())
+            .reduce(Shape::union)?;
+
+        let mut mapping = field(&items, ptr, overrides)?;
+        if items.type_ - types::NULL == types::OBJECT {
+            mapping["type"] = json!("nested");
+        }
+        mapping
+    } else if type_ == types::STRING {
+        string(shape, overrides)
+    } else if type_ == types::BOOLEAN {
+        json!({"type": "boolean"})
+    } else if type_ == types::INTEGER {
+        json!({"type": "long"})
+    } else if type_ - types::INT_OR_FRAC == types::INVALID {
+        json!({"type": "double"})
+    } else if type_.overlaps(types::OBJECT | types::ARRAY) {
+        // Structured values of mixed types are stored, but not indexed.
+        json!({"type": "object", "enabled": false})
+    } else {
+        // Scalars of mixed types are indexed by their string representation.
+        json!({"type": "keyword", "ignore_above": overrides.keyword_max_length})
+    };
+
+    Some(mapping)
+}
+
+fn string(shape: &Shape, overrides: &Overrides) -> Value {
+    match shape.string.format {
+        Some(Format::Date) | Some(Format::DateTime) => return json!({"type": "date"}),
+        Some(Format::Ipv4) | Some(Format::Ipv6) => return json!({"type": "ip"}),
+        Some(Format::Integer) => return json!({"type": "long"}),
+        Some(Format::Number) => return json!({"type": "double"}),
+        Some(
+            Format::Email
+            | Format::Hostname
+            | Format::Uuid
+            | Format::Uri
+            | Format::Iri
+            | Format::Macaddr
+            | Format::Macaddr8,
+        ) => return json!({"type": "keyword"}),
+        _ => (),
+    }
+
+    if matches!(shape.string.max_length, Some(max) if max <= overrides.keyword_max_length)
+        || shape.enum_.is_some()
+    {
+        json!({"type": "keyword"})
+    } else {
+        // Also index a `keyword` sub-field for sorting and aggregations,
+        // ignoring values which are too long to be meaningfully compared.
+        json!({
+            "type": "text",
+            "fields": {
+                "keyword": {"type": "keyword", "ignore_above": overrides.keyword_max_length},
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_elasticsearch_mappings() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer"},
+                "code": {"type": "string", "maxLength": 16},
+                "status": {"type": "string", "enum": ["open", "closed"]},
+                "body": {"type": "string"},
+                "created": {"type": "string", "format": "date-time"},
+                "addr": {"type": ["string", "null"], "format": "ipv4"},
+                "price": {"type": "number"},
+                "total": {"type": "string", "format": "number"},
+                "flag": {"type": "boolean"},
+                "tags": {"type": "array", "items": {"type": "string", "maxLength": 32}},
+                "lines": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {"sku": {"type": "string", "format": "uuid"}},
+                    },
+                },
+                "meta": {
+                    "type": "object",
+                    "properties": {"note": {"type": "string"}, "a/b": {"type": "integer"}},
+                },
+                "mixed": {"type": ["string", "integer"]},
+                "blob": {"type": ["object", "string"]},
+                "nothing": {"type": "null"},
+            },
+        });
+        let shape = crate::diff::infer(&schema).unwrap();
+
+        let overrides: Overrides = serde_json::from_value(json!({
+            "keywordMaxLength": 64,
+            "fields": {
+                "/meta/a~1b": {"type": "integer"},
+                "/flag": null,
+            },
+        }))
+        .unwrap();
+
+        let mappings = to_mappings(&shape, &overrides);
+        let props = &mappings["mappings"]["properties"];
+
+        assert_eq!(props["id"], json!({"type": "long"}));
+        assert_eq!(props["code"], json!({"type": "keyword"}));
+        assert_eq!(props["status"], json!({"type": "keyword"}));
+        assert_eq!(
+            props["body"],
+            json!({"type": "text", "fields": {"keyword": {"type": "keyword", "ignore_above": 64}}})
+        );
+        assert_eq!(props["created"], json!({"type": "date"}));
+        assert_eq!(props["addr"], json!({"type": "ip"}));
+        assert_eq!(props["price"], json!({"type": "double"}));
+        assert_eq!(props["total"], json!({"type": "double"}));
+        assert_eq!(props["tags"], json!({"type": "keyword"}));
+        assert_eq!(
+            props["lines"],
+            json!({"type": "nested", "properties": {"sku": {"type": "keyword"}}})
+        );
+        assert_eq!(
+            props["meta"]["properties"]["a/b"],
+            json!({"type": "integer"})
+        );
+        assert_eq!(
+            props["mixed"],
+            json!({"type": "keyword", "ignore_above": 64})
+        );
+        assert_eq!(props["blob"], json!({"type": "object", "enabled": false}));
+
+        let props = props.as_object().unwrap();
+        assert!(!props.contains_key("flag"));
+        assert!(!props.contains_key("nothing"));
+    }
+}
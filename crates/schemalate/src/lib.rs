@@ -1,6 +1,12 @@
 //! JSON Schema + Translate = Schemalate
 //! Contains modules for generating various things from JSON schemas.
 
+/// Diffs JSON schemas, classifying the compatibility of each change.
+pub mod diff;
+
+/// Generates Elasticsearch index mappings of a collection schema.
+pub mod elasticsearch;
+
 /// Generates Markdown documentation of the fields in a schema.
 pub mod markdown;
 
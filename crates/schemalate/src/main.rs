@@ -21,6 +21,10 @@ enum Subcommand {
     Markdown(schemalate::markdown::Args),
     // Generates a Firebolt table schema
     FireboltSchema(schemalate::firebolt::Args),
+    /// Diffs two JSON schemas, classifying each change as additive, compatible, or breaking.
+    Diff(schemalate::diff::Args),
+    /// Generates Elasticsearch index mappings of a collection schema.
+    Elasticsearch(schemalate::elasticsearch::Args),
 }
 
 fn main() -> Result<(), anyhow::Error> {
@@ -36,6 +40,8 @@ fn run_subcommand(subcommand: Subcommand) -> Result<(), anyhow::Error> {
     let result = match subcommand {
         Subcommand::Markdown(md_args) => schemalate::markdown::run(md_args),
         Subcommand::FireboltSchema(fb_args) => schemalate::firebolt::run(fb_args),
+        Subcommand::Diff(diff_args) => schemalate::diff::run(diff_args),
+        Subcommand::Elasticsearch(es_args) => schemalate::elasticsearch::run(es_args),
     };
 
     if let Err(err) = result.as_ref() {
@@ -93,7 +93,7 @@ fn surround_if<T: Display>(surround: bool, with: &'static str, inner: T) -> Surr
 }
 
 /// Wrapper around a string to be formatted as a markdown code block
-struct Code<'a>(&'a str);
+pub(crate) struct Code<'a>(pub &'a str);
 impl<'a> Display for Code<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // An empty pair of backtics ends up rendering as an empty pair of backtics, which looks
@@ -141,7 +141,7 @@ impl Display for Repeat {
 
 // md_escape aggressively escapes any characters which have
 // significance for either Markdown or HTML.
-fn md_escape(s: &str) -> String {
+pub(crate) fn md_escape(s: &str) -> String {
     let mut out = String::new();
     for c in s.chars() {
         match c {
@@ -261,9 +261,12 @@ fn indirect_collection(
         write_schema,
         read_schema,
         key: _,
+        key_migration: _,
         projections: _,
         journals: _,
+        frozen: _,
         derive,
+        annotations: _,
         expect_pub_id: _,
         delete: _,
     } = model;
@@ -317,6 +320,7 @@ fn indirect_derivation(
         transforms,
         shuffle_key_types: _,
         shards: _,
+        allow_cross_plane_read: _,
     } = derivation;
     let mut is_sql = false;
 
@@ -540,19 +544,22 @@ fn indirect_test(
     let base = base_name(test);
 
     for (index, step) in model.steps.iter_mut().enumerate() {
-        let documents = match step {
-            models::TestStep::Ingest(models::TestStepIngest { documents, .. })
-            | models::TestStep::Verify(models::TestStepVerify { documents, .. }) => documents,
-        };
-        indirect_dom(
-            Scope::new(scope).push_item(index).push_prop("documents"),
-            documents,
-            ContentType::Config,
-            format!("{base}.step.{index}"),
-            imports,
-            resources,
-            threshold,
-        );
+        for (prop, documents) in super::test_step_documents_mut(step) {
+            // Preserve historical file names of step `documents`.
+            let filename = match prop {
+                "documents" => format!("{base}.step.{index}"),
+                _ => format!("{base}.step.{index}.{prop}"),
+            };
+            indirect_dom(
+                Scope::new(scope).push_item(index).push_prop(prop),
+                documents,
+                ContentType::Config,
+                filename,
+                imports,
+                resources,
+                threshold,
+            );
+        }
     }
 }
 
@@ -92,9 +92,12 @@ fn inline_collection(
         write_schema,
         read_schema,
         key: _,
+        key_migration: _,
         projections: _,
         journals: _,
+        frozen: _,
         derive,
+        annotations: _,
         expect_pub_id: _,
         delete: _,
     } = model;
@@ -139,6 +142,7 @@ fn inline_derivation(
         transforms,
         shuffle_key_types: _,
         shards: _,
+        allow_cross_plane_read: _,
     } = derivation;
 
     match using {
@@ -238,6 +242,8 @@ fn inline_materialization(
         endpoint,
         bindings,
         shards: _,
+        allow_cross_plane_read: _,
+        annotations: _,
         expect_pub_id: _,
         delete: _,
         on_incompatible_schema_change: _,
@@ -300,16 +306,14 @@ fn inline_test(
     resources: &[tables::Resource],
 ) {
     for (index, step) in model.steps.iter_mut().enumerate() {
-        let documents = match step {
-            models::TestStep::Ingest(models::TestStepIngest { documents, .. })
-            | models::TestStep::Verify(models::TestStepVerify { documents, .. }) => documents,
-        };
-        inline_config(
-            Scope::new(scope).push_item(index).push_prop("documents"),
-            documents,
-            imports,
-            resources,
-        );
+        for (prop, documents) in super::test_step_documents_mut(step) {
+            inline_config(
+                Scope::new(scope).push_item(index).push_prop(prop),
+                documents,
+                imports,
+                resources,
+            );
+        }
     }
 }
 
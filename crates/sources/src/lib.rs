@@ -53,3 +53,35 @@ impl Format {
         buf
     }
 }
+
+// Document fixtures of a test step, and the property of the step which holds each.
+fn test_step_documents(step: &models::TestStep) -> Vec<(&'static str, &models::TestDocuments)> {
+    match step {
+        models::TestStep::Ingest(models::TestStepIngest { documents, .. })
+        | models::TestStep::Verify(models::TestStepVerify { documents, .. }) => {
+            vec![("documents", documents)]
+        }
+        models::TestStep::Expect(models::TestStepExpect {
+            documents, reduced, ..
+        }) => std::iter::once(("documents", documents))
+            .chain(reduced.as_ref().map(|reduced| ("reduced", reduced)))
+            .collect(),
+    }
+}
+
+// Mutable variant of `test_step_documents`.
+fn test_step_documents_mut(
+    step: &mut models::TestStep,
+) -> Vec<(&'static str, &mut models::TestDocuments)> {
+    match step {
+        models::TestStep::Ingest(models::TestStepIngest { documents, .. })
+        | models::TestStep::Verify(models::TestStepVerify { documents, .. }) => {
+            vec![("documents", documents)]
+        }
+        models::TestStep::Expect(models::TestStepExpect {
+            documents, reduced, ..
+        }) => std::iter::once(("documents", documents))
+            .chain(reduced.as_mut().map(|reduced| ("reduced", reduced)))
+            .collect(),
+    }
+}
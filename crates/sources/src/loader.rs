@@ -1,4 +1,4 @@
-use super::Scope;
+use super::{test_step_documents, Scope};
 use doc::Schema as CompiledSchema;
 use futures::future::{BoxFuture, FutureExt};
 use json::schema::{self, build::build_schema};
@@ -81,8 +81,8 @@ impl<F: Fetcher> Loader<F> {
         content_type: flow::ContentType,
     ) {
         if resource.fragment().is_some() {
-            self.tables_mut().errors.insert_row(
-                &scope.flatten(),
+            self.tables_mut().errors.insert_scoped(
+                scope.flatten(),
                 anyhow::anyhow!(LoadError::Fetch {
                     uri: resource.clone(),
                     detail: LoadError::ResourceWithFragment.into(),
@@ -106,8 +106,8 @@ impl<F: Fetcher> Loader<F> {
                     .await
             }
             Err(err) => {
-                self.tables_mut().errors.insert_row(
-                    &scope.flatten(),
+                self.tables_mut().errors.insert_scoped(
+                    scope.flatten(),
                     anyhow::anyhow!(LoadError::Fetch {
                         uri: resource.clone(),
                         detail: err,
@@ -170,10 +170,9 @@ impl<F: Fetcher> Loader<F> {
             } else if let Ok(content) = std::str::from_utf8(&content) {
                 RawValue::from_string(serde_json::to_string(&content).unwrap()).unwrap()
             } else {
-                self.tables_mut().errors.insert_row(
-                    &scope.flatten(),
-                    anyhow::anyhow!(LoadError::ResourceNotUTF8),
-                );
+                self.tables_mut()
+                    .errors
+                    .insert_scoped(scope.flatten(), anyhow::anyhow!(LoadError::ResourceNotUTF8));
                 return None;
             };
 
@@ -742,15 +741,12 @@ impl<F: Fetcher> Loader<F> {
         let mut tasks = Vec::new();
 
         for (index, test_step) in spec.steps.iter().enumerate() {
-            let documents = match test_step {
-                models::TestStep::Ingest(models::TestStepIngest { documents, .. })
-                | models::TestStep::Verify(models::TestStepVerify { documents, .. }) => documents,
-            };
-
-            tasks.push(async move {
-                self.load_test_documents(scope.push_item(index).push_prop("documents"), documents)
-                    .await
-            });
+            for (prop, documents) in test_step_documents(test_step) {
+                tasks.push(async move {
+                    self.load_test_documents(scope.push_item(index).push_prop(prop), documents)
+                        .await
+                });
+            }
         }
 
         let _: Vec<()> = futures::future::join_all(tasks.into_iter()).await;
@@ -792,7 +788,7 @@ impl<F: Fetcher> Loader<F> {
             Err(err) => {
                 self.tables_mut()
                     .errors
-                    .insert_row(scope.flatten(), anyhow::anyhow!(err.into()));
+                    .insert_scoped(scope.flatten(), anyhow::anyhow!(err.into()));
                 None
             }
         }
@@ -0,0 +1,247 @@
+use anyhow::Context;
+use sha2::Digest;
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Name of the archive entry holding the manifest.
+const MANIFEST: &str = "manifest.json";
+/// Name of the archive entry holding the build database.
+const BUILD_DB: &str = "build.db";
+/// File extension of archives.
+const EXTENSION: &str = "tar";
+
+/// Manifest of a build archive, which records SHA-256 content hashes of its
+/// entries and of the build resources within its database. An archive is
+/// addressed by the hash of its manifest, which transitively covers all of
+/// its content.
+#[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Manifest {
+    /// Content hashes of archive entries, keyed on entry name.
+    pub entries: BTreeMap<String, String>,
+    /// Content hashes of build resources, keyed on resource URL.
+    pub resources: BTreeMap<String, String>,
+}
+
+/// Write the build database at `db_path` into a content-addressed tarball
+/// within directory `dir`, returning the path of the archive.
+/// The manifest is the first entry of the archive, followed by the database.
+/// The archive file is named by its content address, and writing an archive
+/// which already exists is a no-op.
+pub fn write(db_path: &Path, dir: &Path) -> anyhow::Result<PathBuf> {
+    let db = rusqlite::Connection::open(db_path).context("failed to open build database")?;
+    let manifest = Manifest {
+        entries: [(
+            BUILD_DB.to_string(),
+            hash_copy(open(db_path)?, &mut std::io::sink())?,
+        )]
+        .into(),
+        resources: resource_hashes(&db).context("failed to hash build resources")?,
+    };
+    std::mem::drop(db);
+
+    let manifest = serde_json::to_vec_pretty(&manifest).unwrap();
+    let address = hash_bytes(&manifest);
+    let path = dir.join(format!("{address}.{EXTENSION}"));
+
+    if path.exists() {
+        return Ok(path);
+    }
+    // Write to a temporary file, and then rename into place so that
+    // an archive having its content address is always complete.
+    let tmp_path = dir.join(format!(".{address}.{EXTENSION}.tmp"));
+    let file = std::fs::File::create(&tmp_path)
+        .with_context(|| format!("failed to create {tmp_path:?}"))?;
+
+    let db_file = open(db_path)?;
+    let db_size = db_file.metadata()?.len();
+    let mut tar = tar::Builder::new(file);
+
+    append(
+        &mut tar,
+        MANIFEST,
+        manifest.len() as u64,
+        manifest.as_slice(),
+    )?;
+    append(&mut tar, BUILD_DB, db_size, db_file)?;
+    tar.into_inner()?.sync_all()?;
+
+    std::fs::rename(&tmp_path, &path)
+        .with_context(|| format!("failed to rename {tmp_path:?} to {path:?}"))?;
+
+    Ok(path)
+}
+
+/// Read and verify the build archive at `path`, extracting its build
+/// database to `db_path`. Verification fails if the archive doesn't match
+/// its content address, or if any entry or resource doesn't match its hash.
+pub fn read(path: &Path, db_path: &Path) -> anyhow::Result<Manifest> {
+    let mut tar = tar::Archive::new(open(path)?);
+    let mut entries = tar
+        .entries()
+        .with_context(|| format!("failed to read build archive {path:?}"))?;
+
+    let mut manifest = Vec::new();
+    match entries.next() {
+        Some(entry) => {
+            let mut entry = entry?;
+            if entry.path()?.as_ref() != Path::new(MANIFEST) {
+                anyhow::bail!("build archive {path:?} doesn't begin with its {MANIFEST}");
+            }
+            entry.read_to_end(&mut manifest)?;
+        }
+        None => anyhow::bail!("build archive {path:?} is empty"),
+    }
+
+    let address = hash_bytes(&manifest);
+    let expect = path.file_stem().and_then(|stem| stem.to_str());
+    if expect != Some(address.as_str()) {
+        anyhow::bail!("build archive {path:?} doesn't match its content address {address}");
+    }
+    let manifest: Manifest =
+        serde_json::from_slice(&manifest).context("failed to parse archive manifest")?;
+
+    // Hash each remaining entry, extracting the build database as we go.
+    let mut hashes = BTreeMap::new();
+    for entry in entries {
+        let mut entry = entry?;
+        let name = entry.path()?.to_string_lossy().into_owned();
+
+        let hash = if name == BUILD_DB {
+            let mut file = std::fs::File::create(db_path)
+                .with_context(|| format!("failed to create {db_path:?}"))?;
+            hash_copy(&mut entry, &mut file)?
+        } else {
+            hash_copy(&mut entry, &mut std::io::sink())?
+        };
+        hashes.insert(name, hash);
+    }
+
+    if !hashes.contains_key(BUILD_DB) {
+        anyhow::bail!("build archive {path:?} has no {BUILD_DB} entry");
+    }
+    for (name, expect) in &manifest.entries {
+        match hashes.get(name) {
+            Some(actual) if actual == expect => (),
+            Some(actual) => {
+                anyhow::bail!("archive entry {name} has hash {actual}, but expected {expect}")
+            }
+            None => anyhow::bail!("build archive {path:?} is missing entry {name}"),
+        }
+    }
+
+    let db = rusqlite::Connection::open(db_path).context("failed to open build database")?;
+    let resources = resource_hashes(&db).context("failed to hash build resources")?;
+
+    if resources != manifest.resources {
+        anyhow::bail!("build resources don't match the hashes of the archive manifest");
+    }
+    Ok(manifest)
+}
+
+// Hash the content of each build resource, if the database has any.
+fn resource_hashes(db: &rusqlite::Connection) -> rusqlite::Result<BTreeMap<String, String>> {
+    let mut out = BTreeMap::new();
+
+    let exists: bool = db.query_row(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'resources');",
+        [],
+        |row| row.get(0),
+    )?;
+    if !exists {
+        return Ok(out);
+    }
+
+    let mut stmt = db.prepare("SELECT resource, content FROM resources;")?;
+    let mut rows = stmt.query([])?;
+
+    while let Some(row) = rows.next()? {
+        let resource: String = row.get(0)?;
+        let content: Vec<u8> = row.get(1)?;
+        out.insert(resource, hash_bytes(&content));
+    }
+    Ok(out)
+}
+
+fn open(path: &Path) -> anyhow::Result<std::fs::File> {
+    std::fs::File::open(path).with_context(|| format!("failed to open {path:?}"))
+}
+
+// Append a regular file entry `name` of `size` bytes read from `data`.
+// Entries have fixed metadata, so that an archive is a function of its content.
+fn append(
+    tar: &mut tar::Builder<std::fs::File>,
+    name: &str,
+    size: u64,
+    data: impl Read,
+) -> std::io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(size);
+    header.set_mode(0o644);
+    header.set_mtime(0);
+    tar.append_data(&mut header, name, data)
+}
+
+fn hash_bytes(b: &[u8]) -> String {
+    format!("{:x}", sha2::Sha256::digest(b))
+}
+
+// Copy `r` into `w`, returning the hash of the copied content.
+fn hash_copy(mut r: impl Read, w: &mut impl Write) -> std::io::Result<String> {
+    let mut hasher = sha2::Sha256::new();
+    let mut buf = vec![0; 1 << 16];
+
+    loop {
+        match r.read(&mut buf)? {
+            0 => break,
+            n => {
+                hasher.update(&buf[..n]);
+                w.write_all(&buf[..n])?;
+            }
+        }
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SqlTableObj;
+
+    #[test]
+    fn test_write_and_read_archive() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("build.db");
+
+        let mut resources = crate::Resources::new();
+        resources.insert_row(
+            url::Url::parse("file:///flow.yaml").unwrap(),
+            proto_flow::flow::ContentType::Catalog,
+            bytes::Bytes::from_static(b"collections: {}"),
+            models::RawValue::from_str("{\"collections\":{}}").unwrap(),
+        );
+        let db = rusqlite::Connection::open(&db_path).unwrap();
+        crate::persist_tables(&db, &[&resources as &dyn SqlTableObj]).unwrap();
+        std::mem::drop(db);
+
+        let path = write(&db_path, dir.path()).unwrap();
+        // Writes of the same content produce the same archive.
+        assert_eq!(write(&db_path, dir.path()).unwrap(), path);
+
+        let manifest = read(&path, &dir.path().join("extracted.db")).unwrap();
+        assert_eq!(
+            manifest.resources.keys().collect::<Vec<_>>(),
+            vec!["file:///flow.yaml"]
+        );
+        assert_eq!(manifest.entries[BUILD_DB].len(), 64); // Hex SHA-256.
+
+        // An archive which doesn't match its content address is rejected.
+        let moved = dir.path().join(format!("0123.{EXTENSION}"));
+        std::fs::rename(&path, &moved).unwrap();
+
+        let err = read(&moved, &dir.path().join("extracted.db")).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("doesn't match its content address"));
+    }
+}
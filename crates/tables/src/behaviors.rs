@@ -201,6 +201,12 @@ impl super::StorageMapping {
     }
 }
 
+impl super::Prefix {
+    pub fn scope(&self) -> url::Url {
+        crate::synthetic_scope("storageMapping", &self.prefix)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{Import, Imports, RoleGrant, RoleGrants, UserGrant, UserGrants};
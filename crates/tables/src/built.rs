@@ -1,10 +1,17 @@
-use crate::{BuiltCaptures, BuiltCollections, BuiltMaterializations, BuiltTests, Errors};
+use crate::{
+    BuiltCaptures, BuiltCollections, BuiltMaterializations, BuiltTests, ConnectorSpecs, Errors,
+};
 
 /// BuiltRow is a common trait of rows reflecting built specifications.
 pub trait BuiltRow: crate::Row {
     type ModelDef: models::ModelDef;
     type Validated;
     type BuiltSpec;
+    type LiveRow: crate::LiveRow<
+        Key = Self::Key,
+        ModelDef = Self::ModelDef,
+        BuiltSpec = Self::BuiltSpec,
+    >;
 
     // Build a new BuiltRow from its parts.
     fn new(
@@ -48,6 +55,13 @@ pub trait BuiltRow: crate::Row {
     /// updating the model.
     fn is_touch(&self) -> bool;
 
+    /// Convert this BuiltRow into the LiveRow which results from its publication
+    /// as `pub_id` and `build_id`, or None if the specification is being deleted.
+    /// Touched specifications retain their `expect_pub_id` as their last publication,
+    /// and passed-through specifications are unchanged from their live specification.
+    /// Implementations de-structure their rows, so that added columns must be mapped.
+    fn into_live(self, pub_id: models::Id, build_id: models::Id) -> Option<Self::LiveRow>;
+
     /// Is this specification untouched (passed through) from its live specification?
     fn is_passthrough(&self) -> bool {
         !self.expect_pub_id().is_zero() && self.previous_spec().is_none() && !self.is_touch()
@@ -70,6 +84,7 @@ impl BuiltRow for crate::BuiltCapture {
     type ModelDef = models::CaptureDef;
     type Validated = proto_flow::capture::response::Validated;
     type BuiltSpec = proto_flow::flow::CaptureSpec;
+    type LiveRow = crate::LiveCapture;
 
     fn new(
         catalog_name: Self::Key,
@@ -98,6 +113,8 @@ impl BuiltRow for crate::BuiltCapture {
             previous_spec,
             is_touch,
             dependency_hash,
+            documentation_url: None,
+            oauth2: None,
         }
     }
     fn catalog_name(&self) -> &Self::Key {
@@ -133,12 +150,43 @@ impl BuiltRow for crate::BuiltCapture {
     fn is_touch(&self) -> bool {
         self.is_touch
     }
+    fn into_live(self, pub_id: models::Id, build_id: models::Id) -> Option<Self::LiveRow> {
+        let (last_pub_id, last_build_id) = live_ids(&self, pub_id, build_id);
+        let Self {
+            capture,
+            scope: _,
+            control_id,
+            data_plane_id,
+            expect_pub_id: _,
+            expect_build_id: _,
+            model,
+            validated: _,
+            spec,
+            previous_spec: _,
+            is_touch: _,
+            dependency_hash,
+            documentation_url: _,
+            oauth2: _,
+        } = self;
+
+        Some(crate::LiveCapture {
+            capture,
+            control_id,
+            data_plane_id,
+            last_pub_id,
+            last_build_id,
+            model: model?,
+            spec: spec?,
+            dependency_hash,
+        })
+    }
 }
 
 impl BuiltRow for crate::BuiltCollection {
     type ModelDef = models::CollectionDef;
     type Validated = proto_flow::derive::response::Validated;
     type BuiltSpec = proto_flow::flow::CollectionSpec;
+    type LiveRow = crate::LiveCollection;
 
     fn new(
         catalog_name: Self::Key,
@@ -167,6 +215,10 @@ impl BuiltRow for crate::BuiltCollection {
             previous_spec,
             is_touch,
             dependency_hash,
+            documentation_url: None,
+            oauth2: None,
+            key_migration: None,
+            lineage: Vec::new(),
         }
     }
     fn catalog_name(&self) -> &Self::Key {
@@ -202,12 +254,45 @@ impl BuiltRow for crate::BuiltCollection {
     fn is_touch(&self) -> bool {
         self.is_touch
     }
+    fn into_live(self, pub_id: models::Id, build_id: models::Id) -> Option<Self::LiveRow> {
+        let (last_pub_id, last_build_id) = live_ids(&self, pub_id, build_id);
+        let Self {
+            collection,
+            scope: _,
+            control_id,
+            data_plane_id,
+            expect_pub_id: _,
+            expect_build_id: _,
+            model,
+            validated: _,
+            spec,
+            previous_spec: _,
+            is_touch: _,
+            dependency_hash,
+            documentation_url: _,
+            oauth2: _,
+            key_migration: _,
+            lineage: _,
+        } = self;
+
+        Some(crate::LiveCollection {
+            collection,
+            control_id,
+            data_plane_id,
+            last_pub_id,
+            last_build_id,
+            model: model?,
+            spec: spec?,
+            dependency_hash,
+        })
+    }
 }
 
 impl BuiltRow for crate::BuiltMaterialization {
     type ModelDef = models::MaterializationDef;
     type Validated = proto_flow::materialize::response::Validated;
     type BuiltSpec = proto_flow::flow::MaterializationSpec;
+    type LiveRow = crate::LiveMaterialization;
 
     fn new(
         catalog_name: Self::Key,
@@ -236,6 +321,10 @@ impl BuiltRow for crate::BuiltMaterialization {
             previous_spec,
             is_touch,
             dependency_hash,
+            source_capture_preview: None,
+            documentation_url: None,
+            oauth2: None,
+            lineage: Vec::new(),
         }
     }
     fn catalog_name(&self) -> &Self::Key {
@@ -271,12 +360,45 @@ impl BuiltRow for crate::BuiltMaterialization {
     fn is_touch(&self) -> bool {
         self.is_touch
     }
+    fn into_live(self, pub_id: models::Id, build_id: models::Id) -> Option<Self::LiveRow> {
+        let (last_pub_id, last_build_id) = live_ids(&self, pub_id, build_id);
+        let Self {
+            materialization,
+            scope: _,
+            control_id,
+            data_plane_id,
+            expect_pub_id: _,
+            expect_build_id: _,
+            model,
+            validated: _,
+            spec,
+            previous_spec: _,
+            is_touch: _,
+            dependency_hash,
+            source_capture_preview: _,
+            documentation_url: _,
+            oauth2: _,
+            lineage: _,
+        } = self;
+
+        Some(crate::LiveMaterialization {
+            materialization,
+            control_id,
+            data_plane_id,
+            last_pub_id,
+            last_build_id,
+            model: model?,
+            spec: spec?,
+            dependency_hash,
+        })
+    }
 }
 
 impl BuiltRow for crate::BuiltTest {
     type ModelDef = models::TestDef;
     type Validated = ();
     type BuiltSpec = proto_flow::flow::TestSpec;
+    type LiveRow = crate::LiveTest;
 
     fn new(
         catalog_name: Self::Key,
@@ -338,6 +460,46 @@ impl BuiltRow for crate::BuiltTest {
     fn is_touch(&self) -> bool {
         self.is_touch
     }
+    fn into_live(self, pub_id: models::Id, build_id: models::Id) -> Option<Self::LiveRow> {
+        let (last_pub_id, last_build_id) = live_ids(&self, pub_id, build_id);
+        let Self {
+            test,
+            scope: _,
+            control_id,
+            expect_pub_id: _,
+            expect_build_id: _,
+            model,
+            spec,
+            previous_spec: _,
+            is_touch: _,
+            dependency_hash,
+        } = self;
+
+        Some(crate::LiveTest {
+            test,
+            control_id,
+            last_pub_id,
+            last_build_id,
+            model: model?,
+            spec: spec?,
+            dependency_hash,
+        })
+    }
+}
+
+// Publication and build IDs of the LiveRow which results from publishing `row`.
+fn live_ids<R: BuiltRow>(
+    row: &R,
+    pub_id: models::Id,
+    build_id: models::Id,
+) -> (models::Id, models::Id) {
+    if row.is_passthrough() {
+        (row.expect_pub_id(), row.expect_build_id())
+    } else if row.is_touch() {
+        (row.expect_pub_id(), build_id)
+    } else {
+        (pub_id, build_id)
+    }
 }
 
 /// Validations are tables populated by catalog validations of the `validation` crate.
@@ -347,7 +509,11 @@ pub struct Validations {
     pub built_collections: BuiltCollections,
     pub built_materializations: BuiltMaterializations,
     pub built_tests: BuiltTests,
+    /// Specs of connector images which were used by the build.
+    pub connector_specs: ConnectorSpecs,
     pub errors: Errors,
+    /// Warnings are diagnostics of the catalog which don't fail the build.
+    pub warnings: Errors,
 }
 
 impl Validations {
@@ -390,7 +556,9 @@ impl Validations {
             built_collections,
             built_materializations,
             built_tests,
+            connector_specs,
             errors,
+            warnings: _, // Warnings are not persisted.
         } = self;
 
         vec![
@@ -398,6 +566,7 @@ impl Validations {
             built_collections,
             built_materializations,
             built_tests,
+            connector_specs,
             errors,
         ]
     }
@@ -409,7 +578,9 @@ impl Validations {
             built_collections,
             built_materializations,
             built_tests,
+            connector_specs,
             errors,
+            warnings: _, // Warnings are not persisted.
         } = self;
 
         vec![
@@ -417,7 +588,63 @@ impl Validations {
             built_collections,
             built_materializations,
             built_tests,
+            connector_specs,
             errors,
         ]
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::BuiltRow;
+    use crate::{DraftRow, LiveRow};
+
+    #[test]
+    fn test_built_into_live_and_touch_draft() {
+        let id = |n: u8| models::Id::new([0, 0, 0, 0, 0, 0, 0, n]);
+
+        let built = |expect_pub_id: u8, previous: bool, spec: bool, is_touch: bool| {
+            crate::BuiltTest::new(
+                models::Test::new("acmeCo/test"),
+                crate::synthetic_scope(models::CatalogType::Test, "acmeCo/test"),
+                id(9),
+                models::Id::zero(),
+                id(expect_pub_id),
+                id(2),
+                spec.then(models::TestDef::example),
+                None,
+                spec.then(Default::default),
+                previous.then(Default::default),
+                is_touch,
+                Some("hash".to_string()),
+            )
+        };
+        let ids = |built: crate::BuiltTest| {
+            built
+                .into_live(id(5), id(6))
+                .map(|live| (live.last_pub_id, live.last_build_id))
+        };
+
+        // Inserts and updates take the new publication and build.
+        assert_eq!(ids(built(0, false, true, false)), Some((id(5), id(6))));
+        assert_eq!(ids(built(1, true, true, false)), Some((id(5), id(6))));
+        // Touches retain their publication, but take the new build.
+        assert_eq!(ids(built(1, true, true, true)), Some((id(1), id(6))));
+        // Passthroughs are unchanged.
+        assert_eq!(ids(built(1, false, true, false)), Some((id(1), id(2))));
+        // Deletions have no live row.
+        assert_eq!(ids(built(1, true, false, false)), None);
+
+        let live = built(0, false, true, false)
+            .into_live(id(5), id(6))
+            .unwrap();
+        assert_eq!(live.control_id(), id(9));
+        assert_eq!(live.dependency_hash(), Some("hash"));
+
+        let draft = live.as_touch_draft();
+        assert!(draft.is_touch());
+        assert_eq!(draft.expect_pub_id(), Some(id(5)));
+        assert_eq!(draft.model(), Some(&models::TestDef::example()));
+        assert_eq!(draft.scope(), &live.scope());
+    }
+}
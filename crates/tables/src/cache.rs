@@ -0,0 +1,406 @@
+use crate::{LiveCapture, LiveCatalog, LiveCollection, LiveMaterialization, LiveRow, LiveTest};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// CachedSpec is a live specification held by a CatalogCache.
+/// Its row is shared, and is copied only when it's modified by a holder.
+#[derive(Debug, Clone)]
+pub enum CachedSpec {
+    Capture(Arc<LiveCapture>),
+    Collection(Arc<LiveCollection>),
+    Materialization(Arc<LiveMaterialization>),
+    Test(Arc<LiveTest>),
+}
+
+impl CachedSpec {
+    pub fn catalog_name(&self) -> &str {
+        match self {
+            Self::Capture(r) => r.catalog_name(),
+            Self::Collection(r) => r.catalog_name(),
+            Self::Materialization(r) => r.catalog_name(),
+            Self::Test(r) => r.catalog_name(),
+        }
+    }
+
+    /// Revision of this specification, which is its most recent build ID.
+    /// Every publication which touches or changes a specification
+    /// advances its last build ID.
+    pub fn revision(&self) -> models::Id {
+        match self {
+            Self::Capture(r) => r.last_build_id,
+            Self::Collection(r) => r.last_build_id,
+            Self::Materialization(r) => r.last_build_id,
+            Self::Test(r) => r.last_build_id,
+        }
+    }
+}
+
+/// CatalogCache is a concurrency-safe cache of live specifications,
+/// keyed on catalog name and shared across all users within a process.
+///
+/// Each cached specification is valid for exactly its revision.
+/// Users which know the current revision of a specification (for example,
+/// from its `live_specs` row) may fetch it with [`CatalogCache::get`],
+/// and users which don't may fetch specifications which haven't been
+/// invalidated with [`CatalogCache::resolve`].
+///
+/// Publications must [`CatalogCache::invalidate`] the specifications they
+/// commit, which also prevents racing users from caching a prior revision.
+pub struct CatalogCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    // Logical clock which orders uses of cached specifications.
+    clock: u64,
+    // Cached specifications and the clock of their last use.
+    entries: HashMap<String, (u64, CachedSpec)>,
+    // Minimum revision of each invalidated catalog name which isn't cached
+    // at or above that revision, and the clock of its invalidation.
+    floors: HashMap<String, (u64, models::Id)>,
+}
+
+impl CatalogCache {
+    /// Build a CatalogCache which holds at most `capacity` specifications,
+    /// evicting those which were least-recently used.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Number of specifications which are currently cached.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the cached specification of `catalog_name`, if it's cached
+    /// at exactly `revision`.
+    pub fn get(&self, catalog_name: &str, revision: models::Id) -> Option<CachedSpec> {
+        let mut state = self.state.lock().unwrap();
+        let CacheState { clock, entries, .. } = &mut *state;
+
+        match entries.get_mut(catalog_name) {
+            Some((used, spec)) if spec.revision() == revision => {
+                *clock += 1;
+                *used = *clock;
+                Some(spec.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the cached specification of `catalog_name` at whatever revision
+    /// it's cached, which hasn't been invalidated.
+    pub fn get_any(&self, catalog_name: &str) -> Option<CachedSpec> {
+        let mut state = self.state.lock().unwrap();
+        let CacheState { clock, entries, .. } = &mut *state;
+
+        let (used, spec) = entries.get_mut(catalog_name)?;
+        *clock += 1;
+        *used = *clock;
+        Some(spec.clone())
+    }
+
+    /// Resolve each of `catalog_names` which is cached and hasn't been
+    /// invalidated into `live`, returning the names which were not resolved.
+    pub fn resolve<'a>(&self, catalog_names: &[&'a str], live: &mut LiveCatalog) -> Vec<&'a str> {
+        let mut resolved = Vec::new();
+        let mut missing = Vec::new();
+        {
+            let mut state = self.state.lock().unwrap();
+            let CacheState { clock, entries, .. } = &mut *state;
+
+            for name in catalog_names {
+                if let Some((used, spec)) = entries.get_mut(*name) {
+                    *clock += 1;
+                    *used = *clock;
+                    resolved.push(spec.clone());
+                } else {
+                    missing.push(*name);
+                }
+            }
+        }
+        // Copy rows out of the cache only after releasing its lock.
+        for spec in resolved {
+            live.add_cached(spec);
+        }
+        missing
+    }
+
+    /// Insert `spec` into the cache. It's ignored if the cache already holds
+    /// a newer revision, or if its catalog name was invalidated at a newer
+    /// revision, as would happen if `spec` was read before a publication
+    /// committed but inserted after.
+    pub fn insert(&self, spec: CachedSpec) {
+        let mut state = self.state.lock().unwrap();
+        let CacheState {
+            clock,
+            entries,
+            floors,
+        } = &mut *state;
+
+        if matches!(floors.get(spec.catalog_name()), Some((_, floor)) if spec.revision() < *floor) {
+            return;
+        }
+        if matches!(entries.get(spec.catalog_name()), Some((_, cur)) if cur.revision() >= spec.revision())
+        {
+            return;
+        }
+        // The cached entry now guards against older revisions.
+        floors.remove(spec.catalog_name());
+
+        *clock += 1;
+        entries.insert(spec.catalog_name().to_string(), (*clock, spec));
+
+        if entries.len() > self.capacity {
+            Self::evict(entries, self.capacity);
+        }
+    }
+
+    /// Insert all live specifications of `live` into the cache.
+    /// Rows are copied only if the cache doesn't already hold their revision.
+    pub fn insert_live(&self, live: &LiveCatalog) {
+        let specs = {
+            let state = self.state.lock().unwrap();
+            let stale = |name: &str, revision: models::Id| !matches!(state.entries.get(name), Some((_, cur)) if cur.revision() >= revision);
+
+            live.captures
+                .iter()
+                .filter(|r| stale(&r.capture, r.last_build_id))
+                .map(|r| CachedSpec::Capture(Arc::new(r.clone())))
+                .chain(
+                    live.collections
+                        .iter()
+                        .filter(|r| stale(&r.collection, r.last_build_id))
+                        .map(|r| CachedSpec::Collection(Arc::new(r.clone()))),
+                )
+                .chain(
+                    live.materializations
+                        .iter()
+                        .filter(|r| stale(&r.materialization, r.last_build_id))
+                        .map(|r| CachedSpec::Materialization(Arc::new(r.clone()))),
+                )
+                .chain(
+                    live.tests
+                        .iter()
+                        .filter(|r| stale(&r.test, r.last_build_id))
+                        .map(|r| CachedSpec::Test(Arc::new(r.clone()))),
+                )
+                .collect::<Vec<_>>()
+        };
+
+        for spec in specs {
+            self.insert(spec);
+        }
+    }
+
+    /// Invalidate `catalog_name` as of `revision`, the build ID of a
+    /// committed publication. Cached revisions older than `revision` are
+    /// dropped, and will not be cached again.
+    ///
+    /// Floors of invalidated names are kept only until the name is cached
+    /// at or above its floor, and at most `capacity` floors are kept:
+    /// the oldest are dropped first, as they only guard against inserts
+    /// of reads which raced their invalidation.
+    pub fn invalidate(&self, catalog_name: &str, revision: models::Id) {
+        let mut state = self.state.lock().unwrap();
+        let CacheState {
+            clock,
+            entries,
+            floors,
+        } = &mut *state;
+
+        match entries.get(catalog_name) {
+            Some((_, cur)) if cur.revision() >= revision => return,
+            Some(_) => {
+                entries.remove(catalog_name);
+            }
+            None => {}
+        }
+        *clock += 1;
+
+        let floor = floors
+            .entry(catalog_name.to_string())
+            .or_insert((*clock, revision));
+        *floor = (*clock, floor.1.max(revision));
+
+        if floors.len() > self.capacity {
+            Self::evict(floors, self.capacity);
+        }
+    }
+
+    // Evict least-recently used entries until at most `capacity` remain.
+    // Eviction removes an additional tenth of `capacity`, so that it's
+    // amortized across many inserts.
+    fn evict<V>(entries: &mut HashMap<String, (u64, V)>, capacity: usize) {
+        let target = capacity - capacity / 10;
+
+        if target == 0 {
+            return entries.clear();
+        }
+        let mut used: Vec<u64> = entries.values().map(|(used, _)| *used).collect();
+        used.sort_unstable();
+        let threshold = used[entries.len() - target];
+
+        entries.retain(|_, (used, _)| *used >= threshold);
+    }
+}
+
+impl std::fmt::Debug for CatalogCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CatalogCache")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl Default for CatalogCache {
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+impl LiveCatalog {
+    /// Add a specification resolved from a CatalogCache. Its row is moved
+    /// out of the cache's shared allocation if no one else holds it,
+    /// and is otherwise copied.
+    pub fn add_cached(&mut self, spec: CachedSpec) {
+        match spec {
+            CachedSpec::Capture(r) => self.captures.insert(unwrap_or_clone(r)),
+            CachedSpec::Collection(r) => self.collections.insert(unwrap_or_clone(r)),
+            CachedSpec::Materialization(r) => self.materializations.insert(unwrap_or_clone(r)),
+            CachedSpec::Test(r) => self.tests.insert(unwrap_or_clone(r)),
+        }
+    }
+}
+
+fn unwrap_or_clone<T: Clone>(arc: Arc<T>) -> T {
+    Arc::try_unwrap(arc).unwrap_or_else(|arc| (*arc).clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fixture(name: &str, revision: u64) -> CachedSpec {
+        let mut live = LiveCatalog::default();
+        live.captures.insert_row(
+            models::Capture::new(name),
+            models::Id::new([1; 8]),
+            models::Id::zero(),
+            models::Id::zero(),
+            models::Id::new(revision.to_be_bytes()),
+            models::CaptureDef::example(),
+            proto_flow::flow::CaptureSpec::default(),
+            None,
+        );
+        CachedSpec::Capture(Arc::new(live.captures.into_iter().next().unwrap()))
+    }
+
+    #[test]
+    fn test_revisions_and_invalidation() {
+        let cache = CatalogCache::new(100);
+        let rev = |r: u64| models::Id::new(r.to_be_bytes());
+
+        cache.insert(fixture("acmeCo/one", 10));
+        cache.insert(fixture("acmeCo/two", 10));
+
+        // Specs are fetched only at their exact revision.
+        assert!(cache.get("acmeCo/one", rev(10)).is_some());
+        assert!(cache.get("acmeCo/one", rev(11)).is_none());
+
+        // Older revisions don't replace newer ones.
+        cache.insert(fixture("acmeCo/one", 5));
+        assert!(cache.get("acmeCo/one", rev(10)).is_some());
+
+        // A publication of `one` invalidates it, and a racing insert of
+        // the prior revision is ignored.
+        cache.invalidate("acmeCo/one", rev(20));
+        cache.insert(fixture("acmeCo/one", 10));
+        assert!(cache.get("acmeCo/one", rev(10)).is_none());
+
+        // Invalidation of a newer revision than is cached doesn't drop it.
+        cache.invalidate("acmeCo/two", rev(5));
+        assert!(cache.get("acmeCo/two", rev(10)).is_some());
+
+        cache.insert(fixture("acmeCo/one", 20));
+
+        let mut live = LiveCatalog::default();
+        let missing = cache.resolve(&["acmeCo/one", "acmeCo/three", "acmeCo/two"], &mut live);
+
+        assert_eq!(missing, vec!["acmeCo/three"]);
+        assert_eq!(
+            live.captures
+                .iter()
+                .map(|r| (r.capture.as_str(), r.last_build_id))
+                .collect::<Vec<_>>(),
+            vec![("acmeCo/one", rev(20)), ("acmeCo/two", rev(10))]
+        );
+    }
+
+    #[test]
+    fn test_floors_are_bounded() {
+        let cache = CatalogCache::new(10);
+        let rev = |r: u64| models::Id::new(r.to_be_bytes());
+        let floors = |cache: &CatalogCache| cache.state.lock().unwrap().floors.len();
+
+        // Invalidation of a name cached at or above the revision needs no floor.
+        cache.insert(fixture("acmeCo/one", 10));
+        cache.invalidate("acmeCo/one", rev(10));
+        assert_eq!(floors(&cache), 0);
+
+        // Otherwise a floor is kept until the name is cached at its floor.
+        cache.invalidate("acmeCo/one", rev(20));
+        assert_eq!(floors(&cache), 1);
+        cache.insert(fixture("acmeCo/one", 10));
+        assert_eq!(floors(&cache), 1);
+        cache.insert(fixture("acmeCo/one", 20));
+        assert_eq!(floors(&cache), 0);
+
+        // The entry now guards against the racing insert of an older revision.
+        cache.insert(fixture("acmeCo/one", 10));
+        assert!(cache.get("acmeCo/one", rev(20)).is_some());
+
+        // Floors of names which are never cached are bounded,
+        // retaining the most-recent invalidations.
+        for i in 0..11 {
+            cache.invalidate(&format!("acmeCo/{i}"), rev(30));
+        }
+        assert_eq!(floors(&cache), 9);
+        cache.insert(fixture("acmeCo/0", 20));
+        assert!(cache.get("acmeCo/0", rev(20)).is_some());
+        cache.insert(fixture("acmeCo/10", 20));
+        assert!(cache.get("acmeCo/10", rev(20)).is_none());
+    }
+
+    #[test]
+    fn test_eviction() {
+        let cache = CatalogCache::new(10);
+
+        for i in 0..10 {
+            cache.insert(fixture(&format!("acmeCo/{i}"), 1));
+        }
+        // Use the oldest entry, so that it's retained.
+        assert!(cache
+            .get("acmeCo/0", models::Id::new(1u64.to_be_bytes()))
+            .is_some());
+
+        cache.insert(fixture("acmeCo/10", 1));
+        assert_eq!(cache.len(), 9);
+        assert!(cache
+            .get("acmeCo/0", models::Id::new(1u64.to_be_bytes()))
+            .is_some());
+        assert!(cache
+            .get("acmeCo/1", models::Id::new(1u64.to_be_bytes()))
+            .is_none());
+    }
+}
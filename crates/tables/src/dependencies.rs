@@ -1,6 +1,7 @@
 use crate::{DraftCatalog, DraftRow, LiveCatalog, LiveRow};
 use models::ModelDef;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
 
 /// Used to compute the dependency hash of each built specification. We use this struct instead of
 /// passing around `Built_` tables because it allows the built tables to be constructed
@@ -9,9 +10,13 @@ use std::collections::BTreeMap;
 /// contained in the map is assumed to have a `last_pub_id` of `default_pub_id`. For computing
 /// hashes during publication, the `default_pub_id` is set to the current `pub_id`, and drafted
 /// specs are removed from the map. Outside of publications, the `default_pub_id` is left zeroed.
+///
+/// For debugging, Dependencies may retain the inputs of each hash it computes
+/// (see `retain_inputs`), so that a change between two hashes can be explained.
 pub struct Dependencies<'a> {
     default_pub_id: models::Id,
     by_catalog_name: BTreeMap<&'a str, models::Id>,
+    retained: Option<Mutex<BTreeMap<String, HashInputs>>>,
 }
 
 impl<'a> Dependencies<'a> {
@@ -32,9 +37,17 @@ impl<'a> Dependencies<'a> {
         Dependencies {
             default_pub_id: models::Id::zero(),
             by_catalog_name: map,
+            retained: None,
         }
     }
 
+    /// Retain the inputs of each hash which is computed,
+    /// for use with `hash_inputs` and `explain_hash_change`.
+    pub fn retain_inputs(mut self) -> Self {
+        self.retained = Some(Default::default());
+        self
+    }
+
     pub fn of_publication(
         pub_id: models::Id,
         draft: &'a DraftCatalog,
@@ -58,6 +71,25 @@ impl<'a> Dependencies<'a> {
         deps
     }
 
+    /// Dependencies of a publication which reuses, rather than rebuilds, the
+    /// drafted specifications named by `reused`. As with touched specifications,
+    /// reused specifications retain the `last_pub_id` of their live specification.
+    pub fn of_incremental_publication(
+        pub_id: models::Id,
+        draft: &'a DraftCatalog,
+        live: &'a LiveCatalog,
+        reused: &BTreeSet<String>,
+    ) -> Dependencies<'a> {
+        let mut deps = Dependencies::of_publication(pub_id, draft, live);
+
+        for (name, last_pub_id) in Dependencies::from_live(live).by_catalog_name {
+            if reused.contains(name) {
+                deps.by_catalog_name.insert(name, last_pub_id);
+            }
+        }
+        deps
+    }
+
     fn get_pub_id(&self, dependency_name: &str) -> models::Id {
         self.by_catalog_name
             .get(dependency_name)
@@ -68,6 +100,39 @@ impl<'a> Dependencies<'a> {
     pub fn compute_hash<M: ModelDef>(&self, model: &M) -> Option<String> {
         use xxhash_rust::xxh3::Xxh3;
 
+        let inputs = self.compute_inputs(model)?;
+
+        let mut hasher = Xxh3::new();
+        for (name, last_pub) in inputs.0.iter() {
+            hasher.update(name.as_bytes());
+            hasher.update(&last_pub.as_array());
+        }
+        let hash = format!("{:x}", hasher.digest());
+
+        if let Some(retained) = &self.retained {
+            retained.lock().unwrap().insert(hash.clone(), inputs);
+        }
+        Some(hash)
+    }
+
+    /// Inputs of a previously computed `hash`, if inputs are retained.
+    pub fn hash_inputs(&self, hash: &str) -> Option<HashInputs> {
+        self.retained.as_ref()?.lock().unwrap().get(hash).cloned()
+    }
+
+    /// Explain the change from an `old` to a `new` hash as the dependencies
+    /// which differ between their inputs. Returns None unless the inputs of
+    /// both hashes were retained.
+    pub fn explain_hash_change(&self, old: &str, new: &str) -> Option<HashChange> {
+        Some(HashChange::between(
+            &self.hash_inputs(old)?,
+            &self.hash_inputs(new)?,
+        ))
+    }
+
+    // Map the dependencies of `model` into the inputs of its hash,
+    // or None if it has no dependencies.
+    fn compute_inputs<M: ModelDef>(&self, model: &M) -> Option<HashInputs> {
         // TODO: This function can probably be cleaned up if we introduced a function like:
         // `ModelDef::get_dependencies(&self) -> impl Iterator<Item=(&str, FlowType)>`
 
@@ -90,19 +155,310 @@ impl<'a> Dependencies<'a> {
             return None;
         }
 
-        deps.sort();
-        deps.dedup();
+        Some(HashInputs(
+            deps.into_iter()
+                .map(|name| (name.to_string(), self.get_pub_id(name)))
+                .collect(),
+        ))
+    }
+}
 
-        let mut hasher = Xxh3::new();
-        for name in deps {
-            hasher.update(name.as_bytes());
-            let last_pub = self.get_pub_id(name);
-            hasher.update(&last_pub.as_array());
+/// HashInputs are the dependencies which were hashed into a dependency hash,
+/// ordered on name, and the `last_pub_id` of each.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct HashInputs(pub BTreeMap<String, models::Id>);
+
+impl std::fmt::Display for HashInputs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, (name, last_pub_id)) in self.0.iter().enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{name}@{last_pub_id}")?;
         }
-        Some(format!("{:x}", hasher.digest()))
+        Ok(())
     }
 }
 
+/// DependencyChange is a dependency which differs between the inputs of two hashes.
+/// A dependency which was added has no `old_pub_id`, and one which was
+/// removed has no `new_pub_id`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyChange {
+    pub name: String,
+    pub old_pub_id: Option<models::Id>,
+    pub new_pub_id: Option<models::Id>,
+}
+
+/// HashChange explains a change of dependency hash
+/// as the dependencies which differ, ordered on name.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct HashChange(pub Vec<DependencyChange>);
+
+impl HashChange {
+    pub fn between(old: &HashInputs, new: &HashInputs) -> Self {
+        use itertools::{EitherOrBoth as EOB, Itertools};
+
+        let changes = old
+            .0
+            .iter()
+            .merge_join_by(new.0.iter(), |(l, _), (r, _)| l.cmp(r))
+            .filter_map(|eob| {
+                let (name, old_pub_id, new_pub_id) = match eob {
+                    EOB::Left((name, old)) => (name, Some(*old), None),
+                    EOB::Right((name, new)) => (name, None, Some(*new)),
+                    EOB::Both((name, old), (_, new)) if old != new => {
+                        (name, Some(*old), Some(*new))
+                    }
+                    EOB::Both(..) => return None,
+                };
+                Some(DependencyChange {
+                    name: name.clone(),
+                    old_pub_id,
+                    new_pub_id,
+                })
+            })
+            .collect();
+
+        Self(changes)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::fmt::Display for HashChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, change) in self.0.iter().enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            match (&change.old_pub_id, &change.new_pub_id) {
+                (Some(old), Some(new)) => write!(f, "{} {old} => {new}", change.name)?,
+                (None, Some(new)) => write!(f, "{} added @{new}", change.name)?,
+                (Some(old), None) => write!(f, "{} removed @{old}", change.name)?,
+                (None, None) => write!(f, "{}", change.name)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Kind of a dependency between two specifications.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DependencyKind {
+    /// The dependent reads from the collection.
+    Source,
+    /// The dependent writes into the collection.
+    Target,
+    /// The dependent materialization adds bindings for the capture.
+    SourceCapture,
+}
+
+/// DependencyEdge relates a dependent specification to a specification upon which it depends.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub struct DependencyEdge {
+    /// Name of the dependent specification.
+    pub from: String,
+    /// Name of the specification upon which `from` depends.
+    pub to: String,
+    /// Kind of the dependency.
+    pub kind: DependencyKind,
+}
+
+/// DependencyGraph is the graph of spec-to-spec dependencies of a catalog,
+/// which are the same dependencies that factor into a spec's dependency hash.
+/// Drafted specifications take precedence over live ones, and drafted
+/// deletions are removed from the graph.
+#[derive(Debug, Default, serde::Serialize)]
+pub struct DependencyGraph {
+    /// Specifications of the catalog and their types.
+    pub nodes: BTreeMap<String, models::CatalogType>,
+    /// Dependency edges, ordered on their dependent specification.
+    /// An edge may reference a specification which isn't in `nodes`.
+    pub edges: BTreeSet<DependencyEdge>,
+}
+
+impl DependencyGraph {
+    pub fn from_live(live: &LiveCatalog) -> Self {
+        Self::of_publication(&DraftCatalog::default(), live)
+    }
+
+    pub fn of_publication(draft: &DraftCatalog, live: &LiveCatalog) -> Self {
+        let mut graph = Self::default();
+        let drafted: BTreeSet<&str> = draft.all_spec_names().collect();
+
+        for r in draft.captures.iter() {
+            graph.add(r.catalog_name().as_str(), r.model());
+        }
+        for r in draft.collections.iter() {
+            graph.add(r.catalog_name().as_str(), r.model());
+        }
+        for r in draft.materializations.iter() {
+            graph.add(r.catalog_name().as_str(), r.model());
+        }
+        for r in draft.tests.iter() {
+            graph.add(r.catalog_name().as_str(), r.model());
+        }
+
+        let live_rows = live
+            .captures
+            .iter()
+            .map(|r| graph_node(r.catalog_name().as_str(), r.model()))
+            .chain(
+                live.collections
+                    .iter()
+                    .map(|r| graph_node(r.catalog_name().as_str(), r.model())),
+            )
+            .chain(
+                live.materializations
+                    .iter()
+                    .map(|r| graph_node(r.catalog_name().as_str(), r.model())),
+            )
+            .chain(
+                live.tests
+                    .iter()
+                    .map(|r| graph_node(r.catalog_name().as_str(), r.model())),
+            );
+
+        for (name, catalog_type, deps) in live_rows {
+            if !drafted.contains(name) {
+                graph.insert(name, catalog_type, deps);
+            }
+        }
+        graph
+    }
+
+    fn add<M: ModelDef>(&mut self, name: &str, model: Option<&M>) {
+        if let Some(model) = model {
+            let (name, catalog_type, deps) = graph_node(name, model);
+            self.insert(name, catalog_type, deps);
+        }
+    }
+
+    fn insert(
+        &mut self,
+        name: &str,
+        catalog_type: models::CatalogType,
+        deps: Vec<(String, DependencyKind)>,
+    ) {
+        self.nodes.insert(name.to_string(), catalog_type);
+
+        for (to, kind) in deps {
+            self.edges.insert(DependencyEdge {
+                from: name.to_string(),
+                to,
+                kind,
+            });
+        }
+    }
+
+    /// Edges from `name` to the specifications upon which it depends.
+    pub fn dependencies<'s>(&'s self, name: &'s str) -> impl Iterator<Item = &'s DependencyEdge> {
+        self.edges
+            .iter()
+            .skip_while(move |edge| edge.from.as_str() < name)
+            .take_while(move |edge| edge.from == name)
+    }
+
+    /// Edges to `name` from the specifications which depend upon it.
+    pub fn dependents<'s>(&'s self, name: &'s str) -> impl Iterator<Item = &'s DependencyEdge> {
+        self.edges.iter().filter(move |edge| edge.to == name)
+    }
+
+    /// Names of all specifications which transitively depend upon any of
+    /// `changed`, and whose dependency hashes are therefore affected by
+    /// a publication of `changed`. Specifications of `changed` are included
+    /// only if they depend upon another changed specification.
+    pub fn ripple<'s>(&'s self, changed: impl IntoIterator<Item = &'s str>) -> BTreeSet<&'s str> {
+        let mut stack: Vec<&str> = changed.into_iter().collect();
+        let mut affected = BTreeSet::new();
+
+        while let Some(name) = stack.pop() {
+            for edge in self.dependents(name) {
+                if affected.insert(edge.from.as_str()) {
+                    stack.push(edge.from.as_str());
+                }
+            }
+        }
+        affected
+    }
+
+    /// Adjacency lists of each specification, mapping its name to the
+    /// names of the specifications upon which it depends.
+    pub fn adjacency(&self) -> BTreeMap<&str, Vec<&str>> {
+        let mut out: BTreeMap<&str, Vec<&str>> = self
+            .nodes
+            .keys()
+            .map(|name| (name.as_str(), Vec::new()))
+            .collect();
+
+        for edge in &self.edges {
+            out.entry(edge.from.as_str())
+                .or_default()
+                .push(edge.to.as_str());
+        }
+        for deps in out.values_mut() {
+            deps.dedup();
+        }
+        out
+    }
+
+    /// Render adjacency lists of the graph as JSON.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self.adjacency()).unwrap()
+    }
+
+    /// Render the graph in the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::from("digraph catalog {\n");
+
+        for (name, catalog_type) in &self.nodes {
+            let shape = match catalog_type {
+                models::CatalogType::Capture => "invhouse",
+                models::CatalogType::Collection => "ellipse",
+                models::CatalogType::Materialization => "house",
+                models::CatalogType::Test => "note",
+            };
+            writeln!(out, "  {name:?} [shape={shape}];").unwrap();
+        }
+        for DependencyEdge { from, to, kind } in &self.edges {
+            let label = match kind {
+                DependencyKind::Source => "source",
+                DependencyKind::Target => "target",
+                DependencyKind::SourceCapture => "sourceCapture",
+            };
+            writeln!(out, "  {from:?} -> {to:?} [label={label:?}];").unwrap();
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+// Map a model into its graph node and dependency edges.
+fn graph_node<'n, M: ModelDef>(
+    name: &'n str,
+    model: &M,
+) -> (&'n str, models::CatalogType, Vec<(String, DependencyKind)>) {
+    let mut deps = Vec::new();
+
+    for source in model.sources() {
+        deps.push((source.collection().to_string(), DependencyKind::Source));
+    }
+    for target in model.targets() {
+        deps.push((target.to_string(), DependencyKind::Target));
+    }
+    if let Some(source_cap) = model.materialization_source_capture_name() {
+        deps.push((source_cap.to_string(), DependencyKind::SourceCapture));
+    }
+    (name, model.catalog_type(), deps)
+}
+
 #[cfg(test)]
 mod test {
     use proto_flow::flow::CollectionSpec;
@@ -299,6 +655,12 @@ mod test {
             }),
         );
 
+        // An incremental publication which reuses `test/c2` retains its live last_pub_id.
+        let reused = ["test/c2".to_string()].into_iter().collect();
+        let subject = Dependencies::of_incremental_publication(id(3), &draft, &live, &reused);
+        assert_eq!(id(2), subject.get_pub_id("test/c2"));
+        assert_eq!(id(1), subject.get_pub_id("test/c1"));
+
         // Simulate publishing at id 3, which should result in a different hash
         let subject = Dependencies::of_publication(id(3), &draft, &live);
         assert_eq!(id(3), subject.get_pub_id("test/d2"));
@@ -328,6 +690,153 @@ mod test {
         );
     }
 
+    #[test]
+    fn dependency_graph_of_publication() {
+        use crate::{DraftCapture, DraftMaterialization};
+
+        let live = live_catalog();
+        let mut draft = crate::DraftCatalog::default();
+
+        draft.captures.insert(DraftCapture {
+            capture: models::Capture::new("test/capture"),
+            scope: crate::synthetic_scope(models::CatalogType::Capture, "test/capture"),
+            expect_pub_id: None,
+            model: Some(
+                serde_json::from_value(serde_json::json!({
+                    "endpoint": {"connector": {"image": "test/image:test", "config": {}}},
+                    "bindings": [{"resource": {"r": "1"}, "target": "test/c1"}]
+                }))
+                .unwrap(),
+            ),
+            is_touch: false,
+        });
+        draft.materializations.insert(DraftMaterialization {
+            materialization: models::Materialization::new("test/m2"),
+            scope: crate::synthetic_scope(models::CatalogType::Materialization, "test/m2"),
+            expect_pub_id: None,
+            model: Some(
+                serde_json::from_value(serde_json::json!({
+                    "sourceCapture": "test/capture",
+                    "endpoint": {"connector": {"image": "test/image:test", "config": {}}},
+                    "bindings": [{"resource": {"r": "1"}, "source": "test/c1"}]
+                }))
+                .unwrap(),
+            ),
+            is_touch: false,
+        });
+        // Deleted specifications are removed from the graph.
+        draft.materializations.insert(DraftMaterialization {
+            materialization: models::Materialization::new("test/materialize"),
+            scope: crate::synthetic_scope(models::CatalogType::Materialization, "test/materialize"),
+            expect_pub_id: None,
+            model: None,
+            is_touch: false,
+        });
+
+        let graph = DependencyGraph::of_publication(&draft, &live);
+
+        assert_eq!(
+            graph.to_json(),
+            serde_json::json!({
+                "test/c1": [],
+                "test/c2": [],
+                "test/capture": ["test/c1"],
+                "test/m2": ["test/c1", "test/capture"],
+            })
+        );
+        assert_eq!(
+            graph
+                .dependencies("test/m2")
+                .map(|edge| (edge.to.as_str(), edge.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                ("test/c1", DependencyKind::Source),
+                ("test/capture", DependencyKind::SourceCapture),
+            ]
+        );
+        assert_eq!(
+            graph.ripple(["test/c1"]).into_iter().collect::<Vec<_>>(),
+            vec!["test/capture", "test/m2"]
+        );
+        assert!(graph.ripple(["test/c2"]).is_empty());
+
+        let dot = graph.to_dot();
+        assert!(dot.contains(r#"  "test/capture" [shape=invhouse];"#));
+        assert!(dot.contains(r#"  "test/m2" -> "test/c1" [label="source"];"#));
+    }
+
+    #[test]
+    fn dependencies_explain_hash_changes() {
+        let live = live_catalog();
+        let mut draft = crate::DraftCatalog::default();
+        draft.collections.insert(DraftCollection {
+            collection: models::Collection::new("test/c2"),
+            scope: crate::synthetic_scope(models::CatalogType::Collection, "test/c2"),
+            expect_pub_id: None,
+            model: Some(models::CollectionDef::example()),
+            is_touch: false,
+        });
+
+        let materialization = |source_capture: bool, sources: &[&str]| {
+            let mut model = serde_json::json!({
+                "endpoint": {"connector": {"image": "test/image:test", "config": {}}},
+                "bindings": sources
+                    .iter()
+                    .map(|source| serde_json::json!({"resource": {"r": source}, "source": source}))
+                    .collect::<Vec<_>>(),
+            });
+            if source_capture {
+                model["sourceCapture"] = "test/capture".into();
+            }
+            serde_json::from_value::<models::MaterializationDef>(model).unwrap()
+        };
+        let model = materialization(true, &["test/c1", "test/c2"]);
+
+        // Inputs are retained only if requested.
+        let live_deps = Dependencies::from_live(&live);
+        let old = live_deps.compute_hash(&model).unwrap();
+        assert_eq!(old, "7b850cd4f60163b0");
+        assert_eq!(live_deps.hash_inputs(&old), None);
+
+        let live_deps = Dependencies::from_live(&live).retain_inputs();
+        let pub_deps = Dependencies::of_publication(id(3), &draft, &live).retain_inputs();
+        let new = pub_deps.compute_hash(&model).unwrap();
+        assert_eq!(new, "e94b0ce2e25aa96a");
+
+        let old = live_deps.compute_hash(&model).unwrap();
+        let old_inputs = live_deps.hash_inputs(&old).unwrap();
+        assert_eq!(
+            old_inputs.to_string(),
+            "test/c1@0100000000000000, test/c2@0200000000000000, test/capture@0100000000000000"
+        );
+
+        // The publication of `test/c2` changed the hash.
+        let change = HashChange::between(&old_inputs, &pub_deps.hash_inputs(&new).unwrap());
+        assert_eq!(
+            change.to_string(),
+            "test/c2 0200000000000000 => 0300000000000000"
+        );
+
+        // Changes of the model's dependencies are explained as well.
+        let narrowed = pub_deps
+            .compute_hash(&materialization(false, &["test/c1"]))
+            .unwrap();
+        let change = pub_deps.explain_hash_change(&new, &narrowed).unwrap();
+        assert_eq!(
+            change.to_string(),
+            "test/c2 removed @0300000000000000, test/capture removed @0100000000000000"
+        );
+        assert_eq!(
+            serde_json::to_value(&change).unwrap(),
+            serde_json::json!([
+                {"name": "test/c2", "oldPubId": "0300000000000000", "newPubId": null},
+                {"name": "test/capture", "oldPubId": "0100000000000000", "newPubId": null},
+            ])
+        );
+        assert!(pub_deps.explain_hash_change(&new, &new).unwrap().is_empty());
+        assert_eq!(pub_deps.explain_hash_change(&new, "unknown"), None);
+    }
+
     fn assert_hash<M: ModelDef>(
         expected: Option<&str>,
         deps: &Dependencies,
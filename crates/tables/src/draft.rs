@@ -187,6 +187,8 @@ impl DraftCatalog {
                         .context("deserializing draft capture spec")
                         .map_err(|error| Error {
                             scope: scope.clone(),
+                            catalog_type: Some(spec_type),
+                            catalog_name: catalog_name.to_string(),
                             error,
                         })?
                 } else {
@@ -207,6 +209,8 @@ impl DraftCatalog {
                         .context("deserializing draft collection spec")
                         .map_err(|error| Error {
                             scope: scope.clone(),
+                            catalog_type: Some(spec_type),
+                            catalog_name: catalog_name.to_string(),
                             error,
                         })?
                 } else {
@@ -226,6 +230,8 @@ impl DraftCatalog {
                         .context("deserializing draft materialization spec")
                         .map_err(|error| Error {
                             scope: scope.clone(),
+                            catalog_type: Some(spec_type),
+                            catalog_name: catalog_name.to_string(),
                             error,
                         })?
                 } else {
@@ -245,6 +251,8 @@ impl DraftCatalog {
                         .context("deserializing draft test spec")
                         .map_err(|error| Error {
                             scope: scope.clone(),
+                            catalog_type: Some(spec_type),
+                            catalog_name: catalog_name.to_string(),
                             error,
                         })?
                 } else {
@@ -266,49 +274,20 @@ impl DraftCatalog {
     /// Adds the given live catalog to this draft. All live specs are added with
     /// `is_touch` set to `true`.
     pub fn add_live(&mut self, live: crate::LiveCatalog) {
-        for capture in live.captures {
-            let scope = crate::synthetic_scope(models::CatalogType::Capture, &capture.capture);
-            self.captures.insert(DraftCapture {
-                capture: capture.capture,
-                scope,
-                expect_pub_id: Some(capture.last_pub_id),
-                model: Some(capture.model),
-                is_touch: true,
-            });
+        use crate::LiveRow;
+
+        for capture in live.captures.iter() {
+            self.captures.insert(capture.as_touch_draft());
         }
-        for collection in live.collections {
-            let scope =
-                crate::synthetic_scope(models::CatalogType::Collection, &collection.collection);
-            self.collections.insert(DraftCollection {
-                collection: collection.collection,
-                scope,
-                expect_pub_id: Some(collection.last_pub_id),
-                model: Some(collection.model),
-                is_touch: true,
-            });
+        for collection in live.collections.iter() {
+            self.collections.insert(collection.as_touch_draft());
         }
-        for materialization in live.materializations {
-            let scope = crate::synthetic_scope(
-                models::CatalogType::Materialization,
-                &materialization.materialization,
-            );
-            self.materializations.insert(DraftMaterialization {
-                materialization: materialization.materialization,
-                scope,
-                expect_pub_id: Some(materialization.last_pub_id),
-                model: Some(materialization.model),
-                is_touch: true,
-            });
+        for materialization in live.materializations.iter() {
+            self.materializations
+                .insert(materialization.as_touch_draft());
         }
-        for test in live.tests {
-            let scope = crate::synthetic_scope(models::CatalogType::Test, &test.test);
-            self.tests.insert(DraftTest {
-                test: test.test,
-                scope,
-                expect_pub_id: Some(test.last_pub_id),
-                model: Some(test.model),
-                is_touch: true,
-            });
+        for test in live.tests.iter() {
+            self.tests.insert(test.as_touch_draft());
         }
     }
 }
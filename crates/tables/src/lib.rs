@@ -1,8 +1,10 @@
 #[macro_use]
 mod macros;
 mod behaviors;
+mod cache;
 mod dependencies;
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::str::FromStr;
 
 use macros::*;
@@ -12,17 +14,28 @@ pub use itertools::EitherOrBoth;
 pub use macros::{Row, Table};
 
 #[cfg(feature = "persist")]
-pub use macros::{load_tables, persist_tables, SqlTableObj};
+pub use macros::{
+    load_tables, load_tables_as_of, load_tables_prefix, persist_tables, persist_tables_version,
+    SqlTableObj,
+};
 #[cfg(feature = "persist")]
 use prost::Message;
 
+#[cfg(feature = "archive")]
+pub mod archive;
 mod built;
 mod draft;
 mod live;
 pub use built::{BuiltRow, Validations};
-pub use dependencies::Dependencies;
+pub use cache::{CachedSpec, CatalogCache};
+pub use dependencies::{
+    Dependencies, DependencyChange, DependencyEdge, DependencyGraph, DependencyKind, HashChange,
+    HashInputs,
+};
 pub use draft::{DraftCatalog, DraftRow};
-pub use live::{CatalogResolver, LiveCatalog, LiveRow};
+pub use live::{
+    paginate, CatalogPage, CatalogResolver, LiveCatalog, LiveRow, PagedCatalogResolver, Pagination,
+};
 
 tables!(
     table Fetches (row Fetch, sql "fetches") {
@@ -59,6 +72,29 @@ tables!(
         val stores: Vec<models::Store>,
     }
 
+    table Prefixes (row Prefix, sql "prefixes") {
+        // Catalog prefix to which this row applies.
+        key prefix: models::Prefix,
+        // Control-plane ID of the storage mapping of this prefix.
+        val control_id: models::Id,
+        // Stores for collection journal fragments under this prefix.
+        val partition_stores: Vec<models::Store>,
+        // Stores for task recovery log fragments under this prefix.
+        val recovery_stores: Vec<models::Store>,
+        // Names of data-planes to which specifications under this prefix
+        // may be assigned. If empty, any data-plane is admissible.
+        val data_planes: Vec<String>,
+    }
+
+    table Tenants (row Tenant, sql "tenants") {
+        // Catalog prefix of this tenant.
+        key tenant: models::Prefix,
+        // Control-plane ID of this tenant.
+        val control_id: models::Id,
+        // Should collection schemas of this tenant be validated in strict mode?
+        val strict_schemas: bool,
+    }
+
     table InferredSchemas (row InferredSchema, sql "inferred_schemas") {
         // Collection which this inferred schema reflects.
         key collection_name: models::Collection,
@@ -68,6 +104,13 @@ tables!(
         val md5: String,
     }
 
+    table CollectionThroughputs (row CollectionThroughput, sql "collection_throughputs") {
+        // Collection of these throughput statistics.
+        key collection: models::Collection,
+        // Peak bytes per hour written to the collection over a trailing window.
+        val bytes_per_hour: i64,
+    }
+
     table DataPlanes (row #[derive(Clone)] DataPlane, sql "data_planes") {
         // Control-plane identifier for this data-plane.
         key control_id: models::Id,
@@ -89,6 +132,19 @@ tables!(
         val broker_address: String,
         // Address of reactors within the data-plane.
         val reactor_address: String,
+        // Names of peer data-planes whose collections may be read
+        // by tasks of this data-plane, which opt-in to cross-plane reads.
+        val cross_plane_read_peers: Vec<String>,
+        // Zones of the data-plane, which journals and shards may declare.
+        val zones: Vec<String>,
+        // Maximum CPU limit, in millicores, of task connector containers.
+        val max_task_cpu_millis: Option<i64>,
+        // Maximum memory limit, in bytes, of task connector containers.
+        val max_task_memory_bytes: Option<i64>,
+        // Minimum maxTxnDuration, in milliseconds, of tasks.
+        val min_max_txn_duration_millis: Option<i64>,
+        // Maximum minTxnDuration or maxTxnDuration, in milliseconds, of tasks.
+        val max_txn_duration_millis: Option<i64>,
     }
 
     table RoleGrants (row #[derive(serde::Deserialize, serde::Serialize)] RoleGrant, sql "role_grants") {
@@ -169,7 +225,7 @@ tables!(
         val is_touch: bool,
     }
 
-    table LiveCaptures (row LiveCapture, sql "live_captures") {
+    table LiveCaptures (row #[derive(Clone)] LiveCapture, sql "live_captures") {
         // Catalog name of this capture.
         key capture: models::Capture,
         // Control-plane ID of this capture.
@@ -188,7 +244,7 @@ tables!(
         val dependency_hash: Option<String>,
     }
 
-    table LiveCollections (row LiveCollection, sql "live_collections") {
+    table LiveCollections (row #[derive(Clone)] LiveCollection, sql "live_collections") {
         // Catalog name of this collection.
         key collection: models::Collection,
         // Control-plane ID of this collection.
@@ -207,7 +263,7 @@ tables!(
         val dependency_hash: Option<String>,
     }
 
-    table LiveMaterializations (row LiveMaterialization, sql "live_materializations") {
+    table LiveMaterializations (row #[derive(Clone)] LiveMaterialization, sql "live_materializations") {
         // Catalog name of this materialization.
         key materialization: models::Materialization,
         // Control-plane ID of this materialization.
@@ -226,7 +282,7 @@ tables!(
         val dependency_hash: Option<String>,
     }
 
-    table LiveTests (row LiveTest, sql "live_tests") {
+    table LiveTests (row #[derive(Clone)] LiveTest, sql "live_tests") {
         // Catalog name of this test.
         key test: models::Test,
         // Control-plane ID of this test.
@@ -269,6 +325,10 @@ tables!(
         val is_touch: bool,
         // Hash of the last_pub_ids of all the dependencies that were used to build the capture
         val dependency_hash: Option<String>,
+        // URL of the connector's documentation, if known.
+        val documentation_url: Option<String>,
+        // OAuth2 specification of the connector, if known and it supports OAuth2.
+        val oauth2: Option<proto_flow::flow::OAuth2>,
     }
 
     table BuiltCollections (row BuiltCollection, sql "built_collections") {
@@ -297,6 +357,15 @@ tables!(
         val is_touch: bool,
         // Hash of the last_pub_ids of all the dependencies that were used to build the collection
         val dependency_hash: Option<String>,
+        // URL of the connector's documentation, if known.
+        val documentation_url: Option<String>,
+        // OAuth2 specification of the connector, if known and it supports OAuth2.
+        val oauth2: Option<proto_flow::flow::OAuth2>,
+        // Plan for migrating the collection to a changed key,
+        // or None if its key is unchanged.
+        val key_migration: Option<models::KeyMigrationPlan>,
+        // Field-level lineage of a derivation, from the shuffle keys of its transforms.
+        val lineage: Vec<models::FieldLineage>,
     }
 
     table BuiltMaterializations (row BuiltMaterialization, sql "built_materializations") {
@@ -325,6 +394,15 @@ tables!(
         val is_touch: bool,
         // Hash of the last_pub_ids of all the dependencies that were used to build the materialization
         val dependency_hash: Option<String>,
+        // Bindings which will be added or disabled by reconciling the materialization
+        // with its sourceCapture, or None if it has no sourceCapture or is up-to-date.
+        val source_capture_preview: Option<models::SourceCapturePreview>,
+        // URL of the connector's documentation, if known.
+        val documentation_url: Option<String>,
+        // OAuth2 specification of the connector, if known and it supports OAuth2.
+        val oauth2: Option<proto_flow::flow::OAuth2>,
+        // Field-level lineage of the materialization, from its field selections.
+        val lineage: Vec<models::FieldLineage>,
     }
 
     table BuiltTests (row BuiltTest, sql "built_tests") {
@@ -351,9 +429,31 @@ tables!(
         val dependency_hash: Option<String>,
     }
 
+    table ConnectorSpecs (row ConnectorSpec, sql "connector_specs") {
+        // Connector image, including its tag.
+        key image: String,
+        // Type of catalog task which uses this connector image.
+        val catalog_type: models::CatalogType,
+        // Protocol version of the connector.
+        val protocol: u32,
+        // JSON schema of the connector's endpoint configuration.
+        val config_schema: models::RawValue,
+        // JSON schema of the connector's resource configuration.
+        val resource_config_schema: models::RawValue,
+        // URL of the connector's documentation, or empty if not provided.
+        val documentation_url: String,
+        // OAuth2 specification of the connector, if it supports OAuth2.
+        val oauth2: Option<proto_flow::flow::OAuth2>,
+    }
+
     table Errors (row Error, sql "errors") {
         // Scope of this error.
         val scope: url::Url,
+        // Type of the catalog specification to which this error pertains, if known.
+        val catalog_type: Option<models::CatalogType>,
+        // Name of the catalog specification to which this error pertains,
+        // or empty if not known.
+        val catalog_name: String,
         // Error content.
         val error: anyhow::Error,
     }
@@ -364,12 +464,25 @@ tables!(
 );
 
 impl Error {
+    /// Build an Error of `scope`, populating its catalog type and name
+    /// from the scope if it's a synthetic scope or a scope of a catalog
+    /// specification within a source document.
+    pub fn new(scope: url::Url, error: anyhow::Error) -> Self {
+        let (catalog_type, catalog_name) = match parse_scope_catalog(&scope) {
+            Some((catalog_type, catalog_name)) => (Some(catalog_type), catalog_name),
+            None => (None, String::new()),
+        };
+        Self {
+            scope,
+            catalog_type,
+            catalog_name,
+            error,
+        }
+    }
+
     pub fn to_draft_error(&self) -> models::draft_error::Error {
-        let catalog_name = parse_synthetic_scope(&self.scope)
-            .map(|(_, name)| name)
-            .unwrap_or_default();
         models::draft_error::Error {
-            catalog_name,
+            catalog_name: self.catalog_name.clone(),
             scope: Some(self.scope.to_string()),
             // use alternate to print chained contexts
             detail: format!("{:#}", self.error),
@@ -377,6 +490,35 @@ impl Error {
     }
 }
 
+impl Errors {
+    /// Insert an Error of `scope`, populating its catalog type and name from the scope.
+    pub fn insert_scoped(&mut self, scope: url::Url, error: anyhow::Error) {
+        self.insert(Error::new(scope, error))
+    }
+
+    /// Group errors by their catalog type and name.
+    /// Errors which don't pertain to a catalog specification are grouped
+    /// under `(None, "")`.
+    pub fn group_by_catalog(&self) -> BTreeMap<(Option<models::CatalogType>, &str), Vec<&Error>> {
+        let mut groups: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for error in self.iter() {
+            groups
+                .entry((error.catalog_type, error.catalog_name.as_str()))
+                .or_default()
+                .push(error);
+        }
+        groups
+    }
+
+    /// Catalog names having at least one error, in sorted order.
+    pub fn catalog_names(&self) -> BTreeSet<&str> {
+        self.iter()
+            .map(|error| error.catalog_name.as_str())
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Serialize)]
 pub struct GrantRef<'a> {
     subject_role: &'a str,
@@ -397,6 +539,34 @@ pub fn parse_synthetic_scope(url: &url::Url) -> Option<(models::CatalogType, Str
     Some((catalog_type, catalog_name))
 }
 
+/// Attempts to parse a catalog type and name from a synthetic scope URL,
+/// or from a URL having a fragment pointer into a catalog specification,
+/// such as `file:///flow.yaml#/collections/acmeCo~1anvils/schema`.
+pub fn parse_scope_catalog(url: &url::Url) -> Option<(models::CatalogType, String)> {
+    if let Some(parsed) = parse_synthetic_scope(url) {
+        return Some(parsed);
+    }
+    let ptr = doc::Pointer::from_str(url.fragment()?);
+    let mut tokens = ptr.iter();
+
+    let catalog_type = match tokens.next()? {
+        doc::ptr::Token::Property(prop) => match prop.as_str() {
+            "captures" => models::CatalogType::Capture,
+            "collections" => models::CatalogType::Collection,
+            "materializations" => models::CatalogType::Materialization,
+            "tests" => models::CatalogType::Test,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let catalog_name = match tokens.next()? {
+        doc::ptr::Token::Property(name) => name.clone(),
+        // Catalog names are never bare indices.
+        _ => return None,
+    };
+    Some((catalog_type, catalog_name))
+}
+
 /// Generate a synthetic scope URL for a given catalog type and name, for when a meaningful scope
 /// URL is otherwise not avaialble. The `catalog_type` can be a `models::CatalogType` or a `&str`.
 pub fn synthetic_scope(catalog_type: impl AsRef<str>, catalog_name: impl AsRef<str>) -> url::Url {
@@ -415,6 +585,7 @@ primitive_sql_types!(
     url::Url => "TEXT",
     bool => "BOOLEAN",
     u32 => "INTEGER",
+    i64 => "INTEGER",
 );
 
 // primitive_sql_types generates SqlColumn but not Column implementations.
@@ -439,27 +610,31 @@ impl Column for bool {
     }
 }
 impl Column for u32 {}
+impl Column for i64 {}
 
 string_wrapper_types!(
-    models::Capture,
-    models::Collection,
-    models::Materialization,
-    models::Prefix,
-    models::Test,
+    models::Capture => true,
+    models::Collection => true,
+    models::Materialization => true,
+    models::Prefix => false,
+    models::Test => true,
 );
 
 json_sql_types!(
     Vec<String>,
+    Vec<models::FieldLineage>,
     Vec<models::Store>,
     models::Capability,
     models::CaptureDef,
     models::CatalogType,
     models::CollectionDef,
     models::Id,
+    models::KeyMigrationPlan,
     models::MaterializationDef,
     models::Name,
     models::RawValue,
     models::Schema,
+    models::SourceCapturePreview,
     models::TestDef,
     proto_flow::flow::ContentType,
     uuid::Uuid,
@@ -471,6 +646,7 @@ proto_sql_types!(
     proto_flow::flow::CaptureSpec,
     proto_flow::flow::CollectionSpec,
     proto_flow::flow::MaterializationSpec,
+    proto_flow::flow::OAuth2,
     proto_flow::flow::TestSpec,
     proto_flow::flow::build_api::Config,
     proto_flow::materialize::response::Validated,
@@ -535,6 +711,11 @@ mod test {
             key q1: u32,
             key q2: u32,
         }
+
+        table Names (row Name, sql "names") {
+            key name: models::Collection,
+            val n: u32,
+        }
     );
 
     #[test]
@@ -609,4 +790,229 @@ mod test {
 
         assert_eq!(joined, vec![2, 4, 5]);
     }
+
+    #[test]
+    fn test_upsert_remove_and_retain() {
+        let mut tbl = Bars::new();
+        assert!(tbl.upsert_row(10, 1).is_none());
+        assert!(tbl.upsert_row(0, 2).is_none());
+        assert!(tbl.upsert_row(20, 3).is_none());
+
+        // Upserting an existing key replaces its row in place.
+        let prev = tbl.upsert_row(10, 4).unwrap();
+        assert_eq!((prev.b1, prev.b2), (10, 1));
+        assert!(tbl.upsert_row(5, 5).is_none());
+
+        assert_eq!(
+            tbl.iter().map(|r| (r.b1, r.b2)).collect::<Vec<_>>(),
+            vec![(0, 2), (5, 5), (10, 4), (20, 3)]
+        );
+
+        let removed = tbl.remove_by_key(&5).unwrap();
+        assert_eq!((removed.b1, removed.b2), (5, 5));
+        assert!(tbl.remove_by_key(&5).is_none());
+
+        tbl.retain(|r| r.b1 != 0);
+        assert_eq!(
+            tbl.iter().map(|r| (r.b1, r.b2)).collect::<Vec<_>>(),
+            vec![(10, 4), (20, 3)]
+        );
+
+        // Joins continue to work against the maintained ordering.
+        let joined: Vec<(u32, usize)> = tbl
+            .inner_join(
+                [(0u32, 1usize), (5, 2), (10, 3), (20, 4)].into_iter(),
+                |bar, _k, v| Some((bar.b2, v)),
+            )
+            .collect();
+
+        assert_eq!(joined, vec![(4, 3), (3, 4)]);
+
+        // Composite keys are upserted on the full key.
+        let mut tbl = Quibs::new();
+        tbl.upsert_row(10, 90);
+        tbl.upsert_row(10, 34);
+        assert!(tbl.upsert_row(10, 90).is_some());
+        assert_eq!(
+            tbl.iter().map(|r| (r.q1, r.q2)).collect::<Vec<_>>(),
+            vec![(10, 34), (10, 90)]
+        );
+    }
+
+    #[test]
+    fn test_index_by_and_key_prefix() {
+        let names = Names::from_iter(
+            [
+                "acmeCo/b",
+                "acmeCo",
+                "acmeCo/a/c",
+                "acmeCoX",
+                "other/a",
+                "acmeCo/a",
+            ]
+            .into_iter()
+            .enumerate()
+            .map(|(n, name)| Name {
+                name: models::Collection::new(name),
+                n: n as u32 % 2,
+            }),
+        );
+
+        assert_eq!(
+            names
+                .get_by_key_prefix("acmeCo/")
+                .iter()
+                .map(|r| r.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["acmeCo/a", "acmeCo/a/c", "acmeCo/b"]
+        );
+        assert!(names.get_by_key_prefix("missing/").is_empty());
+        assert_eq!(names.get_by_key_prefix("").len(), 6);
+
+        let index = names.index_by(|r| r.n);
+        assert_eq!(
+            index
+                .iter()
+                .map(|(n, rows)| (*n, rows.iter().map(|r| r.name.as_str()).collect()))
+                .collect::<Vec<(u32, Vec<&str>)>>(),
+            vec![
+                (0, vec!["acmeCo/a/c", "acmeCo/b", "other/a"]),
+                (1, vec!["acmeCo", "acmeCo/a", "acmeCoX"]),
+            ]
+        );
+    }
+
+    #[cfg(feature = "persist")]
+    #[test]
+    fn test_persist_iter_and_load_prefix() {
+        let db = rusqlite::Connection::open_in_memory().unwrap();
+
+        // Rows are persisted directly from an iterator.
+        let count = Names::persist_iter(
+            &db,
+            ["acmeCo/a_b", "acmeCo/aXb/c", "acmeCo/a_b/c", "other/a_b"]
+                .into_iter()
+                .enumerate()
+                .map(|(n, name)| Name {
+                    name: models::Collection::new(name),
+                    n: n as u32,
+                }),
+        )
+        .unwrap();
+        assert_eq!(count, 4);
+
+        let bars = Bars::from_iter([Bar { b1: 1, b2: 2 }]);
+        persist_tables(&db, &[&bars]).unwrap();
+
+        // Names are filtered by literal prefix (`_` isn't a wildcard),
+        // while tables not keyed by catalog name are loaded in full.
+        let (mut names, mut bars) = (Names::new(), Bars::new());
+        load_tables_prefix(&db, &mut [&mut names, &mut bars], "acmeCo/a_b").unwrap();
+
+        assert_eq!(
+            names
+                .iter()
+                .map(|r| (r.name.as_str(), r.n))
+                .collect::<Vec<_>>(),
+            vec![("acmeCo/a_b", 0), ("acmeCo/a_b/c", 2)]
+        );
+        assert_eq!(bars.len(), 1);
+    }
+
+    #[cfg(feature = "persist")]
+    #[test]
+    fn test_persist_versions_and_load_as_of() {
+        let db = rusqlite::Connection::open_in_memory().unwrap();
+        let id = |n: u8| models::Id::new([0, 0, 0, 0, 0, 0, 0, n]);
+        let names = |rows: &[(&str, u32)]| {
+            Names::from_iter(rows.iter().map(|(name, n)| Name {
+                name: models::Collection::new(*name),
+                n: *n,
+            }))
+        };
+
+        persist_tables_version(&db, &[&names(&[("a", 1), ("b", 1)])], id(10)).unwrap();
+        // "a" is unchanged, "b" is updated, and "c" is added.
+        persist_tables_version(&db, &[&names(&[("a", 1), ("b", 2), ("c", 1)])], id(20)).unwrap();
+        // "a" is removed.
+        persist_tables_version(&db, &[&names(&[("b", 2), ("c", 1)])], id(30)).unwrap();
+
+        let as_of = |pub_id| {
+            let mut out = Names::new();
+            load_tables_as_of(&db, &mut [&mut out], pub_id).unwrap();
+            out.iter()
+                .map(|r| (r.name.to_string(), r.n))
+                .collect::<Vec<_>>()
+        };
+        let expect = |rows: &[(&str, u32)]| {
+            rows.iter()
+                .map(|(name, n)| (name.to_string(), *n))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(as_of(id(5)), expect(&[]));
+        assert_eq!(as_of(id(10)), expect(&[("a", 1), ("b", 1)]));
+        assert_eq!(as_of(id(15)), expect(&[("a", 1), ("b", 1)]));
+        assert_eq!(as_of(id(20)), expect(&[("a", 1), ("b", 2), ("c", 1)]));
+        assert_eq!(as_of(id(30)), expect(&[("b", 2), ("c", 1)]));
+        assert_eq!(as_of(id(99)), expect(&[("b", 2), ("c", 1)]));
+
+        // The unchanged row "a" was stored only once.
+        let count: usize = db
+            .query_row(
+                "SELECT COUNT(*) FROM names_versions WHERE name = ?1",
+                ["a"],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_error_catalog_columns() {
+        let url = |s: &str| url::Url::parse(s).unwrap();
+
+        let mut errors = crate::Errors::new();
+        errors.insert_scoped(
+            url("test://example/catalog.yaml#/collections/acmeCo~1anvils/schema"),
+            anyhow::anyhow!("one"),
+        );
+        errors.insert_scoped(
+            crate::synthetic_scope(models::CatalogType::Capture, "acmeCo/source"),
+            anyhow::anyhow!("two"),
+        );
+        errors.insert_scoped(
+            url("test://example/catalog.yaml#/collections/acmeCo~1anvils/key"),
+            anyhow::anyhow!("three"),
+        );
+        errors.insert_scoped(
+            url("flow://storageMapping/acmeCo/"),
+            anyhow::anyhow!("four"),
+        );
+        // An array index is not a catalog name.
+        errors.insert_scoped(
+            url("test://example/catalog.yaml#/tests/0/ingest"),
+            anyhow::anyhow!("five"),
+        );
+
+        let groups: Vec<_> = errors
+            .group_by_catalog()
+            .into_iter()
+            .map(|((catalog_type, name), errors)| (catalog_type, name, errors.len()))
+            .collect();
+
+        assert_eq!(
+            groups,
+            vec![
+                (None, "", 2),
+                (Some(models::CatalogType::Capture), "acmeCo/source", 1),
+                (Some(models::CatalogType::Collection), "acmeCo/anvils", 2),
+            ]
+        );
+        assert_eq!(
+            errors.catalog_names().into_iter().collect::<Vec<_>>(),
+            vec!["acmeCo/anvils", "acmeCo/source"]
+        );
+        assert_eq!(errors[1].to_draft_error().catalog_name, "acmeCo/source");
+    }
 }
@@ -2,9 +2,9 @@ use anyhow::Context;
 use serde_json::value::RawValue;
 
 use crate::{
-    DataPlanes, Errors, InferredSchemas, LiveCapture, LiveCaptures, LiveCollection,
-    LiveCollections, LiveMaterialization, LiveMaterializations, LiveTest, LiveTests,
-    StorageMappings,
+    CollectionThroughputs, DataPlanes, Errors, InferredSchemas, LiveCapture, LiveCaptures,
+    LiveCollection, LiveCollections, LiveMaterialization, LiveMaterializations, LiveTest,
+    LiveTests, Prefixes, StorageMappings, Tenants,
 };
 
 // CatalogResolver is a trait which maps `catalog_names`, such as those from
@@ -25,10 +25,116 @@ pub trait CatalogResolver {
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = crate::LiveCatalog> + Send + 'a>>;
 }
 
+/// CatalogPage is a page of live specifications to be resolved by a
+/// PagedCatalogResolver, identified by exact catalog names and by
+/// catalog name prefixes which are resolved in their entirety.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CatalogPage<'a> {
+    pub names: Vec<&'a str>,
+    pub prefixes: Vec<&'a str>,
+}
+
+// PagedCatalogResolver is a trait which resolves live specifications
+// a page at a time, allowing very large drafts to be resolved with
+// bounded requests which are issued concurrently.
+pub trait PagedCatalogResolver: Sync {
+    /// Fetch live specifications of the provided `page`.
+    ///
+    /// A PagedCatalogResolver MUST return all specifications matched by
+    /// the page's names or prefixes, and MAY return additional specifications,
+    /// as with CatalogResolver. Tables which aren't keyed on catalog name,
+    /// like data-planes and storage mappings, may be returned by any page.
+    fn resolve_page<'a>(
+        &'a self,
+        page: CatalogPage<'a>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = crate::LiveCatalog> + Send + 'a>>;
+}
+
+/// Pagination bounds the pages of a PagedCatalogResolver.
+#[derive(Debug, Clone)]
+pub struct Pagination {
+    /// Maximum number of names and prefixes of each page.
+    pub page_size: usize,
+    /// Maximum number of pages which are resolved concurrently.
+    pub concurrency: usize,
+    /// Names sharing a parent prefix are resolved by that prefix
+    /// if there are at least this many of them.
+    pub prefix_threshold: usize,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            page_size: 500,
+            concurrency: 8,
+            prefix_threshold: 100,
+        }
+    }
+}
+
+/// Plan the pages which resolve `catalog_names`.
+///
+/// Names are grouped by their parent prefix, and a prefix having at least
+/// `prefix_threshold` names is pushed down in place of its names. A pushed-down
+/// prefix may match specifications which weren't named, which is permitted
+/// over-fetching. Names and prefixes covered by another pushed-down prefix
+/// are omitted, so that pages are disjoint. At least one (possibly empty)
+/// page is always returned.
+pub fn paginate<'a>(
+    mut catalog_names: Vec<&'a str>,
+    pagination: &Pagination,
+) -> Vec<CatalogPage<'a>> {
+    catalog_names.sort();
+    catalog_names.dedup();
+
+    let mut groups: std::collections::BTreeMap<&str, usize> = Default::default();
+    for name in &catalog_names {
+        let parent = &name[..name.rfind('/').map(|i| i + 1).unwrap_or_default()];
+        *groups.entry(parent).or_default() += 1;
+    }
+
+    let mut prefixes: Vec<&str> = groups
+        .into_iter()
+        .filter(|(prefix, count)| !prefix.is_empty() && *count >= pagination.prefix_threshold)
+        .map(|(prefix, _count)| prefix)
+        .collect();
+
+    // Prefixes are ordered, so a nested prefix follows its covering prefix.
+    prefixes.dedup_by(|next, prev| next.starts_with(*prev));
+
+    // As prefixes are disjoint, a covering prefix of a name is the greatest
+    // prefix which is less than or equal to it.
+    catalog_names.retain(|name| {
+        let index = prefixes.partition_point(|prefix| prefix <= name);
+        !(index != 0 && name.starts_with(prefixes[index - 1]))
+    });
+
+    let page_size = pagination.page_size.max(1);
+
+    let prefix_pages = prefixes.chunks(page_size).map(|prefixes| CatalogPage {
+        names: Vec::new(),
+        prefixes: prefixes.to_vec(),
+    });
+    let name_pages = catalog_names.chunks(page_size).map(|names| CatalogPage {
+        names: names.to_vec(),
+        prefixes: Vec::new(),
+    });
+
+    let mut pages: Vec<_> = prefix_pages.chain(name_pages).collect();
+
+    // Always resolve at least one page, which resolves tables that
+    // aren't keyed on catalog name.
+    if pages.is_empty() {
+        pages.push(CatalogPage::default());
+    }
+    pages
+}
+
 /// LiveRow is a common trait of rows reflecting live specifications.
 pub trait LiveRow: crate::Row {
     type ModelDef: models::ModelDef;
     type BuiltSpec: Clone;
+    type DraftRow: crate::DraftRow<Key = Self::Key, ModelDef = Self::ModelDef>;
 
     // Name of this specification.
     fn catalog_name(&self) -> &Self::Key;
@@ -48,11 +154,24 @@ pub trait LiveRow: crate::Row {
     fn spec(&self) -> &Self::BuiltSpec;
     /// Hash of the dependencies that were used to build this row
     fn dependency_hash(&self) -> Option<&str>;
+
+    /// Build a DraftRow which touches this live specification,
+    /// re-building it from its current model.
+    fn as_touch_draft(&self) -> Self::DraftRow {
+        crate::DraftRow::new(
+            self.catalog_name().clone(),
+            self.scope(),
+            Some(self.last_pub_id()),
+            Some(self.model().clone()),
+            true,
+        )
+    }
 }
 
 impl LiveRow for crate::LiveCapture {
     type ModelDef = models::CaptureDef;
     type BuiltSpec = proto_flow::flow::CaptureSpec;
+    type DraftRow = crate::DraftCapture;
 
     fn catalog_name(&self) -> &Self::Key {
         &self.capture
@@ -86,6 +205,7 @@ impl LiveRow for crate::LiveCapture {
 impl LiveRow for crate::LiveCollection {
     type ModelDef = models::CollectionDef;
     type BuiltSpec = proto_flow::flow::CollectionSpec;
+    type DraftRow = crate::DraftCollection;
 
     fn catalog_name(&self) -> &Self::Key {
         &self.collection
@@ -122,6 +242,7 @@ impl LiveRow for crate::LiveCollection {
 impl LiveRow for crate::LiveMaterialization {
     type ModelDef = models::MaterializationDef;
     type BuiltSpec = proto_flow::flow::MaterializationSpec;
+    type DraftRow = crate::DraftMaterialization;
 
     fn catalog_name(&self) -> &Self::Key {
         &self.materialization
@@ -158,6 +279,7 @@ impl LiveRow for crate::LiveMaterialization {
 impl LiveRow for crate::LiveTest {
     type ModelDef = models::TestDef;
     type BuiltSpec = proto_flow::flow::TestSpec;
+    type DraftRow = crate::DraftTest;
 
     fn catalog_name(&self) -> &Self::Key {
         &self.test
@@ -202,23 +324,29 @@ impl LiveCatalog {
         // This de-structure ensures we can't fail to update as tables change.
         let Self {
             captures,
+            collection_throughputs,
             collections,
             data_planes,
             errors,
             inferred_schemas,
             materializations,
+            prefixes,
             storage_mappings,
+            tenants,
             tests,
         } = self;
 
         vec![
             captures,
+            collection_throughputs,
             collections,
             data_planes,
             errors,
             inferred_schemas,
             materializations,
+            prefixes,
             storage_mappings,
+            tenants,
             tests,
         ]
     }
@@ -227,23 +355,29 @@ impl LiveCatalog {
     pub fn as_tables_mut(&mut self) -> Vec<&mut dyn crate::SqlTableObj> {
         let Self {
             captures,
+            collection_throughputs,
             collections,
             data_planes,
             errors,
             inferred_schemas,
             materializations,
+            prefixes,
             storage_mappings,
+            tenants,
             tests,
         } = self;
 
         vec![
             captures,
+            collection_throughputs,
             collections,
             data_planes,
             errors,
             inferred_schemas,
             materializations,
+            prefixes,
             storage_mappings,
+            tenants,
             tests,
         ]
     }
@@ -253,16 +387,50 @@ impl LiveCatalog {
 #[derive(Default, Debug)]
 pub struct LiveCatalog {
     pub captures: LiveCaptures,
+    pub collection_throughputs: CollectionThroughputs,
     pub collections: LiveCollections,
     pub data_planes: DataPlanes,
     pub errors: Errors,
     pub inferred_schemas: InferredSchemas,
     pub materializations: LiveMaterializations,
+    pub prefixes: Prefixes,
     pub storage_mappings: StorageMappings,
+    pub tenants: Tenants,
     pub tests: LiveTests,
 }
 
 impl LiveCatalog {
+    /// Merge `other` into this LiveCatalog, as when it's resolved in pages.
+    /// Rows of `other` replace rows of this LiveCatalog having equal keys.
+    pub fn merge(&mut self, other: LiveCatalog) {
+        let LiveCatalog {
+            captures,
+            collection_throughputs,
+            collections,
+            data_planes,
+            errors,
+            inferred_schemas,
+            materializations,
+            prefixes,
+            storage_mappings,
+            tenants,
+            tests,
+        } = other;
+
+        self.captures.merge(captures);
+        self.collection_throughputs.merge(collection_throughputs);
+        self.collections.merge(collections);
+        self.data_planes.merge(data_planes);
+        // Errors are not keyed, and are not de-duplicated.
+        self.errors.extend(errors.into_iter());
+        self.inferred_schemas.merge(inferred_schemas);
+        self.materializations.merge(materializations);
+        self.prefixes.merge(prefixes);
+        self.storage_mappings.merge(storage_mappings);
+        self.tenants.merge(tenants);
+        self.tests.merge(tests);
+    }
+
     pub fn is_empty(&self) -> bool {
         self.captures.is_empty()
             && self.collections.is_empty()
@@ -380,3 +548,88 @@ impl LiveCatalog {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_paginate_with_prefix_push_down() {
+        let names = vec![
+            "acmeCo/other",
+            "acmeCo/also-other",
+            "acmeCo/big/one",
+            "acmeCo/big/two",
+            "acmeCo/big/three",
+            "acmeCo/big/nested/one",
+            "acmeCo/big/nested/two",
+            "acmeCo/big/nested/three",
+            "acmeCo/small/one",
+            "acmeCo/small/two",
+            "otherCo/one",
+            "otherCo/two",
+            "otherCo/two", // Duplicate.
+            "otherCo/three",
+            "unprefixed",
+        ];
+
+        let pages = paginate(
+            names,
+            &Pagination {
+                page_size: 3,
+                concurrency: 1,
+                prefix_threshold: 3,
+            },
+        );
+
+        insta::assert_debug_snapshot!(pages, @r###"
+        [
+            CatalogPage {
+                names: [],
+                prefixes: [
+                    "acmeCo/big/",
+                    "otherCo/",
+                ],
+            },
+            CatalogPage {
+                names: [
+                    "acmeCo/also-other",
+                    "acmeCo/other",
+                    "acmeCo/small/one",
+                ],
+                prefixes: [],
+            },
+            CatalogPage {
+                names: [
+                    "acmeCo/small/two",
+                    "unprefixed",
+                ],
+                prefixes: [],
+            },
+        ]
+        "###);
+    }
+
+    #[test]
+    fn test_merge_replaces_equal_keys() {
+        let mut live = LiveCatalog::default();
+        live.tenants
+            .insert_row(models::Prefix::new("a/"), models::Id::new([1; 8]), false);
+
+        let mut page = LiveCatalog::default();
+        page.tenants
+            .insert_row(models::Prefix::new("a/"), models::Id::new([2; 8]), true);
+        page.tenants
+            .insert_row(models::Prefix::new("b/"), models::Id::new([3; 8]), false);
+
+        live.merge(page);
+
+        assert_eq!(
+            live.tenants
+                .iter()
+                .map(|t| (t.tenant.as_str(), t.strict_schemas))
+                .collect::<Vec<_>>(),
+            vec![("a/", true), ("b/", false)]
+        );
+    }
+}
@@ -20,6 +20,10 @@ pub trait SqlColumn: Sized + Column {
     fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>>;
     /// Convert this TableColumn from SQL.
     fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self>;
+    /// Is this TableColumn a catalog name, which may be filtered by prefix?
+    fn is_catalog_name() -> bool {
+        false
+    }
 }
 
 /// Row is a row of a Table.
@@ -28,6 +32,11 @@ pub trait Row: std::fmt::Debug + Sized {
 
     fn cmp_key(&self, other: &Self::Key) -> std::cmp::Ordering;
     fn cmp_row(&self, other: &Self) -> std::cmp::Ordering;
+
+    /// Key of this Row, if it has exactly one key column.
+    fn single_key(&self) -> Option<&Self::Key> {
+        None
+    }
 }
 
 impl<'a, T: Row> Row for &'a T {
@@ -40,6 +49,10 @@ impl<'a, T: Row> Row for &'a T {
     fn cmp_row(&self, other: &Self) -> std::cmp::Ordering {
         T::cmp_row(*self, other)
     }
+
+    fn single_key(&self) -> Option<&Self::Key> {
+        T::single_key(*self)
+    }
 }
 
 #[cfg(feature = "persist")]
@@ -47,6 +60,8 @@ impl<'a, T: Row> Row for &'a T {
 pub trait SqlRow: Row {
     fn sql_table_name() -> &'static str;
     fn sql_columns() -> Vec<(&'static str, &'static str)>;
+    /// SQL column of the catalog name by which rows are keyed, if any.
+    fn sql_catalog_name_column() -> Option<&'static str>;
 
     /// Persist this row, using a Statement previously prepared from Table::insert_sql().
     fn persist<'stmt>(&self, stmt: &mut rusqlite::Statement<'stmt>) -> rusqlite::Result<()>;
@@ -72,6 +87,22 @@ pub trait SqlTableObj {
         filter: &str,
         params: &[&dyn rusqlite::types::ToSql],
     ) -> rusqlite::Result<()>;
+    /// Load rows from the database having a catalog name which starts with `prefix`.
+    /// Tables which are not keyed by a catalog name are loaded in full.
+    fn load_prefix(&mut self, db: &rusqlite::Connection, prefix: &str) -> rusqlite::Result<()>;
+    /// Persist the rows of this Table as the version which is valid as of `pub_id`,
+    /// into a companion table of row versions and their validity intervals.
+    /// Rows which are unchanged from the prior version remain valid, while
+    /// changed or removed rows of the prior version are closed as of `pub_id`.
+    /// Versions must be persisted in ascending `pub_id` order.
+    fn persist_version(
+        &self,
+        db: &rusqlite::Connection,
+        pub_id: models::Id,
+    ) -> rusqlite::Result<()>;
+    /// Load rows of the version of this Table which was valid as of `pub_id`.
+    fn load_as_of(&mut self, db: &rusqlite::Connection, pub_id: models::Id)
+        -> rusqlite::Result<()>;
 }
 
 /// Table is a collection of Rows.
@@ -97,6 +128,22 @@ impl<R: Row> Table<R> {
         self.reindex();
     }
 
+    /// Merge the Rows of `other` into this keyed Table,
+    /// replacing Rows having equal keys.
+    pub fn merge(&mut self, other: Self) {
+        // `extend` is a stable sort, so Rows of `other` follow equal Rows of `self`.
+        self.extend(other.0.into_iter());
+
+        self.0.dedup_by(|next, prev| {
+            if next.cmp_row(prev).is_eq() {
+                std::mem::swap(next, prev);
+                true
+            } else {
+                false
+            }
+        });
+    }
+
     /// Get a Row having the given key from the table.
     /// If multiple rows match the key, an arbitrary one is returned.
     pub fn get_key<'s>(&'s self, key: &R::Key) -> Option<&'s R> {
@@ -223,12 +270,54 @@ impl<R: Row> Table<R> {
         self.upsert(row, |_, _| {});
     }
 
+    /// Build a secondary index of the Table's Rows, keyed on the result of `f`.
+    /// Rows of each index entry are in their Table order.
+    pub fn index_by<'s, K, F>(&'s self, f: F) -> std::collections::BTreeMap<K, Vec<&'s R>>
+    where
+        K: Ord,
+        F: Fn(&R) -> K,
+    {
+        let mut index = std::collections::BTreeMap::<K, Vec<&'s R>>::new();
+
+        for row in self.0.iter() {
+            index.entry(f(row)).or_default().push(row);
+        }
+        index
+    }
+
+    /// Retain only the Rows for which `keep` returns true.
+    /// The relative ordering of retained Rows is preserved.
+    pub fn retain<F>(&mut self, keep: F)
+    where
+        F: FnMut(&R) -> bool,
+    {
+        self.0.retain(keep)
+    }
+
     // Re-index the Table as a bulk operation.
     fn reindex(&mut self) {
         self.0.sort_by(|l, r| l.cmp_row(r));
     }
 }
 
+impl<R: Row> Table<R>
+where
+    R::Key: AsRef<str>,
+{
+    /// Return the ordered Rows having a string key which starts with `prefix`.
+    pub fn get_by_key_prefix(&self, prefix: &str) -> &[R] {
+        let key = |row: &R| -> &str {
+            row.single_key()
+                .expect("rows having a string key have a single key column")
+                .as_ref()
+        };
+        let begin = self.0.partition_point(|row| key(row) < prefix);
+        let end = begin + self.0[begin..].partition_point(|row| key(row).starts_with(prefix));
+
+        &self.0[begin..end]
+    }
+}
+
 impl<R: Row> Default for Table<R> {
     fn default() -> Self {
         Self::new()
@@ -284,9 +373,14 @@ impl<R: Row> std::fmt::Debug for Table<R> {
 impl<R: SqlRow> Table<R> {
     /// SQL for inserting table rows.
     fn insert_sql() -> String {
+        Self::insert_sql_into(R::sql_table_name())
+    }
+
+    /// SQL for inserting table rows into the named `table`.
+    fn insert_sql_into(table: &str) -> String {
         [
             "INSERT INTO ",
-            R::sql_table_name(),
+            table,
             " ( ",
             R::sql_columns()
                 .iter()
@@ -303,6 +397,11 @@ impl<R: SqlRow> Table<R> {
     /// SQL for querying table rows.
     /// Filtering WHERE clauses may be appended to the returned string.
     fn select_sql() -> String {
+        Self::select_sql_from(R::sql_table_name())
+    }
+
+    /// SQL for querying table rows from the named `table`.
+    fn select_sql_from(table: &str) -> String {
         [
             "SELECT ",
             R::sql_columns()
@@ -311,7 +410,7 @@ impl<R: SqlRow> Table<R> {
                 .join(", ")
                 .as_str(),
             " FROM ",
-            R::sql_table_name(),
+            table,
             // Closing ';' is omitted so that WHERE clauses may be chained.
             // rusqlite is okay with a non-closed statement.
         ]
@@ -330,11 +429,7 @@ impl<R: SqlRow> SqlTableObj for Table<R> {
             "CREATE TABLE IF NOT EXISTS ",
             R::sql_table_name(),
             " ( ",
-            R::sql_columns()
-                .iter()
-                .map(|(name, typ)| format!("{name} {typ}"))
-                .join(", ")
-                .as_str(),
+            Self::columns_sql().as_str(),
             " );",
         ]
         .concat()
@@ -373,6 +468,144 @@ impl<R: SqlRow> SqlTableObj for Table<R> {
         );
         Ok(())
     }
+
+    fn load_prefix(&mut self, db: &rusqlite::Connection, prefix: &str) -> rusqlite::Result<()> {
+        match R::sql_catalog_name_column() {
+            // Compare a leading substring rather than using LIKE,
+            // as '_' is both a LIKE wildcard and common within catalog names.
+            Some(column) => self.load_where(
+                db,
+                &format!("substr({column}, 1, length(?1)) = ?1"),
+                &[&prefix],
+            ),
+            None => self.load_all(db),
+        }
+    }
+
+    fn persist_version(
+        &self,
+        db: &rusqlite::Connection,
+        pub_id: models::Id,
+    ) -> rusqlite::Result<()> {
+        let (versions, staged) = Self::version_table_names();
+        let columns = Self::columns_sql();
+
+        db.execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS {versions} ( {columns}, valid_from TEXT NOT NULL, valid_to TEXT );
+            CREATE TEMP TABLE IF NOT EXISTS {staged} ( {columns} );
+            DELETE FROM {staged};"
+        ))?;
+
+        let mut stmt = db.prepare(&Self::insert_sql_into(&staged))?;
+        for row in &self.0 {
+            row.persist(&mut stmt)?;
+        }
+        std::mem::drop(stmt);
+
+        // Rows are unchanged if every column is identical. `IS` compares NULLs as equal.
+        let unchanged = |lhs: &str, rhs: &str| {
+            R::sql_columns()
+                .iter()
+                .map(|(name, _)| format!("{lhs}.{name} IS {rhs}.{name}"))
+                .join(" AND ")
+        };
+        let names = R::sql_columns().iter().map(|(name, _)| *name).join(", ");
+        let pub_id = pub_id.to_string();
+
+        // Close open versions which were changed or removed.
+        db.execute(
+            &format!(
+                "UPDATE {versions} SET valid_to = ?1 WHERE valid_to IS NULL AND NOT EXISTS (
+                    SELECT 1 FROM {staged} WHERE {}
+                );",
+                unchanged(staged.as_str(), versions.as_str()),
+            ),
+            [&pub_id],
+        )?;
+        // Open versions of rows which were changed or added.
+        db.execute(
+            &format!(
+                "INSERT INTO {versions} ( {names}, valid_from ) SELECT {names}, ?1 FROM {staged} s
+                WHERE NOT EXISTS (
+                    SELECT 1 FROM {versions} v WHERE v.valid_to IS NULL AND {}
+                );",
+                unchanged("s", "v"),
+            ),
+            [&pub_id],
+        )?;
+        db.execute_batch(&format!("DELETE FROM {staged};"))?;
+
+        Ok(())
+    }
+
+    fn load_as_of(
+        &mut self,
+        db: &rusqlite::Connection,
+        pub_id: models::Id,
+    ) -> rusqlite::Result<()> {
+        let (versions, _staged) = Self::version_table_names();
+
+        let mut stmt = db.prepare(&format!(
+            "{} WHERE valid_from <= ?1 AND (valid_to IS NULL OR valid_to > ?1)",
+            Self::select_sql_from(&versions)
+        ))?;
+        self.extend(
+            stmt.query_map([pub_id.to_string()], R::scan)?
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter(),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(feature = "persist")]
+impl<R: SqlRow> Table<R> {
+    /// SQL for the column definitions of this Table's schema.
+    fn columns_sql() -> String {
+        R::sql_columns()
+            .iter()
+            .map(|(name, typ)| format!("{name} {typ}"))
+            .join(", ")
+    }
+
+    /// Names of the table of row versions, and of its temporary staging table.
+    fn version_table_names() -> (String, String) {
+        (
+            format!("{}_versions", R::sql_table_name()),
+            format!("{}_staged", R::sql_table_name()),
+        )
+    }
+}
+
+#[cfg(feature = "persist")]
+impl<R: SqlRow> Table<R> {
+    /// Persist rows of this Table's type into the database as they're yielded
+    /// by an iterator, without first collecting them into a Table.
+    /// The table schema is created if it doesn't yet exist, and all rows are
+    /// written within a single transaction using one prepared statement.
+    /// Returns the number of persisted rows.
+    pub fn persist_iter<I>(db: &rusqlite::Connection, rows: I) -> rusqlite::Result<usize>
+    where
+        I: IntoIterator,
+        I::Item: std::borrow::Borrow<R>,
+    {
+        use std::borrow::Borrow;
+
+        db.execute_batch("BEGIN IMMEDIATE;")?;
+        db.execute_batch(&Self::new().create_table_sql())?;
+
+        let mut stmt = db.prepare(&Self::insert_sql())?;
+        let mut count = 0;
+
+        for row in rows {
+            row.borrow().persist(&mut stmt)?;
+            count += 1;
+        }
+        std::mem::drop(stmt);
+
+        db.execute_batch("COMMIT;")?;
+        Ok(count)
+    }
 }
 
 /// Trait for accepting arguments which may be owned, or can be cloned.
@@ -455,6 +688,55 @@ pub fn load_tables(
     Ok(())
 }
 
+#[cfg(feature = "persist")]
+/// Persist a dynamic set of tables as their version which is valid as of
+/// `pub_id`, retaining prior versions so they may be loaded with `load_tables_as_of`.
+pub fn persist_tables_version(
+    db: &rusqlite::Connection,
+    tables: &[&dyn SqlTableObj],
+    pub_id: models::Id,
+) -> rusqlite::Result<()> {
+    db.execute_batch("BEGIN IMMEDIATE;")?;
+    for table in tables {
+        table.persist_version(db, pub_id)?;
+    }
+    db.execute_batch("COMMIT;")?;
+    Ok(())
+}
+
+#[cfg(feature = "persist")]
+/// Load the versions of a dynamic set of tables which were valid as of `pub_id`.
+pub fn load_tables_as_of(
+    db: &rusqlite::Connection,
+    tables: &mut [&mut dyn SqlTableObj],
+    pub_id: models::Id,
+) -> rusqlite::Result<()> {
+    db.execute_batch("BEGIN;")?;
+    for table in tables {
+        table.load_as_of(db, pub_id)?;
+    }
+    db.execute_batch("COMMIT;")?;
+    Ok(())
+}
+
+#[cfg(feature = "persist")]
+/// Load rows of a dynamic set of tables from the database, restricted to
+/// catalog names having the given `prefix`. This allows for loading a
+/// portion of a very large build without holding all of it in memory.
+/// Tables which are not keyed by catalog name are loaded in full.
+pub fn load_tables_prefix(
+    db: &rusqlite::Connection,
+    tables: &mut [&mut dyn SqlTableObj],
+    prefix: &str,
+) -> rusqlite::Result<()> {
+    db.execute_batch("BEGIN;")?;
+    for table in tables {
+        table.load_prefix(db, prefix)?;
+    }
+    db.execute_batch("COMMIT;")?;
+    Ok(())
+}
+
 /// primitive_sql_types establishes TableColumn implementations for
 /// types already having rusqlite FromSql / ToSql implementations.
 macro_rules! primitive_sql_types {
@@ -481,9 +763,9 @@ macro_rules! primitive_sql_types {
 }
 
 /// string_wrapper_types establishes TableColumn implementations for
-/// newtype String wrappers.
+/// newtype String wrappers, and whether each is a catalog name.
 macro_rules! string_wrapper_types {
-    ($($rust_type:ty,)*) => {
+    ($($rust_type:ty => $is_catalog_name:literal,)*) => {
         $(
         impl Column for $rust_type {
             fn column_fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -504,6 +786,9 @@ macro_rules! string_wrapper_types {
             ) -> rusqlite::types::FromSqlResult<Self> {
                 Ok(Self::new(<String as rusqlite::types::FromSql>::column_result(value)?))
             }
+            fn is_catalog_name() -> bool {
+                $is_catalog_name
+            }
         }
         )*
     };
@@ -617,6 +902,14 @@ macro_rules! tables {
                 ]
             }
 
+            fn sql_catalog_name_column() -> Option<&'static str> {
+                // Only a leading key column may be filtered by prefix.
+                let keys: &[(&'static str, bool)] = &[
+                    $( (stringify!($key), <$key_type as SqlColumn>::is_catalog_name()), )*
+                ];
+                keys.first().and_then(|(name, is_name)| is_name.then_some(*name))
+            }
+
             fn persist(&self, stmt: &mut rusqlite::Statement<'_>) -> rusqlite::Result<()> {
                 stmt.execute(rusqlite::params![
                     $( <$key_type as SqlColumn>::to_sql(&self.$key)?, )*
@@ -650,6 +943,19 @@ macro_rules! tables {
                     $($val: $val.own_or_clone(),)*
                 });
             }
+
+            /// Insert a Row into the Table, replacing and returning a current Row
+            /// having the same key. Arguments match the positional order of the
+            /// table's definition.
+            #[allow(dead_code)]
+            pub fn upsert_row(&mut self, $( $key: impl OwnOrClone<$key_type>, )* $( $val: impl OwnOrClone<$val_type>, )*) -> Option<$row> {
+                let mut prev = None;
+                self.upsert($row {
+                    $($key: $key.own_or_clone(),)*
+                    $($val: $val.own_or_clone(),)*
+                }, |_, replaced| prev = replaced);
+                prev
+            }
         }
 
         )*
@@ -673,6 +979,7 @@ macro_rules! table_impl_row {
 
             fn cmp_key(&self, other: &Self::Key) -> std::cmp::Ordering { self.$key.cmp(other) }
             fn cmp_row(&self, other: &Self) -> std::cmp::Ordering { self.$key.cmp(&other.$key) }
+            fn single_key(&self) -> Option<&Self::Key> { Some(&self.$key) }
         }
     };
     // Key N=2
@@ -105,6 +105,54 @@ pub async fn decrypt_sops(config: &models::RawValue) -> anyhow::Result<models::R
     Ok(serde_json::from_slice(&stdout).context("parsing stripped `jq` output")?)
 }
 
+/// Encrypt a document using `sops` and the customer-managed `key` of `provider`,
+/// using application default credentials. The returned `sops` document may be
+/// decrypted by any holder of decrypt permission on the key.
+pub async fn encrypt_sops(
+    doc: &models::RawValue,
+    provider: models::EncryptionProvider,
+    key: &str,
+) -> anyhow::Result<models::RawValue> {
+    let sops = locate_bin::locate("sops").context("failed to locate sops")?;
+
+    let key_flag = match provider {
+        models::EncryptionProvider::AwsKms => "--kms",
+        models::EncryptionProvider::GcpKms => "--gcp-kms",
+    };
+
+    let async_process::Output {
+        stderr,
+        mut stdout,
+        status,
+    } = async_process::input_output(
+        async_process::Command::new(sops).args([
+            "--encrypt",
+            key_flag,
+            key,
+            "--input-type",
+            "json",
+            "--output-type",
+            "json",
+            "/dev/stdin",
+        ]),
+        doc.get().as_bytes(),
+    )
+    .await
+    .context("failed to run sops")?;
+
+    if !status.success() {
+        anyhow::bail!(
+            "encrypting sops document failed: {}",
+            String::from_utf8_lossy(&stderr),
+        );
+    }
+
+    // `sops` emits JSON with newlines and tabs. Remove them to not break JSONL.
+    stdout.retain(|c| *c != b'\n' && *c != b'\t');
+
+    Ok(serde_json::from_slice(&stdout).context("parsing `sops` output")?)
+}
+
 #[cfg(test)]
 mod test {
     use super::decrypt_sops;
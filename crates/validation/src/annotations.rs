@@ -0,0 +1,151 @@
+use super::Error;
+
+/// Maximum length of an annotation description.
+pub const MAX_DESCRIPTION_LENGTH: usize = 1024;
+/// Maximum length of an annotation owner, or of a label value.
+/// Owners and label values are surfaced as built shard and journal labels.
+pub const MAX_VALUE_LENGTH: usize = 128;
+/// Maximum length of a label name. Names are further prefixed by
+/// labels::ANNOTATION_PREFIX when surfaced as built labels.
+pub const MAX_LABEL_NAME_LENGTH: usize = 32;
+/// Maximum number of labels of a specification.
+pub const MAX_LABELS: usize = 16;
+
+/// Symbols which, in addition to letters and digits, may appear in label names.
+const NAME_SYMBOLS: &str = "-_./";
+/// Symbols which, in addition to letters and digits, may appear in
+/// owners and label values.
+const VALUE_SYMBOLS: &str = "-_+/.=%@:";
+
+pub fn walk_annotations(
+    scope: sources::Scope,
+    entity: &'static str,
+    name: &str,
+    annotations: &models::Annotations,
+    errors: &mut tables::Errors,
+) {
+    let models::Annotations {
+        owner,
+        description,
+        labels,
+    } = annotations;
+
+    if let Some(owner) = owner {
+        let scope = scope.push_prop("owner");
+        walk_token(scope, entity, name, "owner", owner, VALUE_SYMBOLS, errors);
+        walk_length(
+            scope,
+            entity,
+            name,
+            "owner",
+            owner,
+            MAX_VALUE_LENGTH,
+            errors,
+        );
+    }
+    if let Some(description) = description {
+        let scope = scope.push_prop("description");
+        walk_length(
+            scope,
+            entity,
+            name,
+            "description",
+            description,
+            MAX_DESCRIPTION_LENGTH,
+            errors,
+        );
+    }
+
+    let scope = scope.push_prop("labels");
+
+    if labels.len() > MAX_LABELS {
+        Error::TooManyAnnotationLabels {
+            entity,
+            name: name.to_string(),
+            count: labels.len(),
+            limit: MAX_LABELS,
+        }
+        .push(scope, errors);
+    }
+
+    for (label, value) in labels {
+        let scope = scope.push_prop(label);
+        let field = format!("label {label:?}");
+
+        walk_token(scope, entity, name, &field, label, NAME_SYMBOLS, errors);
+        walk_length(
+            scope,
+            entity,
+            name,
+            &field,
+            label,
+            MAX_LABEL_NAME_LENGTH,
+            errors,
+        );
+        // Label values may be empty, but are otherwise held to owner rules.
+        if !value.is_empty() {
+            walk_token(scope, entity, name, &field, value, VALUE_SYMBOLS, errors);
+        }
+        walk_length(scope, entity, name, &field, value, MAX_VALUE_LENGTH, errors);
+    }
+}
+
+fn walk_token(
+    scope: sources::Scope,
+    entity: &'static str,
+    name: &str,
+    field: &str,
+    value: &str,
+    symbols: &'static str,
+    errors: &mut tables::Errors,
+) {
+    if value.is_empty() {
+        Error::AnnotationEmpty {
+            entity,
+            name: name.to_string(),
+            field: field.to_string(),
+        }
+        .push(scope, errors);
+        return;
+    }
+
+    let unmatched: String = value
+        .chars()
+        .filter(|c| !c.is_ascii_alphanumeric() && !symbols.contains(*c))
+        .collect();
+
+    if !unmatched.is_empty() {
+        Error::AnnotationInvalid {
+            entity,
+            name: name.to_string(),
+            field: field.to_string(),
+            value: value.to_string(),
+            unmatched,
+            symbols,
+        }
+        .push(scope, errors);
+    }
+}
+
+fn walk_length(
+    scope: sources::Scope,
+    entity: &'static str,
+    name: &str,
+    field: &str,
+    value: &str,
+    limit: usize,
+    errors: &mut tables::Errors,
+) {
+    let length = value.chars().count();
+
+    if length > limit {
+        Error::AnnotationTooLong {
+            entity,
+            name: name.to_string(),
+            field: field.to_string(),
+            length,
+            limit,
+        }
+        .push(scope, errors);
+    }
+}
@@ -1,5 +1,6 @@
 use super::{
-    indexed, reference, storage_mapping, walk_transition, Connectors, Error, NoOpConnectors, Scope,
+    annotations, indexed, reference, shard_template, storage_mapping, walk_transition, Connectors,
+    Error, NoOpConnectors, Scope,
 };
 use itertools::Itertools;
 use proto_flow::{capture, flow, ops::log::Level as LogLevel};
@@ -14,7 +15,7 @@ pub async fn walk_all_captures(
     connectors: &dyn Connectors,
     data_planes: &tables::DataPlanes,
     default_plane_id: Option<models::Id>,
-    storage_mappings: &tables::StorageMappings,
+    prefixes: &tables::Prefixes,
     dependencies: &tables::Dependencies<'_>,
     errors: &mut tables::Errors,
 ) -> tables::BuiltCaptures {
@@ -41,7 +42,7 @@ pub async fn walk_all_captures(
                 connectors,
                 data_planes,
                 default_plane_id,
-                storage_mappings,
+                prefixes,
                 dependencies,
                 &mut local_errors,
             )
@@ -71,7 +72,7 @@ async fn walk_capture(
     connectors: &dyn Connectors,
     data_planes: &tables::DataPlanes,
     default_plane_id: Option<models::Id>,
-    storage_mappings: &tables::StorageMappings,
+    prefixes: &tables::Prefixes,
     dependencies: &tables::Dependencies<'_>,
     errors: &mut tables::Errors,
 ) -> Option<tables::BuiltCapture> {
@@ -98,11 +99,13 @@ async fn walk_capture(
         bindings: all_bindings,
         interval,
         shards: shard_template,
+        annotations,
         expect_pub_id: _,
         delete: _,
     } = model;
 
     indexed::walk_name(scope, "capture", capture, models::Capture::regex(), errors);
+    indexed::walk_task_name_length(scope, "capture", capture, labels::TASK_TYPE_CAPTURE, errors);
 
     // Unwrap `endpoint` into a connector type and configuration.
     let (connector_type, config_json) = match endpoint {
@@ -137,11 +140,25 @@ async fn walk_capture(
         .collect();
 
     // Determine storage mappings for task recovery logs.
-    let recovery_stores = storage_mapping::mapped_stores(
-        scope,
+    let recovery_stores =
+        storage_mapping::recovery_stores(scope, "capture", capture, prefixes, errors);
+
+    annotations::walk_annotations(
+        scope.push_prop("annotations"),
         "capture",
-        &format!("recovery/{capture}"),
-        storage_mappings,
+        capture,
+        annotations,
+        errors,
+    );
+    shard_template::walk_shard_template(
+        scope.push_prop("shards"),
+        "capture",
+        capture,
+        shard_template,
+        live_spec
+            .and_then(|spec| spec.shard_template.as_ref())
+            .map(|shard| shard.hint_prefix.as_str()),
+        data_planes.get_key(&data_plane_id),
         errors,
     );
 
@@ -280,6 +297,7 @@ async fn walk_capture(
         &shard_id_prefix,
         false, // Don't disable wait_for_ack.
         &network_ports,
+        annotations,
     );
     let built_spec = flow::CaptureSpec {
         name: capture.to_string(),
@@ -306,6 +324,9 @@ async fn walk_capture(
         previous_spec: live_spec.cloned(),
         is_touch,
         dependency_hash,
+        // Connector documentation is attached once Specs are fetched.
+        documentation_url: None,
+        oauth2: None,
     })
 }
 
@@ -331,6 +352,14 @@ fn walk_capture_binding<'a>(
         errors,
     )?;
 
+    if reference::is_frozen(&spec) {
+        Error::FrozenCollectionWrite {
+            this_entity: "this capture binding".to_string(),
+            collection: target.to_string(),
+        }
+        .push(scope.push_prop("target"), errors);
+    }
+
     let request = capture::request::validate::Binding {
         resource_config_json: resource.to_string(),
         collection: Some(spec),
@@ -1,7 +1,9 @@
-use super::{indexed, schema, storage_mapping, walk_transition, Error, Scope};
+use super::{
+    annotations, indexed, journal_template, schema, storage_mapping, walk_transition, Error, Scope,
+};
 use json::schema::types;
 use proto_flow::flow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use tables::EitherOrBoth as EOB;
 
 pub fn walk_all_collections(
@@ -10,8 +12,10 @@ pub fn walk_all_collections(
     default_plane_id: Option<models::Id>,
     draft_collections: &tables::DraftCollections,
     live_collections: &tables::LiveCollections,
-    storage_mappings: &tables::StorageMappings,
+    prefixes: &tables::Prefixes,
+    tenants: &tables::Tenants,
     errors: &mut tables::Errors,
+    warnings: &mut tables::Errors,
 ) -> tables::BuiltCollections {
     // Outer join of live and draft collections.
     let it = live_collections.outer_join(
@@ -23,14 +27,16 @@ pub fn walk_all_collections(
         },
     );
 
-    it.filter_map(|(_collection, eob)| {
+    it.filter_map(|(collection, eob)| {
         walk_collection(
             pub_id,
             build_id,
             default_plane_id,
             eob,
-            storage_mappings,
+            prefixes,
+            strict_schemas(tenants, collection),
             errors,
+            warnings,
         )
     })
     .collect()
@@ -41,8 +47,10 @@ fn walk_collection(
     build_id: models::Id,
     default_plane_id: Option<models::Id>,
     eob: EOB<&tables::LiveCollection, &tables::DraftCollection>,
-    storage_mappings: &tables::StorageMappings,
+    prefixes: &tables::Prefixes,
+    strict_schemas: bool,
     errors: &mut tables::Errors,
+    warnings: &mut tables::Errors,
 ) -> Option<tables::BuiltCollection> {
     let (
         collection,
@@ -66,9 +74,12 @@ fn walk_collection(
         write_schema,
         read_schema,
         key,
+        key_migration,
         projections,
         journals,
-        derive: _,
+        frozen,
+        derive,
+        annotations,
         expect_pub_id: _,
         delete: _,
     } = model;
@@ -80,6 +91,22 @@ fn walk_collection(
         models::Collection::regex(),
         errors,
     );
+    annotations::walk_annotations(
+        scope.push_prop("annotations"),
+        "collection",
+        collection,
+        annotations,
+        errors,
+    );
+    indexed::walk_partition_name_length(
+        scope,
+        collection,
+        projections
+            .iter()
+            .filter(|(_, projection)| projection.as_parts().1)
+            .map(|(field, _)| field.as_str()),
+        errors,
+    );
 
     if key.is_empty() {
         Error::CollectionKeyEmpty {
@@ -88,26 +115,59 @@ fn walk_collection(
         .push(scope.push_prop("key"), errors);
     }
 
+    journal_template::walk_journal_template(
+        scope.push_prop("journals"),
+        collection,
+        journals,
+        errors,
+    );
+
+    // A frozen derivation may not continue to write into its collection.
+    if let (true, Some(derive)) = (*frozen, derive) {
+        if !derive.shards.disable {
+            Error::FrozenCollectionWrite {
+                this_entity: format!("derivation {collection}"),
+                collection: collection.to_string(),
+            }
+            .push(scope.push_prop("frozen"), errors);
+        }
+    }
+
     let (write_schema, write_bundle, read_schema_bundle) = match (schema, write_schema, read_schema)
     {
         // One schema used for both writes and reads.
         (Some(bundle), None, None) => (
-            walk_collection_schema(scope.push_prop("schema"), bundle, errors)?,
+            walk_collection_schema(
+                scope.push_prop("schema"),
+                bundle,
+                strict_schemas.then_some(bundle),
+                errors,
+            )?,
             bundle.clone(),
             None,
         ),
         // Separate schemas used for writes and reads.
         (None, Some(model_write_schema), Some(model_read_schema)) => {
-            let write_schema =
-                walk_collection_schema(scope.push_prop("writeSchema"), model_write_schema, errors);
+            let write_schema = walk_collection_schema(
+                scope.push_prop("writeSchema"),
+                model_write_schema,
+                strict_schemas.then_some(model_write_schema),
+                errors,
+            );
 
             // Potentially extend the user's read schema with definitions
             // for the collection's current write schema.
             let read_bundle =
                 models::Schema::build_read_schema_bundle(model_read_schema, model_write_schema);
 
-            let read_schema =
-                walk_collection_schema(scope.push_prop("readSchema"), &read_bundle, errors);
+            // Strict checks of the read schema are scoped to the user's model,
+            // and not to the definitions which extend it.
+            let read_schema = walk_collection_schema(
+                scope.push_prop("readSchema"),
+                &read_bundle,
+                strict_schemas.then_some(model_read_schema),
+                errors,
+            );
             (
                 write_schema?,
                 model_write_schema.clone(),
@@ -150,6 +210,19 @@ fn walk_collection(
     // Projections should be ascending and unique on field.
     assert!(projections.windows(2).all(|p| p[0].field < p[1].field));
 
+    walk_partition_guardrails(
+        scope.push_prop("projections"),
+        collection,
+        read_schema_bundle
+            .as_ref()
+            .map(|(read_schema, _)| read_schema)
+            .unwrap_or(&write_schema),
+        &projections,
+        live_spec,
+        errors,
+        warnings,
+    );
+
     let partition_fields = projections
         .iter()
         .filter_map(|p| {
@@ -161,29 +234,69 @@ fn walk_collection(
         })
         .collect();
 
-    let partition_stores =
-        storage_mapping::mapped_stores(scope, "collection", collection, storage_mappings, errors);
+    let partition_stores = storage_mapping::partition_stores(scope, collection, prefixes, errors);
+
+    let key_migration = walk_key_migration(
+        scope.push_prop("keyMigration"),
+        collection,
+        pub_id,
+        key,
+        key_migration.as_ref(),
+        live_spec,
+        errors,
+    );
 
     // Pass-through the existing journal prefix, or create a unique new one.
-    let journal_name_prefix = if let Some(flow::CollectionSpec {
-        partition_template: Some(template),
-        ..
-    }) = live_spec
-    {
-        template.name.clone()
-    } else {
-        // Semi-colons are disallowed in Gazette journal names.
-        let pub_id = pub_id.to_string().replace(":", "");
-        format!("{collection}/{pub_id}")
+    // A key migration also passes through the prefix, as partitions don't
+    // depend on the key and existing journals must remain readable.
+    let journal_name_prefix = match live_spec {
+        Some(flow::CollectionSpec {
+            partition_template: Some(template),
+            ..
+        }) => template.name.clone(),
+        _ => {
+            // Semi-colons are disallowed in Gazette journal names.
+            let pub_id = pub_id.to_string().replace(":", "");
+            format!("{collection}/{pub_id}")
+        }
     };
 
-    let partition_template = assemble::partition_template(
+    let mut partition_template = assemble::partition_template(
         build_id,
         collection,
         &journal_name_prefix,
         journals,
+        *frozen,
         partition_stores,
+        annotations,
     );
+
+    // Retained journals of a migrated collection hold documents which were
+    // routed by the key range of the prior key, so their key-range labels
+    // don't bound the keys they contain. Label every journal with the most-recent
+    // generation of the key, so that shuffled reads don't rely on those ranges.
+    let key_generation = match (&key_migration, live_spec) {
+        (Some(plan), _) => Some(plan.generation_id.to_string()),
+        (
+            None,
+            Some(flow::CollectionSpec {
+                partition_template: Some(template),
+                ..
+            }),
+        ) => template
+            .labels
+            .as_ref()
+            .and_then(|set| labels::values(set, labels::KEY_GENERATION).first())
+            .map(|label| label.value.clone()),
+        _ => None,
+    };
+    if let Some(key_generation) = key_generation {
+        partition_template.labels = Some(labels::set_value(
+            partition_template.labels.take().unwrap_or_default(),
+            labels::KEY_GENERATION,
+            &key_generation,
+        ));
+    }
     let bundle_to_string = |b: Option<models::Schema>| -> String {
         let b: Option<Box<serde_json::value::RawValue>> = b.map(|b| b.into_inner().into());
         let b: Option<Box<str>> = b.map(Into::into);
@@ -221,16 +334,409 @@ fn walk_collection(
         is_touch,
         // Regular collections don't have dependencies. Derivation validation will set the hash.
         dependency_hash: None,
+        // Derivation connector documentation is attached once Specs are fetched.
+        documentation_url: None,
+        oauth2: None,
+        key_migration,
+        // Lineage of derivations is extracted once they're built.
+        lineage: Vec::new(),
     })
 }
 
+// Walk a declared migration of a live collection to a changed `key`, returning
+// its plan. The changed key must be inferable from existing documents: each of
+// its locations must exist, with a key-able type, under the live write schema.
+// A migration of an unchanged key is inert, as it is once it's been applied.
+fn walk_key_migration(
+    scope: Scope,
+    collection: &models::Collection,
+    pub_id: models::Id,
+    key: &models::CompositeKey,
+    key_migration: Option<&models::KeyMigration>,
+    live_spec: Option<&flow::CollectionSpec>,
+    errors: &mut tables::Errors,
+) -> Option<models::KeyMigrationPlan> {
+    let (Some(models::KeyMigration { from }), Some(live_spec)) = (key_migration, live_spec) else {
+        return None;
+    };
+    let live_key = models::CompositeKey::new(
+        live_spec
+            .key
+            .iter()
+            .map(models::JsonPointer::new)
+            .collect::<Vec<_>>(),
+    );
+    if *key == live_key {
+        return None;
+    }
+
+    if *from != live_key {
+        Error::KeyMigrationMismatch {
+            collection: collection.to_string(),
+            from: from.iter().map(|p| p.to_string()).collect(),
+            live: live_spec.key.clone(),
+        }
+        .push(scope.push_prop("from"), errors);
+        return None;
+    }
+
+    let live_schema = match schema::Schema::new(&live_spec.write_schema_json) {
+        Ok(live_schema) => live_schema,
+        Err(err) => {
+            err.push(scope, errors);
+            return None;
+        }
+    };
+    let mut inferable = true;
+
+    for ptr in key.iter() {
+        let detail = match live_schema.walk_ptr(ptr, true) {
+            Err(err) => err.to_string(),
+            Ok(()) => match live_schema.shape.locate(&doc::Pointer::from_str(ptr)).1 {
+                doc::shape::location::Exists::Must => continue,
+                _ => "location may not exist in existing documents".to_string(),
+            },
+        };
+        Error::KeyMigrationNotInferable {
+            collection: collection.to_string(),
+            ptr: ptr.to_string(),
+            detail,
+        }
+        .push(scope, errors);
+        inferable = false;
+    }
+
+    inferable.then(|| models::KeyMigrationPlan {
+        from: live_key,
+        to: key.clone(),
+        generation_id: pub_id,
+        // Backfilled tasks are identified once they're built.
+        backfill_materializations: Default::default(),
+        backfill_derivations: Default::default(),
+    })
+}
+
+/// Identify the materializations and derivations which must backfill
+/// collections undergoing a key migration: those having an enabled binding
+/// or transform of the collection. Their existing documents and state are
+/// keyed on the prior key, so each must be drafted and increment the backfill
+/// counter of its bindings or transforms which read the collection, unless
+/// they didn't read it before this publication.
+pub fn walk_key_migration_backfills(
+    built_collections: &mut tables::BuiltCollections,
+    built_materializations: &tables::BuiltMaterializations,
+    live: &tables::LiveCatalog,
+    errors: &mut tables::Errors,
+) {
+    let mut backfills = Vec::new();
+
+    for (built_index, built) in built_collections.iter().enumerate() {
+        if built.key_migration.is_none() {
+            continue;
+        }
+        let collection = &built.collection;
+        let scope = Scope::new(&built.scope).push_prop("keyMigration");
+        let mut materializations = BTreeSet::new();
+        let mut derivations = BTreeSet::new();
+
+        for row in built_materializations.iter() {
+            let Some(model) = &row.model else {
+                continue; // Materialization is being deleted.
+            };
+            let live_backfill = live
+                .materializations
+                .get_key(&row.materialization)
+                .and_then(|live| {
+                    live.model
+                        .bindings
+                        .iter()
+                        .find(|b| !b.disable && b.source.collection() == collection)
+                })
+                .map(|b| b.backfill);
+
+            for (index, binding) in model.bindings.iter().enumerate() {
+                if binding.disable || binding.source.collection() != collection {
+                    continue;
+                }
+                materializations.insert(row.materialization.clone());
+
+                if live_backfill.is_some_and(|live_backfill| binding.backfill <= live_backfill) {
+                    Error::KeyMigrationRequiresBackfill {
+                        collection: collection.to_string(),
+                        task: row.materialization.to_string(),
+                    }
+                    .push(
+                        Scope::new(&row.scope)
+                            .push_prop("bindings")
+                            .push_item(index)
+                            .push_prop("backfill"),
+                        errors,
+                    );
+                }
+            }
+        }
+
+        for row in built_collections.iter() {
+            let Some(derive) = row.model.as_ref().and_then(|model| model.derive.as_ref()) else {
+                continue; // Not a derivation, or it's being deleted.
+            };
+            let live_backfill = live
+                .collections
+                .get_key(&row.collection)
+                .and_then(|live| live.model.derive.as_ref())
+                .and_then(|derive| {
+                    derive
+                        .transforms
+                        .iter()
+                        .find(|t| !t.disable && t.source.collection() == collection)
+                })
+                .map(|t| t.backfill);
+
+            for (index, transform) in derive.transforms.iter().enumerate() {
+                if transform.disable || transform.source.collection() != collection {
+                    continue;
+                }
+                derivations.insert(row.collection.clone());
+
+                if live_backfill.is_some_and(|live_backfill| transform.backfill <= live_backfill) {
+                    Error::KeyMigrationRequiresBackfill {
+                        collection: collection.to_string(),
+                        task: row.collection.to_string(),
+                    }
+                    .push(
+                        Scope::new(&row.scope)
+                            .push_prop("derive")
+                            .push_prop("transforms")
+                            .push_item(index)
+                            .push_prop("backfill"),
+                        errors,
+                    );
+                }
+            }
+        }
+
+        // Live tasks which read the collection, but aren't drafted, can't backfill.
+        for row in live.materializations.iter() {
+            if built_materializations
+                .get_key(&row.materialization)
+                .is_none()
+                && row
+                    .model
+                    .bindings
+                    .iter()
+                    .any(|b| !b.disable && b.source.collection() == collection)
+            {
+                materializations.insert(row.materialization.clone());
+
+                Error::KeyMigrationRequiresBackfill {
+                    collection: collection.to_string(),
+                    task: row.materialization.to_string(),
+                }
+                .push(scope, errors);
+            }
+        }
+        for row in live.collections.iter() {
+            let Some(derive) = &row.model.derive else {
+                continue;
+            };
+            if built_collections.get_key(&row.collection).is_none()
+                && derive
+                    .transforms
+                    .iter()
+                    .any(|t| !t.disable && t.source.collection() == collection)
+            {
+                derivations.insert(row.collection.clone());
+
+                Error::KeyMigrationRequiresBackfill {
+                    collection: collection.to_string(),
+                    task: row.collection.to_string(),
+                }
+                .push(scope, errors);
+            }
+        }
+
+        backfills.push((built_index, materializations, derivations));
+    }
+
+    for (built_index, materializations, derivations) in backfills {
+        let plan = built_collections[built_index]
+            .key_migration
+            .as_mut()
+            .unwrap();
+        plan.backfill_materializations = materializations;
+        plan.backfill_derivations = derivations;
+    }
+}
+
+// Determine whether `collection` belongs to a tenant having strict schemas.
+// The longest matching tenant prefix applies.
+fn strict_schemas(tenants: &tables::Tenants, collection: &str) -> bool {
+    tenants
+        .iter()
+        .filter(|tenant| collection.starts_with(tenant.tenant.as_str()))
+        .last()
+        .is_some_and(|tenant| tenant.strict_schemas)
+}
+
+// Maximum number of distinct values of a partition field
+// which is considered to have bounded cardinality.
+const MAX_PARTITION_CARDINALITY: f64 = 1024.0;
+
+// Partitions of a collection are journals named like
+// `{collection}/{generation}/{field}={value}/.../pivot=00`, and each distinct
+// combination of partition values creates a new journal. Error if partition
+// values may be too long for a journal name, and warn of partitions having
+// unbounded cardinality. Partitions which are unchanged from the `live_spec`
+// aren't checked for length, as their journals may already exist.
+fn walk_partition_guardrails(
+    scope: Scope,
+    collection: &models::Collection,
+    schema: &schema::Schema,
+    projections: &[flow::Projection],
+    live_spec: Option<&flow::CollectionSpec>,
+    errors: &mut tables::Errors,
+    warnings: &mut tables::Errors,
+) {
+    let partitions: Vec<_> = projections
+        .iter()
+        .filter(|p| p.is_partition_key)
+        .map(|p| (p, schema.shape.locate(&doc::Pointer::from_str(&p.ptr)).0))
+        .collect();
+
+    // Length of a journal name, excluding its partition values.
+    let fixed = collection.len()
+        + "/0123456789abcdef".len()
+        + partitions
+            .iter()
+            .map(|(p, _)| "/=".len() + p.field.len())
+            .sum::<usize>()
+        + "/pivot=00".len();
+    let limit = indexed::MAX_GAZETTE_NAME_LENGTH.saturating_sub(fixed);
+
+    let total: usize = partitions
+        .iter()
+        .map(|(_, shape)| shape.string.max_length.unwrap_or_default() as usize)
+        .sum();
+
+    let inferred_max_length = |projection: &flow::Projection| {
+        projection
+            .inference
+            .as_ref()
+            .and_then(|inference| inference.string.as_ref())
+            .map(|string| string.max_length)
+    };
+    let is_unchanged = |projection: &flow::Projection| {
+        live_spec.is_some_and(|live_spec| {
+            live_spec.projections.iter().any(|live| {
+                live.is_partition_key
+                    && live.field == projection.field
+                    && live.ptr == projection.ptr
+                    && inferred_max_length(live) == inferred_max_length(projection)
+            })
+        })
+    };
+
+    for (projection, shape) in partitions {
+        let scope = scope.push_prop(&projection.field);
+
+        match shape.string.max_length {
+            // If `fixed` alone is too long, indexed::walk_partition_name_length has already errored.
+            Some(max_length)
+                if shape.type_.overlaps(types::STRING)
+                    && total > limit
+                    && fixed <= indexed::MAX_GAZETTE_NAME_LENGTH
+                    && !is_unchanged(projection) =>
+            {
+                Error::PartitionValueTooLong {
+                    field: projection.field.clone(),
+                    collection: collection.to_string(),
+                    max_length,
+                    limit,
+                }
+                .push(scope, errors);
+            }
+            _ => (),
+        }
+
+        if let Some(detail) = unbounded_cardinality(shape) {
+            Error::PartitionCardinalityUnbounded {
+                field: projection.field.clone(),
+                collection: collection.to_string(),
+                detail,
+            }
+            .push(scope, warnings);
+        }
+    }
+}
+
+// Determine whether a partition `shape` has unbounded cardinality,
+// returning a description of why if so.
+fn unbounded_cardinality(shape: &doc::Shape) -> Option<String> {
+    if shape.enum_.is_some() {
+        return None; // Enumerated values are always bounded.
+    }
+    let type_ = shape.type_ - types::NULL;
+
+    if type_.overlaps(types::STRING) {
+        return Some("its values are strings without an enum".to_string());
+    } else if !type_.overlaps(types::INTEGER) {
+        return None; // Booleans are bounded.
+    }
+
+    let as_f64 = |n: &json::Number| match *n {
+        json::Number::Unsigned(n) => n as f64,
+        json::Number::Signed(n) => n as f64,
+        json::Number::Float(n) => n,
+    };
+    match (&shape.numeric.minimum, &shape.numeric.maximum) {
+        (Some(minimum), Some(maximum)) => {
+            let (minimum, maximum) = (as_f64(minimum), as_f64(maximum));
+
+            if maximum - minimum + 1.0 > MAX_PARTITION_CARDINALITY {
+                Some(format!(
+                    "its integer range of {minimum} to {maximum} spans more than {MAX_PARTITION_CARDINALITY} values"
+                ))
+            } else {
+                None
+            }
+        }
+        _ => Some("its integer values lack a minimum and maximum".to_string()),
+    }
+}
+
 fn walk_collection_schema(
     scope: Scope,
     bundle: &models::Schema,
+    strict_model: Option<&models::Schema>,
     errors: &mut tables::Errors,
 ) -> Option<schema::Schema> {
+    // Strict checks precede the build of `bundle`, so that unknown keywords
+    // are reported at their exact locations rather than as a build failure.
+    let strict_errors = errors.len();
+    if let Some(model) = strict_model {
+        schema::walk_strict(scope, &serde_json::from_str(model.get()).unwrap(), errors);
+    }
+    let unknown_keywords = errors.iter().skip(strict_errors).any(|err| {
+        matches!(
+            err.error.downcast_ref(),
+            Some(Error::StrictSchemaUnknownKeyword { .. })
+        )
+    });
+
     let schema = match schema::Schema::new(bundle.get()) {
         Ok(schema) => schema,
+        Err(_) if unknown_keywords => return None,
+        // Don't repeat a $ref which was already reported, with a trace, where it's used.
+        Err(Error::SchemaIndex(json::schema::index::Error::InvalidReference { ruri, .. }))
+            if errors.iter().any(|err| {
+                matches!(
+                    err.error.downcast_ref(),
+                    Some(Error::UnresolvedSchemaReference { reference, .. }) if reference == &ruri
+                )
+            }) =>
+        {
+            return None
+        }
         Err(err) => {
             err.push(scope, errors);
             return None;
@@ -529,3 +1035,108 @@ const UUID_DATE_TIME_PTR: &str = "/_meta/uuid/date-time";
 
 /// Used to check if a pointer ends with an empty key, so we can skip projecting those fields.
 const EMPTY_KEY: &'static [doc::ptr::Token] = &[doc::ptr::Token::Property(String::new())];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unbounded_partition_cardinality() {
+        let cases = [
+            (serde_json::json!({"type": "boolean"}), None),
+            (serde_json::json!({"type": ["boolean", "null"]}), None),
+            (
+                serde_json::json!({"type": "string", "enum": ["a", "b"]}),
+                None,
+            ),
+            (
+                serde_json::json!({"type": "integer", "minimum": 0, "maximum": 100}),
+                None,
+            ),
+            (
+                serde_json::json!({"type": "string"}),
+                Some("its values are strings without an enum"),
+            ),
+            (
+                serde_json::json!({"type": "string", "format": "date"}),
+                Some("its values are strings without an enum"),
+            ),
+            (
+                serde_json::json!({"type": "integer", "minimum": 0}),
+                Some("its integer values lack a minimum and maximum"),
+            ),
+            (
+                serde_json::json!({"type": "integer", "minimum": -5000, "maximum": 5000}),
+                Some("its integer range of -5000 to 5000 spans more than 1024 values"),
+            ),
+        ];
+
+        for (schema, expect) in cases {
+            let schema = schema::Schema::new(&schema.to_string()).unwrap();
+            assert_eq!(
+                unbounded_cardinality(&schema.shape).as_deref(),
+                expect,
+                "{:?}",
+                schema.shape.type_
+            );
+        }
+    }
+
+    #[test]
+    fn test_partition_value_length_of_live_partitions() {
+        let schema = schema::Schema::new(
+            &serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "region": {"type": "string", "maxLength": 600},
+                },
+                "required": ["region"],
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let partition = |max_length: u32| flow::Projection {
+            ptr: "/region".to_string(),
+            field: "region".to_string(),
+            is_partition_key: true,
+            inference: Some(flow::Inference {
+                types: vec!["string".to_string()],
+                string: Some(flow::inference::String {
+                    max_length,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let live = |max_length: u32| flow::CollectionSpec {
+            projections: vec![partition(max_length)],
+            ..Default::default()
+        };
+
+        let root = url::Url::parse("test://example/catalog.yaml").unwrap();
+        let collection = models::Collection::new("acmeCo/collection");
+
+        let walk = |live_spec: Option<&flow::CollectionSpec>| {
+            let (mut errors, mut warnings) = (tables::Errors::new(), tables::Errors::new());
+            walk_partition_guardrails(
+                Scope::new(&root),
+                &collection,
+                &schema,
+                &[partition(600)],
+                live_spec,
+                &mut errors,
+                &mut warnings,
+            );
+            errors.len()
+        };
+
+        // A drafted partition is checked.
+        assert_eq!(walk(None), 1);
+        // An unchanged live partition isn't, as its journals may already exist.
+        assert_eq!(walk(Some(&live(600))), 0);
+        // A partition whose maximum length changed is checked.
+        assert_eq!(walk(Some(&live(100))), 1);
+    }
+}
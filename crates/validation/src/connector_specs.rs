@@ -0,0 +1,458 @@
+use super::Connectors;
+use futures::FutureExt;
+use proto_flow::{capture, derive, flow, materialize};
+use std::collections::BTreeMap;
+
+/// Walk the connector images of built tasks, fetching the Spec of each
+/// distinct image through `connectors` for recording in the build.
+/// Tasks which are being deleted or have disabled shards are skipped,
+/// as are tasks which don't use a connector image.
+///
+/// Connector Specs are informational, and a failure to fetch one
+/// doesn't fail the build: the image is simply not recorded.
+pub async fn walk_all_connector_specs(
+    connectors: &dyn Connectors,
+    built_captures: &tables::BuiltCaptures,
+    built_collections: &tables::BuiltCollections,
+    built_materializations: &tables::BuiltMaterializations,
+    data_planes: &tables::DataPlanes,
+) -> tables::ConnectorSpecs {
+    // Map each distinct image to the first task which uses it.
+    let mut images: BTreeMap<&str, (models::CatalogType, &models::ConnectorConfig, models::Id)> =
+        BTreeMap::new();
+
+    for row in built_captures.iter() {
+        let Some(models::CaptureDef {
+            endpoint: models::CaptureEndpoint::Connector(config),
+            shards,
+            ..
+        }) = &row.model
+        else {
+            continue;
+        };
+        if row.spec.is_some() && !shards.disable {
+            images.entry(&config.image).or_insert((
+                models::CatalogType::Capture,
+                config,
+                row.data_plane_id,
+            ));
+        }
+    }
+    for row in built_collections.iter() {
+        let Some(models::Derivation {
+            using: models::DeriveUsing::Connector(config),
+            shards,
+            ..
+        }) = row.model.as_ref().and_then(|model| model.derive.as_ref())
+        else {
+            continue;
+        };
+        if row.spec.is_some() && !shards.disable {
+            images.entry(&config.image).or_insert((
+                models::CatalogType::Collection,
+                config,
+                row.data_plane_id,
+            ));
+        }
+    }
+    for row in built_materializations.iter() {
+        let Some(models::MaterializationDef {
+            endpoint: models::MaterializationEndpoint::Connector(config),
+            shards,
+            ..
+        }) = &row.model
+        else {
+            continue;
+        };
+        if row.spec.is_some() && !shards.disable {
+            images.entry(&config.image).or_insert((
+                models::CatalogType::Materialization,
+                config,
+                row.data_plane_id,
+            ));
+        }
+    }
+
+    let specs = images
+        .into_iter()
+        .filter_map(|(image, (catalog_type, config, data_plane_id))| {
+            let data_plane = data_planes.get_key(&data_plane_id)?;
+            Some(fetch_spec(
+                connectors,
+                image,
+                catalog_type,
+                config,
+                data_plane,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    futures::future::join_all(specs)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Attach the documentation URL and OAuth2 specification of fetched
+/// `connector_specs` to the built tasks which use each connector image,
+/// so that they may be rendered directly from the build.
+pub fn attach_connector_docs(
+    connector_specs: &tables::ConnectorSpecs,
+    built_captures: &mut tables::BuiltCaptures,
+    built_collections: &mut tables::BuiltCollections,
+    built_materializations: &mut tables::BuiltMaterializations,
+) {
+    if connector_specs.is_empty() {
+        return;
+    }
+    let docs = |image: &str| {
+        let spec = connector_specs.get_key(&image.to_string())?;
+        let url = (!spec.documentation_url.is_empty()).then(|| spec.documentation_url.clone());
+        Some((url, spec.oauth2.clone()))
+    };
+
+    for row in built_captures.iter_mut() {
+        let Some(models::CaptureDef {
+            endpoint: models::CaptureEndpoint::Connector(config),
+            ..
+        }) = &row.model
+        else {
+            continue;
+        };
+        if let Some((url, oauth2)) = docs(&config.image) {
+            (row.documentation_url, row.oauth2) = (url, oauth2);
+        }
+    }
+    for row in built_collections.iter_mut() {
+        let Some(models::Derivation {
+            using: models::DeriveUsing::Connector(config),
+            ..
+        }) = row.model.as_ref().and_then(|model| model.derive.as_ref())
+        else {
+            continue;
+        };
+        if let Some((url, oauth2)) = docs(&config.image) {
+            (row.documentation_url, row.oauth2) = (url, oauth2);
+        }
+    }
+    for row in built_materializations.iter_mut() {
+        let Some(models::MaterializationDef {
+            endpoint: models::MaterializationEndpoint::Connector(config),
+            ..
+        }) = &row.model
+        else {
+            continue;
+        };
+        if let Some((url, oauth2)) = docs(&config.image) {
+            (row.documentation_url, row.oauth2) = (url, oauth2);
+        }
+    }
+}
+
+async fn fetch_spec(
+    connectors: &dyn Connectors,
+    image: &str,
+    catalog_type: models::CatalogType,
+    config: &models::ConnectorConfig,
+    data_plane: &tables::DataPlane,
+) -> Option<tables::ConnectorSpec> {
+    let config_json = serde_json::to_string(config).unwrap();
+
+    let result = match catalog_type {
+        models::CatalogType::Capture => {
+            connectors
+                .spec_capture(
+                    capture::Request {
+                        spec: Some(capture::request::Spec {
+                            connector_type: flow::capture_spec::ConnectorType::Image as i32,
+                            config_json,
+                        }),
+                        ..Default::default()
+                    },
+                    data_plane,
+                )
+                .map(|r| r.map(|r| r.and_then(|r| r.spec).map(from_capture_spec)))
+                .await
+        }
+        models::CatalogType::Collection => {
+            connectors
+                .spec_derivation(
+                    derive::Request {
+                        spec: Some(derive::request::Spec {
+                            connector_type: flow::collection_spec::derivation::ConnectorType::Image
+                                as i32,
+                            config_json,
+                        }),
+                        ..Default::default()
+                    },
+                    data_plane,
+                )
+                .map(|r| r.map(|r| r.and_then(|r| r.spec).map(from_derive_spec)))
+                .await
+        }
+        models::CatalogType::Materialization => {
+            connectors
+                .spec_materialization(
+                    materialize::Request {
+                        spec: Some(materialize::request::Spec {
+                            connector_type: flow::materialization_spec::ConnectorType::Image as i32,
+                            config_json,
+                        }),
+                        ..Default::default()
+                    },
+                    data_plane,
+                )
+                .map(|r| r.map(|r| r.and_then(|r| r.spec).map(from_materialize_spec)))
+                .await
+        }
+        models::CatalogType::Test => return None,
+    };
+
+    match result {
+        Ok(Some((protocol, config_schema, resource_config_schema, documentation_url, oauth2))) => {
+            Some(tables::ConnectorSpec {
+                image: image.to_string(),
+                catalog_type,
+                protocol,
+                config_schema: raw_schema(config_schema),
+                resource_config_schema: raw_schema(resource_config_schema),
+                documentation_url,
+                oauth2,
+            })
+        }
+        Ok(None) => None,
+        Err(err) => {
+            tracing::warn!(%image, error = ?err, "failed to fetch connector spec");
+            None
+        }
+    }
+}
+
+type SpecParts = (u32, String, String, String, Option<flow::OAuth2>);
+
+fn from_capture_spec(spec: capture::response::Spec) -> SpecParts {
+    (
+        spec.protocol,
+        spec.config_schema_json,
+        spec.resource_config_schema_json,
+        spec.documentation_url,
+        spec.oauth2,
+    )
+}
+
+fn from_derive_spec(spec: derive::response::Spec) -> SpecParts {
+    (
+        spec.protocol,
+        spec.config_schema_json,
+        spec.resource_config_schema_json,
+        spec.documentation_url,
+        spec.oauth2,
+    )
+}
+
+fn from_materialize_spec(spec: materialize::response::Spec) -> SpecParts {
+    (
+        spec.protocol,
+        spec.config_schema_json,
+        spec.resource_config_schema_json,
+        spec.documentation_url,
+        spec.oauth2,
+    )
+}
+
+// Map a JSON schema of a Spec response into a RawValue,
+// using `null` if it's empty or not valid JSON.
+fn raw_schema(schema: String) -> models::RawValue {
+    models::RawValue::from_string(schema).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::future::BoxFuture;
+
+    // SpecConnectors respond to materialization Spec requests with a fixture
+    // of each image, and record the images which were requested.
+    #[derive(Default)]
+    struct SpecConnectors {
+        requested: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl Connectors for SpecConnectors {
+        fn validate_capture<'a>(
+            &'a self,
+            _request: capture::Request,
+            _data_plane: &'a tables::DataPlane,
+        ) -> BoxFuture<'a, anyhow::Result<capture::Response>> {
+            unreachable!()
+        }
+        fn validate_derivation<'a>(
+            &'a self,
+            _request: derive::Request,
+            _data_plane: &'a tables::DataPlane,
+        ) -> BoxFuture<'a, anyhow::Result<derive::Response>> {
+            unreachable!()
+        }
+        fn validate_materialization<'a>(
+            &'a self,
+            _request: materialize::Request,
+            _data_plane: &'a tables::DataPlane,
+        ) -> BoxFuture<'a, anyhow::Result<materialize::Response>> {
+            unreachable!()
+        }
+        fn spec_materialization<'a>(
+            &'a self,
+            request: materialize::Request,
+            _data_plane: &'a tables::DataPlane,
+        ) -> BoxFuture<'a, anyhow::Result<Option<materialize::Response>>> {
+            let config: models::ConnectorConfig =
+                serde_json::from_str(&request.spec.unwrap().config_json).unwrap();
+            self.requested.lock().unwrap().push(config.image.clone());
+
+            let response = match config.image.as_str() {
+                "failing/image:v1" => Err(anyhow::anyhow!("spec failed")),
+                image => Ok(Some(materialize::Response {
+                    spec: Some(materialize::response::Spec {
+                        protocol: 3032023,
+                        config_schema_json: r#"{"type":"object"}"#.to_string(),
+                        resource_config_schema_json: String::new(),
+                        documentation_url: format!("https://docs.example/{image}"),
+                        oauth2: None,
+                    }),
+                    ..Default::default()
+                })),
+            };
+            Box::pin(futures::future::ready(response))
+        }
+    }
+
+    #[test]
+    fn test_walk_and_attach_connector_specs() {
+        let data_plane_id = models::Id::new([1; 8]);
+        let mut data_planes = tables::DataPlanes::new();
+        data_planes.insert_row(
+            data_plane_id,
+            "ops/dp/public/test".to_string(),
+            "test.dp.estuary-data.com".to_string(),
+            true,
+            Vec::<String>::new(),
+            models::Collection::new("ops/logs"),
+            models::Collection::new("ops/stats"),
+            "broker:address".to_string(),
+            "reactor:address".to_string(),
+            Vec::<String>::new(),
+            Vec::<String>::new(),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let mut built_materializations = tables::BuiltMaterializations::new();
+        for (name, image, disable, deleted, data_plane_id) in [
+            ("acmeCo/one", "an/image:v1", false, false, data_plane_id),
+            ("acmeCo/two", "an/image:v1", false, false, data_plane_id),
+            (
+                "acmeCo/disabled",
+                "disabled/image:v1",
+                true,
+                false,
+                data_plane_id,
+            ),
+            (
+                "acmeCo/deleted",
+                "deleted/image:v1",
+                false,
+                true,
+                data_plane_id,
+            ),
+            (
+                "acmeCo/failing",
+                "failing/image:v1",
+                false,
+                false,
+                data_plane_id,
+            ),
+            (
+                "acmeCo/elsewhere",
+                "other/image:v1",
+                false,
+                false,
+                models::Id::zero(),
+            ),
+        ] {
+            let model: models::MaterializationDef = serde_json::from_value(serde_json::json!({
+                "endpoint": {"connector": {"image": image, "config": {}}},
+                "bindings": [],
+                "shards": {"disable": disable},
+            }))
+            .unwrap();
+
+            built_materializations.insert_row(
+                models::Materialization::new(name),
+                url::Url::parse("test://scope").unwrap(),
+                models::Id::zero(),
+                data_plane_id,
+                models::Id::zero(),
+                models::Id::zero(),
+                Some(model),
+                None,
+                (!deleted).then(proto_flow::flow::MaterializationSpec::default),
+                None,
+                false,
+                None,
+                None,
+                None,
+                None,
+                Vec::new(),
+            );
+        }
+
+        let connectors = SpecConnectors::default();
+        let connector_specs = futures::executor::block_on(walk_all_connector_specs(
+            &connectors,
+            &tables::BuiltCaptures::new(),
+            &tables::BuiltCollections::new(),
+            &built_materializations,
+            &data_planes,
+        ));
+
+        // Each distinct image of an enabled task is requested once, and
+        // a failure to fetch a Spec doesn't record the image.
+        assert_eq!(
+            *connectors.requested.lock().unwrap(),
+            vec!["an/image:v1".to_string(), "failing/image:v1".to_string()],
+        );
+        assert_eq!(connector_specs.len(), 1);
+
+        let spec = connector_specs.get_key(&"an/image:v1".to_string()).unwrap();
+        assert_eq!(spec.catalog_type, models::CatalogType::Materialization);
+        assert_eq!(spec.protocol, 3032023);
+        assert_eq!(spec.config_schema.get(), r#"{"type":"object"}"#);
+        assert_eq!(spec.resource_config_schema.get(), "null");
+
+        attach_connector_docs(
+            &connector_specs,
+            &mut tables::BuiltCaptures::new(),
+            &mut tables::BuiltCollections::new(),
+            &mut built_materializations,
+        );
+        let docs_of = |name: &str| {
+            built_materializations
+                .get_key(&models::Materialization::new(name))
+                .unwrap()
+                .documentation_url
+                .clone()
+        };
+        assert_eq!(
+            docs_of("acmeCo/one").as_deref(),
+            Some("https://docs.example/an/image:v1")
+        );
+        assert_eq!(
+            docs_of("acmeCo/two").as_deref(),
+            Some("https://docs.example/an/image:v1")
+        );
+        assert_eq!(docs_of("acmeCo/failing"), None);
+    }
+}
@@ -0,0 +1,114 @@
+use super::{Error, Scope};
+
+/// Walk the derivations and materializations of a build, verifying that
+/// each enabled transform or binding which reads a collection of another
+/// data-plane is permitted to do so.
+///
+/// A cross-plane read requires that the task opt-in with
+/// `allowCrossPlaneRead: true`, and that the data-plane of the task lists
+/// the data-plane of the source collection as a permitted read peer.
+pub fn walk_cross_plane_reads(
+    built_collections: &tables::BuiltCollections,
+    built_materializations: &tables::BuiltMaterializations,
+    data_planes: &tables::DataPlanes,
+    errors: &mut tables::Errors,
+) {
+    for row in built_collections.iter() {
+        let (Some(_), Some(derive)) = (
+            &row.spec,
+            row.model.as_ref().and_then(|model| model.derive.as_ref()),
+        ) else {
+            continue;
+        };
+        let scope = Scope::new(&row.scope)
+            .push_prop("derive")
+            .push_prop("transforms");
+
+        for (index, transform) in derive.transforms.iter().enumerate() {
+            if transform.disable {
+                continue;
+            }
+            walk_cross_plane_read(
+                scope.push_item(index).push_prop("source"),
+                &row.collection,
+                row.data_plane_id,
+                derive.allow_cross_plane_read,
+                transform.source.collection(),
+                built_collections,
+                data_planes,
+                errors,
+            );
+        }
+    }
+
+    for row in built_materializations.iter() {
+        let (Some(_), Some(model)) = (&row.spec, &row.model) else {
+            continue;
+        };
+        let scope = Scope::new(&row.scope).push_prop("bindings");
+
+        for (index, binding) in model.bindings.iter().enumerate() {
+            if binding.disable {
+                continue;
+            }
+            walk_cross_plane_read(
+                scope.push_item(index).push_prop("source"),
+                &row.materialization,
+                row.data_plane_id,
+                model.allow_cross_plane_read,
+                binding.source.collection(),
+                built_collections,
+                data_planes,
+                errors,
+            );
+        }
+    }
+}
+
+fn walk_cross_plane_read(
+    scope: Scope,
+    this_entity: &str,
+    data_plane_id: models::Id,
+    allow_cross_plane_read: bool,
+    source: &models::Collection,
+    built_collections: &tables::BuiltCollections,
+    data_planes: &tables::DataPlanes,
+    errors: &mut tables::Errors,
+) {
+    // Missing sources are reported when the task is walked.
+    let Some(source_row) = built_collections.get_key(source) else {
+        return;
+    };
+    // Missing data-planes are likewise reported when the task or collection is walked.
+    let (Some(data_plane), Some(source_plane)) = (
+        data_planes.get_key(&data_plane_id),
+        data_planes.get_key(&source_row.data_plane_id),
+    ) else {
+        return;
+    };
+    // Data-planes are compared by name, which is how read peers are listed.
+    if data_plane.data_plane_name == source_plane.data_plane_name {
+        return;
+    }
+
+    if !allow_cross_plane_read {
+        Error::CrossPlaneReadNotAllowed {
+            this_entity: this_entity.to_string(),
+            collection: source.to_string(),
+            data_plane: data_plane.data_plane_name.clone(),
+            source_data_plane: source_plane.data_plane_name.clone(),
+        }
+        .push(scope, errors);
+    } else if !data_plane
+        .cross_plane_read_peers
+        .contains(&source_plane.data_plane_name)
+    {
+        Error::CrossPlaneReadNotPeered {
+            this_entity: this_entity.to_string(),
+            collection: source.to_string(),
+            data_plane: data_plane.data_plane_name.clone(),
+            source_data_plane: source_plane.data_plane_name.clone(),
+        }
+        .push(scope, errors);
+    }
+}
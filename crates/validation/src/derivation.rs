@@ -1,6 +1,6 @@
 use super::{
-    collection, indexed, reference, schema, storage_mapping, Connectors, Error, NoOpConnectors,
-    Scope,
+    collection, indexed, read_hints, reference, schema, shard_template, storage_mapping,
+    Connectors, Error, NoOpConnectors, Scope,
 };
 use proto_flow::{
     derive, flow,
@@ -20,7 +20,7 @@ pub async fn walk_all_derivations(
     data_planes: &tables::DataPlanes,
     imports: &tables::Imports,
     project_root: &url::Url,
-    storage_mappings: &tables::StorageMappings,
+    prefixes: &tables::Prefixes,
     dependencies: &tables::Dependencies<'_>,
     errors: &mut tables::Errors,
 ) -> Vec<(
@@ -52,7 +52,7 @@ pub async fn walk_all_derivations(
                 data_planes,
                 imports,
                 project_root,
-                storage_mappings,
+                prefixes,
                 dependencies,
                 &mut local_errors,
             )
@@ -83,7 +83,7 @@ async fn walk_derivation(
     data_planes: &tables::DataPlanes,
     imports: &tables::Imports,
     project_root: &url::Url,
-    storage_mappings: &tables::StorageMappings,
+    prefixes: &tables::Prefixes,
     dependencies: &tables::Dependencies<'_>,
     errors: &mut tables::Errors,
 ) -> Option<(
@@ -92,33 +92,10 @@ async fn walk_derivation(
     flow::collection_spec::Derivation,
     Option<String>,
 )> {
-    let (collection, scope, model, last_pub_id, last_collection, dependency_hash) = match eob {
-        // If this is a drafted derivation, pluck out its details.
-        EOB::Right(tables::DraftCollection {
-            collection,
-            scope,
-            model:
-                Some(
-                    collection_model @ models::CollectionDef {
-                        derive: Some(model),
-                        ..
-                    },
-                ),
-            ..
-        }) => (
-            collection,
-            scope,
-            model,
-            None,
-            None,
-            dependencies.compute_hash(collection_model),
-        ),
-
-        EOB::Both(
-            tables::LiveCollection {
-                spec, last_pub_id, ..
-            },
-            tables::DraftCollection {
+    let (collection, scope, model, annotations, last_pub_id, last_collection, dependency_hash) =
+        match eob {
+            // If this is a drafted derivation, pluck out its details.
+            EOB::Right(tables::DraftCollection {
                 collection,
                 scope,
                 model:
@@ -129,19 +106,45 @@ async fn walk_derivation(
                         },
                     ),
                 ..
-            },
-        ) => (
-            collection,
-            scope,
-            model,
-            spec.derivation.is_some().then_some(last_pub_id),
-            spec.derivation.is_some().then_some(spec),
-            dependencies.compute_hash(collection_model),
-        ),
+            }) => (
+                collection,
+                scope,
+                model,
+                &collection_model.annotations,
+                None,
+                None,
+                dependencies.compute_hash(collection_model),
+            ),
 
-        // For all other cases, don't build this derivation.
-        _ => return None,
-    };
+            EOB::Both(
+                tables::LiveCollection {
+                    spec, last_pub_id, ..
+                },
+                tables::DraftCollection {
+                    collection,
+                    scope,
+                    model:
+                        Some(
+                            collection_model @ models::CollectionDef {
+                                derive: Some(model),
+                                ..
+                            },
+                        ),
+                    ..
+                },
+            ) => (
+                collection,
+                scope,
+                model,
+                &collection_model.annotations,
+                spec.derivation.is_some().then_some(last_pub_id),
+                spec.derivation.is_some().then_some(spec),
+                dependencies.compute_hash(collection_model),
+            ),
+
+            // For all other cases, don't build this derivation.
+            _ => return None,
+        };
     let scope = Scope::new(scope);
     let scope = scope.push_prop("derive");
 
@@ -178,8 +181,17 @@ async fn walk_derivation(
         transforms: all_transforms,
         shuffle_key_types: given_shuffle_types,
         shards: shard_template,
+        allow_cross_plane_read: _,
     } = model;
 
+    indexed::walk_task_name_length(
+        scope,
+        "derivation",
+        collection,
+        labels::TASK_TYPE_DERIVATION,
+        errors,
+    );
+
     // Unwrap `using` into a connector type and configuration.
     let (connector_type, config_json) = match using {
         models::DeriveUsing::Connector(config) => (
@@ -209,6 +221,18 @@ async fn walk_derivation(
         .filter_map(|(index, transform)| (!transform.disable).then_some((index, transform)))
         .collect();
 
+    read_hints::walk_read_hints(
+        scope_transforms,
+        "derivation",
+        collection,
+        "transform",
+        &enabled_transforms
+            .iter()
+            .map(|(index, transform)| (*index, transform.priority, transform.max_read_rate))
+            .collect::<Vec<_>>(),
+        errors,
+    );
+
     // Map transforms into validation requests.
     let mut disable_wait_for_ack = false;
     let mut inferred_shuffle_types = Vec::new();
@@ -308,11 +332,19 @@ async fn walk_derivation(
     let built_collection = &built_collections[built_index];
 
     // Determine storage mappings for task recovery logs.
-    let recovery_stores = storage_mapping::mapped_stores(
-        scope,
+    let recovery_stores =
+        storage_mapping::recovery_stores(scope, "derivation", collection, prefixes, errors);
+
+    shard_template::walk_shard_template(
+        scope.push_prop("shards"),
         "derivation",
-        &format!("recovery/{collection}"),
-        storage_mappings,
+        &collection,
+        shard_template,
+        last_collection
+            .and_then(|spec| spec.derivation.as_ref())
+            .and_then(|derivation| derivation.shard_template.as_ref())
+            .map(|shard| shard.hint_prefix.as_str()),
+        data_planes.get_key(&built_collection.data_plane_id),
         errors,
     );
 
@@ -416,6 +448,7 @@ async fn walk_derivation(
             source,
             shuffle,
             priority,
+            max_read_rate,
             read_delay,
             lambda: _,
             disable: _,
@@ -455,6 +488,7 @@ async fn walk_derivation(
             collection: source_collection,
             partition_selector,
             priority: *priority,
+            max_read_rate: max_read_rate.unwrap_or_default(),
             read_delay_seconds,
             shuffle_key,
             shuffle_lambda_config_json,
@@ -497,6 +531,7 @@ async fn walk_derivation(
         &shard_id_prefix,
         disable_wait_for_ack,
         &network_ports,
+        annotations,
     );
     let built_spec = flow::collection_spec::Derivation {
         connector_type,
@@ -526,6 +561,7 @@ fn walk_derive_transform<'a>(
         source,
         shuffle,
         priority: _,
+        max_read_rate: _,
         read_delay: _,
         lambda,
         disable: _,
@@ -0,0 +1,193 @@
+use super::{Error, Scope};
+use std::collections::BTreeSet;
+use tables::EitherOrBoth as EOB;
+
+/// Walk the effective catalog of a build -- its drafted specifications,
+/// plus live specifications which aren't drafted -- to flag drafted updates
+/// of live collections which nothing reads or writes, as well as live tests
+/// of collections which are being deleted. Collections which are created by
+/// this build are not flagged, as their readers and writers often follow
+/// in later publications.
+/// Findings are warnings: they don't cause the build to fail.
+pub fn walk_unused(
+    draft: &tables::DraftCatalog,
+    live: &tables::LiveCatalog,
+    warnings: &mut tables::Errors,
+) {
+    let captures = live.captures.outer_join(
+        draft.captures.iter().map(|r| (&r.capture, r)),
+        |eob| match eob {
+            EOB::Left(live) => Some(&live.model),
+            EOB::Right((_, draft)) | EOB::Both(_, (_, draft)) => draft.model.as_ref(),
+        },
+    );
+    let collections = live.collections.outer_join(
+        draft.collections.iter().map(|r| (&r.collection, r)),
+        |eob| match eob {
+            EOB::Left(live) => Some((&live.collection, &live.model)),
+            EOB::Right((name, draft)) | EOB::Both(_, (name, draft)) => {
+                draft.model.as_ref().map(|model| (*name, model))
+            }
+        },
+    );
+    let materializations = live.materializations.outer_join(
+        draft
+            .materializations
+            .iter()
+            .map(|r| (&r.materialization, r)),
+        |eob| match eob {
+            EOB::Left(live) => Some(&live.model),
+            EOB::Right((_, draft)) | EOB::Both(_, (_, draft)) => draft.model.as_ref(),
+        },
+    );
+    let tests = live
+        .tests
+        .outer_join(draft.tests.iter().map(|r| (&r.test, r)), |eob| match eob {
+            EOB::Left(live) => Some((&live.test, &live.model, true)),
+            EOB::Right((name, draft)) | EOB::Both(_, (name, draft)) => {
+                draft.model.as_ref().map(|model| (*name, model, false))
+            }
+        });
+
+    // Collections which are read or written by any spec of the build.
+    let mut used: BTreeSet<&str> = BTreeSet::new();
+    let mut specs = 0;
+
+    for model in captures {
+        specs += 1;
+        used.extend(model.bindings.iter().map(|b| b.target.as_str()));
+    }
+    for (name, model) in collections {
+        let Some(derive) = &model.derive else {
+            continue;
+        };
+        specs += 1;
+        used.insert(name.as_str()); // A derivation writes its own collection.
+        used.extend(
+            derive
+                .transforms
+                .iter()
+                .map(|t| t.source.collection().as_str()),
+        );
+    }
+    for model in materializations {
+        specs += 1;
+        used.extend(
+            model
+                .bindings
+                .iter()
+                .map(|b| b.source.collection().as_str()),
+        );
+    }
+
+    let deleted: BTreeSet<&str> = draft
+        .collections
+        .iter()
+        .filter(|r| r.model.is_none())
+        .map(|r| r.collection.as_str())
+        .collect();
+
+    for (test, model, is_live) in tests {
+        specs += 1;
+
+        for step in &model.steps {
+            let collection = match step {
+                models::TestStep::Ingest(ingest) => &ingest.collection,
+                models::TestStep::Verify(verify) => verify.collection.collection(),
+                models::TestStep::Expect(expect) => &expect.collection,
+            };
+            used.insert(collection.as_str());
+
+            // Drafted tests of deleted collections are already errors.
+            if is_live && deleted.contains(collection.as_str()) {
+                Error::OrphanedTest {
+                    test: test.to_string(),
+                    collection: collection.to_string(),
+                }
+                .push(
+                    Scope::new(&tables::synthetic_scope(
+                        models::CatalogType::Test,
+                        test.as_str(),
+                    )),
+                    warnings,
+                );
+            }
+        }
+    }
+
+    for row in draft.collections.iter() {
+        if row.model.is_none()
+            || row.is_touch
+            || used.contains(row.collection.as_str())
+            || live.collections.get_key(&row.collection).is_none()
+        {
+            continue;
+        }
+        Error::UnusedCollection {
+            collection: row.collection.to_string(),
+            specs,
+        }
+        .push(Scope::new(&row.scope), warnings);
+    }
+}
+
+/// Walk drafted materializations to flag bindings which backfill a collection
+/// having a fragment retention, as documents older than the retention are no
+/// longer available to the backfill. A binding backfills if it's new to the
+/// materialization, or if its backfill counter was incremented.
+/// Findings are warnings: they don't cause the build to fail.
+pub fn walk_retention_backfills(
+    draft: &tables::DraftCatalog,
+    live: &tables::LiveCatalog,
+    warnings: &mut tables::Errors,
+) {
+    let retention = |collection: &models::Collection| {
+        let model = match draft.collections.get_key(collection) {
+            Some(row) => row.model.as_ref(),
+            None => live.collections.get_key(collection).map(|row| &row.model),
+        };
+        model.and_then(|model| model.journals.fragments.retention)
+    };
+
+    for row in draft.materializations.iter() {
+        let Some(model) = &row.model else {
+            continue;
+        };
+        let live_model = live
+            .materializations
+            .get_key(&row.materialization)
+            .map(|live| &live.model);
+
+        for (index, binding) in model.bindings.iter().enumerate() {
+            if binding.disable {
+                continue;
+            }
+            let collection = binding.source.collection();
+
+            let Some(retention) = retention(collection) else {
+                continue;
+            };
+            let live_backfill = live_model.and_then(|live| {
+                live.bindings
+                    .iter()
+                    .find(|b| !b.disable && b.source.collection() == collection)
+                    .map(|b| b.backfill)
+            });
+
+            if matches!(live_backfill, Some(backfill) if backfill >= binding.backfill) {
+                continue; // Not backfilling.
+            }
+            Error::BackfillExceedsRetention {
+                materialization: row.materialization.to_string(),
+                collection: collection.to_string(),
+                retention: humantime::format_duration(retention).to_string(),
+            }
+            .push(
+                Scope::new(&row.scope)
+                    .push_prop("bindings")
+                    .push_item(index),
+                warnings,
+            );
+        }
+    }
+}
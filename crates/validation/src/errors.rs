@@ -13,6 +13,15 @@ pub enum Error {
         name: String,
         unmatched: String,
     },
+    #[error("{entity} name {name} is too long: it assembles into names like {example:?} of {length} characters, which exceed the limit of {limit} characters; shorten the name by at least {trim} characters")]
+    NameTooLong {
+        entity: &'static str,
+        name: String,
+        example: String,
+        length: usize,
+        limit: usize,
+        trim: usize,
+    },
     #[error(
         "{lhs_entity} {lhs_name} {error_class} {rhs_entity} {rhs_name}, defined at {rhs_scope}"
     )]
@@ -48,6 +57,27 @@ pub enum Error {
         prefix: String,
         disallowed: &'static str, // will either be "empty" or "'default/'"
     },
+    #[error(
+        "storage mapping '{prefix}' {store} store doesn't support encryption provider {provider}"
+    )]
+    StoreEncryptionUnsupported {
+        prefix: String,
+        store: &'static str,
+        provider: &'static str,
+    },
+    #[error("storage mapping '{prefix}' encryption key {key:?} is not a valid {provider} key (expected a key like {expect:?})")]
+    InvalidStoreEncryptionKey {
+        prefix: String,
+        key: String,
+        provider: &'static str,
+        expect: &'static str,
+    },
+    #[error("storage mapping '{prefix}' encryption key is in region {key_region}, but its S3 bucket is in region {bucket_region}; KMS keys must be in the same region as the bucket")]
+    StoreEncryptionRegionMismatch {
+        prefix: String,
+        key_region: String,
+        bucket_region: String,
+    },
     #[error("could not map {this_entity} {this_thing} into a storage mapping")]
     NoStorageMapping {
         this_thing: String,
@@ -71,6 +101,14 @@ pub enum Error {
     InvalidSchemaCombination { collection: String },
     #[error("referenced schema fragment location {schema} does not exist")]
     NoSuchSchema { schema: Url },
+    #[error("schema $ref '{reference}', resolved relative to '{base}', doesn't match any loaded schema ({trace})")]
+    UnresolvedSchemaReference {
+        reference: Url,
+        base: Url,
+        trace: String,
+    },
+    #[error("schema $ref '{reference}' is ambiguous, as it's defined by multiple resources: {resources}")]
+    AmbiguousSchemaReference { reference: Url, resources: String },
     #[error("collection {collection} key cannot be empty (https://go.estuary.dev/Zq6zVB)")]
     CollectionKeyEmpty { collection: String },
     #[error("collection schema {schema} must have type 'object'")]
@@ -107,6 +145,19 @@ pub enum Error {
         field: String,
         collection: String,
     },
+    #[error("partition {field} of collection {collection} may have values of up to {max_length} bytes, but its journal names can accommodate at most {limit} bytes of partition values; bound the partition's maxLength, or partition on a shorter field")]
+    PartitionValueTooLong {
+        field: String,
+        collection: String,
+        max_length: u32,
+        limit: usize,
+    },
+    #[error("partition {field} of collection {collection} has unbounded cardinality ({detail}), and every distinct value creates a new journal; prefer partitioning on a boolean, an enum, or a bounded integer range, and split partitions by key range to scale")]
+    PartitionCardinalityUnbounded {
+        field: String,
+        collection: String,
+        detail: String,
+    },
     #[error("projection {field} is the canonical field name of location {canonical_ptr:?}, and cannot re-map it to {wrong_ptr:?}")]
     ProjectionRemapsCanonicalField {
         field: String,
@@ -179,6 +230,21 @@ pub enum Error {
     NotBeforeAfterOrder,
     #[error("test ingest document is invalid against the collection schema: {}", serde_json::to_string_pretty(.0).unwrap())]
     IngestDocInvalid(doc::FailedValidation),
+    #[error(
+        "test document was expected to be invalid, but it validates against the collection schemas"
+    )]
+    TestExpectDocValid,
+    #[error("failed to reduce test documents")]
+    TestExpectReduce {
+        #[source]
+        detail: anyhow::Error,
+    },
+    #[error("reduced test documents don't match expected documents ({actual} reduced, {expected} expected):\n{diffs}")]
+    TestExpectReducedMismatch {
+        actual: usize,
+        expected: usize,
+        diffs: String,
+    },
     #[error("{entity} {name} bindings duplicate the endpoint resource {resource} at {rhs_scope}")]
     BindingDuplicatesResource {
         entity: &'static str,
@@ -186,6 +252,12 @@ pub enum Error {
         resource: String,
         rhs_scope: Url,
     },
+    #[error("materialization {materialization} bindings duplicate the Dekaf topic name {topic} at {rhs_scope}")]
+    BindingDuplicatesTopicName {
+        materialization: String,
+        topic: String,
+        rhs_scope: Url,
+    },
     #[error(transparent)]
     SchemaBuild(#[from] json::schema::build::Error),
     #[error(transparent)]
@@ -223,6 +295,18 @@ pub enum Error {
         ref_entity: &'static str,
         ref_name: String,
     },
+    #[error("field {field} of collection {collection} is an object with unbounded additional properties at {ptr}, but the connector of materialization {materialization} requires bounded objects. Consider bounding the object in the collection schema (for example, with `additionalProperties: false`), or excluding the field")]
+    UnboundedObjectField {
+        materialization: String,
+        collection: String,
+        field: String,
+        ptr: String,
+    },
+    #[error("{this_entity} writes to frozen collection {collection}, which doesn't accept new documents")]
+    FrozenCollectionWrite {
+        this_entity: String,
+        collection: String,
+    },
     #[error(
         "{this_entity} doesn't have an assigned data-plane, and no default data-plane is available"
     )]
@@ -232,6 +316,211 @@ pub enum Error {
         this_entity: String,
         data_plane_id: models::Id,
     },
+    #[error("{this_entity} reads collection {collection} of data-plane {source_data_plane} from data-plane {data_plane}, but doesn't set `allowCrossPlaneRead: true`")]
+    CrossPlaneReadNotAllowed {
+        this_entity: String,
+        collection: String,
+        data_plane: String,
+        source_data_plane: String,
+    },
+    #[error("{this_entity} reads collection {collection} of data-plane {source_data_plane}, but its data-plane {data_plane} doesn't permit cross-plane reads from {source_data_plane}")]
+    CrossPlaneReadNotPeered {
+        this_entity: String,
+        collection: String,
+        data_plane: String,
+        source_data_plane: String,
+    },
+    #[error("{entity} {name} declares zone {zone:?}, which is not a zone of its data-plane {data_plane} (available zones are {zones:?})")]
+    ZoneUnavailable {
+        entity: &'static str,
+        name: String,
+        zone: String,
+        data_plane: String,
+        zones: Vec<String>,
+    },
+    #[error("{entity} {name} has an invalid resource limit: {detail}")]
+    InvalidTaskResource {
+        entity: &'static str,
+        name: String,
+        detail: String,
+    },
+    #[error("{entity} {name} {resource} limit of {value} is less than the minimum of {lower}")]
+    TaskResourceTooLow {
+        entity: &'static str,
+        name: String,
+        resource: &'static str,
+        value: u64,
+        lower: u64,
+    },
+    #[error("{entity} {name} {resource} limit of {value} is greater than the maximum of {upper} permitted by its data-plane {data_plane}")]
+    TaskResourceTooHigh {
+        entity: &'static str,
+        name: String,
+        resource: &'static str,
+        value: u64,
+        upper: u64,
+        data_plane: String,
+    },
+    #[error("{entity} {name} is assigned to data-plane {data_plane}, which is not admissible for prefix {prefix} (admissible data-planes are {data_planes:?})")]
+    DataPlaneNotAdmissible {
+        entity: &'static str,
+        name: String,
+        data_plane: String,
+        prefix: String,
+        data_planes: Vec<String>,
+    },
+    #[error("{entity} {name} {field} of {value:?} is outside of the supported range {lower:?} to {upper:?}")]
+    TxnDurationOutOfRange {
+        entity: &'static str,
+        name: String,
+        field: &'static str,
+        value: std::time::Duration,
+        lower: std::time::Duration,
+        upper: std::time::Duration,
+    },
+    #[error("{entity} {name} minTxnDuration of {min:?} cannot be greater than its maxTxnDuration of {max:?}")]
+    TxnDurationsInverted {
+        entity: &'static str,
+        name: String,
+        min: std::time::Duration,
+        max: std::time::Duration,
+    },
+    #[error(
+        "{entity} {name} hintBackups of {value} is outside of the supported range 0 to {upper}"
+    )]
+    HintBackupsOutOfRange {
+        entity: &'static str,
+        name: String,
+        value: u32,
+        upper: u32,
+    },
+    #[error("{entity} {name} has an invalid hintPrefix {prefix:?}: {detail}")]
+    InvalidHintPrefix {
+        entity: &'static str,
+        name: String,
+        prefix: String,
+        detail: &'static str,
+    },
+    #[error("{entity} {name} hintPrefix cannot be changed from {live:?} to {prefix:?} after the task has been published")]
+    HintPrefixChanged {
+        entity: &'static str,
+        name: String,
+        live: String,
+        prefix: String,
+    },
+    #[error("collection {collection} journal {field} of {value} is outside of the supported range {lower} to {upper}")]
+    JournalTemplateOutOfRange {
+        collection: String,
+        field: &'static str,
+        value: u64,
+        lower: u64,
+        upper: u64,
+    },
+    #[error("collection {collection} has an invalid fragment pathPostfixTemplate {template:?}: {detail}")]
+    InvalidPathPostfixTemplate {
+        collection: String,
+        template: String,
+        detail: String,
+    },
+    #[error("collection {collection} keyMigration is from key {from:?}, but its current key is {live:?}")]
+    KeyMigrationMismatch {
+        collection: String,
+        from: Vec<String>,
+        live: Vec<String>,
+    },
+    #[error("collection {collection} cannot be migrated to key location {ptr}, because it cannot be inferred from existing documents: {detail}")]
+    KeyMigrationNotInferable {
+        collection: String,
+        ptr: String,
+        detail: String,
+    },
+    #[error("{task} must backfill collection {collection}, which is migrating its key, by incrementing the backfill counter of each of its bindings or transforms of the collection")]
+    KeyMigrationRequiresBackfill { collection: String, task: String },
+    #[error("collection {collection} fragment retention of {retention} is less than the minimum of {minimum}")]
+    RetentionTooShort {
+        collection: String,
+        retention: String,
+        minimum: String,
+    },
+    #[error("materialization {materialization} backfills collection {collection}, which retains only {retention} of historical data; documents older than its retention have been removed and will not be materialized")]
+    BackfillExceedsRetention {
+        materialization: String,
+        collection: String,
+        retention: String,
+    },
+    #[error("collection {collection} is not read or written by any of the {specs} captures, derivations, materializations, or tests of this build's drafted and live specifications")]
+    UnusedCollection { collection: String, specs: usize },
+    #[error("test {test} references collection {collection}, which is being deleted")]
+    OrphanedTest { test: String, collection: String },
+    #[error("$schema {draft:?} is not a JSON schema draft supported by Flow (2019-09 or 2020-12); strict schemas are enabled for this tenant")]
+    StrictSchemaDraft { draft: String },
+    #[error("keyword {keyword:?} is not a keyword of JSON schema or of Flow; strict schemas are enabled for this tenant")]
+    StrictSchemaUnknownKeyword { keyword: String },
+    #[error("keyword 'required' is an empty array, which is likely a mistake; strict schemas are enabled for this tenant")]
+    StrictSchemaEmptyRequired,
+    #[error("{detail}, so no document can match this schema location; strict schemas are enabled for this tenant")]
+    StrictSchemaImpossibleType { detail: String },
+    #[error("this publication drafts {count} specifications, which exceeds the limit of {limit} specifications per publication; split it into multiple smaller publications")]
+    TooManySpecs { count: usize, limit: usize },
+    #[error("{entity} {name} maxReadRate of {rate} bytes per second is less than the minimum of {lower} bytes per second")]
+    ReadRateTooLow {
+        entity: &'static str,
+        name: String,
+        rate: u32,
+        lower: u32,
+    },
+    #[error("{entity} {name} {thing} has a maxReadRate and a priority of {priority}, which is greater than the priority {unthrottled_priority} of the unthrottled {thing} at {rhs_scope}; a throttled {thing} cannot have a higher priority than an unthrottled one")]
+    ThrottledPriorityInversion {
+        entity: &'static str,
+        name: String,
+        thing: &'static str,
+        priority: u32,
+        unthrottled_priority: u32,
+        rhs_scope: Url,
+    },
+    #[error("{entity} {name} has {count} enabled bindings, which exceeds the limit of {limit} bindings per task; split it into multiple tasks")]
+    TooManyBindings {
+        entity: &'static str,
+        name: String,
+        count: usize,
+        limit: usize,
+    },
+    #[error("collection {collection} has {count} projections, which exceeds the limit of {limit} projections per collection")]
+    TooManyProjections {
+        collection: String,
+        count: usize,
+        limit: usize,
+    },
+    #[error("{entity} {name} annotation {field} cannot be empty")]
+    AnnotationEmpty {
+        entity: &'static str,
+        name: String,
+        field: String,
+    },
+    #[error("{entity} {name} annotation {field} is {length} characters, which exceeds the limit of {limit} characters")]
+    AnnotationTooLong {
+        entity: &'static str,
+        name: String,
+        field: String,
+        length: usize,
+        limit: usize,
+    },
+    #[error("{entity} {name} annotation {field} {value:?} is invalid ({unmatched:?} is not allowed; use only letters, digits, and the symbols {symbols:?})")]
+    AnnotationInvalid {
+        entity: &'static str,
+        name: String,
+        field: String,
+        value: String,
+        unmatched: String,
+        symbols: &'static str,
+    },
+    #[error("{entity} {name} has {count} labels, which exceeds the limit of {limit} labels")]
+    TooManyAnnotationLabels {
+        entity: &'static str,
+        name: String,
+        count: usize,
+        limit: usize,
+    },
     #[error("expected draft model to be equal to the live model because `is_touch: true`")]
     TouchModelChanged,
     #[error("cannot touch because live model does not exist")]
@@ -242,6 +531,6 @@ pub enum Error {
 
 impl Error {
     pub fn push(self, scope: sources::Scope, errors: &mut tables::Errors) {
-        errors.insert_row(scope.flatten(), anyhow::anyhow!(self));
+        errors.insert_scoped(scope.flatten(), anyhow::anyhow!(self));
     }
 }
@@ -30,6 +30,66 @@ pub fn walk_name(
     }
 }
 
+// Maximum length of a Gazette journal name or shard ID.
+pub const MAX_GAZETTE_NAME_LENGTH: usize = 512;
+
+// Walk the worst-case journal name of the partitions of `collection`,
+// having the given partition `fields`. Partition values are elided here,
+// and are instead bounded by collection::walk_partition_guardrails.
+pub fn walk_partition_name_length<'a>(
+    scope: sources::Scope,
+    collection: &models::Collection,
+    fields: impl Iterator<Item = &'a str>,
+    errors: &mut tables::Errors,
+) {
+    let mut example = format!("{collection}/{}", models::Id::zero());
+    for field in fields {
+        example.push_str(&format!("/{field}="));
+    }
+    example.push_str("/pivot=00");
+
+    walk_name_length(scope, "collection", collection, example, errors)
+}
+
+// Walk the worst-case recovery log journal name of a task, which is the
+// longest Gazette name that's assembled for it: a task shard ID prefixed
+// with `recovery/` and suffixed with the task's key and r-clock splits.
+pub fn walk_task_name_length(
+    scope: sources::Scope,
+    entity: &'static str,
+    task_name: &str,
+    task_type: &str,
+    errors: &mut tables::Errors,
+) {
+    let example = format!(
+        "recovery/{}/ffffffff-ffffffff",
+        assemble::shard_id_prefix(models::Id::zero(), task_name, task_type)
+    );
+    walk_name_length(scope, entity, task_name, example, errors)
+}
+
+fn walk_name_length(
+    scope: sources::Scope,
+    entity: &'static str,
+    name: &str,
+    example: String,
+    errors: &mut tables::Errors,
+) {
+    let length = example.len();
+
+    if length > MAX_GAZETTE_NAME_LENGTH {
+        Error::NameTooLong {
+            entity,
+            name: name.to_string(),
+            example,
+            length,
+            limit: MAX_GAZETTE_NAME_LENGTH,
+            trim: length - MAX_GAZETTE_NAME_LENGTH,
+        }
+        .push(scope, errors);
+    }
+}
+
 pub fn walk_duplicates<'a, I>(i: I, errors: &mut tables::Errors)
 where
     I: Iterator<Item = (&'static str, &'a str, sources::Scope<'a>)> + 'a,
@@ -90,3 +150,110 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn messages(errors: &tables::Errors) -> Vec<String> {
+        errors.iter().map(|e| format!("{:#}", e.error)).collect()
+    }
+
+    // Build a name of exactly `length` characters.
+    fn name_of(length: usize) -> String {
+        format!("acmeCo/{}", "a".repeat(length - "acmeCo/".len()))
+    }
+
+    #[test]
+    fn test_partition_name_length() {
+        let url = url::Url::parse("test://example/catalog.yaml").unwrap();
+        let overhead = "/0000000000000000/region=/shard=/pivot=00".len();
+
+        let walk = |collection: &str| {
+            let mut errors = tables::Errors::new();
+            walk_partition_name_length(
+                sources::Scope::new(&url),
+                &models::Collection::new(collection),
+                ["region", "shard"].into_iter(),
+                &mut errors,
+            );
+            messages(&errors)
+        };
+
+        // A name which assembles into exactly the limit is allowed.
+        assert!(walk(&name_of(MAX_GAZETTE_NAME_LENGTH - overhead)).is_empty());
+
+        // One character more is an error, which names the characters to trim.
+        let errors = walk(&name_of(MAX_GAZETTE_NAME_LENGTH - overhead + 1));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("of 513 characters"), "{errors:?}");
+        assert!(
+            errors[0].contains("shorten the name by at least 1 characters"),
+            "{errors:?}"
+        );
+        assert!(
+            errors[0].contains("/region=/shard=/pivot=00\""),
+            "{errors:?}"
+        );
+
+        // Partition fields alone may push a short name over the limit.
+        let mut errors = tables::Errors::new();
+        let field = "f".repeat(MAX_GAZETTE_NAME_LENGTH);
+        walk_partition_name_length(
+            sources::Scope::new(&url),
+            &models::Collection::new("acmeCo/short"),
+            [field.as_str()].into_iter(),
+            &mut errors,
+        );
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn test_task_name_length() {
+        let url = url::Url::parse("test://example/catalog.yaml").unwrap();
+
+        for (entity, task_type, prefix) in [
+            ("capture", labels::TASK_TYPE_CAPTURE, "capture"),
+            ("derivation", labels::TASK_TYPE_DERIVATION, "derivation"),
+            (
+                "materialization",
+                labels::TASK_TYPE_MATERIALIZATION,
+                "materialize",
+            ),
+        ] {
+            let overhead = format!("recovery/{prefix}//0000000000000000/ffffffff-ffffffff").len();
+
+            let walk = |task: &str| {
+                let mut errors = tables::Errors::new();
+                walk_task_name_length(
+                    sources::Scope::new(&url),
+                    entity,
+                    task,
+                    task_type,
+                    &mut errors,
+                );
+                messages(&errors)
+            };
+
+            assert!(
+                walk(&name_of(MAX_GAZETTE_NAME_LENGTH - overhead)).is_empty(),
+                "{entity}"
+            );
+
+            let errors = walk(&name_of(MAX_GAZETTE_NAME_LENGTH - overhead + 10));
+            assert_eq!(errors.len(), 1, "{entity}");
+            assert!(
+                errors[0].starts_with(&format!("{entity} name acmeCo/aaa")),
+                "{errors:?}"
+            );
+            assert!(
+                errors[0].contains(&format!("\"recovery/{prefix}/acmeCo/aaa")),
+                "{errors:?}"
+            );
+            assert!(
+                errors[0].contains("shorten the name by at least 10 characters"),
+                "{errors:?}"
+            );
+        }
+    }
+}
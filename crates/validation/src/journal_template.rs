@@ -0,0 +1,188 @@
+use super::Error;
+
+/// Minimum retention of collection journal fragments. Shorter retentions are
+/// likely a mistake, as fragments would be removed soon after they're persisted.
+pub const MIN_RETENTION: std::time::Duration = std::time::Duration::from_secs(3600);
+
+/// Methods of `.Spool.FirstAppendTime` which may be used by path postfix templates,
+/// in addition to `Format` with a quoted layout.
+const PATH_POSTFIX_TIME_METHODS: &[&str] = &["Year", "YearDay", "Day", "Hour", "Minute"];
+
+pub fn walk_journal_template(
+    scope: sources::Scope,
+    collection: &models::Collection,
+    journal_template: &models::JournalTemplate,
+    errors: &mut tables::Errors,
+) {
+    let models::JournalTemplate {
+        fragments,
+        replication,
+        max_append_rate,
+        zones: _,
+    } = journal_template;
+
+    if let Some(template) = &fragments.path_postfix_template {
+        if let Err(detail) = check_path_postfix_template(template) {
+            Error::InvalidPathPostfixTemplate {
+                collection: collection.to_string(),
+                template: template.clone(),
+                detail,
+            }
+            .push(
+                scope
+                    .push_prop("fragments")
+                    .push_prop("pathPostfixTemplate"),
+                errors,
+            );
+        }
+    }
+
+    if let Some(retention) = fragments.retention {
+        if retention < MIN_RETENTION {
+            Error::RetentionTooShort {
+                collection: collection.to_string(),
+                retention: humantime::format_duration(retention).to_string(),
+                minimum: humantime::format_duration(MIN_RETENTION).to_string(),
+            }
+            .push(scope.push_prop("fragments").push_prop("retention"), errors);
+        }
+    }
+
+    if let Some(replication) = replication {
+        let (lower, upper) = (models::MIN_REPLICATION, models::MAX_REPLICATION);
+
+        if *replication < lower || *replication > upper {
+            Error::JournalTemplateOutOfRange {
+                collection: collection.to_string(),
+                field: "replication",
+                value: *replication as u64,
+                lower: lower as u64,
+                upper: upper as u64,
+            }
+            .push(scope.push_prop("replication"), errors);
+        }
+    }
+    if let Some(rate) = max_append_rate {
+        let (lower, upper) = (models::MIN_MAX_APPEND_RATE, models::MAX_MAX_APPEND_RATE);
+
+        if *rate < lower || *rate > upper {
+            Error::JournalTemplateOutOfRange {
+                collection: collection.to_string(),
+                field: "maxAppendRate",
+                value: *rate,
+                lower,
+                upper,
+            }
+            .push(scope.push_prop("maxAppendRate"), errors);
+        }
+    }
+}
+
+/// Check a fragment path postfix template, which is a Go text/template.
+/// Rather than fully parse the template, we require that each of its actions
+/// is one of an allow-list of references to the spool's first append time,
+/// and that its literal text is composed of path-safe characters.
+fn check_path_postfix_template(template: &str) -> Result<(), String> {
+    if template.is_empty() {
+        return Err("template cannot be empty".to_string());
+    }
+    if template.starts_with('/') || template.ends_with('/') || template.contains("//") {
+        return Err("template cannot begin or end with '/', or have empty components".to_string());
+    }
+
+    let mut rest = template;
+    while !rest.is_empty() {
+        let (text, action) = match rest.split_once("{{") {
+            Some((text, tail)) => {
+                let Some((action, tail)) = tail.split_once("}}") else {
+                    return Err("template has an unterminated action".to_string());
+                };
+                rest = tail;
+                (text, Some(action))
+            }
+            None => (std::mem::take(&mut rest), None),
+        };
+
+        if text.contains("}}") {
+            return Err("template has an unopened action".to_string());
+        }
+        if let Some(c) = text
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '=' | '_' | '-' | '.' | '/')))
+        {
+            return Err(format!("template text has disallowed character {c:?}"));
+        }
+        if let Some(action) = action {
+            check_path_postfix_action(action.trim())?;
+        }
+    }
+    Ok(())
+}
+
+fn check_path_postfix_action(action: &str) -> Result<(), String> {
+    let Some(method) = action.strip_prefix(".Spool.FirstAppendTime.") else {
+        return Err(format!(
+            "template action {{{{{action}}}}} must reference .Spool.FirstAppendTime"
+        ));
+    };
+
+    if let Some(layout) = method.strip_prefix("Format ") {
+        let layout = layout.trim();
+        let is_quoted = layout.len() >= 2 && layout.starts_with('"') && layout.ends_with('"');
+
+        if is_quoted
+            && layout[1..layout.len() - 1]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'))
+        {
+            return Ok(());
+        }
+        Err(format!(
+            "template action {{{{{action}}}}} must Format with a quoted layout of path-safe characters"
+        ))
+    } else if PATH_POSTFIX_TIME_METHODS.contains(&method) {
+        Ok(())
+    } else {
+        Err(format!(
+            "template action {{{{{action}}}}} must use Format or one of {PATH_POSTFIX_TIME_METHODS:?}"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::check_path_postfix_template;
+
+    #[test]
+    fn test_path_postfix_templates() {
+        for ok in [
+            r#"utc_date={{.Spool.FirstAppendTime.Format "2006-01-02"}}/utc_hour={{.Spool.FirstAppendTime.Format "15"}}"#,
+            r#"dt={{ .Spool.FirstAppendTime.Format "20060102" }}"#,
+            r#"year={{.Spool.FirstAppendTime.Year}}/day={{.Spool.FirstAppendTime.YearDay}}"#,
+            "static/path",
+        ] {
+            assert_eq!(check_path_postfix_template(ok), Ok(()), "{ok}");
+        }
+
+        for (bad, expect) in [
+            ("", "empty"),
+            ("/leading", "begin or end"),
+            ("a//b", "empty components"),
+            ("a b", "disallowed character"),
+            (
+                "dt={{.Spool.FirstAppendTime.Format \"2006\"",
+                "unterminated",
+            ),
+            ("dt=}}", "unopened"),
+            ("{{.JournalSpec.Name}}", "must reference"),
+            (
+                r#"{{.Spool.FirstAppendTime.Format "2006/01"}}"#,
+                "quoted layout",
+            ),
+            ("{{.Spool.FirstAppendTime.Location}}", "must use Format"),
+        ] {
+            let err = check_path_postfix_template(bad).unwrap_err();
+            assert!(err.contains(expect), "{bad}: {err}");
+        }
+    }
+}
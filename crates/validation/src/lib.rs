@@ -2,20 +2,34 @@ use futures::future::BoxFuture;
 use sources::Scope;
 use tables::EitherOrBoth as EOB;
 
+mod annotations;
 mod capture;
 mod collection;
+mod connector_specs;
+mod cross_plane;
 mod derivation;
+mod diagnostics;
 mod errors;
 mod indexed;
+mod journal_template;
+mod limits;
+pub mod lineage;
 mod materialization;
 mod noop;
+mod read_hints;
 mod reference;
 mod schema;
+mod schema_refs;
+mod shard_template;
+mod splits;
 mod storage_mapping;
+mod task_resources;
 mod test_step;
+mod zones;
 
 pub use errors::Error;
-pub use noop::{NoOpConnectors, NoOpWrapper};
+pub use limits::Limits;
+pub use noop::{FixtureConnectors, NoOpConnectors, NoOpWrapper};
 
 /// Connectors is a delegated trait -- provided to validate -- through which
 /// connector validation RPCs are dispatched. Request and Response must always
@@ -38,6 +52,35 @@ pub trait Connectors: Send + Sync {
         request: proto_flow::materialize::Request,
         data_plane: &'a tables::DataPlane,
     ) -> BoxFuture<'a, anyhow::Result<proto_flow::materialize::Response>>;
+
+    /// Fetch the Spec of a capture connector image, which is recorded into the
+    /// ConnectorSpecs of the build. Request and Response are Spec variants.
+    /// Implementations which don't record connector Specs return None.
+    fn spec_capture<'a>(
+        &'a self,
+        _request: proto_flow::capture::Request,
+        _data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<proto_flow::capture::Response>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// Fetch the Spec of a derivation connector image, as does `spec_capture`.
+    fn spec_derivation<'a>(
+        &'a self,
+        _request: proto_flow::derive::Request,
+        _data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<proto_flow::derive::Response>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// Fetch the Spec of a materialization connector image, as does `spec_capture`.
+    fn spec_materialization<'a>(
+        &'a self,
+        _request: proto_flow::materialize::Request,
+        _data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<proto_flow::materialize::Response>>> {
+        Box::pin(async { Ok(None) })
+    }
 }
 
 pub async fn validate(
@@ -48,6 +91,7 @@ pub async fn validate(
     draft: &tables::DraftCatalog,
     live: &tables::LiveCatalog,
     fail_fast: bool,
+    limits: &Limits,
 ) -> tables::Validations {
     let mut errors = tables::Errors::new();
 
@@ -67,16 +111,31 @@ pub async fn validate(
 
     storage_mapping::walk_all_storage_mappings(&live.storage_mappings, &mut errors);
 
+    // Compose Prefixes from storage mappings if the resolver didn't provide them.
+    let composed_prefixes;
+    let prefixes = if live.prefixes.is_empty() {
+        composed_prefixes = storage_mapping::compose_prefixes(&live.storage_mappings);
+        &composed_prefixes
+    } else {
+        &live.prefixes
+    };
+    limits::walk_draft(project_root, draft, limits, &mut errors);
+    schema_refs::walk_schema_references(&draft.resources, &mut errors);
+
     // Build all local collections.
+    let mut warnings = tables::Errors::new();
     let mut built_collections = collection::walk_all_collections(
         pub_id,
         build_id,
         default_plane_id,
         &draft.collections,
         &live.collections,
-        &live.storage_mappings,
+        prefixes,
+        &live.tenants,
         &mut errors,
+        &mut warnings,
     );
+    limits::walk_built_collections(draft, &built_collections, limits, &mut errors);
 
     // If we failed to build one or more collections then further validation
     // will generate lots of misleading "not found" errors.
@@ -86,7 +145,9 @@ pub async fn validate(
             built_collections,
             built_materializations: tables::BuiltMaterializations::new(),
             built_tests: tables::BuiltTests::new(),
+            connector_specs: tables::ConnectorSpecs::new(),
             errors,
+            warnings,
         };
     }
 
@@ -110,7 +171,9 @@ pub async fn validate(
             built_collections,
             built_materializations: tables::BuiltMaterializations::new(),
             built_tests,
+            connector_specs: tables::ConnectorSpecs::new(),
             errors,
+            warnings,
         };
     }
 
@@ -126,7 +189,7 @@ pub async fn validate(
         connectors,
         &live.data_planes,
         default_plane_id,
-        &live.storage_mappings,
+        prefixes,
         &dependencies,
         &mut capture_errors,
     );
@@ -142,7 +205,7 @@ pub async fn validate(
         &live.data_planes,
         &draft.imports,
         project_root,
-        &live.storage_mappings,
+        prefixes,
         &dependencies,
         &mut derive_errors,
     );
@@ -157,13 +220,13 @@ pub async fn validate(
         connectors,
         &live.data_planes,
         default_plane_id,
-        &live.storage_mappings,
+        prefixes,
         &dependencies,
         &mut materialize_errors,
     );
 
     // Concurrently validate all tasks.
-    let (built_captures, built_derivations, built_materializations) =
+    let (mut built_captures, built_derivations, mut built_materializations) =
         futures::join!(built_captures, built_derivations, built_materializations);
 
     errors.extend(capture_errors.into_iter());
@@ -178,6 +241,69 @@ pub async fn validate(
         row.dependency_hash = dependency_hash;
     }
 
+    // Preview the bindings which reconciliation with a sourceCapture will change.
+    materialization::preview_source_captures(
+        &mut built_materializations,
+        &built_captures,
+        &live.captures,
+        &built_collections,
+    );
+
+    // Verify that tasks reading collections having key migrations will backfill them.
+    collection::walk_key_migration_backfills(
+        &mut built_collections,
+        &built_materializations,
+        live,
+        &mut errors,
+    );
+
+    // Record the field-level lineage of derivations and materializations.
+    lineage::walk_lineage(&mut built_collections, &mut built_materializations);
+
+    // Recommend initial splits of new tasks which read high-volume collections.
+    splits::walk_recommended_splits(
+        &mut built_collections,
+        &mut built_materializations,
+        &live.collection_throughputs,
+    );
+
+    // Flag unbounded objects selected by connectors which require bounded ones.
+    materialization::walk_unbounded_objects(&built_materializations, &mut errors, &mut warnings);
+
+    // Verify that reads of collections in other data-planes are permitted.
+    cross_plane::walk_cross_plane_reads(
+        &built_collections,
+        &built_materializations,
+        &live.data_planes,
+        &mut errors,
+    );
+    // Verify that declared zones are zones of assigned data-planes.
+    zones::walk_all_zones(
+        &built_captures,
+        &built_collections,
+        &built_materializations,
+        &live.data_planes,
+        &mut errors,
+    );
+    // Verify that resource limits fall within the policy of assigned data-planes.
+    task_resources::walk_all_task_resources(
+        &built_captures,
+        &built_collections,
+        &built_materializations,
+        &live.data_planes,
+        &mut errors,
+    );
+
+    // Verify that created specifications are assigned admissible data-planes.
+    storage_mapping::walk_admissible_data_planes(
+        &built_captures,
+        &built_collections,
+        &built_materializations,
+        prefixes,
+        &live.data_planes,
+        &mut errors,
+    );
+
     // Look for name collisions among all top-level catalog entities.
     let collections_it = built_collections
         .iter()
@@ -204,12 +330,37 @@ pub async fn validate(
         &mut errors,
     );
 
+    diagnostics::walk_unused(draft, live, &mut warnings);
+    diagnostics::walk_retention_backfills(draft, live, &mut warnings);
+
+    // Record the Specs of connector images used by a successful build.
+    let connector_specs = if errors.is_empty() {
+        connector_specs::walk_all_connector_specs(
+            connectors,
+            &built_captures,
+            &built_collections,
+            &built_materializations,
+            &live.data_planes,
+        )
+        .await
+    } else {
+        tables::ConnectorSpecs::new()
+    };
+    connector_specs::attach_connector_docs(
+        &connector_specs,
+        &mut built_captures,
+        &mut built_collections,
+        &mut built_materializations,
+    );
+
     tables::Validations {
         built_captures,
         built_collections,
         built_materializations,
         built_tests,
+        connector_specs,
         errors,
+        warnings,
     }
 }
 
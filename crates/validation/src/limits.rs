@@ -0,0 +1,210 @@
+use super::{Error, Scope};
+use tables::DraftRow;
+
+/// Limits on the size of a catalog build, which bound the work a single
+/// publication may ask of the control-plane and its connectors.
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Maximum number of enabled bindings of a capture or materialization,
+    /// or transforms of a derivation.
+    pub max_bindings: usize,
+    /// Maximum number of specifications drafted into a single publication.
+    pub max_specs: usize,
+    /// Maximum number of projections of a built collection.
+    pub max_projections: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_bindings: 5_000,
+            max_specs: 20_000,
+            max_projections: 5_000,
+        }
+    }
+}
+
+/// Walk the specifications of `draft`, flagging a draft with too many
+/// specifications and drafted tasks having too many enabled bindings.
+/// Touched specifications aren't changed by the publication, and aren't limited.
+pub fn walk_draft(
+    project_root: &url::Url,
+    draft: &tables::DraftCatalog,
+    limits: &Limits,
+    errors: &mut tables::Errors,
+) {
+    let count = untouched(&draft.captures).count()
+        + untouched(&draft.collections).count()
+        + untouched(&draft.materializations).count()
+        + untouched(&draft.tests).count();
+
+    if count > limits.max_specs {
+        Error::TooManySpecs {
+            count,
+            limit: limits.max_specs,
+        }
+        .push(Scope::new(project_root), errors);
+    }
+
+    for row in untouched(&draft.captures) {
+        let Some(model) = &row.model else { continue };
+        let count = model.bindings.iter().filter(|b| !b.disable).count();
+
+        walk_bindings(
+            "capture",
+            &row.capture,
+            Scope::new(&row.scope).push_prop("bindings"),
+            count,
+            limits,
+            errors,
+        );
+    }
+    for row in untouched(&draft.collections) {
+        let Some(derive) = row.model.as_ref().and_then(|m| m.derive.as_ref()) else {
+            continue;
+        };
+        let count = derive.transforms.iter().filter(|t| !t.disable).count();
+
+        walk_bindings(
+            "derivation",
+            &row.collection,
+            Scope::new(&row.scope)
+                .push_prop("derive")
+                .push_prop("transforms"),
+            count,
+            limits,
+            errors,
+        );
+    }
+    for row in untouched(&draft.materializations) {
+        let Some(model) = &row.model else { continue };
+        let count = model.bindings.iter().filter(|b| !b.disable).count();
+
+        walk_bindings(
+            "materialization",
+            &row.materialization,
+            Scope::new(&row.scope).push_prop("bindings"),
+            count,
+            limits,
+            errors,
+        );
+    }
+}
+
+/// Walk built collections which are drafted and not touched by `draft`,
+/// flagging those having too many projections.
+pub fn walk_built_collections(
+    draft: &tables::DraftCatalog,
+    built_collections: &tables::BuiltCollections,
+    limits: &Limits,
+    errors: &mut tables::Errors,
+) {
+    for built in built_collections.iter() {
+        let Some(spec) = &built.spec else { continue };
+
+        match draft.collections.get_key(&built.collection) {
+            Some(row) if !row.is_touch => {}
+            _ => continue, // Not drafted, or touched.
+        }
+        let count = spec.projections.len();
+
+        if count > limits.max_projections {
+            Error::TooManyProjections {
+                collection: built.collection.to_string(),
+                count,
+                limit: limits.max_projections,
+            }
+            .push(Scope::new(&built.scope).push_prop("projections"), errors);
+        }
+    }
+}
+
+fn untouched<R: DraftRow>(rows: &tables::Table<R>) -> impl Iterator<Item = &R> {
+    rows.iter().filter(|row| !row.is_touch())
+}
+
+fn walk_bindings(
+    entity: &'static str,
+    name: &str,
+    scope: Scope,
+    count: usize,
+    limits: &Limits,
+    errors: &mut tables::Errors,
+) {
+    if count > limits.max_bindings {
+        Error::TooManyBindings {
+            entity,
+            name: name.to_string(),
+            count,
+            limit: limits.max_bindings,
+        }
+        .push(scope, errors);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_limit_boundaries() {
+        let limits = Limits {
+            max_bindings: 2,
+            max_specs: 3,
+            max_projections: 5,
+        };
+        let root = url::Url::parse("test://example/flow.yaml").unwrap();
+        let scope = |name: &str| {
+            let name = name.replace('/', "~1");
+            url::Url::parse(&format!("test://example/flow.yaml#/captures/{name}")).unwrap()
+        };
+
+        let capture = |bindings: usize| {
+            let mut model = models::CaptureDef::example();
+            model.bindings = vec![models::CaptureBinding::example(); bindings];
+            model
+        };
+        let outcome = |draft: &tables::DraftCatalog| {
+            let mut errors = tables::Errors::new();
+            walk_draft(&root, draft, &limits, &mut errors);
+            errors
+                .into_iter()
+                .map(|err| format!("{} {}", err.scope, err.error))
+                .collect::<Vec<_>>()
+        };
+
+        // Exactly at the limits of specs and bindings, with touched
+        // specifications which are ignored.
+        let mut draft = tables::DraftCatalog::default();
+        for (name, bindings, is_touch) in [
+            ("acmeCo/one", 2, false),
+            ("acmeCo/two", 2, false),
+            ("acmeCo/three", 2, false),
+            ("acmeCo/touched", 3, true),
+        ] {
+            draft.captures.insert_row(
+                models::Capture::new(name),
+                scope(name),
+                None,
+                Some(capture(bindings)),
+                is_touch,
+            );
+        }
+        assert!(outcome(&draft).is_empty());
+
+        // One past the limits of specs and bindings.
+        draft.captures.insert_row(
+            models::Capture::new("acmeCo/four"),
+            scope("acmeCo/four"),
+            None,
+            Some(capture(3)),
+            false,
+        );
+        insta::assert_debug_snapshot!(outcome(&draft), @r###"
+        [
+            "test://example/flow.yaml this publication drafts 4 specifications, which exceeds the limit of 3 specifications per publication; split it into multiple smaller publications",
+            "test://example/flow.yaml#/captures/acmeCo~1four/bindings capture acmeCo/four has 3 enabled bindings, which exceeds the limit of 2 bindings per task; split it into multiple tasks",
+        ]
+        "###);
+    }
+}
@@ -0,0 +1,164 @@
+use models::{FieldLineage, LineageVia};
+use proto_flow::flow;
+
+/// Extract the field-level lineage of built derivations and materializations,
+/// attaching it to their built rows.
+pub fn walk_lineage(
+    built_collections: &mut tables::BuiltCollections,
+    built_materializations: &mut tables::BuiltMaterializations,
+) {
+    for row in built_collections.iter_mut() {
+        if let Some(spec) = &row.spec {
+            row.lineage = derivation_lineage(spec);
+        }
+    }
+    for row in built_materializations.iter_mut() {
+        if let Some(spec) = &row.spec {
+            row.lineage = materialization_lineage(spec);
+        }
+    }
+}
+
+/// Extract the field-level lineage of a built materialization,
+/// from the field selection of each of its bindings.
+pub fn materialization_lineage(spec: &flow::MaterializationSpec) -> Vec<FieldLineage> {
+    let mut out = Vec::new();
+
+    for (index, binding) in spec.bindings.iter().enumerate() {
+        let (Some(collection), Some(selection)) = (&binding.collection, &binding.field_selection)
+        else {
+            continue;
+        };
+
+        let selected = selection
+            .keys
+            .iter()
+            .chain(selection.values.iter())
+            .chain(std::iter::once(&selection.document).filter(|d| !d.is_empty()));
+
+        for field in selected {
+            let Some(projection) = collection.projections.iter().find(|p| &p.field == field) else {
+                continue;
+            };
+            out.push(FieldLineage {
+                index: index as u32,
+                field: Some(models::Field::new(field)),
+                source: models::Collection::new(&collection.name),
+                location: models::JsonPointer::new(&projection.ptr),
+                via: LineageVia::Projection,
+            });
+        }
+    }
+    out
+}
+
+/// Extract the lineage of a built derivation, from the shuffle keys of its
+/// transforms. Lambdas don't declare the locations they read, so shuffled
+/// locations are attributed to each transform as a whole.
+pub fn derivation_lineage(spec: &flow::CollectionSpec) -> Vec<FieldLineage> {
+    let Some(derivation) = &spec.derivation else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+
+    for (index, transform) in derivation.transforms.iter().enumerate() {
+        let Some(collection) = &transform.collection else {
+            continue;
+        };
+        for ptr in &transform.shuffle_key {
+            out.push(FieldLineage {
+                index: index as u32,
+                field: None,
+                source: models::Collection::new(&collection.name),
+                location: models::JsonPointer::new(ptr),
+                via: LineageVia::ShuffleKey,
+            });
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn collection(name: &str) -> flow::CollectionSpec {
+        flow::CollectionSpec {
+            name: name.to_string(),
+            projections: [("id", "/id"), ("val", "/nested/val"), ("flow_document", "")]
+                .into_iter()
+                .map(|(field, ptr)| flow::Projection {
+                    field: field.to_string(),
+                    ptr: ptr.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_materialization_lineage() {
+        let spec = flow::MaterializationSpec {
+            bindings: vec![flow::materialization_spec::Binding {
+                collection: Some(collection("acme/source")),
+                field_selection: Some(flow::FieldSelection {
+                    keys: vec!["id".to_string()],
+                    values: vec!["val".to_string()],
+                    document: "flow_document".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let lineage = materialization_lineage(&spec);
+        assert_eq!(
+            lineage
+                .iter()
+                .map(|l| (l.field.as_ref().unwrap().as_str(), l.location.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("id", "/id"), ("val", "/nested/val"), ("flow_document", "")]
+        );
+        assert!(lineage
+            .iter()
+            .all(|l| l.source == "acme/source" && l.via == LineageVia::Projection));
+    }
+
+    #[test]
+    fn test_derivation_lineage() {
+        use flow::collection_spec::{derivation::Transform, Derivation};
+
+        let spec = flow::CollectionSpec {
+            derivation: Some(Derivation {
+                transforms: vec![
+                    Transform {
+                        collection: Some(collection("acme/one")),
+                        shuffle_key: vec!["/id".to_string()],
+                        ..Default::default()
+                    },
+                    Transform {
+                        collection: Some(collection("acme/two")),
+                        shuffle_key: vec!["/a".to_string(), "/b".to_string()],
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }),
+            ..collection("acme/derived")
+        };
+
+        assert_eq!(
+            derivation_lineage(&spec)
+                .iter()
+                .map(|l| (l.index, l.source.as_str(), l.location.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, "acme/one", "/id"),
+                (1, "acme/two", "/a"),
+                (1, "acme/two", "/b"),
+            ]
+        );
+    }
+}
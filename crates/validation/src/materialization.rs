@@ -1,6 +1,6 @@
 use super::{
-    collection, indexed, reference, storage_mapping, walk_transition, Connectors, Error,
-    NoOpConnectors, Scope,
+    annotations, collection, indexed, read_hints, reference, schema, shard_template,
+    storage_mapping, walk_transition, Connectors, Error, NoOpConnectors, Scope,
 };
 use itertools::Itertools;
 use proto_flow::{flow, materialize, ops::log::Level as LogLevel};
@@ -16,7 +16,7 @@ pub async fn walk_all_materializations(
     connectors: &dyn Connectors,
     data_planes: &tables::DataPlanes,
     default_plane_id: Option<models::Id>,
-    storage_mappings: &tables::StorageMappings,
+    prefixes: &tables::Prefixes,
     dependencies: &tables::Dependencies<'_>,
     errors: &mut tables::Errors,
 ) -> tables::BuiltMaterializations {
@@ -44,7 +44,7 @@ pub async fn walk_all_materializations(
                 connectors,
                 data_planes,
                 default_plane_id,
-                storage_mappings,
+                prefixes,
                 dependencies,
                 &mut local_errors,
             )
@@ -74,7 +74,7 @@ async fn walk_materialization(
     connectors: &dyn Connectors,
     data_planes: &tables::DataPlanes,
     default_plane_id: Option<models::Id>,
-    storage_mappings: &tables::StorageMappings,
+    prefixes: &tables::Prefixes,
     dependencies: &tables::Dependencies<'_>,
     errors: &mut tables::Errors,
 ) -> Option<tables::BuiltMaterialization> {
@@ -100,6 +100,8 @@ async fn walk_materialization(
         endpoint,
         bindings: all_bindings,
         shards: shard_template,
+        allow_cross_plane_read: _,
+        annotations,
         expect_pub_id: _,
         delete: _,
         on_incompatible_schema_change: _,
@@ -112,6 +114,13 @@ async fn walk_materialization(
         models::Materialization::regex(),
         errors,
     );
+    indexed::walk_task_name_length(
+        scope,
+        "materialization",
+        materialization,
+        labels::TASK_TYPE_MATERIALIZATION,
+        errors,
+    );
 
     // Unwrap `endpoint` into a connector type and configuration.
     let (connector_type, config_json) = match endpoint {
@@ -136,6 +145,21 @@ async fn walk_materialization(
         .filter_map(|(index, binding)| (!binding.disable).then_some((index, binding)))
         .collect();
 
+    if let models::MaterializationEndpoint::Dekaf(_) = endpoint {
+        walk_dekaf_topic_names(scope, materialization, &enabled_bindings, errors);
+    }
+    read_hints::walk_read_hints(
+        scope.push_prop("bindings"),
+        "materialization",
+        materialization,
+        "binding",
+        &enabled_bindings
+            .iter()
+            .map(|(index, binding)| (*index, binding.priority, binding.max_read_rate))
+            .collect::<Vec<_>>(),
+        errors,
+    );
+
     // Map enabled bindings into validation requests.
     let binding_requests: Vec<_> = enabled_bindings
         .iter()
@@ -152,11 +176,30 @@ async fn walk_materialization(
         .collect();
 
     // Determine storage mappings for task recovery logs.
-    let recovery_stores = storage_mapping::mapped_stores(
+    let recovery_stores = storage_mapping::recovery_stores(
         scope,
         "materialization",
-        &format!("recovery/{materialization}"),
-        storage_mappings,
+        materialization,
+        prefixes,
+        errors,
+    );
+
+    annotations::walk_annotations(
+        scope.push_prop("annotations"),
+        "materialization",
+        materialization,
+        annotations,
+        errors,
+    );
+    shard_template::walk_shard_template(
+        scope.push_prop("shards"),
+        "materialization",
+        materialization,
+        shard_template,
+        live_spec
+            .and_then(|spec| spec.shard_template.as_ref())
+            .map(|shard| shard.hint_prefix.as_str()),
+        data_planes.get_key(&data_plane_id),
         errors,
     );
 
@@ -201,7 +244,7 @@ async fn walk_materialization(
     .await;
 
     // Unwrap `response` and bail out if it failed.
-    let (validated_response, network_ports) = match extract_validated(response) {
+    let (mut validated_response, network_ports) = match extract_validated(response) {
         Err(err) => {
             err.push(scope, errors);
             return None;
@@ -209,8 +252,13 @@ async fn walk_materialization(
         Ok(ok) => ok,
     };
 
+    if let Some(live_spec) = live_spec {
+        flag_incompatible_projections(live_spec, &binding_requests, &mut validated_response);
+    }
+
     let materialize::response::Validated {
         bindings: binding_responses,
+        requires_bounded_objects: _,
     } = &validated_response;
 
     if enabled_bindings.len() != binding_responses.len() {
@@ -247,6 +295,7 @@ async fn walk_materialization(
             fields,
             disable: _,
             priority,
+            max_read_rate,
             resource: _,
             backfill: _,
             on_incompatible_schema_change: _,
@@ -286,6 +335,7 @@ async fn walk_materialization(
             collection,
             partition_selector,
             priority: *priority,
+            max_read_rate: max_read_rate.unwrap_or_default(),
             field_selection,
             delta_updates: *delta_updates,
             deprecated_shuffle: None,
@@ -359,6 +409,7 @@ async fn walk_materialization(
         &shard_id_prefix,
         false, // Don't disable wait_for_ack.
         &network_ports,
+        annotations,
     );
     let built_spec = flow::MaterializationSpec {
         name: materialization.to_string(),
@@ -384,9 +435,67 @@ async fn walk_materialization(
         previous_spec: live_spec.cloned(),
         is_touch,
         dependency_hash,
+        source_capture_preview: None,
+        // Connector documentation is attached once Specs are fetched.
+        documentation_url: None,
+        oauth2: None,
+        // Lineage is extracted once all tasks are built.
+        lineage: Vec::new(),
     })
 }
 
+/// Attach a preview of changes which will be made by reconciling each
+/// materialization with its sourceCapture, as of the capture and collections
+/// of this build. Captures which aren't part of the build are taken from
+/// their live specifications. Reconciliation only ever adds bindings for
+/// capture collections, and disables bindings of collections being deleted.
+pub fn preview_source_captures(
+    built_materializations: &mut tables::BuiltMaterializations,
+    built_captures: &tables::BuiltCaptures,
+    live_captures: &tables::LiveCaptures,
+    built_collections: &tables::BuiltCollections,
+) {
+    use models::ModelDef;
+
+    for built in built_materializations.iter_mut() {
+        let Some(model) = &built.model else {
+            continue; // Materialization is being deleted.
+        };
+        let Some(source_capture) = &model.source_capture else {
+            continue;
+        };
+        let capture = source_capture.capture_name();
+
+        let capture_model = match built_captures.get_by_key(&capture) {
+            Some(built_capture) => built_capture.model.as_ref(),
+            None => live_captures.get_by_key(&capture).map(|live| &live.model),
+        };
+        // A missing or deleted capture is reported through other means.
+        let Some(capture_model) = capture_model else {
+            continue;
+        };
+
+        let mut preview = models::SourceCapturePreview {
+            add_bindings: capture_model.writes_to(),
+            disable_bindings: Default::default(),
+        };
+        for binding in model.bindings.iter() {
+            let collection = binding.source.collection();
+            preview.add_bindings.remove(collection);
+
+            if !binding.disable
+                && built_collections
+                    .get_by_key(collection)
+                    .is_some_and(|built| built.model.is_none())
+            {
+                preview.disable_bindings.insert(collection.clone());
+            }
+        }
+
+        built.source_capture_preview = (!preview.is_empty()).then_some(preview);
+    }
+}
+
 fn walk_materialization_binding<'a>(
     scope: Scope<'a>,
     catalog_name: &models::Materialization,
@@ -406,6 +515,7 @@ fn walk_materialization_binding<'a>(
             },
         disable: _,
         priority: _,
+        max_read_rate: _,
         backfill,
         on_incompatible_schema_change: _,
     } = binding;
@@ -718,6 +828,232 @@ fn walk_materialization_response(
     }
 }
 
+/// Flag selected fields of live bindings whose projected types have changed
+/// incompatibly (for example, from integer to string) by marking them as
+/// unsatisfiable within the connector's Validated response.
+/// Connectors don't always detect such changes themselves, and flagged fields
+/// are then handled like any other incompatible schema change.
+/// Bindings which are being backfilled are not checked.
+fn flag_incompatible_projections(
+    live_spec: &flow::MaterializationSpec,
+    requests: &[materialize::request::validate::Binding],
+    validated: &mut materialize::response::Validated,
+) {
+    use materialize::response::validated::{constraint::Type, Constraint};
+
+    for (request, response) in requests.iter().zip(validated.bindings.iter_mut()) {
+        let Some(live) = live_spec
+            .bindings
+            .iter()
+            .find(|live| live.resource_path == response.resource_path)
+        else {
+            continue; // Binding is new.
+        };
+        let (Some(live_collection), Some(live_selection), Some(collection)) =
+            (&live.collection, &live.field_selection, &request.collection)
+        else {
+            continue;
+        };
+        if request.backfill != live.backfill {
+            continue; // Binding is being backfilled.
+        }
+
+        for field in live_selection
+            .keys
+            .iter()
+            .chain(live_selection.values.iter())
+        {
+            let projected_types = |collection: &flow::CollectionSpec| {
+                collection
+                    .projections
+                    .iter()
+                    .find(|p| &p.field == field)
+                    .and_then(|p| p.inference.as_ref())
+                    .map(|inference| inference.types.clone())
+            };
+            let (Some(live_types), Some(types)) = (
+                projected_types(live_collection),
+                projected_types(collection),
+            ) else {
+                continue;
+            };
+            if !is_incompatible_type_change(&live_types, &types) {
+                continue;
+            }
+
+            let constraint = response.constraints.entry(field.clone()).or_default();
+
+            if matches!(
+                Type::try_from(constraint.r#type),
+                Ok(Type::Unsatisfiable | Type::FieldForbidden)
+            ) {
+                continue; // Connector has already rejected the field.
+            }
+            *constraint = Constraint {
+                r#type: Type::Unsatisfiable as i32,
+                reason: format!(
+                    "field {field} of collection {} changed from types {live_types:?} to {types:?}, which is incompatible with its materialization",
+                    collection.name,
+                ),
+            };
+        }
+    }
+}
+
+/// Walk the enabled bindings of a Dekaf materialization to flag topic names
+/// which are used by more than one binding. Each topic must map to a single
+/// collection, as topic names are how Dekaf consumers address bindings.
+fn walk_dekaf_topic_names(
+    scope: Scope,
+    materialization: &models::Materialization,
+    enabled_bindings: &[(usize, &models::MaterializationBinding)],
+    errors: &mut tables::Errors,
+) {
+    #[derive(serde::Deserialize)]
+    struct Resource {
+        topic_name: String,
+    }
+
+    // Resources which don't parse are left to the connector to reject.
+    let topics = enabled_bindings.iter().filter_map(|(index, binding)| {
+        serde_json::from_str::<Resource>(binding.resource.get())
+            .ok()
+            .map(|Resource { topic_name }| (topic_name, *index))
+    });
+
+    for ((topic, l_index), (_, r_index)) in topics
+        .sorted()
+        .tuple_windows()
+        .filter(|((l_topic, _), (r_topic, _))| l_topic == r_topic)
+    {
+        let scope = scope.push_prop("bindings");
+        let lhs_scope = scope.push_item(l_index);
+        let rhs_scope = scope.push_item(r_index).flatten();
+
+        Error::BindingDuplicatesTopicName {
+            materialization: materialization.to_string(),
+            topic,
+            rhs_scope,
+        }
+        .push(lhs_scope, errors);
+    }
+}
+
+/// Flag selected value fields of materializations which are objects having
+/// unbounded additional properties, where the materialization's connector
+/// requires bounded objects. Newly-selected fields are errors, while fields
+/// already selected by the live materialization are warnings.
+pub fn walk_unbounded_objects(
+    built_materializations: &tables::BuiltMaterializations,
+    errors: &mut tables::Errors,
+    warnings: &mut tables::Errors,
+) {
+    for built in built_materializations.iter() {
+        let (Some(validated), Some(spec), Some(model)) =
+            (&built.validated, &built.spec, &built.model)
+        else {
+            continue;
+        };
+        if !validated.requires_bounded_objects {
+            continue;
+        }
+        let scope = Scope::new(&built.scope);
+
+        // Built bindings are the enabled bindings of the model, in order.
+        let indices = model
+            .bindings
+            .iter()
+            .enumerate()
+            .filter_map(|(index, binding)| (!binding.disable).then_some(index));
+
+        for (index, binding) in indices.zip(spec.bindings.iter()) {
+            let (Some(collection), Some(selection)) =
+                (&binding.collection, &binding.field_selection)
+            else {
+                continue;
+            };
+            let bundle = if collection.read_schema_json.is_empty() {
+                &collection.write_schema_json
+            } else {
+                &collection.read_schema_json
+            };
+            let Ok(schema) = schema::Schema::new(bundle) else {
+                continue; // Errors were reported when building the collection.
+            };
+
+            let live_selection = built
+                .previous_spec
+                .iter()
+                .flat_map(|live| live.bindings.iter())
+                .find(|live| live.resource_path == binding.resource_path)
+                .and_then(|live| live.field_selection.as_ref());
+
+            for field in &selection.values {
+                let Some(projection) = collection.projections.iter().find(|p| &p.field == field)
+                else {
+                    continue;
+                };
+                let (shape, _) = schema
+                    .shape
+                    .locate(&doc::Pointer::from_str(&projection.ptr));
+
+                if !is_unbounded_object(shape) {
+                    continue;
+                }
+                let error = Error::UnboundedObjectField {
+                    materialization: built.materialization.to_string(),
+                    collection: collection.name.clone(),
+                    field: field.clone(),
+                    ptr: projection.ptr.clone(),
+                };
+                let scope = scope.push_prop("bindings").push_item(index);
+
+                if live_selection.is_some_and(|live| live.values.contains(field)) {
+                    error.push(scope, warnings);
+                } else {
+                    error.push(scope, errors);
+                }
+            }
+        }
+    }
+}
+
+/// Is `shape` an object which may have properties beyond those it enumerates?
+fn is_unbounded_object(shape: &doc::Shape) -> bool {
+    if !shape.type_.overlaps(json::schema::types::OBJECT) {
+        return false;
+    }
+    let closed = matches!(
+        &shape.object.additional_properties,
+        Some(addl) if addl.type_ == json::schema::types::INVALID
+    );
+    !closed || !shape.object.pattern_properties.is_empty()
+}
+
+/// Is a change of projected types from `live` to `next` incompatible?
+/// It is if `next` admits a kind of value which `live` does not,
+/// or narrows a number of `live` to only an integer.
+/// Widening an integer to a number, or admitting null, is compatible.
+fn is_incompatible_type_change(live: &[String], next: &[String]) -> bool {
+    fn kind(type_: &String) -> &str {
+        match type_.as_str() {
+            "integer" | "number" => "number",
+            other => other,
+        }
+    }
+    let has = |types: &[String], type_: &str| types.iter().any(|t| t == type_);
+    let narrowed = has(live, "number") && !has(next, "number") && has(next, "integer");
+
+    let live: Vec<&str> = live.iter().map(kind).filter(|k| *k != "null").collect();
+
+    !live.is_empty()
+        && (narrowed
+            || next
+                .iter()
+                .map(kind)
+                .any(|k| k != "null" && !live.contains(&k)))
+}
+
 fn extract_validated(
     response: anyhow::Result<materialize::Response>,
 ) -> Result<(materialize::response::Validated, Vec<flow::NetworkPort>), Error> {
@@ -771,3 +1107,35 @@ fn source_exclusions<'m>(
         })
         .flatten()
 }
+
+#[cfg(test)]
+mod test {
+    use super::is_incompatible_type_change;
+
+    #[test]
+    fn test_incompatible_type_changes() {
+        let types = |t: &[&str]| t.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+
+        for (live, next, expect) in [
+            (&["integer"][..], &["integer"][..], false),
+            (&["integer"], &["number"], false),
+            (&["number"], &["integer"], true),
+            (&["integer", "null"], &["null", "number"], false),
+            (&["null", "number"], &["integer", "null"], true),
+            (&["integer"], &["integer", "null"], false),
+            (&["string"], &["null", "string"], false),
+            (&["string", "null"], &["string"], false),
+            (&["integer"], &["string"], true),
+            (&["integer"], &["integer", "string"], true),
+            (&["string"], &["object"], true),
+            (&["boolean"], &["integer"], true),
+            (&[], &["string"], false),
+        ] {
+            assert_eq!(
+                is_incompatible_type_change(&types(live), &types(next)),
+                expect,
+                "{live:?} => {next:?}"
+            );
+        }
+    }
+}
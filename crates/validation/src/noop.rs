@@ -1,4 +1,5 @@
 use super::Connectors;
+use anyhow::Context;
 use futures::future::BoxFuture;
 use proto_flow::{capture, derive, materialize};
 use std::collections::BTreeMap;
@@ -114,6 +115,7 @@ impl Connectors for NoOpConnectors {
             Ok(materialize::Response {
                 validated: Some(Validated {
                     bindings: response_bindings,
+                    requires_bounded_objects: false,
                 }),
                 ..Default::default()
             })
@@ -164,4 +166,172 @@ impl<C: Connectors> Connectors for NoOpWrapper<C> {
             self.inner.validate_materialization(request, data_plane)
         }
     }
+    fn spec_capture<'a>(
+        &'a self,
+        request: capture::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<capture::Response>>> {
+        if self.noop_captures {
+            NoOpConnectors.spec_capture(request, data_plane)
+        } else {
+            self.inner.spec_capture(request, data_plane)
+        }
+    }
+    fn spec_derivation<'a>(
+        &'a self,
+        request: derive::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<derive::Response>>> {
+        if self.noop_derivations {
+            NoOpConnectors.spec_derivation(request, data_plane)
+        } else {
+            self.inner.spec_derivation(request, data_plane)
+        }
+    }
+    fn spec_materialization<'a>(
+        &'a self,
+        request: materialize::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<Option<materialize::Response>>> {
+        if self.noop_materializations {
+            NoOpConnectors.spec_materialization(request, data_plane)
+        } else {
+            self.inner.spec_materialization(request, data_plane)
+        }
+    }
+}
+
+/// FixtureConnectors respond to validations with fixture Validated responses
+/// of each task, and fall back to NoOpConnectors for tasks without a fixture.
+/// They allow offline builds to reflect the constraints of real connectors.
+#[derive(Debug, Default)]
+pub struct FixtureConnectors {
+    captures: BTreeMap<String, capture::response::Validated>,
+    derivations: BTreeMap<String, derive::response::Validated>,
+    materializations: BTreeMap<String, materialize::response::Validated>,
+}
+
+impl FixtureConnectors {
+    pub fn with_capture(mut self, name: &str, validated: capture::response::Validated) -> Self {
+        self.captures.insert(name.to_string(), validated);
+        self
+    }
+
+    pub fn with_derivation(mut self, name: &str, validated: derive::response::Validated) -> Self {
+        self.derivations.insert(name.to_string(), validated);
+        self
+    }
+
+    pub fn with_materialization(
+        mut self,
+        name: &str,
+        validated: materialize::response::Validated,
+    ) -> Self {
+        self.materializations.insert(name.to_string(), validated);
+        self
+    }
+
+    /// Load fixtures from directory `dir`, which holds JSON-encoded Validated
+    /// responses at paths like `captures/acmeCo/my/capture.json`,
+    /// `derivations/acmeCo/my/derivation.json`, or
+    /// `materializations/acmeCo/my/materialization.json`.
+    pub fn from_dir(dir: &std::path::Path) -> anyhow::Result<Self> {
+        Ok(Self {
+            captures: load_fixtures(&dir.join("captures"))?,
+            derivations: load_fixtures(&dir.join("derivations"))?,
+            materializations: load_fixtures(&dir.join("materializations"))?,
+        })
+    }
+}
+
+// Load `*.json` fixtures beneath `dir`, keyed on their relative path without extension.
+fn load_fixtures<T: serde::de::DeserializeOwned>(
+    dir: &std::path::Path,
+) -> anyhow::Result<BTreeMap<String, T>> {
+    let mut out = BTreeMap::new();
+
+    if !dir.exists() {
+        return Ok(out);
+    }
+    let mut stack = vec![dir.to_path_buf()];
+
+    while let Some(next) = stack.pop() {
+        for entry in std::fs::read_dir(&next).with_context(|| format!("reading {next:?}"))? {
+            let path = entry?.path();
+
+            if path.is_dir() {
+                stack.push(path);
+                continue;
+            } else if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let name = path.strip_prefix(dir)?.with_extension("");
+            let name = name
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            let content = std::fs::read(&path).with_context(|| format!("reading {path:?}"))?;
+            let fixture = serde_json::from_slice(&content)
+                .with_context(|| format!("parsing Validated fixture {path:?}"))?;
+
+            out.insert(name, fixture);
+        }
+    }
+    Ok(out)
+}
+
+impl Connectors for FixtureConnectors {
+    fn validate_capture<'a>(
+        &'a self,
+        request: capture::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<capture::Response>> {
+        let name = request.validate.as_ref().map(|v| v.name.as_str());
+
+        match name.and_then(|name| self.captures.get(name)) {
+            Some(validated) => Box::pin(futures::future::ok(capture::Response {
+                validated: Some(validated.clone()),
+                ..Default::default()
+            })),
+            None => NoOpConnectors.validate_capture(request, data_plane),
+        }
+    }
+
+    fn validate_derivation<'a>(
+        &'a self,
+        request: derive::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<derive::Response>> {
+        let name = request
+            .validate
+            .as_ref()
+            .and_then(|v| v.collection.as_ref())
+            .map(|c| c.name.as_str());
+
+        match name.and_then(|name| self.derivations.get(name)) {
+            Some(validated) => Box::pin(futures::future::ok(derive::Response {
+                validated: Some(validated.clone()),
+                ..Default::default()
+            })),
+            None => NoOpConnectors.validate_derivation(request, data_plane),
+        }
+    }
+
+    fn validate_materialization<'a>(
+        &'a self,
+        request: materialize::Request,
+        data_plane: &'a tables::DataPlane,
+    ) -> BoxFuture<'a, anyhow::Result<materialize::Response>> {
+        let name = request.validate.as_ref().map(|v| v.name.as_str());
+
+        match name.and_then(|name| self.materializations.get(name)) {
+            Some(validated) => Box::pin(futures::future::ok(materialize::Response {
+                validated: Some(validated.clone()),
+                ..Default::default()
+            })),
+            None => NoOpConnectors.validate_materialization(request, data_plane),
+        }
+    }
 }
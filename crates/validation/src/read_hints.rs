@@ -0,0 +1,57 @@
+use super::{Error, Scope};
+
+/// Smallest maximum read rate, in bytes per second, of a binding or transform.
+/// Lower rates are indistinguishable from a stalled read.
+pub const MIN_MAX_READ_RATE: u32 = 1024;
+
+/// Walk the `priority` and `maxReadRate` read hints of the enabled bindings
+/// (or transforms) of a task, as tuples of (index, priority, max-read-rate).
+///
+/// A throttled binding may not have a higher priority than any unthrottled
+/// binding: ready documents of a higher-priority binding are always read first,
+/// so the unthrottled binding would be held to the rate of the throttled one.
+pub fn walk_read_hints(
+    scope: Scope,
+    entity: &'static str,
+    name: &str,
+    thing: &'static str,
+    hints: &[(usize, u32, Option<u32>)],
+    errors: &mut tables::Errors,
+) {
+    for (index, _priority, max_read_rate) in hints {
+        let Some(rate) = max_read_rate else { continue };
+
+        if *rate < MIN_MAX_READ_RATE {
+            Error::ReadRateTooLow {
+                entity,
+                name: name.to_string(),
+                rate: *rate,
+                lower: MIN_MAX_READ_RATE,
+            }
+            .push(scope.push_item(*index).push_prop("maxReadRate"), errors);
+        }
+    }
+
+    // Identify the lowest-priority unthrottled binding, if there is one.
+    let Some((unthrottled_index, unthrottled_priority, _)) = hints
+        .iter()
+        .filter(|(_, _, max_read_rate)| max_read_rate.is_none())
+        .min_by_key(|(_, priority, _)| *priority)
+    else {
+        return;
+    };
+
+    for (index, priority, max_read_rate) in hints {
+        if max_read_rate.is_some() && priority > unthrottled_priority {
+            Error::ThrottledPriorityInversion {
+                entity,
+                name: name.to_string(),
+                thing,
+                priority: *priority,
+                unthrottled_priority: *unthrottled_priority,
+                rhs_scope: scope.push_item(*unthrottled_index).flatten(),
+            }
+            .push(scope.push_item(*index).push_prop("priority"), errors);
+        }
+    }
+}
@@ -21,6 +21,13 @@ pub fn walk_data_plane<'s, 'a>(
     data_plane
 }
 
+/// Is the collection `spec` frozen, such that its partitions are read-only?
+pub fn is_frozen(spec: &flow::CollectionSpec) -> bool {
+    use proto_gazette::broker::journal_spec::Flag;
+
+    matches!(&spec.partition_template, Some(template) if template.flags == Flag::ORdonly as u32)
+}
+
 pub fn walk_reference<'s, 'a>(
     this_scope: Scope<'s>,
     this_entity: &str,
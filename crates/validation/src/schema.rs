@@ -1,11 +1,84 @@
-use super::Error;
+use super::{Error, Scope};
 use doc::{
     reduce,
     shape::{self, location::Exists},
     validation, Shape,
 };
-use json::schema::types;
+use json::schema::{keywords, types};
 use proto_flow::flow::collection_spec::derivation::ShuffleType as ProtoShuffleType;
+use serde_json::Value;
+
+/// `$schema` drafts which are implemented by Flow.
+const SUPPORTED_DRAFTS: &[&str] = &[
+    "https://json-schema.org/draft/2019-09/schema",
+    "https://json-schema.org/draft/2020-12/schema",
+];
+
+/// Keywords of the JSON schema drafts implemented by Flow.
+/// Annotation keywords of Flow itself are checked separately.
+const DRAFT_KEYWORDS: &[&str] = &[
+    keywords::ADDITIONAL_ITEMS,
+    keywords::ADDITIONAL_PROPERTIES,
+    keywords::ALL_OF,
+    keywords::ANCHOR,
+    keywords::ANY_OF,
+    keywords::COMMENT,
+    keywords::CONST,
+    keywords::CONTAINS,
+    keywords::CONTENT_ENCODING,
+    keywords::CONTENT_MEDIA_TYPE,
+    keywords::CONTENT_SCHEMA,
+    keywords::DEF,
+    keywords::DEFAULT,
+    keywords::DEFINITIONS,
+    keywords::DEPENDENT_REQUIRED,
+    keywords::DEPENDENT_SCHEMAS,
+    keywords::DEPRECATED,
+    keywords::DESCRIPTION,
+    keywords::ELSE,
+    keywords::ENUM,
+    keywords::EXAMPLE,
+    keywords::EXAMPLES,
+    keywords::EXCLUSIVE_MAXIMUM,
+    keywords::EXCLUSIVE_MINIMUM,
+    keywords::FORMAT,
+    keywords::ID,
+    keywords::IF,
+    keywords::ITEMS,
+    keywords::MAXIMUM,
+    keywords::MAX_CONTAINS,
+    keywords::MAX_ITEMS,
+    keywords::MAX_LENGTH,
+    keywords::MAX_PROPERTIES,
+    keywords::MINIMUM,
+    keywords::MIN_CONTAINS,
+    keywords::MIN_ITEMS,
+    keywords::MIN_LENGTH,
+    keywords::MIN_PROPERTIES,
+    keywords::MULTIPLE_OF,
+    keywords::NOT,
+    keywords::NULLABLE,
+    keywords::ONE_OF,
+    keywords::PATTERN,
+    keywords::PATTERN_PROPERTIES,
+    keywords::PROPERTIES,
+    keywords::PROPERTY_NAMES,
+    keywords::READ_ONLY,
+    keywords::RECURSIVE_ANCHOR,
+    keywords::RECURSIVE_REF,
+    keywords::REF,
+    keywords::REQUIRED,
+    keywords::SCHEMA,
+    keywords::THEN,
+    keywords::TITLE,
+    keywords::TYPE,
+    keywords::UNEVALUATED_ITEMS,
+    keywords::UNEVALUATED_PROPERTIES,
+    keywords::UNIQUE_ITEMS,
+    keywords::VOCABULARY,
+    keywords::WRITE_ONLY,
+    "prefixItems",
+];
 
 pub struct Schema {
     // Canonical schema URI, which may include a fragment pointer if the schema
@@ -115,3 +188,178 @@ impl Schema {
         .collect()
     }
 }
+
+// Walk a schema in strict mode, which rejects constructs that are technically
+// valid JSON schema but are very likely mistakes: `$schema` drafts which Flow
+// doesn't implement, unknown keywords, empty `required` arrays, and type
+// constraints which no document could satisfy. Errors are scoped to their
+// exact schema location.
+pub fn walk_strict(scope: Scope, schema: &Value, errors: &mut tables::Errors) {
+    use json::schema::build::AnnotationBuilder;
+
+    let Value::Object(obj) = schema else {
+        return; // Boolean schemas have nothing to check.
+    };
+
+    for keyword in obj.keys() {
+        if !DRAFT_KEYWORDS.contains(&keyword.as_str()) && !doc::Annotation::uses_keyword(keyword) {
+            Error::StrictSchemaUnknownKeyword {
+                keyword: keyword.clone(),
+            }
+            .push(scope.push_prop(keyword), errors);
+        }
+    }
+
+    if let Some(Value::String(draft)) = obj.get(keywords::SCHEMA) {
+        if !SUPPORTED_DRAFTS.contains(&draft.trim_end_matches('#')) {
+            Error::StrictSchemaDraft {
+                draft: draft.clone(),
+            }
+            .push(scope.push_prop(keywords::SCHEMA), errors);
+        }
+    }
+
+    if matches!(obj.get(keywords::REQUIRED), Some(Value::Array(required)) if required.is_empty()) {
+        Error::StrictSchemaEmptyRequired.push(scope.push_prop(keywords::REQUIRED), errors);
+    }
+
+    let type_ = match obj.get(keywords::TYPE) {
+        Some(Value::String(name)) => types::Set::for_type_name(name),
+        Some(Value::Array(names)) if names.is_empty() => {
+            Error::StrictSchemaImpossibleType {
+                detail: "keyword 'type' is an empty array".to_string(),
+            }
+            .push(scope.push_prop(keywords::TYPE), errors);
+            None
+        }
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(|name| name.as_str().and_then(types::Set::for_type_name))
+            .reduce(|l, r| l | r),
+        _ => None,
+    };
+
+    if let Some(type_) = type_ {
+        if let Some(value) = obj.get(keywords::CONST) {
+            if !type_.overlaps(types::Set::for_value(value)) {
+                Error::StrictSchemaImpossibleType {
+                    detail: format!("const {value} is not of type {type_}"),
+                }
+                .push(scope.push_prop(keywords::CONST), errors);
+            }
+        }
+        if let Some(Value::Array(values)) = obj.get(keywords::ENUM) {
+            if !values.is_empty()
+                && !values
+                    .iter()
+                    .any(|value| type_.overlaps(types::Set::for_value(value)))
+            {
+                Error::StrictSchemaImpossibleType {
+                    detail: format!("no enum value is of type {type_}"),
+                }
+                .push(scope.push_prop(keywords::ENUM), errors);
+            }
+        }
+    }
+
+    // Recurse into sub-schemas.
+    for (keyword, value) in obj {
+        let scope = scope.push_prop(keyword);
+
+        match (keyword.as_str(), value) {
+            (
+                keywords::PROPERTIES
+                | keywords::PATTERN_PROPERTIES
+                | keywords::DEF
+                | keywords::DEFINITIONS
+                | keywords::DEPENDENT_SCHEMAS,
+                Value::Object(children),
+            ) => {
+                for (name, child) in children {
+                    walk_strict(scope.push_prop(name), child, errors);
+                }
+            }
+            (
+                keywords::ALL_OF
+                | keywords::ANY_OF
+                | keywords::ONE_OF
+                | keywords::ITEMS
+                | "prefixItems",
+                Value::Array(children),
+            ) => {
+                for (index, child) in children.iter().enumerate() {
+                    walk_strict(scope.push_item(index), child, errors);
+                }
+            }
+            (
+                keywords::ADDITIONAL_ITEMS
+                | keywords::ADDITIONAL_PROPERTIES
+                | keywords::CONTAINS
+                | keywords::ELSE
+                | keywords::IF
+                | keywords::ITEMS
+                | keywords::NOT
+                | keywords::PROPERTY_NAMES
+                | keywords::THEN
+                | keywords::UNEVALUATED_ITEMS
+                | keywords::UNEVALUATED_PROPERTIES,
+                child,
+            ) => walk_strict(scope, child, errors),
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_strict_schema_walk() {
+        let schema = serde_json::json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "required": [],
+            "properties": {
+                "ok": {"type": ["string", "null"], "enum": ["a", null]},
+                "const": {"type": "string", "const": 42},
+                "enum": {"type": "integer", "enum": ["one", "two"]},
+                "union": {"type": []},
+                "typo": {"type": "string", "maxLenght": 10, "x-extension": true},
+            },
+            "$defs": {
+                "nested": {
+                    "$schema": "https://json-schema.org/draft/2020-12/schema",
+                    "items": [{"type": "number", "const": 1.5}],
+                },
+            },
+        });
+        let root = url::Url::parse("test://example/schema.yaml").unwrap();
+        let mut errors = tables::Errors::new();
+        walk_strict(Scope::new(&root), &schema, &mut errors);
+
+        let mut errors: Vec<_> = errors
+            .iter()
+            .map(|e| (e.scope.fragment().unwrap().to_string(), e.error.to_string()))
+            .collect();
+        errors.sort();
+
+        assert_eq!(
+            errors
+                .iter()
+                .map(|(scope, _)| scope.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "/$schema",
+                "/properties/const/const",
+                "/properties/enum/enum",
+                "/properties/typo/maxLenght",
+                "/properties/union/type",
+                "/required",
+            ]
+        );
+        assert!(errors[1]
+            .1
+            .starts_with("const 42 is not of type \"string\""));
+    }
+}
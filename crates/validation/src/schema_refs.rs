@@ -0,0 +1,328 @@
+use super::{Error, Scope};
+use json::schema::{Application, Keyword};
+use proto_flow::flow::ContentType;
+use std::collections::{BTreeMap, BTreeSet};
+use url::Url;
+
+/// Walk the `$ref` keywords of all loaded JSON schema resources and of the
+/// inline collection schemas of catalog resources, verifying that each
+/// resolves to exactly one schema across all resources.
+///
+/// Canonical URIs of schemas and their `$anchor` forms may be defined by any
+/// resource, so this is checked across the complete set of resources rather
+/// than per-bundle. Unresolved references are reported with a trace of how
+/// resolution failed, and ambiguous references with each defining resource.
+pub fn walk_schema_references(resources: &tables::Resources, errors: &mut tables::Errors) {
+    // Schemas, each with its defining resource and the scope at which it's walked.
+    let mut compiled = Vec::new();
+    // Collection schema URLs having a fragment location, with their scopes.
+    let mut imports = Vec::new();
+
+    for resource in resources.iter() {
+        if !matches!(
+            resource.content_type,
+            ContentType::JsonSchema | ContentType::Catalog
+        ) {
+            continue;
+        }
+        let Ok(dom) = serde_json::from_str::<serde_json::Value>(resource.content_dom.get()) else {
+            continue;
+        };
+
+        if resource.content_type == ContentType::Catalog {
+            index_catalog_schemas(&resource.resource, &dom, &mut compiled, &mut imports);
+        } else if let Ok(schema) = doc::validation::build_schema(resource.resource.clone(), &dom) {
+            // Schemas which fail to build were already reported by the loader.
+            compiled.push((&resource.resource, resource.resource.clone(), schema));
+        }
+    }
+
+    let mut definitions = BTreeMap::new();
+    let mut canonical = BTreeMap::new();
+
+    for (resource, scope, schema) in &compiled {
+        index_definitions(resource, schema, &mut definitions);
+
+        // Only whole resources, and not inline schemas, have a canonical alias.
+        if *resource == scope && *resource != &schema.curi {
+            canonical.insert(*resource, &schema.curi);
+        }
+    }
+
+    for (_, scope, schema) in &compiled {
+        walk_schema_node(Scope::new(scope), schema, &definitions, &canonical, errors);
+    }
+
+    for (scope, resource, import) in &imports {
+        walk_reference(
+            Scope::new(scope),
+            resource,
+            import,
+            &definitions,
+            &canonical,
+            errors,
+        );
+    }
+}
+
+// Gather the schemas of collections defined by catalog `resource`.
+// Inline schemas are built under the URI with which they're bundled,
+// and schema URLs having a fragment location are references to verify.
+fn index_catalog_schemas<'r>(
+    resource: &'r Url,
+    dom: &serde_json::Value,
+    compiled: &mut Vec<(&'r Url, Url, doc::Schema)>,
+    imports: &mut Vec<(Url, &'r Url, Url)>,
+) {
+    let Some(collections) = dom.get("collections").and_then(|c| c.as_object()) else {
+        return;
+    };
+
+    for (name, collection) in collections {
+        for prop in ["schema", "writeSchema", "readSchema"] {
+            let scope = Scope::new(resource);
+            let scope = scope.push_prop("collections");
+            let scope = scope.push_prop(name);
+            let scope = scope.push_prop(prop).flatten();
+
+            match collection.get(prop) {
+                Some(serde_json::Value::String(import)) => match resource.join(import) {
+                    Ok(import) if import.fragment().is_some() => {
+                        imports.push((scope, resource, import))
+                    }
+                    _ => (),
+                },
+                Some(schema @ serde_json::Value::Object(_)) => {
+                    // Bundling maps the location of an inline schema into a query parameter.
+                    let mut curi = scope.clone();
+                    curi.set_query(Some(&format!(
+                        "ptr={}",
+                        scope.fragment().unwrap_or_default()
+                    )));
+                    curi.set_fragment(None);
+
+                    if let Ok(schema) = doc::validation::build_schema(curi, schema) {
+                        compiled.push((resource, scope, schema));
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+// Index the canonical and anchor-form URIs of `schema` and its sub-schemas,
+// mapping each to the resources which define it.
+fn index_definitions<'s>(
+    resource: &'s Url,
+    schema: &'s doc::Schema,
+    definitions: &mut BTreeMap<&'s Url, BTreeSet<&'s Url>>,
+) {
+    definitions
+        .entry(&schema.curi)
+        .or_default()
+        .insert(resource);
+
+    for kw in &schema.kw {
+        match kw {
+            // Inline applications share the canonical URI of their parent.
+            Keyword::Application(Application::Inline, _) => {}
+            Keyword::Application(_, child) => index_definitions(resource, child, definitions),
+            Keyword::Anchor(auri) => {
+                definitions.entry(auri).or_default().insert(resource);
+            }
+            _ => (),
+        }
+    }
+}
+
+fn walk_schema_node(
+    scope: Scope,
+    schema: &doc::Schema,
+    definitions: &BTreeMap<&Url, BTreeSet<&Url>>,
+    canonical: &BTreeMap<&Url, &Url>,
+    errors: &mut tables::Errors,
+) {
+    for kw in &schema.kw {
+        let Keyword::Application(app, child) = kw else {
+            continue;
+        };
+        let location = app.push_keyword(&scope.location);
+
+        // The "flow" scheme is used to inject contextual schemas.
+        if let Application::Ref(reference) = app {
+            if reference.scheme() != "flow" {
+                walk_reference(
+                    Scope { location, ..scope },
+                    &schema.curi,
+                    reference,
+                    definitions,
+                    canonical,
+                    errors,
+                );
+            }
+        }
+
+        walk_schema_node(
+            Scope {
+                location: app.push_keyword_target(&location),
+                ..scope
+            },
+            child,
+            definitions,
+            canonical,
+            errors,
+        );
+    }
+}
+
+fn walk_reference(
+    scope: Scope,
+    referrer: &Url,
+    reference: &Url,
+    definitions: &BTreeMap<&Url, BTreeSet<&Url>>,
+    canonical: &BTreeMap<&Url, &Url>,
+    errors: &mut tables::Errors,
+) {
+    match definitions.get(reference) {
+        Some(resources) if resources.len() > 1 => Error::AmbiguousSchemaReference {
+            reference: reference.clone(),
+            resources: resources
+                .iter()
+                .map(|r| r.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+        .push(scope, errors),
+        Some(_) => (),
+        None => {
+            let mut base = referrer.clone();
+            base.set_fragment(None);
+
+            Error::UnresolvedSchemaReference {
+                reference: reference.clone(),
+                base,
+                trace: resolution_trace(reference, definitions, canonical),
+            }
+            .push(scope, errors)
+        }
+    }
+}
+
+// Explain why `reference` failed to resolve.
+fn resolution_trace(
+    reference: &Url,
+    definitions: &BTreeMap<&Url, BTreeSet<&Url>>,
+    canonical: &BTreeMap<&Url, &Url>,
+) -> String {
+    let mut target = reference.clone();
+    target.set_fragment(None);
+
+    let fragment = reference.fragment().unwrap_or_default();
+
+    if definitions.contains_key(&target) {
+        if fragment.starts_with('/') {
+            format!("{target} is loaded, but has no schema at location {fragment}")
+        } else {
+            format!("{target} is loaded, but has no $anchor {fragment:?}")
+        }
+    } else if let Some(curi) = canonical.get(&target) {
+        format!("{target} is loaded, but its canonical $id is {curi} which must be used instead")
+    } else {
+        format!("no loaded resource or schema $id is {target}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_schema_references_across_resources() {
+        let mut resources = tables::Resources::new();
+
+        for (url, schema) in [
+            (
+                "test://example/a.schema",
+                serde_json::json!({
+                    "$defs": {
+                        "thing": {"$anchor": "Thing", "type": "string"},
+                    },
+                    "properties": {
+                        "ok": {"$ref": "b.schema"},
+                        "ambiguous": {"$ref": "test://example/shared#Shared"},
+                        "missingPtr": {"$ref": "b.schema#/$defs/missing"},
+                        "missingAnchor": {"$ref": "#Missing"},
+                        "nonCanonical": {"$ref": "c.schema"},
+                        "missing": {"$ref": "test://example/missing.schema"},
+                        "flow": {"$ref": "flow://write-schema"},
+                    },
+                }),
+            ),
+            (
+                "test://example/b.schema",
+                serde_json::json!({"$ref": "a.schema#Thing"}),
+            ),
+            (
+                "test://example/c.schema",
+                serde_json::json!({"$id": "test://example/shared", "$anchor": "Shared"}),
+            ),
+            (
+                "test://example/d.schema",
+                serde_json::json!({"$id": "test://example/shared", "$anchor": "Shared"}),
+            ),
+        ] {
+            let content = serde_json::to_vec(&schema).unwrap();
+
+            resources.insert_row(
+                Url::parse(url).unwrap(),
+                ContentType::JsonSchema,
+                bytes::Bytes::from(content),
+                models::RawValue::from_value(&schema),
+            );
+        }
+
+        // Catalog collections may have inline schemas, or URLs with a fragment location.
+        let catalog = serde_json::json!({
+            "collections": {
+                "acmeCo/things": {
+                    "writeSchema": "b.schema#/$defs/missing",
+                    "readSchema": {
+                        "properties": {
+                            "ok": {"$ref": "a.schema#Thing"},
+                            "missing": {"$ref": "a.schema#Nope"},
+                        },
+                    },
+                    "key": ["/id"],
+                },
+            },
+        });
+        resources.insert_row(
+            Url::parse("test://example/catalog.yaml").unwrap(),
+            ContentType::Catalog,
+            bytes::Bytes::from(serde_json::to_vec(&catalog).unwrap()),
+            models::RawValue::from_value(&catalog),
+        );
+
+        let mut errors = tables::Errors::new();
+        walk_schema_references(&resources, &mut errors);
+
+        let mut errors = errors
+            .iter()
+            .map(|e| format!("{} {}", e.scope, e.error))
+            .collect::<Vec<_>>();
+        errors.sort();
+
+        insta::assert_debug_snapshot!(errors, @r###"
+        [
+            "test://example/a.schema#/properties/ambiguous/$ref schema $ref 'test://example/shared#Shared' is ambiguous, as it's defined by multiple resources: test://example/c.schema, test://example/d.schema",
+            "test://example/a.schema#/properties/missing/$ref schema $ref 'test://example/missing.schema', resolved relative to 'test://example/a.schema', doesn't match any loaded schema (no loaded resource or schema $id is test://example/missing.schema)",
+            "test://example/a.schema#/properties/missingAnchor/$ref schema $ref 'test://example/a.schema#Missing', resolved relative to 'test://example/a.schema', doesn't match any loaded schema (test://example/a.schema is loaded, but has no $anchor \"Missing\")",
+            "test://example/a.schema#/properties/missingPtr/$ref schema $ref 'test://example/b.schema#/$defs/missing', resolved relative to 'test://example/a.schema', doesn't match any loaded schema (test://example/b.schema is loaded, but has no schema at location /$defs/missing)",
+            "test://example/a.schema#/properties/nonCanonical/$ref schema $ref 'test://example/c.schema', resolved relative to 'test://example/a.schema', doesn't match any loaded schema (test://example/c.schema is loaded, but its canonical $id is test://example/shared which must be used instead)",
+            "test://example/catalog.yaml#/collections/acmeCo~1things/readSchema/properties/missing/$ref schema $ref 'test://example/a.schema#Nope', resolved relative to 'test://example/catalog.yaml?ptr=/collections/acmeCo~1things/readSchema', doesn't match any loaded schema (test://example/a.schema is loaded, but has no $anchor \"Nope\")",
+            "test://example/catalog.yaml#/collections/acmeCo~1things/writeSchema schema $ref 'test://example/b.schema#/$defs/missing', resolved relative to 'test://example/catalog.yaml', doesn't match any loaded schema (test://example/b.schema is loaded, but has no schema at location /$defs/missing)",
+        ]
+        "###);
+    }
+}
@@ -0,0 +1,184 @@
+use super::Error;
+use std::time::Duration;
+
+/// Smallest maximum transaction duration of data-planes which don't declare one.
+/// Shorter durations cause shards to spend more time committing than processing.
+pub const DEFAULT_MIN_MAX_TXN_DURATION: Duration = Duration::from_millis(100);
+/// Largest transaction duration of data-planes which don't declare one.
+/// Longer transactions hold recovery log checkpoints and journal appends
+/// open for longer than brokers will tolerate.
+pub const DEFAULT_MAX_TXN_DURATION: Duration = Duration::from_secs(60 * 60);
+/// Largest number of hint backups which may be kept for each shard.
+/// Each backup is an Etcd key which is written on every hint checkpoint.
+pub const MAX_HINT_BACKUPS: u32 = 10;
+
+pub fn walk_shard_template(
+    scope: sources::Scope,
+    entity: &'static str,
+    name: &str,
+    shard_template: &models::ShardTemplate,
+    live_hint_prefix: Option<&str>,
+    data_plane: Option<&tables::DataPlane>,
+    errors: &mut tables::Errors,
+) {
+    let models::ShardTemplate {
+        min_txn_duration,
+        max_txn_duration,
+        hint_backups,
+        hint_prefix,
+        ..
+    } = shard_template;
+
+    // A missing data-plane is reported when the specification is walked.
+    let bound = |millis: Option<i64>, default: Duration| {
+        millis.map_or(
+            default,
+            |millis| Duration::from_millis(millis.max(0) as u64),
+        )
+    };
+    let lower = bound(
+        data_plane.and_then(|dp| dp.min_max_txn_duration_millis),
+        DEFAULT_MIN_MAX_TXN_DURATION,
+    );
+    let upper = bound(
+        data_plane.and_then(|dp| dp.max_txn_duration_millis),
+        DEFAULT_MAX_TXN_DURATION,
+    );
+
+    if let Some(min) = min_txn_duration {
+        if *min > upper {
+            Error::TxnDurationOutOfRange {
+                entity,
+                name: name.to_string(),
+                field: "minTxnDuration",
+                value: *min,
+                lower: Duration::ZERO,
+                upper,
+            }
+            .push(scope.push_prop("minTxnDuration"), errors);
+        }
+    }
+    if let Some(max) = max_txn_duration {
+        if *max < lower || *max > upper {
+            Error::TxnDurationOutOfRange {
+                entity,
+                name: name.to_string(),
+                field: "maxTxnDuration",
+                value: *max,
+                lower,
+                upper,
+            }
+            .push(scope.push_prop("maxTxnDuration"), errors);
+        }
+    }
+    // If only `minTxnDuration` is set, it may exceed the default maximum
+    // which is then raised to match. That's intended, but an explicit
+    // maximum which is less than the minimum is a user error.
+    if let (Some(min), Some(max)) = (min_txn_duration, max_txn_duration) {
+        if min > max {
+            Error::TxnDurationsInverted {
+                entity,
+                name: name.to_string(),
+                min: *min,
+                max: *max,
+            }
+            .push(scope.push_prop("minTxnDuration"), errors);
+        }
+    }
+
+    if let Some(backups) = hint_backups {
+        if *backups > MAX_HINT_BACKUPS {
+            Error::HintBackupsOutOfRange {
+                entity,
+                name: name.to_string(),
+                value: *backups,
+                upper: MAX_HINT_BACKUPS,
+            }
+            .push(scope.push_prop("hintBackups"), errors);
+        }
+    }
+    if let Some(prefix) = hint_prefix {
+        if let Err(detail) = check_hint_prefix(prefix) {
+            Error::InvalidHintPrefix {
+                entity,
+                name: name.to_string(),
+                prefix: prefix.clone(),
+                detail,
+            }
+            .push(scope.push_prop("hintPrefix"), errors);
+        }
+    }
+    // Shards locate their recovery log hints through the prefix of their
+    // current spec, so changing it would strand the hints of a live task.
+    let prefix = hint_prefix
+        .as_deref()
+        .unwrap_or(assemble::DEFAULT_HINT_PREFIX);
+
+    if let Some(live) = live_hint_prefix {
+        if !live.is_empty() && live != prefix {
+            Error::HintPrefixChanged {
+                entity,
+                name: name.to_string(),
+                live: live.to_string(),
+                prefix: prefix.to_string(),
+            }
+            .push(scope.push_prop("hintPrefix"), errors);
+        }
+    }
+}
+
+fn check_hint_prefix(prefix: &str) -> Result<(), &'static str> {
+    if prefix == assemble::DEFAULT_HINT_PREFIX {
+        return Ok(());
+    }
+    let Some(suffix) = prefix
+        .strip_prefix(assemble::DEFAULT_HINT_PREFIX)
+        .and_then(|s| s.strip_prefix('/'))
+    else {
+        return Err("must be nested under /estuary/flow/hints/");
+    };
+
+    for component in suffix.split('/') {
+        if component.is_empty() {
+            return Err("must not have empty components or a trailing '/'");
+        }
+        if component == "." || component == ".." {
+            return Err("must not have relative components");
+        }
+        if !component
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err("may only contain letters, digits, '-', '_', '.', and '/'");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::check_hint_prefix;
+
+    #[test]
+    fn test_hint_prefix_checks() {
+        for ok in [
+            "/estuary/flow/hints",
+            "/estuary/flow/hints/acmeCo",
+            "/estuary/flow/hints/tenant-a/plane_1.v2",
+        ] {
+            assert_eq!(check_hint_prefix(ok), Ok(()), "{ok}");
+        }
+        for bad in [
+            "/estuary/flow/hints/",
+            "/estuary/flow/hintsX/acmeCo",
+            "/other/hints/acmeCo",
+            "estuary/flow/hints/acmeCo",
+            "/estuary/flow/hints/acmeCo/",
+            "/estuary/flow/hints//acmeCo",
+            "/estuary/flow/hints/../acmeCo",
+            "/estuary/flow/hints/acme Co",
+        ] {
+            assert!(check_hint_prefix(bad).is_err(), "{bad}");
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::BTreeSet;
+
+/// Bytes per hour of source collections which one shard is expected to process.
+pub const TARGET_BYTES_PER_SHARD_HOUR: u64 = 16 << 30;
+/// Maximum number of initial splits which are recommended.
+/// It's a power of two, as are all recommendations.
+pub const MAX_RECOMMENDED_SPLITS: u64 = 16;
+
+/// Recommend initial splits of new derivations and materializations, from the
+/// historical throughput of the collections which they read.
+///
+/// Recommendations are recorded as a label of the built ShardSpec template,
+/// which the control-plane uses to create evenly-divided key ranges when it
+/// first creates the task's shards. Tasks which already exist have shards,
+/// and are not given a recommendation.
+pub fn walk_recommended_splits(
+    built_collections: &mut tables::BuiltCollections,
+    built_materializations: &mut tables::BuiltMaterializations,
+    throughputs: &tables::CollectionThroughputs,
+) {
+    if throughputs.is_empty() {
+        return;
+    }
+
+    for row in built_collections.iter_mut() {
+        if !row.expect_pub_id.is_zero() {
+            continue;
+        }
+        let (Some(model), Some(spec)) = (&row.model, &mut row.spec) else {
+            continue;
+        };
+        let (Some(derive), Some(derivation)) = (&model.derive, &mut spec.derivation) else {
+            continue;
+        };
+        let sources = derive
+            .transforms
+            .iter()
+            .filter(|transform| !transform.disable)
+            .map(|transform| transform.source.collection());
+
+        set_recommended_splits(
+            derivation.shard_template.as_mut(),
+            recommend_splits(sources, throughputs),
+        );
+    }
+
+    for row in built_materializations.iter_mut() {
+        if !row.expect_pub_id.is_zero() {
+            continue;
+        }
+        let (Some(model), Some(spec)) = (&row.model, &mut row.spec) else {
+            continue;
+        };
+        // Dekaf materializations have no shards.
+        if matches!(model.endpoint, models::MaterializationEndpoint::Dekaf(_)) {
+            continue;
+        }
+        let sources = model
+            .bindings
+            .iter()
+            .filter(|binding| !binding.disable)
+            .map(|binding| binding.source.collection());
+
+        set_recommended_splits(
+            spec.shard_template.as_mut(),
+            recommend_splits(sources, throughputs),
+        );
+    }
+}
+
+/// Recommend a number of initial splits for a task which reads `sources`.
+pub fn recommend_splits<'a>(
+    sources: impl Iterator<Item = &'a models::Collection>,
+    throughputs: &tables::CollectionThroughputs,
+) -> u64 {
+    let bytes_per_hour: u64 = sources
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|source| throughputs.get_key(source))
+        .map(|throughput| throughput.bytes_per_hour.max(0) as u64)
+        .sum();
+
+    let splits = (bytes_per_hour + TARGET_BYTES_PER_SHARD_HOUR - 1) / TARGET_BYTES_PER_SHARD_HOUR;
+
+    // Powers of two divide the key space into ranges which
+    // remain aligned as shards are further split.
+    splits.clamp(1, MAX_RECOMMENDED_SPLITS).next_power_of_two()
+}
+
+fn set_recommended_splits(template: Option<&mut proto_gazette::consumer::ShardSpec>, splits: u64) {
+    let Some(template) = template else {
+        return;
+    };
+    // A single split is the default, and isn't recorded.
+    if splits <= 1 {
+        return;
+    }
+    let set = template.labels.take().unwrap_or_default();
+    template.labels = Some(labels::set_value(
+        set,
+        labels::INITIAL_SPLITS,
+        &splits.to_string(),
+    ));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_recommended_splits() {
+        let mut throughputs = tables::CollectionThroughputs::new();
+        for (collection, bytes_per_hour) in [
+            ("acmeCo/idle", 0),
+            ("acmeCo/small", 1 << 30),
+            ("acmeCo/medium", 40 << 30),
+            ("acmeCo/huge", 1 << 40),
+        ] {
+            throughputs.insert_row(models::Collection::new(collection), bytes_per_hour);
+        }
+        let recommend = |sources: &[&str]| {
+            let sources: Vec<_> = sources
+                .iter()
+                .map(|s| models::Collection::new(*s))
+                .collect();
+            recommend_splits(sources.iter(), &throughputs)
+        };
+
+        assert_eq!(recommend(&[]), 1);
+        assert_eq!(recommend(&["acmeCo/unknown"]), 1);
+        assert_eq!(recommend(&["acmeCo/idle", "acmeCo/small"]), 1);
+        // 40GiB/hour is three splits, rounded up to four.
+        assert_eq!(recommend(&["acmeCo/medium"]), 4);
+        // Repeated sources are counted once.
+        assert_eq!(recommend(&["acmeCo/medium", "acmeCo/medium"]), 4);
+        assert_eq!(recommend(&["acmeCo/medium", "acmeCo/huge"]), 16);
+    }
+
+    #[test]
+    fn test_walk_recommended_splits() {
+        let mut throughputs = tables::CollectionThroughputs::new();
+        throughputs.insert_row(models::Collection::new("acmeCo/medium"), 40i64 << 30);
+
+        let model: models::MaterializationDef = serde_json::from_value(serde_json::json!({
+            "endpoint": {"connector": {"image": "an/image", "config": {}}},
+            "bindings": [{"source": "acmeCo/medium", "resource": {}}],
+        }))
+        .unwrap();
+        let spec = proto_flow::flow::MaterializationSpec {
+            shard_template: Some(Default::default()),
+            ..Default::default()
+        };
+
+        let mut built_materializations = tables::BuiltMaterializations::new();
+        for (name, expect_pub_id) in [
+            ("acmeCo/new", models::Id::zero()),
+            ("acmeCo/existing", models::Id::new([0, 0, 0, 0, 0, 0, 0, 1])),
+        ] {
+            built_materializations.insert_row(
+                models::Materialization::new(name),
+                url::Url::parse("test://scope").unwrap(),
+                models::Id::zero(),
+                models::Id::zero(),
+                expect_pub_id,
+                models::Id::zero(),
+                Some(model.clone()),
+                None,
+                Some(spec.clone()),
+                None,
+                false,
+                None,
+                None,
+                None,
+                None,
+                Vec::new(),
+            );
+        }
+        walk_recommended_splits(
+            &mut tables::BuiltCollections::new(),
+            &mut built_materializations,
+            &throughputs,
+        );
+
+        let splits_of = |name: &str| {
+            let row = built_materializations
+                .get_key(&models::Materialization::new(name))
+                .unwrap();
+            let template = row.spec.as_ref().unwrap().shard_template.as_ref().unwrap();
+            labels::values(
+                template.labels.as_ref().unwrap_or(&Default::default()),
+                labels::INITIAL_SPLITS,
+            )
+            .iter()
+            .map(|label| label.value.clone())
+            .collect::<Vec<_>>()
+        };
+        // Only the new task is given a recommendation.
+        assert_eq!(splits_of("acmeCo/new"), vec!["4".to_string()]);
+        assert!(splits_of("acmeCo/existing").is_empty());
+    }
+}
@@ -1,7 +1,7 @@
 use super::{indexed, Error, Scope};
 use models::{
-    Store, AZURE_CONTAINER_RE, AZURE_STORAGE_ACCOUNT_RE, CATALOG_PREFIX_RE, GCS_BUCKET_RE,
-    S3_BUCKET_RE, TOKEN_RE,
+    EncryptionProvider, Store, StoreEncryption, AWS_KMS_KEY_RE, AZURE_CONTAINER_RE,
+    AZURE_STORAGE_ACCOUNT_RE, CATALOG_PREFIX_RE, GCS_BUCKET_RE, S3_BUCKET_RE, TOKEN_RE,
 };
 use superslice::Ext;
 
@@ -141,6 +141,16 @@ pub fn walk_all_storage_mappings(
                     }
                 }
             }
+
+            if let Some(encryption) = store.encryption() {
+                walk_store_encryption(
+                    scope.push_prop("encryption"),
+                    &m.catalog_prefix,
+                    store,
+                    encryption,
+                    errors,
+                );
+            }
         }
 
         if m.catalog_prefix.is_empty() {
@@ -177,53 +187,260 @@ pub fn walk_all_storage_mappings(
     );
 }
 
-// mapped_stores maps the |entity| identified by |name| to its corresponding
-// StorageMapping stores. Or, if no StorageMapping is matched, it returns an
-// empty slice and records an error.
-pub fn mapped_stores<'a>(
+// walk_store_encryption validates that the encryption of a store is offered
+// by its provider, and that the key is well-formed for the key provider.
+// Only S3 stores map encryption into store arguments which Gazette parses.
+fn walk_store_encryption(
+    scope: Scope,
+    catalog_prefix: &str,
+    store: &Store,
+    encryption: &StoreEncryption,
+    errors: &mut tables::Errors,
+) {
+    let StoreEncryption { provider, key } = encryption;
+
+    let store_name = match (store, provider) {
+        (Store::S3(_), EncryptionProvider::AwsKms) => None,
+        (Store::S3(_), _) => Some("S3"),
+        (Store::Gcs(_), _) => Some("GCS"),
+        (Store::Azure(_), _) => Some("AZURE"),
+        (Store::Custom(_), _) => Some("CUSTOM"),
+    };
+    if let Some(store_name) = store_name {
+        Error::StoreEncryptionUnsupported {
+            prefix: catalog_prefix.to_string(),
+            store: store_name,
+            provider: provider.as_str(),
+        }
+        .push(scope.push_prop("provider"), errors);
+        return;
+    }
+
+    let Some(captures) = AWS_KMS_KEY_RE.captures(key) else {
+        Error::InvalidStoreEncryptionKey {
+            prefix: catalog_prefix.to_string(),
+            key: key.clone(),
+            provider: provider.as_str(),
+            expect: "arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab",
+        }
+        .push(scope.push_prop("key"), errors);
+        return;
+    };
+
+    // SSE-KMS requires that the key and bucket are in the same region.
+    if let (Store::S3(cfg), Some(key_region)) = (store, captures.get(2)) {
+        if let Some(bucket_region) = cfg.region.as_ref() {
+            if bucket_region != key_region.as_str() {
+                Error::StoreEncryptionRegionMismatch {
+                    prefix: catalog_prefix.to_string(),
+                    key_region: key_region.as_str().to_string(),
+                    bucket_region: bucket_region.clone(),
+                }
+                .push(scope.push_prop("key"), errors);
+            }
+        }
+    }
+}
+
+// compose_prefixes composes Prefixes from StorageMappings, for resolvers which
+// don't provide Prefixes directly. Mappings of `recovery/{prefix}` supply the
+// recovery stores of `{prefix}`, and composed Prefixes admit any data-plane.
+pub fn compose_prefixes(storage_mappings: &tables::StorageMappings) -> tables::Prefixes {
+    let prefixes: std::collections::BTreeSet<&str> = storage_mappings
+        .iter()
+        .map(|m| {
+            m.catalog_prefix
+                .strip_prefix("recovery/")
+                .unwrap_or(m.catalog_prefix.as_str())
+        })
+        .collect();
+
+    let mut out = tables::Prefixes::new();
+
+    for prefix in prefixes {
+        let partition = lookup_mapping(storage_mappings, prefix);
+        let recovery = lookup_mapping(storage_mappings, &format!("recovery/{prefix}"));
+
+        // Prefer the control ID of the mapping which introduced this prefix.
+        let control_id = [partition, recovery]
+            .into_iter()
+            .flatten()
+            .find(|m| {
+                m.catalog_prefix.as_str() == prefix
+                    || m.catalog_prefix.strip_prefix("recovery/") == Some(prefix)
+            })
+            .map(|m| m.control_id)
+            .unwrap_or(models::Id::zero());
+
+        out.insert_row(
+            models::Prefix::new(prefix),
+            control_id,
+            partition.map(|m| m.stores.clone()).unwrap_or_default(),
+            recovery.map(|m| m.stores.clone()).unwrap_or_default(),
+            Vec::<String>::new(),
+        );
+    }
+    out
+}
+
+// partition_stores maps the collection |name| to the partition stores
+// of its Prefix, recording an error if there are none.
+pub fn partition_stores<'a>(
     scope: Scope<'a>,
-    entity: &'static str,
     name: &str,
-    storage_mappings: &'a [tables::StorageMapping],
+    prefixes: &'a [tables::Prefix],
     errors: &mut tables::Errors,
 ) -> &'a [models::Store] {
-    match lookup_mapping(storage_mappings, name) {
-        Some(m) => &m.stores,
-        None if storage_mappings.is_empty() => {
-            Error::NoStorageMapping {
-                this_thing: name.to_string(),
-                this_entity: entity,
-            }
-            .push(scope, errors);
+    match lookup_prefix(prefixes, name) {
+        Some(p) if !p.partition_stores.is_empty() => &p.partition_stores,
+        _ => {
+            no_stores(scope, "collection", name, prefixes, errors);
+            &[]
+        }
+    }
+}
 
+// recovery_stores maps the task |entity| identified by |name| to the recovery
+// stores of its Prefix, recording an error if there are none.
+pub fn recovery_stores<'a>(
+    scope: Scope<'a>,
+    entity: &'static str,
+    name: &str,
+    prefixes: &'a [tables::Prefix],
+    errors: &mut tables::Errors,
+) -> &'a [models::Store] {
+    match lookup_prefix(prefixes, name) {
+        Some(p) if !p.recovery_stores.is_empty() => &p.recovery_stores,
+        _ => {
+            no_stores(scope, entity, &format!("recovery/{name}"), prefixes, errors);
             &[]
         }
-        None => {
-            let (_, suggest_name, suggest_scope) = storage_mappings
+    }
+}
+
+// no_stores records an error that |this_thing| could not be mapped to stores,
+// suggesting the closest mapped prefix if there is one.
+fn no_stores(
+    scope: Scope,
+    entity: &'static str,
+    this_thing: &str,
+    prefixes: &[tables::Prefix],
+    errors: &mut tables::Errors,
+) {
+    // Suggest from the storage mappings which Prefixes are composed of.
+    let suggest = prefixes
+        .iter()
+        .flat_map(|p| {
+            let partition = (!p.partition_stores.is_empty()).then(|| p.prefix.to_string());
+            let recovery =
+                (!p.recovery_stores.is_empty()).then(|| format!("recovery/{}", p.prefix));
+            partition.into_iter().chain(recovery)
+        })
+        .map(|name| {
+            let scope = tables::synthetic_scope("storageMapping", &name);
+            (strsim::osa_distance(this_thing, &name), name, scope)
+        })
+        .min();
+
+    match suggest {
+        None => Error::NoStorageMapping {
+            this_thing: this_thing.to_string(),
+            this_entity: entity,
+        }
+        .push(scope, errors),
+        Some((_, suggest_name, suggest_scope)) => Error::NoStorageMappingSuggest {
+            this_thing: this_thing.to_string(),
+            this_entity: entity,
+            suggest_name,
+            suggest_scope,
+        }
+        .push(scope, errors),
+    }
+}
+
+// walk_admissible_data_planes verifies that captures, collections, and
+// materializations which are being created are assigned to a data-plane
+// which is admissible for their Prefix.
+pub fn walk_admissible_data_planes(
+    built_captures: &tables::BuiltCaptures,
+    built_collections: &tables::BuiltCollections,
+    built_materializations: &tables::BuiltMaterializations,
+    prefixes: &tables::Prefixes,
+    data_planes: &tables::DataPlanes,
+    errors: &mut tables::Errors,
+) {
+    // Only newly-created specifications are assigned a data-plane,
+    // so only they may be checked against current admissions.
+    let it = built_captures
+        .iter()
+        .filter(|r| r.spec.is_some() && r.expect_pub_id.is_zero())
+        .map(|r| ("capture", r.capture.as_str(), &r.scope, r.data_plane_id))
+        .chain(
+            built_collections
                 .iter()
-                .map(|m| {
+                .filter(|r| r.spec.is_some() && r.expect_pub_id.is_zero())
+                .map(|r| {
                     (
-                        strsim::osa_distance(&name, &m.catalog_prefix),
-                        &m.catalog_prefix,
-                        m.scope(),
+                        "collection",
+                        r.collection.as_str(),
+                        &r.scope,
+                        r.data_plane_id,
                     )
-                })
-                .min()
-                .unwrap();
-
-            Error::NoStorageMappingSuggest {
-                this_thing: name.to_string(),
-                this_entity: entity,
-                suggest_name: suggest_name.to_string(),
-                suggest_scope: suggest_scope,
-            }
-            .push(scope, errors);
+                }),
+        )
+        .chain(
+            built_materializations
+                .iter()
+                .filter(|r| r.spec.is_some() && r.expect_pub_id.is_zero())
+                .map(|r| {
+                    (
+                        "materialization",
+                        r.materialization.as_str(),
+                        &r.scope,
+                        r.data_plane_id,
+                    )
+                }),
+        );
 
-            &[]
+    for (entity, name, scope, data_plane_id) in it {
+        let Some(prefix) = lookup_prefix(prefixes, name) else {
+            continue; // Reported when stores are mapped.
+        };
+        if prefix.data_planes.is_empty() {
+            continue;
+        }
+        // A missing data-plane is reported when the specification is walked.
+        let Some(data_plane) = data_planes.get_key(&data_plane_id) else {
+            continue;
+        };
+        if !prefix.data_planes.contains(&data_plane.data_plane_name) {
+            Error::DataPlaneNotAdmissible {
+                entity,
+                name: name.to_string(),
+                data_plane: data_plane.data_plane_name.clone(),
+                prefix: prefix.prefix.to_string(),
+                data_planes: prefix.data_planes.clone(),
+            }
+            .push(Scope::new(scope), errors);
         }
     }
 }
 
+// lookup_prefix returns the Prefix which is the longest prefix of |name|,
+// or None if no such Prefix exists.
+fn lookup_prefix<'a>(prefixes: &'a [tables::Prefix], name: &str) -> Option<&'a tables::Prefix> {
+    // Walk each parent prefix of `name`, from longest to shortest.
+    name.rmatch_indices('/')
+        .map(|(index, _)| &name[..index + 1])
+        .chain(std::iter::once(""))
+        .find_map(|prefix| {
+            prefixes
+                .binary_search_by(|p| p.prefix.as_str().cmp(prefix))
+                .ok()
+                .map(|index| &prefixes[index])
+        })
+}
+
 // lookup_mapping returns a StorageMapping which has a prefix of |name|,
 // or None if no such StorageMapping exists.
 fn lookup_mapping<'a>(
@@ -243,7 +460,7 @@ fn lookup_mapping<'a>(
 
 #[cfg(test)]
 mod test {
-    use super::lookup_mapping;
+    use super::{compose_prefixes, lookup_mapping, lookup_prefix, walk_all_storage_mappings};
     use models::Prefix;
 
     #[test]
@@ -263,4 +480,143 @@ mod test {
         assert!(lookup_mapping(&mappings, "bar/two/3").is_some());
         assert!(lookup_mapping(&mappings, "bar/uwo/4").is_none());
     }
+
+    #[test]
+    fn test_composed_prefixes() {
+        let mut mappings = tables::StorageMappings::new();
+        let store = |bucket: &str| {
+            vec![models::Store::Gcs(models::GcsBucketAndPrefix {
+                bucket: bucket.to_string(),
+                prefix: None,
+                encryption: None,
+            })]
+        };
+
+        mappings.insert_row(Prefix::new("foo/"), models::Id::new([1; 8]), store("foo"));
+        mappings.insert_row(
+            Prefix::new("recovery/foo/"),
+            models::Id::new([2; 8]),
+            store("foo-recovery"),
+        );
+        mappings.insert_row(
+            Prefix::new("recovery/foo/bar/"),
+            models::Id::new([3; 8]),
+            store("bar-recovery"),
+        );
+        mappings.insert_row(Prefix::new("baz/"), models::Id::new([4; 8]), store("baz"));
+
+        let prefixes = compose_prefixes(&mappings);
+
+        let summary = |name: &str| {
+            lookup_prefix(&prefixes, name).map(|p| {
+                (
+                    p.prefix.to_string(),
+                    p.control_id,
+                    p.partition_stores.len(),
+                    p.recovery_stores.first().map(|s| match s {
+                        models::Store::Gcs(cfg) => cfg.bucket.clone(),
+                        _ => unreachable!(),
+                    }),
+                )
+            })
+        };
+
+        assert_eq!(
+            summary("foo/one"),
+            Some((
+                "foo/".to_string(),
+                models::Id::new([1; 8]),
+                1,
+                Some("foo-recovery".to_string())
+            ))
+        );
+        assert_eq!(
+            summary("foo/bar/one"),
+            Some((
+                "foo/bar/".to_string(),
+                models::Id::new([3; 8]),
+                1,
+                Some("bar-recovery".to_string())
+            ))
+        );
+        // Sorting after a nested prefix doesn't prevent matching a parent.
+        assert_eq!(
+            summary("foo/zzz"),
+            Some((
+                "foo/".to_string(),
+                models::Id::new([1; 8]),
+                1,
+                Some("foo-recovery".to_string())
+            ))
+        );
+        assert_eq!(
+            summary("baz/one"),
+            Some(("baz/".to_string(), models::Id::new([4; 8]), 1, None))
+        );
+        assert_eq!(summary("bazz/one"), None);
+    }
+
+    #[test]
+    fn test_store_encryption() {
+        let mut mappings = tables::StorageMappings::new();
+        let mut insert = |prefix: &str, store: serde_json::Value| {
+            mappings.insert_row(
+                Prefix::new(prefix),
+                models::Id::zero(),
+                vec![serde_json::from_value(store).unwrap()],
+            )
+        };
+        let aws_key = "arn:aws:kms:us-east-1:123456789012:key/abcd-1234";
+        let gcp_key = "projects/a-project/locations/us/keyRings/ring/cryptoKeys/key";
+
+        insert(
+            "aws-bad-key/",
+            serde_json::json!({"provider": "S3", "bucket": "a-bucket",
+                "encryption": {"provider": "AWS_KMS", "key": gcp_key}}),
+        );
+        insert(
+            "aws-ok/",
+            serde_json::json!({"provider": "S3", "bucket": "a-bucket", "region": "us-east-1",
+                "encryption": {"provider": "AWS_KMS", "key": aws_key}}),
+        );
+        insert(
+            "aws-region/",
+            serde_json::json!({"provider": "S3", "bucket": "a-bucket", "region": "us-west-2",
+                "encryption": {"provider": "AWS_KMS", "key": aws_key}}),
+        );
+        insert(
+            "azure/",
+            serde_json::json!({"provider": "AZURE", "account_tenant_id": "tenant",
+                "storage_account_name": "account", "container_name": "container",
+                "encryption": {"provider": "GCP_KMS", "key": gcp_key}}),
+        );
+
+        insert(
+            "gcs-mismatch/",
+            serde_json::json!({"provider": "GCS", "bucket": "a-bucket",
+                "encryption": {"provider": "AWS_KMS", "key": aws_key}}),
+        );
+        insert(
+            "gcs-unsupported/",
+            serde_json::json!({"provider": "GCS", "bucket": "a-bucket",
+                "encryption": {"provider": "GCP_KMS", "key": gcp_key}}),
+        );
+        let mut errors = tables::Errors::new();
+        walk_all_storage_mappings(&mappings, &mut errors);
+
+        let errors: Vec<_> = errors
+            .iter()
+            .map(|err| format!("{} {}", err.scope, err.error))
+            .collect();
+
+        insta::assert_debug_snapshot!(errors, @r###"
+        [
+            "flow://storageMapping/aws-bad-key/#/0/encryption/key storage mapping 'aws-bad-key/' encryption key \"projects/a-project/locations/us/keyRings/ring/cryptoKeys/key\" is not a valid AWS_KMS key (expected a key like \"arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab\")",
+            "flow://storageMapping/aws-region/#/0/encryption/key storage mapping 'aws-region/' encryption key is in region us-east-1, but its S3 bucket is in region us-west-2; KMS keys must be in the same region as the bucket",
+            "flow://storageMapping/azure/#/0/encryption/provider storage mapping 'azure/' AZURE store doesn't support encryption provider GCP_KMS",
+            "flow://storageMapping/gcs-mismatch/#/0/encryption/provider storage mapping 'gcs-mismatch/' GCS store doesn't support encryption provider AWS_KMS",
+            "flow://storageMapping/gcs-unsupported/#/0/encryption/provider storage mapping 'gcs-unsupported/' GCS store doesn't support encryption provider GCP_KMS",
+        ]
+        "###);
+    }
 }
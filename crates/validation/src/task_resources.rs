@@ -0,0 +1,138 @@
+use super::{Error, Scope};
+
+/// Smallest CPU limit, in millicores, of a task's connector container.
+pub const MIN_CPU_MILLIS: u64 = 100;
+/// Smallest memory limit, in bytes, of a task's connector container.
+pub const MIN_MEMORY_BYTES: u64 = 128 << 20;
+
+/// Walk the resource limits of built captures, derivations, and
+/// materializations, verifying that each is well-formed and falls within
+/// the range permitted by the specification's assigned data-plane.
+pub fn walk_all_task_resources(
+    built_captures: &tables::BuiltCaptures,
+    built_collections: &tables::BuiltCollections,
+    built_materializations: &tables::BuiltMaterializations,
+    data_planes: &tables::DataPlanes,
+    errors: &mut tables::Errors,
+) {
+    for row in built_captures.iter() {
+        let (Some(_), Some(model)) = (&row.spec, &row.model) else {
+            continue;
+        };
+        walk_task_resources(
+            Scope::new(&row.scope).push_prop("shards"),
+            "capture",
+            &row.capture,
+            &model.shards,
+            row.data_plane_id,
+            data_planes,
+            errors,
+        );
+    }
+    for row in built_collections.iter() {
+        let (Some(_), Some(model)) = (&row.spec, &row.model) else {
+            continue;
+        };
+        if let Some(derive) = &model.derive {
+            walk_task_resources(
+                Scope::new(&row.scope)
+                    .push_prop("derive")
+                    .push_prop("shards"),
+                "derivation",
+                &row.collection,
+                &derive.shards,
+                row.data_plane_id,
+                data_planes,
+                errors,
+            );
+        }
+    }
+    for row in built_materializations.iter() {
+        let (Some(_), Some(model)) = (&row.spec, &row.model) else {
+            continue;
+        };
+        walk_task_resources(
+            Scope::new(&row.scope).push_prop("shards"),
+            "materialization",
+            &row.materialization,
+            &model.shards,
+            row.data_plane_id,
+            data_planes,
+            errors,
+        );
+    }
+}
+
+fn walk_task_resources(
+    scope: Scope,
+    entity: &'static str,
+    name: &str,
+    shards: &models::ShardTemplate,
+    data_plane_id: models::Id,
+    data_planes: &tables::DataPlanes,
+    errors: &mut tables::Errors,
+) {
+    let Some(resources) = &shards.resources else {
+        return;
+    };
+    let scope = scope.push_prop("resources");
+
+    // A missing data-plane is reported when the specification is walked.
+    // Data-planes without a maximum permit any limit above the minimum.
+    let data_plane = data_planes.get_key(&data_plane_id);
+
+    for (resource, parsed, lower, upper) in [
+        (
+            "cpu",
+            resources.cpu_millis(),
+            MIN_CPU_MILLIS,
+            data_plane.and_then(|dp| dp.max_task_cpu_millis),
+        ),
+        (
+            "memory",
+            resources.memory_bytes(),
+            MIN_MEMORY_BYTES,
+            data_plane.and_then(|dp| dp.max_task_memory_bytes),
+        ),
+    ] {
+        let scope = scope.push_prop(resource);
+
+        let value = match parsed {
+            Ok(Some(value)) => value,
+            Ok(None) => continue,
+            Err(detail) => {
+                Error::InvalidTaskResource {
+                    entity,
+                    name: name.to_string(),
+                    detail,
+                }
+                .push(scope, errors);
+                continue;
+            }
+        };
+        if value < lower {
+            Error::TaskResourceTooLow {
+                entity,
+                name: name.to_string(),
+                resource,
+                value,
+                lower,
+            }
+            .push(scope, errors);
+        } else if let (Some(data_plane), Some(upper)) = (data_plane, upper) {
+            let upper = upper.max(0) as u64;
+
+            if value > upper {
+                Error::TaskResourceTooHigh {
+                    entity,
+                    name: name.to_string(),
+                    resource,
+                    value,
+                    upper,
+                    data_plane: data_plane.data_plane_name.clone(),
+                }
+                .push(scope, errors);
+            }
+        }
+    }
+}
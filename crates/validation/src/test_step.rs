@@ -1,5 +1,8 @@
-use super::{collection, errors::Error, indexed, reference, schema, walk_transition, Scope};
+use super::{
+    annotations, collection, errors::Error, indexed, reference, schema, walk_transition, Scope,
+};
 use flow::test_spec::step::Type as StepType;
+use itertools::{EitherOrBoth, Itertools};
 use proto_flow::flow;
 use tables::EitherOrBoth as EOB;
 
@@ -57,9 +60,18 @@ fn walk_test(
     };
     let scope = Scope::new(scope);
 
-    let models::TestDef { steps, .. } = model;
+    let models::TestDef {
+        steps, annotations, ..
+    } = model;
 
     indexed::walk_name(scope, "test", test, models::Test::regex(), errors);
+    annotations::walk_annotations(
+        scope.push_prop("annotations"),
+        "test",
+        test,
+        annotations,
+        errors,
+    );
 
     // Map steps into built steps.
     let built_steps: Vec<_> = steps
@@ -143,6 +155,11 @@ pub fn walk_test_step<'a>(
                 partitions.as_ref(),
             )
         }
+
+        models::TestStep::Expect(expect) => {
+            walk_test_step_expect(scope.push_prop("expect"), built_collections, expect, errors);
+            return None; // Expectations are evaluated only at build time.
+        }
     };
     let scope = match step_type {
         StepType::Ingest => scope.push_prop("ingest"),
@@ -210,3 +227,129 @@ pub fn walk_test_step<'a>(
         description: description.clone(),
     })
 }
+
+fn walk_test_step_expect<'a>(
+    scope: Scope<'a>,
+    built_collections: &'a tables::BuiltCollections,
+    expect: &'a models::TestStepExpect,
+    errors: &mut tables::Errors,
+) {
+    let models::TestStepExpect {
+        description: _,
+        collection,
+        documents,
+        invalid,
+        reduced,
+    } = expect;
+
+    let Some((spec, _)) = reference::walk_reference(
+        scope.push_prop("collection"),
+        "this test step",
+        collection,
+        built_collections,
+        errors,
+    ) else {
+        return;
+    };
+    let documents = serde_json::from_str::<Vec<serde_json::Value>>(documents.get())
+        .expect("a documents fixture is verified to be an array of objects during load");
+
+    let Ok(write_schema) = schema::Schema::new(&spec.write_schema_json) else {
+        return; // Already an error of the collection.
+    };
+    let mut validators = vec![write_schema.validator];
+
+    if !spec.read_schema_json.is_empty() {
+        let Ok(read_schema) = schema::Schema::new(&spec.read_schema_json) else {
+            return;
+        };
+        validators.push(read_schema.validator);
+    }
+
+    for (doc_index, doc) in documents.iter().enumerate() {
+        let scope = scope.push_prop("documents").push_item(doc_index);
+
+        let failed = validators
+            .iter_mut()
+            .find_map(|validator| validator.validate(None, doc).unwrap().ok().err());
+
+        match (failed, invalid) {
+            (Some(err), false) => Error::IngestDocInvalid(err).push(scope, errors),
+            (None, true) => Error::TestExpectDocValid.push(scope, errors),
+            _ => (),
+        }
+    }
+
+    let Some(reduced) = reduced else {
+        return;
+    };
+    let expected = serde_json::from_str::<Vec<serde_json::Value>>(reduced.get())
+        .expect("a documents fixture is verified to be an array of objects during load");
+    let scope = scope.push_prop("reduced");
+
+    let actual = match reduce_documents(&spec, documents) {
+        Ok(actual) => actual,
+        Err(err) => {
+            Error::TestExpectReduce { detail: err }.push(scope, errors);
+            return;
+        }
+    };
+
+    let mut mismatches = Vec::new();
+    for (doc_index, eob) in actual.iter().zip_longest(expected.iter()).enumerate() {
+        let (actual, expected) = match eob {
+            EitherOrBoth::Both(actual, expected) => (Some(actual), Some(expected)),
+            EitherOrBoth::Left(actual) => (Some(actual), None),
+            EitherOrBoth::Right(expected) => (None, Some(expected)),
+        };
+        let diffs = doc::diff(actual, expected);
+
+        if !diffs.is_empty() {
+            mismatches.push(serde_json::json!({
+                "document": doc_index,
+                "diffs": diffs,
+            }));
+        }
+    }
+
+    if !mismatches.is_empty() {
+        Error::TestExpectReducedMismatch {
+            actual: actual.len(),
+            expected: expected.len(),
+            diffs: serde_json::to_string_pretty(&mismatches).unwrap(),
+        }
+        .push(scope, errors);
+    }
+}
+
+// Fully reduce `documents` by the key of collection `spec`,
+// returning reduced documents in key order.
+fn reduce_documents(
+    spec: &flow::CollectionSpec,
+    documents: Vec<serde_json::Value>,
+) -> anyhow::Result<Vec<serde_json::Value>> {
+    let key = extractors::for_key(&spec.key, &spec.projections, &doc::SerPolicy::noop())?;
+    let validator = schema::Schema::new(&spec.write_schema_json)?.validator;
+
+    let memtable = doc::combine::MemTable::new(doc::combine::Spec::with_one_binding(
+        true, // Full reduction.
+        key,
+        spec.name.as_str(),
+        None,
+        validator,
+    ));
+    for doc in documents {
+        let doc = doc::HeapNode::from_node(&doc, memtable.alloc());
+        memtable.add(0, doc, false)?;
+    }
+
+    let mut drainer = memtable.try_into_drainer()?;
+    let mut out = Vec::new();
+
+    while let Some(drained) = drainer.drain_next()? {
+        out.push(serde_json::to_value(
+            doc::SerPolicy::noop().on_owned(&drained.root),
+        )?);
+    }
+    Ok(out)
+}
@@ -0,0 +1,100 @@
+use super::{Error, Scope};
+
+/// Walk the declared zones of built captures, collections, and
+/// materializations, verifying that each declared zone is a zone
+/// of the specification's assigned data-plane.
+pub fn walk_all_zones(
+    built_captures: &tables::BuiltCaptures,
+    built_collections: &tables::BuiltCollections,
+    built_materializations: &tables::BuiltMaterializations,
+    data_planes: &tables::DataPlanes,
+    errors: &mut tables::Errors,
+) {
+    for row in built_captures.iter() {
+        let (Some(_), Some(model)) = (&row.spec, &row.model) else {
+            continue;
+        };
+        walk_zones(
+            Scope::new(&row.scope).push_prop("shards"),
+            "capture",
+            &row.capture,
+            &model.shards.zones,
+            row.data_plane_id,
+            data_planes,
+            errors,
+        );
+    }
+    for row in built_collections.iter() {
+        let (Some(_), Some(model)) = (&row.spec, &row.model) else {
+            continue;
+        };
+        walk_zones(
+            Scope::new(&row.scope).push_prop("journals"),
+            "collection",
+            &row.collection,
+            &model.journals.zones,
+            row.data_plane_id,
+            data_planes,
+            errors,
+        );
+        if let Some(derive) = &model.derive {
+            walk_zones(
+                Scope::new(&row.scope)
+                    .push_prop("derive")
+                    .push_prop("shards"),
+                "derivation",
+                &row.collection,
+                &derive.shards.zones,
+                row.data_plane_id,
+                data_planes,
+                errors,
+            );
+        }
+    }
+    for row in built_materializations.iter() {
+        let (Some(_), Some(model)) = (&row.spec, &row.model) else {
+            continue;
+        };
+        walk_zones(
+            Scope::new(&row.scope).push_prop("shards"),
+            "materialization",
+            &row.materialization,
+            &model.shards.zones,
+            row.data_plane_id,
+            data_planes,
+            errors,
+        );
+    }
+}
+
+fn walk_zones(
+    scope: Scope,
+    entity: &'static str,
+    name: &str,
+    zones: &[String],
+    data_plane_id: models::Id,
+    data_planes: &tables::DataPlanes,
+    errors: &mut tables::Errors,
+) {
+    if zones.is_empty() {
+        return;
+    }
+    // A missing data-plane is reported when the specification is walked.
+    let Some(data_plane) = data_planes.get_key(&data_plane_id) else {
+        return;
+    };
+    let scope = scope.push_prop("zones");
+
+    for (index, zone) in zones.iter().enumerate() {
+        if !data_plane.zones.contains(zone) {
+            Error::ZoneUnavailable {
+                entity,
+                name: name.to_string(),
+                zone: zone.clone(),
+                data_plane: data_plane.data_plane_name.clone(),
+                zones: data_plane.zones.clone(),
+            }
+            .push(scope.push_item(index), errors);
+        }
+    }
+}
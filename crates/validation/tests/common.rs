@@ -99,6 +99,10 @@ impl Outcome {
 }
 
 pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
+    run_with_warnings(fixture_yaml, patch_yaml).0
+}
+
+fn run_with_warnings(fixture_yaml: &str, patch_yaml: &str) -> (Outcome, tables::Errors) {
     let mut fixture: serde_json::Value = serde_yaml::from_str(fixture_yaml).unwrap();
     let patch: serde_json::Value = serde_yaml::from_str(patch_yaml).unwrap();
 
@@ -123,7 +127,7 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
     for (control_id, mock) in &mock_calls.data_planes {
         live.data_planes.insert_row(
             control_id,
-            "ops/dp/public/test".to_string(),
+            mock.name.clone(),
             "the-data-plane.dp.estuary-data.com".to_string(),
             mock.default,
             vec!["hmac-key".to_string()],
@@ -131,6 +135,12 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
             models::Collection::new("ops/stats"),
             "broker:address".to_string(),
             "reactor:address".to_string(),
+            mock.cross_plane_read_peers.clone(),
+            mock.zones.clone(),
+            mock.max_task_cpu_millis,
+            mock.max_task_memory_bytes,
+            mock.min_max_txn_duration_millis,
+            mock.max_txn_duration_millis,
         );
     }
 
@@ -140,6 +150,7 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
             auto_discover: None,
             bindings: Vec::new(),
             endpoint: models::CaptureEndpoint::Connector(live_connector_fixture.clone()),
+            annotations: Default::default(),
             expect_pub_id: None,
             interval: std::time::Duration::from_secs(32),
             shards: models::ShardTemplate::default(),
@@ -188,11 +199,14 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
         let model = models::CollectionDef {
             derive: None,
             journals: Default::default(),
+            frozen: false,
             key: mock.key.clone(),
+            key_migration: None,
             projections: Default::default(),
             read_schema: None,
             schema: Some(schema.clone()),
             write_schema: None,
+            annotations: Default::default(),
             expect_pub_id: None,
             delete: false,
         };
@@ -250,8 +264,10 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
         let model = models::MaterializationDef {
             bindings: mock.bindings.clone(),
             endpoint: models::MaterializationEndpoint::Connector(live_connector_fixture.clone()),
+            annotations: Default::default(),
             expect_pub_id: None,
             shards: models::ShardTemplate::default(),
+            allow_cross_plane_read: false,
             source_capture: None,
             delete: false,
             on_incompatible_schema_change: Default::default(),
@@ -289,6 +305,7 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
         let model = models::TestDef {
             description: "live test".to_string(),
             steps: Vec::new(),
+            annotations: Default::default(),
             expect_pub_id: None,
             delete: false,
         };
@@ -311,12 +328,39 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
         live.storage_mappings
             .insert_row(prefix, models::Id::zero(), &storage.stores);
     }
+    // Load into LiveCatalog::prefixes if fixtures constrain admissible data-planes.
+    // Otherwise, Prefixes are composed from storage mappings during validation.
+    if mock_calls
+        .storage_mappings
+        .values()
+        .any(|storage| !storage.data_planes.is_empty())
+    {
+        for (prefix, storage) in &mock_calls.storage_mappings {
+            if prefix.starts_with("recovery/") {
+                continue;
+            }
+            let recovery_stores = mock_calls
+                .storage_mappings
+                .get(&models::Prefix::new(format!("recovery/{prefix}")))
+                .map(|recovery| recovery.stores.clone())
+                .unwrap_or_default();
+
+            live.prefixes.insert_row(
+                prefix,
+                models::Id::zero(),
+                &storage.stores,
+                recovery_stores,
+                &storage.data_planes,
+            );
+        }
+    }
     // Allow fixtures to omit a storage mapping by providing a default.
     if mock_calls.storage_mappings.is_empty() {
         let store = models::Store::S3(models::S3StorageConfig {
             bucket: "a-bucket".to_string(),
             prefix: None,
             region: None,
+            encryption: None,
         });
         live.storage_mappings
             .insert_row(models::Prefix::new(""), models::Id::zero(), vec![store]);
@@ -330,6 +374,7 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
         &draft,
         &live,
         false, // Don't fail-fast.
+        &validation::Limits::default(),
     ));
 
     let tables::DraftCatalog {
@@ -352,10 +397,12 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
         built_collections,
         built_materializations,
         built_tests,
+        connector_specs: _,
         errors,
+        warnings,
     } = validations;
 
-    Outcome {
+    let outcome = Outcome {
         built_captures,
         built_collections,
         built_materializations,
@@ -370,7 +417,8 @@ pub fn run(fixture_yaml: &str, patch_yaml: &str) -> Outcome {
         resources,
         storage_mappings,
         tests,
-    }
+    };
+    (outcome, warnings)
 }
 
 pub fn run_errors(fixture_yaml: &str, patch_yaml: &str) -> tables::Errors {
@@ -378,6 +426,12 @@ pub fn run_errors(fixture_yaml: &str, patch_yaml: &str) -> tables::Errors {
     outcome.errors
 }
 
+#[allow(dead_code)]
+pub fn run_warnings(fixture_yaml: &str, patch_yaml: &str) -> tables::Errors {
+    let (_outcome, warnings) = run_with_warnings(fixture_yaml, patch_yaml);
+    warnings
+}
+
 #[derive(serde::Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 struct MockLiveCapture {
@@ -429,6 +483,26 @@ struct MockLiveTest {
 struct MockDataPlane {
     #[serde(default)]
     default: bool,
+    #[serde(default = "MockDataPlane::default_name")]
+    name: String,
+    #[serde(default)]
+    cross_plane_read_peers: Vec<String>,
+    #[serde(default)]
+    zones: Vec<String>,
+    #[serde(default)]
+    max_task_cpu_millis: Option<i64>,
+    #[serde(default)]
+    max_task_memory_bytes: Option<i64>,
+    #[serde(default)]
+    min_max_txn_duration_millis: Option<i64>,
+    #[serde(default)]
+    max_txn_duration_millis: Option<i64>,
+}
+
+impl MockDataPlane {
+    fn default_name() -> String {
+        "ops/dp/public/test".to_string()
+    }
 }
 
 #[derive(Default, serde::Deserialize)]
@@ -499,6 +573,8 @@ struct MockMaterializationValidateCall {
     #[serde(default)]
     delta_updates: bool,
     #[serde(default)]
+    requires_bounded_objects: bool,
+    #[serde(default)]
     network_ports: Vec<flow::NetworkPort>,
     #[serde(default)]
     error: Option<String>,
@@ -738,7 +814,10 @@ impl validation::Connectors for MockDriverCalls {
                 .collect();
 
             Ok(materialize::Response {
-                validated: Some(materialize::response::Validated { bindings }),
+                validated: Some(materialize::response::Validated {
+                    bindings,
+                    requires_bounded_objects: call.requires_bounded_objects,
+                }),
                 ..Default::default()
             }
             .with_internal(|internal| {
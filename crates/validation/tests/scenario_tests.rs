@@ -517,6 +517,94 @@ fn test_materialization_constraints_on_excluded_fields() {
     insta::assert_debug_snapshot!(outcome);
 }
 
+#[test]
+fn test_materialization_unbounded_objects() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/db-views:
+  collections:
+    testing/with-objects:
+      schema:
+        type: object
+        properties:
+          id: { type: integer }
+          unbounded: { type: object }
+          patterned:
+            type: object
+            patternProperties:
+              "^a": { type: string }
+            additionalProperties: false
+          bounded:
+            type: object
+            properties:
+              a: { type: string }
+            additionalProperties: false
+        required: [id]
+      key: [/id]
+
+  materializations:
+    testing/db-views:
+      bindings:
+        - source: testing/with-objects
+          resource: { table: the_table }
+
+driver:
+  materializations:
+    testing/db-views:
+      requiresBoundedObjects: true
+      bindings:
+        - constraints:
+            flow_document: { type: 2, reason: "location required" }
+            id: { type: 1, reason: "field required" }
+            unbounded: { type: 1, reason: "field required" }
+            patterned: { type: 1, reason: "field required" }
+            bounded: { type: 1, reason: "field required" }
+          resourcePath: [view]
+"#,
+    );
+    // Expect `unbounded` and `patterned` are errors, but `bounded` is not.
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn test_materialization_source_capture_preview() {
+    let outcome = common::run(
+        MODEL_YAML,
+        r#"
+test://example/db-views:
+  materializations:
+    testing/db-views:
+      sourceCapture: testing/s3-source
+"#,
+    );
+    assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+
+    let previews: std::collections::BTreeMap<_, _> = outcome
+        .built_materializations
+        .iter()
+        .map(|built| {
+            (
+                built.materialization.as_str(),
+                &built.source_capture_preview,
+            )
+        })
+        .collect();
+
+    // Expect the capture's unbound collection is previewed as an added binding,
+    // while the materialization without a sourceCapture has no preview.
+    insta::assert_json_snapshot!(previews, @r###"
+    {
+      "testing/db-views": {
+        "addBindings": [
+          "testing/int-string.v2"
+        ]
+      },
+      "testing/webhook/deliveries": null
+    }
+    "###);
+}
+
 /// Tests a scenario where a collection spec contains an incomplete
 /// `flow://write-schema` definition. This has been observed with users using
 /// flowctl, and resulted in a super confusing error message. This now asserts
@@ -743,6 +831,253 @@ test://example/int-string:
     insta::assert_debug_snapshot!(errors);
 }
 
+#[test]
+fn test_frozen_collection_writes() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/int-string:
+  collections:
+    testing/int-string:
+      frozen: true
+
+test://example/int-reverse:
+  collections:
+    testing/int-reverse:
+      frozen: true
+
+# A frozen derivation is allowed if its shards are disabled.
+test://example/int-halve:
+  collections:
+    testing/int-halve:
+      frozen: true
+      derive:
+        shards:
+          disable: true
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn test_frozen_collection_edit() {
+    use proto_gazette::broker::journal_spec::Flag;
+
+    // A frozen collection which isn't written may be edited, and is still read.
+    let outcome = common::run(
+        &MODEL_YAML,
+        r#"
+test://example/int-string:
+  collections:
+    testing/int-string-rw:
+      frozen: true
+      journals:
+        fragments:
+          length: 256
+"#,
+    );
+    assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+
+    let built = outcome
+        .built_collections
+        .get_key(&models::Collection::new("testing/int-string-rw"))
+        .unwrap();
+    let template = built
+        .spec
+        .as_ref()
+        .unwrap()
+        .partition_template
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(template.flags, Flag::ORdonly as u32);
+    assert_eq!(template.fragment.as_ref().unwrap().length, 256 << 20);
+}
+
+#[test]
+fn test_annotations_are_built_as_labels() {
+    let outcome = common::run(
+        &MODEL_YAML,
+        r#"
+test://example/int-string:
+  collections:
+    testing/int-string:
+      annotations:
+        owner: data-platform
+
+test://example/int-string-captures:
+  captures:
+    testing/s3-source:
+      annotations:
+        owner: ingest@acme.co
+        description: Reads objects of the acme bucket.
+        labels:
+          cost-center: cc-1234
+          tier: ""
+"#,
+    );
+    assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+
+    let capture = outcome
+        .built_captures
+        .get_key(&models::Capture::new("testing/s3-source"))
+        .unwrap();
+    let set = capture
+        .spec
+        .as_ref()
+        .unwrap()
+        .shard_template
+        .as_ref()
+        .unwrap();
+    let set = set.labels.as_ref().unwrap();
+
+    assert_eq!(
+        labels::expect_one(set, labels::OWNER).unwrap(),
+        "ingest@acme.co"
+    );
+    assert_eq!(
+        labels::expect_one(set, "estuary.dev/annotation/cost-center").unwrap(),
+        "cc-1234"
+    );
+    assert_eq!(
+        labels::expect_one(set, "estuary.dev/annotation/tier").unwrap(),
+        ""
+    );
+
+    let collection = outcome
+        .built_collections
+        .get_key(&models::Collection::new("testing/int-string"))
+        .unwrap();
+    let template = collection
+        .spec
+        .as_ref()
+        .unwrap()
+        .partition_template
+        .as_ref();
+    let set = template.unwrap().labels.as_ref().unwrap();
+
+    assert_eq!(
+        labels::expect_one(set, labels::OWNER).unwrap(),
+        "data-platform"
+    );
+}
+
+#[test]
+fn test_annotations_invalid() {
+    let too_many_labels: String = (0..17).map(|i| format!("          l{i:02}: v\n")).collect();
+
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        &format!(
+            r#"
+test://example/int-string:
+  collections:
+    testing/int-string:
+      annotations:
+        owner: data platform
+        labels:
+          cost$center: cc-1234
+
+test://example/int-string-captures:
+  captures:
+    testing/s3-source:
+      annotations:
+        owner: ""
+        description: {description}
+        labels:
+          {long_name}: x
+          team: a<b
+
+test://example/db-views:
+  materializations:
+    testing/db-views:
+      annotations:
+        labels:
+{too_many_labels}"#,
+            description = "x".repeat(1025),
+            long_name = "a".repeat(33),
+        ),
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn test_journal_template_out_of_range() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/int-string:
+  collections:
+    testing/int-string:
+      journals:
+        replication: 7
+        maxAppendRate: 1024
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn test_fragment_retention() {
+    let patch = |retention: &str| {
+        format!(
+            r#"
+test://example/int-string:
+  collections:
+    testing/int-string:
+      journals:
+        fragments:
+          retention: {retention}
+"#
+        )
+    };
+    let messages = |errors: &tables::Errors| -> Vec<String> {
+        errors.iter().map(|e| format!("{:#}", e.error)).collect()
+    };
+
+    // Retention of exactly the minimum is allowed.
+    let outcome = common::run(&MODEL_YAML, &patch("1h"));
+    assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+    let outcome = common::run(&MODEL_YAML, &patch("60m"));
+    assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+
+    // Retention just beneath the minimum is an error.
+    assert_eq!(
+        messages(&common::run_errors(&MODEL_YAML, &patch("59m 59s"))),
+        vec!["collection testing/int-string fragment retention of 59m 59s is less than the minimum of 1h"],
+    );
+    assert_eq!(
+        messages(&common::run_errors(&MODEL_YAML, &patch("0s"))),
+        vec![
+            "collection testing/int-string fragment retention of 0s is less than the minimum of 1h"
+        ],
+    );
+
+    // Retentions which aren't durations fail to load.
+    for (invalid, expect) in [
+        ("1 fortnight", "unknown time unit"),
+        ("-1h", "expected number"),
+        ("soon", "expected number"),
+    ] {
+        let outcome = common::run(&MODEL_YAML, &patch(invalid));
+        assert!(
+            messages(&outcome.errors_draft)
+                .iter()
+                .any(|message| message.contains(expect)),
+            "{invalid}: {:?}",
+            outcome.errors_draft,
+        );
+    }
+
+    // Materializations which newly backfill the collection are warned
+    // of its retention, formatted as a human-readable duration.
+    let warnings = messages(&common::run_warnings(&MODEL_YAML, &patch("36h")));
+    for materialization in ["testing/db-views", "testing/webhook/deliveries"] {
+        let expect = format!("materialization {materialization} backfills collection testing/int-string, which retains only 1day 12h of historical data; documents older than its retention have been removed and will not be materialized");
+        assert!(warnings.contains(&expect), "{warnings:?}");
+    }
+}
+
 #[test]
 fn test_shuffle_key_empty() {
     let errors = common::run_errors(
@@ -1039,6 +1374,50 @@ test://example/int-string-tests:
     insta::assert_debug_snapshot!(errors);
 }
 
+#[test]
+fn test_test_step_expect() {
+    let errors = common::run_errors(
+        &MODEL_YAML,
+        r#"
+test://example/int-string-tests:
+  tests:
+    testing/test:
+      # Valid documents, which reduce to the expected documents.
+      - expect:
+          collection: testing/int-string
+          documents:
+            - {int: 2, str: "two", bit: false}
+            - {int: 1, str: "one", bit: true}
+            - {int: 2, str: "deux", bit: true}
+          reduced:
+            - {int: 1, str: "one"}
+            - {int: 2, str: "deux", bit: true}
+      # Documents expected to be invalid, one of which is valid.
+      - expect:
+          collection: testing/int-string
+          invalid: true
+          documents:
+            - {int: "not an int", str: "one", bit: true}
+            - {int: 3, str: "three", bit: false}
+      # A document which is unexpectedly invalid.
+      - expect:
+          collection: testing/int-string
+          documents:
+            - {int: 4, str_whoops: "four", bit: true}
+      # Documents which don't reduce to the expected documents.
+      - expect:
+          collection: testing/int-string
+          documents:
+            - {int: 5, str: "five", bit: true}
+            - {int: 5, str: "cinq", bit: false}
+          reduced:
+            - {int: 5, str: "five"}
+            - {int: 6, str: "six"}
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
 #[test]
 fn test_test_step_verify_key_order() {
     let errors = common::run_errors(
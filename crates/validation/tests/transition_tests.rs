@@ -334,16 +334,505 @@ driver:
     [
         Error {
             scope: test://example/catalog.yaml#/materializations/the~1materialization/bindings/0/fields/exclude/2,
+            catalog_type: "materialization",
+            catalog_name: the/materialization,
             error: exclude projection existing/but/disabled does not exist in collection the/collection,
         },
         Error {
             scope: test://example/catalog.yaml#/materializations/the~1materialization/bindings/0/fields/exclude/3,
+            catalog_type: "materialization",
+            catalog_name: the/materialization,
             error: exclude projection new/not/found does not exist in collection the/collection,
         },
         Error {
             scope: test://example/catalog.yaml#/materializations/the~1materialization/bindings/0/fields/exclude/4,
+            catalog_type: "materialization",
+            catalog_name: the/materialization,
             error: exclude projection other/not/found does not exist in collection the/collection,
         },
     ]
     "###);
 }
+
+#[test]
+fn test_cross_plane_reads() {
+    // Move the/collection into another data-plane, from which
+    // the/derivation and the/materialization then read.
+    let cross_plane_errors = |opt_in: bool, peers: &[&str]| -> Vec<String> {
+        let patch = serde_json::json!({
+            "test://example/catalog.yaml": {
+                "collections": {
+                    "the/derivation": {"derive": {"allowCrossPlaneRead": opt_in}},
+                },
+                "materializations": {
+                    "the/materialization": {"allowCrossPlaneRead": opt_in},
+                },
+            },
+            "driver": {
+                "dataPlanes": {
+                    "12:12:12:12:12:12:12:02": {"name": "ops/dp/public/other"},
+                    "12:12:12:12:12:12:12:03": {"crossPlaneReadPeers": peers},
+                    "12:12:12:12:12:12:12:04": {"crossPlaneReadPeers": peers},
+                },
+            },
+        });
+
+        common::run_errors(MODEL_YAML, &patch.to_string())
+            .iter()
+            .map(|err| format!("{} {}", err.scope, err.error))
+            .filter(|err| err.contains("cross-plane") || err.contains("allowCrossPlaneRead"))
+            .collect()
+    };
+
+    // Tasks which don't opt-in may not read from another data-plane.
+    insta::assert_debug_snapshot!(cross_plane_errors(false, &["ops/dp/public/other"]), @r###"
+    [
+        "test://example/catalog.yaml#/collections/the~1derivation/derive/transforms/0/source the/derivation reads collection the/collection of data-plane ops/dp/public/other from data-plane ops/dp/public/test, but doesn't set `allowCrossPlaneRead: true`",
+        "test://example/catalog.yaml#/materializations/the~1materialization/bindings/0/source the/materialization reads collection the/collection of data-plane ops/dp/public/other from data-plane ops/dp/public/test, but doesn't set `allowCrossPlaneRead: true`",
+    ]
+    "###);
+
+    // Tasks which opt-in still require that their data-plane permits the peer.
+    insta::assert_debug_snapshot!(cross_plane_errors(true, &[]), @r###"
+    [
+        "test://example/catalog.yaml#/collections/the~1derivation/derive/transforms/0/source the/derivation reads collection the/collection of data-plane ops/dp/public/other, but its data-plane ops/dp/public/test doesn't permit cross-plane reads from ops/dp/public/other",
+        "test://example/catalog.yaml#/materializations/the~1materialization/bindings/0/source the/materialization reads collection the/collection of data-plane ops/dp/public/other, but its data-plane ops/dp/public/test doesn't permit cross-plane reads from ops/dp/public/other",
+    ]
+    "###);
+
+    // Reads are permitted when the task opts-in and its data-plane allows the peer.
+    assert!(cross_plane_errors(true, &["ops/dp/public/other"]).is_empty());
+}
+
+#[test]
+fn test_zone_constraints() {
+    let errors = common::run_errors(
+        MODEL_YAML,
+        r#"
+test://example/catalog.yaml:
+  collections:
+    the/collection:
+      journals:
+        zones: [us-east-1a, us-east-1c]
+  captures:
+    the/capture:
+      shards:
+        zones: [us-east-1a]
+driver:
+  dataPlanes:
+    "12:12:12:12:12:12:12:01":
+      zones: [us-east-1a, us-east-1b]
+    "12:12:12:12:12:12:12:02":
+      zones: [us-east-1a, us-east-1b]
+"#,
+    );
+
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|err| format!("{} {}", err.scope, err.error))
+        .collect();
+
+    insta::assert_debug_snapshot!(errors, @r###"
+    [
+        "test://example/catalog.yaml#/collections/the~1collection/journals/zones/1 collection the/collection declares zone \"us-east-1c\", which is not a zone of its data-plane ops/dp/public/test (available zones are [\"us-east-1a\", \"us-east-1b\"])",
+    ]
+    "###);
+}
+
+#[test]
+fn test_task_resource_limits() {
+    let errors = common::run_errors(
+        MODEL_YAML,
+        r#"
+test://example/catalog.yaml:
+  captures:
+    the/capture:
+      shards:
+        resources: { cpu: 50m, memory: lots }
+  materializations:
+    the/materialization:
+      shards:
+        resources: { cpu: "2", memory: 4Gi }
+driver:
+  dataPlanes:
+    "12:12:12:12:12:12:12:01":
+      maxTaskCpuMillis: 4000
+      maxTaskMemoryBytes: 2147483648
+    "12:12:12:12:12:12:12:04":
+      maxTaskCpuMillis: 4000
+      maxTaskMemoryBytes: 2147483648
+"#,
+    );
+
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|err| format!("{} {}", err.scope, err.error))
+        .collect();
+
+    insta::assert_debug_snapshot!(errors, @r###"
+    [
+        "test://example/catalog.yaml#/captures/the~1capture/shards/resources/cpu capture the/capture cpu limit of 50 is less than the minimum of 100",
+        "test://example/catalog.yaml#/captures/the~1capture/shards/resources/memory capture the/capture has an invalid resource limit: \"lots\" is not a valid memory quantity (expected bytes with an optional suffix of K, M, G, Ki, Mi, or Gi)",
+        "test://example/catalog.yaml#/materializations/the~1materialization/shards/resources/memory materialization the/materialization memory limit of 4294967296 is greater than the maximum of 2147483648 permitted by its data-plane ops/dp/public/test",
+    ]
+    "###);
+}
+
+#[test]
+fn test_txn_duration_bounds() {
+    let errors = common::run_errors(
+        MODEL_YAML,
+        r#"
+test://example/catalog.yaml:
+  captures:
+    the/capture:
+      shards:
+        maxTxnDuration: 200ms
+  collections:
+    the/derivation:
+      derive:
+        shards:
+          minTxnDuration: 5s
+          maxTxnDuration: 1s
+  materializations:
+    the/materialization:
+      shards:
+        minTxnDuration: 15m
+        maxTxnDuration: 20m
+driver:
+  dataPlanes:
+    "12:12:12:12:12:12:12:01":
+      minMaxTxnDurationMillis: 500
+    "12:12:12:12:12:12:12:04":
+      maxTxnDurationMillis: 600000
+"#,
+    );
+
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|err| format!("{} {}", err.scope, err.error))
+        .collect();
+
+    // The capture's maximum is below its data-plane's lower bound, the
+    // derivation's minimum exceeds its explicit maximum, and the
+    // materialization's durations exceed its data-plane's upper bound.
+    insta::assert_debug_snapshot!(errors, @r###"
+    [
+        "test://example/catalog.yaml#/captures/the~1capture/shards/maxTxnDuration capture the/capture maxTxnDuration of 200ms is outside of the supported range 500ms to 3600s",
+        "test://example/catalog.yaml#/collections/the~1derivation/derive/shards/minTxnDuration derivation the/derivation minTxnDuration of 5s cannot be greater than its maxTxnDuration of 1s",
+        "test://example/catalog.yaml#/materializations/the~1materialization/shards/minTxnDuration materialization the/materialization minTxnDuration of 900s is outside of the supported range 0ns to 600s",
+        "test://example/catalog.yaml#/materializations/the~1materialization/shards/maxTxnDuration materialization the/materialization maxTxnDuration of 1200s is outside of the supported range 100ms to 600s",
+    ]
+    "###);
+}
+
+#[test]
+fn test_admissible_data_planes() {
+    let errors = common::run_errors(
+        MODEL_YAML,
+        r#"
+test://example/catalog.yaml:
+  collections:
+    the/new-collection:
+      key: [/id]
+      schema:
+        type: object
+        properties:
+          id: { type: string }
+        required: [id]
+    other/new-collection:
+      key: [/id]
+      schema:
+        type: object
+        properties:
+          id: { type: string }
+        required: [id]
+driver:
+  dataPlanes:
+    "1d:1d:1d:1d:1d:1d:1d:1d":
+      default: true
+      name: ops/dp/public/other
+  storageMappings:
+    the/:
+      stores: [{ provider: S3, bucket: a-bucket }]
+      dataPlanes: [ops/dp/public/test]
+    recovery/the/:
+      stores: [{ provider: S3, bucket: a-bucket }]
+    other/:
+      stores: [{ provider: S3, bucket: a-bucket }]
+"#,
+    );
+
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|err| format!("{} {}", err.scope, err.error))
+        .collect();
+
+    // Existing specifications are unaffected, as is a prefix without constraints.
+    insta::assert_debug_snapshot!(errors, @r###"
+    [
+        "test://example/catalog.yaml#/collections/the~1new-collection collection the/new-collection is assigned to data-plane ops/dp/public/other, which is not admissible for prefix the/ (admissible data-planes are [\"ops/dp/public/test\"])",
+    ]
+    "###);
+}
+
+#[test]
+fn test_read_hints() {
+    let errors = common::run_errors(
+        MODEL_YAML,
+        r#"
+test://example/catalog.yaml:
+  collections:
+    the/derivation:
+      derive:
+        transforms:
+          - name: fromCollection
+            source: { name: the/collection }
+            shuffle: { key: [/f_two] }
+            lambda: select 123 as d_one, 'hello' as d_two;
+          - name: throttled
+            source: { name: the/collection }
+            shuffle: any
+            priority: 5
+            maxReadRate: 4096
+            lambda: select 456 as d_one, 'world' as d_two;
+  materializations:
+    the/materialization:
+      bindings:
+        - source: the/collection
+          resource: { table: bar }
+          maxReadRate: 100
+"#,
+    );
+
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|err| format!("{} {}", err.scope, err.error))
+        .collect();
+
+    insta::assert_debug_snapshot!(errors, @r###"
+    [
+        "test://example/catalog.yaml#/collections/the~1derivation/derive/transforms/1/priority derivation the/derivation transform has a maxReadRate and a priority of 5, which is greater than the priority 0 of the unthrottled transform at test://example/catalog.yaml#/collections/the~1derivation/derive/transforms/0; a throttled transform cannot have a higher priority than an unthrottled one",
+        "test://example/catalog.yaml#/materializations/the~1materialization/bindings/0/maxReadRate materialization the/materialization maxReadRate of 100 bytes per second is less than the minimum of 1024 bytes per second",
+    ]
+    "###);
+
+    // Valid read hints are assembled into the built task specifications.
+    let outcome = common::run(
+        MODEL_YAML,
+        r#"
+test://example/catalog.yaml:
+  collections:
+    the/derivation:
+      derive:
+        transforms:
+          - name: fromCollection
+            source: { name: the/collection }
+            shuffle: { key: [/f_two] }
+            priority: 2
+            maxReadRate: 4096
+            lambda: select 123 as d_one, 'hello' as d_two;
+  materializations:
+    the/materialization:
+      bindings:
+        - source: the/collection
+          resource: { table: bar }
+          maxReadRate: 2048
+"#,
+    );
+    assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+
+    let derivation = outcome
+        .built_collections
+        .iter()
+        .find(|row| row.collection.as_str() == "the/derivation")
+        .and_then(|row| row.spec.as_ref())
+        .and_then(|spec| spec.derivation.as_ref())
+        .unwrap();
+    assert_eq!(
+        (
+            derivation.transforms[0].priority,
+            derivation.transforms[0].max_read_rate
+        ),
+        (2, 4096)
+    );
+
+    let materialization = outcome.built_materializations[0].spec.as_ref().unwrap();
+    assert_eq!(materialization.bindings[0].max_read_rate, 2048);
+}
+
+#[test]
+fn test_key_migrations() {
+    let migrate = |from: &str, to: &str, catalog: &str, driver: &str| {
+        common::run(
+            MODEL_YAML,
+            &format!(
+                r#"
+test://example/catalog.yaml:
+  collections:
+    the/collection:
+      key: [{to}]
+      keyMigration:
+        from: [{from}]
+      schema:
+        type: object
+        properties:
+          f_one: {{ type: integer }}
+          f_two: {{ type: string }}
+          f_three: {{ type: string }}
+        required: [f_one, f_two]
+{catalog}
+driver:
+  liveCollections:
+    the/collection:
+      schema:
+        x-live: pass-through
+        type: object
+        properties:
+          f_one: {{ type: integer }}
+          f_two: {{ type: string }}
+          f_three: {{ type: string }}
+        required: [f_one, f_two]
+{driver}
+"#
+            ),
+        )
+    };
+
+    // The key is migrated, and tasks of the collection are backfilled.
+    // Live tasks don't yet read the collection, so needn't increment their backfill.
+    let outcome = migrate("/f_one", "/f_two", "", "");
+    assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+
+    let built = outcome
+        .built_collections
+        .get_by_key(&models::Collection::new("the/collection"))
+        .unwrap();
+    insta::assert_json_snapshot!(built.key_migration, @r###"
+    {
+      "from": [
+        "/f_one"
+      ],
+      "to": [
+        "/f_two"
+      ],
+      "generationId": "2020202020202020",
+      "backfillMaterializations": [
+        "the/materialization"
+      ],
+      "backfillDerivations": [
+        "the/derivation"
+      ]
+    }
+    "###);
+
+    // Partitions don't depend on the key, and the live journal prefix is
+    // passed through so that activation retains existing journals.
+    let template = built
+        .spec
+        .as_ref()
+        .unwrap()
+        .partition_template
+        .as_ref()
+        .unwrap();
+    assert_eq!(
+        template.name,
+        "the/collection/pass-through/partition_name_prefix"
+    );
+    // Retained journals are labeled with the key generation, as their
+    // key-range labels reflect the prior key.
+    assert_eq!(
+        labels::expect_one(template.labels.as_ref().unwrap(), labels::KEY_GENERATION).unwrap(),
+        "2020202020202020"
+    );
+
+    let errors = |outcome: common::Outcome| -> Vec<String> {
+        outcome
+            .errors
+            .iter()
+            .map(|err| format!("{} {}", err.scope, err.error))
+            .collect()
+    };
+
+    // A migration which isn't from the current key is an error.
+    insta::assert_debug_snapshot!(errors(migrate("/f_three", "/f_two", "", "")), @r###"
+    [
+        "test://example/catalog.yaml#/collections/the~1collection/keyMigration/from collection the/collection keyMigration is from key [\"/f_three\"], but its current key is [\"/f_one\"]",
+    ]
+    "###);
+
+    // The new key must exist in all existing documents.
+    insta::assert_debug_snapshot!(errors(migrate("/f_one", "/f_three", "", "")), @r###"
+    [
+        "test://example/catalog.yaml#/collections/the~1collection/keyMigration collection the/collection cannot be migrated to key location /f_three, because it cannot be inferred from existing documents: location may not exist in existing documents",
+    ]
+    "###);
+
+    // A live materialization which reads the collection must backfill it.
+    let live_binding = r#"
+  liveMaterializations:
+    the/materialization:
+      bindings:
+        - source: the/collection
+          resource: { table: bar }
+"#;
+    let backfilled = r#"
+  materializations:
+    the/materialization:
+      bindings:
+        - source: the/collection
+          resource: { table: bar }
+          backfill: 1
+"#;
+    let outcome = migrate("/f_one", "/f_two", backfilled, live_binding);
+    assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+
+    // Its binding must increment its backfill counter.
+    insta::assert_debug_snapshot!(errors(migrate("/f_one", "/f_two", "", live_binding)), @r###"
+    [
+        "test://example/catalog.yaml#/materializations/the~1materialization/bindings/0/backfill the/materialization must backfill collection the/collection, which is migrating its key, by incrementing the backfill counter of each of its bindings or transforms of the collection",
+    ]
+    "###);
+
+    // And it must be drafted.
+    insta::assert_debug_snapshot!(errors(migrate("/f_one", "/f_two", "  materializations: null", live_binding)), @r###"
+    [
+        "test://example/catalog.yaml#/collections/the~1collection/keyMigration the/materialization must backfill collection the/collection, which is migrating its key, by incrementing the backfill counter of each of its bindings or transforms of the collection",
+    ]
+    "###);
+}
+
+#[test]
+fn test_unused_collection_warnings() {
+    let warnings = common::run_warnings(
+        MODEL_YAML,
+        r#"
+test://example/catalog.yaml:
+  collections:
+    the/derivation: null
+    the/new-collection:
+      key: [/id]
+      schema:
+        type: object
+        properties:
+          id: { type: string }
+        required: [id]
+  captures: null
+  materializations: null
+  tests: null
+        "#,
+    );
+
+    let warnings: Vec<String> = warnings
+        .iter()
+        .map(|err| format!("{} {}", err.scope, err.error))
+        .collect();
+
+    // Live specifications of the build don't read or write the/collection.
+    // the/new-collection is also unused, but is created by this build.
+    insta::assert_debug_snapshot!(warnings, @r###"
+    [
+        "test://example/catalog.yaml#/collections/the~1collection collection the/collection is not read or written by any of the 3 captures, derivations, materializations, or tests of this build's drafted and live specifications",
+    ]
+    "###);
+}
@@ -29,8 +29,30 @@ const (
 	KeyEnd = "estuary.dev/key-end"
 	// KeyEndMax is the maximum possible key.
 	KeyEndMax = "ffffffff"
+	// KeyGeneration is the publication ID of the most-recent key migration of
+	// the collection of this journal. Journals of a migrated collection may hold
+	// documents placed under a prior key, and their KeyBegin and KeyEnd don't
+	// bound the current keys of the documents they contain.
+	KeyGeneration = "estuary.dev/key-generation"
 	// ManagedByFlow is a value for the Gazette labels.ManagedBy label.
 	ManagedByFlow = "estuary.dev/flow"
+	// DeleteAfter is an RFC 3339 timestamp of a tombstoned journal or shard,
+	// after which it may be deleted.
+	DeleteAfter = "estuary.dev/delete-after"
+	// Owner is the team or individual which owns the catalog specification
+	// of this journal or shard, as given by its annotations.
+	Owner = "estuary.dev/owner"
+	// AnnotationPrefix prefixes user-defined label annotations of the
+	// catalog specification of this journal or shard.
+	AnnotationPrefix = "estuary.dev/annotation/"
+	// Zone is a multi-valued label of the zones of this journal or shard,
+	// as declared by its catalog specification. It's informational, and
+	// doesn't constrain the placement of journal replicas or shard assignments.
+	Zone = "estuary.dev/zone"
+	// Encryption is the key management provider (for example, "AWS_KMS")
+	// of the customer-managed key with which new fragments of this journal
+	// are encrypted. If not present, fragments use the store's default encryption.
+	Encryption = "estuary.dev/encryption"
 )
 
 // ShardSpec labels.
@@ -60,6 +82,34 @@ const (
 	LogsJournal = "estuary.dev/logs-journal"
 	// Journal to which task stats are directed.
 	StatsJournal = "estuary.dev/stats-journal"
+	// MaxTxnDuration is an operator override of the shard's maximum
+	// transaction duration, applied by the control-plane upon activation.
+	MaxTxnDuration = "estuary.dev/max-txn-duration"
+	// MinTxnDuration is an operator override of the shard's minimum
+	// transaction duration, applied by the control-plane upon activation.
+	MinTxnDuration = "estuary.dev/min-txn-duration"
+	// TxnDurationExpires is an RFC 3339 timestamp after which transaction
+	// duration overrides are no longer applied, and are removed.
+	// The control-plane re-activates the task when its earliest override
+	// expires, which removes the expired overrides from its shards.
+	TxnDurationExpires = "estuary.dev/txn-duration-expires"
+	// CPULimit is the limit, in millicores, of the CPU available to the
+	// connector container of the shard. If not present, the data-plane's
+	// default limit applies.
+	CPULimit = "estuary.dev/cpu-limit"
+	// MemoryLimit is the limit, in bytes, of the memory available to the
+	// connector container of the shard. If not present, the data-plane's
+	// default limit applies.
+	MemoryLimit = "estuary.dev/memory-limit"
+	// InitialSplits is the recommended number of shards with which a new
+	// task is created, as derived from the throughput of its source collections.
+	// It's a label of the task's ShardSpec template only.
+	InitialSplits = "estuary.dev/initial-splits"
+	// UserPatchPrefix is a prefix of operator patches of individual shards,
+	// where the suffix is the patched label and the value is its value.
+	// For example, `estuary.dev/user-patch/estuary.dev/log-level=debug`.
+	// Patches are preserved and re-applied by the control-plane upon activation.
+	UserPatchPrefix = "estuary.dev/user-patch/"
 
 	Hostname = "estuary.dev/hostname"
 
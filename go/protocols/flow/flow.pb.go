@@ -898,6 +898,10 @@ type CollectionSpec_Derivation_Transform struct {
 	// Priority of this transform, with respect to other transforms of the derivation.
 	// Higher values imply higher priority.
 	Priority uint32 `protobuf:"varint,4,opt,name=priority,proto3" json:"priority,omitempty"`
+	// Maximum rate, in bytes per second, at which this transform reads
+	// its source collection. Zero means the read is not throttled.
+	// Not yet enforced by the runtime.
+	MaxReadRate uint32 `protobuf:"varint,14,opt,name=max_read_rate,json=maxReadRate,proto3" json:"max_read_rate,omitempty"`
 	// Number of seconds for which documents of this transformed are delayed
 	// while reading, relative to other documents (when back-filling) and the
 	// present wall-clock time (when tailing).
@@ -1187,6 +1191,10 @@ type MaterializationSpec_Binding struct {
 	// Priority of this binding, with respect to other bindings of the materialization.
 	// Higher values imply higher priority.
 	Priority uint32 `protobuf:"varint,9,opt,name=priority,proto3" json:"priority,omitempty"`
+	// Maximum rate, in bytes per second, at which this binding reads
+	// its source collection. Zero means the read is not throttled.
+	// Not yet enforced by the runtime.
+	MaxReadRate uint32 `protobuf:"varint,14,opt,name=max_read_rate,json=maxReadRate,proto3" json:"max_read_rate,omitempty"`
 	// Resolved fields selected for materialization.
 	FieldSelection FieldSelection `protobuf:"bytes,4,opt,name=field_selection,json=fieldSelection,proto3" json:"field_selection"`
 	// Materialize delta updates of documents rather than full reductions.
@@ -2321,239 +2329,237 @@ func init() {
 func init() { proto.RegisterFile("go/protocols/flow/flow.proto", fileDescriptor_d0677502142fec31) }
 
 var fileDescriptor_d0677502142fec31 = []byte{
-	// 3702 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xd4, 0x5a, 0xcb, 0x8f, 0x1b, 0xc9,
-	0x79, 0x9f, 0xe6, 0x9b, 0x1f, 0x1f, 0xd3, 0x53, 0x33, 0x92, 0x28, 0xee, 0x6a, 0x38, 0xa2, 0x63,
-	0xac, 0x76, 0xe5, 0xe5, 0xac, 0x67, 0x61, 0x67, 0x25, 0x41, 0x31, 0xf8, 0x1a, 0x2d, 0x25, 0x0e,
-	0xc9, 0x34, 0x39, 0x5e, 0xcb, 0x80, 0xd1, 0x68, 0x76, 0x17, 0x39, 0xad, 0x69, 0x76, 0x33, 0xdd,
-	0xc5, 0xd1, 0xd0, 0x97, 0x04, 0x81, 0x81, 0x00, 0x8b, 0x04, 0xf0, 0x25, 0xb0, 0x6f, 0xde, 0x18,
-	0x48, 0x90, 0x7f, 0x21, 0xc9, 0x25, 0x40, 0x2e, 0x7b, 0x0a, 0x0c, 0x04, 0x49, 0x80, 0x20, 0xd0,
-	0x22, 0xce, 0xc1, 0x3e, 0x25, 0xb7, 0x20, 0xd0, 0x29, 0xa8, 0x47, 0x37, 0x9b, 0xc3, 0xd1, 0x3c,
-	0xd6, 0xce, 0x61, 0x2f, 0x44, 0xd7, 0xf7, 0xaa, 0xaa, 0xef, 0xab, 0xfa, 0x7d, 0x5f, 0x55, 0x11,
-	0xde, 0x1e, 0x3b, 0xbb, 0x53, 0xd7, 0x21, 0x8e, 0xee, 0x58, 0xde, 0xee, 0xc8, 0x72, 0x5e, 0xb2,
-	0x9f, 0x0a, 0xa3, 0xa1, 0x18, 0xfd, 0x2e, 0x6e, 0x0f, 0x5d, 0xe7, 0x18, 0xbb, 0x81, 0x5c, 0xf0,
-	0xc1, 0xa5, 0x8a, 0x3b, 0xba, 0x63, 0x7b, 0xb3, 0xc9, 0x05, 0x12, 0x5b, 0x63, 0x67, 0xec, 0xb0,
-	0xcf, 0x5d, 0xfa, 0x25, 0xa8, 0xa5, 0xb1, 0xe3, 0x8c, 0x2d, 0xcc, 0x85, 0x87, 0xb3, 0xd1, 0x2e,
-	0x31, 0x27, 0xd8, 0x23, 0xda, 0x64, 0xca, 0x05, 0xca, 0x8f, 0x21, 0xde, 0xb7, 0x4c, 0x1d, 0xa3,
-	0x2d, 0x88, 0x0f, 0xf1, 0xd8, 0xb4, 0x0b, 0xd2, 0x8e, 0x74, 0x2f, 0xa7, 0xf0, 0x06, 0x92, 0x21,
-	0x8a, 0x6d, 0xa3, 0x10, 0x61, 0x34, 0xfa, 0xf9, 0x30, 0xfb, 0x8b, 0xbf, 0x28, 0xad, 0xfd, 0xf8,
-	0xe7, 0xa5, 0xb5, 0x9f, 0xfe, 0xbc, 0xb4, 0x56, 0xb6, 0x21, 0x7d, 0x78, 0xd8, 0x6a, 0xf4, 0x34,
-	0x97, 0x78, 0x08, 0x41, 0xcc, 0x76, 0x0c, 0xcc, 0x2c, 0x24, 0x14, 0xf6, 0x8d, 0x1e, 0x41, 0x5c,
-	0xb7, 0x1c, 0xfd, 0x98, 0x99, 0x48, 0xd4, 0xbe, 0xfe, 0xfa, 0x55, 0xe9, 0xee, 0xd8, 0xa9, 0x8c,
-	0xb5, 0x1f, 0x62, 0x42, 0x70, 0xc5, 0xc0, 0x27, 0xbb, 0xba, 0xe3, 0xe2, 0xdd, 0x09, 0xf6, 0x3c,
-	0x6d, 0x8c, 0x2b, 0x75, 0x2a, 0xac, 0x70, 0x9d, 0x87, 0xf2, 0xaf, 0x3f, 0x2b, 0x49, 0x4b, 0xfd,
-	0xfd, 0xb3, 0x04, 0xd0, 0x73, 0x9d, 0x17, 0x58, 0x27, 0xa6, 0xc3, 0x86, 0x37, 0x25, 0x2e, 0xeb,
-	0x30, 0xad, 0xd0, 0x4f, 0x3a, 0x8d, 0x91, 0x89, 0x2d, 0x3e, 0xe4, 0xb4, 0xc2, 0x1b, 0xa8, 0x08,
-	0x29, 0x7c, 0x3a, 0xb5, 0x4c, 0xdd, 0x24, 0x85, 0xe8, 0x8e, 0x74, 0x2f, 0xa5, 0x04, 0x6d, 0x74,
-	0x0f, 0x64, 0xd3, 0x53, 0xa7, 0x9a, 0x4b, 0x4c, 0x6a, 0x53, 0x3d, 0xc6, 0xf3, 0x42, 0x8c, 0xc9,
-	0xe4, 0x4d, 0xaf, 0xe7, 0x93, 0x9f, 0xe1, 0x39, 0xfa, 0x1d, 0xc8, 0x53, 0x49, 0xd7, 0x9c, 0x68,
-	0xee, 0x9c, 0xc9, 0xc5, 0x99, 0x5c, 0xd6, 0xf4, 0x7a, 0x9c, 0x48, 0xa5, 0x3e, 0x84, 0xb4, 0x69,
-	0x8f, 0xb0, 0x8b, 0x6d, 0x1d, 0x17, 0x12, 0x3b, 0xd2, 0xbd, 0xcc, 0xde, 0x7a, 0x85, 0x05, 0xbc,
-	0xe5, 0x93, 0x6b, 0xb1, 0xcf, 0x5f, 0x95, 0xd6, 0x94, 0x85, 0x5c, 0xf9, 0xdf, 0x13, 0x90, 0x0e,
-	0xd8, 0x74, 0x12, 0x64, 0x3e, 0xc5, 0x5e, 0x41, 0xda, 0x89, 0xd2, 0x49, 0xb0, 0x06, 0xaa, 0x40,
-	0xc2, 0x23, 0xae, 0x69, 0x8f, 0xd9, 0x14, 0x32, 0x7b, 0x37, 0xcf, 0x58, 0xad, 0xf4, 0x19, 0x57,
-	0x11, 0x52, 0xcc, 0x8a, 0x49, 0x2c, 0xcc, 0x66, 0x43, 0xad, 0xd0, 0x06, 0xda, 0x81, 0x8c, 0x81,
-	0x3d, 0xdd, 0x35, 0xa7, 0x74, 0x5a, 0x6c, 0x06, 0x69, 0x25, 0x4c, 0x42, 0x8f, 0x20, 0x6b, 0xe0,
-	0x91, 0x36, 0xb3, 0x88, 0xfa, 0xc2, 0x73, 0x6c, 0x36, 0x87, 0x74, 0xed, 0xed, 0xd7, 0xaf, 0x4a,
-	0x05, 0x6c, 0xeb, 0x8e, 0x61, 0xda, 0xe3, 0x5d, 0xca, 0xa8, 0x28, 0xda, 0xcb, 0x03, 0x1e, 0x36,
-	0x25, 0x29, 0x34, 0xd0, 0x4d, 0x48, 0x78, 0x58, 0x77, 0x31, 0x29, 0x24, 0x99, 0x6f, 0x44, 0x8b,
-	0x0e, 0x1e, 0x9f, 0x9a, 0x1e, 0xf1, 0x0a, 0xa9, 0x1d, 0xe9, 0x5e, 0x7e, 0x75, 0xf0, 0x4d, 0xc6,
-	0x55, 0x84, 0x14, 0xfa, 0x26, 0x24, 0x6d, 0xba, 0xde, 0x4d, 0xbd, 0x90, 0x66, 0xb3, 0xbd, 0x75,
-	0x56, 0xa1, 0xc3, 0xd9, 0x8a, 0x2f, 0x87, 0xee, 0x43, 0x5c, 0x73, 0x5d, 0x6d, 0x5e, 0x00, 0xa6,
-	0x70, 0xe3, 0xac, 0x42, 0x95, 0x32, 0x15, 0x2e, 0x53, 0xfc, 0x99, 0x04, 0x09, 0xee, 0x2f, 0x74,
-	0x17, 0xb2, 0xba, 0x63, 0x13, 0x6c, 0x13, 0x95, 0x3a, 0x9a, 0x79, 0x37, 0xad, 0x64, 0x04, 0x6d,
-	0x30, 0x9f, 0x62, 0x3a, 0xab, 0x91, 0xe3, 0x4e, 0x34, 0x22, 0x7c, 0x29, 0x5a, 0xe8, 0x5d, 0x90,
-	0x7d, 0x55, 0xdf, 0x35, 0x6c, 0xde, 0x69, 0x65, 0x5d, 0xd0, 0x9b, 0x82, 0x8c, 0xee, 0x00, 0x4c,
-	0xb4, 0x53, 0xd5, 0xc2, 0xf6, 0x98, 0x1c, 0x31, 0x9f, 0xe6, 0x94, 0xf4, 0x44, 0x3b, 0x6d, 0x33,
-	0xc2, 0xd3, 0x58, 0x4a, 0x92, 0x23, 0x4f, 0x63, 0xa9, 0x88, 0x1c, 0x7d, 0x1a, 0x4b, 0xc5, 0xe5,
-	0x44, 0xf1, 0x0f, 0x21, 0x29, 0xa6, 0x88, 0x4a, 0x90, 0x39, 0xd2, 0x3c, 0x75, 0x62, 0xda, 0xe6,
-	0x64, 0x36, 0x61, 0xcb, 0x3d, 0xa5, 0xc0, 0x91, 0xe6, 0x1d, 0x70, 0x0a, 0x2a, 0x40, 0xd2, 0x67,
-	0xd2, 0x75, 0x2f, 0x29, 0x7e, 0x33, 0x50, 0xd5, 0x4e, 0x19, 0x37, 0xba, 0x50, 0xe5, 0x14, 0xa6,
-	0x2a, 0x98, 0x31, 0xa1, 0xca, 0x9b, 0xc5, 0x1f, 0x49, 0x10, 0x67, 0x3e, 0x43, 0x6f, 0x41, 0x7a,
-	0x62, 0xda, 0xaa, 0x49, 0xf0, 0xc4, 0x13, 0xf8, 0x90, 0x9a, 0x98, 0x76, 0x8b, 0xb6, 0x51, 0x19,
-	0x72, 0xa2, 0x07, 0x21, 0x10, 0x61, 0x7d, 0x64, 0x78, 0x1f, 0x5c, 0x86, 0x1a, 0x08, 0xf8, 0x51,
-	0x61, 0xc0, 0x67, 0xde, 0x01, 0xa0, 0x0c, 0x95, 0x2f, 0xf9, 0x18, 0x5b, 0xf2, 0x69, 0x4a, 0xa1,
-	0xae, 0xf7, 0xca, 0x35, 0x48, 0xf0, 0xb5, 0x81, 0x32, 0x90, 0x6c, 0x75, 0xbe, 0x5b, 0x6d, 0xb7,
-	0x1a, 0xf2, 0x1a, 0x4a, 0x41, 0xec, 0xe0, 0xb0, 0x3f, 0x90, 0x25, 0x94, 0x84, 0xe8, 0x41, 0xf5,
-	0xb9, 0x1c, 0x41, 0x59, 0x48, 0xb5, 0x0e, 0x7a, 0xed, 0x56, 0xbd, 0x35, 0x90, 0xa3, 0x08, 0x20,
-	0x51, 0xaf, 0x76, 0x3a, 0xdd, 0x81, 0x1c, 0x2b, 0x3f, 0x87, 0x4c, 0x07, 0x93, 0x97, 0x8e, 0x7b,
-	0xdc, 0x73, 0x5c, 0xb6, 0x48, 0xed, 0xd9, 0x64, 0x88, 0x5d, 0x31, 0x19, 0xd1, 0xa2, 0x30, 0xe1,
-	0xa3, 0xaa, 0xc0, 0x8f, 0xa0, 0x4d, 0x75, 0xa6, 0xb3, 0xa1, 0x65, 0xea, 0xc2, 0x87, 0xa2, 0x55,
-	0xfe, 0x9f, 0x3c, 0xe4, 0xeb, 0x8e, 0x65, 0x71, 0x44, 0xea, 0x4f, 0xb1, 0x8e, 0xca, 0x10, 0xb3,
-	0xb5, 0x09, 0xc7, 0xc1, 0x74, 0x2d, 0xff, 0xfa, 0x55, 0x09, 0x16, 0x12, 0x0a, 0xe3, 0xa1, 0x06,
-	0x6c, 0xbc, 0x74, 0x4d, 0x82, 0x55, 0x4f, 0x3f, 0xc2, 0x13, 0x8d, 0xef, 0xb4, 0xd4, 0x15, 0x76,
-	0x5a, 0x86, 0xa9, 0xf5, 0x99, 0x16, 0xaa, 0x81, 0xec, 0x62, 0xcd, 0x58, 0x32, 0x92, 0xb9, 0x82,
-	0x11, 0xa0, 0x5a, 0xc2, 0x86, 0x0c, 0x51, 0x0a, 0x65, 0x51, 0xe6, 0x77, 0xfa, 0x89, 0x6e, 0x43,
-	0x6a, 0x36, 0x33, 0x0d, 0x95, 0x42, 0x2b, 0x5f, 0xef, 0x49, 0xda, 0xee, 0x11, 0x97, 0x2e, 0xf8,
-	0x05, 0x52, 0x32, 0x6c, 0xf5, 0x0a, 0x71, 0xa6, 0xb9, 0x1e, 0xd0, 0xf7, 0x19, 0x19, 0x7d, 0x04,
-	0x99, 0x69, 0x80, 0xd4, 0x5e, 0x21, 0xb1, 0x13, 0xbd, 0x97, 0xd9, 0x93, 0xf9, 0xa6, 0x5c, 0x40,
-	0xb8, 0x80, 0xc2, 0xb0, 0x28, 0xf5, 0x8d, 0xa6, 0x1f, 0xab, 0x04, 0x4f, 0xa6, 0x96, 0x46, 0x30,
-	0x9f, 0x56, 0xf2, 0x2a, 0xbe, 0xd1, 0xf4, 0xe3, 0x81, 0xd0, 0x42, 0x0d, 0x40, 0x8b, 0xa1, 0xfa,
-	0xb6, 0x04, 0x98, 0xdc, 0xa8, 0x04, 0xd9, 0xf3, 0xa9, 0x33, 0x73, 0x6d, 0xcd, 0xa2, 0x81, 0x53,
-	0x36, 0x02, 0x85, 0xc0, 0xca, 0x77, 0x00, 0x0c, 0xec, 0x9a, 0x27, 0x1a, 0x43, 0xcb, 0x2c, 0xd3,
-	0x2e, 0xf1, 0x49, 0x2c, 0x47, 0xbd, 0xd2, 0x08, 0xc4, 0x94, 0x90, 0x4a, 0xf1, 0x5f, 0x00, 0x60,
-	0xc1, 0x42, 0x03, 0xc8, 0xeb, 0x8e, 0x6d, 0x63, 0x9d, 0x38, 0x2e, 0x87, 0x1b, 0x89, 0xe1, 0xe1,
-	0xfb, 0x97, 0xd8, 0xac, 0xd4, 0x7d, 0x2d, 0xba, 0x2b, 0x94, 0x9c, 0x1e, 0x6e, 0xa2, 0x07, 0x40,
-	0xe1, 0x6a, 0x64, 0x8e, 0xb9, 0xaf, 0x22, 0x57, 0xf0, 0x55, 0x82, 0x2b, 0xa0, 0x2e, 0x00, 0x71,
-	0x35, 0xdb, 0xa3, 0x88, 0xe6, 0xb1, 0x55, 0x90, 0xd9, 0x7b, 0xf7, 0xb2, 0xc1, 0x0c, 0x7c, 0x0d,
-	0x11, 0xbe, 0x90, 0x09, 0xf4, 0x09, 0x6c, 0x78, 0x47, 0xb3, 0xd1, 0xc8, 0xc2, 0x34, 0x45, 0x86,
-	0x76, 0x75, 0x7e, 0xef, 0xfe, 0x65, 0x76, 0xfb, 0x5c, 0x91, 0x4d, 0x71, 0x5d, 0x58, 0x79, 0x86,
-	0xe7, 0x0c, 0x08, 0xd0, 0x43, 0xc8, 0x7b, 0x47, 0x9a, 0x6b, 0x2c, 0x82, 0x19, 0x67, 0xe1, 0xd8,
-	0xac, 0xf8, 0xc5, 0x51, 0xa5, 0x4f, 0xf9, 0x2c, 0x94, 0x39, 0x26, 0x1a, 0x84, 0xb1, 0x05, 0x37,
-	0x5c, 0xac, 0x3b, 0x27, 0xd8, 0x9d, 0xab, 0x96, 0x33, 0x5e, 0x98, 0x48, 0x5c, 0xb4, 0x1e, 0x36,
-	0x7d, 0x9d, 0xb6, 0x33, 0x0e, 0x4c, 0x7d, 0x1b, 0x72, 0x36, 0xc7, 0x12, 0x75, 0xea, 0xb8, 0xc4,
-	0x2b, 0x24, 0x99, 0xcf, 0x36, 0xf8, 0xdc, 0x42, 0x30, 0xa3, 0x64, 0xed, 0x45, 0xc3, 0x2b, 0xfe,
-	0x2c, 0x0e, 0xe9, 0xc0, 0x6f, 0xe8, 0xee, 0x12, 0x46, 0xe4, 0x5e, 0xbf, 0x2a, 0x2d, 0x98, 0x02,
-	0x22, 0x1e, 0x02, 0xe8, 0x81, 0xa7, 0x58, 0x4c, 0x33, 0x7b, 0x5b, 0xe7, 0x79, 0xd0, 0x0f, 0xc2,
-	0x42, 0x1a, 0xb5, 0xc3, 0x8b, 0xdf, 0xc3, 0x16, 0x5b, 0x2a, 0xa2, 0x6e, 0xb8, 0xb5, 0x98, 0x6c,
-	0x5b, 0x1b, 0x62, 0xab, 0x2f, 0xd8, 0xc2, 0xcc, 0x62, 0x13, 0xf8, 0x0c, 0x8e, 0x8b, 0xa6, 0xe3,
-	0x9a, 0x84, 0x97, 0x46, 0x39, 0x25, 0x68, 0xa3, 0x6f, 0x00, 0x62, 0x10, 0x64, 0x60, 0x4b, 0x9b,
-	0xab, 0x1e, 0xd6, 0x1d, 0x9b, 0x61, 0x02, 0x95, 0x62, 0xe0, 0xd4, 0xa0, 0x8c, 0x3e, 0xa7, 0xd3,
-	0x74, 0x14, 0x5a, 0x1c, 0x0c, 0x14, 0xd2, 0x0a, 0x2c, 0x22, 0x8d, 0x0e, 0xa1, 0xe8, 0x0b, 0x58,
-	0xda, 0x64, 0x68, 0x68, 0x6a, 0x78, 0x61, 0x5f, 0x05, 0x04, 0x36, 0x85, 0x7e, 0x9b, 0xa9, 0xd7,
-	0xf9, 0x2a, 0xdf, 0x07, 0x74, 0x8e, 0xb9, 0xab, 0xe0, 0x6d, 0xd6, 0x0a, 0xdb, 0x79, 0x0b, 0xd2,
-	0x6c, 0xb6, 0x8e, 0x6d, 0xcd, 0x19, 0x96, 0xa4, 0x94, 0x14, 0x25, 0x74, 0x6d, 0x6b, 0x8e, 0x2a,
-	0xb0, 0xf9, 0x82, 0xaf, 0x1e, 0x95, 0xa3, 0xf2, 0x6c, 0x34, 0x32, 0x4f, 0x59, 0x39, 0x92, 0x56,
-	0x36, 0x04, 0x4b, 0xa1, 0xc8, 0xcb, 0x18, 0xe8, 0x01, 0x80, 0xed, 0x10, 0x75, 0x88, 0x47, 0x8e,
-	0x8b, 0x19, 0x6e, 0x67, 0xf6, 0x8a, 0x15, 0x5e, 0xb1, 0x57, 0xfc, 0x8a, 0xbd, 0x32, 0xf0, 0x2b,
-	0x76, 0x25, 0x6d, 0x3b, 0xa4, 0xc6, 0x84, 0xd1, 0xef, 0x02, 0x6d, 0xa8, 0xda, 0x88, 0x60, 0x57,
-	0xa0, 0xd2, 0x45, 0x9a, 0x29, 0xdb, 0x21, 0x55, 0x2a, 0x4b, 0x43, 0x39, 0xd4, 0xf4, 0xe3, 0x91,
-	0x69, 0x59, 0x85, 0x1c, 0x0f, 0xa5, 0xdf, 0x2e, 0xff, 0x00, 0x72, 0x4b, 0x28, 0x83, 0x8a, 0x70,
-	0x53, 0x24, 0x5c, 0xb5, 0xde, 0xed, 0x74, 0x9a, 0xf5, 0x41, 0x57, 0x51, 0x07, 0xcf, 0x7b, 0x4d,
-	0x79, 0x8d, 0xa6, 0xd7, 0xfe, 0xef, 0xb7, 0x5b, 0x83, 0xa6, 0x2c, 0xa1, 0x3c, 0x00, 0xa5, 0xf6,
-	0xeb, 0x4a, 0xab, 0x37, 0x90, 0x23, 0x28, 0x0d, 0xf1, 0xd6, 0x41, 0xf5, 0x49, 0x53, 0x8e, 0xd2,
-	0xcf, 0x76, 0xb7, 0x5e, 0x6d, 0xcb, 0xb1, 0xf2, 0x01, 0x64, 0x42, 0xfb, 0x1b, 0x15, 0x60, 0xcb,
-	0x37, 0xde, 0xff, 0xf8, 0x70, 0x7f, 0xbf, 0xdd, 0xf4, 0x4d, 0x67, 0x20, 0x59, 0xeb, 0x76, 0xdb,
-	0xcd, 0x6a, 0x47, 0x96, 0x78, 0xd2, 0x1f, 0x34, 0x9f, 0x34, 0x15, 0x39, 0xc2, 0x3a, 0x1d, 0x28,
-	0xad, 0xce, 0x13, 0x39, 0x5a, 0xfe, 0x49, 0x04, 0xf2, 0x2c, 0xd5, 0xf0, 0x65, 0x4a, 0x57, 0x3d,
-	0x82, 0xd8, 0x31, 0x9e, 0xfb, 0x65, 0x33, 0xfb, 0xa6, 0x79, 0xfb, 0x44, 0xb3, 0x66, 0x98, 0xd6,
-	0x25, 0x94, 0x2a, 0x5a, 0xd4, 0x11, 0x86, 0xa3, 0xcf, 0x26, 0xd8, 0x26, 0xa2, 0xe2, 0x0b, 0xda,
-	0xe8, 0x04, 0x6e, 0xb0, 0xdc, 0x16, 0x5e, 0x2c, 0xea, 0x44, 0x9b, 0x32, 0x18, 0xcb, 0xec, 0x7d,
-	0x83, 0x6f, 0xc2, 0xe5, 0xce, 0x79, 0x93, 0x2f, 0x93, 0xa7, 0x9e, 0x63, 0x1f, 0x68, 0xd3, 0xa6,
-	0x4d, 0xdc, 0x79, 0xed, 0xed, 0x4f, 0xbf, 0xb8, 0x28, 0x65, 0x8d, 0x16, 0x6a, 0xc5, 0x26, 0xdc,
-	0x7a, 0x83, 0x15, 0x3f, 0x4b, 0x8b, 0x93, 0x0e, 0xcd, 0xd2, 0x5b, 0x10, 0x67, 0x53, 0xf1, 0x4f,
-	0x3a, 0xac, 0xf1, 0x30, 0xf2, 0x91, 0x54, 0xfe, 0xdf, 0x38, 0x64, 0xea, 0xda, 0x94, 0xcc, 0x5c,
-	0xcc, 0xea, 0x91, 0xd2, 0x12, 0xd6, 0x64, 0x5e, 0xbf, 0x2a, 0x25, 0x05, 0x5b, 0x20, 0xcd, 0xfe,
-	0x4a, 0x52, 0x8a, 0xb0, 0xa4, 0xe4, 0x27, 0xba, 0x85, 0xad, 0x6b, 0xa5, 0xa1, 0xe8, 0x35, 0xd2,
-	0xd0, 0xb7, 0x20, 0x35, 0x34, 0x6d, 0x2a, 0xe2, 0x09, 0x2f, 0xdf, 0x5e, 0xed, 0xbc, 0xc6, 0x25,
-	0x94, 0x40, 0x94, 0xd6, 0x23, 0xa6, 0x4d, 0xb0, 0x7b, 0xa2, 0x59, 0x67, 0xb0, 0x67, 0xdd, 0xa7,
-	0xfb, 0xd0, 0xb3, 0x9a, 0x3e, 0x12, 0xbf, 0x79, 0xfa, 0x48, 0xfe, 0xe6, 0xe9, 0x23, 0x75, 0xb5,
-	0xf4, 0xf1, 0xdf, 0x12, 0x24, 0xc5, 0xfc, 0xd1, 0x53, 0xd8, 0x72, 0xb1, 0xe7, 0xcc, 0x5c, 0x1d,
-	0x2f, 0xe1, 0x99, 0x74, 0x05, 0x87, 0xe7, 0x7d, 0x4d, 0x81, 0x68, 0x5f, 0x83, 0x5c, 0x60, 0x6b,
-	0xaa, 0x91, 0x23, 0xb1, 0x4d, 0xb2, 0x3e, 0xb1, 0xa7, 0x91, 0xa3, 0x33, 0xa9, 0x28, 0x7a, 0xad,
-	0x54, 0x14, 0x46, 0x9c, 0xd8, 0x32, 0xe2, 0x50, 0x38, 0xf5, 0x08, 0x2d, 0xf1, 0xfc, 0xc3, 0x74,
-	0x5a, 0x49, 0x31, 0xc2, 0x33, 0x3c, 0x2f, 0xef, 0x9d, 0x85, 0xa3, 0xa5, 0xfa, 0x3f, 0xc0, 0x98,
-	0xe4, 0x02, 0x63, 0x52, 0xe5, 0x7f, 0x05, 0xd8, 0x3c, 0xd0, 0x08, 0x76, 0x4d, 0xcd, 0x32, 0x7f,
-	0xa8, 0x05, 0x25, 0xf9, 0x3b, 0x4b, 0x5b, 0x60, 0xf3, 0xf5, 0xab, 0xd2, 0xfa, 0x19, 0x31, 0xb1,
-	0x15, 0x3a, 0x6f, 0xd8, 0x0a, 0xef, 0xf0, 0xd9, 0x9e, 0x63, 0xfb, 0xff, 0x6d, 0x4b, 0x3c, 0x5e,
-	0xd9, 0x12, 0x77, 0xdf, 0x3c, 0x88, 0xd5, 0xad, 0xf1, 0x15, 0x2f, 0x97, 0xfe, 0x31, 0xf1, 0x15,
-	0x5d, 0xef, 0xe7, 0x97, 0x5e, 0xc9, 0xdf, 0x42, 0xe9, 0x95, 0x3e, 0x53, 0x7a, 0xd5, 0x61, 0x9d,
-	0xa7, 0x29, 0xcf, 0x4f, 0x42, 0x6c, 0x83, 0x05, 0x43, 0x5d, 0x4e, 0x50, 0xa2, 0x8f, 0xfc, 0x68,
-	0x39, 0x67, 0x7e, 0x0d, 0x72, 0x06, 0xb6, 0x88, 0xa6, 0xce, 0xa6, 0x86, 0x46, 0xb0, 0xe7, 0xdf,
-	0x69, 0x31, 0xe2, 0x21, 0xa7, 0xa1, 0x21, 0x20, 0x03, 0x4f, 0x5d, 0xac, 0x6b, 0x04, 0x1b, 0xaa,
-	0x28, 0xb0, 0xc4, 0x62, 0xf8, 0xf0, 0xd2, 0x45, 0x59, 0x69, 0x04, 0xba, 0xa2, 0x00, 0x50, 0x36,
-	0x8c, 0xb3, 0xa4, 0x37, 0x55, 0x4f, 0xa9, 0xab, 0x55, 0x4f, 0xf0, 0xa5, 0xab, 0xa7, 0xcc, 0x97,
-	0xac, 0x9e, 0xb2, 0x17, 0x61, 0x59, 0x6e, 0x19, 0xcb, 0x8a, 0x7f, 0x24, 0xc1, 0xc6, 0x8a, 0x17,
-	0xd0, 0x1d, 0x80, 0xb1, 0xeb, 0xcc, 0xa6, 0xea, 0x02, 0x9b, 0x94, 0x34, 0xa3, 0x74, 0x28, 0x16,
-	0xfd, 0x56, 0x8b, 0xf8, 0xf2, 0xe3, 0xab, 0xc1, 0x69, 0x6a, 0x01, 0xa7, 0x69, 0xfa, 0xd9, 0x68,
-	0x3e, 0xab, 0xee, 0xcb, 0x50, 0xfe, 0xaf, 0x34, 0x24, 0xba, 0xd5, 0x19, 0x39, 0xda, 0x13, 0xd7,
-	0x24, 0x27, 0xa6, 0x81, 0xfd, 0xab, 0xd7, 0xa0, 0x8d, 0xde, 0x83, 0x0d, 0x6d, 0x46, 0x8e, 0xd4,
-	0x99, 0x6b, 0x2d, 0x50, 0x83, 0x57, 0x28, 0xeb, 0x94, 0x71, 0xe8, 0x5a, 0x01, 0x34, 0x3c, 0x80,
-	0xdb, 0x9a, 0xae, 0x63, 0xcf, 0x53, 0x89, 0x73, 0x8c, 0xed, 0x65, 0x1d, 0x5e, 0x93, 0xdd, 0xe4,
-	0x02, 0x03, 0xca, 0x0f, 0xab, 0x56, 0x60, 0x73, 0x49, 0x75, 0x82, 0xc9, 0x91, 0x63, 0xf0, 0xbb,
-	0x0f, 0x65, 0x23, 0xa4, 0x74, 0xc0, 0x18, 0x6c, 0x58, 0x61, 0xf9, 0xa1, 0x63, 0xcc, 0xc5, 0xdd,
-	0xc6, 0x7a, 0x48, 0xba, 0xe6, 0x18, 0x73, 0xf4, 0x23, 0x09, 0xee, 0x2c, 0x09, 0x1f, 0x61, 0xcd,
-	0xc0, 0xae, 0xb7, 0x28, 0x03, 0xe3, 0x0c, 0xc2, 0xc4, 0x69, 0x96, 0x3b, 0xa5, 0x52, 0x5d, 0x58,
-	0xf9, 0x98, 0xcb, 0x5f, 0xa3, 0x0a, 0x44, 0xda, 0x8a, 0x36, 0xfa, 0x54, 0x82, 0xed, 0xa5, 0x61,
-	0xb8, 0xd8, 0x9b, 0x3a, 0xb6, 0x87, 0x17, 0xe3, 0x48, 0x84, 0xcb, 0xd1, 0xd5, 0x71, 0x28, 0x42,
-	0xe1, 0x1a, 0x03, 0x09, 0xfb, 0xdb, 0x57, 0x3f, 0xd0, 0xa6, 0xe8, 0x11, 0x14, 0x5d, 0x3c, 0x72,
-	0xb1, 0x77, 0x74, 0x5e, 0xac, 0xf8, 0x95, 0xe7, 0x2d, 0x21, 0xb1, 0x12, 0xac, 0x0f, 0x28, 0x7c,
-	0x87, 0x95, 0x45, 0xb4, 0xb2, 0x4c, 0x0d, 0x85, 0xd5, 0x44, 0xb8, 0xd8, 0xa1, 0x32, 0xac, 0xc1,
-	0xe2, 0xc5, 0xa1, 0x40, 0x0e, 0xcb, 0xb3, 0x80, 0xfd, 0x89, 0x04, 0xdb, 0xcb, 0xe2, 0x2b, 0x11,
-	0x4b, 0x9f, 0xe3, 0x29, 0x25, 0x64, 0xe7, 0xfa, 0x21, 0xdb, 0x74, 0x57, 0xd5, 0xd1, 0x9f, 0x49,
-	0x50, 0x5a, 0x1e, 0xc9, 0x6a, 0xd0, 0x80, 0x0d, 0xe5, 0xfd, 0x37, 0x0e, 0xe5, 0x4b, 0x44, 0x6d,
-	0xc9, 0xf3, 0xa1, 0xb0, 0x15, 0xdb, 0xb0, 0x7d, 0xf1, 0xba, 0xbc, 0xce, 0xb9, 0xa2, 0x78, 0x00,
-	0xa5, 0x4b, 0x56, 0xd7, 0x75, 0xcd, 0x5d, 0x12, 0x82, 0x6b, 0x99, 0xeb, 0xc0, 0xce, 0x65, 0x6e,
-	0xbc, 0xd6, 0x29, 0xea, 0x6f, 0xa2, 0x90, 0x1a, 0x60, 0x8f, 0xb0, 0xfa, 0x11, 0x85, 0xeb, 0x47,
-	0x51, 0x2a, 0xee, 0x42, 0xdc, 0x23, 0x78, 0xca, 0x0f, 0x96, 0xb4, 0xae, 0x62, 0x11, 0xf5, 0x55,
-	0x2a, 0x7d, 0x82, 0xa7, 0x02, 0x8d, 0xb9, 0x5c, 0xf1, 0x57, 0x11, 0x88, 0x51, 0x2a, 0xfa, 0x16,
-	0x4d, 0x15, 0x78, 0x1a, 0xbe, 0xff, 0x2b, 0x9c, 0xa3, 0x5d, 0x61, 0x05, 0x65, 0x8a, 0x8a, 0x32,
-	0xc0, 0xbe, 0x03, 0xc0, 0xd4, 0x4c, 0xdb, 0xc0, 0xa7, 0xe2, 0x4d, 0x8e, 0x19, 0x6a, 0x51, 0xc2,
-	0xd9, 0x97, 0x9d, 0xe8, 0xea, 0xcb, 0x8e, 0x6f, 0xc0, 0xd3, 0x9d, 0xa9, 0xff, 0x2c, 0xc4, 0x0c,
-	0xf4, 0x29, 0x01, 0x55, 0x96, 0xaa, 0x9e, 0xf8, 0xb9, 0xb7, 0xd7, 0xe1, 0x4a, 0xe7, 0x01, 0xe4,
-	0x0c, 0x47, 0x17, 0xbb, 0xec, 0x04, 0xeb, 0xfc, 0x3a, 0xe7, 0x92, 0x7a, 0x2c, 0x46, 0x55, 0xd0,
-	0x63, 0x80, 0x20, 0x43, 0x79, 0x57, 0x2b, 0x8e, 0x42, 0x0a, 0xe5, 0x6d, 0x88, 0x31, 0x8f, 0x00,
-	0x24, 0x5a, 0x9d, 0x27, 0xcd, 0xfe, 0x80, 0x5f, 0x48, 0x7c, 0xb7, 0xa9, 0xb4, 0xf6, 0x9f, 0xcb,
-	0x52, 0xf9, 0x27, 0x12, 0xa4, 0x15, 0xcd, 0x1e, 0xf3, 0xf3, 0xef, 0x5b, 0x90, 0x3e, 0xc6, 0x73,
-	0x95, 0x3f, 0x6f, 0x52, 0xb7, 0x25, 0x95, 0xd4, 0x31, 0x9e, 0xd7, 0xd8, 0x0b, 0xe7, 0x2d, 0x48,
-	0x52, 0x26, 0xb6, 0x0d, 0xe6, 0xb1, 0xa4, 0x92, 0x38, 0xc6, 0xf3, 0xa6, 0x6d, 0xa0, 0x32, 0xe4,
-	0x5c, 0x95, 0xbd, 0x43, 0x0a, 0xcd, 0x18, 0x63, 0x67, 0x5c, 0xf6, 0x44, 0xc9, 0x95, 0xb7, 0x21,
-	0xe3, 0xcb, 0x50, 0x03, 0x71, 0x26, 0x91, 0xe6, 0x12, 0x4d, 0xdb, 0x78, 0x28, 0xff, 0xf4, 0xb3,
-	0xd2, 0xda, 0x99, 0x07, 0xd3, 0x7c, 0x90, 0x85, 0xfb, 0xb4, 0x3a, 0x40, 0x8f, 0x20, 0xcb, 0x4b,
-	0x2f, 0xe3, 0xea, 0x45, 0x6d, 0x52, 0x68, 0xa0, 0x12, 0x64, 0x26, 0xd8, 0x1d, 0xb3, 0x52, 0x56,
-	0x3f, 0x12, 0x4f, 0x2f, 0xc0, 0x48, 0x3d, 0x4a, 0x29, 0xff, 0x95, 0x04, 0xe9, 0x3e, 0x76, 0x7b,
-	0x8e, 0x65, 0xea, 0xec, 0xb2, 0xce, 0x23, 0xae, 0x4a, 0xdc, 0x99, 0x4d, 0x0b, 0x11, 0x51, 0x01,
-	0xf1, 0x47, 0x10, 0xd9, 0x23, 0xee, 0x40, 0x30, 0x78, 0xb5, 0xf3, 0x00, 0x6e, 0xdb, 0xd8, 0xa3,
-	0x03, 0x73, 0x86, 0x2f, 0xce, 0x2a, 0xf1, 0xe5, 0x77, 0x93, 0x0b, 0x74, 0x87, 0x2f, 0x96, 0x55,
-	0x3f, 0x80, 0x2d, 0xf6, 0xce, 0x76, 0x56, 0x8b, 0xbf, 0xfd, 0x20, 0xc6, 0x5b, 0xd2, 0x28, 0xff,
-	0x65, 0x04, 0xa0, 0x79, 0x4a, 0x5c, 0x4d, 0x27, 0xd5, 0x5e, 0xab, 0xf8, 0x77, 0x12, 0x24, 0x44,
-	0xc5, 0x1e, 0x7e, 0x8e, 0x90, 0x96, 0x9f, 0x23, 0x1e, 0x43, 0x26, 0xfc, 0xf4, 0x71, 0x95, 0x7b,
-	0x6f, 0xe0, 0x0a, 0x14, 0x16, 0xe8, 0x7e, 0xe0, 0x05, 0xf4, 0x94, 0xb8, 0x9e, 0x78, 0x01, 0x49,
-	0x33, 0x4a, 0x8f, 0xb8, 0x2b, 0x2f, 0x18, 0xb1, 0x2b, 0xbf, 0x60, 0x94, 0x9f, 0x43, 0xac, 0xee,
-	0x18, 0x67, 0x4a, 0xac, 0x1c, 0xa4, 0xeb, 0xdd, 0xce, 0x7e, 0xeb, 0xc9, 0xa1, 0xd2, 0xe4, 0x17,
-	0x5b, 0xcd, 0xef, 0x0d, 0x94, 0x6a, 0x7d, 0x20, 0x47, 0x10, 0x82, 0xbc, 0x68, 0x34, 0x1b, 0xea,
-	0xe1, 0x61, 0xab, 0x21, 0x47, 0xd1, 0x16, 0xc8, 0x0b, 0xda, 0x7e, 0xab, 0xd9, 0x6e, 0xf4, 0xe5,
-	0x58, 0xf9, 0x57, 0x12, 0xa4, 0x6a, 0x33, 0xd3, 0x32, 0xa8, 0x97, 0xfe, 0x6d, 0xc9, 0x4b, 0x43,
-	0x4a, 0x56, 0x4d, 0xc3, 0xf7, 0x12, 0x6b, 0xb7, 0x8c, 0x05, 0xcb, 0x18, 0x0a, 0x98, 0xe3, 0xac,
-	0xc6, 0x90, 0x3d, 0xd7, 0xb2, 0x63, 0x8f, 0x80, 0x0b, 0xd1, 0x42, 0x7b, 0x90, 0x11, 0x67, 0x24,
-	0x86, 0x51, 0x31, 0x86, 0x51, 0x1b, 0xfe, 0x09, 0x28, 0x78, 0x18, 0x55, 0x80, 0x4b, 0xb1, 0xcd,
-	0x78, 0x1f, 0x36, 0x16, 0x47, 0x67, 0x71, 0x96, 0x13, 0x87, 0x7a, 0x39, 0x60, 0x88, 0x03, 0x1f,
-	0xba, 0x0b, 0x59, 0xe1, 0x30, 0xd5, 0x75, 0x1c, 0xc2, 0x1f, 0x99, 0x03, 0x27, 0x2a, 0x8e, 0x43,
-	0xca, 0x9b, 0xb0, 0xa1, 0x60, 0x0f, 0x13, 0xb6, 0x4d, 0x14, 0xfc, 0x07, 0x33, 0xec, 0x91, 0xf2,
-	0x16, 0xa0, 0x30, 0x91, 0x63, 0x7c, 0xf9, 0x31, 0xa0, 0xaa, 0x71, 0xa2, 0xd9, 0x3a, 0xa6, 0x55,
-	0xbb, 0x90, 0x45, 0xef, 0xc0, 0xba, 0xc6, 0xa9, 0xc1, 0xdd, 0x10, 0xf5, 0x4c, 0x4c, 0xc9, 0x0b,
-	0xb2, 0xb8, 0x1a, 0x2a, 0xdf, 0x80, 0xcd, 0x25, 0x75, 0x61, 0xf5, 0x23, 0xc8, 0x36, 0x1c, 0xdd,
-	0xab, 0xda, 0x46, 0x6d, 0x4e, 0x4f, 0x41, 0x08, 0x18, 0x78, 0x89, 0xfd, 0xc2, 0x81, 0x6c, 0x0b,
-	0xe2, 0x43, 0xca, 0x64, 0x8e, 0x8d, 0x29, 0xbc, 0x51, 0xfe, 0x73, 0x09, 0x72, 0x2d, 0x7b, 0x8c,
-	0x3d, 0xe2, 0x8f, 0x65, 0x19, 0x5b, 0xa5, 0x4b, 0xb1, 0x35, 0x1c, 0xce, 0xc8, 0x72, 0x38, 0x57,
-	0x60, 0x37, 0x7a, 0x55, 0xd8, 0x2d, 0xff, 0x53, 0x04, 0xf2, 0xfe, 0xb8, 0xf8, 0x24, 0xd1, 0xdf,
-	0x4a, 0x8b, 0x73, 0x17, 0x7f, 0x91, 0xa4, 0xc5, 0x13, 0xbf, 0x43, 0x0d, 0x6a, 0xdc, 0x65, 0x1d,
-	0xff, 0xd0, 0xff, 0x09, 0x15, 0xa7, 0x69, 0xdb, 0xe3, 0x45, 0xca, 0x0f, 0xfe, 0xf8, 0x8b, 0xd2,
-	0xfd, 0xf3, 0xfe, 0xdc, 0x71, 0xe6, 0xcf, 0x2d, 0xbe, 0xfe, 0xa7, 0x5f, 0x94, 0xde, 0xbb, 0x8a,
-	0x78, 0x77, 0x34, 0xf2, 0x30, 0x09, 0xce, 0x80, 0x8b, 0x6e, 0xd1, 0xef, 0x41, 0xd6, 0x1f, 0x3b,
-	0x26, 0xba, 0x21, 0x1e, 0x49, 0x42, 0xd7, 0x13, 0xbc, 0xa8, 0xa8, 0x34, 0x89, 0x6e, 0xf8, 0xfb,
-	0x54, 0x28, 0x50, 0x52, 0xb1, 0x01, 0x37, 0xcf, 0x9f, 0xcb, 0x65, 0x95, 0x42, 0x34, 0x5c, 0x29,
-	0xfc, 0x75, 0x04, 0x6e, 0x0d, 0x34, 0xef, 0xd8, 0xbf, 0xcc, 0x70, 0x9d, 0xd3, 0xb9, 0x1f, 0xf7,
-	0x6f, 0x43, 0xcc, 0x99, 0x62, 0x1e, 0xf1, 0xcc, 0x5e, 0x59, 0x64, 0xf9, 0xf3, 0x85, 0x2b, 0xdd,
-	0x29, 0xb6, 0x15, 0x26, 0xcf, 0xd6, 0x9a, 0x46, 0x34, 0xd6, 0x59, 0x56, 0x61, 0xdf, 0xc5, 0xbf,
-	0x97, 0x20, 0x46, 0x45, 0xd0, 0x3d, 0x48, 0xf0, 0x0a, 0x57, 0x98, 0x95, 0xcf, 0x4e, 0x58, 0x11,
-	0x7c, 0xd4, 0x86, 0x14, 0xbf, 0x04, 0xf2, 0x97, 0x51, 0xed, 0x9b, 0xaf, 0x5f, 0x95, 0xde, 0x3f,
-	0xcf, 0xeb, 0x2b, 0xff, 0x30, 0xe2, 0xd7, 0x43, 0xad, 0x86, 0x92, 0x64, 0x26, 0x5a, 0x2c, 0xdb,
-	0x10, 0xcd, 0x1d, 0x63, 0xc2, 0xae, 0x72, 0x04, 0x98, 0x03, 0x27, 0xb1, 0x87, 0xf5, 0x12, 0x64,
-	0x74, 0xcb, 0xc4, 0x36, 0x51, 0x35, 0xc3, 0xf0, 0x1f, 0x8f, 0x81, 0x93, 0xaa, 0x86, 0xe1, 0x96,
-	0xff, 0x34, 0x0a, 0x85, 0xd5, 0xd9, 0x8b, 0xa5, 0xd8, 0x83, 0x1c, 0x9d, 0x7b, 0x50, 0x33, 0x8b,
-	0xd9, 0xdd, 0x7f, 0x93, 0xd3, 0xc4, 0x6a, 0x64, 0x5e, 0x13, 0x0d, 0x25, 0xeb, 0x84, 0x5a, 0xe7,
-	0x7a, 0xf1, 0x25, 0x64, 0xc3, 0x1a, 0xe8, 0x31, 0x24, 0xe8, 0x31, 0x7d, 0xe6, 0x89, 0x4a, 0xec,
-	0xeb, 0x97, 0x74, 0xd7, 0x67, 0xc2, 0x8a, 0x50, 0x0a, 0xc5, 0x22, 0x72, 0x71, 0x2c, 0xca, 0x3f,
-	0x66, 0x7f, 0x39, 0x61, 0x4a, 0x09, 0x88, 0x74, 0x9f, 0xc9, 0x6b, 0x68, 0x13, 0xd6, 0xfb, 0x1f,
-	0x57, 0x95, 0x86, 0xda, 0xe9, 0x0e, 0xd4, 0xfd, 0xee, 0x61, 0xa7, 0x21, 0x4b, 0x14, 0xf7, 0x3b,
-	0x5d, 0x95, 0xd3, 0x7b, 0x4a, 0xeb, 0xa0, 0xaa, 0x3c, 0x97, 0x23, 0xe8, 0x06, 0x6c, 0x50, 0xa1,
-	0x65, 0x72, 0x94, 0x26, 0x8e, 0x56, 0x67, 0xd0, 0x54, 0x3a, 0xd5, 0xb6, 0xda, 0x54, 0x94, 0xae,
-	0x22, 0xc7, 0xd0, 0x06, 0xe4, 0xb8, 0x58, 0x7f, 0xd0, 0xed, 0xf5, 0x9a, 0x0d, 0x39, 0x8e, 0x6e,
-	0x80, 0xdc, 0xeb, 0x2a, 0x03, 0xd6, 0x4f, 0xb5, 0xdd, 0xee, 0x7e, 0xd2, 0x6c, 0xc8, 0xbf, 0x4e,
-	0xbe, 0xd7, 0x81, 0x4c, 0x08, 0xcd, 0x69, 0x4a, 0xaa, 0x57, 0x07, 0xd5, 0x76, 0xf7, 0x89, 0xbc,
-	0x86, 0xd6, 0x21, 0xf3, 0xb4, 0xdf, 0xed, 0xa8, 0xfd, 0xfa, 0xc7, 0xcd, 0x83, 0xaa, 0x2c, 0xb1,
-	0x3f, 0x54, 0xb0, 0xfc, 0x25, 0xc7, 0xe8, 0x68, 0x1a, 0xdd, 0xfa, 0xe1, 0x41, 0xb3, 0x33, 0xe8,
-	0xab, 0xfb, 0xad, 0xef, 0x0d, 0x68, 0x4e, 0x8b, 0xef, 0xfd, 0x83, 0x04, 0x49, 0x5a, 0xc2, 0x9a,
-	0xf6, 0x18, 0x7d, 0x07, 0x60, 0x81, 0xd4, 0x48, 0xfc, 0x7d, 0x67, 0x05, 0xd0, 0x8b, 0x85, 0x55,
-	0x86, 0x08, 0x4c, 0x0d, 0x32, 0x21, 0x54, 0x46, 0x42, 0x70, 0x15, 0xe7, 0x8b, 0xb7, 0xcf, 0xe1,
-	0x08, 0x1b, 0x1f, 0x42, 0x82, 0x63, 0x17, 0xda, 0x5c, 0x46, 0x32, 0xae, 0xb9, 0x75, 0x1e, 0xbc,
-	0xed, 0x7d, 0x1f, 0xb2, 0xe1, 0xc8, 0xa3, 0xa7, 0x10, 0xe7, 0x1f, 0x77, 0x2e, 0xdc, 0xbe, 0xc5,
-	0xed, 0x8b, 0x57, 0xce, 0x3d, 0xe9, 0x03, 0xa9, 0xf6, 0xe8, 0xf3, 0xff, 0xd8, 0x5e, 0xfb, 0xfc,
-	0x97, 0xdb, 0xd2, 0x2f, 0x7e, 0xb9, 0x2d, 0x7d, 0xf6, 0x9f, 0xdb, 0xd2, 0xf7, 0xdf, 0x1d, 0x9b,
-	0xe4, 0x68, 0x36, 0xac, 0xe8, 0xce, 0x64, 0x17, 0x7b, 0x64, 0xa6, 0xb9, 0x73, 0xfe, 0x27, 0xc1,
-	0x95, 0xbf, 0x0d, 0x0e, 0x13, 0xac, 0xfd, 0xe1, 0xff, 0x05, 0x00, 0x00, 0xff, 0xff, 0x96, 0xc6,
-	0xe0, 0x87, 0x52, 0x28, 0x00, 0x00,
+	// 3670 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xd5, 0x5a, 0xcd, 0x8f, 0x23, 0x47,
+	0x15, 0x5f, 0x7f, 0xdb, 0xcf, 0x1f, 0xe3, 0xa9, 0xd9, 0x8f, 0x59, 0x93, 0xcc, 0x6c, 0x0c, 0x51,
+	0x36, 0xd9, 0xc4, 0x93, 0xcc, 0x8a, 0x90, 0x6c, 0xb4, 0x44, 0xfe, 0x9a, 0x5d, 0x6f, 0x3c, 0xb6,
+	0x69, 0x7b, 0x92, 0x6c, 0xa4, 0xc8, 0x6a, 0xdb, 0x6d, 0x4f, 0xef, 0x7a, 0xdc, 0xa6, 0xbb, 0x3d,
+	0xbb, 0x93, 0x0b, 0x08, 0x45, 0x42, 0x8a, 0x40, 0xca, 0x05, 0x25, 0xe2, 0x42, 0x40, 0x02, 0xf1,
+	0x2f, 0x00, 0x17, 0xa4, 0x5c, 0x72, 0x8c, 0x84, 0xb8, 0x20, 0x94, 0x08, 0x38, 0x24, 0x27, 0x90,
+	0x38, 0xa1, 0x70, 0xe1, 0xd5, 0xab, 0xea, 0x76, 0x7b, 0x3c, 0x3b, 0xe3, 0xd9, 0x84, 0x43, 0x0e,
+	0xb6, 0xba, 0xde, 0x57, 0x57, 0xbd, 0x7a, 0xf5, 0x7b, 0xaf, 0xaa, 0x1a, 0x1e, 0x19, 0x18, 0x1b,
+	0x63, 0xd3, 0xb0, 0x8d, 0xae, 0x31, 0xb4, 0x36, 0xfa, 0x43, 0xe3, 0x1e, 0xfd, 0xe5, 0x88, 0xc6,
+	0x82, 0xfc, 0x39, 0xb3, 0xd6, 0x31, 0x8d, 0xbb, 0x9a, 0xe9, 0xca, 0xb9, 0x0f, 0x42, 0x2a, 0x73,
+	0xa9, 0x6b, 0x8c, 0xac, 0xc9, 0xde, 0x31, 0x12, 0x67, 0x07, 0xc6, 0xc0, 0xa0, 0xc7, 0x0d, 0xfe,
+	0x24, 0xa9, 0xeb, 0x03, 0xc3, 0x18, 0x0c, 0x35, 0x21, 0xdc, 0x99, 0xf4, 0x37, 0x6c, 0x7d, 0x4f,
+	0xb3, 0x6c, 0x75, 0x6f, 0x2c, 0x04, 0xb2, 0xd7, 0x21, 0xd4, 0x1c, 0xea, 0x5d, 0x8d, 0x9d, 0x85,
+	0x50, 0x47, 0x1b, 0xe8, 0xa3, 0x55, 0xdf, 0x25, 0xdf, 0xe5, 0xa4, 0x22, 0x1a, 0x2c, 0x0d, 0x01,
+	0x6d, 0xd4, 0x5b, 0xf5, 0x13, 0x8d, 0x3f, 0x5e, 0x4b, 0x7c, 0xfc, 0xcb, 0xf5, 0x33, 0xef, 0xfe,
+	0x6a, 0xfd, 0xcc, 0xfb, 0xf8, 0xcb, 0x8e, 0x20, 0xb6, 0xb3, 0x53, 0x29, 0x35, 0x54, 0xd3, 0xb6,
+	0x18, 0x83, 0xe0, 0xc8, 0xe8, 0x69, 0x64, 0x21, 0xac, 0xd0, 0x33, 0x7b, 0x09, 0x42, 0xdd, 0xa1,
+	0xd1, 0xbd, 0x4b, 0x26, 0xc2, 0x85, 0xc7, 0xbf, 0xf8, 0x64, 0xfd, 0x31, 0xec, 0xdd, 0x40, 0x7d,
+	0x4b, 0xb3, 0x6d, 0x2d, 0xd7, 0xd3, 0xf6, 0x37, 0xba, 0x86, 0xa9, 0x6d, 0x60, 0xaf, 0x2c, 0x75,
+	0xa0, 0xe5, 0x8a, 0x5c, 0x58, 0x11, 0x3a, 0xd7, 0xd2, 0x9f, 0x7f, 0xb0, 0xee, 0x9b, 0x79, 0xdf,
+	0x9f, 0x7d, 0x00, 0x0d, 0xd3, 0xb8, 0xa3, 0x75, 0x6d, 0xdd, 0xa0, 0xee, 0x8d, 0x6d, 0x93, 0x5e,
+	0x18, 0x53, 0xf8, 0x23, 0x1f, 0x46, 0x5f, 0xd7, 0x86, 0xa2, 0xcb, 0x31, 0x45, 0x34, 0x58, 0x06,
+	0xa2, 0xda, 0xfd, 0x31, 0x8e, 0x53, 0xb7, 0x57, 0x03, 0xc8, 0x88, 0x2a, 0x6e, 0x9b, 0x5d, 0x86,
+	0xb4, 0x6e, 0xb5, 0xc7, 0x38, 0x02, 0x9d, 0xdb, 0x6c, 0xdf, 0xd5, 0x0e, 0x56, 0x83, 0x24, 0x93,
+	0xd2, 0xad, 0x86, 0x43, 0x7e, 0x45, 0x3b, 0x60, 0xdf, 0x82, 0x14, 0x97, 0x34, 0xf5, 0x3d, 0xd5,
+	0x3c, 0x20, 0xb9, 0x10, 0xc9, 0x25, 0x50, 0x4e, 0x10, 0xb9, 0xd4, 0x55, 0x88, 0xe9, 0xa3, 0xbe,
+	0x66, 0x6a, 0xa3, 0xae, 0xb6, 0x1a, 0x46, 0x81, 0xf8, 0xe6, 0x52, 0x8e, 0x26, 0xbc, 0xe2, 0x90,
+	0x0b, 0xc1, 0x8f, 0x3e, 0x59, 0x3f, 0xa3, 0x4c, 0xe5, 0xb2, 0x7f, 0x0d, 0x43, 0xcc, 0x65, 0xf3,
+	0x41, 0xd8, 0x07, 0x63, 0xcd, 0xc2, 0x81, 0x05, 0xf8, 0x20, 0xa8, 0xc1, 0x72, 0x10, 0xb6, 0x6c,
+	0x53, 0x1f, 0x0d, 0x68, 0x08, 0xf1, 0xcd, 0xf3, 0x87, 0xac, 0xe6, 0x9a, 0xc4, 0x55, 0xa4, 0x14,
+	0x59, 0xd1, 0xed, 0xa1, 0x46, 0xa3, 0xe1, 0x56, 0x78, 0x83, 0x5d, 0x82, 0x78, 0x4f, 0xb3, 0xba,
+	0xa6, 0x3e, 0xe6, 0xc3, 0xa2, 0x11, 0xc4, 0x14, 0x2f, 0x09, 0xa7, 0x2c, 0xd1, 0xd3, 0xfa, 0xea,
+	0x64, 0x68, 0xb7, 0xef, 0x58, 0x28, 0xc2, 0xc7, 0x10, 0x2b, 0x3c, 0x82, 0x33, 0xb7, 0x8a, 0xaf,
+	0x31, 0x7a, 0x68, 0x7b, 0x83, 0x33, 0x72, 0x8a, 0x7a, 0x6f, 0x5b, 0x4c, 0x9b, 0x12, 0x91, 0x1a,
+	0xec, 0x3c, 0x76, 0x52, 0xeb, 0x9a, 0x9a, 0xbd, 0x1a, 0x21, 0xdf, 0xc8, 0x16, 0xef, 0xbc, 0x76,
+	0x5f, 0xb7, 0x6c, 0x6b, 0x35, 0x8a, 0xf4, 0xd4, 0x7c, 0xe7, 0xcb, 0xc4, 0x55, 0xa4, 0x14, 0x7b,
+	0x0e, 0x22, 0x23, 0x1e, 0xef, 0x7a, 0x77, 0x35, 0x46, 0xa3, 0xbd, 0x70, 0x58, 0xa1, 0x26, 0xd8,
+	0x8a, 0x23, 0xc7, 0xae, 0x40, 0x48, 0x35, 0x4d, 0xf5, 0x60, 0x15, 0x48, 0xe1, 0xdc, 0x61, 0x85,
+	0x3c, 0x67, 0x2a, 0x42, 0x26, 0xf3, 0x0b, 0x1f, 0x84, 0x85, 0xbf, 0xd8, 0x63, 0x90, 0xc0, 0xd5,
+	0x65, 0x6b, 0x23, 0xbb, 0xcd, 0x1d, 0x4d, 0xde, 0x45, 0x97, 0x48, 0x5a, 0x0b, 0x49, 0x7c, 0x54,
+	0x7d, 0xc3, 0xdc, 0x53, 0x6d, 0xe9, 0x4b, 0xd9, 0x62, 0x4f, 0x42, 0xda, 0x51, 0x75, 0x5c, 0x43,
+	0xe3, 0x8e, 0x29, 0x4b, 0x92, 0x5e, 0x96, 0x64, 0xf6, 0x28, 0xc0, 0x9e, 0x7a, 0xbf, 0x3d, 0xd4,
+	0x46, 0x03, 0x7b, 0x97, 0x7c, 0x9a, 0x54, 0x62, 0x48, 0xa9, 0x12, 0xe1, 0x56, 0x30, 0xea, 0x4b,
+	0xfb, 0xf1, 0xdf, 0x9f, 0x0e, 0xe0, 0x7f, 0x28, 0x1d, 0xce, 0xfc, 0x00, 0x22, 0x72, 0x88, 0x6c,
+	0x1d, 0xe2, 0xbb, 0xaa, 0xd5, 0xde, 0xd3, 0x47, 0xfa, 0xde, 0x64, 0x8f, 0xc2, 0x3d, 0xaa, 0x00,
+	0x92, 0xb6, 0x05, 0x85, 0xad, 0x42, 0xc4, 0x61, 0xf2, 0xb8, 0xf7, 0x29, 0x4e, 0xd3, 0x55, 0x55,
+	0xef, 0x13, 0x37, 0x30, 0x55, 0x15, 0x14, 0x52, 0x95, 0xcc, 0xa0, 0x54, 0x15, 0xcd, 0xcc, 0xdb,
+	0x3e, 0x08, 0x91, 0xcf, 0xd8, 0x37, 0x20, 0x86, 0xf6, 0xda, 0xba, 0xad, 0xed, 0x59, 0x12, 0x1f,
+	0xa2, 0x48, 0xa8, 0xf0, 0x36, 0xcb, 0x42, 0x52, 0xbe, 0x41, 0x0a, 0xf8, 0xe9, 0x1d, 0x71, 0xf1,
+	0x0e, 0x21, 0xc3, 0x0d, 0xb8, 0xfc, 0x80, 0x34, 0xe0, 0x30, 0xd1, 0x33, 0x9c, 0xd1, 0x16, 0x21,
+	0x1f, 0xa4, 0x90, 0x8f, 0x71, 0x0a, 0x77, 0xbd, 0x95, 0x2d, 0x40, 0x58, 0xc4, 0x06, 0x8b, 0x43,
+	0xa4, 0x52, 0x7b, 0x35, 0x5f, 0xad, 0x94, 0xd2, 0x67, 0x58, 0x14, 0x82, 0xdb, 0x3b, 0xcd, 0x56,
+	0xda, 0xc7, 0x22, 0x10, 0xd8, 0xce, 0xdf, 0x4e, 0xfb, 0x59, 0x02, 0xa2, 0x95, 0xed, 0x46, 0xb5,
+	0x52, 0xac, 0xb4, 0xd2, 0x01, 0x06, 0x10, 0x2e, 0xe6, 0x6b, 0xb5, 0x7a, 0x2b, 0x1d, 0xcc, 0xde,
+	0x86, 0x78, 0x4d, 0xb3, 0xef, 0x19, 0xe6, 0xdd, 0x86, 0x61, 0x52, 0x90, 0x62, 0xd0, 0x74, 0x34,
+	0x53, 0x0e, 0x46, 0xb6, 0x38, 0x4c, 0x38, 0xa8, 0x2a, 0xf1, 0xc3, 0x6d, 0x73, 0x9d, 0xf1, 0xa4,
+	0x83, 0x90, 0x21, 0x7d, 0x28, 0x5b, 0xd9, 0x9f, 0x2f, 0x41, 0xaa, 0x68, 0x0c, 0x87, 0x02, 0x91,
+	0x9a, 0x63, 0xad, 0x8b, 0x1e, 0x09, 0x8e, 0xd4, 0x3d, 0x81, 0x83, 0xb1, 0x42, 0x0a, 0x17, 0x0e,
+	0x4c, 0x25, 0x14, 0xe2, 0xb1, 0x12, 0x2c, 0xdf, 0x33, 0x71, 0x90, 0x6d, 0xab, 0xbb, 0xab, 0xed,
+	0xa9, 0x62, 0xa5, 0x45, 0x17, 0x58, 0x69, 0x71, 0x52, 0x6b, 0x92, 0x16, 0x2b, 0x40, 0xda, 0xd4,
+	0xd4, 0xde, 0x8c, 0x91, 0xf8, 0x02, 0x46, 0x80, 0x6b, 0x49, 0x1b, 0x88, 0xa1, 0x1c, 0xca, 0x02,
+	0xe4, 0x77, 0xfe, 0xc8, 0x2e, 0x42, 0x74, 0x32, 0xd1, 0x7b, 0x6d, 0x0e, 0xad, 0x22, 0xde, 0x23,
+	0xbc, 0xdd, 0x40, 0x78, 0xc5, 0x80, 0x9f, 0x22, 0x25, 0x61, 0xab, 0x85, 0x10, 0xc2, 0x35, 0x97,
+	0x5c, 0xfa, 0x16, 0x91, 0xd9, 0x0b, 0x10, 0x1f, 0xbb, 0x48, 0x6d, 0x61, 0xc4, 0x07, 0x70, 0x51,
+	0xa6, 0xc5, 0xa2, 0x9c, 0x42, 0xb8, 0x84, 0x42, 0xaf, 0x28, 0xf7, 0x8d, 0xda, 0xbd, 0xdb, 0xc6,
+	0x08, 0x18, 0x0f, 0x55, 0x74, 0x11, 0x0d, 0x2b, 0xb2, 0x88, 0x6f, 0x50, 0xad, 0x25, 0xb5, 0xd0,
+	0x0a, 0x9b, 0x76, 0xd5, 0xb1, 0x25, 0xc1, 0xe4, 0x5c, 0xce, 0xcd, 0x9e, 0xb7, 0x8c, 0x89, 0x39,
+	0x52, 0x87, 0x7c, 0xe2, 0x94, 0x65, 0x57, 0xc1, 0xb5, 0xf2, 0x32, 0x40, 0x0f, 0xd7, 0xe0, 0xbe,
+	0x4a, 0x68, 0x99, 0x20, 0xed, 0x75, 0x31, 0x88, 0xd9, 0x59, 0xcf, 0x95, 0x5c, 0x31, 0xc5, 0xa3,
+	0x92, 0xf9, 0x02, 0x00, 0xa6, 0x2c, 0xd6, 0x82, 0x14, 0x22, 0xc3, 0x08, 0xf5, 0x0c, 0x53, 0xc0,
+	0x8d, 0x8f, 0xf0, 0xf0, 0x99, 0x13, 0x6c, 0x22, 0x47, 0x6a, 0xf1, 0x55, 0xa1, 0x24, 0xbb, 0xde,
+	0x26, 0x7b, 0x11, 0x38, 0x5c, 0xf5, 0xf5, 0x81, 0xf0, 0x95, 0x7f, 0x01, 0x5f, 0x85, 0x85, 0x02,
+	0xab, 0x03, 0xd8, 0xa6, 0x3a, 0xb2, 0x38, 0xa2, 0x59, 0x14, 0x05, 0xf1, 0xcd, 0x27, 0x4f, 0xea,
+	0x4c, 0xcb, 0xd1, 0x90, 0xd3, 0xe7, 0x31, 0xc1, 0x5e, 0x83, 0x65, 0x6b, 0x77, 0xd2, 0xef, 0x0f,
+	0x35, 0x9e, 0x22, 0x3d, 0xab, 0x3a, 0xb5, 0x79, 0xe5, 0x24, 0xbb, 0x4d, 0xa1, 0x48, 0x43, 0x5c,
+	0x92, 0x56, 0x30, 0xa7, 0x12, 0x10, 0xb0, 0x6b, 0x90, 0xb2, 0x76, 0x55, 0xb3, 0x37, 0x9d, 0xcc,
+	0x10, 0x4d, 0xc7, 0x4a, 0xce, 0x29, 0x8e, 0x50, 0x1f, 0xf9, 0x34, 0x95, 0x49, 0x12, 0x75, 0xa7,
+	0xb1, 0x02, 0xe7, 0x4c, 0xad, 0x6b, 0xec, 0x6b, 0x98, 0xb8, 0x87, 0xc6, 0x60, 0x6a, 0x22, 0x7c,
+	0x5c, 0x3c, 0xac, 0x38, 0x3a, 0x55, 0x63, 0xe0, 0x9a, 0x7a, 0x1e, 0x92, 0x23, 0x81, 0x25, 0xed,
+	0x31, 0x82, 0x89, 0x85, 0x91, 0xc9, 0x7d, 0xb6, 0x2c, 0xc6, 0xe6, 0x81, 0x19, 0x25, 0x31, 0x9a,
+	0x36, 0xac, 0xcc, 0x87, 0x21, 0x88, 0xb9, 0x7e, 0xc3, 0xa4, 0xe3, 0xc5, 0x88, 0x24, 0x4e, 0xd5,
+	0x94, 0x29, 0x21, 0xe2, 0x1a, 0x40, 0xd7, 0xf5, 0x14, 0xcd, 0x69, 0x7c, 0xf3, 0xec, 0x51, 0x1e,
+	0x74, 0x26, 0x61, 0x2a, 0xcd, 0xaa, 0xde, 0xe0, 0xb7, 0xb4, 0x21, 0x85, 0x8a, 0xac, 0x1b, 0x2e,
+	0x4c, 0x07, 0x5b, 0x55, 0x3b, 0xda, 0xb0, 0x29, 0xd9, 0xd2, 0xcc, 0x74, 0x11, 0x38, 0x0c, 0x81,
+	0x8b, 0xba, 0x81, 0xc0, 0x23, 0x4a, 0xa3, 0xa4, 0xe2, 0xb6, 0x39, 0xfc, 0x73, 0x68, 0x27, 0x18,
+	0x32, 0xb9, 0x47, 0x53, 0x24, 0x10, 0x47, 0xa2, 0x82, 0x34, 0x85, 0xbb, 0xec, 0x69, 0x60, 0xc4,
+	0xef, 0x69, 0x43, 0xf5, 0x00, 0xbb, 0x83, 0xf3, 0x45, 0xb8, 0xc1, 0x05, 0x09, 0xc0, 0x4a, 0x9c,
+	0xd1, 0x14, 0x74, 0x9e, 0xb2, 0x3c, 0x01, 0x44, 0xc0, 0x11, 0x53, 0x60, 0x1a, 0x0d, 0x6c, 0x07,
+	0x32, 0x8e, 0xc0, 0x50, 0xdd, 0xeb, 0xf4, 0xd4, 0xb6, 0x37, 0xf8, 0x17, 0x01, 0x8a, 0x15, 0xa9,
+	0x5f, 0x25, 0xf5, 0xa2, 0x58, 0x09, 0x5b, 0xc0, 0x8e, 0x30, 0xb7, 0x08, 0x26, 0x27, 0x86, 0x5e,
+	0x3b, 0x98, 0xec, 0x68, 0xb4, 0xc6, 0x68, 0x78, 0x40, 0x78, 0x83, 0xd5, 0x26, 0x27, 0xd4, 0xb1,
+	0x8d, 0x75, 0xd0, 0xca, 0x1d, 0x11, 0x61, 0xc2, 0x65, 0x16, 0xf6, 0x43, 0xbf, 0x4f, 0x25, 0x4b,
+	0x4c, 0x59, 0x96, 0x2c, 0xee, 0xb8, 0x26, 0x31, 0x70, 0x65, 0xc3, 0xc8, 0xb0, 0xdb, 0x1d, 0x0d,
+	0x03, 0x43, 0x23, 0x6c, 0x8f, 0x6f, 0x66, 0x72, 0xa2, 0xaa, 0xcf, 0x39, 0x55, 0x7d, 0xae, 0xe5,
+	0x54, 0xf5, 0x4a, 0x0c, 0xa5, 0x0b, 0x24, 0xcc, 0xbe, 0x03, 0xbc, 0xd1, 0x56, 0xfb, 0x36, 0x26,
+	0xba, 0xc4, 0x89, 0x9a, 0x51, 0x14, 0xce, 0x73, 0x59, 0x3e, 0xdd, 0x1d, 0x44, 0xd2, 0xbe, 0x3e,
+	0x1c, 0xae, 0x26, 0xc5, 0x74, 0x3b, 0xed, 0xec, 0x9b, 0x90, 0x9c, 0x41, 0x22, 0x14, 0x3e, 0x2f,
+	0x93, 0x72, 0xbb, 0x58, 0xaf, 0xd5, 0xca, 0xc5, 0x56, 0x5d, 0x69, 0xb7, 0x6e, 0x37, 0xca, 0x98,
+	0xa3, 0x31, 0x05, 0x37, 0xbf, 0x57, 0xad, 0xb4, 0xca, 0x98, 0xa5, 0x53, 0x00, 0x9c, 0xda, 0x2c,
+	0x2a, 0x95, 0x46, 0x0b, 0x93, 0x75, 0x0c, 0x42, 0x95, 0xed, 0xfc, 0x8d, 0x32, 0x66, 0x6a, 0x7c,
+	0xac, 0xd6, 0x8b, 0xf9, 0x2a, 0x26, 0xea, 0x6d, 0x88, 0x7b, 0x30, 0x00, 0x8b, 0x93, 0xb3, 0x8e,
+	0xf1, 0xe6, 0xcd, 0x9d, 0xad, 0xad, 0x6a, 0xd9, 0x31, 0x8d, 0xb5, 0x40, 0xa1, 0x5e, 0xaf, 0x96,
+	0xf3, 0x35, 0xb4, 0x4d, 0x85, 0x41, 0xab, 0x7c, 0xa3, 0xac, 0xa0, 0x61, 0xfe, 0xd2, 0x96, 0x52,
+	0xa9, 0xdd, 0x48, 0x07, 0xb2, 0xef, 0xf9, 0x21, 0x45, 0xe9, 0x48, 0x84, 0x32, 0x5f, 0x19, 0xb8,
+	0x49, 0xc1, 0xa8, 0x72, 0x4a, 0x6b, 0x7a, 0xe6, 0xb9, 0x7d, 0x5f, 0x1d, 0x4e, 0x34, 0x5e, 0xbb,
+	0x70, 0xaa, 0x6c, 0x71, 0x47, 0xf4, 0x8c, 0x2e, 0x22, 0xcb, 0xc8, 0x96, 0x55, 0xa1, 0xdb, 0x66,
+	0xfb, 0x70, 0x8e, 0xf2, 0x9f, 0x37, 0x58, 0xb0, 0x08, 0x1a, 0x13, 0xd4, 0xc5, 0x37, 0x9f, 0x16,
+	0x0b, 0x75, 0xf6, 0xe5, 0xa2, 0x29, 0xc2, 0xe4, 0x16, 0xca, 0x6f, 0xab, 0xe3, 0xf2, 0xc8, 0x36,
+	0x0f, 0x0a, 0x8f, 0xbc, 0xf3, 0xe9, 0x71, 0x69, 0xad, 0x3f, 0x55, 0xcb, 0x94, 0xe1, 0xc2, 0x03,
+	0xac, 0x38, 0x99, 0x5c, 0xee, 0x86, 0x78, 0x26, 0xc7, 0x2d, 0x00, 0x0d, 0xc5, 0xd9, 0x0d, 0x51,
+	0xe3, 0x9a, 0xff, 0x05, 0x5f, 0xf6, 0x3f, 0x21, 0x88, 0x17, 0xd5, 0xb1, 0x3d, 0x31, 0x35, 0xaa,
+	0x59, 0xd6, 0x67, 0xf0, 0x28, 0x8e, 0xe1, 0x1e, 0x91, 0x6c, 0x89, 0x46, 0x5b, 0x73, 0x89, 0xcb,
+	0x4f, 0x89, 0xcb, 0x49, 0x86, 0x53, 0x5b, 0xa7, 0x4a, 0x55, 0x81, 0x53, 0xa4, 0xaa, 0x6f, 0x63,
+	0x5c, 0xea, 0x23, 0x2e, 0x62, 0x49, 0x2f, 0x5f, 0x9c, 0x7f, 0x79, 0x41, 0x48, 0x28, 0xae, 0x28,
+	0xaf, 0x59, 0x74, 0xac, 0xc5, 0x4d, 0x1c, 0xfc, 0x21, 0xec, 0x59, 0x72, 0xe8, 0x0e, 0xf4, 0xcc,
+	0xa7, 0x98, 0xf0, 0x97, 0x4f, 0x31, 0x91, 0x2f, 0x9f, 0x62, 0xa2, 0x8b, 0xa5, 0x98, 0x7f, 0xf9,
+	0x70, 0x55, 0x88, 0x61, 0xb3, 0x5b, 0x70, 0xd6, 0xd4, 0x2c, 0x7c, 0x53, 0x57, 0x9b, 0xc1, 0x33,
+	0xdf, 0x02, 0x0e, 0x4f, 0x39, 0x9a, 0x12, 0xd1, 0xbe, 0x09, 0x49, 0xd7, 0xd6, 0x58, 0xc5, 0xed,
+	0x8b, 0x58, 0x26, 0x09, 0x87, 0xd8, 0x40, 0xda, 0xa1, 0x74, 0x15, 0x38, 0x55, 0xba, 0xf2, 0x22,
+	0x4e, 0x70, 0x16, 0x71, 0x38, 0x9c, 0x22, 0x40, 0xd9, 0x9a, 0xbb, 0xe1, 0xc6, 0x55, 0x48, 0x04,
+	0x4c, 0x05, 0xd9, 0xcd, 0xc3, 0x70, 0x34, 0xb3, 0x47, 0x70, 0x31, 0x26, 0x32, 0xc5, 0x98, 0x68,
+	0xf6, 0xbf, 0x00, 0x2b, 0xdb, 0xa8, 0x6f, 0xea, 0xea, 0x50, 0x7f, 0x4b, 0x75, 0xcb, 0xf6, 0x27,
+	0x66, 0x96, 0xc0, 0x0a, 0x7a, 0x68, 0xe9, 0x90, 0x98, 0x5c, 0x0a, 0xb5, 0x07, 0x2c, 0x85, 0x27,
+	0xc4, 0x68, 0x8f, 0xb0, 0xfd, 0x7f, 0x5b, 0x12, 0xd7, 0xe7, 0x96, 0xc4, 0x63, 0x0f, 0xee, 0xc4,
+	0xfc, 0xd2, 0xf8, 0x9a, 0x97, 0x54, 0xff, 0x0e, 0x7f, 0x4d, 0xe3, 0xfd, 0xe8, 0xf2, 0x2c, 0xf2,
+	0x15, 0x94, 0x67, 0xb1, 0x87, 0x28, 0xcf, 0x8a, 0xb0, 0x24, 0x52, 0x99, 0xe5, 0x24, 0x2a, 0x5a,
+	0x84, 0xee, 0x70, 0x66, 0x93, 0x98, 0xec, 0x47, 0xaa, 0x3f, 0x9b, 0x57, 0xd1, 0x67, 0x58, 0xde,
+	0xd9, 0x6a, 0x7b, 0x32, 0xee, 0xa1, 0x51, 0xcb, 0x39, 0x1b, 0x23, 0xe2, 0x8e, 0xa0, 0xb1, 0x0e,
+	0xb0, 0x9e, 0x36, 0xc6, 0x10, 0xc0, 0x16, 0xbe, 0x4e, 0x64, 0x7a, 0x19, 0x30, 0x57, 0x4f, 0x0c,
+	0x5c, 0xdc, 0x29, 0x38, 0xba, 0xb2, 0x48, 0x50, 0x96, 0x7b, 0x87, 0x49, 0x0f, 0xaa, 0xb0, 0xa2,
+	0x8b, 0x55, 0x58, 0xf0, 0xd0, 0x15, 0x56, 0xfc, 0x21, 0x2b, 0xac, 0xc4, 0x71, 0x78, 0x97, 0x9c,
+	0xc5, 0xbb, 0xcc, 0x0f, 0x7d, 0xb0, 0x3c, 0xe7, 0x05, 0x7e, 0x82, 0x32, 0x30, 0x8d, 0xc9, 0xb8,
+	0x3d, 0xc5, 0x2f, 0x25, 0x46, 0x94, 0x1a, 0xc7, 0xab, 0xaf, 0x74, 0x33, 0x90, 0xbd, 0xbe, 0x18,
+	0xe4, 0x46, 0xa7, 0x90, 0x1b, 0xe3, 0x8f, 0xa5, 0xf2, 0x2b, 0xf9, 0xad, 0x34, 0x64, 0xff, 0x19,
+	0x83, 0x70, 0x3d, 0x3f, 0xb1, 0x77, 0x37, 0xe5, 0x71, 0xcb, 0xbe, 0xde, 0xd3, 0x9c, 0x23, 0x5c,
+	0xb7, 0xcd, 0x9e, 0x82, 0x65, 0x15, 0x85, 0xda, 0x13, 0x73, 0x38, 0x45, 0x16, 0x51, 0xc5, 0x2c,
+	0x71, 0xc6, 0x8e, 0x39, 0x74, 0xe1, 0xe3, 0x45, 0xb8, 0xa8, 0x76, 0xbb, 0xb8, 0x96, 0xdb, 0xb6,
+	0x71, 0x57, 0x1b, 0xcd, 0xea, 0x88, 0xba, 0xed, 0xbc, 0x10, 0x68, 0x71, 0xbe, 0x57, 0x15, 0x83,
+	0x65, 0x46, 0x75, 0x4f, 0xb3, 0x77, 0x8d, 0x9e, 0x38, 0x43, 0x51, 0x96, 0x3d, 0x4a, 0xdb, 0xc4,
+	0xa0, 0x6e, 0x79, 0xe5, 0x3b, 0x46, 0xef, 0x40, 0x9e, 0x91, 0x2c, 0x79, 0xa4, 0x0b, 0x48, 0x66,
+	0x6f, 0xfb, 0xe0, 0xd1, 0x19, 0xe1, 0x5d, 0x0c, 0x3a, 0xcd, 0xb4, 0xa6, 0xa5, 0x62, 0x88, 0x60,
+	0x4e, 0xee, 0x8a, 0x85, 0x53, 0x72, 0xf9, 0xa9, 0x95, 0x9b, 0x42, 0xfe, 0x14, 0x95, 0x22, 0x53,
+	0xe7, 0xb4, 0xd9, 0x3b, 0x3e, 0x58, 0x9b, 0xe9, 0x06, 0xa2, 0xd8, 0x18, 0xd1, 0x5d, 0x9b, 0xf6,
+	0x23, 0xec, 0x2d, 0x59, 0xe7, 0xfb, 0xa1, 0x48, 0x85, 0x53, 0x74, 0xc4, 0xeb, 0x6f, 0x47, 0x1d,
+	0x55, 0xd9, 0x4b, 0x90, 0x31, 0xb5, 0x3e, 0xf6, 0x60, 0xf7, 0xa8, 0xb9, 0x12, 0x47, 0xa7, 0x17,
+	0xa4, 0xc4, 0xdc, 0x64, 0x3d, 0xcb, 0x21, 0xde, 0xab, 0x2c, 0x67, 0x2b, 0x41, 0x6a, 0xcc, 0xab,
+	0x26, 0xa7, 0x8b, 0x36, 0x9e, 0x5e, 0x0d, 0x9a, 0x2f, 0x01, 0x05, 0x69, 0xaf, 0x3c, 0x4d, 0xd8,
+	0x8f, 0xd1, 0x53, 0xb3, 0xe2, 0x73, 0x33, 0x16, 0x3b, 0xc2, 0x53, 0x8a, 0xc7, 0xce, 0xe9, 0xa7,
+	0x6c, 0xc5, 0x9c, 0x57, 0x67, 0x3f, 0xf5, 0xc1, 0xfa, 0x6c, 0x4f, 0xe6, 0x27, 0x0d, 0xa8, 0x2b,
+	0xcf, 0x3c, 0xb0, 0x2b, 0x0f, 0x31, 0x6b, 0x33, 0x9e, 0xf7, 0x4c, 0x5b, 0xa6, 0x0a, 0x6b, 0xc7,
+	0xc7, 0xe5, 0x69, 0xf6, 0x1e, 0x99, 0x6d, 0x58, 0x3f, 0x21, 0xba, 0x4e, 0x6b, 0xee, 0x84, 0x29,
+	0x38, 0x95, 0xb9, 0x1a, 0x5c, 0x3a, 0xc9, 0x8d, 0xa7, 0xda, 0x69, 0xfd, 0x2e, 0x00, 0xd1, 0x16,
+	0x66, 0x00, 0xaa, 0x31, 0x99, 0xb7, 0xc6, 0x94, 0xe5, 0xe4, 0x06, 0x84, 0x2c, 0x5b, 0x1b, 0x8b,
+	0xcd, 0x27, 0xaf, 0xbd, 0x68, 0x46, 0x1d, 0x95, 0x5c, 0x13, 0x79, 0x12, 0x8d, 0x85, 0x5c, 0xe6,
+	0x33, 0x3f, 0x04, 0x39, 0x15, 0x37, 0x44, 0x31, 0x4e, 0xf1, 0x9e, 0x23, 0xae, 0x1e, 0xa1, 0x9d,
+	0xa3, 0xa2, 0x33, 0xca, 0x45, 0x09, 0xb0, 0x31, 0x5d, 0x90, 0x1a, 0x66, 0x55, 0xed, 0xbe, 0xbc,
+	0xdb, 0x23, 0x43, 0x15, 0x4e, 0x38, 0x7c, 0x43, 0x14, 0x98, 0xbf, 0x21, 0x72, 0x0c, 0x58, 0x5d,
+	0x63, 0xec, 0x5c, 0x2f, 0x91, 0x81, 0x26, 0x27, 0x20, 0xa8, 0x7a, 0x2b, 0xa3, 0xd0, 0x91, 0xa7,
+	0xe0, 0xde, 0x6a, 0xe8, 0x45, 0x2c, 0x1d, 0x8c, 0xae, 0x5c, 0x65, 0xfb, 0x5a, 0x57, 0x1c, 0xf9,
+	0x9c, 0x50, 0xb3, 0x05, 0xb9, 0x0a, 0xd6, 0xbf, 0xe0, 0x66, 0x28, 0x6b, 0xb1, 0x02, 0xca, 0xa3,
+	0x90, 0x5d, 0x83, 0x20, 0x79, 0x04, 0x20, 0x5c, 0xa9, 0xdd, 0x28, 0x37, 0x5b, 0xe2, 0xd0, 0xe2,
+	0xd5, 0xb2, 0x52, 0xd9, 0xba, 0x9d, 0xf6, 0x65, 0xdf, 0xf3, 0x41, 0x4c, 0x51, 0x47, 0x03, 0xb1,
+	0x47, 0xc6, 0xcc, 0xcc, 0x4f, 0x34, 0xc5, 0x35, 0x29, 0x77, 0x5b, 0x44, 0x89, 0x22, 0xa1, 0x40,
+	0x37, 0xa5, 0x17, 0x20, 0xc2, 0x99, 0xfc, 0xb6, 0x34, 0x40, 0xac, 0x30, 0x36, 0xcb, 0xa3, 0x1e,
+	0xaf, 0xc0, 0xcc, 0x36, 0xdd, 0x67, 0x4a, 0xcd, 0x20, 0xb1, 0xe3, 0x26, 0x5d, 0x75, 0x0a, 0xe5,
+	0x35, 0x88, 0x3b, 0x32, 0xdc, 0x40, 0x88, 0x24, 0x62, 0x42, 0x02, 0x6d, 0x5c, 0x4b, 0xbf, 0xff,
+	0xc1, 0xfa, 0x99, 0x43, 0x17, 0xaf, 0x29, 0x37, 0x0b, 0x37, 0x79, 0x75, 0xc0, 0xaf, 0xed, 0x44,
+	0xe9, 0xd5, 0x5b, 0xbc, 0xf0, 0x8d, 0x48, 0x0d, 0x7e, 0xe6, 0x86, 0xc5, 0xfe, 0x80, 0xca, 0xdd,
+	0xee, 0xae, 0xbc, 0xc2, 0x01, 0x22, 0x35, 0x38, 0x25, 0xfb, 0x1b, 0xf4, 0x44, 0x53, 0x33, 0x1b,
+	0xc6, 0x50, 0xef, 0x1e, 0x70, 0x5c, 0xb5, 0x6c, 0xdc, 0xfb, 0x98, 0x93, 0x11, 0x2f, 0x44, 0x64,
+	0x05, 0x24, 0x2e, 0x53, 0xd2, 0xc8, 0x69, 0x49, 0x86, 0xa8, 0x76, 0x30, 0x3f, 0x8f, 0x30, 0x1e,
+	0xb1, 0x63, 0x46, 0xe7, 0xce, 0x61, 0x25, 0x11, 0x7e, 0xe7, 0x85, 0x40, 0xbd, 0x73, 0x67, 0x56,
+	0x15, 0x21, 0x9f, 0xee, 0xeb, 0x0e, 0x6b, 0x89, 0x3b, 0x24, 0x46, 0xbc, 0x19, 0x8d, 0xec, 0xaf,
+	0xfd, 0x00, 0xe5, 0xfb, 0xb6, 0xa9, 0x76, 0xed, 0x7c, 0xa3, 0x92, 0xf9, 0x83, 0x0f, 0xc2, 0xb2,
+	0xaa, 0xf7, 0x5e, 0x6b, 0xf8, 0x66, 0xaf, 0x35, 0xae, 0x43, 0xdc, 0x7b, 0x85, 0xb2, 0xc8, 0xf9,
+	0x39, 0x08, 0x05, 0x0e, 0x0b, 0x7c, 0x3d, 0x88, 0x02, 0x1a, 0x4d, 0x5b, 0xf2, 0x26, 0x25, 0x46,
+	0x14, 0x34, 0x3e, 0x77, 0x13, 0x12, 0x5c, 0xf8, 0x26, 0x24, 0x7b, 0x1b, 0x82, 0x45, 0x7e, 0x8b,
+	0x3e, 0x53, 0x62, 0x25, 0x21, 0x56, 0xac, 0xd7, 0xb6, 0x2a, 0x37, 0x76, 0x94, 0xb2, 0x38, 0xfc,
+	0x2a, 0xbf, 0xde, 0x52, 0xf2, 0x45, 0x7e, 0xaa, 0xc6, 0x20, 0x25, 0x1b, 0xe5, 0x52, 0x9b, 0xdf,
+	0xcc, 0xa7, 0x03, 0x08, 0x4d, 0xe9, 0x29, 0x6d, 0xab, 0x52, 0xae, 0x96, 0x9a, 0xe9, 0x60, 0xf6,
+	0x33, 0x1f, 0x44, 0x0b, 0x13, 0x7d, 0xd8, 0xe3, 0x5e, 0xfa, 0xcb, 0x8c, 0x97, 0x3a, 0x9c, 0xdc,
+	0xd6, 0x7b, 0x8e, 0x97, 0xa8, 0x5d, 0xe9, 0x4d, 0x59, 0xbd, 0x8e, 0x84, 0x39, 0xc1, 0x2a, 0x75,
+	0xe8, 0xda, 0x97, 0xb6, 0x46, 0x12, 0x2e, 0x64, 0x8b, 0x6d, 0xa2, 0x63, 0xc5, 0x3e, 0x8a, 0x30,
+	0x2a, 0x48, 0x18, 0xb5, 0xec, 0xec, 0x92, 0xdc, 0x0b, 0x56, 0xf4, 0x26, 0x49, 0xd1, 0x62, 0xbc,
+	0x02, 0xcb, 0xd3, 0xed, 0xb5, 0xdc, 0xef, 0xc9, 0x8d, 0x7f, 0xda, 0x65, 0xc8, 0x4d, 0x21, 0xbf,
+	0xbc, 0x95, 0x0e, 0x6b, 0x9b, 0x86, 0x61, 0x8b, 0xcb, 0x6a, 0xd7, 0x89, 0x0a, 0x92, 0xb2, 0x2b,
+	0xb0, 0x8c, 0x20, 0xae, 0xd9, 0xb4, 0x4c, 0x14, 0xed, 0xfb, 0x13, 0x0c, 0xb5, 0xec, 0x59, 0x60,
+	0x5e, 0xa2, 0xc0, 0x78, 0xac, 0x6d, 0x59, 0xbe, 0xb7, 0xaf, 0x8e, 0xb0, 0x27, 0x58, 0xb5, 0x4b,
+	0x59, 0xf6, 0x04, 0x2c, 0xa9, 0x82, 0xea, 0x9e, 0x1f, 0x71, 0xcf, 0x04, 0x95, 0x94, 0x24, 0xcb,
+	0xe3, 0xa3, 0xec, 0x39, 0x58, 0x99, 0x51, 0x97, 0x56, 0x5f, 0x80, 0x44, 0x09, 0xc1, 0x2a, 0x3f,
+	0xea, 0x15, 0x0e, 0xf8, 0x2e, 0x08, 0x93, 0x00, 0x07, 0x2f, 0xb9, 0x5e, 0x04, 0x90, 0xf1, 0xcf,
+	0x2f, 0x38, 0x93, 0x1c, 0x1b, 0x54, 0x44, 0x23, 0xfb, 0x33, 0x1f, 0x24, 0x2b, 0x08, 0x3f, 0x96,
+	0xed, 0xf4, 0x65, 0x16, 0x5b, 0x7d, 0x27, 0x62, 0xab, 0x77, 0x3a, 0xfd, 0xb3, 0xd3, 0x39, 0x07,
+	0xbb, 0x81, 0x45, 0x61, 0x37, 0xfb, 0x27, 0x3f, 0xa4, 0x9c, 0x7e, 0x89, 0x41, 0xb2, 0xdf, 0xfb,
+	0xa6, 0xfb, 0x2e, 0x71, 0xb3, 0xc9, 0x8b, 0x27, 0x71, 0xce, 0xea, 0xd6, 0xb8, 0xb3, 0x3a, 0xce,
+	0xc1, 0xc0, 0x6b, 0x5c, 0x9c, 0xa7, 0x6d, 0x4b, 0x14, 0x29, 0x6f, 0xfe, 0xe8, 0xd3, 0xf5, 0x2b,
+	0x47, 0x7d, 0x24, 0x72, 0xe8, 0x23, 0x19, 0x47, 0x1f, 0x6b, 0x9a, 0xa7, 0x16, 0x11, 0xaf, 0xf7,
+	0xfb, 0x38, 0xe3, 0xee, 0x1e, 0x70, 0xfa, 0x5a, 0xf6, 0x5d, 0x48, 0x38, 0x7d, 0xd7, 0xec, 0x6e,
+	0x4f, 0x5e, 0xb6, 0x78, 0x8e, 0x30, 0x44, 0x51, 0x91, 0x2b, 0x23, 0xd3, 0x59, 0xa7, 0x52, 0x81,
+	0x93, 0x32, 0x25, 0x38, 0x7f, 0xf4, 0x58, 0x4e, 0xaa, 0x14, 0x02, 0xde, 0x4a, 0xe1, 0xb7, 0x7e,
+	0xb8, 0xd0, 0x52, 0xad, 0xbb, 0xce, 0x81, 0x87, 0x69, 0xdc, 0x3f, 0x70, 0xe6, 0xfd, 0x79, 0x08,
+	0x62, 0x6a, 0x15, 0x33, 0x1e, 0xdf, 0xcc, 0xca, 0x2c, 0x7f, 0xb4, 0x70, 0xae, 0x8e, 0x92, 0x0a,
+	0xc9, 0x53, 0xac, 0xa9, 0xb6, 0x4a, 0x2f, 0x4b, 0x28, 0xf4, 0x9c, 0xf9, 0xa3, 0x0f, 0x82, 0x5c,
+	0x84, 0x5d, 0x86, 0xb0, 0xa8, 0x70, 0xa5, 0xd9, 0xf4, 0xe1, 0x01, 0x2b, 0x92, 0x8f, 0x5b, 0xc8,
+	0xa8, 0x38, 0x28, 0x72, 0xc2, 0xa8, 0xf0, 0x1c, 0x86, 0xc9, 0x33, 0x47, 0x79, 0x7d, 0xee, 0x4b,
+	0x25, 0x71, 0x84, 0x54, 0x29, 0x29, 0x11, 0x32, 0x51, 0xa1, 0x6c, 0x63, 0xab, 0x98, 0x5a, 0x6c,
+	0x3a, 0xee, 0x91, 0x60, 0x0e, 0x82, 0x44, 0x17, 0xf4, 0x28, 0xd0, 0x1d, 0xea, 0xfc, 0xb3, 0x0a,
+	0xb5, 0xd7, 0x73, 0x2e, 0xa1, 0x41, 0x90, 0xf2, 0x48, 0xc9, 0xfe, 0x24, 0x00, 0xab, 0xf3, 0xa3,
+	0x97, 0xa1, 0xd8, 0x80, 0x24, 0x1f, 0xbb, 0x5b, 0x33, 0xcb, 0xd1, 0x5d, 0x79, 0x90, 0xd3, 0x64,
+	0x34, 0x92, 0xd7, 0x64, 0x43, 0x49, 0x18, 0x9e, 0xd6, 0x91, 0x5e, 0xbc, 0x07, 0x09, 0xaf, 0x06,
+	0xe6, 0x90, 0x30, 0xdf, 0xa6, 0x4f, 0x2c, 0x59, 0x89, 0x3d, 0x7e, 0xc2, 0xeb, 0x9a, 0x24, 0xac,
+	0x48, 0x25, 0xcf, 0x5c, 0xf8, 0x8f, 0x9f, 0x8b, 0xec, 0xbb, 0xf4, 0xe9, 0x0a, 0x29, 0x85, 0xc1,
+	0x5f, 0x7f, 0x05, 0x53, 0xc2, 0x0a, 0x2c, 0x35, 0x6f, 0xe6, 0x95, 0x52, 0xbb, 0x56, 0x6f, 0xb5,
+	0xb7, 0xea, 0x3b, 0xb5, 0x12, 0x26, 0x06, 0xc4, 0xfd, 0x5a, 0xbd, 0x2d, 0xe8, 0x0d, 0x05, 0x77,
+	0xe5, 0x0a, 0xff, 0x48, 0xe2, 0x1c, 0x2c, 0x73, 0xa1, 0x59, 0x72, 0x80, 0x27, 0x0e, 0x7e, 0x85,
+	0xa2, 0xd4, 0xf2, 0xd5, 0x76, 0x59, 0x51, 0xea, 0x4a, 0x3a, 0xc8, 0x96, 0x21, 0x29, 0xc4, 0x9a,
+	0xad, 0x7a, 0xa3, 0x51, 0x2e, 0xa5, 0x43, 0xa8, 0x9d, 0x6e, 0xd4, 0x95, 0x16, 0xbd, 0x27, 0x5f,
+	0xad, 0xd6, 0x5f, 0x43, 0xea, 0xe7, 0x91, 0xa7, 0x6a, 0x10, 0xf7, 0xa0, 0x39, 0x4f, 0x49, 0xc5,
+	0x7c, 0x2b, 0x5f, 0xad, 0xdf, 0xc0, 0xbe, 0x2d, 0x41, 0xfc, 0x56, 0xb3, 0x5e, 0x6b, 0x37, 0x8b,
+	0x37, 0xcb, 0xdb, 0x79, 0xec, 0x17, 0xff, 0x30, 0x83, 0xf2, 0x17, 0xbe, 0x02, 0x7b, 0x53, 0xaa,
+	0x17, 0x77, 0xb6, 0xcb, 0xb5, 0x56, 0x13, 0x73, 0xd3, 0xeb, 0x2d, 0x9e, 0xd3, 0x42, 0x9b, 0x1f,
+	0xfa, 0x20, 0xc2, 0x4b, 0x58, 0x7e, 0xb0, 0xf7, 0x32, 0xc0, 0x14, 0xa9, 0x99, 0xfc, 0x0c, 0x68,
+	0x0e, 0xd0, 0x33, 0xab, 0xf3, 0x0c, 0x39, 0x31, 0x05, 0x88, 0x7b, 0x50, 0x99, 0x49, 0xc1, 0x79,
+	0x9c, 0xcf, 0x5c, 0x3c, 0x82, 0x23, 0x6d, 0x5c, 0xc5, 0x02, 0x91, 0xb0, 0x8b, 0xad, 0xcc, 0x22,
+	0x99, 0xd0, 0x3c, 0x7b, 0x14, 0xbc, 0x6d, 0xbe, 0x01, 0x09, 0xef, 0xcc, 0xb3, 0x5b, 0x10, 0x12,
+	0x0f, 0x8f, 0x1e, 0xbb, 0x7c, 0x33, 0x6b, 0xc7, 0x47, 0xce, 0x65, 0xdf, 0xb3, 0xbe, 0xc2, 0x4b,
+	0x1f, 0xfd, 0x6d, 0xed, 0xcc, 0x47, 0x7f, 0x5f, 0xf3, 0x7d, 0x8c, 0xbf, 0x0f, 0xfe, 0xb1, 0xe6,
+	0x7b, 0xe3, 0xc9, 0x81, 0x6e, 0xef, 0x4e, 0x3a, 0xb9, 0xae, 0xb1, 0xb7, 0x81, 0x56, 0x26, 0xaa,
+	0x79, 0x20, 0x3e, 0x36, 0x9c, 0xfb, 0xfc, 0xb0, 0x13, 0xa6, 0xf6, 0xd5, 0xff, 0x01, 0xe2, 0xc4,
+	0x69, 0x9c, 0x9a, 0x28, 0x00, 0x00,
 }
 
 func (this *UUIDParts) Equal(that interface{}) bool {
@@ -3565,6 +3571,11 @@ func (m *CollectionSpec_Derivation_Transform) MarshalToSizedBuffer(dAtA []byte)
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.MaxReadRate != 0 {
+		i = encodeVarintFlow(dAtA, i, uint64(m.MaxReadRate))
+		i--
+		dAtA[i] = 0x70
+	}
 	if m.Backfill != 0 {
 		i = encodeVarintFlow(dAtA, i, uint64(m.Backfill))
 		i--
@@ -4035,6 +4046,11 @@ func (m *MaterializationSpec_Binding) MarshalToSizedBuffer(dAtA []byte) (int, er
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.MaxReadRate != 0 {
+		i = encodeVarintFlow(dAtA, i, uint64(m.MaxReadRate))
+		i--
+		dAtA[i] = 0x70
+	}
 	if len(m.StateKey) > 0 {
 		i -= len(m.StateKey)
 		copy(dAtA[i:], m.StateKey)
@@ -5624,6 +5640,9 @@ func (m *CollectionSpec_Derivation_Transform) ProtoSize() (n int) {
 	if m.Backfill != 0 {
 		n += 1 + sovFlow(uint64(m.Backfill))
 	}
+	if m.MaxReadRate != 0 {
+		n += 1 + sovFlow(uint64(m.MaxReadRate))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -5837,6 +5856,9 @@ func (m *MaterializationSpec_Binding) ProtoSize() (n int) {
 	if l > 0 {
 		n += 1 + l + sovFlow(uint64(l))
 	}
+	if m.MaxReadRate != 0 {
+		n += 1 + sovFlow(uint64(m.MaxReadRate))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -8688,6 +8710,25 @@ func (m *CollectionSpec_Derivation_Transform) Unmarshal(dAtA []byte) error {
 					break
 				}
 			}
+		case 14:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxReadRate", wireType)
+			}
+			m.MaxReadRate = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowFlow
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.MaxReadRate |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
 		default:
 			iNdEx = preIndex
 			skippy, err := skipFlow(dAtA[iNdEx:])
@@ -10172,6 +10213,25 @@ func (m *MaterializationSpec_Binding) Unmarshal(dAtA []byte) error {
 			}
 			m.StateKey = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
+		case 14:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxReadRate", wireType)
+			}
+			m.MaxReadRate = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowFlow
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.MaxReadRate |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
 		default:
 			iNdEx = preIndex
 			skippy, err := skipFlow(dAtA[iNdEx:])
@@ -735,10 +735,14 @@ var xxx_messageInfo_Response_Spec proto.InternalMessageInfo
 
 // Validated responds to Request.Validate.
 type Response_Validated struct {
-	Bindings             []*Response_Validated_Binding `protobuf:"bytes,1,rep,name=bindings,proto3" json:"bindings,omitempty"`
-	XXX_NoUnkeyedLiteral struct{}                      `json:"-"`
-	XXX_unrecognized     []byte                        `json:"-"`
-	XXX_sizecache        int32                         `json:"-"`
+	Bindings []*Response_Validated_Binding `protobuf:"bytes,1,rep,name=bindings,proto3" json:"bindings,omitempty"`
+	// When set, the connector materializes into a strongly-typed destination
+	// which degrades when a selected field is an object having unbounded
+	// additional properties. Flow validation will then flag such fields.
+	RequiresBoundedObjects bool     `protobuf:"varint,2,opt,name=requires_bounded_objects,json=requiresBoundedObjects,proto3" json:"requires_bounded_objects,omitempty"`
+	XXX_NoUnkeyedLiteral   struct{} `json:"-"`
+	XXX_unrecognized       []byte   `json:"-"`
+	XXX_sizecache          int32    `json:"-"`
 }
 
 func (m *Response_Validated) Reset()         { *m = Response_Validated{} }
@@ -1333,114 +1337,114 @@ func init() {
 }
 
 var fileDescriptor_3e8b62b327f34bc6 = []byte{
-	// 1699 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xbc, 0x58, 0xcf, 0x6f, 0xdb, 0xc8,
-	0x15, 0x0e, 0x65, 0xfd, 0x7c, 0x92, 0x6d, 0x79, 0xa2, 0xb4, 0x2c, 0x37, 0x9b, 0x38, 0xde, 0x5d,
-	0xac, 0x91, 0x62, 0xe5, 0xc0, 0x69, 0xb1, 0x49, 0x16, 0x29, 0x2a, 0xc9, 0x12, 0xa0, 0x54, 0xb6,
-	0xbc, 0xe3, 0x24, 0x0b, 0xec, 0x45, 0x98, 0x90, 0x63, 0x99, 0x31, 0xc5, 0x61, 0xc9, 0x51, 0xb2,
-	0xea, 0xa9, 0x28, 0x50, 0x14, 0xe8, 0xad, 0x40, 0xd1, 0x4b, 0x51, 0xa0, 0xb7, 0xfe, 0x05, 0xbd,
-	0xf4, 0x5a, 0x14, 0xc8, 0xb1, 0xb7, 0xde, 0x52, 0x74, 0xfb, 0x17, 0xf4, 0xba, 0x87, 0xa2, 0x98,
-	0x1f, 0xa4, 0x28, 0x47, 0x92, 0xb5, 0x40, 0x9a, 0x8b, 0xc1, 0x79, 0xf3, 0x7d, 0x8f, 0x8f, 0x6f,
-	0xde, 0xbc, 0xf7, 0xc9, 0x70, 0x7b, 0xc8, 0xf6, 0x82, 0x90, 0x71, 0x66, 0x33, 0x2f, 0xda, 0x1b,
-	0x11, 0x4e, 0x43, 0x97, 0x78, 0xee, 0xcf, 0x68, 0xfa, 0xb9, 0x2e, 0x11, 0xa8, 0x9c, 0x32, 0x59,
-	0xdb, 0x36, 0xf3, 0xa3, 0xf1, 0x88, 0x86, 0x09, 0x3d, 0x79, 0x50, 0x70, 0xeb, 0xfa, 0x8c, 0xeb,
-	0x53, 0x8f, 0xbd, 0x94, 0x7f, 0xf4, 0x6e, 0x6d, 0xc8, 0x86, 0x4c, 0x3e, 0xee, 0x89, 0x27, 0x65,
-	0xdd, 0xf9, 0xc5, 0x55, 0x28, 0x60, 0xfa, 0xd3, 0x31, 0x8d, 0x38, 0xfa, 0x04, 0xb2, 0x51, 0x40,
-	0x6d, 0xd3, 0xd8, 0x36, 0x76, 0xcb, 0xfb, 0xdf, 0xab, 0xa7, 0x03, 0xd2, 0x98, 0xfa, 0x49, 0x40,
-	0x6d, 0x2c, 0x61, 0xe8, 0x3e, 0x14, 0x5f, 0x10, 0xcf, 0x75, 0x08, 0xa7, 0x66, 0x46, 0x52, 0xde,
-	0x9f, 0x4b, 0x79, 0xaa, 0x41, 0x38, 0x81, 0xa3, 0x3b, 0x90, 0x23, 0x41, 0xe0, 0x4d, 0xcc, 0x35,
-	0xc9, 0xb3, 0xe6, 0xf2, 0x1a, 0x02, 0x81, 0x15, 0x50, 0xc4, 0xc6, 0x02, 0xea, 0x9b, 0xd9, 0x25,
-	0xb1, 0xf5, 0x03, 0xea, 0x63, 0x09, 0x13, 0x70, 0x8f, 0x11, 0xc7, 0xcc, 0x2d, 0x81, 0xf7, 0x18,
-	0x71, 0xb0, 0x84, 0x89, 0x78, 0x4e, 0xbd, 0x71, 0x74, 0x66, 0xe6, 0x97, 0xc4, 0xd3, 0x11, 0x08,
-	0xac, 0x80, 0x82, 0x11, 0x71, 0x16, 0x52, 0xb3, 0xb0, 0x84, 0x71, 0x22, 0x10, 0x58, 0x01, 0x51,
-	0x0b, 0x2a, 0x11, 0x27, 0x21, 0x1f, 0xd8, 0x6c, 0x34, 0x72, 0xb9, 0x59, 0x94, 0xc4, 0xed, 0x05,
-	0x44, 0x12, 0xf2, 0x96, 0xc4, 0xe1, 0x72, 0x34, 0x5d, 0xa0, 0x26, 0x94, 0x89, 0x7d, 0xee, 0xb3,
-	0x97, 0x1e, 0x75, 0x86, 0xd4, 0x2c, 0x2d, 0xf1, 0xd1, 0x98, 0xe2, 0x70, 0x9a, 0x84, 0xde, 0x83,
-	0xa2, 0xeb, 0x73, 0x1a, 0xfa, 0xc4, 0x33, 0x9d, 0x6d, 0x63, 0xb7, 0x82, 0x4b, 0x1f, 0xc6, 0x06,
-	0xeb, 0x37, 0x06, 0x64, 0xc5, 0x19, 0xa3, 0x23, 0xd8, 0xb0, 0x99, 0xef, 0x53, 0x9b, 0xb3, 0x70,
-	0xc0, 0x27, 0x01, 0x95, 0x65, 0xb1, 0xb1, 0xff, 0x71, 0x5d, 0xd6, 0xd4, 0x61, 0xf2, 0x46, 0xc2,
-	0x5d, 0xe6, 0x0b, 0x4a, 0xbd, 0x15, 0xe3, 0x1f, 0x4f, 0x02, 0x8a, 0xd7, 0xed, 0xf4, 0x12, 0xdd,
-	0x87, 0xb2, 0xcd, 0xfc, 0x53, 0x77, 0x38, 0x78, 0x1e, 0x31, 0x5f, 0x16, 0x4c, 0xa9, 0x79, 0xfd,
-	0x9b, 0xd7, 0x37, 0x4d, 0xea, 0xdb, 0xcc, 0x71, 0xfd, 0xe1, 0x9e, 0xd8, 0xa8, 0x63, 0xf2, 0xf2,
-	0x90, 0x46, 0x11, 0x19, 0x52, 0x9c, 0x57, 0x04, 0xeb, 0x0f, 0x79, 0x28, 0xc6, 0x45, 0x84, 0x3e,
-	0x87, 0xac, 0x4f, 0x46, 0x2a, 0x9a, 0x52, 0xf3, 0xe1, 0x37, 0xaf, 0x6f, 0xde, 0x1f, 0xba, 0xfc,
-	0x6c, 0xfc, 0xac, 0x6e, 0xb3, 0xd1, 0x1e, 0x8d, 0xf8, 0x98, 0x84, 0x13, 0x55, 0xfc, 0x6f, 0x5c,
-	0x87, 0x8b, 0x51, 0x63, 0xe9, 0x6a, 0xce, 0xa7, 0x66, 0xde, 0xe6, 0xa7, 0xae, 0xad, 0xfe, 0xa9,
-	0xa8, 0x01, 0xc5, 0x67, 0xae, 0x2f, 0x20, 0x91, 0x99, 0xdd, 0x5e, 0xdb, 0x2d, 0xef, 0x7f, 0xb4,
-	0xf4, 0x4e, 0xd5, 0x9b, 0x0a, 0x8d, 0x13, 0x1a, 0xea, 0x41, 0xcd, 0x23, 0x11, 0x1f, 0x8c, 0x66,
-	0xc3, 0x4e, 0xae, 0xc2, 0xa2, 0x6f, 0xc2, 0x57, 0x05, 0xed, 0xc2, 0x06, 0xba, 0x05, 0x15, 0xe9,
-	0xed, 0x05, 0x0d, 0x23, 0xe1, 0x45, 0x5c, 0x90, 0x12, 0x2e, 0x0b, 0xdb, 0x53, 0x65, 0xb2, 0xfe,
-	0x9b, 0x81, 0x82, 0x0e, 0x03, 0x3d, 0x82, 0x5a, 0x48, 0x23, 0x36, 0x0e, 0x6d, 0x3a, 0x48, 0xe7,
-	0xc0, 0x58, 0x21, 0x07, 0x1b, 0x31, 0xb3, 0xa5, 0x72, 0xf1, 0x00, 0xc0, 0x66, 0x9e, 0x47, 0x6d,
-	0x19, 0xbe, 0xea, 0x30, 0x35, 0x15, 0x7e, 0x2b, 0xb1, 0x8b, 0xc8, 0x9b, 0xd9, 0x57, 0xaf, 0x6f,
-	0x5e, 0xc1, 0x29, 0x34, 0xfa, 0x95, 0x01, 0xd7, 0x4e, 0x5d, 0xea, 0x39, 0xe9, 0x28, 0x06, 0x23,
-	0x12, 0x98, 0x6b, 0x32, 0xab, 0x0f, 0x57, 0xca, 0x6a, 0xbd, 0x23, 0x5c, 0xa8, 0x70, 0x1e, 0x45,
-	0xcc, 0x3f, 0x24, 0x41, 0xdb, 0xe7, 0xe1, 0xa4, 0x79, 0xfd, 0xd7, 0xff, 0x5c, 0xf2, 0x21, 0xe5,
-	0xd3, 0x29, 0x0d, 0x59, 0x50, 0x7c, 0x46, 0xec, 0xf3, 0x53, 0xd7, 0xf3, 0x64, 0xf3, 0x5a, 0xc7,
-	0xc9, 0xda, 0x6a, 0xc3, 0x77, 0x17, 0xbc, 0x01, 0x55, 0x61, 0xed, 0x9c, 0x4e, 0x54, 0xde, 0xb0,
-	0x78, 0x44, 0x35, 0xc8, 0xbd, 0x20, 0xde, 0x58, 0x15, 0x67, 0x09, 0xab, 0xc5, 0x83, 0xcc, 0x3d,
-	0xc3, 0xfa, 0x87, 0x01, 0x39, 0xd9, 0x2c, 0x51, 0x0b, 0x36, 0x2f, 0x1e, 0xbb, 0x71, 0xd9, 0xb1,
-	0x5f, 0x64, 0x20, 0x13, 0x0a, 0xf1, 0x69, 0xab, 0x57, 0xc5, 0xcb, 0x85, 0xa5, 0x95, 0x7d, 0x2b,
-	0xa5, 0x95, 0x7b, 0xb3, 0xb4, 0xfe, 0x66, 0x40, 0x56, 0x74, 0xf5, 0xff, 0xf7, 0x87, 0x7d, 0x04,
-	0xb9, 0x90, 0xf8, 0x43, 0xaa, 0xe7, 0xd1, 0xa6, 0x72, 0x8a, 0x85, 0x49, 0xba, 0x52, 0xbb, 0xe8,
-	0x53, 0x80, 0x88, 0x13, 0x4e, 0x55, 0x4d, 0x67, 0x57, 0xa8, 0xe9, 0x9c, 0xc4, 0x5b, 0x1c, 0xb2,
-	0x62, 0xda, 0x88, 0x08, 0xf4, 0x3d, 0x95, 0xe1, 0xaf, 0xe3, 0x78, 0x89, 0xee, 0x42, 0xf1, 0x9c,
-	0x4e, 0x56, 0xef, 0x8d, 0xb2, 0x24, 0xde, 0x07, 0x10, 0xa4, 0x80, 0xd8, 0xe7, 0xd4, 0x91, 0xb1,
-	0x57, 0x70, 0xe9, 0x9c, 0x4e, 0x8e, 0xa5, 0xc1, 0x2a, 0x40, 0x4e, 0xce, 0x2c, 0xeb, 0x2f, 0x19,
-	0xc8, 0xc9, 0x59, 0xf4, 0x6e, 0x03, 0x10, 0x8d, 0x50, 0x56, 0x69, 0xb4, 0x7a, 0xc2, 0xf2, 0x8a,
-	0x80, 0x3e, 0x80, 0x75, 0x4d, 0xd5, 0xce, 0x73, 0xd2, 0x79, 0x45, 0x19, 0xb5, 0xff, 0xbb, 0x50,
-	0x74, 0x98, 0xad, 0x9c, 0xe7, 0x57, 0x89, 0xd9, 0x61, 0x36, 0xfa, 0x0e, 0xe4, 0xe9, 0x57, 0x6e,
-	0xc4, 0x23, 0x39, 0xba, 0x8b, 0x58, 0xaf, 0x84, 0xdd, 0xa1, 0x1e, 0xe5, 0x54, 0x4e, 0xe6, 0x22,
-	0xd6, 0x2b, 0x0b, 0x43, 0x39, 0x35, 0x8e, 0x51, 0x0b, 0x50, 0x38, 0xf6, 0xb9, 0x3b, 0xa2, 0x03,
-	0xfb, 0x8c, 0xda, 0xe7, 0x01, 0x73, 0x7d, 0xae, 0x8b, 0xb1, 0x56, 0x8f, 0x35, 0x5a, 0xbd, 0x95,
-	0xec, 0xe1, 0x2d, 0x8d, 0x9f, 0x9a, 0xac, 0x75, 0x28, 0xa7, 0xc6, 0xf3, 0xce, 0x9f, 0x36, 0xa0,
-	0x88, 0x69, 0x14, 0x30, 0x3f, 0xa2, 0xa8, 0x3e, 0xa3, 0xc2, 0x2e, 0x0a, 0x0b, 0x05, 0x4a, 0xcb,
-	0xb0, 0x87, 0x50, 0x8a, 0x75, 0x95, 0xa3, 0xbb, 0xe4, 0xcd, 0xf9, 0xa4, 0xb8, 0xbd, 0x39, 0x78,
-	0xca, 0x40, 0x9f, 0x42, 0x41, 0x28, 0x2c, 0x57, 0x9f, 0xdf, 0x9b, 0x22, 0x4e, 0x93, 0x1b, 0x0a,
-	0x84, 0x63, 0x34, 0xfa, 0x01, 0xe4, 0x85, 0xd4, 0xa2, 0x8e, 0xbe, 0xfe, 0xd7, 0xe7, 0xf3, 0xfa,
-	0x12, 0x83, 0x35, 0x56, 0xb0, 0x84, 0xe2, 0xa2, 0xb1, 0x34, 0x5b, 0xc0, 0xea, 0x49, 0x0c, 0xd6,
-	0x58, 0x11, 0xa4, 0x94, 0x5d, 0xd4, 0xd1, 0x0a, 0x6d, 0x41, 0x90, 0x1d, 0x05, 0xc2, 0x31, 0x1a,
-	0x3d, 0x82, 0x0d, 0x29, 0x9f, 0xa8, 0x13, 0xcb, 0x2e, 0xa5, 0xd7, 0x3e, 0x58, 0x90, 0x56, 0x85,
-	0xd5, 0xca, 0x6b, 0x3d, 0x4a, 0x2f, 0x51, 0x07, 0x2a, 0x29, 0x19, 0xe5, 0x68, 0x01, 0xb7, 0xb3,
-	0x20, 0x5d, 0x29, 0x24, 0x9e, 0xe1, 0x2d, 0xd7, 0x5f, 0xbf, 0xcb, 0x68, 0xfd, 0x65, 0x41, 0x31,
-	0x16, 0x2f, 0xfa, 0xaa, 0x26, 0x6b, 0xd4, 0x01, 0xa4, 0xc7, 0x5a, 0x64, 0x9f, 0xd1, 0x11, 0x59,
-	0xfd, 0xd6, 0x56, 0x14, 0xef, 0x44, 0xd2, 0xd0, 0x17, 0xf0, 0xde, 0xc5, 0x69, 0x9d, 0x76, 0xb8,
-	0x8a, 0x70, 0xa9, 0xcd, 0x0e, 0x6d, 0xed, 0xf8, 0xfb, 0xb0, 0xe5, 0x30, 0x7b, 0x3c, 0xa2, 0x3e,
-	0x97, 0xad, 0x77, 0x30, 0x0e, 0xd5, 0xf4, 0x2b, 0xe1, 0xea, 0xcc, 0xc6, 0x93, 0xd0, 0x43, 0x1f,
-	0x42, 0x9e, 0x91, 0x31, 0x3f, 0xdb, 0xd7, 0x25, 0x51, 0x51, 0xdd, 0xb7, 0xdf, 0x10, 0x36, 0xac,
-	0xf7, 0xac, 0xff, 0x64, 0xa1, 0x94, 0x14, 0x30, 0x6a, 0xa5, 0x74, 0x92, 0x21, 0x27, 0xfa, 0xc7,
-	0x97, 0xd4, 0xfc, 0x9b, 0x4a, 0xc9, 0xfa, 0x79, 0x06, 0xa0, 0xc5, 0xfc, 0x88, 0x87, 0xc4, 0xf5,
-	0xc5, 0xcd, 0xce, 0xa6, 0xc4, 0xdf, 0xde, 0x65, 0xfe, 0xa6, 0xcc, 0xba, 0x14, 0x81, 0x92, 0x2c,
-	0xba, 0x48, 0x48, 0x49, 0x92, 0x3d, 0xac, 0x57, 0x3b, 0xbf, 0x35, 0x20, 0x2b, 0xc5, 0x61, 0x19,
-	0x0a, 0xdd, 0xa3, 0xa7, 0x8d, 0x5e, 0xf7, 0xa0, 0x7a, 0x05, 0x21, 0xd8, 0xe8, 0x74, 0xdb, 0xbd,
-	0x83, 0x01, 0x6e, 0x7f, 0xfe, 0xa4, 0x8b, 0xdb, 0x07, 0x55, 0x03, 0x5d, 0x83, 0xad, 0x5e, 0xbf,
-	0xd5, 0x78, 0xdc, 0xed, 0x1f, 0x4d, 0xcd, 0x19, 0x64, 0x42, 0x2d, 0x65, 0x6e, 0xf5, 0x0f, 0x0f,
-	0xdb, 0x47, 0x07, 0xed, 0x83, 0xea, 0xda, 0xd4, 0x49, 0xff, 0x58, 0xec, 0x36, 0x7a, 0xd5, 0x2c,
-	0xba, 0x0a, 0x9b, 0xca, 0xd6, 0xe9, 0xe3, 0x66, 0xf7, 0xe0, 0xa0, 0x7d, 0x54, 0xcd, 0xa1, 0x2d,
-	0x58, 0x7f, 0x72, 0x74, 0xd2, 0x78, 0xdc, 0x3d, 0xe9, 0x74, 0x1b, 0xcd, 0x5e, 0xbb, 0x9a, 0xb7,
-	0x7e, 0x9f, 0xd2, 0x6e, 0x5f, 0x4a, 0xd9, 0xaa, 0xbf, 0x29, 0x4e, 0xeb, 0xbd, 0x15, 0xd3, 0x9a,
-	0x4a, 0x47, 0x24, 0x15, 0x0c, 0x4e, 0x3b, 0x13, 0xed, 0x3c, 0xa9, 0xb4, 0x80, 0xf0, 0x33, 0x33,
-	0xb3, 0xbd, 0xb6, 0x5b, 0xc2, 0x95, 0xd8, 0x78, 0x4c, 0xf8, 0x99, 0x00, 0x39, 0xd4, 0xe3, 0x64,
-	0x30, 0x0e, 0x84, 0xef, 0x48, 0xa6, 0xb0, 0x88, 0x2b, 0xd2, 0xf8, 0x44, 0xd9, 0xac, 0xe7, 0x50,
-	0xbd, 0xf8, 0xaa, 0x39, 0x62, 0xe9, 0xc7, 0x69, 0xb1, 0x54, 0xde, 0xbf, 0xbd, 0xfa, 0x61, 0xa6,
-	0x85, 0xd5, 0x3d, 0x28, 0xe8, 0xb6, 0x87, 0x3e, 0x01, 0x44, 0xa4, 0xb4, 0x1c, 0x38, 0x34, 0xb2,
-	0x43, 0x37, 0x48, 0x34, 0x48, 0x09, 0x6f, 0xa9, 0x9d, 0x83, 0xe9, 0x86, 0x75, 0x08, 0x79, 0xd5,
-	0xf8, 0xde, 0xce, 0xbc, 0xf8, 0x02, 0xf2, 0xaa, 0x23, 0x2e, 0x1f, 0xe0, 0xc9, 0x30, 0xcc, 0xac,
-	0x38, 0x0c, 0xad, 0x1f, 0x42, 0x41, 0xf7, 0x4c, 0x74, 0x1b, 0x94, 0x58, 0x49, 0x62, 0xd3, 0x4a,
-	0x5b, 0xff, 0xb2, 0x39, 0x11, 0x7b, 0xb1, 0x9e, 0xf9, 0x0c, 0xd6, 0x67, 0x5a, 0xe5, 0xb7, 0x22,
-	0x3f, 0x80, 0x4a, 0xba, 0x3b, 0x7e, 0x1b, 0xee, 0xce, 0x2f, 0xb3, 0x90, 0x6b, 0x7f, 0xc5, 0x43,
-	0x62, 0xfd, 0xd5, 0x80, 0x5b, 0xf1, 0xf9, 0xb5, 0xc5, 0x04, 0x77, 0xfd, 0xe1, 0x71, 0xc8, 0x9e,
-	0xab, 0x1f, 0x00, 0xf1, 0xbf, 0x34, 0x7a, 0x50, 0xa5, 0x7a, 0x73, 0x90, 0xce, 0x5b, 0x79, 0xff,
-	0xd6, 0xe2, 0x1f, 0x77, 0x71, 0xa7, 0xd8, 0x8c, 0xa9, 0xf1, 0x0d, 0x39, 0x86, 0x6a, 0x10, 0xb2,
-	0x80, 0x45, 0xd4, 0x49, 0xbc, 0xa9, 0x02, 0x5b, 0xf1, 0x57, 0xda, 0x66, 0x4c, 0xd7, 0x06, 0xeb,
-	0xcf, 0x99, 0xe9, 0x57, 0x68, 0x5b, 0x63, 0x48, 0x5c, 0x3f, 0xe2, 0xa9, 0x22, 0x47, 0x9f, 0xcd,
-	0x1e, 0xfa, 0x4a, 0xc1, 0x27, 0x75, 0x31, 0x9c, 0xbd, 0xd6, 0x19, 0x79, 0xad, 0xdb, 0x33, 0xf1,
-	0xca, 0x8c, 0xd6, 0x2f, 0x8d, 0x63, 0xf9, 0x1d, 0x7f, 0x97, 0x37, 0x73, 0xff, 0x27, 0x50, 0x4a,
-	0x0a, 0x04, 0xfd, 0x08, 0xca, 0xd3, 0x4c, 0x50, 0x54, 0x9b, 0x77, 0x16, 0xd6, 0xb5, 0xb9, 0x2f,
-	0xda, 0x35, 0xee, 0x18, 0xcd, 0xe6, 0xab, 0x7f, 0xdd, 0xb8, 0xf2, 0xea, 0xeb, 0x1b, 0xc6, 0xdf,
-	0xbf, 0xbe, 0x61, 0xfc, 0xf1, 0xdf, 0x37, 0x8c, 0x2f, 0xef, 0xac, 0xf4, 0x2f, 0x85, 0x94, 0xc3,
-	0x67, 0x79, 0x69, 0xbe, 0xfb, 0xbf, 0x00, 0x00, 0x00, 0xff, 0xff, 0x4b, 0x65, 0x75, 0xc0, 0xdf,
-	0x13, 0x00, 0x00,
+	// 1704 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xbd, 0x58, 0xcd, 0x6f, 0x1b, 0x45,
+	0x14, 0xef, 0x3a, 0xfe, 0x7c, 0xb6, 0x13, 0x67, 0xea, 0x16, 0xb3, 0x4d, 0xbf, 0xd2, 0x56, 0xad,
+	0x8a, 0x70, 0xaa, 0x14, 0x44, 0xda, 0xaa, 0x08, 0xdb, 0x71, 0xa4, 0x14, 0x27, 0x4e, 0x27, 0xfd,
+	0x90, 0x7a, 0xb1, 0x36, 0xf6, 0xc4, 0xd9, 0xd4, 0xde, 0x35, 0xbb, 0xeb, 0xb6, 0xe1, 0x84, 0x90,
+	0x10, 0x12, 0x37, 0x24, 0xc4, 0x05, 0x21, 0x71, 0xe7, 0xcc, 0x85, 0x2b, 0x42, 0xea, 0x91, 0x1b,
+	0x37, 0x50, 0xe1, 0xbf, 0xe0, 0x80, 0x78, 0xf3, 0xb1, 0xeb, 0x75, 0x6a, 0x3b, 0xae, 0x54, 0x7a,
+	0x68, 0xe5, 0x79, 0xf3, 0xfb, 0x3d, 0xbf, 0x79, 0xf3, 0xe6, 0xbd, 0x9f, 0x03, 0x57, 0xdb, 0xf6,
+	0x52, 0xcf, 0xb1, 0x3d, 0xbb, 0x69, 0x77, 0xdc, 0xa5, 0xae, 0xe1, 0x31, 0xc7, 0x34, 0x3a, 0xe6,
+	0xa7, 0x2c, 0xfc, 0xb9, 0x28, 0x10, 0x24, 0x1d, 0x32, 0xe9, 0xe7, 0x9a, 0xb6, 0xe5, 0xf6, 0xbb,
+	0xcc, 0x09, 0xe8, 0xc1, 0x07, 0x09, 0xd7, 0x17, 0x86, 0x5c, 0xef, 0x76, 0xec, 0xa7, 0xe2, 0x3f,
+	0xb5, 0x9b, 0x6f, 0xdb, 0x6d, 0x5b, 0x7c, 0x5c, 0xe2, 0x9f, 0xa4, 0x75, 0xf1, 0xf3, 0xe3, 0x90,
+	0xa0, 0xec, 0x93, 0x3e, 0x73, 0x3d, 0xf2, 0x2e, 0x44, 0xdd, 0x1e, 0x6b, 0x16, 0xb4, 0x73, 0xda,
+	0x95, 0xf4, 0xf2, 0xdb, 0xc5, 0x70, 0x40, 0x0a, 0x53, 0xdc, 0x46, 0x00, 0x15, 0x30, 0x72, 0x03,
+	0x92, 0x4f, 0x70, 0xb3, 0x85, 0xa8, 0x42, 0x44, 0x50, 0x4e, 0x8f, 0xa4, 0x3c, 0x50, 0x20, 0x1a,
+	0xc0, 0xc9, 0x35, 0x88, 0x19, 0xbd, 0x5e, 0xe7, 0xa0, 0x30, 0x23, 0x78, 0xfa, 0x48, 0x5e, 0x89,
+	0x23, 0xa8, 0x04, 0xf2, 0xd8, 0xec, 0x1e, 0xb3, 0x0a, 0xd1, 0x09, 0xb1, 0xd5, 0x11, 0x40, 0x05,
+	0x8c, 0xc3, 0x3b, 0xb6, 0xd1, 0x2a, 0xc4, 0x26, 0xc0, 0x6b, 0x08, 0xa0, 0x02, 0xc6, 0xe3, 0xd9,
+	0xed, 0xf4, 0xdd, 0xbd, 0x42, 0x7c, 0x42, 0x3c, 0x6b, 0x1c, 0x41, 0x25, 0x90, 0x33, 0x5c, 0xcf,
+	0x76, 0x58, 0x21, 0x31, 0x81, 0xb1, 0xcd, 0x11, 0x54, 0x02, 0x49, 0x05, 0x32, 0xae, 0x67, 0x38,
+	0x5e, 0xa3, 0x69, 0x77, 0xbb, 0xa6, 0x57, 0x48, 0x0a, 0xe2, 0xb9, 0x31, 0x44, 0x04, 0x56, 0x04,
+	0x8e, 0xa6, 0xdd, 0xc1, 0x82, 0x94, 0x21, 0x6d, 0x34, 0x1f, 0x5b, 0xf6, 0xd3, 0x0e, 0x6b, 0xb5,
+	0x59, 0x21, 0x35, 0xc1, 0x47, 0x69, 0x80, 0xa3, 0x61, 0x12, 0x39, 0x05, 0x49, 0xd3, 0x42, 0xbc,
+	0x65, 0x74, 0x0a, 0x2d, 0x74, 0x90, 0xa1, 0xa9, 0x8b, 0xbe, 0x41, 0xff, 0x5a, 0x83, 0x28, 0xbf,
+	0x63, 0xb2, 0x09, 0xb3, 0x58, 0x70, 0x16, 0x6b, 0x62, 0xf0, 0x0d, 0xef, 0xa0, 0xc7, 0x44, 0x59,
+	0xcc, 0x2e, 0x5f, 0x2e, 0x8a, 0x9a, 0xda, 0x08, 0xbe, 0xd1, 0xf0, 0x4c, 0xdb, 0xe2, 0x94, 0x62,
+	0xc5, 0xc7, 0xdf, 0x43, 0x38, 0xcd, 0x36, 0xc3, 0x4b, 0xac, 0x96, 0x34, 0x1a, 0x76, 0xcd, 0x76,
+	0x63, 0xdf, 0xb5, 0x2d, 0x51, 0x30, 0xa9, 0xf2, 0xc2, 0x3f, 0x7f, 0x9c, 0x2d, 0x30, 0xab, 0x69,
+	0xb7, 0x4c, 0xab, 0xbd, 0xc4, 0x37, 0x8a, 0xd4, 0x78, 0xba, 0xc1, 0x5c, 0xd7, 0xc0, 0xa8, 0xe3,
+	0x92, 0xa0, 0x7f, 0x1f, 0x87, 0xa4, 0x5f, 0x44, 0xe4, 0x2e, 0x44, 0x2d, 0xa3, 0x2b, 0xa3, 0x49,
+	0x95, 0x6f, 0xa3, 0x83, 0x1b, 0x6d, 0xd3, 0xdb, 0xeb, 0xef, 0x14, 0x31, 0xaf, 0x4b, 0x78, 0xf0,
+	0xbe, 0xe1, 0x1c, 0xc8, 0xe2, 0x7f, 0xe9, 0x39, 0x1c, 0x8e, 0x9a, 0x0a, 0x57, 0x23, 0x8e, 0x1a,
+	0x79, 0x9d, 0x47, 0x9d, 0x99, 0xfe, 0xa8, 0xa4, 0x04, 0xc9, 0x1d, 0xd3, 0xe2, 0x10, 0x17, 0x4b,
+	0x7d, 0x06, 0x2f, 0xf7, 0xd2, 0xc4, 0x37, 0x55, 0x2c, 0x4b, 0x34, 0x0d, 0x68, 0xa4, 0x06, 0xf9,
+	0x8e, 0xe1, 0x7a, 0x8d, 0xee, 0x70, 0xd8, 0xc1, 0x53, 0x18, 0x77, 0x26, 0x7a, 0x9c, 0xd3, 0x0e,
+	0x6d, 0x90, 0xf3, 0x90, 0x11, 0xde, 0x9e, 0x30, 0xc7, 0xe5, 0x5e, 0xf8, 0x03, 0x49, 0xd1, 0x34,
+	0xb7, 0x3d, 0x90, 0x26, 0xfd, 0xdf, 0x08, 0x24, 0x54, 0x18, 0xe4, 0x0e, 0xe4, 0x1d, 0xe6, 0xda,
+	0x7d, 0xa7, 0xc9, 0x1a, 0xe1, 0x1c, 0x68, 0x53, 0xe4, 0x60, 0xd6, 0x67, 0x56, 0x64, 0x2e, 0x6e,
+	0x02, 0xe0, 0xcd, 0x75, 0x30, 0xaf, 0xa6, 0x2a, 0x98, 0xf4, 0x72, 0x5e, 0x86, 0x5f, 0x09, 0xec,
+	0x3c, 0xf2, 0x72, 0xf4, 0xf9, 0x1f, 0x67, 0x8f, 0xd1, 0x10, 0x9a, 0x7c, 0xa9, 0xc1, 0x89, 0x5d,
+	0x93, 0x75, 0x5a, 0xe1, 0x28, 0x30, 0x25, 0x3d, 0xbc, 0x0d, 0x9e, 0xd5, 0xdb, 0x53, 0x65, 0xb5,
+	0xb8, 0xc6, 0x5d, 0xc8, 0x70, 0xee, 0xa0, 0x83, 0x0d, 0xa3, 0x57, 0xb5, 0x3c, 0xe7, 0xa0, 0xbc,
+	0xf0, 0xd5, 0x9f, 0x13, 0x0e, 0x92, 0xde, 0x1d, 0xd0, 0x88, 0x8e, 0x37, 0x8a, 0xaf, 0x6f, 0xd7,
+	0xec, 0x74, 0x44, 0xf3, 0xca, 0xd2, 0x60, 0xad, 0x57, 0xe1, 0xad, 0x31, 0xdf, 0x40, 0x72, 0x30,
+	0xf3, 0x98, 0x1d, 0xc8, 0xbc, 0x51, 0xfe, 0x91, 0xe4, 0x21, 0x86, 0xfd, 0xb3, 0x2f, 0x8b, 0x33,
+	0x45, 0xe5, 0xe2, 0x66, 0x64, 0x45, 0xd3, 0x7f, 0xd7, 0x20, 0x26, 0x9a, 0x25, 0xf6, 0x98, 0xb9,
+	0xc3, 0xd7, 0xae, 0x1d, 0x75, 0xed, 0x87, 0x19, 0xa4, 0x00, 0x09, 0xff, 0xb6, 0xe5, 0x57, 0xf9,
+	0xcb, 0xb1, 0xa5, 0x15, 0x7d, 0x2d, 0xa5, 0x15, 0x7b, 0xb9, 0xb4, 0x7e, 0xc5, 0x6e, 0xc4, 0xbb,
+	0xfa, 0xff, 0x7d, 0xb0, 0x4b, 0x10, 0x73, 0x0c, 0x0b, 0x1b, 0xaa, 0x9c, 0x47, 0x73, 0xd2, 0x29,
+	0xe5, 0x26, 0xe1, 0x4a, 0xee, 0x92, 0x0f, 0x00, 0xb0, 0x19, 0x7b, 0x4c, 0xd6, 0x74, 0x74, 0x8a,
+	0x9a, 0x8e, 0x09, 0xbc, 0xee, 0x41, 0x94, 0x4f, 0x1b, 0x1e, 0x81, 0x7a, 0xa7, 0x22, 0xfc, 0x2c,
+	0xf5, 0x97, 0xe4, 0x3a, 0x24, 0xf1, 0x92, 0xa7, 0xef, 0x8d, 0xa2, 0x24, 0x4e, 0x03, 0x70, 0x52,
+	0x0f, 0xeb, 0x89, 0xb5, 0x44, 0xec, 0xd8, 0xcb, 0xd1, 0xb2, 0x25, 0x0c, 0x7a, 0x02, 0x62, 0x62,
+	0x66, 0xe9, 0x3f, 0x47, 0x20, 0x26, 0x66, 0xd1, 0x9b, 0x0d, 0x80, 0x37, 0x42, 0x51, 0xa5, 0xee,
+	0xf4, 0x09, 0x8b, 0x4b, 0x02, 0xb9, 0x00, 0x59, 0x45, 0x55, 0xce, 0x63, 0xc2, 0x79, 0x46, 0x1a,
+	0x95, 0x7f, 0x8c, 0xb9, 0x65, 0x37, 0xa5, 0xf3, 0xf8, 0x34, 0x31, 0x23, 0x9a, 0x9c, 0x84, 0x38,
+	0x7b, 0x66, 0xba, 0x9e, 0x2b, 0x46, 0x77, 0x92, 0xaa, 0x15, 0xb7, 0xb7, 0x58, 0x87, 0xa1, 0x98,
+	0x49, 0x4a, 0xbb, 0x5c, 0xe9, 0x14, 0xd2, 0xa1, 0x71, 0x8c, 0x95, 0x48, 0x9c, 0xbe, 0xe5, 0x99,
+	0x5d, 0x6c, 0x70, 0x7b, 0xac, 0xf9, 0xb8, 0x67, 0xe3, 0xec, 0x54, 0xc5, 0x98, 0x2f, 0xfa, 0x1a,
+	0xad, 0x58, 0x09, 0xf6, 0xe8, 0xbc, 0xc2, 0x0f, 0x4c, 0x7a, 0x16, 0xd2, 0xa1, 0xf1, 0xbc, 0xf8,
+	0x62, 0x16, 0x92, 0x94, 0xb9, 0x3d, 0x24, 0x33, 0x52, 0x1c, 0x52, 0x61, 0x87, 0x85, 0x85, 0x04,
+	0x85, 0x65, 0xd8, 0x6d, 0x48, 0xf9, 0xba, 0xaa, 0xa5, 0xba, 0xe4, 0xd9, 0xd1, 0x24, 0xbf, 0xbd,
+	0xb5, 0xe8, 0x80, 0x81, 0x35, 0x9d, 0xe0, 0x0a, 0xcb, 0x54, 0xf7, 0xf7, 0xb2, 0x88, 0x53, 0xe4,
+	0x92, 0x04, 0x51, 0x1f, 0x4d, 0xde, 0x83, 0x38, 0x97, 0x5a, 0xc8, 0x93, 0xcf, 0x7f, 0x61, 0x34,
+	0xaf, 0x2e, 0x30, 0x54, 0x61, 0x39, 0x8b, 0x2b, 0x2e, 0xe6, 0x4b, 0xb3, 0x31, 0xac, 0x9a, 0xc0,
+	0x50, 0x85, 0xe5, 0x41, 0x0a, 0xd9, 0x85, 0xb4, 0xf8, 0xa4, 0x20, 0xd7, 0x24, 0x88, 0xfa, 0x68,
+	0x9c, 0x47, 0xb3, 0x42, 0x3e, 0xb1, 0x96, 0x2f, 0xbb, 0xa4, 0x5e, 0xbb, 0x30, 0x26, 0xad, 0x12,
+	0xab, 0x94, 0x57, 0xd6, 0x0d, 0x2f, 0xc9, 0x1a, 0x64, 0x42, 0x32, 0xaa, 0xa5, 0x04, 0xdc, 0xe2,
+	0x98, 0x74, 0x85, 0x90, 0x74, 0x88, 0x37, 0x59, 0x7f, 0x7d, 0x1b, 0x51, 0xfa, 0x0b, 0xe7, 0x86,
+	0x2f, 0x5e, 0xd4, 0x53, 0x0d, 0xd6, 0x18, 0x09, 0x51, 0x63, 0xcd, 0xc5, 0x1a, 0xec, 0x1a, 0xd3,
+	0xbf, 0xda, 0x8c, 0xe4, 0x6d, 0x0b, 0x1a, 0x79, 0x08, 0xa7, 0x0e, 0x4f, 0xeb, 0xb0, 0xc3, 0x69,
+	0x84, 0x4b, 0x7e, 0x78, 0x68, 0x2b, 0xc7, 0xef, 0xc0, 0x3c, 0x3e, 0x35, 0x7c, 0x08, 0x96, 0x27,
+	0x5a, 0x6f, 0xa3, 0xef, 0xc8, 0xe9, 0x97, 0xa2, 0xb9, 0xa1, 0x8d, 0xfb, 0x4e, 0x87, 0x5c, 0xc4,
+	0x42, 0x32, 0xfa, 0xde, 0xde, 0xb2, 0x2a, 0x89, 0x8c, 0xec, 0xbe, 0xf5, 0x12, 0xb7, 0x51, 0xb5,
+	0xa7, 0xff, 0x18, 0x83, 0x54, 0x50, 0xc0, 0xf8, 0x0a, 0x07, 0x3a, 0x49, 0x13, 0x13, 0xfd, 0xf2,
+	0x11, 0x35, 0x3f, 0x42, 0x29, 0xad, 0x40, 0xc1, 0xc1, 0xc9, 0x6f, 0xe2, 0x11, 0x1a, 0x3b, 0x76,
+	0xdf, 0xc2, 0x4a, 0x6b, 0xd8, 0x3b, 0xfb, 0x28, 0x21, 0x5c, 0x91, 0xcc, 0x24, 0x3d, 0xe9, 0xef,
+	0x97, 0xe5, 0x76, 0x5d, 0xee, 0xea, 0x9f, 0x45, 0x00, 0xf0, 0xc0, 0xae, 0xe7, 0x18, 0x78, 0x73,
+	0x18, 0x4d, 0x34, 0x24, 0x1b, 0x97, 0x8e, 0x8a, 0x64, 0xc0, 0x2c, 0x0a, 0xf9, 0x28, 0xc8, 0xbc,
+	0xff, 0x38, 0xcc, 0x08, 0xf2, 0x4e, 0xd5, 0x6a, 0xf1, 0x1b, 0x9c, 0x81, 0x42, 0x56, 0xa6, 0x21,
+	0xb1, 0xbe, 0xf9, 0xa0, 0x54, 0x5b, 0x5f, 0xcd, 0x1d, 0x23, 0x04, 0x66, 0xd7, 0xd6, 0xab, 0xb5,
+	0xd5, 0x06, 0xad, 0xde, 0xbd, 0xbf, 0x4e, 0xab, 0xab, 0x39, 0x8d, 0x9c, 0x80, 0xf9, 0x5a, 0xbd,
+	0x52, 0xba, 0xb7, 0x5e, 0xdf, 0x1c, 0x98, 0x23, 0xd8, 0xf3, 0xf3, 0x21, 0x73, 0xa5, 0xbe, 0xb1,
+	0x51, 0xdd, 0x5c, 0xc5, 0x9d, 0x99, 0x81, 0x93, 0xfa, 0x16, 0xdf, 0x2d, 0xd5, 0x72, 0x51, 0x72,
+	0x1c, 0xe6, 0xa4, 0x6d, 0xad, 0x4e, 0xcb, 0xeb, 0xab, 0xab, 0xd5, 0xcd, 0x5c, 0x8c, 0xcc, 0x43,
+	0xf6, 0xfe, 0xe6, 0x36, 0xba, 0xd8, 0x5e, 0x5b, 0x2f, 0x95, 0x6b, 0xd5, 0x5c, 0x5c, 0xff, 0x2e,
+	0xa4, 0xfa, 0x1e, 0x09, 0xc1, 0xab, 0xce, 0xe4, 0x5f, 0xc8, 0xca, 0x94, 0x17, 0x12, 0x4a, 0x87,
+	0x2b, 0xb4, 0x0f, 0x0d, 0x3b, 0xe3, 0x83, 0x20, 0xa8, 0xd1, 0x9e, 0xe1, 0xed, 0x61, 0x92, 0x67,
+	0x30, 0x3b, 0x19, 0xdf, 0xb8, 0x85, 0x36, 0x0e, 0xc2, 0x6e, 0xed, 0x19, 0x8d, 0x7e, 0x8f, 0xfb,
+	0x76, 0x45, 0x0a, 0x93, 0x34, 0x23, 0x8c, 0xf7, 0xa5, 0x4d, 0xdf, 0x87, 0xdc, 0xe1, 0xaf, 0x1a,
+	0x21, 0xb3, 0x3e, 0x0a, 0xcb, 0xac, 0xf4, 0xf2, 0xd5, 0xe9, 0x2f, 0x33, 0x2c, 0xc9, 0x56, 0x20,
+	0xa1, 0x1a, 0x26, 0xfe, 0x14, 0x25, 0x86, 0x10, 0xa5, 0x8d, 0x16, 0x73, 0x9b, 0x8e, 0xd9, 0x0b,
+	0xd4, 0x4b, 0x8a, 0xce, 0xcb, 0x9d, 0xd5, 0xc1, 0x86, 0xbe, 0x01, 0x71, 0xd9, 0x32, 0x5f, 0xcf,
+	0xa4, 0x79, 0x08, 0x71, 0xd9, 0x4b, 0x27, 0x8f, 0xfe, 0x60, 0x8c, 0x46, 0xa6, 0x1c, 0xa3, 0xfa,
+	0xfb, 0x90, 0x50, 0xdd, 0x96, 0x5c, 0x05, 0x29, 0x73, 0x82, 0xd8, 0x94, 0x46, 0x57, 0xbf, 0x89,
+	0xb6, 0xf9, 0x9e, 0xaf, 0x84, 0x6e, 0x41, 0x76, 0xa8, 0xc9, 0xbe, 0x12, 0xf9, 0x26, 0x64, 0xc2,
+	0x7d, 0xf5, 0x55, 0xb8, 0x8b, 0x5f, 0x44, 0x21, 0x56, 0x7d, 0x86, 0x37, 0xa5, 0xff, 0xa2, 0xc1,
+	0x79, 0xff, 0xfe, 0xaa, 0x7c, 0xf6, 0xe3, 0x19, 0xb7, 0x1c, 0x7b, 0x5f, 0xfe, 0x74, 0xf0, 0xff,
+	0x18, 0x52, 0x83, 0x1c, 0x53, 0x9b, 0x8d, 0x70, 0xde, 0xd2, 0xcb, 0xe7, 0xc7, 0xff, 0x2c, 0xf4,
+	0x7b, 0xcc, 0x9c, 0x4f, 0xf5, 0x5f, 0xc8, 0x16, 0xe4, 0xb0, 0x7b, 0xf7, 0x6c, 0x17, 0x5b, 0x8c,
+	0xef, 0x4d, 0x16, 0xd8, 0x94, 0xbf, 0xef, 0xe6, 0x7c, 0xba, 0x32, 0xe8, 0x3f, 0x45, 0x06, 0xa7,
+	0x50, 0xb6, 0x52, 0x1b, 0xcb, 0xd0, 0xf5, 0x42, 0x45, 0x4e, 0x6e, 0x0d, 0x5f, 0xfa, 0x54, 0xc1,
+	0x07, 0x75, 0xd1, 0x1e, 0x7e, 0xd6, 0x11, 0xf1, 0xac, 0xab, 0x43, 0xf1, 0x8a, 0x8c, 0x16, 0x8f,
+	0x8c, 0x63, 0xf2, 0x1b, 0x7f, 0x93, 0x2f, 0x73, 0xf9, 0x63, 0x48, 0x05, 0x05, 0x42, 0x3e, 0x84,
+	0xf4, 0x20, 0x13, 0x8c, 0xe4, 0x47, 0xdd, 0x85, 0x7e, 0x62, 0xe4, 0x17, 0x5d, 0xd1, 0xae, 0x69,
+	0xe5, 0xf2, 0xf3, 0x17, 0x67, 0x8e, 0x3d, 0xff, 0xeb, 0x8c, 0xf6, 0x1b, 0xfe, 0xfb, 0xe1, 0xef,
+	0x33, 0xda, 0xa3, 0x6b, 0x53, 0xfd, 0x31, 0x22, 0xe4, 0x70, 0x27, 0x2e, 0xcc, 0xd7, 0xff, 0x03,
+	0x67, 0xa8, 0x27, 0x0a, 0x19, 0x14, 0x00, 0x00,
 }
 
 // Reference imports to suppress errors if they are not otherwise used.
@@ -2462,6 +2466,16 @@ func (m *Response_Validated) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.RequiresBoundedObjects {
+		i--
+		if m.RequiresBoundedObjects {
+			dAtA[i] = 1
+		} else {
+			dAtA[i] = 0
+		}
+		i--
+		dAtA[i] = 0x10
+	}
 	if len(m.Bindings) > 0 {
 		for iNdEx := len(m.Bindings) - 1; iNdEx >= 0; iNdEx-- {
 			{
@@ -3361,6 +3375,9 @@ func (m *Response_Validated) ProtoSize() (n int) {
 			n += 1 + l + sovMaterialize(uint64(l))
 		}
 	}
+	if m.RequiresBoundedObjects {
+		n += 2
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -6206,6 +6223,26 @@ func (m *Response_Validated) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RequiresBoundedObjects", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowMaterialize
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.RequiresBoundedObjects = bool(v != 0)
 		default:
 			iNdEx = preIndex
 			skippy, err := skipMaterialize(dAtA[iNdEx:])
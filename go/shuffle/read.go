@@ -633,21 +633,9 @@ func walkReads(id pc.ShardID, shardSpecs []*pc.ShardSpec, shuffles []shuffle,
 				// writes and it's possible the journal contains other key hashes, depending on its
 				// history over time.
 
-				// Extract owned key range from journal labels.
-				sourceBegin, err := labels.ParseHexU32Label(labels.KeyBegin, source.LabelSet)
-				if err != nil {
-					return fmt.Errorf("shuffle JournalSpec: %w", err)
-				}
-				sourceEnd, err := labels.ParseHexU32Label(labels.KeyEnd, source.LabelSet)
-				if err != nil {
-					return fmt.Errorf("shuffle JournalSpec: %w", err)
-				}
-				// Identify shards that cover this journal's range.
-				start, stop = rangeSpan(members, sourceBegin, sourceEnd)
-
-				if start == stop {
-					return fmt.Errorf("none of %d shards overlap the key-range of journal %s, %08x-%08x",
-						len(members), source.Name, sourceBegin, sourceEnd)
+				var err error
+				if start, stop, err = sourceKeySpan(members, &source); err != nil {
+					return err
 				}
 			} else {
 				// Documents of this journal are equally likely to shuffle to any member.
@@ -666,6 +654,34 @@ func walkReads(id pc.ShardID, shardSpecs []*pc.ShardSpec, shuffles []shuffle,
 	return nil
 }
 
+// sourceKeySpan returns the span of `members` which overlap the key range
+// of a `source` journal read on its collection's key. If the collection has
+// migrated its key, then the journal may hold documents routed under a prior
+// key and any member may be responsible for them.
+func sourceKeySpan(members []shuffleMember, source *pb.JournalSpec) (start, stop int, err error) {
+	if source.LabelSet.ValueOf(labels.KeyGeneration) != "" {
+		return 0, len(members), nil
+	}
+
+	// Extract owned key range from journal labels.
+	sourceBegin, err := labels.ParseHexU32Label(labels.KeyBegin, source.LabelSet)
+	if err != nil {
+		return 0, 0, fmt.Errorf("shuffle JournalSpec: %w", err)
+	}
+	sourceEnd, err := labels.ParseHexU32Label(labels.KeyEnd, source.LabelSet)
+	if err != nil {
+		return 0, 0, fmt.Errorf("shuffle JournalSpec: %w", err)
+	}
+	// Identify shards that cover this journal's range.
+	start, stop = rangeSpan(members, sourceBegin, sourceEnd)
+
+	if start == stop {
+		return 0, 0, fmt.Errorf("none of %d shards overlap the key-range of journal %s, %08x-%08x",
+			len(members), source.Name, sourceBegin, sourceEnd)
+	}
+	return start, stop, nil
+}
+
 // shuffleMember is a parsed ShardSpec representation used for walking reads.
 type shuffleMember struct {
 	spec    *pc.ShardSpec
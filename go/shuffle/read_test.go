@@ -463,6 +463,52 @@ func TestHRWRegression(t *testing.T) {
 	require.Equal(t, 2, pickHRW(h, ring, 2, 3))
 }
 
+func TestSourceKeySpanOfMigratedJournals(t *testing.T) {
+	var journals, shards, _ = buildReadTestJournalsAndTransforms()
+
+	var members, err = newShuffleMembers(shards)
+	require.NoError(t, err)
+
+	// Journals of bar=1 and bar=2 are split across three and two key ranges.
+	type span struct{ start, stop int }
+	var expect = []span{
+		{2, 3}, // foo/bar=1/baz=abc/part=00, aaaaaaaa-ffffffff
+		{1, 2}, // foo/bar=1/baz=abc/part=01, 55555555-aaaaaaa9
+		{0, 1}, // foo/bar=1/baz=def/part=00, 00000000-55555554
+		{2, 3}, // foo/bar=2/baz=def/part=00, aaaaaaaa-bbbbbbba
+		{2, 3}, // foo/bar=2/baz=def/part=01, bbbbbbbb-ffffffff
+	}
+	for i, exp := range expect {
+		var start, stop, err = sourceKeySpan(members, journals[i])
+		require.NoError(t, err)
+		require.Equal(t, exp, span{start, stop}, journals[i].Name)
+	}
+
+	var migrate = func(spec *pb.JournalSpec) *pb.JournalSpec {
+		var out = *spec
+		out.LabelSet = pb.LabelSet{Labels: append([]pb.Label(nil), spec.LabelSet.Labels...)}
+		out.LabelSet.AddValue(labels.KeyGeneration, "1122334455667788")
+		return &out
+	}
+
+	// After a key migration, the documents of every split may have keys
+	// which are owned by any member, and its key range is not relied upon.
+	for i := range expect {
+		var start, stop, err = sourceKeySpan(members, migrate(journals[i]))
+		require.NoError(t, err)
+		require.Equal(t, span{0, 3}, span{start, stop}, journals[i].Name)
+	}
+
+	// A migrated journal is read even if its range isn't covered by any member.
+	_, _, err = sourceKeySpan(members[:2], journals[0])
+	require.EqualError(t, err,
+		"none of 2 shards overlap the key-range of journal foo/bar=1/baz=abc/part=00, aaaaaaaa-ffffffff")
+
+	start, stop, err := sourceKeySpan(members[:2], migrate(journals[0]))
+	require.NoError(t, err)
+	require.Equal(t, span{0, 2}, span{start, stop})
+}
+
 func TestShuffleMemberOrdering(t *testing.T) {
 	var _, shards, _ = buildReadTestJournalsAndTransforms()
 
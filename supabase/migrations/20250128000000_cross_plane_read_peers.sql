@@ -0,0 +1,10 @@
+begin;
+
+alter table public.data_planes add column cross_plane_read_peers text[] not null default '{}';
+
+comment on column public.data_planes.cross_plane_read_peers is
+  'Names of peer data-planes whose collections may be read by tasks of this data-plane, where the task opts in with allowCrossPlaneRead';
+
+grant select(cross_plane_read_peers) on table public.data_planes to authenticated;
+
+commit;
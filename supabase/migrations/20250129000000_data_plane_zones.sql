@@ -0,0 +1,10 @@
+begin;
+
+alter table public.data_planes add column zones text[] not null default '{}';
+
+comment on column public.data_planes.zones is
+  'Zones of the data-plane, which journals and shards of its collections and tasks may declare';
+
+grant select(zones) on table public.data_planes to authenticated;
+
+commit;
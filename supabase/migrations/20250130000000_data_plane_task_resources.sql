@@ -0,0 +1,13 @@
+begin;
+
+alter table public.data_planes add column max_task_cpu_millis bigint;
+alter table public.data_planes add column max_task_memory_bytes bigint;
+
+comment on column public.data_planes.max_task_cpu_millis is
+  'Maximum CPU limit, in millicores, which tasks of the data-plane may declare for their connector containers';
+comment on column public.data_planes.max_task_memory_bytes is
+  'Maximum memory limit, in bytes, which tasks of the data-plane may declare for their connector containers';
+
+grant select(max_task_cpu_millis, max_task_memory_bytes) on table public.data_planes to authenticated;
+
+commit;
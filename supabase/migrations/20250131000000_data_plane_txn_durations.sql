@@ -0,0 +1,13 @@
+begin;
+
+alter table public.data_planes add column min_max_txn_duration_millis bigint;
+alter table public.data_planes add column max_txn_duration_millis bigint;
+
+comment on column public.data_planes.min_max_txn_duration_millis is
+  'Minimum maxTxnDuration, in milliseconds, which tasks of the data-plane may declare';
+comment on column public.data_planes.max_txn_duration_millis is
+  'Maximum minTxnDuration or maxTxnDuration, in milliseconds, which tasks of the data-plane may declare';
+
+grant select(min_max_txn_duration_millis, max_txn_duration_millis) on table public.data_planes to authenticated;
+
+commit;
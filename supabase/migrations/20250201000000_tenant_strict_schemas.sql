@@ -0,0 +1,8 @@
+begin;
+
+alter table public.tenants add column strict_schemas boolean not null default false;
+
+comment on column public.tenants.strict_schemas is
+  'Should collection schemas of the tenant be validated in strict mode?';
+
+commit;
@@ -0,0 +1,12 @@
+begin;
+
+alter table public.draft_specs
+  add column documentation_url text,
+  add column oauth2 json;
+
+comment on column public.draft_specs.documentation_url is
+  'Documentation URL of the connector used by the built specification, if any';
+comment on column public.draft_specs.oauth2 is
+  'OAuth2 specification of the connector used by the built specification, if any';
+
+commit;
@@ -0,0 +1,35 @@
+begin;
+
+create table internal.data_plane_audit (
+  id bigint generated always as identity primary key,
+  data_plane_id public.flowid not null,
+  actor text not null,
+  change_kind text not null,
+  name text not null,
+  action text not null,
+  old_build text,
+  new_build text,
+  revision bigint not null,
+  applied_at timestamptz not null
+);
+
+create index idx_data_plane_audit_name on internal.data_plane_audit (name);
+
+comment on table internal.data_plane_audit is
+  'Audit records of journal and shard changes applied to data-planes by activations';
+comment on column internal.data_plane_audit.actor is
+  'Actor which requested the change';
+comment on column internal.data_plane_audit.change_kind is
+  'Kind of the changed specification: journal or shard';
+comment on column internal.data_plane_audit.name is
+  'Journal name or shard ID of the changed specification';
+comment on column internal.data_plane_audit.action is
+  'Action which was applied: upsert or delete';
+comment on column internal.data_plane_audit.old_build is
+  'Build of the specification prior to the change, if known';
+comment on column internal.data_plane_audit.new_build is
+  'Build of the specification after the change, or null if it was deleted';
+comment on column internal.data_plane_audit.revision is
+  'Etcd revision at which the change was applied';
+
+commit;